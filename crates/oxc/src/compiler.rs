@@ -5,15 +5,15 @@ use oxc_ast::ast::Program;
 use oxc_codegen::{Codegen, CodegenOptions, CodegenReturn};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_isolated_declarations::{IsolatedDeclarations, IsolatedDeclarationsOptions};
-use oxc_mangler::{MangleOptions, Mangler};
+use oxc_mangler::{MangleOptions, Mangler, PropertyMangler};
 use oxc_minifier::{CompressOptions, Compressor};
 use oxc_parser::{ParseOptions, Parser, ParserReturn};
 use oxc_semantic::{Scoping, SemanticBuilder, SemanticBuilderReturn};
 use oxc_span::SourceType;
 use oxc_transformer::{TransformOptions, Transformer, TransformerReturn};
 use oxc_transformer_plugins::{
-    InjectGlobalVariables, InjectGlobalVariablesConfig, ReplaceGlobalDefines,
-    ReplaceGlobalDefinesConfig,
+    ImportMetaTransform, ImportMetaTransformConfig, InjectGlobalVariables,
+    InjectGlobalVariablesConfig, ReplaceGlobalDefines, ReplaceGlobalDefinesConfig,
 };
 
 #[derive(Default)]
@@ -78,6 +78,10 @@ pub trait CompilerInterface {
         None
     }
 
+    fn import_meta_options(&self) -> Option<ImportMetaTransformConfig> {
+        None
+    }
+
     fn compress_options(&self) -> Option<CompressOptions> {
         None
     }
@@ -170,15 +174,26 @@ pub trait CompilerInterface {
             (scoping) = transformer_return.scoping;
         }
 
+        let import_meta_options = self.import_meta_options();
         let inject_options = self.inject_options();
         let define_options = self.define_options();
 
         // Symbols and scopes are out of sync.
-        if inject_options.is_some() || define_options.is_some() {
+        if import_meta_options.is_some() || inject_options.is_some() || define_options.is_some() {
             scoping =
                 SemanticBuilder::new().with_stats(stats).build(&program).semantic.into_scoping();
         }
 
+        if let Some(options) = import_meta_options {
+            let ret = ImportMetaTransform::new(&allocator, source_path, options)
+                .build(scoping, &mut program);
+            if !ret.errors.is_empty() {
+                self.handle_errors(ret.errors);
+                return;
+            }
+            scoping = ret.scoping;
+        }
+
         if let Some(options) = inject_options {
             let ret = InjectGlobalVariables::new(&allocator, options).build(scoping, &mut program);
             scoping = ret.scoping;
@@ -211,7 +226,8 @@ pub trait CompilerInterface {
 
         /* Mangler */
 
-        let mangler = self.mangle_options().map(|options| self.mangle(&mut program, options));
+        let mangler =
+            self.mangle_options().map(|options| self.mangle(&allocator, &mut program, options));
 
         /* Codegen */
 
@@ -282,7 +298,15 @@ pub trait CompilerInterface {
         Compressor::new(allocator).build(program, options);
     }
 
-    fn mangle(&self, program: &mut Program<'_>, options: MangleOptions) -> Scoping {
+    fn mangle<'a>(
+        &self,
+        allocator: &'a Allocator,
+        program: &mut Program<'a>,
+        mut options: MangleOptions,
+    ) -> Scoping {
+        if let Some(props) = options.props.take() {
+            PropertyMangler::new(props).build(allocator, program);
+        }
         Mangler::new().with_options(options).build(program)
     }
 
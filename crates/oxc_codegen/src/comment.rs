@@ -1,41 +1,71 @@
+use cow_utils::CowUtils;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::borrow::Cow;
 
-use oxc_ast::{Comment, CommentKind, ast::Program};
-use oxc_syntax::identifier::is_line_terminator;
+use oxc_ast::{Comment, CommentContent, CommentKind, ast::Program};
+use oxc_span::Span;
+use oxc_syntax::identifier::{is_line_terminator, is_white_space_single_line};
 
 use crate::{Codegen, LegalComment, options::CommentOptions};
 
 pub type CommentsMap = FxHashMap</* attached_to */ u32, Vec<Comment>>;
 
 impl Codegen<'_> {
+    /// Attach the comments to the start of the token following them.
+    ///
+    /// Trailing comments, e.g. `foo(); // comment`, are attached to the next token as well,
+    /// and are printed after the previous token by [Codegen::print_trailing_comments]
+    /// or [Codegen::print_comments_after] when possible.
     pub(crate) fn build_comments(&mut self, comments: &[Comment]) {
         if self.options.comments == CommentOptions::disabled() {
             return;
         }
-        for comment in comments {
+        let source_text = self.source_text.unwrap_or_default();
+        // Start and attached token of the comment after the current one.
+        let mut next_comment: Option<(u32, u32)> = None;
+        for comment in comments.iter().rev() {
+            let attached_to = if comment.is_leading() {
+                comment.attached_to
+            } else {
+                let next_start = skip_whitespace(source_text, comment.span.end);
+                match next_comment {
+                    Some((start, attached_to)) if start == next_start => attached_to,
+                    _ => next_start,
+                }
+            };
+            next_comment = Some((comment.span.start, attached_to));
             // Omit pure comments because they are handled separately.
             if comment.is_pure() || comment.is_no_side_effects() {
                 continue;
             }
-            let mut add = false;
-            if comment.is_leading() {
-                if comment.is_legal() && self.options.print_legal_comment() {
-                    add = true;
-                }
-                if comment.is_jsdoc() && self.options.print_jsdoc_comment() {
-                    add = true;
-                }
-                if comment.is_annotation() && self.options.print_annotation_comment() {
-                    add = true;
-                }
-                if comment.is_normal() && self.options.print_normal_comment() {
-                    add = true;
-                }
-            }
+            let is_legal =
+                matches!(comment.content, CommentContent::Legal | CommentContent::JsdocLegal);
+            let is_jsdoc =
+                matches!(comment.content, CommentContent::Jsdoc | CommentContent::JsdocLegal);
+            let add = (is_legal && self.options.print_legal_comment())
+                || (is_jsdoc && self.options.print_jsdoc_comment())
+                || (comment.is_annotation() && self.options.print_annotation_comment())
+                || (comment.is_normal() && self.options.print_normal_comment());
             if add {
-                self.comments.entry(comment.attached_to).or_default().push(*comment);
+                self.comments.entry(attached_to).or_default().push(*comment);
+            }
+        }
+        for comments in self.comments.values_mut() {
+            comments.reverse();
+        }
+        // `/** @type {T} */ (x)`
+        for &start in self.comments.keys() {
+            if source_text.as_bytes().get(start as usize) != Some(&b'(') {
+                continue;
+            }
+            let mut inner_start = next_token_start(source_text, start + 1);
+            while source_text.as_bytes().get(inner_start as usize) == Some(&b'(') {
+                inner_start = next_token_start(source_text, inner_start + 1);
             }
+            self.parenthesized_starts.entry(inner_start).or_default().push(start);
+        }
+        for starts in self.parenthesized_starts.values_mut() {
+            starts.sort_unstable();
         }
     }
 
@@ -83,6 +113,229 @@ impl Codegen<'_> {
         }
     }
 
+    /// Print comments attached to `start` within an expression, e.g. `foo(a, /* comment */ b)`.
+    pub(crate) fn print_inline_comments_at(&mut self, start: u32) {
+        self.print_inline_comments_before_parens(start);
+        if let Some(comments) = self.get_comments(start) {
+            self.print_inline_comments(&comments);
+        }
+    }
+
+    /// Print comments before the parentheses around the expression starting at `start`,
+    /// which are not printed with the parentheses, e.g. `/** @type {T} */ (x)` when the parentheses are removed.
+    pub(crate) fn print_inline_comments_before_parens(&mut self, start: u32) {
+        if self.comments.is_empty() {
+            return;
+        }
+        let Some(paren_starts) = self.parenthesized_starts.remove(&start) else { return };
+        for paren_start in paren_starts {
+            if let Some(comments) = self.get_comments(paren_start) {
+                self.print_inline_comments(&comments);
+            }
+        }
+    }
+
+    fn print_inline_comments(&mut self, comments: &[Comment]) {
+        let code_len = self.code_len();
+        // A line terminator after `return`, `throw` or `yield` would end the statement.
+        let no_line_terminator = self.start_of_no_line_terminator == code_len;
+        for comment in comments {
+            self.print_comment_separator();
+            if no_line_terminator {
+                self.print_comment_on_single_line(comment);
+                self.print_soft_space();
+                continue;
+            }
+            self.print_comment(comment);
+            if comment.is_line() || comment.followed_by_newline() {
+                self.print_hard_newline();
+                self.print_indent();
+            } else {
+                self.print_soft_space();
+            }
+        }
+        // The comments do not change what the next token starts.
+        if self.start_of_stmt == code_len {
+            self.start_of_stmt = self.code_len();
+        }
+        if self.start_of_arrow_expr == code_len {
+            self.start_of_arrow_expr = self.code_len();
+        }
+        if self.start_of_default_export == code_len {
+            self.start_of_default_export = self.code_len();
+        }
+        if no_line_terminator {
+            self.start_of_no_line_terminator = self.code_len();
+        }
+    }
+
+    /// Print comments between a node ending at `end` and the next token,
+    /// e.g. `a /* comment */ + b` or `promise.then(f) // comment`.
+    ///
+    /// If the next token may start another statement, only comments which fit in the line are printed,
+    /// and the others are left for the next statement.
+    /// Comments before a closing bracket, or before the trailing comma of arguments,
+    /// are left for the enclosing list or block.
+    pub(crate) fn print_comments_after(&mut self, end: u32) {
+        if self.comments.is_empty() {
+            return;
+        }
+        let Some(source_text) = self.source_text else { return };
+        let start = next_token_start(source_text, end);
+        if start == end {
+            return;
+        }
+        let rest = &source_text[start as usize..];
+        if rest.starts_with([')', ']', '}']) {
+            return;
+        }
+        if rest.starts_with(',')
+            && source_text.as_bytes().get(next_token_start(source_text, start + 1) as usize)
+                == Some(&b')')
+        {
+            return;
+        }
+        let comments = if continues_expression(rest) {
+            self.comments.remove(&start).unwrap_or_default()
+        } else {
+            self.take_comments(end, start, |comment, source_text| {
+                comment.is_block()
+                    && !comment.span.source_text(source_text).contains(is_line_terminator)
+            })
+        };
+        for comment in &comments {
+            if comment.preceded_by_newline() {
+                self.print_hard_newline();
+                self.print_indent();
+            } else {
+                self.print_hard_space();
+            }
+            self.print_comment(comment);
+        }
+        if comments.last().is_some_and(|comment| comment.is_line() || comment.followed_by_newline())
+        {
+            self.print_hard_newline();
+            self.print_indent();
+        }
+    }
+
+    /// Print trailing comments on the same line after a statement or a list item ending at `end`,
+    /// e.g. `foo(); // comment`.
+    ///
+    /// Must be followed by a newline.
+    pub(crate) fn print_trailing_comments(&mut self, end: u32) {
+        if self.comments.is_empty() {
+            return;
+        }
+        let Some(source_text) = self.source_text else { return };
+        let mut end = end;
+        let mut start = next_token_start(source_text, end);
+        // `end` may be the end of the expression in an expression statement, or of a list item.
+        if source_text[start as usize..].starts_with([';', ',']) {
+            end = start + 1;
+            start = next_token_start(source_text, end);
+        }
+        self.print_trailing_comments_attached_to(end, start);
+    }
+
+    /// Print comments on the same line after `end`, which are attached to the token at `start`.
+    pub(crate) fn print_trailing_comments_attached_to(&mut self, end: u32, start: u32) {
+        let comments = self.take_comments(end, start, |comment, _| comment.is_trailing());
+        if !comments.is_empty() {
+            self.print_semicolon_if_needed();
+        }
+        for comment in &comments {
+            self.print_comment_separator();
+            self.print_soft_space();
+            self.print_comment(comment);
+        }
+        // Minified statements are not followed by a newline.
+        if self.options.minify && comments.last().is_some_and(|comment| comment.is_line()) {
+            self.print_hard_newline();
+        }
+    }
+
+    /// Whether the comments before the closing brace of a block can be printed on the same line as the braces,
+    /// e.g. `function foo() { /* comment */ }`.
+    pub(crate) fn has_only_inline_comments_at_end(&self, span: Span) -> bool {
+        span.end == 0
+            || self.comments.get(&(span.end - 1)).is_none_or(|comments| {
+                comments.iter().all(|c| !c.preceded_by_newline() && !c.followed_by_newline())
+            })
+    }
+
+    /// Print comments before the closing brace of a block on their own lines.
+    pub(crate) fn print_comments_at_end(&mut self, span: Span) {
+        if span.end == 0 {
+            return;
+        }
+        if let Some(comments) = self.get_comments(span.end - 1) {
+            // `{ /* comment */ }` is printed on a single line.
+            let single_line = self.last_byte() == Some(b'{');
+            self.print_comments(&comments);
+            self.print_next_indent_as_space = false;
+            if !single_line && self.last_byte() != Some(b'\n') {
+                self.print_soft_newline();
+            }
+        }
+    }
+
+    /// Print comments before the closing bracket of a list ending at `end`,
+    /// on their own lines if the list is printed on multiple lines.
+    pub(crate) fn print_comments_before_closing_bracket(&mut self, end: u32, multi_line: bool) {
+        if end == 0 {
+            return;
+        }
+        let Some(comments) = self.get_comments(end - 1) else { return };
+        for comment in &comments {
+            if multi_line {
+                self.print_hard_newline();
+                self.print_indent();
+            } else {
+                self.print_comment_separator();
+                self.print_soft_space();
+            }
+            self.print_comment(comment);
+            if !multi_line && comment.is_line() {
+                self.print_hard_newline();
+                self.print_indent();
+            }
+        }
+    }
+
+    /// Remove the comments attached to `start` which are on the same line as `end`,
+    /// and satisfy `predicate`.
+    fn take_comments(
+        &mut self,
+        end: u32,
+        start: u32,
+        predicate: impl Fn(&Comment, &str) -> bool,
+    ) -> Vec<Comment> {
+        let Some(source_text) = self.source_text else { return vec![] };
+        let Some(comments) = self.comments.get_mut(&start) else { return vec![] };
+        let count = comments
+            .iter()
+            .take_while(|comment| {
+                comment.span.start >= end
+                    && !source_text[end as usize..comment.span.start as usize]
+                        .contains(is_line_terminator)
+                    && predicate(comment, source_text)
+            })
+            .count();
+        let taken = comments.drain(..count).collect::<Vec<_>>();
+        if comments.is_empty() {
+            self.comments.remove(&start);
+        }
+        taken
+    }
+
+    /// Avoid `a / /* comment */ b` from being printed as a line comment.
+    fn print_comment_separator(&mut self) {
+        if self.last_byte() == Some(b'/') {
+            self.print_hard_space();
+        }
+    }
+
     pub(crate) fn print_comments(&mut self, comments: &[Comment]) {
         for (i, comment) in comments.iter().enumerate() {
             if i == 0 {
@@ -145,6 +398,31 @@ impl Codegen<'_> {
         }
     }
 
+    /// Print a comment as a block comment without line terminators, e.g. `// comment` as `/* comment */`.
+    fn print_comment_on_single_line(&mut self, comment: &Comment) {
+        let Some(source_text) = self.source_text else {
+            return;
+        };
+        let comment_source = comment.span.source_text(source_text);
+        let text = match comment.kind {
+            CommentKind::Line => comment_source[2..].trim_end(),
+            CommentKind::Block => &comment_source[2..comment_source.len() - 2],
+        };
+        self.print_str("/*");
+        for (i, line) in text.split(is_line_terminator).enumerate() {
+            let line = if i == 0 { line } else { line.trim_start() };
+            if i != 0 && !line.is_empty() {
+                self.print_ascii_byte(b' ');
+            }
+            // Avoid closing the comment early.
+            self.print_str_escaping_script_close_tag(&line.cow_replace("*/", "* /"));
+        }
+        if comment.is_line() {
+            self.print_ascii_byte(b' ');
+        }
+        self.print_str("*/");
+    }
+
     /// Handle Eof / Linked / External Comments.
     /// Return a list of comments of linked or external.
     pub(crate) fn handle_eof_linked_or_external_comments(
@@ -208,3 +486,49 @@ impl Codegen<'_> {
         }
     }
 }
+
+fn skip_whitespace(source_text: &str, start: u32) -> u32 {
+    let Some(rest) = source_text.get(start as usize..) else { return start };
+    let len = rest.len()
+        - rest.trim_start_matches(|c| is_white_space_single_line(c) || is_line_terminator(c)).len();
+    #[expect(clippy::cast_possible_truncation)]
+    let len = len as u32;
+    start + len
+}
+
+/// Start of the first token at or after `start`, skipping whitespace and comments.
+fn next_token_start(source_text: &str, start: u32) -> u32 {
+    let mut start = skip_whitespace(source_text, start);
+    loop {
+        let Some(rest) = source_text.get(start as usize..) else { return start };
+        let comment_len = if rest.starts_with("//") {
+            rest.find(is_line_terminator).unwrap_or(rest.len())
+        } else if let Some(body) = rest.strip_prefix("/*") {
+            body.find("*/").map_or(rest.len(), |end| end + 4)
+        } else {
+            return start;
+        };
+        #[expect(clippy::cast_possible_truncation)]
+        let comment_len = comment_len as u32;
+        start = skip_whitespace(source_text, start + comment_len);
+    }
+}
+
+/// Whether a token starting `rest` can continue the expression before it,
+/// so that a newline can be printed before it.
+fn continues_expression(rest: &str) -> bool {
+    match rest.as_bytes() {
+        [b'+', b'+', ..] | [b'-', b'-', ..] => false,
+        [b'!', b'=', ..]
+        | [
+            b'.' | b'?' | b':' | b',' | b';' | b'(' | b'[' | b'+' | b'-' | b'*' | b'/' | b'%'
+            | b'<' | b'>' | b'=' | b'&' | b'|' | b'^' | b'`',
+            ..,
+        ] => true,
+        _ => ["in", "instanceof"].iter().any(|keyword| {
+            rest.strip_prefix(keyword).is_some_and(|rest| {
+                !rest.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+            })
+        }),
+    }
+}
@@ -133,7 +133,7 @@ impl Gen for Statement<'_> {
                 p.print_comments_at(decl.span.start);
                 p.print_indent();
                 decl.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_statement(decl.span.end);
             }
             Self::FunctionDeclaration(decl) => {
                 p.print_comments_at(decl.span.start);
@@ -143,43 +143,48 @@ impl Gen for Statement<'_> {
                 }
                 p.print_indent();
                 decl.print(p, ctx);
+                p.print_trailing_comments(decl.span.end);
                 p.print_soft_newline();
             }
             Self::ClassDeclaration(decl) => {
                 p.print_comments_at(decl.span.start);
                 p.print_indent();
                 decl.print(p, ctx);
+                p.print_trailing_comments(decl.span.end);
                 p.print_soft_newline();
             }
             Self::TSModuleDeclaration(decl) => {
                 p.print_comments_at(decl.span.start);
                 p.print_indent();
                 decl.print(p, ctx);
+                p.print_trailing_comments(decl.span.end);
                 p.print_soft_newline();
             }
             Self::TSTypeAliasDeclaration(decl) => {
                 p.print_indent();
                 p.print_comments_at(decl.span.start);
                 decl.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_statement(decl.span.end);
             }
             Self::TSInterfaceDeclaration(decl) => {
                 p.print_indent();
                 p.print_comments_at(decl.span.start);
                 decl.print(p, ctx);
+                p.print_trailing_comments(decl.span.end);
                 p.print_soft_newline();
             }
             Self::TSEnumDeclaration(decl) => {
                 p.print_indent();
                 p.print_comments_at(decl.span.start);
                 decl.print(p, ctx);
+                p.print_trailing_comments(decl.span.end);
                 p.print_soft_newline();
             }
             Self::TSImportEqualsDeclaration(decl) => {
                 p.print_indent();
                 p.print_comments_at(decl.span.start);
                 decl.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_statement(decl.span.end);
             }
         }
     }
@@ -194,7 +199,7 @@ impl Gen for ExpressionStatement<'_> {
         if self.expression.is_specific_id("let") {
             p.print_semicolon();
         } else {
-            p.print_semicolon_after_statement();
+            p.print_semicolon_after_statement(self.span.end);
         }
     }
 }
@@ -223,6 +228,7 @@ fn print_if(if_stmt: &IfStatement<'_>, p: &mut Codegen, ctx: Context) {
             if if_stmt.alternate.is_some() {
                 p.print_soft_space();
             } else {
+                p.print_trailing_comments(block.span.end);
                 p.print_soft_newline();
             }
         }
@@ -253,6 +259,7 @@ fn print_if(if_stmt: &IfStatement<'_>, p: &mut Codegen, ctx: Context) {
             Statement::BlockStatement(block) => {
                 p.print_soft_space();
                 p.print_block_statement(block, ctx);
+                p.print_trailing_comments(block.span.end);
                 p.print_soft_newline();
             }
             Statement::IfStatement(if_stmt) => {
@@ -291,6 +298,7 @@ impl Gen for BlockStatement<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         p.print_indent();
         p.print_block_statement(self, ctx);
+        p.print_trailing_comments(self.span.end);
         p.print_soft_newline();
     }
 }
@@ -306,6 +314,9 @@ impl Gen for ForStatement<'_> {
         p.print_ascii_byte(b'(');
 
         if let Some(init) = &self.init {
+            if let ForStatementInit::VariableDeclaration(var) = init {
+                p.print_inline_comments_at(var.span.start);
+            }
             init.print(p, Context::FORBID_IN);
         }
 
@@ -337,7 +348,9 @@ impl Gen for ForInStatement<'_> {
         p.print_str("for");
         p.print_soft_space();
         p.print_ascii_byte(b'(');
+        p.print_inline_comments_at(self.left.span().start);
         self.left.print(p, Context::FORBID_IN);
+        p.print_comments_after(self.left.span().end);
         p.print_soft_space();
         p.print_space_before_identifier();
         p.print_str("in");
@@ -360,7 +373,9 @@ impl Gen for ForOfStatement<'_> {
         }
         p.print_soft_space();
         p.print_ascii_byte(b'(');
+        p.print_inline_comments_at(self.left.span().start);
         self.left.print(p, ctx);
+        p.print_comments_after(self.left.span().end);
         p.print_soft_space();
         p.print_space_before_identifier();
         p.print_str("of");
@@ -438,7 +453,7 @@ impl Gen for DoWhileStatement<'_> {
         p.print_ascii_byte(b'(');
         p.print_expression(&self.test);
         p.print_ascii_byte(b')');
-        p.print_semicolon_after_statement();
+        p.print_semicolon_after_statement(self.span.end);
     }
 }
 
@@ -463,7 +478,7 @@ impl Gen for ContinueStatement<'_> {
             p.print_soft_space();
             label.print(p, ctx);
         }
-        p.print_semicolon_after_statement();
+        p.print_semicolon_after_statement(self.span.end);
     }
 }
 
@@ -478,7 +493,7 @@ impl Gen for BreakStatement<'_> {
             p.print_soft_space();
             label.print(p, ctx);
         }
-        p.print_semicolon_after_statement();
+        p.print_semicolon_after_statement(self.span.end);
     }
 }
 
@@ -494,11 +509,14 @@ impl Gen for SwitchStatement<'_> {
         p.print_expression(&self.discriminant);
         p.print_ascii_byte(b')');
         p.print_soft_space();
-        p.print_curly_braces(self.span, self.cases.is_empty(), |p| {
+        let single_line = self.cases.is_empty() && p.has_only_inline_comments_at_end(self.span);
+        p.print_curly_braces(self.span, single_line, |p| {
             for case in &self.cases {
                 case.print(p, ctx);
             }
+            p.print_comments_at_end(self.span);
         });
+        p.print_trailing_comments(self.span.end);
         p.print_soft_newline();
         p.needs_semicolon = false;
     }
@@ -507,6 +525,7 @@ impl Gen for SwitchStatement<'_> {
 impl Gen for SwitchCase<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         p.print_semicolon_if_needed();
+        p.print_comments_at(self.span.start);
         p.print_indent();
         p.add_source_mapping(self.span);
         match &self.test {
@@ -543,9 +562,10 @@ impl Gen for ReturnStatement<'_> {
         p.print_str("return");
         if let Some(arg) = &self.argument {
             p.print_soft_space();
+            p.start_of_no_line_terminator = p.code_len();
             p.print_expression(arg);
         }
-        p.print_semicolon_after_statement();
+        p.print_semicolon_after_statement(self.span.end);
     }
 }
 
@@ -581,6 +601,7 @@ impl Gen for TryStatement<'_> {
             p.print_soft_space();
             p.print_block_statement(finalizer, ctx);
         }
+        p.print_trailing_comments(self.span.end);
         p.print_soft_newline();
     }
 }
@@ -609,8 +630,9 @@ impl Gen for ThrowStatement<'_> {
         p.print_space_before_identifier();
         p.print_str("throw");
         p.print_soft_space();
+        p.start_of_no_line_terminator = p.code_len();
         p.print_expression(&self.argument);
-        p.print_semicolon_after_statement();
+        p.print_semicolon_after_statement(self.span.end);
     }
 }
 
@@ -635,7 +657,7 @@ impl Gen for DebuggerStatement {
         p.print_indent();
         p.print_space_before_identifier();
         p.print_str("debugger");
-        p.print_semicolon_after_statement();
+        p.print_semicolon_after_statement(self.span.end);
     }
 }
 
@@ -654,10 +676,14 @@ impl Gen for VariableDeclaration<'_> {
             VariableDeclarationKind::Using => "using",
             VariableDeclarationKind::AwaitUsing => "await using",
         });
-        if !self.declarations.is_empty() {
+        for (i, declarator) in self.declarations.iter().enumerate() {
+            if i != 0 {
+                p.print_comma();
+            }
             p.print_soft_space();
+            p.print_inline_comments_at(declarator.span.start);
+            declarator.print(p, ctx);
         }
-        p.print_list(&self.declarations, ctx);
     }
 }
 
@@ -735,22 +761,11 @@ impl Gen for Function<'_> {
 
 impl Gen for FunctionBody<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
-        let span_end = self.span.end;
-        let comments_at_end = if span_end > 0 { p.get_comments(span_end - 1) } else { None };
-        let single_line = if self.is_empty() {
-            comments_at_end.as_ref().is_none_or(|comments| {
-                comments.iter().all(|c| !c.preceded_by_newline() && !c.followed_by_newline())
-            })
-        } else {
-            false
-        };
+        let single_line = self.is_empty() && p.has_only_inline_comments_at_end(self.span);
         p.print_curly_braces(self.span, single_line, |p| {
             p.print_directives_and_statements(&self.directives, &self.statements, ctx);
             // Print trailing statement comments.
-            if let Some(comments) = comments_at_end {
-                p.print_comments(&comments);
-                p.print_next_indent_as_space = false;
-            }
+            p.print_comments_at_end(self.span);
         });
         p.needs_semicolon = false;
     }
@@ -780,14 +795,24 @@ impl Gen for FormalParameter<'_> {
 
 impl Gen for FormalParameters<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
-        p.print_list(&self.items, ctx);
+        for (i, item) in self.items.iter().enumerate() {
+            if i != 0 {
+                p.print_comma();
+                p.print_soft_space();
+            }
+            p.print_inline_comments_at(item.span.start);
+            item.print(p, ctx);
+            p.print_comments_after(item.span.end);
+        }
         if let Some(rest) = &self.rest {
             if !self.items.is_empty() {
                 p.print_comma();
                 p.print_soft_space();
             }
+            p.print_inline_comments_at(rest.span.start);
             rest.print(p, ctx);
         }
+        p.print_comments_before_closing_bracket(self.span.end, false);
     }
 }
 
@@ -819,7 +844,7 @@ impl Gen for ImportDeclaration<'_> {
                     p.print_hard_space();
                     with_clause.print(p, ctx);
                 }
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_statement(self.span.end);
                 return;
             }
 
@@ -901,7 +926,7 @@ impl Gen for ImportDeclaration<'_> {
             with_clause.print(p, ctx);
         }
         p.add_source_mapping_end(self.span);
-        p.print_semicolon_after_statement();
+        p.print_semicolon_after_statement(self.span.end);
     }
 }
 
@@ -967,7 +992,7 @@ impl Gen for ExportNamedDeclaration<'_> {
                     | Declaration::TSTypeAliasDeclaration(_)
                     | Declaration::TSImportEqualsDeclaration(_)
             ) {
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_statement(self.span.end);
             } else {
                 p.print_soft_newline();
                 p.needs_semicolon = false;
@@ -991,7 +1016,7 @@ impl Gen for ExportNamedDeclaration<'_> {
                 p.print_soft_space();
                 p.print_string_literal(source, false);
             }
-            p.print_semicolon_after_statement();
+            p.print_semicolon_after_statement(self.span.end);
         }
     }
 }
@@ -1002,7 +1027,7 @@ impl Gen for TSExportAssignment<'_> {
         p.print_comments_at(self.span.start);
         p.print_str("export = ");
         self.expression.print_expr(p, Precedence::Lowest, ctx);
-        p.print_semicolon_after_statement();
+        p.print_semicolon_after_statement(self.span.end);
     }
 }
 
@@ -1012,7 +1037,7 @@ impl Gen for TSNamespaceExportDeclaration<'_> {
         p.print_comments_at(self.span.start);
         p.print_str("export as namespace ");
         self.id.print(p, ctx);
-        p.print_semicolon_after_statement();
+        p.print_semicolon_after_statement(self.span.end);
     }
 }
 
@@ -1081,7 +1106,7 @@ impl Gen for ExportAllDeclaration<'_> {
             p.print_hard_space();
             with_clause.print(p, ctx);
         }
-        p.print_semicolon_after_statement();
+        p.print_semicolon_after_statement(self.span.end);
     }
 }
 
@@ -1103,10 +1128,12 @@ impl Gen for ExportDefaultDeclarationKind<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
             Self::FunctionDeclaration(func) => {
+                p.print_inline_comments_at(func.span.start);
                 func.print(p, ctx);
                 p.print_soft_newline();
             }
             Self::ClassDeclaration(class) => {
+                p.print_inline_comments_at(class.span.start);
                 class.print(p, ctx);
                 p.print_soft_newline();
             }
@@ -1114,7 +1141,7 @@ impl Gen for ExportDefaultDeclarationKind<'_> {
             _ => {
                 p.start_of_default_export = p.code_len();
                 self.to_expression().print_expr(p, Precedence::Comma, Context::empty());
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_statement(self.span().end);
             }
         }
     }
@@ -1122,6 +1149,12 @@ impl Gen for ExportDefaultDeclarationKind<'_> {
 
 impl GenExpr for Expression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
+        // Comments before object literals are printed with parentheses, see `ObjectExpression`.
+        if matches!(self, Self::ObjectExpression(_)) {
+            p.print_inline_comments_before_parens(self.span().start);
+        } else {
+            p.print_inline_comments_at(self.span().start);
+        }
         match self {
             Self::BooleanLiteral(lit) => lit.print(p, ctx),
             Self::NullLiteral(lit) => lit.print(p, ctx),
@@ -1177,6 +1210,7 @@ impl GenExpr for Expression<'_> {
             Self::TSInstantiationExpression(e) => e.print_expr(p, precedence, ctx),
             Self::V8IntrinsicExpression(e) => e.print_expr(p, precedence, ctx),
         }
+        p.print_comments_after(self.span().end);
     }
 }
 
@@ -1366,6 +1400,7 @@ impl GenExpr for StaticMemberExpression<'_> {
             p.print_hard_space();
         }
         p.print_ascii_byte(b'.');
+        p.print_inline_comments_at(self.property.span.start);
         self.property.print(p, ctx);
     }
 }
@@ -1377,6 +1412,7 @@ impl GenExpr for PrivateFieldExpression<'_> {
             p.print_str("?");
         }
         p.print_ascii_byte(b'.');
+        p.print_inline_comments_at(self.field.span.start);
         self.field.print(p, ctx);
     }
 }
@@ -1451,6 +1487,10 @@ impl Gen for ArrayExpression<'_> {
         for (i, item) in self.elements.iter().enumerate() {
             if i != 0 {
                 p.print_comma();
+                if is_multi_line {
+                    let prev_end = self.elements[i - 1].span().end;
+                    p.print_trailing_comments_attached_to(prev_end, item.span().start);
+                }
             }
             if is_multi_line {
                 p.print_soft_newline();
@@ -1463,6 +1503,11 @@ impl Gen for ArrayExpression<'_> {
                 p.print_comma();
             }
         }
+        if is_multi_line {
+            let last_end = self.elements[self.elements.len() - 1].span().end;
+            p.print_trailing_comments_attached_to(last_end, self.span.end.saturating_sub(1));
+        }
+        p.print_comments_before_closing_bracket(self.span.end, is_multi_line);
         if is_multi_line {
             p.print_soft_newline();
             p.dedent();
@@ -1495,6 +1540,10 @@ impl GenExpr for ObjectExpression<'_> {
             for (i, item) in self.properties.iter().enumerate() {
                 if i != 0 {
                     p.print_comma();
+                    if is_multi_line {
+                        let prev_end = self.properties[i - 1].span().end;
+                        p.print_trailing_comments_attached_to(prev_end, item.span().start);
+                    }
                 }
                 if is_multi_line {
                     p.print_soft_newline();
@@ -1502,8 +1551,14 @@ impl GenExpr for ObjectExpression<'_> {
                 } else {
                     p.print_soft_space();
                 }
+                p.print_inline_comments_at(item.span().start);
                 item.print(p, ctx);
             }
+            if is_multi_line {
+                let last_end = self.properties[len - 1].span().end;
+                p.print_trailing_comments_attached_to(last_end, self.span.end.saturating_sub(1));
+            }
+            p.print_comments_before_closing_bracket(self.span.end, is_multi_line);
             if is_multi_line {
                 p.print_soft_newline();
                 p.dedent();
@@ -1622,6 +1677,8 @@ impl Gen for ObjectProperty<'_> {
 
 impl Gen for PropertyKey<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
+        // `get /* comment */ x() {}`
+        p.print_inline_comments_at(self.span().start);
         match self {
             Self::StaticIdentifier(ident) => ident.print(p, ctx),
             Self::PrivateIdentifier(ident) => ident.print(p, ctx),
@@ -1671,6 +1728,7 @@ impl GenExpr for ArrowFunctionExpression<'_> {
             if self.expression {
                 if let Some(Statement::ExpressionStatement(stmt)) = &self.body.statements.first() {
                     p.start_of_arrow_expr = p.code_len();
+                    p.start_of_no_line_terminator = p.code_len();
                     stmt.expression.print_expr(p, Precedence::Comma, ctx);
                 }
             } else {
@@ -1691,6 +1749,7 @@ impl GenExpr for YieldExpression<'_> {
             }
             if let Some(argument) = self.argument.as_ref() {
                 p.print_soft_space();
+                p.start_of_no_line_terminator = p.code_len();
                 argument.print_expr(p, Precedence::Yield, Context::empty());
             }
         });
@@ -1958,9 +2017,11 @@ impl Gen for AssignmentTargetPropertyIdentifier<'_> {
             self.binding.print(p, ctx);
         } else {
             // `({x: a} = y);`
+            p.add_source_mapping(self.binding.span);
             p.print_str(self.binding.name.as_str());
             p.print_colon();
             p.print_soft_space();
+            p.add_source_mapping_for_name(self.binding.span, ident_name);
             p.print_str(ident_name);
         }
         if let Some(expr) = &self.init {
@@ -2099,6 +2160,7 @@ impl Gen for TemplateLiteral<'_> {
         for (expr, quasi) in self.expressions.iter().zip(remaining_quasis) {
             p.print_str("${");
             p.print_expression(expr);
+            p.print_comments_before_closing_bracket(quasi.span.start, false);
             p.print_ascii_byte(b'}');
 
             p.add_source_mapping(quasi.span);
@@ -2295,13 +2357,15 @@ impl Gen for Class<'_> {
 
 impl Gen for ClassBody<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
-        p.print_curly_braces(self.span, self.body.is_empty(), |p| {
+        let single_line = self.body.is_empty() && p.has_only_inline_comments_at_end(self.span);
+        p.print_curly_braces(self.span, single_line, |p| {
             for item in &self.body {
                 p.print_semicolon_if_needed();
                 p.print_leading_comments(item.span().start);
                 p.print_indent();
                 item.print(p, ctx);
             }
+            p.print_comments_at_end(self.span);
         });
     }
 }
@@ -2311,23 +2375,25 @@ impl Gen for ClassElement<'_> {
         match self {
             Self::StaticBlock(elem) => {
                 elem.print(p, ctx);
+                p.print_trailing_comments(elem.span.end);
                 p.print_soft_newline();
             }
             Self::MethodDefinition(elem) => {
                 elem.print(p, ctx);
+                p.print_trailing_comments(elem.span.end);
                 p.print_soft_newline();
             }
             Self::PropertyDefinition(elem) => {
                 elem.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_statement(elem.span.end);
             }
             Self::AccessorProperty(elem) => {
                 elem.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_statement(elem.span.end);
             }
             Self::TSIndexSignature(elem) => {
                 elem.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_statement(elem.span.end);
             }
         }
     }
@@ -2400,6 +2466,7 @@ impl Gen for JSXAttribute<'_> {
 impl Gen for JSXEmptyExpression {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_comments_at(self.span.end);
+        p.print_next_indent_as_space = false;
     }
 }
 
@@ -2416,6 +2483,9 @@ impl Gen for JSXExpressionContainer<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         p.print_ascii_byte(b'{');
         self.expression.print(p, ctx);
+        if !matches!(self.expression, JSXExpression::EmptyExpression(_)) {
+            p.print_comments_before_closing_bracket(self.span.end, false);
+        }
         p.print_ascii_byte(b'}');
     }
 }
@@ -2470,6 +2540,7 @@ impl Gen for JSXElement<'_> {
                     p.print_soft_space();
                 }
             }
+            p.print_inline_comments_at(attr.span().start);
             attr.print(p, ctx);
         }
         if self.closing_element.is_none() {
@@ -3217,14 +3288,17 @@ impl Gen for TSTemplateLiteralType<'_> {
 
 impl Gen for TSTypeLiteral<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
-        p.print_curly_braces(self.span, self.members.is_empty(), |p| {
+        let single_line = self.members.is_empty() && p.has_only_inline_comments_at_end(self.span);
+        p.print_curly_braces(self.span, single_line, |p| {
             for item in &self.members {
                 p.print_leading_comments(item.span().start);
                 p.print_indent();
                 item.print(p, ctx);
                 p.print_semicolon();
+                p.print_trailing_comments(item.span().end);
                 p.print_soft_newline();
             }
+            p.print_comments_at_end(self.span);
         });
     }
 }
@@ -3628,14 +3702,18 @@ impl Gen for TSInterfaceDeclaration<'_> {
             p.print_list(&self.extends, ctx);
         }
         p.print_soft_space();
-        p.print_curly_braces(self.body.span, self.body.body.is_empty(), |p| {
+        let single_line =
+            self.body.body.is_empty() && p.has_only_inline_comments_at_end(self.body.span);
+        p.print_curly_braces(self.body.span, single_line, |p| {
             for item in &self.body.body {
                 p.print_leading_comments(item.span().start);
                 p.print_indent();
                 item.print(p, ctx);
                 p.print_semicolon();
+                p.print_trailing_comments(item.span().end);
                 p.print_soft_newline();
             }
+            p.print_comments_at_end(self.body.span);
         });
     }
 }
@@ -3668,7 +3746,8 @@ impl Gen for TSEnumDeclaration<'_> {
 
 impl Gen for TSEnumBody<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
-        p.print_curly_braces(self.span, self.members.is_empty(), |p| {
+        let single_line = self.members.is_empty() && p.has_only_inline_comments_at_end(self.span);
+        p.print_curly_braces(self.span, single_line, |p| {
             for (index, member) in self.members.iter().enumerate() {
                 p.print_leading_comments(member.span().start);
                 p.print_indent();
@@ -3676,8 +3755,10 @@ impl Gen for TSEnumBody<'_> {
                 if index != self.members.len() - 1 {
                     p.print_comma();
                 }
+                p.print_trailing_comments(member.span().end);
                 p.print_soft_newline();
             }
+            p.print_comments_at_end(self.span);
         });
     }
 }
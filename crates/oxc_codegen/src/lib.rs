@@ -20,6 +20,8 @@ mod str;
 
 use std::borrow::Cow;
 
+use rustc_hash::FxHashMap;
+
 use oxc_ast::ast::*;
 use oxc_data_structures::{code_buffer::CodeBuffer, stack::Stack};
 use oxc_semantic::Scoping;
@@ -105,6 +107,9 @@ pub struct Codegen<'a> {
     start_of_stmt: usize,
     start_of_arrow_expr: usize,
     start_of_default_export: usize,
+    /// Start of an expression which must not be preceded by a line terminator,
+    /// e.g. the argument of `return`.
+    start_of_no_line_terminator: usize,
 
     /// Track the current indentation level
     indent: u32,
@@ -114,6 +119,9 @@ pub struct Codegen<'a> {
 
     // Builders
     comments: CommentsMap,
+    /// Start of the token inside parentheses to the start of the parentheses with comments,
+    /// for printing the comments when the parentheses are not in the AST.
+    parenthesized_starts: FxHashMap<u32, Vec<u32>>,
 
     sourcemap_builder: Option<SourcemapBuilder<'a>>,
 }
@@ -159,10 +167,12 @@ impl<'a> Codegen<'a> {
             start_of_stmt: 0,
             start_of_arrow_expr: 0,
             start_of_default_export: 0,
+            start_of_no_line_terminator: usize::MAX,
             is_jsx: false,
             indent: 0,
             quote: Quote::Double,
             comments: CommentsMap::default(),
+            parenthesized_starts: FxHashMap::default(),
             sourcemap_builder: None,
         }
     }
@@ -194,6 +204,7 @@ impl<'a> Codegen<'a> {
     /// Print a [`Program`] into a string of source code.
     ///
     /// A source map will be generated if [`CodegenOptions::source_map_path`] is set.
+    /// It is composed with [`CodegenOptions::input_source_map`] if provided.
     #[must_use]
     pub fn build(mut self, program: &Program<'a>) -> CodegenReturn {
         self.quote = if self.options.single_quote { Quote::Single } else { Quote::Double };
@@ -206,7 +217,13 @@ impl<'a> Codegen<'a> {
         program.print(&mut self, Context::default());
         let legal_comments = self.handle_eof_linked_or_external_comments(program);
         let code = self.code.into_string();
-        let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
+        let map = self.sourcemap_builder.map(|builder| {
+            let map = builder.into_sourcemap();
+            match &self.options.input_source_map {
+                Some(input_source_map) => sourcemap_builder::compose(input_source_map, &map),
+                None => map,
+            }
+        });
         CodegenReturn { code, map, legal_comments }
     }
 
@@ -470,12 +487,16 @@ impl<'a> Codegen<'a> {
         self.code.print_indent(self.indent as usize);
     }
 
+    /// Print the semicolon after a statement ending at `end`, followed by the comments on the same line.
     #[inline]
-    fn print_semicolon_after_statement(&mut self) {
+    fn print_semicolon_after_statement(&mut self, end: u32) {
         if self.options.minify {
             self.needs_semicolon = true;
+            self.print_trailing_comments(end);
         } else {
-            self.print_str(";\n");
+            self.print_semicolon();
+            self.print_trailing_comments(end);
+            self.print_hard_newline();
         }
     }
 
@@ -537,6 +558,7 @@ impl<'a> Codegen<'a> {
             Statement::BlockStatement(stmt) => {
                 self.print_soft_space();
                 self.print_block_statement(stmt, ctx);
+                self.print_trailing_comments(stmt.span.end);
                 self.print_soft_newline();
             }
             Statement::EmptyStatement(_) => {
@@ -554,11 +576,13 @@ impl<'a> Codegen<'a> {
     }
 
     fn print_block_statement(&mut self, stmt: &BlockStatement<'_>, ctx: Context) {
-        self.print_curly_braces(stmt.span, stmt.body.is_empty(), |p| {
+        let single_line = stmt.body.is_empty() && self.has_only_inline_comments_at_end(stmt.span);
+        self.print_curly_braces(stmt.span, single_line, |p| {
             for stmt in &stmt.body {
                 p.print_semicolon_if_needed();
                 stmt.print(p, ctx);
             }
+            p.print_comments_at_end(stmt.span);
         });
         self.needs_semicolon = false;
     }
@@ -586,7 +610,7 @@ impl<'a> Codegen<'a> {
                     self.print_ascii_byte(b'(');
                     s.print_expr(self, Precedence::Lowest, ctx);
                     self.print_ascii_byte(b')');
-                    self.print_semicolon_after_statement();
+                    self.print_semicolon_after_statement(s.span().end);
                 }
             }
         }
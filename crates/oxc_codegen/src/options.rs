@@ -15,7 +15,9 @@ pub struct CodegenOptions {
 
     /// Print comments?
     ///
-    /// At present, only some leading comments are preserved.
+    /// Leading and trailing comments are preserved, including comments within expressions,
+    /// object and array literals, parameters and JSX.
+    /// Comments are printed next to the token they precede in the source.
     ///
     /// Default is [CommentOptions::default].
     pub comments: CommentOptions,
@@ -26,6 +28,15 @@ pub struct CodegenOptions {
     ///
     /// Default is `None` - no sourcemap is produced.
     pub source_map_path: Option<PathBuf>,
+
+    /// Source map of the input source text, e.g. produced by a previous compilation step.
+    ///
+    /// When set, the generated source map is composed with it,
+    /// so it maps the output back to the original sources of the input.
+    /// Only used when [`CodegenOptions::source_map_path`] is set.
+    ///
+    /// Default is `None`.
+    pub input_source_map: Option<oxc_sourcemap::SourceMap>,
 }
 
 impl CodegenOptions {
@@ -36,6 +47,7 @@ impl CodegenOptions {
             minify: true,
             comments: CommentOptions::disabled(),
             source_map_path: None,
+            input_source_map: None,
         }
     }
 
@@ -65,8 +77,6 @@ impl CodegenOptions {
 pub struct CommentOptions {
     /// Print normal comments that do not have special meanings.
     ///
-    /// At present only statement level comments are printed.
-    ///
     /// Default is `true`.
     pub normal: bool,
 
@@ -1,9 +1,10 @@
-use std::path::Path;
+use std::{path::Path, sync::Arc};
 
 use nonmax::NonMaxU32;
 use oxc_index::{Idx, IndexVec};
 use oxc_span::Span;
-use oxc_syntax::identifier::{LS, PS};
+use oxc_syntax::identifier::{LS, PS, is_identifier_name};
+use rustc_hash::FxHashMap;
 
 // Irregular line breaks - '\u{2028}' (LS) and '\u{2029}' (PS)
 const LS_OR_PS_FIRST: u8 = 0xE2;
@@ -64,12 +65,23 @@ pub struct ColumnOffsets {
     columns: Box<[u32]>,
 }
 
+#[derive(Debug, Clone, Copy)]
+struct PendingToken {
+    generated_line: u32,
+    generated_column: u32,
+    original_line: u32,
+    original_column: u32,
+    name_id: Option<u32>,
+}
+
 #[expect(clippy::struct_field_names)]
 pub struct SourcemapBuilder<'a> {
     source_id: u32,
     original_source: &'a str,
     last_generated_update: usize,
     last_position: Option<u32>,
+    /// The last token, which is not added to `sourcemap_builder` yet so a name can still be attached to it.
+    last_token: Option<PendingToken>,
     line_offset_tables: LineOffsetTables,
     sourcemap_builder: oxc_sourcemap::SourceMapBuilder,
     generated_line: u32,
@@ -91,6 +103,7 @@ impl<'a> SourcemapBuilder<'a> {
             original_source: source_text,
             last_generated_update: 0,
             last_position: None,
+            last_token: None,
             line_offset_tables,
             sourcemap_builder,
             generated_line: 0,
@@ -99,7 +112,8 @@ impl<'a> SourcemapBuilder<'a> {
         }
     }
 
-    pub fn into_sourcemap(self) -> oxc_sourcemap::SourceMap {
+    pub fn into_sourcemap(mut self) -> oxc_sourcemap::SourceMap {
+        self.flush_last_token();
         self.sourcemap_builder.into_sourcemap()
     }
 
@@ -111,7 +125,11 @@ impl<'a> SourcemapBuilder<'a> {
             span.end,
             self.original_source.len()
         );
-        let original_name = self.original_source.get(span.start as usize..span.end as usize);
+        // Spans of nodes created by transforms may not cover an identifier.
+        let original_name = self
+            .original_source
+            .get(span.start as usize..span.end as usize)
+            .filter(|original_name| is_identifier_name(original_name));
         // The token name should be original name.
         // If it hasn't change, name should be `None` to reduce `SourceMap` size.
         let token_name = if original_name == Some(name) { None } else { original_name };
@@ -120,20 +138,54 @@ impl<'a> SourcemapBuilder<'a> {
 
     pub fn add_source_mapping(&mut self, output: &[u8], position: u32, name: Option<&str>) {
         if self.last_position == Some(position) {
+            // An identifier can share its position with the node printed before it,
+            // e.g. the mangled `b` printed for the shorthand property `{ a }` as `{ a: b }`.
+            // Keep its name, so that every renamed identifier is recorded.
+            let Some(name) = name else { return };
+            self.update_generated_line_and_column(output);
+            let name_id = self.sourcemap_builder.add_name(name);
+            if let Some(token) = &mut self.last_token {
+                if token.name_id.is_none()
+                    && token.generated_line == self.generated_line
+                    && token.generated_column == self.generated_column
+                {
+                    token.name_id = Some(name_id);
+                    return;
+                }
+            }
+            let (original_line, original_column) = self.search_original_line_and_column(position);
+            self.add_token(original_line, original_column, Some(name_id));
             return;
         }
         let (original_line, original_column) = self.search_original_line_and_column(position);
         self.update_generated_line_and_column(output);
         let name_id = name.map(|s| self.sourcemap_builder.add_name(s));
-        self.sourcemap_builder.add_token(
-            self.generated_line,
-            self.generated_column,
+        self.add_token(original_line, original_column, name_id);
+        self.last_position = Some(position);
+    }
+
+    fn add_token(&mut self, original_line: u32, original_column: u32, name_id: Option<u32>) {
+        self.flush_last_token();
+        self.last_token = Some(PendingToken {
+            generated_line: self.generated_line,
+            generated_column: self.generated_column,
             original_line,
             original_column,
-            Some(self.source_id),
             name_id,
-        );
-        self.last_position = Some(position);
+        });
+    }
+
+    fn flush_last_token(&mut self) {
+        if let Some(token) = self.last_token.take() {
+            self.sourcemap_builder.add_token(
+                token.generated_line,
+                token.generated_column,
+                token.original_line,
+                token.original_column,
+                Some(self.source_id),
+                token.name_id,
+            );
+        }
     }
 
     #[expect(clippy::cast_possible_truncation)]
@@ -429,6 +481,67 @@ impl<'a> SourcemapBuilder<'a> {
     }
 }
 
+/// Compose `generated`, which maps output to input source text, with `input`, which maps input
+/// source text to its original sources.
+///
+/// The result maps output directly to the original sources of `input`.
+/// Tokens which do not map to anywhere in the original sources are dropped.
+///
+/// Based on [@ampproject/remapping](https://github.com/ampproject/remapping).
+pub fn compose(
+    input: &oxc_sourcemap::SourceMap,
+    generated: &oxc_sourcemap::SourceMap,
+) -> oxc_sourcemap::SourceMap {
+    let lookup_table = input.generate_lookup_table();
+
+    let mut names: Vec<Arc<str>> = vec![];
+    let mut names_map = FxHashMap::<&str, u32>::default();
+    let mut tokens = vec![];
+    for token in generated.get_tokens() {
+        let Some(original) =
+            input.lookup_token(&lookup_table, token.get_src_line(), token.get_src_col())
+        else {
+            continue;
+        };
+        let Some(source_id) = original.get_source_id() else { continue };
+        // Prefer name from the original sources, as names in `generated` refer to the input source text.
+        let name = original
+            .get_name_id()
+            .and_then(|id| input.get_name(id))
+            .or_else(|| token.get_name_id().and_then(|id| generated.get_name(id)));
+        let name_id = name.map(|name| {
+            #[expect(clippy::cast_possible_truncation)]
+            let next_id = names.len() as u32;
+            *names_map.entry(name).or_insert_with(|| {
+                names.push(Arc::from(name));
+                next_id
+            })
+        });
+        tokens.push(oxc_sourcemap::Token::new(
+            token.get_dst_line(),
+            token.get_dst_col(),
+            original.get_src_line(),
+            original.get_src_col(),
+            Some(source_id),
+            name_id,
+        ));
+    }
+
+    let mut map = oxc_sourcemap::SourceMap::new(
+        generated.get_file().map(Arc::from),
+        names,
+        input.get_source_root().map(String::from),
+        input.get_sources().map(Arc::from).collect(),
+        input.get_source_contents().map(|content| content.map(Arc::clone)).collect(),
+        tokens,
+        None,
+    );
+    if let Some(ignore_list) = input.get_x_google_ignore_list() {
+        map.set_x_google_ignore_list(ignore_list.to_vec());
+    }
+    map
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -551,6 +664,21 @@ mod test {
         );
     }
 
+    #[test]
+    fn add_source_mapping_for_name_at_same_position() {
+        // `{ a }` printed as `{a:b}`, where the key and the value share the same position.
+        let output = b"{a:b";
+        let mut builder = SourcemapBuilder::new(Path::new("x.js"), "a");
+        builder.add_source_mapping(&output[..1], 0, None);
+        builder.add_source_mapping_for_name(&output[..3], Span::new(0, 1), "b");
+        let sm = builder.into_sourcemap();
+        assert_eq!(sm.get_tokens().count(), 2);
+        assert_eq!(
+            sm.get_source_view_token(1_u32).as_ref().and_then(|token| token.get_name()),
+            Some("a")
+        );
+    }
+
     #[test]
     fn add_source_mapping_for_unordered_position() {
         let output = b"";
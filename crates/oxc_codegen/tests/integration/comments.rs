@@ -479,6 +479,98 @@ delete /* @__PURE__ */ (() => {})();",
     }
 }
 
+pub mod normal {
+    use crate::{
+        snapshot,
+        tester::{test, test_minify, test_tsx},
+    };
+
+    #[test]
+    fn comment() {
+        let cases = vec![
+            "const a = 1; // trailing\nconst b = 2; /* trailing */\n",
+            "x = /* assign */ 1;",
+            "const c = cond /* c1 */ ? yes /* c2 */ : no;",
+            "const c = cond\n  // why\n  ? yes\n  : no;",
+            "const d = a /* op */ + /* right */ b;",
+            "const e = a.b /* member */ .c;",
+            "promise\n  .then(f) // then\n  .catch(g); // catch",
+            "const o = { // first\n  x: 1, // trailing x\n  /* before y */ y: 2,\n  // before z\n  z: 3, // trailing z\n  // last\n};",
+            "const o = { x /* after x */ };",
+            "const arr = [1, /* in array */ 2, 3, // trailing 3\n];",
+            "function f(/* p */ a, b /* q */, /* r */ ...c) {\n  return /* ret */ a; // done\n  // end of body\n}",
+            "if (a) { // after if\n  b();\n} else { /* else */ } // after else",
+            "if (a) {\n  b(); // after b\n}",
+            "switch (a) {\n  // first case\n  case 1: break; // after break\n  // no default\n}",
+            "class C { /* in class */ m() {} // after m\n  // end of class\n}",
+            "try { a(); } catch { /* ignore */ } // after try",
+        ];
+
+        snapshot("normal_comments", &cases);
+    }
+
+    #[test]
+    fn jsx() {
+        test_tsx(
+            "<div /* attr */ a=\"1\">{/* child */}<span>{x /* after x */}</span></div>;",
+            "<div /* attr */ a=\"1\">{/* child */}<span>{x /* after x */}</span></div>;\n",
+        );
+    }
+
+    #[test]
+    fn no_line_terminator() {
+        test("function f() { return ( // c\n a ); }", "function f() {\n\treturn /* c */ a;\n}\n");
+        test("function f() { throw ( // c\n a ); }", "function f() {\n\tthrow /* c */ a;\n}\n");
+        test("function* f() { yield ( // c\n a ); }", "function* f() {\n\tyield /* c */ a;\n}\n");
+        test("() => ( // c\n a );", "() => /* c */ a;\n");
+        test(
+            "function f() { return ( // c\n a ).b; }",
+            "function f() {\n\treturn /* c */ a.b;\n}\n",
+        );
+        test(
+            "function f() { return ( /* multi\n line */ a ); }",
+            "function f() {\n\treturn /* multi line */ a;\n}\n",
+        );
+        test(
+            "function f() { return ( // */\n a ); }",
+            "function f() {\n\treturn /* * / */ a;\n}\n",
+        );
+    }
+
+    #[test]
+    fn positions() {
+        test("for (/* a */ x of y) {}", "for (/* a */ x of y) {}\n");
+        test("for (const /* a */ x of y) {}", "for (const /* a */ x of y) {}\n");
+        test("for (let x /* a */ in y) {}", "for (let x /* a */ in y) {}\n");
+        test("let /* a */ x = 1, /* b */ y;", "let /* a */ x = 1, /* b */ y;\n");
+        test("`a${ /* b */ x }c${ y /* d */ }e`;", "`a${/* b */ x}c${y /* d */}e`;\n");
+        test("export default /* a */ function() {}", "export default /* a */ function() {}\n");
+        test("export default /* a */ class {}", "export default /* a */ class {}\n");
+        test("x = { get /* a */ y() {} };", "x = { get /* a */ y() {} };\n");
+        test("class A { static /* a */ m() {} }", "class A {\n\tstatic /* a */ m() {}\n}\n");
+        test("a?./* b */ c;", "a?./* b */ c;\n");
+        test(
+            "class A { #c; m() { a./* b */ #c } }",
+            "class A {\n\t#c;\n\tm() {\n\t\ta./* b */ #c;\n\t}\n}\n",
+        );
+    }
+
+    #[test]
+    fn end_of_block() {
+        test("if (a) { b(); /* end */ }", "if (a) {\n\tb();\n\t/* end */\n}\n");
+        test("{ b(); /* end */ }", "{\n\tb();\n\t/* end */\n}\n");
+        test("function f() { b(); /* end */ }", "function f() {\n\tb();\n\t/* end */\n}\n");
+        test("if (a) { /* end */ }", "if (a) {/* end */}\n");
+    }
+
+    #[test]
+    fn minify() {
+        test_minify("a(); // trailing\nb();", "a();// trailing\nb();");
+        test_minify("a() /* c */ + b;", "a() /* c */+b;");
+        test_minify("const o = { x: 1, // x\n y: 2 };", "const o={x:1,// x\ny:2};");
+    }
+}
+
 pub mod options {
     use oxc_codegen::{CodegenOptions, CommentOptions, LegalComment};
 
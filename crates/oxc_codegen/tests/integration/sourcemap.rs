@@ -1,6 +1,6 @@
 use oxc_allocator::Allocator;
 use oxc_ast::ast::{Expression, Statement};
-use oxc_codegen::Codegen;
+use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_parser::Parser;
 use oxc_span::{SourceType, Span};
 
@@ -27,3 +27,50 @@ fn incorrect_ast() {
     let ret = Codegen::new().with_options(default_options()).build(&program);
     assert!(ret.map.is_some(), "sourcemap exists");
 }
+
+#[test]
+fn input_source_map() {
+    let allocator = Allocator::default();
+    let source_type = SourceType::mjs();
+
+    // First step, e.g. a framework compiler: `original.js` -> `intermediate.js`.
+    let original = "\n\n  let   foo =\n    1;\n\n  console.log(  foo  );\n";
+    let ret = Parser::new(&allocator, original, source_type).parse();
+    let first = Codegen::new()
+        .with_options(CodegenOptions {
+            source_map_path: Some("original.js".into()),
+            ..CodegenOptions::default()
+        })
+        .build(&ret.program);
+    let input_source_map = first.map.unwrap();
+
+    // Second step: `intermediate.js` -> output, composed with the source map of the first step.
+    let ret = Parser::new(&allocator, &first.code, source_type).parse();
+    let second = Codegen::new()
+        .with_options(CodegenOptions {
+            minify: true,
+            source_map_path: Some("intermediate.js".into()),
+            input_source_map: Some(input_source_map),
+            ..CodegenOptions::default()
+        })
+        .build(&ret.program);
+    let map = second.map.unwrap();
+
+    assert_eq!(map.get_sources().collect::<Vec<_>>(), ["original.js"]);
+    assert_eq!(map.get_source_content(0).map(AsRef::as_ref), Some(original));
+
+    // `console` in the output maps back to line 5, column 2 of `original.js`.
+    let console_col = second.code.find("console").unwrap();
+    let token = map
+        .get_tokens()
+        .find(|token| token.get_dst_line() == 0 && token.get_dst_col() as usize == console_col)
+        .unwrap();
+    assert_eq!((token.get_src_line(), token.get_src_col()), (5, 2));
+    // `foo` in the output maps back to line 2, column 8 of `original.js`.
+    let foo_col = second.code.find("foo").unwrap();
+    let token = map
+        .get_tokens()
+        .find(|token| token.get_dst_line() == 0 && token.get_dst_col() as usize == foo_col)
+        .unwrap();
+    assert_eq!((token.get_src_line(), token.get_src_col()), (2, 8));
+}
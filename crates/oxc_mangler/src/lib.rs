@@ -3,7 +3,7 @@ use std::iter::{self, repeat_with};
 use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use keep_names::collect_name_symbols;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use base54::base54;
 use oxc_allocator::{Allocator, Vec};
@@ -15,10 +15,16 @@ use oxc_span::Atom;
 
 pub(crate) mod base54;
 mod keep_names;
+mod mangle_props;
+mod name_cache;
+mod rename_map;
 
 pub use keep_names::MangleOptionsKeepNames;
+pub use mangle_props::{MangleOptionsProps, PropertyMangler};
+pub use name_cache::NameCache;
+pub use rename_map::{RenameMap, RenamedSymbol, RenamedSymbolScope};
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone)]
 pub struct MangleOptions {
     /// Pass true to mangle names declared in the top level scope.
     ///
@@ -33,6 +39,11 @@ pub struct MangleOptions {
     ///
     /// Uses base54 if false.
     pub debug: bool,
+
+    /// Mangle property names. See [`PropertyMangler`].
+    ///
+    /// Default: `None`
+    pub props: Option<MangleOptionsProps>,
 }
 
 type Slot = usize;
@@ -175,8 +186,16 @@ impl TempAllocator<'_> {
 /// - slot 1: `top_level_b`, `foo_a`, `bar_a`
 /// - slot 2: `foo`
 /// - slot 3: `bar`
+///
+/// ### Name Cache
+///
+/// When mangling the top level scope, a [`NameCache`] from a previous build can be passed with
+/// [`Mangler::with_name_cache`]. Top level symbols found in the cache keep their previously
+/// mangled name, unless it would clash with a name that is not mangled (e.g. an export or a global).
+/// These names are excluded from the generated names, so no other symbol can shadow them.
 pub struct Mangler<'t> {
     options: MangleOptions,
+    name_cache: NameCache,
     /// An allocator meant to be used for temporary allocations during mangling.
     /// It can be cleared after mangling is done, to free up memory for subsequent
     /// files or other operations.
@@ -187,6 +206,7 @@ impl Default for Mangler<'_> {
     fn default() -> Self {
         Self {
             options: MangleOptions::default(),
+            name_cache: NameCache::default(),
             temp_allocator: TempAllocator::Owned(Allocator::default()),
         }
     }
@@ -245,6 +265,7 @@ impl<'t> Mangler<'t> {
     pub fn new_with_temp_allocator(temp_allocator: &'t Allocator) -> Self {
         Self {
             options: MangleOptions::default(),
+            name_cache: NameCache::default(),
             temp_allocator: TempAllocator::Borrowed(temp_allocator),
         }
     }
@@ -255,6 +276,15 @@ impl<'t> Mangler<'t> {
         self
     }
 
+    /// Reuse the mangled top level names of a previous build.
+    ///
+    /// Only used when [`MangleOptions::top_level`] is enabled.
+    #[must_use]
+    pub fn with_name_cache(mut self, name_cache: NameCache) -> Self {
+        self.name_cache = name_cache;
+        self
+    }
+
     /// Mangles the program. The resulting SymbolTable contains the mangled symbols - `program` is not modified.
     /// Pass the symbol table to oxc_codegen to generate the mangled code.
     #[must_use]
@@ -265,30 +295,39 @@ impl<'t> Mangler<'t> {
         semantic.into_scoping()
     }
 
+    /// Mangles the symbols in `semantic`.
+    ///
+    /// Returns the name cache, including the top level names mangled in this build.
+    ///
     /// # Panics
     ///
     /// Panics if the child_ids does not exist in scope_tree.
-    pub fn build_with_semantic(self, semantic: &mut Semantic<'_>, program: &Program<'_>) {
+    pub fn build_with_semantic(
+        self,
+        semantic: &mut Semantic<'_>,
+        program: &Program<'_>,
+    ) -> NameCache {
         if self.options.debug {
-            self.build_with_semantic_impl(semantic, program, debug_name);
+            self.build_with_semantic_impl(semantic, program, debug_name)
         } else {
-            self.build_with_semantic_impl(semantic, program, base54);
+            self.build_with_semantic_impl(semantic, program, base54)
         }
     }
 
     fn build_with_semantic_impl<const CAPACITY: usize, G: Fn(u32) -> InlineString<CAPACITY, u8>>(
-        self,
+        mut self,
         semantic: &mut Semantic<'_>,
         program: &Program<'_>,
         generate_name: G,
-    ) {
+    ) -> NameCache {
+        let mut name_cache = std::mem::take(&mut self.name_cache);
         let (scoping, ast_nodes) = semantic.scoping_mut_and_nodes();
 
         assert!(scoping.has_scope_child_ids(), "child_id needs to be generated");
 
         // TODO: implement opt-out of direct-eval in a branch of scopes.
         if scoping.root_scope_flags().contains_direct_eval() {
-            return;
+            return name_cache;
         }
 
         let (exported_names, exported_symbols) = if self.options.top_level {
@@ -299,6 +338,36 @@ impl<'t> Mangler<'t> {
         let (keep_name_names, keep_name_symbols) =
             Mangler::collect_keep_name_symbols(self.options.keep_names, scoping, ast_nodes);
 
+        // Top level symbols which are mangled, with their original names.
+        let top_level_symbols: std::vec::Vec<(SymbolId, String)> = if self.options.top_level {
+            scoping
+                .get_bindings(scoping.root_scope_id())
+                .iter()
+                .filter(|(name, symbol_id)| {
+                    !exported_symbols.contains(symbol_id)
+                        && !keep_name_symbols.contains(symbol_id)
+                        && !is_special_name(name)
+                })
+                .map(|(name, symbol_id)| (*symbol_id, (*name).to_string()))
+                .collect()
+        } else {
+            vec![]
+        };
+        // Names reserved by the name cache. Generated names must not use them,
+        // so that symbols in a nested scope do not shadow the cached top level symbols.
+        let cached_names: FxHashSet<&str> = if self.options.top_level {
+            name_cache.vars.values().map(String::as_str).collect()
+        } else {
+            FxHashSet::default()
+        };
+        let cached_symbols = Self::collect_cached_symbols(
+            &top_level_symbols,
+            &name_cache,
+            scoping,
+            &exported_names,
+            &keep_name_names,
+        );
+
         let temp_allocator = self.temp_allocator.as_ref();
 
         // All symbols with their assigned slots. Keyed by symbol id.
@@ -319,9 +388,9 @@ impl<'t> Mangler<'t> {
 
             // Sort `bindings` in declaration order.
             tmp_bindings.clear();
-            tmp_bindings.extend(
-                bindings.values().copied().filter(|binding| !keep_name_symbols.contains(binding)),
-            );
+            tmp_bindings.extend(bindings.values().copied().filter(|binding| {
+                !keep_name_symbols.contains(binding) && !cached_symbols.contains_key(binding)
+            }));
             tmp_bindings.sort_unstable();
             if tmp_bindings.is_empty() {
                 continue;
@@ -389,6 +458,7 @@ impl<'t> Mangler<'t> {
             scoping,
             &exported_symbols,
             &keep_name_symbols,
+            &cached_symbols,
             total_number_of_slots,
             &slots,
         );
@@ -412,6 +482,7 @@ impl<'t> Mangler<'t> {
                         && (!self.options.top_level || exported_names.contains(n)))
                         // TODO: only skip the names that are kept in the current scope
                         && !keep_name_names.contains(n)
+                        && !cached_names.contains(n)
                 {
                     break name;
                 }
@@ -468,6 +539,43 @@ impl<'t> Mangler<'t> {
                 }
             }
         }
+
+        for (&symbol_id, cached_name) in &cached_symbols {
+            scoping.set_symbol_name(symbol_id, cached_name);
+        }
+        for (symbol_id, name) in top_level_symbols {
+            name_cache.vars.insert(name, scoping.symbol_name(symbol_id).to_string());
+        }
+        name_cache
+    }
+
+    /// Top level symbols which can reuse their name from the name cache.
+    ///
+    /// A cached name is not reused if it clashes with a name which is not mangled,
+    /// or if it was already taken by another symbol.
+    fn collect_cached_symbols(
+        top_level_symbols: &[(SymbolId, String)],
+        name_cache: &NameCache,
+        scoping: &Scoping,
+        exported_names: &FxHashSet<Atom<'_>>,
+        keep_name_names: &FxHashSet<&str>,
+    ) -> FxHashMap<SymbolId, String> {
+        let root_unresolved_references = scoping.root_unresolved_references();
+        let mut used_names = FxHashSet::default();
+        top_level_symbols
+            .iter()
+            .filter_map(|(symbol_id, name)| {
+                let cached_name = name_cache.vars.get(name)?;
+                let n = cached_name.as_str();
+                let is_valid = !is_keyword(n)
+                    && !is_special_name(n)
+                    && !root_unresolved_references.contains_key(n)
+                    && !exported_names.contains(n)
+                    && !keep_name_names.contains(n)
+                    && used_names.insert(n);
+                is_valid.then(|| (*symbol_id, cached_name.clone()))
+            })
+            .collect()
     }
 
     fn tally_slot_frequencies<'a>(
@@ -475,6 +583,7 @@ impl<'t> Mangler<'t> {
         scoping: &Scoping,
         exported_symbols: &FxHashSet<SymbolId>,
         keep_name_symbols: &FxHashSet<SymbolId>,
+        cached_symbols: &FxHashMap<SymbolId, String>,
         total_number_of_slots: usize,
         slots: &[Slot],
     ) -> Vec<'a, SlotFrequency<'a>> {
@@ -495,7 +604,7 @@ impl<'t> Mangler<'t> {
             if is_special_name(scoping.symbol_name(symbol_id)) {
                 continue;
             }
-            if keep_name_symbols.contains(&symbol_id) {
+            if keep_name_symbols.contains(&symbol_id) || cached_symbols.contains_key(&symbol_id) {
                 continue;
             }
             let index = slot;
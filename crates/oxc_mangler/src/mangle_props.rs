@@ -0,0 +1,352 @@
+use std::cmp::Reverse;
+
+use lazy_regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{Allocator, FromIn, TakeIn};
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_span::Atom;
+
+use crate::{NameCache, base54::base54};
+
+/// Property names which have a special meaning, and are never mangled.
+const ALWAYS_RESERVED: [&str; 3] = ["constructor", "prototype", "__proto__"];
+
+#[derive(Debug, Default, Clone)]
+pub struct MangleOptionsProps {
+    /// Only mangle property names matching this pattern, e.g. `^_`.
+    ///
+    /// When `None`, no public property names are mangled,
+    /// because there is no way to know which properties are accessed outside of the program.
+    pub regex: Option<Regex>,
+
+    /// Never mangle property names matching this pattern.
+    pub reserved_regex: Option<Regex>,
+
+    /// Never mangle these property names.
+    pub reserved: Vec<String>,
+
+    /// Only mangle unquoted property names.
+    ///
+    /// A name which is quoted anywhere (`obj["_foo"]` or `{ "_foo": 1 }`) is not mangled at all.
+    ///
+    /// Default: `false`
+    pub keep_quoted: bool,
+
+    /// Mangle names of private class members (`#foo`).
+    ///
+    /// Default: `false`
+    pub private: bool,
+}
+
+impl MangleOptionsProps {
+    fn is_mangleable(&self, name: &str, quoted: bool) -> bool {
+        if !self.regex.as_ref().is_some_and(|regex| regex.is_match(name)) {
+            return false;
+        }
+        if quoted && self.keep_quoted {
+            return false;
+        }
+        !self.is_reserved(name)
+    }
+
+    fn is_reserved(&self, name: &str) -> bool {
+        ALWAYS_RESERVED.contains(&name)
+            || self.reserved.iter().any(|reserved| reserved == name)
+            || self.reserved_regex.as_ref().is_some_and(|regex| regex.is_match(name))
+    }
+}
+
+/// # Property Mangler
+///
+/// Shortens property names matching [`MangleOptionsProps::regex`], and names of private class members.
+///
+/// Unlike [`crate::Mangler`], this modifies `program`, because property names are not symbols.
+/// It should run before the symbols are mangled.
+///
+/// ## Example
+///
+/// ```rust
+/// use oxc_allocator::Allocator;
+/// use oxc_codegen::Codegen;
+/// use oxc_mangler::{MangleOptionsProps, PropertyMangler};
+/// use oxc_parser::Parser;
+/// use oxc_span::SourceType;
+///
+/// let allocator = Allocator::default();
+/// let source = "x._foo = x._bar + x._foo;";
+/// let mut program = Parser::new(&allocator, source, SourceType::mjs()).parse().program;
+///
+/// let options = MangleOptionsProps {
+///     regex: Some(lazy_regex::regex!("^_").clone()),
+///     ..MangleOptionsProps::default()
+/// };
+/// let name_cache = PropertyMangler::new(options).build(&allocator, &mut program);
+///
+/// let js = Codegen::new().build(&program);
+/// assert_eq!(js.code, "x.e = x.t + x.e;\n");
+/// assert_eq!(name_cache.props["_foo"], "e");
+/// ```
+///
+/// ## Implementation
+///
+/// Names are assigned in order of frequency, so that the most frequently used names get the
+/// shortest ones. Names found in the [`NameCache`] are reused, unless they would clash with a
+/// property name which is not mangled.
+/// All property names which are not mangled are excluded from the generated names,
+/// to avoid accidentally accessing a different property.
+#[derive(Debug, Default)]
+pub struct PropertyMangler {
+    options: MangleOptionsProps,
+    name_cache: NameCache,
+}
+
+impl PropertyMangler {
+    pub fn new(options: MangleOptionsProps) -> Self {
+        Self { options, name_cache: NameCache::default() }
+    }
+
+    /// Reuse the mangled names of a previous build.
+    #[must_use]
+    pub fn with_name_cache(mut self, name_cache: NameCache) -> Self {
+        self.name_cache = name_cache;
+        self
+    }
+
+    /// Mangles property names in `program`.
+    ///
+    /// Returns the name cache, including the names assigned in this build.
+    pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) -> NameCache {
+        let mut collector = PropertyCollector::default();
+        collector.visit_program(program);
+
+        let Self { options, mut name_cache } = self;
+        let props = assign_property_names(&options, &collector.props, &mut name_cache, allocator);
+        let private = if options.private {
+            assign_private_names(&collector.private, allocator)
+        } else {
+            FxHashMap::default()
+        };
+
+        if !props.is_empty() || !private.is_empty() {
+            let ast = AstBuilder::new(allocator);
+            PropertyRenamer { ast, props: &props, private: &private }.visit_program(program);
+        }
+
+        name_cache
+    }
+}
+
+fn assign_property_names<'a>(
+    options: &MangleOptionsProps,
+    props: &FxHashMap<Atom<'a>, NameUsage>,
+    name_cache: &mut NameCache,
+    allocator: &'a Allocator,
+) -> FxHashMap<Atom<'a>, Atom<'a>> {
+    let (mut candidates, kept): (Vec<_>, Vec<_>) =
+        props.iter().partition(|(name, usage)| options.is_mangleable(name, usage.quoted));
+    candidates.sort_unstable_by_key(|(_, usage)| (Reverse(usage.count), usage.order));
+    let kept = kept.into_iter().map(|(name, _)| name.as_str()).collect::<FxHashSet<_>>();
+
+    // Names which are not available for new mangled names.
+    let mut used_names = name_cache.props.values().cloned().collect::<FxHashSet<_>>();
+    used_names.extend(options.reserved.iter().cloned());
+
+    let mut assigned = FxHashMap::default();
+    let mut count = 0;
+    for (&name, _) in candidates {
+        let mangled = match name_cache.props.get(name.as_str()) {
+            Some(mangled) if !kept.contains(mangled.as_str()) => mangled.clone(),
+            _ => {
+                let mangled = loop {
+                    let mangled = base54(count);
+                    count += 1;
+                    let mangled = mangled.as_str();
+                    if !used_names.contains(mangled) && !kept.contains(mangled) {
+                        break mangled.to_string();
+                    }
+                };
+                used_names.insert(mangled.clone());
+                name_cache.props.insert(name.to_string(), mangled.clone());
+                mangled
+            }
+        };
+        assigned.insert(name, Atom::from_in(mangled.as_str(), allocator));
+    }
+    assigned
+}
+
+/// Private names are only visible inside of the class body, so they don't clash with
+/// any other names, and don't need to be cached.
+fn assign_private_names<'a>(
+    private: &FxHashMap<Atom<'a>, NameUsage>,
+    allocator: &'a Allocator,
+) -> FxHashMap<Atom<'a>, Atom<'a>> {
+    let mut names = private.iter().collect::<Vec<_>>();
+    names.sort_unstable_by_key(|(_, usage)| (Reverse(usage.count), usage.order));
+    names
+        .into_iter()
+        .zip(0..)
+        .map(|((&name, _), n)| (name, Atom::from_in(base54(n).as_str(), allocator)))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy)]
+struct NameUsage {
+    count: usize,
+    /// Order of first appearance, to assign names deterministically.
+    order: usize,
+    quoted: bool,
+}
+
+#[derive(Default)]
+struct PropertyCollector<'a> {
+    props: FxHashMap<Atom<'a>, NameUsage>,
+    private: FxHashMap<Atom<'a>, NameUsage>,
+}
+
+impl<'a> PropertyCollector<'a> {
+    fn add_prop(&mut self, name: Atom<'a>, quoted: bool) {
+        Self::add(&mut self.props, name, quoted);
+    }
+
+    fn add(names: &mut FxHashMap<Atom<'a>, NameUsage>, name: Atom<'a>, quoted: bool) {
+        let order = names.len();
+        let usage = names.entry(name).or_insert(NameUsage { count: 0, order, quoted: false });
+        usage.count += 1;
+        usage.quoted |= quoted;
+    }
+}
+
+impl<'a> Visit<'a> for PropertyCollector<'a> {
+    fn visit_static_member_expression(&mut self, expr: &StaticMemberExpression<'a>) {
+        self.add_prop(expr.property.name, false);
+        walk::walk_static_member_expression(self, expr);
+    }
+
+    fn visit_computed_member_expression(&mut self, expr: &ComputedMemberExpression<'a>) {
+        if let Expression::StringLiteral(lit) = &expr.expression {
+            self.add_prop(lit.value, true);
+        }
+        walk::walk_computed_member_expression(self, expr);
+    }
+
+    fn visit_property_key(&mut self, key: &PropertyKey<'a>) {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => self.add_prop(ident.name, false),
+            PropertyKey::StringLiteral(lit) => self.add_prop(lit.value, true),
+            _ => {}
+        }
+        walk::walk_property_key(self, key);
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        it: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        self.add_prop(it.binding.name, false);
+        walk::walk_assignment_target_property_identifier(self, it);
+    }
+
+    fn visit_private_identifier(&mut self, ident: &PrivateIdentifier<'a>) {
+        Self::add(&mut self.private, ident.name, false);
+    }
+}
+
+struct PropertyRenamer<'a, 'm> {
+    ast: AstBuilder<'a>,
+    props: &'m FxHashMap<Atom<'a>, Atom<'a>>,
+    private: &'m FxHashMap<Atom<'a>, Atom<'a>>,
+}
+
+impl<'a> PropertyRenamer<'a, '_> {
+    fn rename_string_literal(&self, lit: &mut StringLiteral<'a>) {
+        if let Some(&name) = self.props.get(&lit.value) {
+            lit.value = name;
+            lit.raw = None;
+        }
+    }
+
+    fn is_renamed(&self, key: &PropertyKey<'a>) -> bool {
+        matches!(key, PropertyKey::StaticIdentifier(ident) if self.props.contains_key(&ident.name))
+    }
+}
+
+impl<'a> VisitMut<'a> for PropertyRenamer<'a, '_> {
+    fn visit_static_member_expression(&mut self, expr: &mut StaticMemberExpression<'a>) {
+        if let Some(&name) = self.props.get(&expr.property.name) {
+            expr.property.name = name;
+        }
+        walk_mut::walk_static_member_expression(self, expr);
+    }
+
+    fn visit_computed_member_expression(&mut self, expr: &mut ComputedMemberExpression<'a>) {
+        if let Expression::StringLiteral(lit) = &mut expr.expression {
+            self.rename_string_literal(lit);
+        }
+        walk_mut::walk_computed_member_expression(self, expr);
+    }
+
+    fn visit_property_key(&mut self, key: &mut PropertyKey<'a>) {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                if let Some(&name) = self.props.get(&ident.name) {
+                    ident.name = name;
+                }
+            }
+            PropertyKey::StringLiteral(lit) => self.rename_string_literal(lit),
+            _ => {}
+        }
+        walk_mut::walk_property_key(self, key);
+    }
+
+    // `{ _foo }` -> `{ e: _foo }`
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        if prop.shorthand && self.is_renamed(&prop.key) {
+            prop.shorthand = false;
+        }
+        walk_mut::walk_object_property(self, prop);
+    }
+
+    // `let { _foo } = x` -> `let { e: _foo } = x`
+    fn visit_binding_property(&mut self, prop: &mut BindingProperty<'a>) {
+        if prop.shorthand && self.is_renamed(&prop.key) {
+            prop.shorthand = false;
+        }
+        walk_mut::walk_binding_property(self, prop);
+    }
+
+    // `({ _foo = 1 } = x)` -> `({ e: _foo = 1 } = x)`
+    fn visit_assignment_target_property(&mut self, prop: &mut AssignmentTargetProperty<'a>) {
+        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) = prop
+            && self.props.contains_key(&ident.binding.name)
+        {
+            let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) =
+                prop.take_in(self.ast)
+            else {
+                unreachable!()
+            };
+            let AssignmentTargetPropertyIdentifier { span, binding, init } = ident.unbox();
+            // The key is renamed when walking the new property.
+            let key = self.ast.property_key_static_identifier(binding.span, binding.name);
+            let target = AssignmentTarget::AssignmentTargetIdentifier(self.ast.alloc(binding));
+            let binding = match init {
+                Some(init) => AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(
+                    self.ast.alloc_assignment_target_with_default(span, target, init),
+                ),
+                None => AssignmentTargetMaybeDefault::from(target),
+            };
+            *prop = self.ast.assignment_target_property_assignment_target_property_property(
+                span, key, binding, false,
+            );
+        }
+        walk_mut::walk_assignment_target_property(self, prop);
+    }
+
+    fn visit_private_identifier(&mut self, ident: &mut PrivateIdentifier<'a>) {
+        if let Some(&name) = self.private.get(&ident.name) {
+            ident.name = name;
+        }
+    }
+}
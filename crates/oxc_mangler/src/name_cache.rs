@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Mangled names to be reused across builds.
+///
+/// Passing the cache returned from one build to the next one makes both of them mangle the same
+/// names in the same way, which is required when multiple bundles access the same objects.
+///
+/// The JSON representation is compatible with terser's `nameCache`:
+///
+/// ```json
+/// { "vars": { "props": { "$foo": "a" } }, "props": { "props": { "$_foo": "a" } } }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NameCache {
+    /// Original top level symbol name to mangled symbol name.
+    pub vars: BTreeMap<String, String>,
+
+    /// Original property name to mangled property name.
+    pub props: BTreeMap<String, String>,
+}
+
+impl NameCache {
+    /// Read the cache from terser's `nameCache` JSON format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` is not a valid name cache.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let json: NameCacheJson = serde_json::from_str(json)?;
+        Ok(Self {
+            vars: json.vars.map(CacheJson::into_map).unwrap_or_default(),
+            props: json.props.map(CacheJson::into_map).unwrap_or_default(),
+        })
+    }
+
+    /// Write the cache in terser's `nameCache` JSON format.
+    ///
+    /// # Panics
+    ///
+    /// Never panics, as maps of strings can always be serialized.
+    pub fn to_json(&self) -> String {
+        let json = NameCacheJson {
+            vars: Some(CacheJson::from_map(&self.vars)),
+            props: Some(CacheJson::from_map(&self.props)),
+        };
+        serde_json::to_string(&json).expect("name cache is always serializable")
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vars.is_empty() && self.props.is_empty()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct NameCacheJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    vars: Option<CacheJson>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    props: Option<CacheJson>,
+}
+
+/// terser prefixes every name with `$`, so names like `__proto__` can be used as keys of a JS object.
+#[derive(Serialize, Deserialize)]
+struct CacheJson {
+    #[serde(default)]
+    props: BTreeMap<String, String>,
+}
+
+impl CacheJson {
+    fn from_map(map: &BTreeMap<String, String>) -> Self {
+        Self {
+            props: map
+                .iter()
+                .map(|(name, mangled)| (format!("${name}"), mangled.clone()))
+                .collect(),
+        }
+    }
+
+    fn into_map(self) -> BTreeMap<String, String> {
+        self.props
+            .into_iter()
+            .map(|(name, mangled)| match name.strip_prefix('$') {
+                Some(name) => (name.to_string(), mangled),
+                None => (name, mangled),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,103 @@
+use oxc_ast::AstKind;
+use oxc_semantic::Semantic;
+use oxc_span::{GetSpan, Span};
+use serde::Serialize;
+
+/// Symbols renamed by the mangler.
+///
+/// Used for mapping minified names back to the original ones, e.g. when de-minifying stack traces.
+///
+/// The JSON representation lists the renamed symbols in declaration order:
+///
+/// ```json
+/// { "symbols": [{ "original": "foo", "renamed": "e", "start": 13, "end": 16, "scope": { "name": "bar", "start": 0, "end": 30 } }] }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct RenameMap {
+    pub symbols: Vec<RenamedSymbol>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RenamedSymbol {
+    /// Name in the original source.
+    pub original: String,
+
+    /// Name in the output.
+    pub renamed: String,
+
+    /// Start of the declaration in the original source.
+    pub start: u32,
+
+    /// End of the declaration in the original source.
+    pub end: u32,
+
+    /// Scope the symbol is declared in.
+    pub scope: RenamedSymbolScope,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RenamedSymbolScope {
+    /// Name of the function or class which owns the scope.
+    /// `None` for anonymous functions, blocks and the top level scope.
+    pub name: Option<String>,
+
+    /// Start of the scope in the original source.
+    pub start: u32,
+
+    /// End of the scope in the original source.
+    pub end: u32,
+}
+
+impl RenameMap {
+    /// Collect the symbols whose name in `semantic` differs from `original_names`,
+    /// which holds the symbol names before mangling, indexed by [`SymbolId`](oxc_semantic::SymbolId).
+    pub fn new(semantic: &Semantic<'_>, original_names: &[String]) -> Self {
+        let scoping = semantic.scoping();
+        let nodes = semantic.nodes();
+        let symbols = scoping
+            .symbol_ids()
+            .zip(original_names)
+            .filter(|(symbol_id, original)| scoping.symbol_name(*symbol_id) != original.as_str())
+            .map(|(symbol_id, original)| {
+                let span = scoping.symbol_span(symbol_id);
+                let scope_node =
+                    nodes.get_node(scoping.get_node_id(scoping.symbol_scope_id(symbol_id)));
+                RenamedSymbol {
+                    original: original.clone(),
+                    renamed: scoping.symbol_name(symbol_id).to_string(),
+                    start: span.start,
+                    end: span.end,
+                    scope: RenamedSymbolScope::new(scope_node.kind()),
+                }
+            })
+            .collect();
+        Self { symbols }
+    }
+
+    /// Write the map as JSON.
+    ///
+    /// # Panics
+    ///
+    /// Never panics, as the map only contains strings and numbers.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("rename map is always serializable")
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+}
+
+impl RenamedSymbolScope {
+    fn new(kind: AstKind<'_>) -> Self {
+        // The mangler does not modify the AST, so these are the original names.
+        let name = match kind {
+            AstKind::Function(func) => func.id.as_ref(),
+            AstKind::Class(class) => class.id.as_ref(),
+            _ => None,
+        }
+        .map(|id| id.name.to_string());
+        let Span { start, end, .. } = kind.span();
+        Self { name, start, end }
+    }
+}
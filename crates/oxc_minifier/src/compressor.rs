@@ -1,15 +1,16 @@
 use oxc_allocator::Allocator;
-use oxc_ast::ast::*;
+use oxc_ast::{AstBuilder, ast::*};
 use oxc_semantic::{Scoping, SemanticBuilder};
 use oxc_traverse::ReusableTraverseCtx;
 
 use crate::{
-    CompressOptions,
+    CompressOptions, CompressOptionsUnused,
     peephole::{
-        DeadCodeElimination, LatePeepholeOptimizations, Normalize, NormalizeOptions,
-        PeepholeOptimizations,
+        DeadCodeElimination, DeadStoreElimination, HoistProperties, InlineExportHelpers,
+        LatePeepholeOptimizations, Normalize, NormalizeOptions, PeepholeOptimizations,
     },
     state::MinifierState,
+    stats::{CompressorPass, CompressorStats},
 };
 
 pub struct Compressor<'a> {
@@ -32,13 +33,80 @@ impl<'a> Compressor<'a> {
         scoping: Scoping,
         options: CompressOptions,
     ) {
-        let state = MinifierState::new(program.source_type, options);
+        self.compress(program, scoping, options, None);
+    }
+
+    /// Compress the program, and report the changes made by each pass.
+    pub fn build_with_stats(
+        self,
+        program: &mut Program<'a>,
+        options: CompressOptions,
+    ) -> CompressorStats {
+        let scoping = SemanticBuilder::new().build(program).semantic.into_scoping();
+        let mut stats = CompressorStats::new(program);
+        self.compress(program, scoping, options, Some(&mut stats));
+        stats
+    }
+
+    fn compress(
+        self,
+        program: &mut Program<'a>,
+        scoping: Scoping,
+        options: CompressOptions,
+        mut stats: Option<&mut CompressorStats>,
+    ) {
+        let state = MinifierState::new(program.source_type, options.clone());
         let mut ctx = ReusableTraverseCtx::new(state, scoping, self.allocator);
         let normalize_options =
             NormalizeOptions { convert_while_to_fors: true, convert_const_to_let: true };
         Normalize::new(normalize_options).build(program, &mut ctx);
-        PeepholeOptimizations::new().run_in_loop(program, &mut ctx);
+        if let Some(stats) = stats.as_deref_mut() {
+            stats.record(CompressorPass::Normalize, program);
+        }
+        let passes = options.passes;
+        let keep_top_level = program.source_type.is_script() && !options.bundle;
+        let ast = AstBuilder::new(self.allocator);
+        let mut changed = false;
+        if options.bundle {
+            changed |= InlineExportHelpers::new(ast).build(program);
+            if let Some(stats) = stats.as_deref_mut() {
+                stats.record(CompressorPass::InlineExportHelpers, program);
+            }
+        }
+        if options.hoist_props {
+            changed |= HoistProperties::new(ast, keep_top_level, options.keep_names).build(program);
+            if let Some(stats) = stats.as_deref_mut() {
+                stats.record(CompressorPass::HoistProperties, program);
+            }
+        }
+        if options.unused != CompressOptionsUnused::Keep {
+            changed |= DeadStoreElimination::new(ast, keep_top_level).build(program);
+            if let Some(stats) = stats.as_deref_mut() {
+                stats.record(CompressorPass::DeadStoreElimination, program);
+            }
+        }
+        if changed {
+            // Rebuild scoping for the peephole optimizations, as references and bindings are changed.
+            let scoping = SemanticBuilder::new().build(program).semantic.into_scoping();
+            let state = MinifierState::new(program.source_type, options);
+            ctx = ReusableTraverseCtx::new(state, scoping, self.allocator);
+        }
+        let mut peephole = PeepholeOptimizations::new();
+        if stats.is_some() {
+            peephole = peephole.with_stats();
+        }
+        peephole.run_in_loop(passes, program, &mut ctx, |iteration, program| {
+            if let Some(stats) = stats.as_deref_mut() {
+                stats.record(CompressorPass::Peephole(iteration), program);
+            }
+        });
+        if let Some(stats) = stats.as_deref_mut() {
+            stats.optimizations = peephole.take_stats();
+        }
         LatePeepholeOptimizations::new().build(program, &mut ctx);
+        if let Some(stats) = stats {
+            stats.record(CompressorPass::LatePeephole, program);
+        }
     }
 
     pub fn dead_code_elimination(self, program: &mut Program<'a>, options: CompressOptions) {
@@ -61,6 +61,9 @@ impl<'a> oxc_ecmascript::side_effects::MayHaveSideEffectsContext<'a> for Ctx<'a,
     }
 
     fn manual_pure_functions(&self, callee: &Expression) -> bool {
+        if self.is_pure_func(callee) {
+            return true;
+        }
         if let Expression::Identifier(ident) = callee {
             return self
                 .state
@@ -74,6 +77,9 @@ impl<'a> oxc_ecmascript::side_effects::MayHaveSideEffectsContext<'a> for Ctx<'a,
     }
 
     fn property_read_side_effects(&self) -> PropertyReadSideEffects {
+        if self.state.options.pure_getters {
+            return PropertyReadSideEffects::None;
+        }
         self.state.options.treeshake.property_read_side_effects
     }
 
@@ -101,10 +107,32 @@ impl<'a> Ctx<'a, '_> {
         &self.0.state.options
     }
 
+    /// Whether `callee` is listed in `compress.pure_funcs`, e.g. `assert` or `console.log`.
+    pub fn is_pure_func(&self, callee: &Expression) -> bool {
+        fn matches(scoping: &Scoping, expr: &Expression, name: &str) -> bool {
+            if let Expression::Identifier(ident) = expr {
+                // A local `console` is not the global one.
+                return ident.name == name && ident.is_global_reference(scoping);
+            }
+            let Some(member_expr) = expr.as_member_expression() else { return false };
+            name.rsplit_once('.').is_some_and(|(object, property)| {
+                member_expr.static_property_name() == Some(property)
+                    && matches(scoping, member_expr.object(), object)
+            })
+        }
+        let scoping = self.scoping();
+        self.options().pure_funcs.iter().any(|name| matches(scoping, callee, name))
+    }
+
     pub fn source_type(&self) -> SourceType {
         self.0.state.source_type
     }
 
+    /// Whether top level declarations are global variables which can be used by other scripts.
+    pub fn is_top_level_global(&self) -> bool {
+        self.source_type().is_script() && !self.options().bundle
+    }
+
     pub fn is_global_reference(&self, ident: &IdentifierReference<'a>) -> bool {
         ident.is_global_reference(self.0.scoping())
     }
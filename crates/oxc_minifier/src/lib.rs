@@ -8,18 +8,29 @@ mod keep_var;
 mod options;
 mod peephole;
 mod state;
+mod stats;
 
 #[cfg(test)]
 mod tester;
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
-use oxc_mangler::Mangler;
+use oxc_mangler::{Mangler, PropertyMangler};
 use oxc_semantic::{Scoping, SemanticBuilder, Stats};
 
-pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames};
+pub use oxc_mangler::{
+    MangleOptions, MangleOptionsKeepNames, MangleOptionsProps, NameCache, RenameMap, RenamedSymbol,
+    RenamedSymbolScope,
+};
 
-pub use crate::{compressor::Compressor, options::*};
+pub use crate::{
+    compressor::Compressor,
+    options::*,
+    stats::{
+        CompressorPass, CompressorPassStats, CompressorStats, PeepholeOptimization,
+        PeepholeOptimizationStats,
+    },
+};
 
 #[derive(Debug, Clone)]
 pub struct MinifierOptions {
@@ -35,15 +46,39 @@ impl Default for MinifierOptions {
 
 pub struct MinifierReturn {
     pub scoping: Option<Scoping>,
+
+    /// Name cache including the names mangled in this build.
+    /// `None` if names were not mangled.
+    pub name_cache: Option<NameCache>,
+
+    /// Symbols renamed by the mangler.
+    /// `None` if names were not mangled or the map was not requested with [`Minifier::with_rename_map`].
+    pub rename_map: Option<RenameMap>,
 }
 
 pub struct Minifier {
     options: MinifierOptions,
+    name_cache: NameCache,
+    rename_map: bool,
 }
 
 impl Minifier {
     pub fn new(options: MinifierOptions) -> Self {
-        Self { options }
+        Self { options, name_cache: NameCache::default(), rename_map: false }
+    }
+
+    /// Reuse the mangled names of a previous build.
+    #[must_use]
+    pub fn with_name_cache(mut self, name_cache: NameCache) -> Self {
+        self.name_cache = name_cache;
+        self
+    }
+
+    /// Return the symbols renamed by the mangler in [`MinifierReturn::rename_map`].
+    #[must_use]
+    pub fn with_rename_map(mut self, yes: bool) -> Self {
+        self.rename_map = yes;
+        self
     }
 
     pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) -> MinifierReturn {
@@ -56,15 +91,32 @@ impl Minifier {
         } else {
             Stats::default()
         };
-        let scoping = self.options.mangle.map(|options| {
+        let name_cache = self.name_cache;
+        let mut ret_name_cache = None;
+        let mut rename_map = None;
+        let scoping = self.options.mangle.map(|mut options| {
+            let name_cache = match options.props.take() {
+                Some(props) => PropertyMangler::new(props)
+                    .with_name_cache(name_cache)
+                    .build(allocator, program),
+                None => name_cache,
+            };
             let mut semantic = SemanticBuilder::new()
                 .with_stats(stats)
                 .with_scope_tree_child_ids(true)
                 .build(program)
                 .semantic;
-            Mangler::default().with_options(options).build_with_semantic(&mut semantic, program);
+            let original_names: Option<Vec<String>> = self
+                .rename_map
+                .then(|| semantic.scoping().symbol_names().map(ToString::to_string).collect());
+            let name_cache = Mangler::default()
+                .with_options(options)
+                .with_name_cache(name_cache)
+                .build_with_semantic(&mut semantic, program);
+            ret_name_cache = Some(name_cache);
+            rename_map = original_names.map(|names| RenameMap::new(&semantic, &names));
             semantic.into_scoping()
         });
-        MinifierReturn { scoping }
+        MinifierReturn { scoping, name_cache: ret_name_cache, rename_map }
     }
 }
@@ -24,6 +24,23 @@ pub struct CompressOptions {
     /// Default `false`
     pub drop_console: bool,
 
+    /// Remove labeled statements with these labels.
+    ///
+    /// `DEV: { foo() }` -> `` with `drop_labels: ["DEV"]`
+    ///
+    /// Default `[]`
+    pub drop_labels: Vec<String>,
+
+    /// Drop calls to these functions when their return value is unused.
+    ///
+    /// Dotted names such as `console.log` match member expression callees.
+    /// Arguments with side effects are kept.
+    ///
+    /// `assert(foo(), "message")` -> `foo()` with `pure_funcs: ["assert"]`
+    ///
+    /// Default `[]`
+    pub pure_funcs: Vec<String>,
+
     /// Join consecutive var, let and const statements.
     ///
     /// Default `true`
@@ -37,8 +54,101 @@ pub struct CompressOptions {
     pub sequences: bool,
 
     /// Drop unreferenced functions and variables.
+    ///
+    /// Also drops assignments which are always overwritten before being read,
+    /// and assignments to properties of local objects which are never read.
     pub unused: CompressOptionsUnused,
 
+    /// Inline single-use function declarations into their call site.
+    ///
+    /// * `0`: disabled
+    /// * `1`: functions without parameters
+    /// * `2`: functions whose parameters are used at most once
+    /// * `3`: functions whose parameters are used multiple times, if the arguments are primitive literals
+    ///
+    /// Only functions whose body is a single `return` statement are inlined.
+    /// Has no effect when `unused` is [CompressOptionsUnused::Keep], as the inlined declarations would be kept.
+    ///
+    /// Default `3`
+    pub inline: u8,
+
+    /// Substitute variables which are never reassigned.
+    ///
+    /// * `const` and `let` bindings initialized with a constant value are replaced with that value.
+    /// * Single-use `const` and `let` bindings are moved into the following statement.
+    ///
+    /// Has no effect when `unused` is [CompressOptionsUnused::Keep], as the inlined declarations would be kept.
+    ///
+    /// Default `true`
+    pub reduce_vars: bool,
+
+    /// Maximum number of times the peephole optimizations are run.
+    ///
+    /// `0` runs them until no more changes are made.
+    ///
+    /// Default `0`
+    pub passes: u8,
+
+    /// Assume that reading a property has no side effects,
+    /// i.e. there are no getters and the object is never `null` or `undefined`.
+    ///
+    /// `a.b; a.c()` -> `a.c()`
+    ///
+    /// Default `false`
+    pub pure_getters: bool,
+
+    /// Reassociate numeric constants, which may change the result due to floating point rounding.
+    ///
+    /// `x * 2 * 3` -> `x * 6`
+    ///
+    /// Default `false`
+    pub unsafe_math: bool,
+
+    /// Convert function expressions which do not use `this`, `arguments` or `new.target` to arrow functions.
+    ///
+    /// `foo(function () { return 1 })` -> `foo(() => 1)`
+    ///
+    /// This is unsafe if the function is called with `new` or its `prototype` is used.
+    ///
+    /// Default `false`
+    pub unsafe_arrows: bool,
+
+    /// Convert function expressions in object literals to methods.
+    ///
+    /// `({ a: function () {} })` -> `({ a() {} })`
+    ///
+    /// This is unsafe if the function is called with `new` or its `prototype` is used.
+    ///
+    /// Default `false`
+    pub unsafe_methods: bool,
+
+    /// Keep unused function parameters.
+    ///
+    /// When `false`, unused trailing parameters are dropped, which changes `Function.prototype.length`.
+    ///
+    /// Default `true`
+    pub keep_fargs: bool,
+
+    /// Hoist properties of local object literals into variables,
+    /// if the object is only used for reading its properties.
+    ///
+    /// `let o = { a: 1, b: 2 }; foo(o.a, o.b)` -> `let o_a = 1, o_b = 2; foo(o_a, o_b)`
+    ///
+    /// Default `true`
+    pub hoist_props: bool,
+
+    /// Compress a scope-hoisted bundle, where the modules of a chunk are concatenated by a bundler.
+    ///
+    /// * The top level scope of scripts is treated as a module scope,
+    ///   so unused top level declarations are removed.
+    /// * Namespace objects whose exports are defined with `__export` or `Object.defineProperty` getters
+    ///   are inlined into their property reads, and the helper calls are removed.
+    /// * Reads of CommonJS `exports` properties defined by `Object.defineProperty` getters at the
+    ///   top level are inlined after the definition. The helper calls are kept.
+    ///
+    /// Default `false`
+    pub bundle: bool,
+
     /// Keep function / class names.
     pub keep_names: CompressOptionsKeepNames,
 
@@ -60,9 +170,21 @@ impl CompressOptions {
             keep_names: CompressOptionsKeepNames::all_false(),
             drop_debugger: true,
             drop_console: false,
+            drop_labels: vec![],
+            pure_funcs: vec![],
             join_vars: true,
             sequences: true,
             unused: CompressOptionsUnused::Remove,
+            inline: 3,
+            reduce_vars: true,
+            passes: 0,
+            pure_getters: false,
+            unsafe_math: false,
+            unsafe_arrows: false,
+            unsafe_methods: false,
+            keep_fargs: true,
+            hoist_props: true,
+            bundle: false,
             treeshake: TreeShakeOptions::default(),
         }
     }
@@ -73,9 +195,21 @@ impl CompressOptions {
             keep_names: CompressOptionsKeepNames::all_true(),
             drop_debugger: false,
             drop_console: false,
+            drop_labels: vec![],
+            pure_funcs: vec![],
             join_vars: true,
             sequences: true,
             unused: CompressOptionsUnused::Keep,
+            inline: 0,
+            reduce_vars: false,
+            passes: 0,
+            pure_getters: false,
+            unsafe_math: false,
+            unsafe_arrows: false,
+            unsafe_methods: false,
+            keep_fargs: true,
+            hoist_props: false,
+            bundle: false,
             treeshake: TreeShakeOptions::default(),
         }
     }
@@ -86,9 +220,21 @@ impl CompressOptions {
             keep_names: CompressOptionsKeepNames::all_true(),
             drop_debugger: false,
             drop_console: false,
+            drop_labels: vec![],
+            pure_funcs: vec![],
             join_vars: false,
             sequences: false,
             unused: CompressOptionsUnused::Remove,
+            inline: 0,
+            reduce_vars: false,
+            passes: 0,
+            pure_getters: false,
+            unsafe_math: false,
+            unsafe_arrows: false,
+            unsafe_methods: false,
+            keep_fargs: true,
+            hoist_props: false,
+            bundle: false,
             treeshake: TreeShakeOptions::default(),
         }
     }
@@ -0,0 +1,537 @@
+use oxc_allocator::TakeIn;
+use oxc_ast::{AstBuilder, AstKind, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_cfg::{
+    BlockNodeId, ControlFlowGraph, EdgeType, ErrorEdgeKind,
+    graph::{Direction, visit::EdgeRef},
+};
+use oxc_semantic::{AstNodes, NodeId, ReferenceId, Semantic, SemanticBuilder, SymbolId};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Block, index of the statement in the block, and index of the expression or declarator in the statement.
+type Position = (BlockNodeId, usize, usize);
+
+/// Dead Store Elimination
+///
+/// Removes writes to local variables which are always overwritten before being read,
+/// and assignments to properties of local object literals which never escape.
+///
+/// * `x = 1; x = 2; foo(x)` -> `1; x = 2; foo(x)`
+/// * `let o = {}; o.a = foo()` -> `let o = {}; foo()`
+///
+/// The liveness of a variable is computed from the control flow graph built by semantic analysis.
+/// The remaining values and unused declarations are removed by the peephole optimizations.
+pub struct DeadStoreElimination<'a> {
+    ast: AstBuilder<'a>,
+    /// `x` of `x = value` and `o` of `o.a = value`.
+    dead_writes: FxHashSet<ReferenceId>,
+    /// `x` of `let x = value`.
+    dead_inits: FxHashSet<SymbolId>,
+    /// Top level declarations are global variables in scripts.
+    keep_top_level: bool,
+}
+
+/// Reads and overwrites of a variable.
+#[derive(Default)]
+struct Uses {
+    reads: Vec<Position>,
+    /// Blocks containing a read which cannot be ordered with the statements of the block.
+    unordered_reads: FxHashSet<BlockNodeId>,
+    kills: Vec<Position>,
+}
+
+impl<'a> DeadStoreElimination<'a> {
+    pub fn new(ast: AstBuilder<'a>, keep_top_level: bool) -> Self {
+        Self {
+            ast,
+            dead_writes: FxHashSet::default(),
+            dead_inits: FxHashSet::default(),
+            keep_top_level,
+        }
+    }
+
+    /// Returns `true` if the program is changed.
+    pub fn build(mut self, program: &mut Program<'a>) -> bool {
+        let semantic = SemanticBuilder::new().with_cfg(true).build(program).semantic;
+        self.analyze(&semantic);
+        drop(semantic);
+        if self.dead_writes.is_empty() && self.dead_inits.is_empty() {
+            return false;
+        }
+        self.visit_program(program);
+        true
+    }
+
+    fn analyze(&mut self, semantic: &Semantic<'_>) {
+        let scoping = semantic.scoping();
+        let nodes = semantic.nodes();
+        let Some(cfg) = semantic.cfg() else { return };
+        // Direct `eval` and `with` can read any variable.
+        if scoping.root_scope_flags().contains_direct_eval()
+            || nodes.iter().any(|node| matches!(node.kind(), AstKind::WithStatement(_)))
+        {
+            return;
+        }
+
+        let mut instructions = FxHashMap::default();
+        for block_id in cfg.graph().node_indices() {
+            for (index, instruction) in cfg.basic_block(block_id).instructions().iter().enumerate()
+            {
+                if let Some(node_id) = instruction.node_id {
+                    instructions.entry(node_id).or_insert((block_id, index));
+                }
+            }
+        }
+
+        // `x = value` and `let x = value`
+        let mut stores: FxHashMap<SymbolId, Vec<(Position, Option<ReferenceId>)>> =
+            FxHashMap::default();
+        // `o.a = value`
+        let mut property_stores: FxHashMap<SymbolId, Vec<ReferenceId>> = FxHashMap::default();
+        for (&node_id, &(block, index)) in &instructions {
+            match nodes.kind(node_id) {
+                AstKind::ExpressionStatement(stmt) => {
+                    let exprs = match &stmt.expression {
+                        Expression::SequenceExpression(e) => e.expressions.as_slice(),
+                        expr => std::slice::from_ref(expr),
+                    };
+                    for (sub_index, expr) in exprs.iter().enumerate() {
+                        let Expression::AssignmentExpression(e) = expr else { continue };
+                        if e.operator != AssignmentOperator::Assign {
+                            continue;
+                        }
+                        match &e.left {
+                            AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                                let reference_id = ident.reference_id();
+                                let reference = scoping.get_reference(reference_id);
+                                let Some(symbol_id) = reference.symbol_id() else { continue };
+                                // A write after a short-circuit expression can not be ordered.
+                                if nodes.get_node(reference.node_id()).cfg_id() != block {
+                                    continue;
+                                }
+                                stores
+                                    .entry(symbol_id)
+                                    .or_default()
+                                    .push(((block, index, sub_index), Some(reference_id)));
+                            }
+                            AssignmentTarget::StaticMemberExpression(member) => {
+                                let Expression::Identifier(ident) = &member.object else {
+                                    continue;
+                                };
+                                let reference_id = ident.reference_id();
+                                if let Some(symbol_id) =
+                                    scoping.get_reference(reference_id).symbol_id()
+                                {
+                                    property_stores
+                                        .entry(symbol_id)
+                                        .or_default()
+                                        .push(reference_id);
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                AstKind::VariableDeclaration(decl)
+                    if matches!(
+                        nodes.parent_kind(node_id),
+                        AstKind::Program(_)
+                            | AstKind::BlockStatement(_)
+                            | AstKind::FunctionBody(_)
+                            | AstKind::SwitchCase(_)
+                            | AstKind::StaticBlock(_)
+                    ) =>
+                {
+                    for (sub_index, declarator) in decl.declarations.iter().enumerate() {
+                        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind
+                        else {
+                            continue;
+                        };
+                        if declarator.init.is_none() {
+                            continue;
+                        }
+                        let symbol_id = ident.symbol_id();
+                        if nodes.get_node(scoping.symbol_declaration(symbol_id)).cfg_id() != block {
+                            continue;
+                        }
+                        stores
+                            .entry(symbol_id)
+                            .or_default()
+                            .push(((block, index, sub_index), None));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for (symbol_id, stores) in stores {
+            if !self.is_local_variable(symbol_id, semantic) {
+                continue;
+            }
+            let uses = Self::collect_uses(symbol_id, &stores, &instructions, semantic);
+            for (position, reference_id) in stores {
+                if Self::is_read_before_overwritten(cfg, position, &uses) {
+                    continue;
+                }
+                match reference_id {
+                    Some(reference_id) => {
+                        self.dead_writes.insert(reference_id);
+                    }
+                    None => {
+                        if Self::is_removable_init(symbol_id, nodes, scoping) {
+                            self.dead_inits.insert(symbol_id);
+                        }
+                    }
+                }
+            }
+        }
+
+        for (symbol_id, reference_ids) in property_stores {
+            if reference_ids.len() == scoping.get_resolved_reference_ids(symbol_id).len()
+                && self.is_local_variable(symbol_id, semantic)
+                && Self::is_non_escaping_object(symbol_id, nodes, scoping)
+            {
+                self.dead_writes.extend(reference_ids);
+            }
+        }
+    }
+
+    /// Variables declared with `var` or `let`, which are only referenced in the function they are declared in.
+    fn is_local_variable(&self, symbol_id: SymbolId, semantic: &Semantic<'_>) -> bool {
+        let scoping = semantic.scoping();
+        let nodes = semantic.nodes();
+        let declaration = scoping.symbol_declaration(symbol_id);
+        if !matches!(nodes.kind(declaration), AstKind::VariableDeclarator(_))
+            || !scoping.symbol_redeclarations(symbol_id).is_empty()
+        {
+            return false;
+        }
+        if scoping.symbol_scope_id(symbol_id) == scoping.root_scope_id()
+            && (self.keep_top_level
+                || nodes
+                    .ancestor_kinds(declaration)
+                    .any(|kind| matches!(kind, AstKind::ExportNamedDeclaration(_))))
+        {
+            return false;
+        }
+        let owner = Self::function_owner(declaration, nodes);
+        scoping.get_resolved_references(symbol_id).all(|reference| {
+            !matches!(nodes.parent_kind(reference.node_id()), AstKind::ExportSpecifier(_))
+                && Self::function_owner(reference.node_id(), nodes) == owner
+        })
+    }
+
+    /// The function, or class field initializer, which evaluates `node_id`.
+    fn function_owner(node_id: NodeId, nodes: &AstNodes<'_>) -> Option<NodeId> {
+        nodes.ancestor_ids(node_id).find(|&id| {
+            matches!(
+                nodes.kind(id),
+                AstKind::Program(_)
+                    | AstKind::Function(_)
+                    | AstKind::ArrowFunctionExpression(_)
+                    | AstKind::StaticBlock(_)
+                    | AstKind::PropertyDefinition(_)
+                    | AstKind::AccessorProperty(_)
+            )
+        })
+    }
+
+    fn collect_uses(
+        symbol_id: SymbolId,
+        stores: &[(Position, Option<ReferenceId>)],
+        instructions: &FxHashMap<NodeId, (BlockNodeId, usize)>,
+        semantic: &Semantic<'_>,
+    ) -> Uses {
+        let scoping = semantic.scoping();
+        let nodes = semantic.nodes();
+        let mut uses = Uses {
+            kills: stores.iter().map(|(position, _)| *position).collect(),
+            ..Uses::default()
+        };
+        for reference in scoping.get_resolved_references(symbol_id) {
+            if !reference.is_read() {
+                continue;
+            }
+            let node_id = reference.node_id();
+            let block = nodes.get_node(node_id).cfg_id();
+            let span = nodes.kind(node_id).span();
+            let statement = std::iter::once(node_id)
+                .chain(nodes.ancestor_ids(node_id))
+                .find_map(|id| instructions.get(&id).map(|&position| (id, position)));
+            let Some((statement_id, (statement_block, index))) = statement else {
+                uses.unordered_reads.insert(block);
+                continue;
+            };
+            let position =
+                (statement_block, index, Self::sub_index(nodes.kind(statement_id), span));
+            uses.reads.push(position);
+            // The read is in a short-circuit expression of the statement.
+            if statement_block != block {
+                uses.unordered_reads.insert(block);
+            }
+        }
+        uses
+    }
+
+    /// Index of the expression in `a, b, c` or the declarator in `var a, b, c` containing `span`.
+    fn sub_index(kind: AstKind<'_>, span: Span) -> usize {
+        let position = match kind {
+            AstKind::ExpressionStatement(stmt) => match &stmt.expression {
+                Expression::SequenceExpression(e) => {
+                    e.expressions.iter().position(|e| e.span().contains_inclusive(span))
+                }
+                _ => None,
+            },
+            AstKind::VariableDeclaration(decl) => {
+                decl.declarations.iter().position(|d| d.span.contains_inclusive(span))
+            }
+            _ => None,
+        };
+        position.unwrap_or_default()
+    }
+
+    /// Walk the control flow graph from the store, until the variable is read or overwritten.
+    /// A store which reaches the end of the function without being overwritten is not dead.
+    fn is_read_before_overwritten(cfg: &ControlFlowGraph, store: Position, uses: &Uses) -> bool {
+        let mut visited = FxHashSet::default();
+        let mut stack = vec![(store.0, Some((store.1, store.2)))];
+        while let Some((block, after)) = stack.pop() {
+            let edges = cfg.graph().edges_directed(block, Direction::Outgoing);
+            // Bail out of `try` statements, the variable may be read by `catch` or `finally`
+            // after an exception thrown before it is overwritten.
+            if uses.unordered_reads.contains(&block)
+                || edges.clone().any(|edge| {
+                    matches!(
+                        edge.weight(),
+                        EdgeType::Error(ErrorEdgeKind::Explicit) | EdgeType::Finalize
+                    )
+                })
+            {
+                return true;
+            }
+            let next = |positions: &[Position]| {
+                positions
+                    .iter()
+                    .filter(|position| position.0 == block)
+                    .map(|position| (position.1, position.2))
+                    .filter(|position| after.is_none_or(|after| *position > after))
+                    .min()
+            };
+            match (next(&uses.reads), next(&uses.kills)) {
+                // `x = x + 1` reads `x` before overwriting it.
+                (Some(read), Some(kill)) if read <= kill => return true,
+                (Some(_), None) => return true,
+                (_, Some(_)) => continue,
+                (None, None) => {}
+            }
+            let mut successors = edges
+                .filter(|edge| !matches!(edge.weight(), EdgeType::NewFunction | EdgeType::Error(_)))
+                .peekable();
+            // The last store is left to the removal of unused variables.
+            if successors.peek().is_none() {
+                return true;
+            }
+            for edge in successors {
+                if visited.insert(edge.target()) {
+                    stack.push((edge.target(), None));
+                }
+            }
+        }
+        false
+    }
+
+    /// Initial values which can be dropped without side effects.
+    fn is_removable_init(
+        symbol_id: SymbolId,
+        nodes: &AstNodes<'_>,
+        scoping: &oxc_semantic::Scoping,
+    ) -> bool {
+        let AstKind::VariableDeclarator(decl) = nodes.kind(scoping.symbol_declaration(symbol_id))
+        else {
+            return false;
+        };
+        decl.init.as_ref().is_some_and(|init| match init {
+            Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_) => true,
+            Expression::TemplateLiteral(e) => e.expressions.is_empty(),
+            _ => false,
+        })
+    }
+
+    /// `let o = { a: 1 }` where the object literal has no accessors and does not change the prototype.
+    fn is_non_escaping_object(
+        symbol_id: SymbolId,
+        nodes: &AstNodes<'_>,
+        scoping: &oxc_semantic::Scoping,
+    ) -> bool {
+        let AstKind::VariableDeclarator(decl) = nodes.kind(scoping.symbol_declaration(symbol_id))
+        else {
+            return false;
+        };
+        let Some(Expression::ObjectExpression(object)) = &decl.init else { return false };
+        object.properties.iter().all(|property| match property {
+            ObjectPropertyKind::ObjectProperty(p) => {
+                p.kind == PropertyKind::Init
+                    && !p.computed
+                    && p.key.static_name().is_some_and(|name| name != "__proto__")
+            }
+            ObjectPropertyKind::SpreadProperty(_) => false,
+        })
+    }
+
+    fn remove_dead_store(&self, expr: &mut Expression<'a>) {
+        let Expression::AssignmentExpression(e) = expr else { return };
+        let reference_id = match &e.left {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => ident.reference_id(),
+            AssignmentTarget::StaticMemberExpression(member) => match &member.object {
+                Expression::Identifier(ident) => ident.reference_id(),
+                _ => return,
+            },
+            _ => return,
+        };
+        if self.dead_writes.contains(&reference_id) {
+            *expr = e.right.take_in(self.ast);
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for DeadStoreElimination<'a> {
+    fn visit_expression_statement(&mut self, it: &mut ExpressionStatement<'a>) {
+        match &mut it.expression {
+            Expression::SequenceExpression(e) => {
+                for expr in &mut e.expressions {
+                    self.remove_dead_store(expr);
+                }
+            }
+            expr => self.remove_dead_store(expr),
+        }
+        walk_mut::walk_expression_statement(self, it);
+    }
+
+    fn visit_variable_declarator(&mut self, it: &mut VariableDeclarator<'a>) {
+        if let BindingPatternKind::BindingIdentifier(ident) = &it.id.kind {
+            if self.dead_inits.contains(&ident.symbol_id()) {
+                it.init = None;
+            }
+        }
+        walk_mut::walk_variable_declarator(self, it);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_syntax::es_target::ESTarget;
+
+    use crate::{
+        CompressOptions, CompressOptionsUnused,
+        tester::{test_options, test_same_options},
+    };
+
+    fn options() -> CompressOptions {
+        CompressOptions { inline: 0, reduce_vars: false, ..CompressOptions::smallest() }
+    }
+
+    fn test(source_text: &str, expected: &str) {
+        test_options(source_text, expected, &options());
+    }
+
+    fn test_same(source_text: &str) {
+        test_same_options(source_text, &options());
+    }
+
+    #[test]
+    fn dead_stores() {
+        test(
+            "export function f() { var x; x = foo(); x = bar(); return x }",
+            "export function f() { var x; return foo(), x = bar(), x }",
+        );
+        test(
+            "export function f() { let x = 1; x = bar(); return x }",
+            "export function f() { let x; return x = bar(), x }",
+        );
+        test(
+            "export function f() { var x; x = 1; x = 2; return x }",
+            "export function f() { var x; return x = 2, x }",
+        );
+        test(
+            "export function f() { var x; x = foo(), x = bar(); return x }",
+            "export function f() { var x; return foo(), x = bar(), x }",
+        );
+        test(
+            "export function f() { var x; x = foo(); if (a) x = bar(); else x = baz(); return x }",
+            "export function f() { var x; return foo(), x = a ? bar() : baz(), x }",
+        );
+    }
+
+    #[test]
+    fn logical_assignments() {
+        test(
+            "export function f() { var x; x = foo(); x = bar(); x = x || 1; return x }",
+            "export function f() { var x; return foo(), x = bar(), x ||= 1, x }",
+        );
+        test_same("export function f() { var x; return x = foo(), x ||= 1, x }");
+        test("var x; x = x || 1", "var x; x ||= 1");
+        let options = CompressOptions { target: ESTarget::ES2019, ..options() };
+        test_same_options(
+            "export function f() { var x; return x = foo(), x = x || 1, x }",
+            &options,
+        );
+    }
+
+    #[test]
+    fn live_stores() {
+        test_same("export function f() { var x; return x = foo(), x += bar(), x }");
+        test_same("export function f() { var x; return x = foo(), a && (x = bar()), x }");
+        test_same("export function f() { var x; return x = foo(), a && bar(x), x = baz(), x }");
+        test_same("export function f() { for (var x;;) { if (a()) return x; x = foo() } }");
+        test_same("export function f() { var x; for (x = foo(); a; ) bar(x), x = baz() }");
+        test_same(
+            "export function f() { var x; x = foo(); try { x = bar(), baz() } catch {} return x }",
+        );
+        test_same("export function f() { var x; try { x = foo(), x = bar() } finally { baz(x) } }");
+        // Read by closures.
+        test_same(
+            "export function f() { var x; function g() { return x } return x = foo(), x = bar(), g }",
+        );
+        test_same("export function f() { var x; return x = foo(), x = bar(), () => x }");
+        // Global variables of scripts and exported variables.
+        test_same("export var x; x = foo(), x = bar();");
+        test_same("var x; x = foo(), x = bar(); export { x };");
+        test_same("export function f() { var x; x = foo(), eval('x'), x = bar() }");
+        // The last store, which is left to the removal of unused variables.
+        test_same("export function f() { var x; x = foo() }");
+        test_same("let x; x = foo();");
+    }
+
+    #[test]
+    fn unused_property_assignments() {
+        test(
+            "export function f() { let o = {}; o.a = foo(), o.b = 1 }",
+            "export function f() { foo() }",
+        );
+        test(
+            "export function f() { let o = { a: 1 }; o.a = foo(); o.b = bar() }",
+            "export function f() { foo(), bar() }",
+        );
+        test_same("export function f() { let o = {}; return o.a = foo(), o }");
+        test_same("export function f() { let o = {}; o.a = foo(), bar(o.a) }");
+        test_same("export function f() { let o = { set a(v) { foo(v) } }; o.a = 1 }");
+        test_same("export function f() { let o = { __proto__: a }; o.b = 1 }");
+        test_same("export function f() { let o = foo(); o.a = 1 }");
+    }
+
+    #[test]
+    fn keep_unused() {
+        let options = CompressOptions { unused: CompressOptionsUnused::Keep, ..options() };
+        test_same_options(
+            "export function f() { var x; return x = foo(), x = bar(), x }",
+            &options,
+        );
+    }
+}
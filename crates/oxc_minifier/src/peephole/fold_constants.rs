@@ -28,7 +28,8 @@ impl<'a> PeepholeOptimizations {
 
         if let Some(folded_expr) = match expr {
             Expression::BinaryExpression(e) => Self::try_fold_binary_expr(e, ctx)
-                .or_else(|| Self::try_fold_binary_typeof_comparison(e, ctx)),
+                .or_else(|| Self::try_fold_binary_typeof_comparison(e, ctx))
+                .or_else(|| Self::try_fold_unsafe_math(e, ctx)),
             Expression::UnaryExpression(e) => Self::try_fold_unary_expr(e, ctx),
             Expression::StaticMemberExpression(e) => Self::try_fold_static_member_expr(e, ctx),
             Expression::ComputedMemberExpression(e) => Self::try_fold_computed_member_expr(e, ctx),
@@ -44,7 +45,11 @@ impl<'a> PeepholeOptimizations {
         // Save `const value = false` into constant values.
         if let Ancestor::VariableDeclaratorInit(decl) = ctx.parent() {
             // TODO: Check for no write references.
-            if decl.kind().is_const() {
+            if decl.kind().is_const()
+                || (*decl.kind() == VariableDeclarationKind::Let
+                    && Self::can_reduce_vars(ctx)
+                    && Self::is_never_reassigned(decl.id(), ctx))
+            {
                 if let BindingPatternKind::BindingIdentifier(ident) = &decl.id().kind {
                     // TODO: refactor all the above code to return value instead of expression, to avoid calling `evaluate_value` again.
                     if let Some(value) = expr.evaluate_value(ctx) {
@@ -514,6 +519,72 @@ impl<'a> PeepholeOptimizations {
         }
     }
 
+    /// Reassociate numeric constants.
+    ///
+    /// * `x * 2 * 3` -> `x * 6`
+    /// * `x / 2 / 4` -> `x / 8`
+    /// * `8 / x * 2` -> `16 / x`
+    ///
+    /// Enabled by `compress.unsafe_math`, as the result may differ due to floating point rounding.
+    fn try_fold_unsafe_math(
+        e: &mut BinaryExpression<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        use oxc_syntax::number::ToJsString;
+
+        if !ctx.options().unsafe_math
+            || !matches!(e.operator, BinaryOperator::Multiplication | BinaryOperator::Division)
+        {
+            return None;
+        }
+        let Expression::NumericLiteral(right) = &e.right else { return None };
+        let Expression::BinaryExpression(left) = &mut e.left else { return None };
+        let (constant, is_constant_left) = match (&left.left, &left.right) {
+            (Expression::NumericLiteral(n), _) => (n.value, true),
+            (_, Expression::NumericLiteral(n)) => (n.value, false),
+            _ => return None,
+        };
+        let (value, operator, is_value_left) = match (left.operator, is_constant_left, e.operator) {
+            // `(x * c) * r`, `(c * x) * r`
+            (BinaryOperator::Multiplication, _, BinaryOperator::Multiplication) => {
+                (constant * right.value, BinaryOperator::Multiplication, false)
+            }
+            // `(x * c) / r`, `(c * x) / r`
+            (BinaryOperator::Multiplication, _, BinaryOperator::Division) => {
+                (constant / right.value, BinaryOperator::Multiplication, false)
+            }
+            // `(x / c) * r`
+            (BinaryOperator::Division, false, BinaryOperator::Multiplication) => {
+                (right.value / constant, BinaryOperator::Multiplication, false)
+            }
+            // `(x / c) / r`
+            (BinaryOperator::Division, false, BinaryOperator::Division) => {
+                (constant * right.value, BinaryOperator::Division, false)
+            }
+            // `(c / x) * r`
+            (BinaryOperator::Division, true, BinaryOperator::Multiplication) => {
+                (constant * right.value, BinaryOperator::Division, true)
+            }
+            // `(c / x) / r`
+            (BinaryOperator::Division, true, BinaryOperator::Division) => {
+                (constant / right.value, BinaryOperator::Division, true)
+            }
+            _ => return None,
+        };
+        if !value.is_finite() || value == 0.0 {
+            return None;
+        }
+        let printed_len = |value: f64| value.to_js_string().len();
+        if printed_len(value) > printed_len(constant) + printed_len(right.value) {
+            return None;
+        }
+        let value = ctx.value_to_expr(e.span, ConstantValue::Number(value));
+        let other = if is_constant_left { &mut left.right } else { &mut left.left };
+        let other = other.take_in(ctx.ast);
+        let (left, right) = if is_value_left { (value, other) } else { (other, value) };
+        Some(ctx.ast.expression_binary(e.span, left, operator, right))
+    }
+
     fn try_fold_left_child_op(
         e: &mut BinaryExpression<'a>,
         ctx: &mut Ctx<'a, '_>,
@@ -827,7 +898,10 @@ mod test {
     static MAX_SAFE_INT: i64 = 9_007_199_254_740_991_i64;
     static NEG_MAX_SAFE_INT: i64 = -9_007_199_254_740_991_i64;
 
-    use crate::tester::test;
+    use crate::{
+        CompressOptions,
+        tester::{default_options, test, test_options},
+    };
 
     // wrap with a function call so it doesn't get removed.
     fn fold(source_text: &str, expected: &str) {
@@ -2156,4 +2230,27 @@ mod test {
             fold("({ ...{ ['__proto__']: null } })", "({ ['__proto__']: null })");
         }
     }
+
+    #[test]
+    fn test_unsafe_math() {
+        let options = CompressOptions { unsafe_math: true, ..default_options() };
+        let fold_unsafe = |source_text: &str, expected: &str| {
+            test_options(&format!("NOOP({source_text})"), &format!("NOOP({expected})"), &options);
+        };
+        let fold_unsafe_same = |source_text: &str| fold_unsafe(source_text, source_text);
+        fold_unsafe("x * 2 * 3", "x * 6");
+        fold_unsafe("2 * x * 3", "x * 6");
+        fold_unsafe("x / 2 / 4", "x / 8");
+        fold_unsafe("x * 6 / 2", "x * 3");
+        fold_unsafe("x / 2 * 4", "x * 2");
+        fold_unsafe("8 / x / 2", "4 / x");
+        fold_unsafe("8 / x * 2", "16 / x");
+        fold_unsafe_same("x * 3 / 7");
+        fold_unsafe_same("x * 0 * 2");
+        fold_unsafe_same("x * 1e308 * 10");
+        fold_unsafe_same("x + 2 + 3");
+
+        // Disabled by default.
+        fold_same("x * 2 * 3");
+    }
 }
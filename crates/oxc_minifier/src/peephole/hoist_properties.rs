@@ -0,0 +1,376 @@
+use std::borrow::Cow;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{AstBuilder, AstKind, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_semantic::{ReferenceId, Scoping, Semantic, SemanticBuilder, SymbolId};
+use oxc_span::{Atom, GetSpan};
+use oxc_syntax::identifier::is_identifier_name;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::CompressOptionsKeepNames;
+
+/// Hoist Properties
+///
+/// Replaces local object literals which are only used for reading their properties with a variable for each property.
+///
+/// `let o = { a: 1, b: foo() }; bar(o.a, o.b)` -> `let o_a = 1, o_b = foo(); bar(o_a, o_b)`
+///
+/// The new variables are inlined or mangled by the following optimizations.
+pub struct HoistProperties<'a> {
+    ast: AstBuilder<'a>,
+    /// Object to the variable name of each property, in the order of the object literal.
+    objects: FxHashMap<SymbolId, Vec<(String, Atom<'a>)>>,
+    /// `o` of `o.a` to the variable name of `a`.
+    reads: FxHashMap<ReferenceId, Atom<'a>>,
+    /// Top level declarations are global variables in scripts.
+    keep_top_level: bool,
+    /// Anonymous functions and classes are named after their property key.
+    keep_names: CompressOptionsKeepNames,
+}
+
+impl<'a> HoistProperties<'a> {
+    pub fn new(
+        ast: AstBuilder<'a>,
+        keep_top_level: bool,
+        keep_names: CompressOptionsKeepNames,
+    ) -> Self {
+        Self {
+            ast,
+            objects: FxHashMap::default(),
+            reads: FxHashMap::default(),
+            keep_top_level,
+            keep_names,
+        }
+    }
+
+    /// Returns `true` if the program is changed.
+    pub fn build(mut self, program: &mut Program<'a>) -> bool {
+        let semantic = SemanticBuilder::new().build(program).semantic;
+        self.analyze(&semantic, program);
+        drop(semantic);
+        if self.objects.is_empty() {
+            return false;
+        }
+        self.visit_program(program);
+        true
+    }
+
+    /// `{ a: function() {} }` has the name `a`, which would change if the property is hoisted.
+    fn is_named_after_key(&self, value: &Expression<'a>) -> bool {
+        match value {
+            Expression::ArrowFunctionExpression(_) => self.keep_names.function,
+            Expression::FunctionExpression(func) => func.id.is_none() && self.keep_names.function,
+            Expression::ClassExpression(class) => class.id.is_none() && self.keep_names.class,
+            _ => false,
+        }
+    }
+
+    fn analyze(&mut self, semantic: &Semantic<'_>, program: &Program<'a>) {
+        let scoping = semantic.scoping();
+        let nodes = semantic.nodes();
+        if scoping.root_scope_flags().contains_direct_eval() {
+            return;
+        }
+
+        let mut candidates = FxHashMap::default();
+        for symbol_id in scoping.symbol_ids() {
+            let declaration = scoping.symbol_declaration(symbol_id);
+            let AstKind::VariableDeclarator(decl) = nodes.kind(declaration) else { continue };
+            let Some(Expression::ObjectExpression(object)) = &decl.init else { continue };
+            if !scoping.symbol_redeclarations(symbol_id).is_empty()
+                || !matches!(decl.id.kind, BindingPatternKind::BindingIdentifier(_))
+            {
+                continue;
+            }
+            // Declarations in `for` statements are evaluated multiple times.
+            let declaration_parent = nodes.parent_id(declaration);
+            if !matches!(
+                nodes.parent_kind(declaration_parent),
+                AstKind::Program(_)
+                    | AstKind::BlockStatement(_)
+                    | AstKind::FunctionBody(_)
+                    | AstKind::SwitchCase(_)
+                    | AstKind::StaticBlock(_)
+            ) {
+                continue;
+            }
+            if scoping.symbol_scope_id(symbol_id) == scoping.root_scope_id() && self.keep_top_level
+            {
+                continue;
+            }
+            let mut keys = FxHashSet::default();
+            let is_plain_object = object.properties.iter().all(|property| match property {
+                ObjectPropertyKind::ObjectProperty(p) => {
+                    p.kind == PropertyKind::Init
+                        && !p.method
+                        && !p.computed
+                        && !self.is_named_after_key(&p.value)
+                        && p.key
+                            .static_name()
+                            .is_some_and(|name| name != "__proto__" && keys.insert(name))
+                }
+                ObjectPropertyKind::SpreadProperty(_) => false,
+            });
+            if is_plain_object {
+                candidates.insert(symbol_id, keys);
+            }
+        }
+        if candidates.is_empty() {
+            return;
+        }
+
+        let mut collector = PropertyReadCollector {
+            scoping,
+            candidates: &candidates,
+            reads: FxHashMap::default(),
+            invalid: FxHashSet::default(),
+        };
+        collector.visit_program(program);
+        let PropertyReadCollector { reads, invalid, .. } = collector;
+
+        let mut used_names = scoping
+            .symbol_names()
+            .chain(scoping.root_unresolved_references().keys().copied())
+            .map(ToString::to_string)
+            .collect::<FxHashSet<_>>();
+        let mut reads_by_symbol: FxHashMap<SymbolId, Vec<(ReferenceId, String)>> =
+            FxHashMap::default();
+        for (reference_id, (symbol_id, key)) in reads {
+            reads_by_symbol.entry(symbol_id).or_default().push((reference_id, key));
+        }
+        for (symbol_id, reads) in reads_by_symbol {
+            if invalid.contains(&symbol_id)
+                || reads.len() != scoping.get_resolved_reference_ids(symbol_id).len()
+            {
+                continue;
+            }
+            let AstKind::VariableDeclarator(decl) =
+                nodes.kind(scoping.symbol_declaration(symbol_id))
+            else {
+                continue;
+            };
+            let Some(Expression::ObjectExpression(object)) = &decl.init else { continue };
+            let object_name = scoping.symbol_name(symbol_id);
+            let mut names = vec![];
+            for (index, property) in object.properties.iter().enumerate() {
+                let ObjectPropertyKind::ObjectProperty(p) = property else { unreachable!() };
+                let key = p.key.static_name().unwrap().into_owned();
+                let base = if is_identifier_name(&key) {
+                    format!("{object_name}_{key}")
+                } else {
+                    format!("{object_name}_{index}")
+                };
+                let mut name = base.clone();
+                let mut suffix = 1;
+                while !used_names.insert(name.clone()) {
+                    name = format!("{base}{suffix}");
+                    suffix += 1;
+                }
+                names.push((key, self.ast.atom(&name)));
+            }
+            for (reference_id, key) in reads {
+                let name = names.iter().find(|(k, _)| *k == key).unwrap().1;
+                self.reads.insert(reference_id, name);
+            }
+            self.objects.insert(symbol_id, names);
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for HoistProperties<'a> {
+    fn visit_expression(&mut self, it: &mut Expression<'a>) {
+        if let Expression::StaticMemberExpression(e) = it {
+            if let Expression::Identifier(ident) = &e.object {
+                if let Some(name) = self.reads.get(&ident.reference_id()) {
+                    *it = self.ast.expression_identifier(e.span, *name);
+                    return;
+                }
+            }
+        }
+        walk_mut::walk_expression(self, it);
+    }
+
+    fn visit_variable_declaration(&mut self, it: &mut VariableDeclaration<'a>) {
+        let is_hoisted = |decl: &VariableDeclarator<'a>| {
+            decl.id
+                .get_binding_identifier()
+                .is_some_and(|id| self.objects.contains_key(&id.symbol_id()))
+        };
+        if it.declarations.iter().any(is_hoisted) {
+            let mut declarations =
+                ArenaVec::with_capacity_in(it.declarations.len(), self.ast.allocator);
+            for mut decl in it.declarations.take_in(self.ast) {
+                let symbol_id = decl.id.get_binding_identifier().map(BindingIdentifier::symbol_id);
+                let Some(names) = symbol_id.and_then(|symbol_id| self.objects.get(&symbol_id))
+                else {
+                    declarations.push(decl);
+                    continue;
+                };
+                let Some(Expression::ObjectExpression(object)) = &mut decl.init else {
+                    unreachable!()
+                };
+                for (property, (_, name)) in object.properties.iter_mut().zip(names) {
+                    let ObjectPropertyKind::ObjectProperty(p) = property else { unreachable!() };
+                    let value = p.value.take_in(self.ast);
+                    declarations.push(self.ast.variable_declarator(
+                        p.span,
+                        decl.kind,
+                        self.ast.binding_pattern(
+                            self.ast.binding_pattern_kind_binding_identifier(p.key.span(), *name),
+                            oxc_ast::NONE,
+                            false,
+                        ),
+                        Some(value),
+                        false,
+                    ));
+                }
+            }
+            it.declarations = declarations;
+        }
+        walk_mut::walk_variable_declaration(self, it);
+    }
+}
+
+/// Collects `o.a` which only read the property of an object literal.
+struct PropertyReadCollector<'s> {
+    scoping: &'s Scoping,
+    candidates: &'s FxHashMap<SymbolId, FxHashSet<Cow<'s, str>>>,
+    reads: FxHashMap<ReferenceId, (SymbolId, String)>,
+    /// Objects whose methods are called, or whose properties are deleted.
+    invalid: FxHashSet<SymbolId>,
+}
+
+impl PropertyReadCollector<'_> {
+    fn candidate_of(&self, expr: &Expression<'_>) -> Option<SymbolId> {
+        let Expression::StaticMemberExpression(e) = expr else { return None };
+        let Expression::Identifier(ident) = &e.object else { return None };
+        let symbol_id = self.scoping.get_reference(ident.reference_id()).symbol_id()?;
+        self.candidates.contains_key(&symbol_id).then_some(symbol_id)
+    }
+}
+
+impl<'a> Visit<'a> for PropertyReadCollector<'_> {
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        if let Expression::StaticMemberExpression(e) = it {
+            if let Some(symbol_id) = self.candidate_of(it) {
+                let Expression::Identifier(ident) = &e.object else { unreachable!() };
+                if self.candidates[&symbol_id].contains(e.property.name.as_str()) {
+                    self.reads
+                        .insert(ident.reference_id(), (symbol_id, e.property.name.to_string()));
+                } else {
+                    // Properties from the prototype.
+                    self.invalid.insert(symbol_id);
+                }
+                return;
+            }
+        }
+        walk::walk_expression(self, it);
+    }
+
+    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        // `o.a()` calls `a` with `this` set to `o`.
+        if let Some(symbol_id) = self.candidate_of(&it.callee) {
+            self.invalid.insert(symbol_id);
+        }
+        walk::walk_call_expression(self, it);
+    }
+
+    fn visit_tagged_template_expression(&mut self, it: &TaggedTemplateExpression<'a>) {
+        if let Some(symbol_id) = self.candidate_of(&it.tag) {
+            self.invalid.insert(symbol_id);
+        }
+        walk::walk_tagged_template_expression(self, it);
+    }
+
+    fn visit_unary_expression(&mut self, it: &UnaryExpression<'a>) {
+        if it.operator == UnaryOperator::Delete {
+            if let Some(symbol_id) = self.candidate_of(&it.argument) {
+                self.invalid.insert(symbol_id);
+            }
+        }
+        walk::walk_unary_expression(self, it);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+
+    use crate::{
+        CompressOptions, CompressOptionsKeepNames,
+        tester::{test_options, test_same_options, test_same_options_source_type},
+    };
+
+    fn options() -> CompressOptions {
+        CompressOptions { inline: 0, reduce_vars: false, ..CompressOptions::smallest() }
+    }
+
+    fn test(source_text: &str, expected: &str) {
+        test_options(source_text, expected, &options());
+    }
+
+    fn test_same(source_text: &str) {
+        test_same_options(source_text, &options());
+    }
+
+    #[test]
+    fn hoist_props() {
+        test(
+            "export function f() { let o = { a: 1, b: foo() }; bar(o.a, o.b) }",
+            "export function f() { let o_a = 1, o_b = foo(); bar(o_a, o_b) }",
+        );
+        test("let o = { 'b-c': foo(), a: 1 }; bar(o.a, o_0)", "foo(); let o_a = 1; bar(o_a, o_0)");
+        test("let o = { a: 1 }, o_a = 2; foo(o.a, o_a)", "let o_a1 = 1, o_a = 2; foo(o_a1, o_a)");
+        test(
+            "export function f() { let o = { a: () => o.b, b: 1 }; return o.a }",
+            "export function f() { let o_a = () => o_b, o_b = 1; return o_a }",
+        );
+    }
+
+    #[test]
+    fn keep_props() {
+        test_same("export function f() { let o = { a: 1 }; foo(o.a, o) }");
+        test_same("export function f() { let o = { a: 1 }; foo(o.a, o.b) }");
+        test_same("export function f() { let o = { a: foo }; o.a() }");
+        test_same("export function f() { let o = { a: 1 }; o.a = 2, foo(o.a) }");
+        test_same("export function f() { let o = { a: 1 }; o.a++, foo(o.a) }");
+        test_same("export function f() { let o = { a: 1 }; delete o.a, foo(o.a) }");
+        test_same("export function f() { let o = { get a() { return 1 } }; foo(o.a) }");
+        test_same("export function f() { let o = { a() { return this } }; foo(o.a) }");
+        test_same("export function f() { let o = { __proto__: null }; foo(o.__proto__) }");
+        test_same("export function f() { let o = { [a]: 1 }; foo(o.a) }");
+        test_same("export function f() { let o = { a: 1, a: 2 }; foo(o.a) }");
+        test_same("export function f() { let o = { ...a }; foo(o.a) }");
+        test_same("export let o = { a: 1 }; foo(o.a);");
+        test_same("export function f() { for (let o = { a: 1 }; ; ) foo(o.a) }");
+        test_same("export function f() { let o = { a: 1 }; eval(''), foo(o.a) }");
+        test_same_options_source_type("var o = { a: 1 }; foo(o.a);", SourceType::cjs(), &options());
+        let options = CompressOptions { hoist_props: false, ..options() };
+        test_same_options("export function f() { let o = { a: 1 }; foo(o.a) }", &options);
+    }
+
+    #[test]
+    fn keep_names() {
+        let options =
+            CompressOptions { keep_names: CompressOptionsKeepNames::function_only(), ..options() };
+        test_same_options(
+            "export function f() { let o = { a: function() {} }; foo(o.a) }",
+            &options,
+        );
+        test_same_options("export function f() { let o = { a: () => {} }; foo(o.a) }", &options);
+        test_options(
+            "export function f() { let o = { a: function b() {}, c: class {} }; foo(o.a, o.c) }",
+            "export function f() { let o_a = function b() {}, o_c = class {}; foo(o_a, o_c) }",
+            &options,
+        );
+        let options =
+            CompressOptions { keep_names: CompressOptionsKeepNames::class_only(), ..options };
+        test_same_options("export function f() { let o = { a: class {} }; foo(o.a) }", &options);
+        test_options(
+            "export function f() { let o = { a: class b {}, c: () => {} }; foo(o.a, o.c) }",
+            "export function f() { let o_a = class b {}, o_c = () => {}; foo(o_a, o_c) }",
+            &options,
+        );
+    }
+}
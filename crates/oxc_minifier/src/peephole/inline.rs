@@ -0,0 +1,1064 @@
+use oxc_allocator::{CloneIn, TakeIn, Vec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_ecmascript::{constant_evaluation::ConstantValue, side_effects::MayHaveSideEffects};
+use oxc_semantic::{ScopeFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_traverse::Ancestor;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{CompressOptionsUnused, ctx::Ctx, state::InlineFunction};
+
+use super::PeepholeOptimizations;
+
+/// Inline Variables and Functions
+///
+/// * `const x = 1; f(x)` -> `f(1)`
+/// * `let x = g(); f(x)` -> `f(g())`
+/// * `function f(a) { return a + 1 } f(1)` -> `1 + 1`
+///
+/// The declarations left without references are removed by the unused code removal.
+///
+/// <https://github.com/google/closure-compiler/blob/v20240609/src/com/google/javascript/jscomp/InlineVariables.java>
+/// <https://github.com/google/closure-compiler/blob/v20240609/src/com/google/javascript/jscomp/InlineFunctions.java>
+impl<'a> PeepholeOptimizations {
+    /// Whether the inlined declarations are going to be removed.
+    fn is_inlining_allowed(ctx: &Ctx<'a, '_>) -> bool {
+        ctx.state.options.unused != CompressOptionsUnused::Keep
+            && !ctx.scoping().root_scope_flags().contains_direct_eval()
+    }
+
+    /// Whether `let` bindings which are never reassigned can be treated as constants.
+    pub fn can_reduce_vars(ctx: &Ctx<'a, '_>) -> bool {
+        ctx.state.options.reduce_vars && Self::is_inlining_allowed(ctx)
+    }
+
+    /// `let x = 1` which is never reassigned, and is not a global variable of a script.
+    pub fn is_never_reassigned(id: &BindingPattern<'a>, ctx: &Ctx<'a, '_>) -> bool {
+        let BindingPatternKind::BindingIdentifier(ident) = &id.kind else { return false };
+        let symbol_id = ident.symbol_id();
+        if ctx.is_top_level_global()
+            && ctx.scoping().symbol_scope_id(symbol_id) == ctx.scoping().root_scope_id()
+        {
+            return false;
+        }
+        ctx.scoping()
+            .get_resolved_references(symbol_id)
+            .all(|reference| reference.flags().is_read_only())
+    }
+
+    /// `const x = 1; f(x)` -> `f(1)`
+    ///
+    /// Only values which are not larger than the identifier after mangling are substituted,
+    /// unless the binding is referenced once.
+    pub fn inline_constant_identifier(&self, expr: &mut Expression<'a>, ctx: &mut Ctx<'a, '_>) {
+        if !Self::can_reduce_vars(ctx) {
+            return;
+        }
+        let Expression::Identifier(ident) = expr else { return };
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let Some(symbol_id) = ctx.scoping().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        if ctx.state.tdz_symbols.contains(&symbol_id) {
+            return;
+        }
+        let Some(value) = ctx.state.constant_values.get(&symbol_id) else { return };
+        let is_small = match value {
+            ConstantValue::Number(n) => n.fract() == 0.0 && n.abs() < 1000.0,
+            ConstantValue::Boolean(_) | ConstantValue::Null | ConstantValue::Undefined => true,
+            ConstantValue::String(s) => s.len() <= 3,
+            ConstantValue::BigInt(_) => false,
+        };
+        if !is_small && ctx.scoping().get_resolved_reference_ids(symbol_id).len() > 1 {
+            return;
+        }
+        match ctx.parent() {
+            // `delete x` returns `false`, `delete 1` returns `true`.
+            Ancestor::UnaryExpressionArgument(e) if e.operator().is_delete() => return,
+            // Keep `{ x }`.
+            Ancestor::ObjectPropertyValue(prop) if *prop.shorthand() => return,
+            _ => {}
+        }
+        let value = value.clone();
+        *expr = ctx.value_to_expr(ident.span, value);
+        ctx.state.changed = true;
+    }
+
+    /// `let x = g(); f(x)` -> `f(g())`
+    ///
+    /// A `const` or `let` binding which is referenced once in the following statement is moved into
+    /// the reference, if nothing evaluated before the reference can observe the reordering.
+    pub fn inline_single_use_variables(
+        &self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        if !Self::can_reduce_vars(ctx) || Self::keep_top_level_var_in_script_mode(ctx) {
+            return;
+        }
+        if !stmts.iter().any(
+            |stmt| matches!(stmt, Statement::VariableDeclaration(decl) if decl.kind.is_lexical()),
+        ) {
+            return;
+        }
+        let mut result = ctx.ast.vec_with_capacity(stmts.len());
+        for stmt in stmts.drain(..) {
+            result.push(stmt);
+            while result.len() >= 2 {
+                let len = result.len();
+                let (prev, next) = result.split_at_mut(len - 1);
+                let prev = prev.last_mut().unwrap();
+                if !Self::inline_last_declarator(prev, &mut next[0], ctx) {
+                    break;
+                }
+                ctx.state.changed = true;
+                if matches!(prev, Statement::VariableDeclaration(decl) if decl.declarations.is_empty())
+                {
+                    let index = result.len() - 2;
+                    result.remove(index);
+                }
+            }
+        }
+        *stmts = result;
+    }
+
+    fn inline_last_declarator(
+        prev: &mut Statement<'a>,
+        next: &mut Statement<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> bool {
+        let Statement::VariableDeclaration(decl) = prev else { return false };
+        if !matches!(decl.kind, VariableDeclarationKind::Const | VariableDeclarationKind::Let) {
+            return false;
+        }
+        let Some(declarator) = decl.declarations.last_mut() else { return false };
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+            return false;
+        };
+        let Some(init) = &declarator.init else { return false };
+        let symbol_id = ident.symbol_id();
+        if !Self::is_single_read_reference(symbol_id, ctx.scoping()) {
+            return false;
+        }
+        // `let f = function() {}` sets the `name` of the function.
+        let keep_names = ctx.options().keep_names;
+        if init.is_anonymous_function_definition() && (keep_names.function || keep_names.class) {
+            return false;
+        }
+
+        let mut substitution = SingleUseSubstitution {
+            target: symbol_id,
+            init_has_side_effects: init.may_have_side_effects(ctx),
+            init_is_member_expression: matches!(
+                init.without_parentheses(),
+                Expression::ChainExpression(_)
+            ) || init.without_parentheses().is_member_expression(),
+            init: declarator.init.take(),
+        };
+        if substitution.statement(next, ctx) == Substitution::Done {
+            decl.declarations.pop();
+            true
+        } else {
+            decl.declarations.last_mut().unwrap().init = substitution.init;
+            false
+        }
+    }
+
+    fn is_single_read_reference(symbol_id: SymbolId, scoping: &Scoping) -> bool {
+        let mut references = scoping.get_resolved_references(symbol_id);
+        references.next().is_some_and(|reference| reference.flags().is_read_only())
+            && references.next().is_none()
+            && scoping.symbol_redeclarations(symbol_id).is_empty()
+    }
+
+    /// Collect `let` and `const` bindings which may be read in their temporal dead zone,
+    /// e.g. `g(); let x = 1; function g() { return x }`.
+    ///
+    /// Their values must not be inlined, as the read throws a `ReferenceError`.
+    pub fn collect_tdz_symbols(program: &Program<'a>, ctx: &mut Ctx<'a, '_>) {
+        ctx.state.tdz_symbols.clear();
+        if !Self::can_reduce_vars(ctx) && ctx.options().inline == 0 {
+            return;
+        }
+        let mut collector = TdzCollector {
+            scoping: ctx.scoping(),
+            position: 0,
+            functions: FxHashMap::default(),
+            references: FxHashMap::default(),
+            declarations: vec![],
+            function_stack: vec![],
+            switch_scopes: vec![],
+            in_export: false,
+        };
+        collector.visit_program(program);
+        ctx.state.tdz_symbols = collector.into_tdz_symbols();
+    }
+
+    /// Collect function declarations which can be inlined into their only call site.
+    pub fn collect_inline_functions(program: &Program<'a>, ctx: &mut Ctx<'a, '_>) {
+        ctx.state.inline_functions.clear();
+        let level = ctx.options().inline;
+        if level == 0 || !Self::is_inlining_allowed(ctx) {
+            return;
+        }
+        let mut collector = InlineFunctionCollector {
+            scoping: ctx.scoping(),
+            allocator: ctx.ast.allocator,
+            level,
+            keep_top_level: ctx.is_top_level_global(),
+            functions: FxHashMap::default(),
+        };
+        collector.visit_program(program);
+        ctx.state.inline_functions = collector.functions;
+    }
+
+    /// `function f(a) { return a + 1 } f(1)` -> `1 + 1`
+    pub fn inline_function_call(&self, expr: &mut Expression<'a>, ctx: &mut Ctx<'a, '_>) {
+        if ctx.state.inline_functions.is_empty() {
+            return;
+        }
+        let Expression::CallExpression(call) = expr else { return };
+        if call.optional || call.arguments.iter().any(Argument::is_spread) {
+            return;
+        }
+        let Expression::Identifier(ident) = &call.callee else { return };
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let Some(symbol_id) = ctx.scoping().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        let Some(func) = ctx.state.inline_functions.get(&symbol_id) else { return };
+
+        for (i, arg) in call.arguments.iter().enumerate() {
+            let arg = arg.to_expression();
+            let is_inlinable = match func.params.get(i) {
+                // Arguments without parameter are dropped.
+                None => !arg.may_have_side_effects(ctx),
+                Some(&param) => match ctx.scoping().get_resolved_reference_ids(param).len() {
+                    0 => !arg.may_have_side_effects(ctx),
+                    1 => Self::is_immutable_value(arg, ctx),
+                    _ => Self::is_primitive_literal(arg, ctx),
+                },
+            };
+            if !is_inlinable {
+                return;
+            }
+        }
+        if let Some(body) = &func.body {
+            let mut checker = FreeReferenceChecker {
+                scoping: ctx.scoping(),
+                scope_id: ctx.current_scope_id(),
+                params: &func.params,
+                tdz_symbols: &ctx.state.tdz_symbols,
+                is_valid: true,
+            };
+            checker.visit_expression(body);
+            if !checker.is_valid {
+                return;
+            }
+        }
+
+        let func = ctx.state.inline_functions.remove(&symbol_id).unwrap();
+        let span = call.span;
+        let mut arguments = call.arguments.take_in(ctx.ast).into_iter();
+        let mut values = FxHashMap::default();
+        for param in func.params {
+            let value =
+                arguments.next().map_or_else(|| ctx.ast.void_0(span), Argument::into_expression);
+            values.insert(param, value);
+        }
+        *expr = match func.body {
+            Some(mut body) => {
+                InlineBodyRewriter { ctx, values }.visit_expression(&mut body);
+                body
+            }
+            None => ctx.ast.void_0(span),
+        };
+        ctx.state.changed = true;
+    }
+
+    fn is_primitive_literal(expr: &Expression<'a>, ctx: &Ctx<'a, '_>) -> bool {
+        matches!(
+            expr,
+            Expression::BooleanLiteral(_)
+                | Expression::NullLiteral(_)
+                | Expression::NumericLiteral(_)
+                | Expression::BigIntLiteral(_)
+                | Expression::StringLiteral(_)
+        ) || ctx.is_expression_undefined(expr)
+    }
+
+    /// Whether `expr` evaluates to the same value regardless of when it is evaluated.
+    fn is_immutable_value(expr: &Expression<'a>, ctx: &Ctx<'a, '_>) -> bool {
+        if Self::is_primitive_literal(expr, ctx) {
+            return true;
+        }
+        let Expression::Identifier(ident) = expr else { return false };
+        let Some(reference_id) = ident.reference_id.get() else { return false };
+        ctx.scoping().get_reference(reference_id).symbol_id().is_some_and(|symbol_id| {
+            ctx.scoping()
+                .get_resolved_references(symbol_id)
+                .all(|reference| reference.flags().is_read_only())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Substitution {
+    /// The reference was not found yet, and the walk can continue.
+    Continue,
+    /// The reference was replaced.
+    Done,
+    /// The reference cannot be replaced.
+    Abort,
+}
+
+/// Replaces the single reference of `target` with `init`, walking in evaluation order.
+struct SingleUseSubstitution<'a> {
+    target: SymbolId,
+    init: Option<Expression<'a>>,
+    init_has_side_effects: bool,
+    init_is_member_expression: bool,
+}
+
+impl<'a> SingleUseSubstitution<'a> {
+    fn statement(&mut self, stmt: &mut Statement<'a>, ctx: &Ctx<'a, '_>) -> Substitution {
+        match stmt {
+            Statement::ExpressionStatement(stmt) => self.expression(&mut stmt.expression, ctx),
+            Statement::ReturnStatement(stmt) => match &mut stmt.argument {
+                Some(argument) => self.expression(argument, ctx),
+                None => Substitution::Abort,
+            },
+            Statement::ThrowStatement(stmt) => self.expression(&mut stmt.argument, ctx),
+            Statement::IfStatement(stmt) => self.expression(&mut stmt.test, ctx),
+            Statement::SwitchStatement(stmt) => self.expression(&mut stmt.discriminant, ctx),
+            Statement::VariableDeclaration(decl) => {
+                for declarator in &mut decl.declarations {
+                    if let Some(init) = &mut declarator.init {
+                        let result = self.expression(init, ctx);
+                        if result != Substitution::Continue {
+                            return result;
+                        }
+                    }
+                    // Destructuring may call getters and iterators.
+                    if !declarator.id.kind.is_binding_identifier() {
+                        return Substitution::Abort;
+                    }
+                }
+                Substitution::Abort
+            }
+            _ => Substitution::Abort,
+        }
+    }
+
+    fn expression(&mut self, expr: &mut Expression<'a>, ctx: &Ctx<'a, '_>) -> Substitution {
+        let result = match expr {
+            Expression::Identifier(ident) => {
+                let symbol_id = ident
+                    .reference_id
+                    .get()
+                    .and_then(|reference_id| ctx.scoping().get_reference(reference_id).symbol_id());
+                if symbol_id == Some(self.target) {
+                    *expr = self.init.take().unwrap();
+                    return Substitution::Done;
+                }
+                Substitution::Continue
+            }
+            Expression::ParenthesizedExpression(e) => {
+                return self.expression(&mut e.expression, ctx);
+            }
+            Expression::UnaryExpression(e) => {
+                if e.operator.is_delete() {
+                    return Substitution::Abort;
+                }
+                self.expression(&mut e.argument, ctx)
+            }
+            Expression::BinaryExpression(e) => {
+                let e = &mut **e;
+                self.pair(&mut e.left, &mut e.right, ctx)
+            }
+            Expression::LogicalExpression(e) => {
+                // The right side is evaluated conditionally.
+                match self.expression(&mut e.left, ctx) {
+                    Substitution::Continue if self.can_skip(&e.right, ctx) => {
+                        Substitution::Continue
+                    }
+                    Substitution::Continue => Substitution::Abort,
+                    result => result,
+                }
+            }
+            Expression::ConditionalExpression(e) => match self.expression(&mut e.test, ctx) {
+                Substitution::Continue
+                    if self.can_skip(&e.consequent, ctx) && self.can_skip(&e.alternate, ctx) =>
+                {
+                    Substitution::Continue
+                }
+                Substitution::Continue => Substitution::Abort,
+                result => result,
+            },
+            Expression::SequenceExpression(e) => self.expressions(e.expressions.iter_mut(), ctx),
+            Expression::StaticMemberExpression(e) => self.expression(&mut e.object, ctx),
+            Expression::ComputedMemberExpression(e) => {
+                let e = &mut **e;
+                self.pair(&mut e.object, &mut e.expression, ctx)
+            }
+            Expression::CallExpression(e) => {
+                if e.optional {
+                    return Substitution::Abort;
+                }
+                // `let x = a.b; x()` -> `a.b()` would change `this`.
+                if self.init_is_member_expression && matches!(e.callee, Expression::Identifier(_)) {
+                    return Substitution::Abort;
+                }
+                // Calling a global or a constant function, e.g. `let x = foo(); bar(x)`.
+                let is_callee_skippable = match &e.callee {
+                    Expression::Identifier(ident) => {
+                        ctx.is_global_reference(ident)
+                            || PeepholeOptimizations::is_immutable_value(&e.callee, ctx)
+                    }
+                    _ => false,
+                };
+                if !is_callee_skippable {
+                    let result = self.expression(&mut e.callee, ctx);
+                    if result != Substitution::Continue {
+                        return result;
+                    }
+                }
+                self.arguments(&mut e.arguments, ctx)
+            }
+            Expression::NewExpression(e) => {
+                let result = self.expression(&mut e.callee, ctx);
+                if result != Substitution::Continue {
+                    return result;
+                }
+                self.arguments(&mut e.arguments, ctx)
+            }
+            Expression::ArrayExpression(e) => {
+                for element in &mut e.elements {
+                    let result = match element {
+                        ArrayExpressionElement::SpreadElement(_) => Substitution::Abort,
+                        ArrayExpressionElement::Elision(_) => Substitution::Continue,
+                        match_expression!(ArrayExpressionElement) => {
+                            self.expression(element.to_expression_mut(), ctx)
+                        }
+                    };
+                    if result != Substitution::Continue {
+                        return result;
+                    }
+                }
+                Substitution::Continue
+            }
+            Expression::ObjectExpression(e) => {
+                for property in &mut e.properties {
+                    let result = match property {
+                        ObjectPropertyKind::ObjectProperty(p) if !p.computed && !p.shorthand => {
+                            self.expression(&mut p.value, ctx)
+                        }
+                        _ => Substitution::Abort,
+                    };
+                    if result != Substitution::Continue {
+                        return result;
+                    }
+                }
+                Substitution::Continue
+            }
+            Expression::TemplateLiteral(e) => self.expressions(e.expressions.iter_mut(), ctx),
+            Expression::AssignmentExpression(e) => {
+                if e.operator != AssignmentOperator::Assign
+                    || !matches!(e.left, AssignmentTarget::AssignmentTargetIdentifier(_))
+                {
+                    return Substitution::Abort;
+                }
+                let result = self.expression(&mut e.right, ctx);
+                // The assignment may change the value of `init`.
+                if result == Substitution::Continue { Substitution::Abort } else { result }
+            }
+            _ => Substitution::Continue,
+        };
+        match result {
+            Substitution::Continue if self.can_skip(expr, ctx) => Substitution::Continue,
+            Substitution::Continue => Substitution::Abort,
+            result => result,
+        }
+    }
+
+    fn pair(
+        &mut self,
+        left: &mut Expression<'a>,
+        right: &mut Expression<'a>,
+        ctx: &Ctx<'a, '_>,
+    ) -> Substitution {
+        match self.expression(left, ctx) {
+            Substitution::Continue => self.expression(right, ctx),
+            result => result,
+        }
+    }
+
+    fn expressions<'e>(
+        &mut self,
+        exprs: impl Iterator<Item = &'e mut Expression<'a>>,
+        ctx: &Ctx<'a, '_>,
+    ) -> Substitution
+    where
+        'a: 'e,
+    {
+        for expr in exprs {
+            let result = self.expression(expr, ctx);
+            if result != Substitution::Continue {
+                return result;
+            }
+        }
+        Substitution::Continue
+    }
+
+    fn arguments(
+        &mut self,
+        arguments: &mut Vec<'a, Argument<'a>>,
+        ctx: &Ctx<'a, '_>,
+    ) -> Substitution {
+        for argument in arguments {
+            let result = match argument {
+                Argument::SpreadElement(_) => Substitution::Abort,
+                match_expression!(Argument) => self.expression(argument.to_expression_mut(), ctx),
+            };
+            if result != Substitution::Continue {
+                return result;
+            }
+        }
+        // The call may change the value of `init`.
+        Substitution::Abort
+    }
+
+    /// Whether `expr` can be evaluated before `init` without changing the result.
+    fn can_skip(&self, expr: &Expression<'a>, ctx: &Ctx<'a, '_>) -> bool {
+        !expr.may_have_side_effects(ctx)
+            && (!self.init_has_side_effects || PeepholeOptimizations::is_immutable_value(expr, ctx))
+    }
+}
+
+struct InlineFunctionCollector<'a, 's> {
+    scoping: &'s Scoping,
+    allocator: &'a oxc_allocator::Allocator,
+    level: u8,
+    /// Top level functions in scripts are globals.
+    keep_top_level: bool,
+    functions: FxHashMap<SymbolId, InlineFunction<'a>>,
+}
+
+impl<'a> Visit<'a> for InlineFunctionCollector<'a, '_> {
+    fn visit_export_named_declaration(&mut self, decl: &ExportNamedDeclaration<'a>) {
+        // Exported functions are not removed after inlining.
+        if let Some(Declaration::FunctionDeclaration(func)) = &decl.declaration {
+            if let Some(body) = &func.body {
+                self.visit_function_body(body);
+            }
+            return;
+        }
+        walk::walk_export_named_declaration(self, decl);
+    }
+
+    fn visit_export_default_declaration(&mut self, decl: &ExportDefaultDeclaration<'a>) {
+        if let ExportDefaultDeclarationKind::FunctionDeclaration(func) = &decl.declaration {
+            if let Some(body) = &func.body {
+                self.visit_function_body(body);
+            }
+            return;
+        }
+        walk::walk_export_default_declaration(self, decl);
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        if let Some((symbol_id, function)) = self.inline_function(func) {
+            self.functions.insert(symbol_id, function);
+        }
+        walk::walk_function(self, func, flags);
+    }
+}
+
+impl<'a> InlineFunctionCollector<'a, '_> {
+    fn inline_function(&self, func: &Function<'a>) -> Option<(SymbolId, InlineFunction<'a>)> {
+        if !func.is_declaration() || func.r#async || func.generator {
+            return None;
+        }
+        let symbol_id = func.id.as_ref()?.symbol_id();
+        let scope_id = self.scoping.symbol_scope_id(symbol_id);
+        // Functions declared in blocks have different semantics in sloppy mode.
+        if !self.scoping.scope_flags(scope_id).is_var()
+            || (self.keep_top_level && scope_id == self.scoping.root_scope_id())
+        {
+            return None;
+        }
+        if !PeepholeOptimizations::is_single_read_reference(symbol_id, self.scoping) {
+            return None;
+        }
+
+        if func.params.rest.is_some() || (self.level < 2 && !func.params.items.is_empty()) {
+            return None;
+        }
+        let mut params = vec![];
+        for param in &func.params.items {
+            let BindingPatternKind::BindingIdentifier(ident) = &param.pattern.kind else {
+                return None;
+            };
+            let param_id = ident.symbol_id();
+            let mut count = 0;
+            for reference in self.scoping.get_resolved_references(param_id) {
+                if !reference.flags().is_read_only() {
+                    return None;
+                }
+                count += 1;
+            }
+            if self.level < 3 && count > 1 {
+                return None;
+            }
+            params.push(param_id);
+        }
+
+        let body = func.body.as_ref()?;
+        if !body.directives.is_empty() {
+            return None;
+        }
+        let expr = match body.statements.as_slice() {
+            [] => None,
+            [Statement::ReturnStatement(stmt)] => stmt.argument.as_ref(),
+            _ => return None,
+        };
+        if let Some(expr) = expr {
+            let mut checker =
+                InlineBodyChecker { function: symbol_id, scoping: self.scoping, is_valid: true };
+            checker.visit_expression(expr);
+            if !checker.is_valid {
+                return None;
+            }
+        }
+        let body = expr.map(|expr| expr.clone_in_with_semantic_ids(self.allocator));
+        Some((symbol_id, InlineFunction { params, body }))
+    }
+}
+
+/// Checks that the returned expression does not depend on the function it is in.
+struct InlineBodyChecker<'s> {
+    function: SymbolId,
+    scoping: &'s Scoping,
+    is_valid: bool,
+}
+
+impl<'a> Visit<'a> for InlineBodyChecker<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let symbol_id = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| self.scoping.get_reference(reference_id).symbol_id());
+        if ident.name == "arguments" || symbol_id == Some(self.function) {
+            self.is_valid = false;
+        }
+    }
+
+    fn visit_object_property(&mut self, prop: &ObjectProperty<'a>) {
+        if prop.shorthand {
+            self.is_valid = false;
+        }
+        walk::walk_object_property(self, prop);
+    }
+
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.is_valid = false;
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        self.is_valid = false;
+    }
+
+    fn visit_meta_property(&mut self, _it: &MetaProperty<'a>) {
+        self.is_valid = false;
+    }
+
+    fn visit_private_identifier(&mut self, _it: &PrivateIdentifier<'a>) {
+        self.is_valid = false;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {
+        self.is_valid = false;
+    }
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {
+        self.is_valid = false;
+    }
+
+    fn visit_class(&mut self, _it: &Class<'a>) {
+        self.is_valid = false;
+    }
+}
+
+/// Checks that the references in the inlined expression resolve to the same bindings at the call site.
+struct FreeReferenceChecker<'s> {
+    scoping: &'s Scoping,
+    scope_id: ScopeId,
+    params: &'s [SymbolId],
+    /// Moving a read of these bindings may remove a `ReferenceError`.
+    tdz_symbols: &'s FxHashSet<SymbolId>,
+    is_valid: bool,
+}
+
+impl<'a> Visit<'a> for FreeReferenceChecker<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let symbol_id = self.scoping.get_reference(reference_id).symbol_id();
+        if symbol_id.is_some_and(|symbol_id| self.params.contains(&symbol_id)) {
+            return;
+        }
+        if self.scoping.find_binding(self.scope_id, &ident.name) != symbol_id
+            || symbol_id.is_some_and(|symbol_id| self.tdz_symbols.contains(&symbol_id))
+        {
+            self.is_valid = false;
+        }
+    }
+}
+
+/// Substitutes the parameters with the arguments, and creates new references for the copied identifiers.
+struct InlineBodyRewriter<'a, 'c, 'b> {
+    ctx: &'c mut Ctx<'a, 'b>,
+    values: FxHashMap<SymbolId, Expression<'a>>,
+}
+
+impl<'a> VisitMut<'a> for InlineBodyRewriter<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr {
+            let symbol_id = ident.reference_id.get().and_then(|reference_id| {
+                self.ctx.scoping().get_reference(reference_id).symbol_id()
+            });
+            if let Some(value) = symbol_id.and_then(|symbol_id| self.values.get(&symbol_id)) {
+                *expr = value.clone_in_with_semantic_ids(self.ctx.ast.allocator);
+                return;
+            }
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let reference = self.ctx.scoping().get_reference(reference_id);
+        let (symbol_id, flags) = (reference.symbol_id(), reference.flags());
+        let reference_id = match symbol_id {
+            Some(symbol_id) => self.ctx.create_bound_reference(symbol_id, flags),
+            None => self.ctx.create_unbound_reference(&ident.name, flags),
+        };
+        ident.reference_id.set(Some(reference_id));
+    }
+}
+
+/// A function declaration, which is hoisted and can be called before it appears.
+struct HoistedFunction {
+    /// Positions of the start and the end of the function.
+    range: (u32, u32),
+    /// Exported functions can be called by a cyclic import before the module is evaluated.
+    exported: bool,
+}
+
+/// Finds `let` and `const` bindings with a reference which may be evaluated before the declaration.
+///
+/// A reference is evaluated at its position, unless it is inside a function declaration
+/// which does not contain the declaration. The function may then be called at any of its references.
+///
+/// Positions are counted in visiting order, because the spans of inlined code are not in order.
+struct TdzCollector<'s> {
+    scoping: &'s Scoping,
+    position: u32,
+    functions: FxHashMap<SymbolId, HoistedFunction>,
+    /// Position of each reference to a lexical binding or function, and the function declaration it is in.
+    references: FxHashMap<SymbolId, std::vec::Vec<(u32, Option<SymbolId>)>>,
+    /// `let` and `const` declarators with the position of their end.
+    declarations: std::vec::Vec<(SymbolId, u32)>,
+    function_stack: std::vec::Vec<SymbolId>,
+    switch_scopes: std::vec::Vec<ScopeId>,
+    in_export: bool,
+}
+
+impl<'a> Visit<'a> for TdzCollector<'_> {
+    fn visit_export_named_declaration(&mut self, decl: &ExportNamedDeclaration<'a>) {
+        if let Some(Declaration::FunctionDeclaration(func)) = &decl.declaration {
+            self.visit_function(func, ScopeFlags::Function);
+            self.mark_exported(func);
+            return;
+        }
+        self.in_export = decl.source.is_none() && decl.declaration.is_none();
+        walk::walk_export_named_declaration(self, decl);
+        self.in_export = false;
+    }
+
+    fn visit_export_default_declaration(&mut self, decl: &ExportDefaultDeclaration<'a>) {
+        self.in_export = true;
+        walk::walk_export_default_declaration(self, decl);
+        self.in_export = false;
+        if let ExportDefaultDeclarationKind::FunctionDeclaration(func) = &decl.declaration {
+            self.mark_exported(func);
+        }
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        let in_export = std::mem::replace(&mut self.in_export, false);
+        let symbol_id =
+            func.id.as_ref().filter(|_| func.is_declaration()).map(BindingIdentifier::symbol_id);
+        if let Some(symbol_id) = symbol_id {
+            self.function_stack.push(symbol_id);
+        }
+        let start = self.next_position();
+        walk::walk_function(self, func, flags);
+        let end = self.next_position();
+        if let Some(symbol_id) = symbol_id {
+            self.function_stack.pop();
+            self.functions
+                .insert(symbol_id, HoistedFunction { range: (start, end), exported: false });
+        }
+        self.in_export = in_export;
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &SwitchStatement<'a>) {
+        // A `case` can jump over a declaration in a previous `case`.
+        self.switch_scopes.push(stmt.scope_id());
+        walk::walk_switch_statement(self, stmt);
+    }
+
+    fn visit_variable_declarator(&mut self, decl: &VariableDeclarator<'a>) {
+        walk::walk_variable_declarator(self, decl);
+        if decl.kind.is_lexical() {
+            if let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind {
+                let position = self.next_position();
+                self.declarations.push((ident.symbol_id(), position));
+            }
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let Some(symbol_id) = self.scoping.get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        let flags = self.scoping.symbol_flags(symbol_id);
+        let entry = if self.in_export {
+            // `export { x }` does not read `x`, but `export { f }` allows calling `f` at any time.
+            if !flags.is_function() {
+                return;
+            }
+            (0, None)
+        } else {
+            if !flags.intersects(SymbolFlags::BlockScopedVariable | SymbolFlags::Function) {
+                return;
+            }
+            (self.next_position(), self.function_stack.last().copied())
+        };
+        self.references.entry(symbol_id).or_default().push(entry);
+    }
+}
+
+impl TdzCollector<'_> {
+    fn next_position(&mut self) -> u32 {
+        self.position += 1;
+        self.position
+    }
+
+    fn mark_exported(&mut self, func: &Function<'_>) {
+        if let Some(function) =
+            func.id.as_ref().and_then(|id| self.functions.get_mut(&id.symbol_id()))
+        {
+            function.exported = true;
+        }
+    }
+
+    fn into_tdz_symbols(self) -> FxHashSet<SymbolId> {
+        let mut tdz_symbols = FxHashSet::default();
+        for &(symbol_id, declaration) in &self.declarations {
+            let scope_id = self.scoping.symbol_scope_id(symbol_id);
+            let is_tdz = self.switch_scopes.contains(&scope_id)
+                || self.references.get(&symbol_id).is_some_and(|references| {
+                    references.iter().any(|&(position, function)| {
+                        self.earliest_evaluation(position, function, declaration, &mut vec![])
+                            < declaration
+                    })
+                });
+            if is_tdz {
+                tdz_symbols.insert(symbol_id);
+            }
+        }
+        tdz_symbols
+    }
+
+    /// The earliest position at which a reference in `function` at `position` can be evaluated,
+    /// relative to the declaration at `declaration`. `u32::MAX` if it is never evaluated.
+    fn earliest_evaluation(
+        &self,
+        position: u32,
+        function: Option<SymbolId>,
+        declaration: u32,
+        visiting: &mut std::vec::Vec<SymbolId>,
+    ) -> u32 {
+        let Some(function) = function else { return position };
+        let hoisted = &self.functions[&function];
+        if (hoisted.range.0..hoisted.range.1).contains(&declaration) {
+            return position;
+        }
+        if hoisted.exported {
+            return 0;
+        }
+        if visiting.contains(&function) {
+            return u32::MAX;
+        }
+        visiting.push(function);
+        let earliest = self.references.get(&function).map_or(u32::MAX, |references| {
+            references
+                .iter()
+                .map(|&(position, function)| {
+                    self.earliest_evaluation(position, function, declaration, visiting)
+                })
+                .min()
+                .unwrap_or(u32::MAX)
+        });
+        visiting.pop();
+        earliest
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+
+    use crate::{
+        CompressOptions,
+        tester::{test_options, test_same_options, test_same_options_source_type},
+    };
+
+    fn test(source_text: &str, expected: &str) {
+        test_options(source_text, expected, &CompressOptions::smallest());
+    }
+
+    fn test_same(source_text: &str) {
+        test_same_options(source_text, &CompressOptions::smallest());
+    }
+
+    #[test]
+    fn inline_constants() {
+        test("const x = 1; foo(x, x)", "foo(1, 1)");
+        test(
+            "export function f() { let x = true; foo(x); bar(x) }",
+            "export function f() { foo(!0), bar(!0) }",
+        );
+        test(
+            "export function f() { const s = 'abc'; foo(s, s) }",
+            "export function f() { foo('abc', 'abc') }",
+        );
+        test_same("export function f() { let s = 'abcd'; foo(s, s) }");
+        test_same("export function f() { let x = 1; foo(x), x = 2, foo(x, x) }");
+        test_same("export function f() { let x = 1; foo({ x }) }");
+        test_same("export function f() { let x = 1; delete x }");
+        test("const x = 1; export { x }; foo(x)", "const x = 1; export { x }; foo(1)");
+        test_same_options_source_type(
+            "let x = 1; foo(x)",
+            SourceType::cjs(),
+            &CompressOptions::smallest(),
+        );
+    }
+
+    #[test]
+    fn temporal_dead_zone() {
+        test_same("export function t1() { g(); let x = 1; function g() { return x } }");
+        test_same("export function t2() { g(); let x = 1; function g() { return foo(x) } }");
+        test(
+            "export function t3() { h(); let x = 1; function g() { return x } function h() { return g() } }",
+            "export function t3() { g(); let x = 1; function g() { return x } }",
+        );
+        test_same("export function t4() { foo(x); let x = 1; bar(x) }");
+        test_same("export function t5() { let f = () => x; f(); let x = 1 }");
+        test_same(
+            "export function t6(a) { switch (a) { case 0: let x = 1; break; case 1: foo(x) } }",
+        );
+        test_same("export function g() { return x } const x = 1");
+        test(
+            "export function t7() { let x = 1; g(); function g() { return foo(x) } }",
+            "export function t7() { foo(1) }",
+        );
+        test(
+            "export function t8() { let x = 1; return () => x }",
+            "export function t8() { return () => 1 }",
+        );
+    }
+
+    #[test]
+    fn inline_single_use_variables() {
+        test(
+            "export function f() { let x = foo(); return bar(x) }",
+            "export function f() { return bar(foo()) }",
+        );
+        test("export function f() { let x = foo(); bar(x) }", "export function f() { bar(foo()) }");
+        test(
+            "export function f() { const x = a + b; if (x) bar() }",
+            "export function f() { a + b && bar() }",
+        );
+        test(
+            "export function f() { let x = foo(), y = bar(); baz(x, y) }",
+            "export function f() { baz(foo(), bar()) }",
+        );
+        // Side effects are not reordered.
+        test_same("export function f() { let x = foo(); bar(y, x) }");
+        test_same("export function f() { let x = y; bar(), baz(x) }");
+        test_same("export function f() { let x = foo(); bar(), baz(x) }");
+        test_same("export function f() { let x = foo(); y && baz(x) }");
+        test_same("export function f() { let x = foo(); return () => x }");
+        // `this` of the call.
+        test_same("export function f() { let x = a.b; x() }");
+        test_same("export function f() { let x = foo(); bar(x, x) }");
+        test_same("var x = foo(); bar(x)");
+    }
+
+    #[test]
+    fn inline_functions() {
+        test("function f() { return foo() } f()", "foo()");
+        test("function f() {} f()", "");
+        test("function f(a, b) { return a + b } x = f(1, 2)", "x = 3");
+        test("function f(a) { return a + a } x = f(1)", "x = 2");
+        test("function f(a, b) { return foo(a, b) } f(1)", "foo(1, void 0)");
+        test_same("function f(a) { return foo(a) } f(bar())");
+        test_same("function f() { return this } f()");
+        test_same("function f() { return arguments } f()");
+        test_same("function f() { return foo() } f(), f()");
+        test_same("export function f() { return foo() } f()");
+        test_same(
+            "function f() { return x } export function g() { let x = foo(); return f() + x }",
+        );
+        test("function f() { foo(); return bar() } f()", "foo(), bar()");
+        test_same("function f() { for (;;) foo() } f()");
+        test_same_options(
+            "function f() { return foo() } f()",
+            &CompressOptions { inline: 0, ..CompressOptions::smallest() },
+        );
+        test_same_options(
+            "function f(a) { return foo(a) } f(1)",
+            &CompressOptions { inline: 1, ..CompressOptions::smallest() },
+        );
+    }
+
+    #[test]
+    fn passes() {
+        let source_text = "export function f() { let x = 1; let y = x; return y }";
+        test_options(
+            source_text,
+            "export function f() { let x = 1, y = 1; return 1 }",
+            &CompressOptions { passes: 1, ..CompressOptions::smallest() },
+        );
+        test_options(source_text, "export function f() { return 1 }", &CompressOptions::smallest());
+    }
+
+    #[test]
+    fn passes_above_default_limit() {
+        // Only the innermost function can be inlined in each pass, as the others are not in scope.
+        // This converges after 13 passes, more than the limit when `passes` is `0`.
+        let mut body = "return foo()".to_string();
+        for i in (2..=6).rev() {
+            body = format!("return f{i}(); function f{i}() {{ {body} }}");
+        }
+        let source_text = format!("function f1() {{ {body} }} f1()");
+        test_options(
+            &source_text,
+            "foo()",
+            &CompressOptions { passes: 15, ..CompressOptions::smallest() },
+        );
+    }
+}
@@ -0,0 +1,668 @@
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_semantic::{ReferenceId, Scoping, Semantic, SemanticBuilder, SymbolId};
+use oxc_span::Atom;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Inline Export Helpers
+///
+/// Replaces the namespace objects of a scope-hoisted bundle, whose exports are defined by
+/// bundler helpers, with the exported variables.
+///
+/// `var ns = {}; __export(ns, { foo: () => foo }); bar(ns.foo)` -> `var ns = {}; bar(foo)`
+///
+/// The helper call must directly follow the declaration of the namespace object, and the namespace
+/// object must only be used for reading the exports. The following helpers are recognized:
+///
+/// * `__export(ns, { foo: () => foo })`
+/// * `Object.defineProperty(ns, "foo", { get: () => foo, enumerable: true })`
+/// * `__defProp(ns, "foo", { get: () => foo, enumerable: true })`
+/// * `Object.defineProperty(ns, "__esModule", { value: true })`
+///
+/// The unused namespace object and helper are removed by the following optimizations.
+///
+/// The `exports` object of a CommonJS module is also read by other modules, so its helper calls
+/// at the top level are kept, and only the reads of `exports.foo` after the helper call are inlined:
+///
+/// ```js
+/// Object.defineProperty(exports, "__esModule", { value: true });
+/// exports.foo = void 0;
+/// Object.defineProperty(exports, "foo", { enumerable: true, get: function () { return foo; } });
+/// bar(exports.foo, exports.__esModule); // -> bar(foo, true)
+/// ```
+pub struct InlineExportHelpers<'a> {
+    ast: AstBuilder<'a>,
+    /// `ns` of the helper calls to remove.
+    helper_calls: FxHashSet<ReferenceId>,
+    /// `ns` of `ns.foo` to the export.
+    reads: FxHashMap<ReferenceId, Export<'a>>,
+}
+
+/// A namespace object, and the exports defined by the helper calls following its declaration.
+struct Namespace<'a> {
+    helper_calls: Vec<ReferenceId>,
+    /// Export name to the export.
+    exports: FxHashMap<Atom<'a>, Export<'a>>,
+}
+
+#[derive(Clone, Copy)]
+enum Export<'a> {
+    /// `get: () => foo`
+    Variable { name: Atom<'a>, symbol_id: Option<SymbolId> },
+    /// `value: true`
+    Boolean(bool),
+}
+
+/// A read or a write of `exports.foo` in a CommonJS module.
+struct ExportsMember<'a> {
+    reference_id: ReferenceId,
+    key: Atom<'a>,
+    /// Index of the top level statement containing the member expression.
+    statement: usize,
+    /// Whether it is in a top level function declaration, which can be called before the statement.
+    in_function_declaration: bool,
+}
+
+impl<'a> InlineExportHelpers<'a> {
+    pub fn new(ast: AstBuilder<'a>) -> Self {
+        Self { ast, helper_calls: FxHashSet::default(), reads: FxHashMap::default() }
+    }
+
+    /// Returns `true` if the program is changed.
+    pub fn build(mut self, program: &mut Program<'a>) -> bool {
+        let semantic = SemanticBuilder::new().build(program).semantic;
+        self.analyze(&semantic, program);
+        drop(semantic);
+        if self.helper_calls.is_empty() && self.reads.is_empty() {
+            return false;
+        }
+        self.visit_program(program);
+        true
+    }
+
+    fn analyze(&mut self, semantic: &Semantic<'_>, program: &Program<'a>) {
+        let scoping = semantic.scoping();
+        let nodes = semantic.nodes();
+        if scoping.root_scope_flags().contains_direct_eval() {
+            return;
+        }
+
+        let mut collector = ExportHelperCollector {
+            scoping,
+            namespaces: FxHashMap::default(),
+            reads: vec![],
+            invalid: FxHashSet::default(),
+            statement: 0,
+            in_function_declaration: false,
+            exports: FxHashMap::default(),
+            exports_reads: vec![],
+            exports_writes: vec![],
+            exports_escaped: false,
+        };
+        collector.visit_program(program);
+        let ExportHelperCollector {
+            namespaces,
+            reads,
+            invalid,
+            exports,
+            exports_reads,
+            exports_writes,
+            exports_escaped,
+            ..
+        } = collector;
+
+        let is_visible = |reference_id: ReferenceId, export: &Export<'a>| match export {
+            Export::Variable { name, symbol_id } => {
+                let node_id = scoping.get_reference(reference_id).node_id();
+                let scope_id = nodes.get_node(node_id).scope_id();
+                scoping.find_binding(scope_id, name) == *symbol_id
+            }
+            Export::Boolean(_) => true,
+        };
+
+        if !exports_escaped {
+            for read in exports_reads {
+                let Some(&(helper_statement, export)) = exports.get(&read.key) else { continue };
+                // `exports.foo = void 0` before the helper call is emitted by TypeScript.
+                let is_written = exports_writes.iter().any(|(write, is_void_0)| {
+                    write.key == read.key
+                        && !(*is_void_0
+                            && !write.in_function_declaration
+                            && write.statement < helper_statement)
+                });
+                if !is_written
+                    && !read.in_function_declaration
+                    && read.statement > helper_statement
+                    && is_visible(read.reference_id, &export)
+                {
+                    self.reads.insert(read.reference_id, export);
+                }
+            }
+        }
+
+        let mut reads_by_symbol: FxHashMap<SymbolId, Vec<(ReferenceId, Atom<'a>)>> =
+            FxHashMap::default();
+        for (reference_id, symbol_id, key) in reads {
+            reads_by_symbol.entry(symbol_id).or_default().push((reference_id, key));
+        }
+        for (symbol_id, namespace) in namespaces {
+            let reads = reads_by_symbol.remove(&symbol_id).unwrap_or_default();
+            if invalid.contains(&symbol_id)
+                || reads.len() + namespace.helper_calls.len()
+                    != scoping.get_resolved_reference_ids(symbol_id).len()
+            {
+                continue;
+            }
+            // The exported variable must not be shadowed where the export is read.
+            let is_inlinable = reads.iter().all(|(reference_id, key)| {
+                namespace.exports.get(key).is_some_and(|export| is_visible(*reference_id, export))
+            });
+            if !is_inlinable {
+                continue;
+            }
+            for (reference_id, key) in reads {
+                self.reads.insert(reference_id, namespace.exports[&key]);
+            }
+            self.helper_calls.extend(namespace.helper_calls);
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for InlineExportHelpers<'a> {
+    fn visit_statements(&mut self, it: &mut oxc_allocator::Vec<'a, Statement<'a>>) {
+        it.retain(|stmt| {
+            let Statement::ExpressionStatement(stmt) = stmt else { return true };
+            let Expression::CallExpression(call) = &stmt.expression else { return true };
+            !matches!(
+                call.arguments.first(),
+                Some(Argument::Identifier(ident)) if self.helper_calls.contains(&ident.reference_id())
+            )
+        });
+        walk_mut::walk_statements(self, it);
+    }
+
+    fn visit_expression(&mut self, it: &mut Expression<'a>) {
+        if let Expression::StaticMemberExpression(e) = it {
+            if let Expression::Identifier(ident) = &e.object {
+                if let Some(export) = self.reads.get(&ident.reference_id()) {
+                    *it = match *export {
+                        Export::Variable { name, .. } => {
+                            self.ast.expression_identifier(e.span, name)
+                        }
+                        Export::Boolean(value) => {
+                            self.ast.expression_boolean_literal(e.span, value)
+                        }
+                    };
+                    return;
+                }
+            }
+        }
+        walk_mut::walk_expression(self, it);
+    }
+}
+
+/// Collects the namespace objects defined by export helpers, and the reads of their properties.
+struct ExportHelperCollector<'a, 's> {
+    scoping: &'s Scoping,
+    namespaces: FxHashMap<SymbolId, Namespace<'a>>,
+    /// `ns` of `ns.foo`, the object and the property name.
+    reads: Vec<(ReferenceId, SymbolId, Atom<'a>)>,
+    /// Objects whose properties are deleted.
+    invalid: FxHashSet<SymbolId>,
+    /// Index of the current top level statement.
+    statement: usize,
+    in_function_declaration: bool,
+    /// Exports defined on `exports` by helper calls at the top level, and the index of the call.
+    exports: FxHashMap<Atom<'a>, (usize, Export<'a>)>,
+    exports_reads: Vec<ExportsMember<'a>>,
+    /// Writes to `exports.foo`, and whether it is the statement `exports.foo = void 0`.
+    exports_writes: Vec<(ExportsMember<'a>, bool)>,
+    /// `exports` is used other than by reading or writing its properties.
+    exports_escaped: bool,
+}
+
+impl<'a> ExportHelperCollector<'a, '_> {
+    /// `var ns = {}`
+    fn namespace_declaration(stmt: &Statement<'a>) -> Option<SymbolId> {
+        let Statement::VariableDeclaration(decl) = stmt else { return None };
+        let [declarator] = decl.declarations.as_slice() else { return None };
+        let Some(Expression::ObjectExpression(object)) = &declarator.init else { return None };
+        let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else { return None };
+        object.properties.is_empty().then(|| id.symbol_id())
+    }
+
+    /// Adds the exports defined by `stmt` to `namespace`.
+    /// Returns `false` if `stmt` is not a helper call for `symbol_id`.
+    fn add_helper_call(
+        &self,
+        stmt: &Statement<'a>,
+        symbol_id: SymbolId,
+        namespace: &mut Namespace<'a>,
+    ) -> bool {
+        let Some((target, exports)) = self.helper_call(stmt) else { return false };
+        if self.scoping.get_reference(target.reference_id()).symbol_id() != Some(symbol_id) {
+            return false;
+        }
+        for (key, export) in exports {
+            // Redefining a property throws, as the properties are not configurable.
+            if namespace.exports.insert(key, export).is_some() {
+                return false;
+            }
+        }
+        namespace.helper_calls.push(target.reference_id());
+        true
+    }
+
+    /// Adds the exports defined by a helper call on `exports` at the top level.
+    /// Returns `false` if `stmt` is not a helper call for `exports`.
+    fn add_exports_helper_call(&mut self, stmt: &Statement<'a>) -> bool {
+        let Some((target, exports)) = self.helper_call(stmt) else { return false };
+        if !self.is_exports(target) {
+            return false;
+        }
+        for (key, export) in exports {
+            if self.exports.insert(key, (self.statement, export)).is_some() {
+                self.exports_escaped = true;
+            }
+        }
+        true
+    }
+
+    /// The target and the exports of a helper call.
+    fn helper_call<'b>(
+        &self,
+        stmt: &'b Statement<'a>,
+    ) -> Option<(&'b IdentifierReference<'a>, Vec<(Atom<'a>, Export<'a>)>)> {
+        let Statement::ExpressionStatement(stmt) = stmt else { return None };
+        let Expression::CallExpression(call) = &stmt.expression else { return None };
+        if call.optional {
+            return None;
+        }
+        let Some(Argument::Identifier(target)) = call.arguments.first() else { return None };
+        let mut exports = vec![];
+        match (&call.callee, call.arguments.as_slice()) {
+            // `__export(ns, { foo: () => foo })`
+            (Expression::Identifier(callee), [_, Argument::ObjectExpression(getters)])
+                if callee.name == "__export" =>
+            {
+                for property in &getters.properties {
+                    let ObjectPropertyKind::ObjectProperty(p) = property else { return None };
+                    let key = Self::property_key(p)?;
+                    let export = self.getter_value(&p.value)?;
+                    exports.push((key, export));
+                }
+            }
+            // `Object.defineProperty(ns, "foo", { get: () => foo, enumerable: true })`
+            // `Object.defineProperty(ns, "__esModule", { value: true })`
+            (callee, [_, Argument::StringLiteral(key), Argument::ObjectExpression(descriptor)])
+                if self.is_define_property(callee) =>
+            {
+                let mut export = None;
+                for property in &descriptor.properties {
+                    let ObjectPropertyKind::ObjectProperty(p) = property else { return None };
+                    match Self::property_key(p).as_deref() {
+                        Some("get") if export.is_none() => export = self.getter_value(&p.value),
+                        Some("value") if export.is_none() && !p.method => {
+                            let Expression::BooleanLiteral(lit) = &p.value else { return None };
+                            export = Some(Export::Boolean(lit.value));
+                        }
+                        Some("enumerable") if !p.method => {}
+                        _ => return None,
+                    }
+                }
+                exports.push((key.value, export?));
+            }
+            _ => return None,
+        }
+        if exports.iter().any(|(key, _)| key == "__proto__") {
+            return None;
+        }
+        Some((target, exports))
+    }
+
+    /// `exports` of a CommonJS module.
+    fn is_exports(&self, ident: &IdentifierReference<'a>) -> bool {
+        ident.name == "exports"
+            && self.scoping.get_reference(ident.reference_id()).symbol_id().is_none()
+    }
+
+    /// `exports.foo`
+    fn exports_member(&self, expr: &Expression<'a>) -> Option<ExportsMember<'a>> {
+        let Expression::StaticMemberExpression(e) = expr else { return None };
+        self.exports_static_member(e)
+    }
+
+    fn exports_static_member(&self, e: &StaticMemberExpression<'a>) -> Option<ExportsMember<'a>> {
+        let Expression::Identifier(ident) = &e.object else { return None };
+        self.is_exports(ident).then(|| ExportsMember {
+            reference_id: ident.reference_id(),
+            key: e.property.name,
+            statement: self.statement,
+            in_function_declaration: self.in_function_declaration,
+        })
+    }
+
+    /// `exports.foo = void 0`, or `exports.foo = exports.bar = void 0`
+    fn add_exports_void_0(&mut self, stmt: &Statement<'a>) -> bool {
+        let Statement::ExpressionStatement(stmt) = stmt else { return false };
+        let mut writes = vec![];
+        let mut expr = &stmt.expression;
+        while let Expression::AssignmentExpression(e) = expr {
+            if e.operator != AssignmentOperator::Assign {
+                return false;
+            }
+            let AssignmentTarget::StaticMemberExpression(member) = &e.left else { return false };
+            let Some(write) = self.exports_static_member(member) else { return false };
+            writes.push(write);
+            expr = &e.right;
+        }
+        if writes.is_empty() || !expr.is_void_0() {
+            return false;
+        }
+        self.exports_writes.extend(writes.into_iter().map(|write| (write, true)));
+        true
+    }
+
+    fn is_define_property(&self, callee: &Expression<'a>) -> bool {
+        match callee {
+            Expression::Identifier(ident) => ident.name == "__defProp",
+            Expression::StaticMemberExpression(e) => {
+                e.property.name == "defineProperty"
+                    && matches!(&e.object, Expression::Identifier(ident)
+                        if ident.name == "Object"
+                            && self.scoping.get_reference(ident.reference_id()).symbol_id().is_none())
+            }
+            _ => false,
+        }
+    }
+
+    fn property_key(property: &ObjectProperty<'a>) -> Option<Atom<'a>> {
+        if property.kind != PropertyKind::Init || property.computed {
+            return None;
+        }
+        match &property.key {
+            PropertyKey::StaticIdentifier(ident) => Some(ident.name),
+            PropertyKey::StringLiteral(lit) => Some(lit.value),
+            _ => None,
+        }
+    }
+
+    /// `() => foo` or `function () { return foo }`
+    fn getter_value(&self, expr: &Expression<'a>) -> Option<Export<'a>> {
+        let (params, body, is_expression) = match expr {
+            Expression::ArrowFunctionExpression(arrow) if !arrow.r#async => {
+                (&arrow.params, &arrow.body, arrow.expression)
+            }
+            Expression::FunctionExpression(func) if !func.r#async && !func.generator => {
+                (&func.params, func.body.as_ref()?, false)
+            }
+            _ => return None,
+        };
+        if !params.is_empty() || !body.directives.is_empty() {
+            return None;
+        }
+        let ident = match body.statements.as_slice() {
+            [Statement::ExpressionStatement(stmt)] if is_expression => &stmt.expression,
+            [Statement::ReturnStatement(stmt)] => stmt.argument.as_ref()?,
+            _ => return None,
+        };
+        let Expression::Identifier(ident) = ident else { return None };
+        // `arguments` of an arrow function is the one of the enclosing function.
+        if ident.name == "arguments" {
+            return None;
+        }
+        let symbol_id = self.scoping.get_reference(ident.reference_id()).symbol_id();
+        Some(Export::Variable { name: ident.name, symbol_id })
+    }
+
+    fn namespace_of(&self, expr: &Expression<'a>) -> Option<(ReferenceId, SymbolId, Atom<'a>)> {
+        let Expression::StaticMemberExpression(e) = expr else { return None };
+        let Expression::Identifier(ident) = &e.object else { return None };
+        let symbol_id = self.scoping.get_reference(ident.reference_id()).symbol_id()?;
+        Some((ident.reference_id(), symbol_id, e.property.name))
+    }
+}
+
+impl<'a> Visit<'a> for ExportHelperCollector<'a, '_> {
+    fn visit_program(&mut self, it: &Program<'a>) {
+        self.collect_namespaces(&it.body);
+        for (index, stmt) in it.body.iter().enumerate() {
+            self.statement = index;
+            self.in_function_declaration = matches!(stmt, Statement::FunctionDeclaration(_));
+            if !self.add_exports_helper_call(stmt) && !self.add_exports_void_0(stmt) {
+                self.visit_statement(stmt);
+            }
+        }
+    }
+
+    fn visit_statements(&mut self, it: &oxc_allocator::Vec<'a, Statement<'a>>) {
+        self.collect_namespaces(it);
+        walk::walk_statements(self, it);
+    }
+
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        if let Some(read) = self.namespace_of(it) {
+            self.reads.push(read);
+            return;
+        }
+        if let Some(read) = self.exports_member(it) {
+            self.exports_reads.push(read);
+            return;
+        }
+        walk::walk_expression(self, it);
+    }
+
+    fn visit_assignment_expression(&mut self, it: &AssignmentExpression<'a>) {
+        if let AssignmentTarget::StaticMemberExpression(member) = &it.left {
+            if let Some(write) = self.exports_static_member(member) {
+                self.exports_writes.push((write, false));
+                self.visit_expression(&it.right);
+                return;
+            }
+        }
+        walk::walk_assignment_expression(self, it);
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if self.is_exports(it) {
+            self.exports_escaped = true;
+        }
+    }
+
+    fn visit_unary_expression(&mut self, it: &UnaryExpression<'a>) {
+        if it.operator == UnaryOperator::Delete {
+            if let Some((_, symbol_id, _)) = self.namespace_of(&it.argument) {
+                self.invalid.insert(symbol_id);
+            }
+            if self.exports_member(&it.argument).is_some() {
+                self.exports_escaped = true;
+            }
+        }
+        walk::walk_unary_expression(self, it);
+    }
+}
+
+impl<'a> ExportHelperCollector<'a, '_> {
+    fn collect_namespaces(&mut self, it: &oxc_allocator::Vec<'a, Statement<'a>>) {
+        for (index, stmt) in it.iter().enumerate() {
+            let Some(symbol_id) = Self::namespace_declaration(stmt) else { continue };
+            if !self.scoping.symbol_redeclarations(symbol_id).is_empty() {
+                continue;
+            }
+            let mut namespace = Namespace { helper_calls: vec![], exports: FxHashMap::default() };
+            for stmt in &it[index + 1..] {
+                if !self.add_helper_call(stmt, symbol_id, &mut namespace) {
+                    break;
+                }
+            }
+            if !namespace.helper_calls.is_empty() {
+                self.namespaces.insert(symbol_id, namespace);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+
+    use crate::{
+        CompressOptions,
+        tester::{test_options_source_type, test_same_options_source_type},
+    };
+
+    fn options() -> CompressOptions {
+        CompressOptions { bundle: true, ..CompressOptions::smallest() }
+    }
+
+    fn test(source_text: &str, expected: &str) {
+        test_options_source_type(source_text, expected, SourceType::cjs(), &options());
+    }
+
+    fn test_same(source_text: &str) {
+        test_same_options_source_type(source_text, SourceType::cjs(), &options());
+    }
+
+    #[test]
+    fn inline_export_helpers() {
+        test(
+            "var ns = {}; __export(ns, { foo: () => foo, bar: () => bar }); var foo = x(), bar = y(); z(ns.foo, ns.bar)",
+            "var foo = x(), bar = y(); z(foo, bar)",
+        );
+        test(
+            "var ns = {}; Object.defineProperty(ns, 'foo', { get: () => foo, enumerable: true }); var foo = x(); z(ns.foo)",
+            "var foo = x(); z(foo)",
+        );
+        test(
+            "var ns = {}; __defProp(ns, 'foo', { get() { return foo }, enumerable: true }); var foo = x(); z(ns.foo)",
+            "var foo = x(); z(foo)",
+        );
+        test(
+            "var ns = {}; __export(ns, { default: function () { return foo } }); var foo = x(); z(ns.default)",
+            "var foo = x(); z(foo)",
+        );
+        test(
+            "var ns = {}; __export(ns, { foo: () => foo }); function foo() { return x() } z(ns.foo())",
+            "z(x())",
+        );
+        test(
+            "var __defProp = Object.defineProperty;
+            var __export = (target, all) => {
+                for (var name in all) __defProp(target, name, { get: all[name], enumerable: true });
+            };
+            var ns = {};
+            __export(ns, { foo: () => foo });
+            var foo = x();
+            z(ns.foo);",
+            "Object.defineProperty; var foo = x(); z(foo)",
+        );
+        // Unused namespace objects.
+        test("var ns = {}; __export(ns, { foo: () => foo }); var foo = 1;", "");
+    }
+
+    #[test]
+    fn keep_export_helpers() {
+        test_same("var ns = {}; __export(ns, { foo: () => foo }); var foo = x(); z(ns.foo, ns)");
+        test_same(
+            "var ns = {}; __export(ns, { foo: () => foo }); var foo = x(); z(ns.foo, ns.bar)",
+        );
+        test_same(
+            "var ns = {}; __export(ns, { foo: () => foo }); var foo = x(); ns.foo = 1, z(ns.foo)",
+        );
+        test_same(
+            "var ns = {}; __export(ns, { foo: () => foo }); var foo = x(); delete ns.foo, z(ns.foo)",
+        );
+        test_same(
+            "var ns = {}; __export(ns, { foo: () => foo }); var foo = x(); z(function (foo) { return ns.foo })",
+        );
+        test_same(
+            "var ns = {}; __export(ns, { foo: () => foo, foo: () => bar }); var foo = x(), bar = y(); z(ns.foo)",
+        );
+        test(
+            "var ns = {}; __export(ns, { foo: () => x() }); z(ns.foo)",
+            "var ns = {}; __export(ns, { foo: () => x() }), z(ns.foo)",
+        );
+        test_same("var ns = {}; __export(ns, { [foo]: () => foo }); var foo = x(); z(ns.foo)");
+        test_same(
+            "var ns = {}; Object.defineProperty(ns, 'foo', { get: () => foo, set: x }); var foo = x(); z(ns.foo)",
+        );
+        test(
+            "var ns = {}; x(); __export(ns, { foo: () => foo }); var foo = x(); z(ns.foo)",
+            "var ns = {}; x(), __export(ns, { foo: () => foo }); var foo = x(); z(ns.foo)",
+        );
+        test_same(
+            "var ns = {}; __export(ns, { foo: () => foo }); var foo = x(); z(ns.foo), eval('')",
+        );
+        test_same_options_source_type(
+            "var ns = {}; __export(ns, { foo: () => foo }); var foo = x(); z(ns.foo)",
+            SourceType::cjs(),
+            &CompressOptions::smallest(),
+        );
+    }
+
+    #[test]
+    fn inline_commonjs_exports() {
+        test(
+            "Object.defineProperty(exports, '__esModule', { value: true });
+            exports.foo = exports.bar = void 0;
+            var foo = x();
+            Object.defineProperty(exports, 'foo', { enumerable: true, get: function () { return foo; } });
+            exports.bar = y();
+            z(exports.foo, exports.bar, exports.__esModule);",
+            "Object.defineProperty(exports, '__esModule', { value: !0 }), exports.foo = exports.bar = void 0;
+            var foo = x();
+            Object.defineProperty(exports, 'foo', { enumerable: !0, get: function () { return foo; } }), exports.bar = y(), z(foo, exports.bar, !0);",
+        );
+        test(
+            "__defProp(exports, 'foo', { get: () => foo, enumerable: true }); var foo = x(); z(() => exports.foo)",
+            "__defProp(exports, 'foo', { get: () => foo, enumerable: !0 }); var foo = x(); z(() => foo)",
+        );
+    }
+
+    #[test]
+    fn keep_commonjs_exports() {
+        // Read before the helper call.
+        test_same(
+            "var foo = x(); z(exports.foo), Object.defineProperty(exports, 'foo', { get: () => foo })",
+        );
+        test(
+            "function f() { return exports.foo } Object.defineProperty(exports, 'foo', { get: () => foo }); var foo = x(); z(f())",
+            "Object.defineProperty(exports, 'foo', { get: () => foo }); var foo = x(); z(exports.foo)",
+        );
+        // `exports` escapes.
+        test_same(
+            "Object.defineProperty(exports, 'foo', { get: () => foo }); var foo = x(); z(exports.foo, exports)",
+        );
+        test_same(
+            "Object.defineProperty(exports, 'foo', { get: () => foo }); var foo = x(); delete exports.foo, z(exports.foo)",
+        );
+        // Written after the helper call.
+        test_same(
+            "Object.defineProperty(exports, 'foo', { get: () => foo }); var foo = x(); exports.foo = y(), z(exports.foo)",
+        );
+        // Not at the top level.
+        test_same(
+            "if (x) { Object.defineProperty(exports, 'foo', { get: () => foo }); var foo = x(); z(exports.foo) }",
+        );
+        // Shadowed.
+        test_same(
+            "Object.defineProperty(exports, 'foo', { get: () => foo }); var foo = x(); z(function (foo) { return exports.foo })",
+        );
+        // `module.exports` is not handled.
+        test_same(
+            "Object.defineProperty(module.exports, 'foo', { get: () => foo }); var foo = x(); z(module.exports.foo)",
+        );
+    }
+
+    #[test]
+    fn top_level_is_not_global() {
+        test("var a = 1, b = x(); function f() {} class C {} z(b)", "var b = x(); z(b)");
+        test("var x = /* @__PURE__ */ (() => { return { a: 1 } })();", "");
+        test("var x = /* @__PURE__ */ (function () { y() })();", "");
+        test_same_options_source_type(
+            "var a = 1;",
+            SourceType::cjs(),
+            &CompressOptions::smallest(),
+        );
+    }
+}
@@ -1,7 +1,11 @@
 #![allow(clippy::unused_self)]
 
 mod convert_to_dotted_properties;
+mod dead_store_elimination;
 mod fold_constants;
+mod hoist_properties;
+mod inline;
+mod inline_export_helpers;
 mod minimize_conditional_expression;
 mod minimize_conditions;
 mod minimize_expression_in_boolean_context;
@@ -28,9 +32,15 @@ use oxc_traverse::{ReusableTraverseCtx, Traverse, traverse_mut_with_ctx};
 use crate::{
     ctx::{Ctx, TraverseCtx},
     state::MinifierState,
+    stats::{Measure, NodeSize, PeepholeOptimization, PeepholeOptimizationStats},
 };
 
-pub use self::normalize::{Normalize, NormalizeOptions};
+pub use self::{
+    dead_store_elimination::DeadStoreElimination,
+    hoist_properties::HoistProperties,
+    inline_export_helpers::InlineExportHelpers,
+    normalize::{Normalize, NormalizeOptions},
+};
 
 pub struct PeepholeOptimizations {
     /// Walk the ast in a fixed point loop until no changes are made.
@@ -39,11 +49,46 @@ pub struct PeepholeOptimizations {
     /// in the previous walk.
     iteration: u8,
     changed: bool,
+    /// Changes made by each optimization, collected only when stats are requested.
+    stats: Option<std::vec::Vec<PeepholeOptimizationStats>>,
 }
 
 impl<'a> PeepholeOptimizations {
     pub fn new() -> Self {
-        Self { iteration: 0, changed: false }
+        Self { iteration: 0, changed: false, stats: None }
+    }
+
+    /// Measure the changes made by each optimization, see [`Self::take_stats`].
+    ///
+    /// The changed node is printed before and after each optimization, so this is slow.
+    #[must_use]
+    pub fn with_stats(mut self) -> Self {
+        self.stats = Some(vec![]);
+        self
+    }
+
+    pub fn take_stats(&mut self) -> std::vec::Vec<PeepholeOptimizationStats> {
+        self.stats.take().unwrap_or_default()
+    }
+
+    /// Size of `node` before running optimizations on it, if stats are collected.
+    fn measure<T: Measure>(&self, node: &T) -> Option<NodeSize> {
+        self.stats.as_ref().map(|_| node.size())
+    }
+
+    /// Credit `optimization` with the change of `node` since `size` was measured.
+    fn record<T: Measure>(
+        &mut self,
+        optimization: PeepholeOptimization,
+        size: &mut Option<NodeSize>,
+        node: &T,
+    ) {
+        let (Some(stats), Some(before)) = (self.stats.as_mut(), size.as_mut()) else { return };
+        let after = node.size();
+        if *before != after {
+            NodeSize::record(*before, after, optimization, stats);
+            *before = after;
+        }
     }
 
     pub fn build(
@@ -54,18 +99,25 @@ impl<'a> PeepholeOptimizations {
         traverse_mut_with_ctx(self, program, ctx);
     }
 
+    /// Run until no changes are made, or at most `passes` times if `passes` is not `0`.
+    ///
+    /// `after_pass` is called with the 1-based iteration number after each walk.
     pub fn run_in_loop(
         &mut self,
+        passes: u8,
         program: &mut Program<'a>,
         ctx: &mut ReusableTraverseCtx<'a, MinifierState<'a>>,
+        mut after_pass: impl FnMut(u8, &Program<'a>),
     ) {
         loop {
             self.changed = false;
             self.build(program, ctx);
-            if !self.changed {
+            after_pass(self.iteration + 1, program);
+            if !self.changed || (passes != 0 && self.iteration + 1 >= passes) {
                 break;
             }
-            if self.iteration > 10 {
+            // An explicit number of passes may be larger than the default cap.
+            if passes == 0 && self.iteration > 10 {
                 debug_assert!(false, "Ran loop more than 10 times.");
                 break;
             }
@@ -101,8 +153,11 @@ impl<'a> PeepholeOptimizations {
 }
 
 impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
-    fn enter_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         ctx.state.changed = false;
+        let mut ctx = Ctx::new(ctx);
+        Self::collect_tdz_symbols(program, &mut ctx);
+        Self::collect_inline_functions(program, &mut ctx);
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -118,7 +173,11 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
 
     fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
         let mut ctx = Ctx::new(ctx);
+        let mut size = self.measure(stmts);
+        self.inline_single_use_variables(stmts, &mut ctx);
+        self.record(PeepholeOptimization::Inline, &mut size, stmts);
         self.minimize_statements(stmts, &mut ctx);
+        self.record(PeepholeOptimization::MinimizeStatements, &mut size, stmts);
     }
 
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -128,25 +187,32 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
 
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         let mut ctx = Ctx::new(ctx);
+        let mut size = self.measure(stmt);
         self.try_fold_stmt_in_boolean_context(stmt, &mut ctx);
+        self.record(PeepholeOptimization::MinimizeExpressionInBooleanContext, &mut size, stmt);
         self.remove_dead_code_exit_statement(stmt, &mut ctx);
+        self.record(PeepholeOptimization::RemoveDeadCode, &mut size, stmt);
         if let Statement::IfStatement(if_stmt) = stmt {
             if let Some(folded_stmt) = self.try_minimize_if(if_stmt, &mut ctx) {
                 *stmt = folded_stmt;
                 ctx.state.changed = true;
             }
         }
+        self.record(PeepholeOptimization::MinimizeIfStatement, &mut size, stmt);
     }
 
     fn exit_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         let mut ctx = Ctx::new(ctx);
+        let mut size = self.measure(stmt);
         self.minimize_for_statement(stmt, &mut ctx);
+        self.record(PeepholeOptimization::MinimizeForStatement, &mut size, stmt);
     }
 
     fn exit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         let mut ctx = Ctx::new(ctx);
-
+        let mut size = self.measure(stmt);
         self.substitute_return_statement(stmt, &mut ctx);
+        self.record(PeepholeOptimization::SubstituteAlternateSyntax, &mut size, stmt);
     }
 
     fn exit_variable_declaration(
@@ -155,45 +221,85 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let mut ctx = Ctx::new(ctx);
-
+        let mut size = self.measure(decl);
         self.substitute_variable_declaration(decl, &mut ctx);
+        self.record(PeepholeOptimization::SubstituteAlternateSyntax, &mut size, decl);
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         let mut ctx = Ctx::new(ctx);
-
+        let mut size = self.measure(expr);
+        self.inline_constant_identifier(expr, &mut ctx);
+        self.inline_function_call(expr, &mut ctx);
+        self.record(PeepholeOptimization::Inline, &mut size, expr);
         self.fold_constants_exit_expression(expr, &mut ctx);
+        self.record(PeepholeOptimization::FoldConstants, &mut size, expr);
         self.minimize_conditions_exit_expression(expr, &mut ctx);
+        self.record(PeepholeOptimization::MinimizeConditions, &mut size, expr);
         self.remove_dead_code_exit_expression(expr, &mut ctx);
+        self.record(PeepholeOptimization::RemoveDeadCode, &mut size, expr);
         self.replace_known_methods_exit_expression(expr, &mut ctx);
+        self.record(PeepholeOptimization::ReplaceKnownMethods, &mut size, expr);
         self.substitute_exit_expression(expr, &mut ctx);
+        self.record(PeepholeOptimization::SubstituteAlternateSyntax, &mut size, expr);
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut ctx = Ctx::new(ctx);
+        let mut size = self.measure(&*func.params);
+        Self::remove_unused_function_parameters(&mut func.params, &mut ctx);
+        self.record(
+            PeepholeOptimization::RemoveUnusedVariableDeclaration,
+            &mut size,
+            &*func.params,
+        );
+    }
+
+    fn exit_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut ctx = Ctx::new(ctx);
+        let mut size = self.measure(&*arrow.params);
+        Self::remove_unused_function_parameters(&mut arrow.params, &mut ctx);
+        self.record(
+            PeepholeOptimization::RemoveUnusedVariableDeclaration,
+            &mut size,
+            &*arrow.params,
+        );
     }
 
     fn exit_unary_expression(&mut self, expr: &mut UnaryExpression<'a>, ctx: &mut TraverseCtx<'a>) {
         let mut ctx = Ctx::new(ctx);
+        let mut size = self.measure(expr);
         if expr.operator.is_not()
             && self.try_fold_expr_in_boolean_context(&mut expr.argument, &mut ctx)
         {
             ctx.state.changed = true;
         }
+        self.record(PeepholeOptimization::MinimizeExpressionInBooleanContext, &mut size, expr);
     }
 
     fn exit_call_expression(&mut self, expr: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
         let mut ctx = Ctx::new(ctx);
-
+        let mut size = self.measure(expr);
         self.substitute_call_expression(expr, &mut ctx);
+        self.record(PeepholeOptimization::SubstituteAlternateSyntax, &mut size, expr);
     }
 
     fn exit_new_expression(&mut self, expr: &mut NewExpression<'a>, ctx: &mut TraverseCtx<'a>) {
         let mut ctx = Ctx::new(ctx);
-
+        let mut size = self.measure(expr);
         self.substitute_new_expression(expr, &mut ctx);
+        self.record(PeepholeOptimization::SubstituteAlternateSyntax, &mut size, expr);
     }
 
     fn exit_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
         let mut ctx = Ctx::new(ctx);
-
+        let mut size = self.measure(prop);
         self.substitute_object_property(prop, &mut ctx);
+        self.record(PeepholeOptimization::SubstituteAlternateSyntax, &mut size, prop);
     }
 
     fn exit_assignment_target_property(
@@ -202,8 +308,9 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let mut ctx = Ctx::new(ctx);
-
+        let mut size = self.measure(node);
         self.substitute_assignment_target_property(node, &mut ctx);
+        self.record(PeepholeOptimization::SubstituteAlternateSyntax, &mut size, node);
     }
 
     fn exit_assignment_target_property_property(
@@ -212,14 +319,16 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let mut ctx = Ctx::new(ctx);
-
+        let mut size = self.measure(prop);
         self.substitute_assignment_target_property_property(prop, &mut ctx);
+        self.record(PeepholeOptimization::SubstituteAlternateSyntax, &mut size, prop);
     }
 
     fn exit_binding_property(&mut self, prop: &mut BindingProperty<'a>, ctx: &mut TraverseCtx<'a>) {
         let mut ctx = Ctx::new(ctx);
-
+        let mut size = self.measure(prop);
         self.substitute_binding_property(prop, &mut ctx);
+        self.record(PeepholeOptimization::SubstituteAlternateSyntax, &mut size, prop);
     }
 
     fn exit_method_definition(
@@ -228,8 +337,9 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let mut ctx = Ctx::new(ctx);
-
+        let mut size = self.measure(prop);
         self.substitute_method_definition(prop, &mut ctx);
+        self.record(PeepholeOptimization::SubstituteAlternateSyntax, &mut size, prop);
     }
 
     fn exit_property_definition(
@@ -238,8 +348,9 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let mut ctx = Ctx::new(ctx);
-
+        let mut size = self.measure(prop);
         self.substitute_property_definition(prop, &mut ctx);
+        self.record(PeepholeOptimization::SubstituteAlternateSyntax, &mut size, prop);
     }
 
     fn exit_accessor_property(
@@ -248,8 +359,9 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         ctx: &mut TraverseCtx<'a>,
     ) {
         let mut ctx = Ctx::new(ctx);
-
+        let mut size = self.measure(prop);
         self.substitute_accessor_property(prop, &mut ctx);
+        self.record(PeepholeOptimization::SubstituteAlternateSyntax, &mut size, prop);
     }
 }
 
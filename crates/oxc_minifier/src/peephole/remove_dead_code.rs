@@ -240,7 +240,7 @@ impl<'a> PeepholeOptimizations {
         }
     }
 
-    /// Remove meaningless labeled statements.
+    /// Remove meaningless labeled statements, and labeled statements listed in `compress.drop_labels`.
     ///
     /// ```js
     /// a: break a;
@@ -250,6 +250,13 @@ impl<'a> PeepholeOptimizations {
         ctx: &mut Ctx<'a, '_>,
     ) -> Option<Statement<'a>> {
         let id = s.label.name.as_str();
+        // `drop_labels`: remove the whole statement, but keep the hoisted `var` declarations.
+        if ctx.options().drop_labels.iter().any(|label| label == id) {
+            let mut var = KeepVar::new(ctx.ast);
+            var.visit_statement(&s.body);
+            let var_decl = var.get_variable_declaration_statement();
+            return var_decl.unwrap_or_else(|| ctx.ast.statement_empty(s.span)).into();
+        }
         // Check the first statement in the block, or just the `break [id] ` statement.
         // Check if we need to remove the whole block.
         match &mut s.body {
@@ -552,7 +559,7 @@ impl<'a> LatePeepholeOptimizations {
 mod test {
     use crate::{
         CompressOptions,
-        tester::{test, test_options, test_same},
+        tester::{default_options, test, test_options, test_same, test_same_options},
     };
 
     #[test]
@@ -611,6 +618,25 @@ mod test {
         test("foo:;", "");
     }
 
+    #[test]
+    fn drop_labels() {
+        let options =
+            CompressOptions { drop_labels: vec!["DEV".into(), "TEST".into()], ..default_options() };
+        test_options("DEV: { foo() } bar()", "bar()", &options);
+        test_options("DEV: foo(); TEST: bar()", "", &options);
+        test_options("DEV: for (;;) { foo(); break DEV }", "", &options);
+        test_options("DEV: { var x = foo() } bar(x)", "var x; bar(x)", &options);
+        test_options(
+            "function f() { DEV: { return foo() } bar() }",
+            "function f() { bar() }",
+            &options,
+        );
+        test_options("PROD: DEV: foo()", "", &options);
+        test_same_options("PROD: foo()", &options);
+
+        test_same("DEV: foo()");
+    }
+
     #[test]
     fn test_fold_useless_for() {
         test("for(;false;) { foo() }", "");
@@ -524,7 +524,7 @@ impl<'a> PeepholeOptimizations {
     fn fold_call_expression(&self, e: &mut Expression<'a>, ctx: &mut Ctx<'a, '_>) -> bool {
         let Expression::CallExpression(call_expr) = e else { return false };
 
-        if call_expr.pure && ctx.annotations() {
+        if (call_expr.pure && ctx.annotations()) || ctx.manual_pure_functions(&call_expr.callee) {
             let mut exprs =
                 self.fold_arguments_into_needed_expressions(&mut call_expr.arguments, ctx);
             if exprs.is_empty() {
@@ -905,4 +905,45 @@ mod test {
             &options,
         );
     }
+
+    #[test]
+    fn pure_getters() {
+        let options = CompressOptions { pure_getters: true, ..default_options() };
+        test_options("export function f(a) { a.b }", "export function f(a) {}", &options);
+        test_options(
+            "export function f(a) { a.b.c, foo() }",
+            "export function f(a) { foo() }",
+            &options,
+        );
+        test_same_options("export function f(a) { a.b() }", &options);
+        test_same_options("a.b", &options);
+
+        test_same("export function f(a) { a.b }");
+    }
+
+    #[test]
+    fn pure_funcs() {
+        let options = CompressOptions {
+            pure_funcs: vec!["assert".into(), "console.log".into(), "a.b.c".into()],
+            ..default_options()
+        };
+        test_options("assert(1)", "", &options);
+        test_options("assert(x)", "x", &options);
+        test_options("assert(foo(), 'message')", "foo()", &options);
+        test_options("assert(foo(), ...bar)", "foo(), [...bar]", &options);
+        test_options("console.log(foo, bar())", "foo, bar()", &options);
+        test_options("a.b.c(1)", "", &options);
+        test_options("x = 1, assert(1), foo()", "x = 1, foo()", &options);
+        test_same_options("x = assert(1)", &options);
+        test_same_options("console.warn(1)", &options);
+        test_same_options("b.c(1)", &options);
+        test_same_options("a.b.c.d(1)", &options);
+        test_options("console['log'](1)", "", &options);
+        test_same_options("console[log](1)", &options);
+        test_same_options("function f(console) { console.log(x) }", &options);
+        test_same_options("function f(assert) { assert(x) }", &options);
+        test_same_options("let a; a.b.c(1)", &options);
+
+        test_same("assert(x)");
+    }
 }
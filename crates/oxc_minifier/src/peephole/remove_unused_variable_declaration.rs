@@ -1,4 +1,5 @@
 use oxc_ast::ast::*;
+use oxc_traverse::Ancestor;
 
 use crate::{CompressOptionsUnused, ctx::Ctx};
 
@@ -27,6 +28,51 @@ impl<'a> PeepholeOptimizations {
         false
     }
 
+    /// `function (a, b) { return a }` -> `function (a) { return a }`
+    ///
+    /// Enabled by `compress.keep_fargs: false`, as `Function.prototype.length` is changed.
+    pub fn remove_unused_function_parameters(
+        params: &mut FormalParameters<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        if ctx.state.options.keep_fargs {
+            return;
+        }
+        // It is unsafe to remove if direct eval is involved.
+        if ctx.scoping().root_scope_flags().contains_direct_eval() {
+            return;
+        }
+        // Setters must have exactly one parameter.
+        match ctx.parent() {
+            Ancestor::ObjectPropertyValue(prop) if *prop.kind() == PropertyKind::Set => return,
+            Ancestor::MethodDefinitionValue(method)
+                if *method.kind() == MethodDefinitionKind::Set =>
+            {
+                return;
+            }
+            _ => {}
+        }
+        let is_unused = |pattern: &BindingPattern<'a>, ctx: &Ctx<'a, '_>| match &pattern.kind {
+            BindingPatternKind::BindingIdentifier(ident) => {
+                ctx.scoping().symbol_is_unused(ident.symbol_id())
+            }
+            _ => false,
+        };
+        if params.rest.as_ref().is_some_and(|rest| is_unused(&rest.argument, ctx)) {
+            params.rest = None;
+            ctx.state.changed = true;
+        }
+        if params.rest.is_some() {
+            return;
+        }
+        while params.items.last().is_some_and(|param| {
+            param.decorators.is_empty() && !param.has_modifier() && is_unused(&param.pattern, ctx)
+        }) {
+            params.items.pop();
+            ctx.state.changed = true;
+        }
+    }
+
     pub fn remove_unused_function_declaration(
         f: &Function<'a>,
         ctx: &mut Ctx<'a, '_>,
@@ -123,9 +169,8 @@ impl<'a> PeepholeOptimizations {
     }
 
     /// Do remove top level vars in script mode.
-    fn keep_top_level_var_in_script_mode(ctx: &Ctx<'a, '_>) -> bool {
-        ctx.scoping.current_scope_id() == ctx.scoping().root_scope_id()
-            && ctx.source_type().is_script()
+    pub fn keep_top_level_var_in_script_mode(ctx: &Ctx<'a, '_>) -> bool {
+        ctx.scoping.current_scope_id() == ctx.scoping().root_scope_id() && ctx.is_top_level_global()
     }
 }
 
@@ -221,4 +266,27 @@ mod test {
 
         test_options_source_type("class C {}", "class C {}", source_type, &options);
     }
+
+    #[test]
+    fn remove_unused_function_parameters() {
+        let options = CompressOptions { keep_fargs: false, ..CompressOptions::smallest() };
+        test_options(
+            "export function f(a, b, c) { return b }",
+            "export function f(a, b) { return b }",
+            &options,
+        );
+        test_options(
+            "export function f(a, ...b) { return a }",
+            "export function f(a) { return a }",
+            &options,
+        );
+        test_options("export let f = (a, b) => a", "export let f = (a) => a", &options);
+        test_same_options("export function f(a, b = foo()) { return a }", &options);
+        test_same_options("export function f(a, { b }) { return a }", &options);
+        test_same_options("export let o = { set a(v) {} }", &options);
+        test_same_options("export class C { set a(v) {} }", &options);
+        test_same_options("export function f(a) { eval('a') }", &options);
+
+        test_same_options("export function f(a, b) { return a }", &CompressOptions::smallest());
+    }
 }
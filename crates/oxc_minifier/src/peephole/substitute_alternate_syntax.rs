@@ -2,8 +2,9 @@ use std::iter::repeat_with;
 
 use oxc_allocator::{CloneIn, TakeIn, Vec};
 use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
 use oxc_ecmascript::constant_evaluation::DetermineValueType;
-use oxc_ecmascript::{ToJsString, ToNumber, side_effects::MayHaveSideEffects};
+use oxc_ecmascript::{BoundNames, ToJsString, ToNumber, side_effects::MayHaveSideEffects};
 use oxc_span::GetSpan;
 use oxc_span::SPAN;
 use oxc_syntax::{
@@ -11,8 +12,10 @@ use oxc_syntax::{
     identifier::is_identifier_name,
     number::NumberBase,
     operator::{BinaryOperator, UnaryOperator},
+    scope::ScopeFlags,
 };
 use oxc_traverse::Ancestor;
+use rustc_hash::FxHashSet;
 
 use crate::ctx::Ctx;
 
@@ -35,6 +38,31 @@ impl<'a> PeepholeOptimizations {
         }
 
         self.try_compress_property_key(&mut prop.key, &mut prop.computed, ctx);
+        self.try_convert_property_to_method(prop, ctx);
+    }
+
+    /// `{ a: function () {} }` -> `{ a() {} }`
+    ///
+    /// Enabled by `compress.unsafe_methods`, as methods can not be called with `new`.
+    fn try_convert_property_to_method(&self, prop: &mut ObjectProperty<'a>, ctx: &mut Ctx<'a, '_>) {
+        if !ctx.options().unsafe_methods
+            || ctx.options().target < ESTarget::ES2015
+            || prop.method
+            || prop.shorthand
+            || prop.kind != PropertyKind::Init
+        {
+            return;
+        }
+        // `{ __proto__: function () {} }` sets the prototype.
+        if !prop.computed && prop.key.static_name().is_some_and(|name| name == "__proto__") {
+            return;
+        }
+        if let Expression::FunctionExpression(func) = &prop.value {
+            if func.id.is_none() {
+                prop.method = true;
+                ctx.state.changed = true;
+            }
+        }
     }
 
     pub fn substitute_assignment_target_property_property(
@@ -198,6 +226,7 @@ impl<'a> PeepholeOptimizations {
                     Self::try_fold_object_or_array_constructor(e.span, name, &mut e.arguments, ctx)
                 })
                 .or_else(|| self.try_fold_simple_function_call(e, ctx)),
+            Expression::FunctionExpression(e) => self.try_convert_function_to_arrow(e, ctx),
             _ => None,
         } {
             *expr = folded_expr;
@@ -239,6 +268,63 @@ impl<'a> PeepholeOptimizations {
         }
     }
 
+    /// `function () { return 1 }` -> `() => 1`
+    ///
+    /// Enabled by `compress.unsafe_arrows`, as arrow functions can not be called with `new`.
+    fn try_convert_function_to_arrow(
+        &self,
+        func: &mut Function<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        if !ctx.options().unsafe_arrows
+            || ctx.options().target < ESTarget::ES2015
+            || func.generator
+            || func.id.is_some()
+        {
+            return None;
+        }
+        match ctx.parent() {
+            Ancestor::NewExpressionCallee(_) => return None,
+            // Methods and accessors can not be arrow functions, and methods are preferred as they keep `this`.
+            Ancestor::ObjectPropertyValue(prop)
+                if *prop.method()
+                    || *prop.kind() != PropertyKind::Init
+                    || ctx.options().unsafe_methods =>
+            {
+                return None;
+            }
+            _ => {}
+        }
+        let body = func.body.as_ref()?;
+        let mut checker = ThisChecker::default();
+        checker.visit_formal_parameters(&func.params);
+        checker.visit_function_body(body);
+        if checker.uses_this {
+            return None;
+        }
+        // Arrow functions can not have duplicate parameters.
+        let mut names = FxHashSet::default();
+        let mut has_duplicate_params = false;
+        func.params.bound_names(&mut |ident| has_duplicate_params |= !names.insert(ident.name));
+        if has_duplicate_params {
+            return None;
+        }
+        let scope_id = func.scope_id();
+        *ctx.scoping_mut().scope_flags_mut(scope_id) |= ScopeFlags::Arrow;
+        Some(ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            func.span,
+            false,
+            func.r#async,
+            NONE,
+            func.params.take_in(ctx.ast),
+            NONE,
+            func.body.take()?,
+            scope_id,
+            func.pure,
+            false,
+        ))
+    }
+
     /// Compress `typeof foo == "undefined"`
     ///
     /// - `typeof foo == "undefined"` (if foo is not resolved) -> `typeof foo > "u"`
@@ -1227,15 +1313,56 @@ where
     }
 }
 
+/// Checks whether a function uses its own `this`, `arguments`, `new.target` or `super`.
+#[derive(Default)]
+struct ThisChecker {
+    uses_this: bool,
+}
+
+impl<'a> Visit<'a> for ThisChecker {
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.uses_this = true;
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        self.uses_this = true;
+    }
+
+    fn visit_meta_property(&mut self, it: &MetaProperty<'a>) {
+        if it.meta.name == "new" {
+            self.uses_this = true;
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if it.name == "arguments" {
+            self.uses_this = true;
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {
+        // Functions have their own `this`.
+    }
+
+    fn visit_class(&mut self, _it: &Class<'a>) {
+        // Class fields have their own `this`, but `extends` and computed keys do not.
+        self.uses_this = true;
+    }
+}
+
 /// Port from <https://github.com/google/closure-compiler/blob/v20240609/test/com/google/javascript/jscomp/PeepholeSubstituteAlternateSyntaxTest.java>
 #[cfg(test)]
 mod test {
+    use oxc_span::SourceType;
     use oxc_syntax::es_target::ESTarget;
 
     use crate::{
         CompressOptions,
         options::CompressOptionsKeepNames,
-        tester::{default_options, test, test_same, test_same_options},
+        tester::{
+            default_options, test, test_options, test_same, test_same_options,
+            test_same_options_source_type,
+        },
     };
 
     #[test]
@@ -1919,4 +2046,56 @@ mod test {
         test("var {y: z, 'z': y} = x", "var {y: z, z: y} = x");
         test("var {y: y, 'z': z} = x", "var {y, z} = x");
     }
+
+    #[test]
+    fn test_unsafe_arrows() {
+        let options = CompressOptions { unsafe_arrows: true, ..default_options() };
+        test_options("foo(function () { return 1 })", "foo(() => 1)", &options);
+        test_options(
+            "foo(async function (a) { await a })",
+            "foo(async (a) => { await a })",
+            &options,
+        );
+        test_options(
+            "foo(function () { return function () { return this } })",
+            "foo(() => function () { return this })",
+            &options,
+        );
+        test_same_options("foo(function () { return this })", &options);
+        test_same_options("foo(function () { return () => this })", &options);
+        test_same_options("foo(function () { return arguments })", &options);
+        test_same_options("foo(function () { return new.target })", &options);
+        test_same_options("foo(function () { return class { x = this } })", &options);
+        test_same_options("foo(function* () {})", &options);
+        test_same_options("foo(function f() { return f })", &options);
+        test_same_options("new function () {}", &options);
+        test_same_options_source_type("foo(function (a, a) {})", SourceType::cjs(), &options);
+
+        let options = CompressOptions { target: ESTarget::ES5, ..options };
+        test_same_options("foo(function () { return 1 })", &options);
+
+        test_same("foo(function () { return 1 })");
+    }
+
+    #[test]
+    fn test_unsafe_methods() {
+        let options = CompressOptions { unsafe_methods: true, ..default_options() };
+        test_options(
+            "foo({ a: function () { return this } })",
+            "foo({ a() { return this } })",
+            &options,
+        );
+        test_options("foo({ 'a': async function* () {} })", "foo({ async *a() {} })", &options);
+        test_same_options("foo({ a: function b() { return b } })", &options);
+        test_same_options("foo({ __proto__: function () {} })", &options);
+        test_same_options("foo({ a: () => {} })", &options);
+
+        let options = CompressOptions { unsafe_arrows: true, ..options };
+        test_options("foo({ a: function () { return 1 } })", "foo({ a() { return 1 } })", &options);
+
+        let options = CompressOptions { target: ESTarget::ES5, ..options };
+        test_same_options("foo({ a: function () { return 1 } })", &options);
+
+        test_same("foo({ a: function () { return 1 } })");
+    }
 }
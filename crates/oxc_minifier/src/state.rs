@@ -1,5 +1,6 @@
 use rustc_hash::{FxHashMap, FxHashSet};
 
+use oxc_ast::ast::Expression;
 use oxc_ecmascript::constant_evaluation::ConstantValue;
 use oxc_semantic::SymbolId;
 use oxc_span::SourceType;
@@ -20,6 +21,16 @@ pub struct MinifierState<'a> {
     /// Function declarations that are empty
     pub empty_functions: FxHashSet<SymbolId>,
 
+    /// Single-use function declarations that can be inlined into their call site.
+    ///
+    /// Collected at the start of each walk.
+    pub inline_functions: FxHashMap<SymbolId, InlineFunction<'a>>,
+
+    /// `let` and `const` bindings which may be read before they are initialized.
+    ///
+    /// Collected at the start of each walk.
+    pub tdz_symbols: FxHashSet<SymbolId>,
+
     pub changed: bool,
 }
 
@@ -30,7 +41,18 @@ impl MinifierState<'_> {
             options,
             constant_values: FxHashMap::default(),
             empty_functions: FxHashSet::default(),
+            inline_functions: FxHashMap::default(),
+            tdz_symbols: FxHashSet::default(),
             changed: false,
         }
     }
 }
+
+/// A function declaration `function f(a, b) { return a + b }` which can be inlined.
+pub struct InlineFunction<'a> {
+    /// Parameters of the function, in declaration order.
+    pub params: Vec<SymbolId>,
+
+    /// Copy of the returned expression. `None` if the function returns `undefined`.
+    pub body: Option<Expression<'a>>,
+}
@@ -0,0 +1,331 @@
+use std::fmt;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{AstKind, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_codegen::{Codegen, CodegenOptions, Context, Gen, GenExpr};
+use oxc_semantic::Stats;
+use oxc_syntax::precedence::Precedence;
+
+/// A pass of the [`Compressor`](crate::Compressor).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CompressorPass {
+    Normalize,
+    InlineExportHelpers,
+    HoistProperties,
+    DeadStoreElimination,
+    /// An iteration of the peephole optimizations, starting from `1`.
+    Peephole(u8),
+    LatePeephole,
+}
+
+impl fmt::Display for CompressorPass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Normalize => f.write_str("normalize"),
+            Self::InlineExportHelpers => f.write_str("inline_export_helpers"),
+            Self::HoistProperties => f.write_str("hoist_props"),
+            Self::DeadStoreElimination => f.write_str("dead_store_elimination"),
+            Self::Peephole(iteration) => write!(f, "peephole #{iteration}"),
+            Self::LatePeephole => f.write_str("late_peephole"),
+        }
+    }
+}
+
+/// An optimization in the peephole loop, named after its module.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PeepholeOptimization {
+    Inline,
+    FoldConstants,
+    MinimizeConditions,
+    MinimizeExpressionInBooleanContext,
+    MinimizeIfStatement,
+    MinimizeForStatement,
+    MinimizeStatements,
+    RemoveDeadCode,
+    RemoveUnusedVariableDeclaration,
+    ReplaceKnownMethods,
+    SubstituteAlternateSyntax,
+}
+
+impl fmt::Display for PeepholeOptimization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Inline => "inline",
+            Self::FoldConstants => "fold_constants",
+            Self::MinimizeConditions => "minimize_conditions",
+            Self::MinimizeExpressionInBooleanContext => "minimize_expression_in_boolean_context",
+            Self::MinimizeIfStatement => "minimize_if_statement",
+            Self::MinimizeForStatement => "minimize_for_statement",
+            Self::MinimizeStatements => "minimize_statements",
+            Self::RemoveDeadCode => "remove_dead_code",
+            Self::RemoveUnusedVariableDeclaration => "remove_unused_variable_declaration",
+            Self::ReplaceKnownMethods => "replace_known_methods",
+            Self::SubstituteAlternateSyntax => "substitute_alternate_syntax",
+        })
+    }
+}
+
+/// Changes made by a [`PeepholeOptimization`], summed over all iterations of the peephole loop.
+#[derive(Debug, Clone)]
+pub struct PeepholeOptimizationStats {
+    pub optimization: PeepholeOptimization,
+
+    /// Number of AST nodes removed by this optimization.
+    pub nodes_removed: i64,
+
+    /// Number of bytes saved by this optimization.
+    pub bytes_saved: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompressorPassStats {
+    pub pass: CompressorPass,
+
+    /// Number of AST nodes removed by this pass.
+    /// Negative if the pass added nodes, e.g. by splitting a declaration.
+    pub nodes_removed: i64,
+
+    /// Number of bytes saved by this pass, printed with [`CodegenOptions::minify`] without mangling.
+    pub bytes_saved: i64,
+}
+
+/// Statistics returned by [`Compressor::build_with_stats`](crate::Compressor::build_with_stats).
+///
+/// Printing the program after every pass is slow, so this is meant for tooling only.
+#[derive(Debug, Clone)]
+pub struct CompressorStats {
+    /// Number of AST nodes before compression.
+    pub original_nodes: u32,
+
+    /// Number of bytes before compression.
+    pub original_bytes: usize,
+
+    /// Passes in the order they were run.
+    pub passes: Vec<CompressorPassStats>,
+
+    /// Optimizations of the peephole loop, in the order they first made a change.
+    ///
+    /// Only the changed node is measured, so the sum differs slightly from the `Peephole` passes.
+    pub optimizations: Vec<PeepholeOptimizationStats>,
+
+    nodes: u32,
+    bytes: usize,
+}
+
+impl CompressorStats {
+    pub(crate) fn new(program: &Program<'_>) -> Self {
+        let (nodes, bytes) = Self::measure(program);
+        Self {
+            original_nodes: nodes,
+            original_bytes: bytes,
+            passes: vec![],
+            optimizations: vec![],
+            nodes,
+            bytes,
+        }
+    }
+
+    #[expect(clippy::cast_possible_wrap)]
+    pub(crate) fn record(&mut self, pass: CompressorPass, program: &Program<'_>) {
+        let (nodes, bytes) = Self::measure(program);
+        self.passes.push(CompressorPassStats {
+            pass,
+            nodes_removed: i64::from(self.nodes) - i64::from(nodes),
+            bytes_saved: self.bytes as i64 - bytes as i64,
+        });
+        self.nodes = nodes;
+        self.bytes = bytes;
+    }
+
+    /// Number of AST nodes after compression.
+    pub fn nodes(&self) -> u32 {
+        self.nodes
+    }
+
+    /// Number of bytes after compression.
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    fn measure(program: &Program<'_>) -> (u32, usize) {
+        let nodes = Stats::count(program).nodes;
+        let bytes = Codegen::new().with_options(CodegenOptions::minify()).build(program).code.len();
+        (nodes, bytes)
+    }
+}
+
+/// Size of an AST node changed by a [`PeepholeOptimization`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct NodeSize {
+    nodes: u32,
+    bytes: usize,
+}
+
+impl NodeSize {
+    fn new(count: impl FnOnce(&mut NodeCounter), print: impl FnOnce(&mut Codegen)) -> Self {
+        let mut counter = NodeCounter::default();
+        count(&mut counter);
+        let mut codegen = Codegen::new().with_options(CodegenOptions::minify());
+        print(&mut codegen);
+        Self { nodes: counter.nodes, bytes: codegen.into_source_text().len() }
+    }
+
+    #[expect(clippy::cast_possible_wrap)]
+    pub fn record(
+        before: Self,
+        after: Self,
+        optimization: PeepholeOptimization,
+        stats: &mut Vec<PeepholeOptimizationStats>,
+    ) {
+        let nodes_removed = i64::from(before.nodes) - i64::from(after.nodes);
+        let bytes_saved = before.bytes as i64 - after.bytes as i64;
+        match stats.iter_mut().find(|stats| stats.optimization == optimization) {
+            Some(stats) => {
+                stats.nodes_removed += nodes_removed;
+                stats.bytes_saved += bytes_saved;
+            }
+            None => {
+                stats.push(PeepholeOptimizationStats { optimization, nodes_removed, bytes_saved });
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct NodeCounter {
+    nodes: u32,
+}
+
+impl<'a> Visit<'a> for NodeCounter {
+    fn enter_node(&mut self, _: AstKind<'a>) {
+        self.nodes += 1;
+    }
+}
+
+/// AST nodes which are changed by the peephole optimizations.
+pub trait Measure {
+    fn size(&self) -> NodeSize;
+}
+
+macro_rules! impl_measure {
+    ($($ty:ident => $visit:ident),* $(,)?) => {
+        $(
+            impl Measure for $ty<'_> {
+                fn size(&self) -> NodeSize {
+                    NodeSize::new(
+                        |counter| counter.$visit(self),
+                        |codegen| self.print(codegen, Context::empty()),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_measure_expr {
+    ($($ty:ident => $visit:ident),* $(,)?) => {
+        $(
+            impl Measure for $ty<'_> {
+                fn size(&self) -> NodeSize {
+                    NodeSize::new(
+                        |counter| counter.$visit(self),
+                        |codegen| self.print_expr(codegen, Precedence::Lowest, Context::empty()),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_measure! {
+    Statement => visit_statement,
+    ForStatement => visit_for_statement,
+    ReturnStatement => visit_return_statement,
+    VariableDeclaration => visit_variable_declaration,
+    FormalParameters => visit_formal_parameters,
+    ObjectProperty => visit_object_property,
+    AssignmentTargetProperty => visit_assignment_target_property,
+    AssignmentTargetPropertyProperty => visit_assignment_target_property_property,
+    BindingProperty => visit_binding_property,
+    MethodDefinition => visit_method_definition,
+    PropertyDefinition => visit_property_definition,
+    AccessorProperty => visit_accessor_property,
+}
+
+impl_measure_expr! {
+    Expression => visit_expression,
+    UnaryExpression => visit_unary_expression,
+    CallExpression => visit_call_expression,
+    NewExpression => visit_new_expression,
+}
+
+impl Measure for ArenaVec<'_, Statement<'_>> {
+    fn size(&self) -> NodeSize {
+        NodeSize::new(
+            |counter| counter.visit_statements(self),
+            |codegen| {
+                for stmt in self {
+                    stmt.print(codegen, Context::empty());
+                }
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::{CompressorPass, PeepholeOptimization};
+    use crate::{CompressOptions, Compressor};
+
+    #[test]
+    fn build_with_stats() {
+        let allocator = Allocator::default();
+        let source_text = "if (true) { foo(1 + 2) } else { bar() }";
+        let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        let stats =
+            Compressor::new(&allocator).build_with_stats(&mut program, CompressOptions::default());
+        let passes = stats.passes.iter().map(|pass| pass.pass).collect::<Vec<_>>();
+        assert_eq!(
+            passes,
+            [
+                CompressorPass::Normalize,
+                CompressorPass::HoistProperties,
+                CompressorPass::DeadStoreElimination,
+                CompressorPass::Peephole(1),
+                CompressorPass::Peephole(2),
+                CompressorPass::LatePeephole,
+            ]
+        );
+        assert_eq!(stats.original_bytes, "if(true){foo(1+2)}else{bar()}".len());
+        assert_eq!(stats.bytes(), "foo(3);".len());
+        let bytes_saved: i64 = stats.passes.iter().map(|pass| pass.bytes_saved).sum();
+        assert_eq!(usize::try_from(bytes_saved).unwrap(), stats.original_bytes - stats.bytes());
+        let nodes_removed: i64 = stats.passes.iter().map(|pass| pass.nodes_removed).sum();
+        assert_eq!(nodes_removed, i64::from(stats.original_nodes) - i64::from(stats.nodes()));
+        assert!(stats.passes[3].bytes_saved > 0);
+    }
+
+    #[test]
+    fn optimization_stats() {
+        let allocator = Allocator::default();
+        let source_text = "if (true) { foo(1 + 2) } else { bar() }";
+        let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        let stats =
+            Compressor::new(&allocator).build_with_stats(&mut program, CompressOptions::default());
+        let optimization = |optimization| {
+            stats.optimizations.iter().find(|stats| stats.optimization == optimization).unwrap()
+        };
+        // `1 + 2` -> `3`
+        let fold_constants = optimization(PeepholeOptimization::FoldConstants);
+        assert_eq!(fold_constants.bytes_saved, 2);
+        assert_eq!(fold_constants.nodes_removed, 2);
+        // `if (true) { foo(3) } else { bar() }` -> `foo(3)`
+        assert!(optimization(PeepholeOptimization::RemoveDeadCode).bytes_saved > 0);
+        assert_eq!(PeepholeOptimization::FoldConstants.to_string(), "fold_constants");
+    }
+}
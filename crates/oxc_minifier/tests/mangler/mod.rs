@@ -1,9 +1,14 @@
 use std::fmt::Write;
 
+use lazy_regex::Regex;
 use oxc_allocator::Allocator;
-use oxc_codegen::Codegen;
-use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler};
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_mangler::{
+    MangleOptions, MangleOptionsKeepNames, MangleOptionsProps, Mangler, NameCache, PropertyMangler,
+};
+use oxc_minifier::{Minifier, MinifierOptions};
 use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
 
 fn mangle(source_text: &str, options: MangleOptions) -> String {
@@ -100,3 +105,177 @@ fn mangler() {
         insta::assert_snapshot!("mangler", snapshot);
     });
 }
+
+fn mangle_props(
+    source_text: &str,
+    options: MangleOptionsProps,
+    cache: NameCache,
+) -> (String, NameCache) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::mjs();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let name_cache =
+        PropertyMangler::new(options).with_name_cache(cache).build(&allocator, &mut program);
+    (Codegen::new().build(&program).code, name_cache)
+}
+
+fn underscore_props() -> MangleOptionsProps {
+    MangleOptionsProps {
+        regex: Some(Regex::new(r"^_\w").unwrap()),
+        ..MangleOptionsProps::default()
+    }
+}
+
+#[test]
+fn property_mangler() {
+    let cases = [
+        "x._foo = x._bar + x._foo + x.baz",
+        "x['_foo'] = { _foo: 1, '_bar': 2, [`_baz`]: 3, baz: 4 }",
+        "let _foo = 1; x = { _foo }; ({ _foo } = x); let { _foo: y } = x",
+        "class C { _foo = 1; #bar = 2; constructor() { this.#bar; this._foo } }",
+        "x._constructor = x.constructor; x.__proto__ = x.prototype",
+    ];
+    let keep_quoted_cases = ["x['_foo'] = { _foo: 1, '_bar': 2 }; x._bar = x._foo"];
+    let reserved_cases = ["x._foo = x._bar"];
+    let private_cases =
+        ["class C { #foo; #bar; m() { return this.#bar + this.#foo + this.#bar } }"];
+
+    let mut snapshot = String::new();
+    cases.into_iter().fold(&mut snapshot, |w, case| {
+        let (code, _) = mangle_props(case, underscore_props(), NameCache::default());
+        write!(w, "{case}\n{code}\n").unwrap();
+        w
+    });
+    keep_quoted_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptionsProps { keep_quoted: true, ..underscore_props() };
+        let (code, _) = mangle_props(case, options, NameCache::default());
+        write!(w, "{case}\n{code}\n").unwrap();
+        w
+    });
+    reserved_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options =
+            MangleOptionsProps { reserved: vec!["_bar".to_string()], ..underscore_props() };
+        let (code, _) = mangle_props(case, options, NameCache::default());
+        write!(w, "{case}\n{code}\n").unwrap();
+        w
+    });
+    private_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptionsProps { private: true, ..MangleOptionsProps::default() };
+        let (code, _) = mangle_props(case, options, NameCache::default());
+        write!(w, "{case}\n{code}\n").unwrap();
+        w
+    });
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("property_mangler", snapshot);
+    });
+}
+
+#[test]
+fn property_mangler_name_cache() {
+    let (first, cache) =
+        mangle_props("x._a = x._b + x._b", underscore_props(), NameCache::default());
+    assert_eq!(first, "x.t = x.e + x.e;\n");
+
+    // Names from a previous build are reused, even if the frequencies differ.
+    let cache = NameCache::from_json(&cache.to_json()).unwrap();
+    let (second, cache) = mangle_props("x._c = x._a + x._a + x._b", underscore_props(), cache);
+    assert_eq!(second, "x.n = x.t + x.t + x.e;\n");
+    assert_eq!(cache.props.len(), 3);
+
+    assert_eq!(
+        NameCache::from_json(r#"{"props":{"props":{"$_foo":"a"}}}"#).unwrap().props["_foo"],
+        "a"
+    );
+    assert!(NameCache::from_json("{").is_err());
+}
+
+fn mangle_with_name_cache(source_text: &str, name_cache: NameCache) -> (String, NameCache) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::mjs();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let program = ret.program;
+    let mut semantic =
+        SemanticBuilder::new().with_scope_tree_child_ids(true).build(&program).semantic;
+    let name_cache = Mangler::new()
+        .with_options(MangleOptions { top_level: true, ..MangleOptions::default() })
+        .with_name_cache(name_cache)
+        .build_with_semantic(&mut semantic, &program);
+    let code = Codegen::new().with_scoping(Some(semantic.into_scoping())).build(&program).code;
+    (code, name_cache)
+}
+
+#[test]
+fn mangler_name_cache() {
+    let (first, cache) =
+        mangle_with_name_cache("var foo = 1, bar = 2; bar++; bar++;", NameCache::default());
+    assert_eq!(first, "var e = 1, t = 2;\nt++;\nt++;\n");
+    assert_eq!(cache.vars.len(), 2);
+
+    // `foo` and `bar` keep their names, even though the frequencies differ.
+    // New symbols do not take the cached names, and nested symbols do not shadow them.
+    let cache = NameCache::from_json(&cache.to_json()).unwrap();
+    let (second, cache) = mangle_with_name_cache(
+        "var baz = 0, foo = 1, bar = 2; foo++; foo++; function f(x) { return x + foo + bar }",
+        cache,
+    );
+    assert_eq!(
+        second,
+        "var n = 0, e = 1, t = 2;\ne++;\ne++;\nfunction r(n) {\n\treturn n + e + t;\n}\n"
+    );
+    assert_eq!(cache.vars.len(), 4);
+
+    // Cached names which clash with a global or an export are not reused.
+    let cache = NameCache::from_json(r#"{"vars":{"props":{"$foo":"e","$bar":"t"}}}"#).unwrap();
+    let (third, cache) =
+        mangle_with_name_cache("var foo = 1, bar = 2; export var t; e(foo, bar)", cache);
+    assert_eq!(third, "var n = 1, i = 2;\nexport var t;\ne(n, i);\n");
+    assert_eq!(cache.vars["foo"], "n");
+    assert_eq!(cache.vars["bar"], "i");
+}
+
+#[test]
+fn rename_map() {
+    let allocator = Allocator::default();
+    let source_text = "function foo(param) { let { prop } = param; return () => prop; }";
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let options = MinifierOptions { mangle: Some(MangleOptions::default()), compress: None };
+    let ret = Minifier::new(options).with_rename_map(true).build(&allocator, &mut program);
+    let rename_map = ret.rename_map.unwrap();
+    assert_eq!(
+        rename_map.to_json(),
+        r#"{"symbols":[{"original":"param","renamed":"e","start":13,"end":18,"scope":{"name":"foo","start":0,"end":64}},{"original":"prop","renamed":"t","start":28,"end":32,"scope":{"name":"foo","start":0,"end":64}}]}"#
+    );
+
+    let ret = Minifier::new(MinifierOptions::default()).build(&allocator, &mut program);
+    assert!(ret.rename_map.is_none());
+}
+
+#[test]
+fn source_map_names() {
+    let allocator = Allocator::default();
+    let source_text =
+        "export function foo(param) { let { prop } = param; ({ prop } = param); return { prop }; }";
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let options = MinifierOptions { mangle: Some(MangleOptions::default()), compress: None };
+    let ret = Minifier::new(options).build(&allocator, &mut program);
+    let ret = Codegen::new()
+        .with_options(CodegenOptions {
+            source_map_path: Some("test.js".into()),
+            ..CodegenOptions::minify()
+        })
+        .with_scoping(ret.scoping)
+        .build(&program);
+    assert_eq!(ret.code, "export function foo(e){let{prop:t}=e;({prop:t}=e);return{prop:t}}");
+    let map = ret.map.unwrap();
+    let names = map
+        .get_tokens()
+        .filter_map(|token| {
+            let name = map.get_name(token.get_name_id()?)?;
+            Some(format!("{}:{name}", token.get_dst_col()))
+        })
+        .collect::<Vec<_>>();
+    // Every mangled identifier, including the values of shorthand properties, has a name.
+    assert_eq!(names, ["20:param", "32:prop", "35:param", "44:prop", "47:param", "62:prop"]);
+}
@@ -78,3 +78,27 @@ pub fn get_source_type(
         _ => ty,
     }
 }
+
+/// Convert a source map received from JavaScript, e.g. `inputSourceMap` option, to [`oxc_sourcemap::SourceMap`].
+///
+/// # Errors
+///
+/// * When `mappings` is invalid.
+pub fn get_input_source_map(
+    map: oxc_sourcemap::napi::SourceMap,
+) -> Result<oxc_sourcemap::SourceMap, String> {
+    let json = oxc_sourcemap::JSONSourceMap {
+        file: map.file,
+        mappings: map.mappings,
+        source_root: map.source_root,
+        sources: map.sources,
+        sources_content: map
+            .sources_content
+            .map(|contents| contents.into_iter().map(Some).collect()),
+        names: map.names,
+        debug_id: None,
+        x_google_ignore_list: map.x_google_ignorelist,
+    };
+    oxc_sourcemap::SourceMap::from_json(json)
+        .map_err(|err| format!("Invalid input source map: {err}"))
+}
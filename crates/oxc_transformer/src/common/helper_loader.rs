@@ -149,6 +149,7 @@ pub enum Helper {
     Extends,
     ObjectDestructuringEmpty,
     ObjectWithoutProperties,
+    ObjectWithoutPropertiesLoose,
     ToPropertyKey,
     DefineProperty,
     ClassPrivateFieldInitSpec,
@@ -168,6 +169,10 @@ pub enum Helper {
     DecorateParam,
     DecorateMetadata,
     UsingCtx,
+    ApplyDecs2311,
+    WrapRegExp,
+    InteropRequireDefault,
+    InteropRequireWildcard,
 }
 
 impl Helper {
@@ -182,6 +187,7 @@ impl Helper {
             Self::Extends => "extends",
             Self::ObjectDestructuringEmpty => "objectDestructuringEmpty",
             Self::ObjectWithoutProperties => "objectWithoutProperties",
+            Self::ObjectWithoutPropertiesLoose => "objectWithoutPropertiesLoose",
             Self::ToPropertyKey => "toPropertyKey",
             Self::DefineProperty => "defineProperty",
             Self::ClassPrivateFieldInitSpec => "classPrivateFieldInitSpec",
@@ -201,6 +207,10 @@ impl Helper {
             Self::DecorateParam => "decorateParam",
             Self::DecorateMetadata => "decorateMetadata",
             Self::UsingCtx => "usingCtx",
+            Self::ApplyDecs2311 => "applyDecs2311",
+            Self::WrapRegExp => "wrapRegExp",
+            Self::InteropRequireDefault => "interopRequireDefault",
+            Self::InteropRequireWildcard => "interopRequireWildcard",
         }
     }
 
@@ -227,6 +237,13 @@ impl HelperLoaderStore<'_> {
             used_helpers: RefCell::new(FxHashMap::default()),
         }
     }
+
+    /// Check if `source` is the source of a runtime helper import, e.g. `@babel/runtime/helpers/extends`.
+    pub fn is_helper_source(&self, source: &str) -> bool {
+        source
+            .strip_prefix(self.module_name.as_ref())
+            .is_some_and(|rest| rest.starts_with("/helpers/"))
+    }
 }
 
 // Public methods implemented directly on `TransformCtx`, as they need access to `TransformCtx::module_imports`.
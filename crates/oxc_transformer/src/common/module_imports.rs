@@ -118,6 +118,30 @@ impl<'a> ModuleImportsStore<'a> {
         self.add_import(source, Import::Named(NamedImport { imported, local }), front);
     }
 
+    /// Add side-effect `import` or `require` to top of program.
+    ///
+    /// * `import 'source';` or
+    /// * `require('source');`
+    ///
+    /// If `front` is `true`, `import`/`require` is added to front of the `import`s/`require`s.
+    pub fn add_side_effect_import(&self, source: Atom<'a>, front: bool) {
+        let mut imports = self.imports.borrow_mut();
+        match imports.entry(source) {
+            IndexMapEntry::Occupied(entry) => {
+                if front && entry.index() != 0 {
+                    entry.move_index(0);
+                }
+            }
+            IndexMapEntry::Vacant(entry) => {
+                if front {
+                    entry.shift_insert(0, vec![]);
+                } else {
+                    entry.insert(vec![]);
+                }
+            }
+        }
+    }
+
     /// Returns `true` if no imports have been scheduled for insertion.
     pub fn is_empty(&self) -> bool {
         self.imports.borrow().is_empty()
@@ -210,9 +234,11 @@ impl<'a> ModuleImportsStore<'a> {
             ),
         }));
 
+        // Side-effect import: `import 'source';`
+        let specifiers = if specifiers.is_empty() { None } else { Some(specifiers) };
         Statement::from(ctx.ast.module_declaration_import_declaration(
             SPAN,
-            Some(specifiers),
+            specifiers,
             ctx.ast.string_literal(SPAN, source, None),
             None,
             NONE,
@@ -237,12 +263,16 @@ impl<'a> ModuleImportsStore<'a> {
             let arg = Argument::from(ctx.ast.expression_string_literal(SPAN, source, None));
             ctx.ast.vec1(arg)
         };
-        let Some(Import::Default(local)) = names.into_iter().next() else { unreachable!() };
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, args, false);
+        // Side-effect require: `require('source');`
+        let Some(import) = names.into_iter().next() else {
+            return ctx.ast.statement_expression(SPAN, call);
+        };
+        let Import::Default(local) = import else { unreachable!() };
         let id = local.create_binding_pattern(ctx);
         let var_kind = VariableDeclarationKind::Var;
         let decl = {
-            let init = ctx.ast.expression_call(SPAN, callee, NONE, args, false);
-            let decl = ctx.ast.variable_declarator(SPAN, var_kind, id, Some(init), false);
+            let decl = ctx.ast.variable_declarator(SPAN, var_kind, id, Some(call), false);
             ctx.ast.vec1(decl)
         };
         Statement::from(ctx.ast.declaration_variable(SPAN, var_kind, decl, false))
@@ -62,7 +62,6 @@ pub struct CompilerAssumptions {
     pub object_rest_no_symbols: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub private_fields_as_symbols: bool,
 
     #[serde(default)]
@@ -122,7 +121,6 @@ pub struct CompilerAssumptions {
     pub set_public_class_fields: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub set_spread_properties: bool,
 
     #[serde(default)]
@@ -28,6 +28,9 @@ pub struct TransformCtx<'a> {
     /// Source path in the form of `<CWD>/path/to/file/input.js`
     pub source_path: PathBuf,
 
+    /// <https://babeljs.io/docs/options#cwd>
+    pub cwd: PathBuf,
+
     pub source_type: SourceType,
 
     pub source_text: &'a str,
@@ -63,6 +66,7 @@ impl TransformCtx<'_> {
             errors: RefCell::new(vec![]),
             filename,
             source_path: source_path.to_path_buf(),
+            cwd: options.cwd.clone(),
             source_type: SourceType::default(),
             source_text: "",
             module: options.env.module,
@@ -12,6 +12,16 @@ pub struct DecoratorOptions {
     #[serde(skip)]
     pub legacy: bool,
 
+    /// Enables the transform of decorators following the TC39 2023-11 decorators proposal,
+    /// including lowering of class auto-accessors (`accessor x = 1`).
+    ///
+    /// This is the same as `@babel/plugin-proposal-decorators` with `version: "2023-11"`.
+    /// Has no effect if `legacy` is true.
+    ///
+    /// <https://github.com/tc39/proposal-decorators>
+    #[serde(skip)]
+    pub standard: bool,
+
     /// Enables emitting decorator metadata.
     ///
     /// This option the same as [emitDecoratorMetadata](https://www.typescriptlang.org/tsconfig/#emitDecoratorMetadata)
@@ -0,0 +1,1770 @@
+//! Standard decorators (2023-11)
+//!
+//! This plugin transforms decorators following the [TC39 Decorators proposal](https://github.com/tc39/proposal-decorators)
+//! as of its 2023-11 version, by calling the `_applyDecs2311` helper to apply decorators.
+//!
+//! Class auto-accessors (`accessor x = 1`) are lowered to a private storage field plus a getter/setter
+//! pair, whether they are decorated or not.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! @dec
+//! class Class {
+//!   @dec prop = 0;
+//!   @dec accessor value = 1;
+//!   @dec method() {}
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _initClass, _init_prop, _init_extra_prop, _init_value, _init_extra_value, _initProto;
+//! let _Class;
+//! class Class {
+//!   static {
+//!     ({
+//!       e: [_init_value, _init_extra_value, _init_prop, _init_extra_prop, _initProto],
+//!       c: [_Class, _initClass]
+//!     } = _applyDecs2311(this, [dec], [[dec, 1, "value"], [dec, 2, "method"], [dec, 0, "prop"]]));
+//!   }
+//!   prop = (_initProto(this), _init_prop(this, 0));
+//!   #A = (_init_extra_prop(this), _init_value(this, 1));
+//!   get value() { return this.#A; }
+//!   set value(v) { this.#A = v; }
+//!   method() {}
+//!   constructor() { _init_extra_value(this); }
+//!   static { _initClass(); }
+//! }
+//! ```
+//!
+//! All references to a class declaration with class decorators, inside and outside of the class,
+//! are renamed to the alias the decorated class is assigned to (`Class` -> `_Class`).
+//! If the class is exported, the alias is exported instead: `export { _Class as Class }`.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-decorators](https://github.com/babel/babel/blob/v7.26.2/packages/babel-helper-create-class-features-plugin/src/decorators.ts)
+//! with `version: "2023-11"`.
+//!
+//! The transform happens in two phases:
+//!
+//! 1. In [`StandardDecorator::enter_class`], before the class body is visited, the class is restructured:
+//!    auto-accessors are lowered, decorated private methods are moved into the `applyDecs2311` call,
+//!    initializer calls are injected and the static block calling `applyDecs2311` is inserted.
+//!    This has to happen before other plugins (e.g. `class-properties`) visit the class body, so that
+//!    they see the new private elements and lower them too.
+//! 2. In [`StandardDecorator::exit_class`], decorator expressions which can't be referenced directly
+//!    (anything other than a plain identifier) are memoized into temp vars assigned before the class.
+//!    Decorators are left in place until this point, so that other plugins have already transformed them.
+//!
+//! Differences from Babel:
+//!
+//! * Static fields of a class with class decorators are defined on the original class,
+//!   before class decorators are applied, the same as TypeScript does.
+//!
+//! ## References:
+//! * Decorators proposal: <https://github.com/tc39/proposal-decorators>
+//! * Babel helper: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-helpers/src/helpers/applyDecs2311.ts>
+
+use std::{cell::Cell, mem};
+
+use oxc_allocator::{Address, Box as ArenaBox, CloneIn, GetAddress, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_syntax::{identifier::is_identifier_name, operator::AssignmentOperator};
+use oxc_traverse::{Ancestor, BoundIdentifier, MaybeBoundIdentifier, Traverse};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::{create_assignment, create_class_constructor},
+};
+
+/// Kind of a class element, as encoded in the flags passed to `applyDecs2311`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ElementKind {
+    Field = 0,
+    Accessor = 1,
+    Method = 2,
+    Getter = 3,
+    Setter = 4,
+}
+
+/// Flag for static elements.
+const STATIC: u8 = 8;
+/// Flag for elements whose decorators are passed as `[this, decorator]` pairs.
+const DECORATORS_HAVE_THIS: u8 = 16;
+
+/// A decorated class element, which becomes an entry of the `memberDecs` argument of `applyDecs2311`.
+///
+/// `[decorators, flags, key, ...private_args]`
+struct DecoratedElement<'a> {
+    kind: ElementKind,
+    r#static: bool,
+    decorators: Expression<'a>,
+    decorators_have_this: bool,
+    key: Expression<'a>,
+    /// Accessors for private fields and accessors (`o => o.#x`, `(o, v) => o.#x = v`),
+    /// or the function of private methods, getters and setters.
+    private_args: Vec<Expression<'a>>,
+    /// Bindings which the functions pushed by `applyDecs2311` for this element are assigned to.
+    initializers: Vec<BoundIdentifier<'a>>,
+}
+
+impl DecoratedElement<'_> {
+    /// `applyDecs2311` handles elements in 4 passes. Elements are sorted in the same order,
+    /// so that the order of the returned initializers matches the order of the elements.
+    fn pass(&self) -> u8 {
+        match (self.r#static, self.kind == ElementKind::Field) {
+            (true, false) => 0,
+            (false, false) => 1,
+            (true, true) => 2,
+            (false, true) => 3,
+        }
+    }
+}
+
+/// Decorators which are memoized into a temp var in [`StandardDecorator::exit_class`].
+struct MemoizedDecorators<'a> {
+    /// Address of the class or class element which the decorators are attached to
+    holder: Address,
+    binding: BoundIdentifier<'a>,
+    have_this: bool,
+}
+
+/// Data of a transformed class, passed from [`StandardDecorator::enter_class`] to
+/// [`StandardDecorator::exit_class`].
+struct ClassData<'a> {
+    class_decorators: Option<MemoizedDecorators<'a>>,
+    element_decorators: Vec<MemoizedDecorators<'a>>,
+    /// Binding the decorated class is assigned to, if class has class decorators.
+    class_alias: Option<BoundIdentifier<'a>>,
+}
+
+/// State used while restructuring a class in [`StandardDecorator::enter_class`].
+struct ClassTransform<'a> {
+    class_scope_id: ScopeId,
+    /// Scope of the static block which calls `applyDecs2311`, created on demand.
+    static_block_scope_id: Option<ScopeId>,
+    elements: Vec<DecoratedElement<'a>>,
+    element_decorators: Vec<MemoizedDecorators<'a>>,
+    /// Extra initializers of instance / static fields, waiting to be prepended to the next field
+    instance_extra_initializers: Vec<BoundIdentifier<'a>>,
+    static_extra_initializers: Vec<BoundIdentifier<'a>>,
+    has_proto_initializer: bool,
+    has_static_initializer: bool,
+    /// Name of a decorated non-static private element, used for the instance brand check
+    instance_brand: Option<Atom<'a>>,
+    /// Private names used in the class, to generate unique names for accessor storage
+    private_names: FxHashSet<Atom<'a>>,
+    next_storage_index: u32,
+}
+
+impl<'a> ClassTransform<'a> {
+    fn static_block_scope_id(&mut self, ctx: &mut TraverseCtx<'a>) -> ScopeId {
+        *self.static_block_scope_id.get_or_insert_with(|| {
+            ctx.create_child_scope(self.class_scope_id, ScopeFlags::ClassStaticBlock)
+        })
+    }
+
+    /// Generate a unique private name for accessor storage: `#A`, `#B`, ... `#Z`, `#A1`, ...
+    fn generate_storage_name(&mut self, ctx: &TraverseCtx<'a>) -> Atom<'a> {
+        loop {
+            let index = self.next_storage_index;
+            self.next_storage_index += 1;
+            let letter = char::from(b'A' + u8::try_from(index % 26).unwrap());
+            let name = if index < 26 {
+                ctx.ast.atom(letter.encode_utf8(&mut [0; 4]))
+            } else {
+                ctx.ast.atom(&format!("{letter}{}", index / 26))
+            };
+            if self.private_names.insert(name) {
+                return name;
+            }
+        }
+    }
+
+    fn pending_extra_initializers(&mut self, r#static: bool) -> &mut Vec<BoundIdentifier<'a>> {
+        if r#static {
+            &mut self.static_extra_initializers
+        } else {
+            &mut self.instance_extra_initializers
+        }
+    }
+}
+
+/// Key of a class element, which may be memoized into a temp var.
+enum ElementKey<'a> {
+    Literal(Expression<'a>),
+    Memoized(BoundIdentifier<'a>),
+}
+
+impl<'a> ElementKey<'a> {
+    fn create_expression(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match self {
+            Self::Literal(expr) => expr.clone_in(ctx.ast.allocator),
+            Self::Memoized(binding) => binding.create_read_expression(ctx),
+        }
+    }
+}
+
+/// Memoized decorators and class alias of the class expression which has just been exited,
+/// to be wrapped around the class expression in [`StandardDecorator::exit_expression`].
+struct ClassExpressionWrap<'a> {
+    assignments: Vec<Expression<'a>>,
+    class_alias: Option<BoundIdentifier<'a>>,
+}
+
+pub struct StandardDecorator<'a, 'ctx> {
+    /// Stack of classes being visited. `None` for classes which don't need transforming.
+    classes: Vec<Option<ClassData<'a>>>,
+    class_expression_wrap: Option<ClassExpressionWrap<'a>>,
+    /// Bindings which references to class declarations with class decorators are renamed to.
+    /// Keyed by symbol of the class declaration.
+    class_aliases: FxHashMap<SymbolId, BoundIdentifier<'a>>,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> StandardDecorator<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            classes: vec![],
+            class_expression_wrap: None,
+            class_aliases: FxHashMap::default(),
+            ctx,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for StandardDecorator<'a, '_> {
+    /// Generate aliases for class declarations with class decorators up front,
+    /// so that references which are visited before the class can be renamed too.
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut collector = DecoratedClassCollector::default();
+        collector.visit_program(program);
+        for symbol_id in collector.symbol_ids {
+            let scoping = ctx.scoping();
+            let name = scoping.symbol_name(symbol_id).to_string();
+            let scope_id = scoping.symbol_scope_id(symbol_id);
+            let alias = ctx.generate_uid(&name, scope_id, SymbolFlags::BlockScopedVariable);
+            self.class_aliases.insert(symbol_id, alias);
+        }
+    }
+
+    // `#[inline]` for fast exit for references which are not to decorated classes
+    #[inline]
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.class_aliases.is_empty() {
+            return;
+        }
+        let reference = ctx.scoping().get_reference(ident.reference_id());
+        let Some(alias) = reference.symbol_id().and_then(|id| self.class_aliases.get(&id)) else {
+            return;
+        };
+        // `Class` -> `_Class`
+        let flags = reference.flags();
+        ctx.delete_reference_for_identifier(ident);
+        *ident = alias.create_spanned_reference(ident.span, flags, ctx);
+    }
+
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        let data = if Self::class_needs_transform(class) {
+            Some(self.transform_class(class, ctx))
+        } else {
+            None
+        };
+        self.classes.push(data);
+    }
+
+    fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(data) = self.classes.pop().flatten() {
+            self.memoize_decorators(class, data, ctx);
+        }
+    }
+
+    // `#[inline]` for fast exit for statements which are not exports
+    #[inline]
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(
+            stmt,
+            Statement::ExportNamedDeclaration(_) | Statement::ExportDefaultDeclaration(_)
+        ) && !self.class_aliases.is_empty()
+        {
+            self.transform_export_class(stmt, ctx);
+        }
+    }
+
+    // `#[inline]` for fast exit for expressions which are not decorated classes
+    #[inline]
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(wrap) = self.class_expression_wrap.take() {
+            Self::wrap_class_expression(expr, wrap, ctx);
+        }
+    }
+}
+
+impl<'a> StandardDecorator<'a, '_> {
+    /// Unwrap exported class declaration with class decorators, and export its alias instead.
+    ///
+    /// * `export class Class {}` -> `class Class {} export { _Class as Class };`
+    /// * `export default class Class {}` -> `class Class {} export { _Class as default };`
+    fn transform_export_class(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let class = match stmt {
+            Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                Some(Declaration::ClassDeclaration(class)) => class,
+                _ => return,
+            },
+            Statement::ExportDefaultDeclaration(export) => match &export.declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => class,
+                _ => return,
+            },
+            _ => return,
+        };
+        let Some(alias) = class.id.as_ref().and_then(|id| self.class_aliases.get(&id.symbol_id()))
+        else {
+            return;
+        };
+
+        let (class, exported) = match stmt {
+            Statement::ExportNamedDeclaration(export) => {
+                let Some(Declaration::ClassDeclaration(class)) = export.declaration.take() else {
+                    unreachable!()
+                };
+                let exported = class.id.as_ref().unwrap().name;
+                (class, exported)
+            }
+            Statement::ExportDefaultDeclaration(export) => {
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) = &mut export.declaration
+                else {
+                    unreachable!()
+                };
+                (class.take_in_box(ctx.ast), Atom::from("default"))
+            }
+            _ => unreachable!(),
+        };
+
+        let kind = ImportOrExportKind::Value;
+        let local = ModuleExportName::IdentifierReference(alias.create_read_reference(ctx));
+        let exported = ctx.ast.module_export_name_identifier_name(SPAN, exported);
+        let specifiers = ctx.ast.vec1(ctx.ast.export_specifier(SPAN, local, exported, kind));
+        let export_alias =
+            Statement::from(ctx.ast.module_declaration_export_named_declaration(
+                SPAN, None, specifiers, None, kind, NONE,
+            ));
+
+        let new_stmt = Statement::ClassDeclaration(class);
+        self.ctx.statement_injector.move_insertions(stmt, &new_stmt);
+        self.ctx.statement_injector.insert_after(&new_stmt, export_alias);
+        *stmt = new_stmt;
+    }
+
+    fn class_needs_transform(class: &Class<'a>) -> bool {
+        if class.declare {
+            return false;
+        }
+        !class.decorators.is_empty()
+            || class.body.body.iter().any(|element| match element {
+                ClassElement::MethodDefinition(method) => !method.decorators.is_empty(),
+                ClassElement::PropertyDefinition(prop) => !prop.decorators.is_empty(),
+                ClassElement::AccessorProperty(accessor) => {
+                    accessor.r#type == AccessorPropertyType::AccessorProperty
+                }
+                _ => false,
+            })
+    }
+
+    /// Restructure class before its body is visited.
+    fn transform_class(
+        &mut self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassData<'a> {
+        let class_scope_id = class.scope_id();
+
+        let mut class_decorators = None;
+        let mut class_alias = None;
+        let mut init_class = None;
+        let mut class_decorators_arg = None;
+        if !class.decorators.is_empty() {
+            let alias = if class.is_declaration() {
+                if let Some(id) = &class.id {
+                    // Generated in `enter_program`
+                    self.class_aliases[&id.symbol_id()].clone()
+                } else {
+                    // `export default @dec class {}` -> `export default class _default {}`
+                    let binding = ctx.generate_uid_in_current_scope("default", SymbolFlags::Class);
+                    class.id = Some(binding.create_binding_identifier(ctx));
+                    let alias = ctx
+                        .generate_uid_in_current_scope("default", SymbolFlags::BlockScopedVariable);
+                    self.class_aliases.insert(binding.symbol_id, alias.clone());
+                    alias
+                }
+            } else {
+                let name = class.id.as_ref().map_or("Class", |id| id.name.as_str());
+                self.ctx.var_declarations.create_uid_var(name, ctx)
+            };
+            if let Some(id) = &class.id {
+                let class_binding = BoundIdentifier::from_binding_ident(id);
+                ClassReferenceChanger::new(&class_binding, &alias, ctx)
+                    .visit_class_body(&mut class.body);
+            }
+            class_alias = Some(alias);
+            init_class = Some(self.ctx.var_declarations.create_uid_var("initClass", ctx));
+
+            let holder = Address::from_ptr(class);
+            let (mut decorators, have_this) =
+                self.take_decorators(&mut class.decorators, holder, &mut class_decorators, ctx);
+            // `applyDecs2311` expects class decorators to be an array
+            if class_decorators.is_none() && !matches!(decorators, Expression::ArrayExpression(_)) {
+                let element = ArrayExpressionElement::from(decorators);
+                decorators = ctx.ast.expression_array(SPAN, ctx.ast.vec1(element));
+            }
+            class_decorators_arg = Some((decorators, have_this));
+        }
+
+        let mut private_names = FxHashSet::default();
+        for element in &class.body.body {
+            if let Some(name) = element.property_key().and_then(PropertyKey::private_name) {
+                private_names.insert(name);
+            }
+        }
+
+        let mut t = ClassTransform {
+            class_scope_id,
+            static_block_scope_id: None,
+            elements: vec![],
+            element_decorators: vec![],
+            instance_extra_initializers: vec![],
+            static_extra_initializers: vec![],
+            has_proto_initializer: false,
+            has_static_initializer: false,
+            instance_brand: None,
+            private_names,
+            next_storage_index: 0,
+        };
+
+        let body = mem::replace(&mut class.body.body, ctx.ast.vec());
+        let mut new_body = ctx.ast.vec_with_capacity(body.len() + 3);
+        for element in body {
+            match element {
+                ClassElement::MethodDefinition(method)
+                    if !method.decorators.is_empty()
+                        && method.r#type == MethodDefinitionType::MethodDefinition
+                        && method.kind != MethodDefinitionKind::Constructor =>
+                {
+                    let method = self.transform_method(method, &mut t, ctx);
+                    new_body.push(method);
+                }
+                ClassElement::PropertyDefinition(mut prop)
+                    if prop.r#type == PropertyDefinitionType::PropertyDefinition
+                        && !prop.declare =>
+                {
+                    self.transform_property(&mut prop, &mut t, ctx);
+                    new_body.push(ClassElement::PropertyDefinition(prop));
+                }
+                ClassElement::AccessorProperty(accessor)
+                    if accessor.r#type == AccessorPropertyType::AccessorProperty =>
+                {
+                    self.transform_accessor(accessor, &mut t, &mut new_body, ctx);
+                }
+                element => new_body.push(element),
+            }
+        }
+
+        // Static extra initializers which were not followed by a static field
+        if !t.static_extra_initializers.is_empty() {
+            let stmts = ctx.ast.vec_from_iter(t.static_extra_initializers.iter().map(|binding| {
+                let call = Self::create_initializer_call(binding, None, ctx);
+                ctx.ast.statement_expression(SPAN, call)
+            }));
+            let scope_id = ctx.create_child_scope(class_scope_id, ScopeFlags::ClassStaticBlock);
+            new_body.push(ctx.ast.class_element_static_block_with_scope_id(SPAN, stmts, scope_id));
+        }
+
+        let proto_initializer = t
+            .has_proto_initializer
+            .then(|| self.ctx.var_declarations.create_uid_var("initProto", ctx));
+        let static_initializer = t
+            .has_static_initializer
+            .then(|| self.ctx.var_declarations.create_uid_var("initStatic", ctx));
+
+        // Initializers which run when an instance is created, before any fields are defined.
+        // `_initProto(this)` is prepended to the first instance field, otherwise it goes
+        // in the constructor along with remaining extra initializers.
+        let mut constructor_initializers = mem::take(&mut t.instance_extra_initializers);
+        if let Some(proto_initializer) = &proto_initializer {
+            let first_instance_field = new_body.iter_mut().find_map(|element| match element {
+                ClassElement::PropertyDefinition(prop)
+                    if !prop.r#static
+                        && prop.r#type == PropertyDefinitionType::PropertyDefinition
+                        && !prop.declare =>
+                {
+                    Some(prop)
+                }
+                _ => None,
+            });
+            if let Some(prop) = first_instance_field {
+                let this = ctx.ast.expression_this(SPAN);
+                let call = Self::create_initializer_call(proto_initializer, Some(this), ctx);
+                prop.value = Self::prepend_expressions(prop.value.take(), vec![call], ctx);
+            } else {
+                constructor_initializers.insert(0, proto_initializer.clone());
+            }
+        }
+        if !constructor_initializers.is_empty() {
+            Self::insert_initializers_into_constructor(
+                &mut new_body,
+                &constructor_initializers,
+                class.super_class.is_some(),
+                class_scope_id,
+                ctx,
+            );
+        }
+
+        if !t.elements.is_empty() || class_alias.is_some() {
+            let parent_class = class.super_class.as_mut().map(|super_class| {
+                if let Expression::Identifier(ident) = super_class {
+                    MaybeBoundIdentifier::from_identifier_reference(ident, ctx)
+                        .create_read_expression(ctx)
+                } else {
+                    // `class C extends (_superClass = expr) {}`
+                    let binding = self.ctx.var_declarations.create_uid_var("superClass", ctx);
+                    let value = super_class.take_in(ctx.ast);
+                    *super_class = create_assignment(&binding, value, ctx);
+                    binding.create_read_expression(ctx)
+                }
+            });
+
+            let static_block = self.create_apply_decorators_static_block(
+                &mut t,
+                class_decorators_arg,
+                class_alias.as_ref().zip(init_class.as_ref()),
+                proto_initializer.as_ref(),
+                static_initializer.as_ref(),
+                parent_class,
+                ctx,
+            );
+            new_body.insert(0, static_block);
+        }
+
+        if let Some(init_class) = &init_class {
+            // `static { _initClass(); }`
+            let call = Self::create_initializer_call(init_class, None, ctx);
+            let stmts = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call));
+            let scope_id = ctx.create_child_scope(class_scope_id, ScopeFlags::ClassStaticBlock);
+            new_body.push(ctx.ast.class_element_static_block_with_scope_id(SPAN, stmts, scope_id));
+        }
+
+        class.body.body = new_body;
+
+        ClassData { class_decorators, element_decorators: t.element_decorators, class_alias }
+    }
+
+    /// Transform a decorated method, getter or setter.
+    ///
+    /// * Public: `@dec method() {}` is kept as is. `[dec, 2, "method"]` is added to `memberDecs`.
+    /// * Private: `@dec #method() {}` -> `get #method() { return _call_method; }`,
+    ///   and `[dec, 2, "method", function () {}]` is added to `memberDecs`.
+    fn transform_method(
+        &self,
+        mut method: ArenaBox<'a, MethodDefinition<'a>>,
+        t: &mut ClassTransform<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let kind = match method.kind {
+            MethodDefinitionKind::Get => ElementKind::Getter,
+            MethodDefinitionKind::Set => ElementKind::Setter,
+            MethodDefinitionKind::Method | MethodDefinitionKind::Constructor => ElementKind::Method,
+        };
+        let is_static = method.r#static;
+        if is_static {
+            t.has_static_initializer = true;
+        } else {
+            t.has_proto_initializer = true;
+        }
+
+        let PropertyKey::PrivateIdentifier(ident) = &method.key else {
+            let computed = method.computed;
+            let key = self.get_element_key(&mut method.key, computed, ctx);
+            let key = key.create_expression(ctx);
+            let holder = method.address();
+            let (decorators, decorators_have_this) =
+                self.take_element_decorators(&mut method.decorators, holder, t, ctx);
+            t.elements.push(DecoratedElement {
+                kind,
+                r#static: is_static,
+                decorators,
+                decorators_have_this,
+                key,
+                private_args: vec![],
+                initializers: vec![],
+            });
+            return ClassElement::MethodDefinition(method);
+        };
+
+        let name = ident.name;
+        if !is_static {
+            t.instance_brand.get_or_insert(name);
+        }
+
+        let MethodDefinition { decorators, key, value: function, .. } = method.unbox();
+        let static_block_scope_id = t.static_block_scope_id(ctx);
+        // The function is moved into the static block as a plain function expression
+        let function_scope_id = function.scope_id();
+        ctx.scoping_mut().change_scope_parent_id(function_scope_id, Some(static_block_scope_id));
+        ctx.scoping_mut()
+            .scope_flags_mut(function_scope_id)
+            .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+
+        let call_binding = self.create_element_uid_var("call", name, ctx);
+        let mut accessor = match kind {
+            // `get #method() { return _call_method; }`
+            ElementKind::Method => {
+                let body = call_binding.create_read_expression(ctx);
+                Self::create_getter(key, false, is_static, body, t.class_scope_id, ctx)
+            }
+            // `get #getter() { return _call_getter(this); }`
+            ElementKind::Getter => {
+                let this = ctx.ast.expression_this(SPAN);
+                let body = Self::create_initializer_call(&call_binding, Some(this), ctx);
+                Self::create_getter(key, false, is_static, body, t.class_scope_id, ctx)
+            }
+            // `set #setter(v) { _call_setter(this, v); }`
+            ElementKind::Setter => {
+                Self::create_setter(key, false, is_static, t.class_scope_id, ctx, |value, ctx| {
+                    let callee = call_binding.create_read_expression(ctx);
+                    let arguments = ctx.ast.vec_from_array([
+                        Argument::from(ctx.ast.expression_this(SPAN)),
+                        Argument::from(value),
+                    ]);
+                    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+                })
+            }
+            ElementKind::Field | ElementKind::Accessor => unreachable!(),
+        };
+
+        let ClassElement::MethodDefinition(accessor_method) = &mut accessor else { unreachable!() };
+        accessor_method.decorators = decorators;
+        let holder = accessor_method.address();
+        let (decorators, decorators_have_this) =
+            self.take_element_decorators(&mut accessor_method.decorators, holder, t, ctx);
+
+        t.elements.push(DecoratedElement {
+            kind,
+            r#static: is_static,
+            decorators,
+            decorators_have_this,
+            key: ctx.ast.expression_string_literal(SPAN, name, None),
+            private_args: vec![Expression::FunctionExpression(function)],
+            initializers: vec![call_binding],
+        });
+
+        accessor
+    }
+
+    /// Transform a field.
+    ///
+    /// * Decorated: `@dec prop = 1` -> `prop = _init_prop(this, 1)`,
+    ///   and `[dec, 0, "prop"]` is added to `memberDecs`.
+    /// * Extra initializers of a previous decorated field are prepended to the initializer:
+    ///   `prop = (_init_extra_prev(this), 1)`.
+    fn transform_property(
+        &self,
+        prop: &mut ArenaBox<'a, PropertyDefinition<'a>>,
+        t: &mut ClassTransform<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_static = prop.r#static;
+        let pending = mem::take(t.pending_extra_initializers(is_static));
+        let value = prop.value.take();
+
+        if prop.decorators.is_empty() {
+            prop.value = Self::prepend_extra_initializers(value, &pending, is_static, ctx);
+            return;
+        }
+
+        let private_name = prop.key.private_name();
+        let name_hint = Self::get_name_hint(&prop.key);
+        let computed = prop.computed;
+        let key = self.get_element_key(&mut prop.key, computed, ctx);
+        let holder = prop.address();
+        let (decorators, decorators_have_this) =
+            self.take_element_decorators(&mut prop.decorators, holder, t, ctx);
+
+        let init_binding = self.create_element_uid_var("init", name_hint, ctx);
+        let extra_binding = self.create_element_uid_var("init_extra", name_hint, ctx);
+        let value = Some(Self::create_field_initializer_call(&init_binding, value, is_static, ctx));
+
+        let (key, private_args) = if let Some(name) = private_name {
+            if !is_static {
+                t.instance_brand.get_or_insert(name);
+            }
+            let static_block_scope_id = t.static_block_scope_id(ctx);
+            let private_args = vec![
+                Self::create_private_getter(name, static_block_scope_id, ctx),
+                Self::create_private_setter(name, static_block_scope_id, ctx),
+            ];
+            (ctx.ast.expression_string_literal(SPAN, name, None), private_args)
+        } else {
+            (key.create_expression(ctx), vec![])
+        };
+
+        t.elements.push(DecoratedElement {
+            kind: ElementKind::Field,
+            r#static: is_static,
+            decorators,
+            decorators_have_this,
+            key,
+            private_args,
+            initializers: vec![init_binding, extra_binding.clone()],
+        });
+        prop.value = Self::prepend_extra_initializers(value, &pending, is_static, ctx);
+        t.pending_extra_initializers(is_static).push(extra_binding);
+    }
+
+    /// Transform an auto-accessor.
+    ///
+    /// `accessor x = 1` ->
+    /// ```js
+    /// #A = 1;
+    /// get x() { return this.#A; }
+    /// set x(v) { this.#A = v; }
+    /// ```
+    ///
+    /// If decorated, the storage field is initialized with `_init_x(this, 1)`, and for private accessors,
+    /// the getter and setter call `_get_x(this)` and `_set_x(this, v)` returned by `applyDecs2311`.
+    fn transform_accessor(
+        &self,
+        accessor: ArenaBox<'a, AccessorProperty<'a>>,
+        t: &mut ClassTransform<'a>,
+        new_body: &mut ArenaVec<'a, ClassElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let AccessorProperty {
+            span,
+            decorators,
+            mut key,
+            value,
+            computed,
+            r#static: is_static,
+            ..
+        } = accessor.unbox();
+        let storage_name = t.generate_storage_name(ctx);
+        let private_name = key.private_name();
+        let name_hint = Self::get_name_hint(&key);
+        let element_key = self.get_element_key(&mut key, computed, ctx);
+        // Key of setter. Same as getter's key, or reference to memoized computed key.
+        let setter_key = match &element_key {
+            ElementKey::Memoized(binding) => PropertyKey::from(binding.create_read_expression(ctx)),
+            ElementKey::Literal(_) => key.clone_in(ctx.ast.allocator),
+        };
+
+        let pending = mem::take(t.pending_extra_initializers(is_static));
+
+        // `#A = value;`
+        let mut storage = ctx.ast.alloc_property_definition(
+            span,
+            PropertyDefinitionType::PropertyDefinition,
+            decorators,
+            ctx.ast.property_key_private_identifier(SPAN, storage_name),
+            NONE,
+            None,
+            false,
+            is_static,
+            false,
+            false,
+            false,
+            false,
+            false,
+            None,
+        );
+
+        let mut value = value;
+        let mut private_accessors = None;
+        if storage.decorators.is_empty() {
+            storage.value = Self::prepend_extra_initializers(value, &pending, is_static, ctx);
+        } else {
+            let holder = storage.address();
+            let (decorators, decorators_have_this) =
+                self.take_element_decorators(&mut storage.decorators, holder, t, ctx);
+            let init_binding = self.create_element_uid_var("init", name_hint, ctx);
+            let extra_binding = self.create_element_uid_var("init_extra", name_hint, ctx);
+            value = Some(Self::create_field_initializer_call(&init_binding, value, is_static, ctx));
+            storage.value = Self::prepend_extra_initializers(value, &pending, is_static, ctx);
+
+            let (key, private_args, initializers) = if let Some(name) = private_name {
+                if !is_static {
+                    t.instance_brand.get_or_insert(name);
+                }
+                let static_block_scope_id = t.static_block_scope_id(ctx);
+                let private_args = vec![
+                    Self::create_private_getter(storage_name, static_block_scope_id, ctx),
+                    Self::create_private_setter(storage_name, static_block_scope_id, ctx),
+                ];
+                let get_binding = self.create_element_uid_var("get", name_hint, ctx);
+                let set_binding = self.create_element_uid_var("set", name_hint, ctx);
+                private_accessors = Some((get_binding.clone(), set_binding.clone()));
+                let initializers =
+                    vec![init_binding, get_binding, set_binding, extra_binding.clone()];
+                (ctx.ast.expression_string_literal(SPAN, name, None), private_args, initializers)
+            } else {
+                let initializers = vec![init_binding, extra_binding.clone()];
+                (element_key.create_expression(ctx), vec![], initializers)
+            };
+
+            t.elements.push(DecoratedElement {
+                kind: ElementKind::Accessor,
+                r#static: is_static,
+                decorators,
+                decorators_have_this,
+                key,
+                private_args,
+                initializers,
+            });
+            t.pending_extra_initializers(is_static).push(extra_binding);
+        }
+
+        let class_scope_id = t.class_scope_id;
+        let (getter, setter) = if let Some((get_binding, set_binding)) = private_accessors {
+            // `get #x() { return _get_x(this); }`
+            let this = (!is_static).then(|| ctx.ast.expression_this(SPAN));
+            let body = Self::create_initializer_call(&get_binding, this, ctx);
+            let getter = Self::create_getter(key, computed, is_static, body, class_scope_id, ctx);
+            // `set #x(v) { _set_x(this, v); }`
+            let setter = Self::create_setter(
+                setter_key,
+                computed,
+                is_static,
+                class_scope_id,
+                ctx,
+                |value, ctx| {
+                    let callee = set_binding.create_read_expression(ctx);
+                    let mut arguments = ctx.ast.vec_with_capacity(2);
+                    if !is_static {
+                        arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+                    }
+                    arguments.push(Argument::from(value));
+                    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+                },
+            );
+            (getter, setter)
+        } else {
+            // `get x() { return this.#A; }`
+            let body =
+                Self::create_private_field_access(ctx.ast.expression_this(SPAN), storage_name, ctx);
+            let getter = Self::create_getter(key, computed, is_static, body, class_scope_id, ctx);
+            // `set x(v) { this.#A = v; }`
+            let setter = Self::create_setter(
+                setter_key,
+                computed,
+                is_static,
+                class_scope_id,
+                ctx,
+                |value, ctx| {
+                    let target = Self::create_private_field_target(
+                        ctx.ast.expression_this(SPAN),
+                        storage_name,
+                        ctx,
+                    );
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+                },
+            );
+            (getter, setter)
+        };
+
+        new_body.push(ClassElement::PropertyDefinition(storage));
+        new_body.push(getter);
+        new_body.push(setter);
+    }
+
+    /// Take decorators of a class element.
+    ///
+    /// If all decorators are identifiers, they're moved into `memberDecs` directly.
+    /// Otherwise, they're left in place to be memoized in [`Self::exit_class`],
+    /// and a reference to the temp var is returned.
+    fn take_element_decorators(
+        &self,
+        decorators: &mut ArenaVec<'a, Decorator<'a>>,
+        holder: Address,
+        t: &mut ClassTransform<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, bool) {
+        let mut memoized = None;
+        let result = self.take_decorators(decorators, holder, &mut memoized, ctx);
+        t.element_decorators.extend(memoized);
+        result
+    }
+
+    fn take_decorators(
+        &self,
+        decorators: &mut ArenaVec<'a, Decorator<'a>>,
+        holder: Address,
+        memoized: &mut Option<MemoizedDecorators<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, bool) {
+        if decorators.iter().all(|decorator| decorator.expression.is_identifier_reference()) {
+            let expr = if decorators.len() == 1 {
+                decorators.pop().unwrap().expression
+            } else {
+                let elements = decorators
+                    .drain(..)
+                    .map(|decorator| ArrayExpressionElement::from(decorator.expression));
+                ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(elements))
+            };
+            return (expr, false);
+        }
+
+        let have_this =
+            decorators.iter().any(|decorator| Self::is_member_decorator(&decorator.expression));
+        let binding = self.ctx.var_declarations.create_uid_var("dec", ctx);
+        let expr = binding.create_read_expression(ctx);
+        *memoized = Some(MemoizedDecorators { holder, binding, have_this });
+        (expr, have_this)
+    }
+
+    /// Whether a decorator is a member expression, which is called with its object as `this`.
+    fn is_member_decorator(expr: &Expression<'a>) -> bool {
+        matches!(
+            expr.without_parentheses(),
+            Expression::StaticMemberExpression(_) | Expression::ComputedMemberExpression(_)
+        )
+    }
+
+    /// Get the key of a class element as passed to `applyDecs2311`.
+    ///
+    /// Computed keys which are not literals are memoized:
+    /// `[foo()]` -> `[_computedKey = _toPropertyKey(foo())]`
+    fn get_element_key(
+        &self,
+        key: &mut PropertyKey<'a>,
+        computed: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ElementKey<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ElementKey::Literal(ctx.ast.expression_string_literal(SPAN, ident.name, None))
+            }
+            PropertyKey::PrivateIdentifier(ident) => {
+                ElementKey::Literal(ctx.ast.expression_string_literal(SPAN, ident.name, None))
+            }
+            PropertyKey::StringLiteral(_) | PropertyKey::NumericLiteral(_) => {
+                ElementKey::Literal(key.to_expression().clone_in(ctx.ast.allocator))
+            }
+            _ if !computed => ElementKey::Literal(key.to_expression().clone_in(ctx.ast.allocator)),
+            _ => {
+                let binding = self.ctx.var_declarations.create_uid_var("computedKey", ctx);
+                let expr = key.to_expression_mut().take_in(ctx.ast);
+                let arguments = ctx.ast.vec1(Argument::from(expr));
+                let value = self.ctx.helper_call_expr(Helper::ToPropertyKey, SPAN, arguments, ctx);
+                *key = PropertyKey::from(create_assignment(&binding, value, ctx));
+                ElementKey::Memoized(binding)
+            }
+        }
+    }
+
+    /// Get name used for naming initializer temp vars of an element.
+    fn get_name_hint(key: &PropertyKey<'a>) -> Atom<'a> {
+        let name = match key {
+            PropertyKey::StaticIdentifier(ident) => ident.name,
+            PropertyKey::PrivateIdentifier(ident) => ident.name,
+            PropertyKey::StringLiteral(lit) => lit.value,
+            _ => return Atom::from("computedKey"),
+        };
+        if is_identifier_name(&name) { name } else { Atom::from("computedKey") }
+    }
+
+    fn create_element_uid_var(
+        &self,
+        prefix: &str,
+        name: Atom<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        self.ctx.var_declarations.create_uid_var(&format!("{prefix}_{name}"), ctx)
+    }
+
+    /// `_init(arg)` or `_init()`
+    fn create_initializer_call(
+        binding: &BoundIdentifier<'a>,
+        argument: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = binding.create_read_expression(ctx);
+        let arguments = ctx.ast.vec_from_iter(argument.map(Argument::from));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// * Instance field: `_init_x(this, value)`
+    /// * Static field: `_init_x(value)`
+    fn create_field_initializer_call(
+        binding: &BoundIdentifier<'a>,
+        value: Option<Expression<'a>>,
+        is_static: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = binding.create_read_expression(ctx);
+        let mut arguments = ctx.ast.vec_with_capacity(2);
+        if !is_static {
+            arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+        }
+        arguments.extend(value.map(Argument::from));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// `value` -> `(_init_extra_a(this), _init_extra_b(this), value)`
+    fn prepend_extra_initializers(
+        value: Option<Expression<'a>>,
+        extra_initializers: &[BoundIdentifier<'a>],
+        is_static: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let calls = extra_initializers
+            .iter()
+            .map(|binding| {
+                let this = (!is_static).then(|| ctx.ast.expression_this(SPAN));
+                Self::create_initializer_call(binding, this, ctx)
+            })
+            .collect();
+        Self::prepend_expressions(value, calls, ctx)
+    }
+
+    /// `value` -> `(a, b, value)`. `value` is `void 0` if there is none.
+    fn prepend_expressions(
+        value: Option<Expression<'a>>,
+        mut exprs: Vec<Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if exprs.is_empty() {
+            return value;
+        }
+        exprs.push(value.unwrap_or_else(|| ctx.ast.void_0(SPAN)));
+        Some(ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter(exprs)))
+    }
+
+    /// Insert initializers which must run before fields are defined into class constructor.
+    ///
+    /// * No constructor: `constructor(..._args) { super(..._args); _initProto(this); }`
+    /// * Base class: `constructor() { _initProto(this); ... }`
+    /// * Derived class: `constructor() { super(); _initProto(this); ... }`.
+    ///   If `super()` is not a top-level statement, all `super()` calls are wrapped instead:
+    ///   `_initProto(super())`. The initializers return the instance.
+    fn insert_initializers_into_constructor(
+        body: &mut ArenaVec<'a, ClassElement<'a>>,
+        initializers: &[BoundIdentifier<'a>],
+        has_super_class: bool,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let create_stmts = |ctx: &mut TraverseCtx<'a>| {
+            initializers
+                .iter()
+                .map(|binding| {
+                    let this = ctx.ast.expression_this(SPAN);
+                    let call = Self::create_initializer_call(binding, Some(this), ctx);
+                    ctx.ast.statement_expression(SPAN, call)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let constructor = body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(method)
+                if method.kind == MethodDefinitionKind::Constructor =>
+            {
+                method.value.body.as_mut()
+            }
+            _ => None,
+        });
+
+        let Some(constructor_body) = constructor else {
+            let scope_id = ctx
+                .create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::Constructor);
+            let stmts = create_stmts(ctx);
+            body.push(create_class_constructor(stmts, has_super_class, scope_id, ctx));
+            return;
+        };
+
+        if !has_super_class {
+            let stmts = create_stmts(ctx);
+            constructor_body.statements.splice(0..0, stmts);
+            return;
+        }
+
+        let super_call_index = constructor_body.statements.iter().position(|stmt| {
+            matches!(stmt, Statement::ExpressionStatement(expr_stmt)
+                if matches!(&expr_stmt.expression, Expression::CallExpression(call) if call.callee.is_super()))
+        });
+        if let Some(index) = super_call_index {
+            let stmts = create_stmts(ctx);
+            constructor_body.statements.splice((index + 1)..=index, stmts);
+        } else {
+            SuperCallWrapper { initializers, ctx }.visit_function_body(constructor_body);
+        }
+    }
+
+    /// Create the static block which applies decorators.
+    ///
+    /// ```js
+    /// static {
+    ///   [_init_x, _init_extra_x, _initProto] = _applyDecs2311(this, [], [[dec, 0, "x"]]).e;
+    ///   _initStatic(this);
+    /// }
+    /// ```
+    ///
+    /// When the class is decorated:
+    /// ```js
+    /// static {
+    ///   ({ e: [_init_x, _init_extra_x], c: [_Class, _initClass] } = _applyDecs2311(this, [dec], [[dec, 0, "x"]]));
+    /// }
+    /// ```
+    #[expect(clippy::too_many_arguments)]
+    fn create_apply_decorators_static_block(
+        &self,
+        t: &mut ClassTransform<'a>,
+        class_decorators: Option<(Expression<'a>, bool)>,
+        class_alias: Option<(&BoundIdentifier<'a>, &BoundIdentifier<'a>)>,
+        proto_initializer: Option<&BoundIdentifier<'a>>,
+        static_initializer: Option<&BoundIdentifier<'a>>,
+        parent_class: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let scope_id = t.static_block_scope_id(ctx);
+
+        let mut elements = mem::take(&mut t.elements);
+        elements.sort_by_key(DecoratedElement::pass);
+
+        let mut initializers = vec![];
+        let member_decorators = ctx.ast.vec_from_iter(elements.into_iter().map(|element| {
+            let mut flags = element.kind as u8;
+            if element.r#static {
+                flags |= STATIC;
+            }
+            if element.decorators_have_this {
+                flags |= DECORATORS_HAVE_THIS;
+            }
+            initializers.extend(element.initializers);
+            let mut items = ctx.ast.vec_with_capacity(3 + element.private_args.len());
+            items.push(ArrayExpressionElement::from(element.decorators));
+            items.push(ArrayExpressionElement::from(ctx.ast.expression_numeric_literal(
+                SPAN,
+                f64::from(flags),
+                None,
+                NumberBase::Decimal,
+            )));
+            items.push(ArrayExpressionElement::from(element.key));
+            items.extend(element.private_args.into_iter().map(ArrayExpressionElement::from));
+            ArrayExpressionElement::from(ctx.ast.expression_array(SPAN, items))
+        }));
+        initializers.extend(proto_initializer.cloned());
+        initializers.extend(static_initializer.cloned());
+
+        // `_applyDecs2311(this, classDecs, memberDecs, classDecsHaveThis, instanceBrand, parentClass)`
+        let (class_decorators, class_decorators_have_this) = class_decorators
+            .unwrap_or_else(|| (ctx.ast.expression_array(SPAN, ctx.ast.vec()), false));
+        let mut arguments = ctx.ast.vec_from_array([
+            Argument::from(ctx.ast.expression_this(SPAN)),
+            Argument::from(class_decorators),
+            Argument::from(ctx.ast.expression_array(SPAN, member_decorators)),
+        ]);
+        let instance_brand =
+            t.instance_brand.map(|name| Self::create_brand_check(name, scope_id, ctx));
+        if class_decorators_have_this || instance_brand.is_some() || parent_class.is_some() {
+            arguments.push(Argument::from(ctx.ast.expression_numeric_literal(
+                SPAN,
+                if class_decorators_have_this { 1.0 } else { 0.0 },
+                None,
+                NumberBase::Decimal,
+            )));
+            if instance_brand.is_some() || parent_class.is_some() {
+                let instance_brand = instance_brand.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+                arguments.push(Argument::from(instance_brand));
+                arguments.extend(parent_class.map(Argument::from));
+            }
+        }
+        let call = self.ctx.helper_call_expr(Helper::ApplyDecs2311, SPAN, arguments, ctx);
+
+        let element_initializers = (!initializers.is_empty())
+            .then(|| Self::create_array_assignment_target(initializers.iter(), ctx));
+        let expr = match (class_alias, element_initializers) {
+            // `({ e: [...], c: [_Class, _initClass] } = _applyDecs2311(...))`
+            (Some((class_alias, init_class)), Some(element_initializers)) => {
+                let class_targets = Self::create_array_assignment_target(
+                    <[_; 2]>::from((class_alias, init_class)).into_iter(),
+                    ctx,
+                );
+                let properties = ctx.ast.vec_from_array([
+                    Self::create_assignment_target_property("e", element_initializers, ctx),
+                    Self::create_assignment_target_property("c", class_targets, ctx),
+                ]);
+                let target = AssignmentTarget::from(
+                    ctx.ast
+                        .assignment_target_pattern_object_assignment_target(SPAN, properties, None),
+                );
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, call)
+            }
+            // `[_Class, _initClass] = _applyDecs2311(...).c`
+            (Some((class_alias, init_class)), None) => {
+                let target = Self::create_array_assignment_target(
+                    <[_; 2]>::from((class_alias, init_class)).into_iter(),
+                    ctx,
+                );
+                let value = create_member(call, "c", ctx);
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+            }
+            // `[...] = _applyDecs2311(...).e`
+            (None, Some(element_initializers)) => {
+                let value = create_member(call, "e", ctx);
+                ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    element_initializers,
+                    value,
+                )
+            }
+            (None, None) => call,
+        };
+
+        let mut stmts = ctx.ast.vec_with_capacity(2);
+        stmts.push(ctx.ast.statement_expression(SPAN, expr));
+        if let Some(static_initializer) = static_initializer {
+            // `_initStatic(this)`
+            let this = ctx.ast.expression_this(SPAN);
+            let call = Self::create_initializer_call(static_initializer, Some(this), ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+        ctx.ast.class_element_static_block_with_scope_id(SPAN, stmts, scope_id)
+    }
+
+    fn create_array_assignment_target<'b>(
+        bindings: impl Iterator<Item = &'b BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AssignmentTarget<'a>
+    where
+        'a: 'b,
+    {
+        let elements = ctx.ast.vec_from_iter(bindings.map(|binding| {
+            Some(AssignmentTargetMaybeDefault::from(binding.create_write_target(ctx)))
+        }));
+        AssignmentTarget::from(
+            ctx.ast.assignment_target_pattern_array_assignment_target(SPAN, elements, None),
+        )
+    }
+
+    /// `name: target`
+    fn create_assignment_target_property(
+        name: &'static str,
+        target: AssignmentTarget<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> AssignmentTargetProperty<'a> {
+        ctx.ast.assignment_target_property_assignment_target_property_property(
+            SPAN,
+            ctx.ast.property_key_static_identifier(SPAN, name),
+            AssignmentTargetMaybeDefault::from(target),
+            false,
+        )
+    }
+
+    /// `o => o.#name`
+    fn create_private_getter(
+        name: Atom<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id =
+            ctx.create_child_scope(parent_scope_id, ScopeFlags::Function | ScopeFlags::Arrow);
+        let object =
+            ctx.generate_binding(Atom::from("o"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let body = Self::create_private_field_access(object.create_read_expression(ctx), name, ctx);
+        Self::create_arrow_function(&[&object], body, scope_id, ctx)
+    }
+
+    /// `(o, v) => o.#name = v`
+    fn create_private_setter(
+        name: Atom<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id =
+            ctx.create_child_scope(parent_scope_id, ScopeFlags::Function | ScopeFlags::Arrow);
+        let object =
+            ctx.generate_binding(Atom::from("o"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let value =
+            ctx.generate_binding(Atom::from("v"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let target =
+            Self::create_private_field_target(object.create_read_expression(ctx), name, ctx);
+        let body = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            target,
+            value.create_read_expression(ctx),
+        );
+        Self::create_arrow_function(&[&object, &value], body, scope_id, ctx)
+    }
+
+    /// `_ => #name in _`
+    fn create_brand_check(
+        name: Atom<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id =
+            ctx.create_child_scope(parent_scope_id, ScopeFlags::Function | ScopeFlags::Arrow);
+        let object =
+            ctx.generate_binding(Atom::from("_"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let body = ctx.ast.expression_private_in(
+            SPAN,
+            ctx.ast.private_identifier(SPAN, name),
+            object.create_read_expression(ctx),
+        );
+        Self::create_arrow_function(&[&object], body, scope_id, ctx)
+    }
+
+    /// `(a, b) => body`
+    fn create_arrow_function(
+        params: &[&BoundIdentifier<'a>],
+        body: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let items =
+            ctx.ast.vec_from_iter(params.iter().map(|param| {
+                ctx.ast.plain_formal_parameter(SPAN, param.create_binding_pattern(ctx))
+            }));
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            items,
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, body)),
+        );
+        ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            SPAN, true, false, NONE, params, NONE, body, scope_id, false, false,
+        )
+    }
+
+    /// `object.#name`
+    fn create_private_field_access(
+        object: Expression<'a>,
+        name: Atom<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let field = ctx.ast.private_identifier(SPAN, name);
+        Expression::from(
+            ctx.ast.member_expression_private_field_expression(SPAN, object, field, false),
+        )
+    }
+
+    /// `object.#name` as assignment target
+    fn create_private_field_target(
+        object: Expression<'a>,
+        name: Atom<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> AssignmentTarget<'a> {
+        let field = ctx.ast.private_identifier(SPAN, name);
+        AssignmentTarget::from(
+            ctx.ast.member_expression_private_field_expression(SPAN, object, field, false),
+        )
+    }
+
+    /// `get key() { return body; }`
+    fn create_getter(
+        key: PropertyKey<'a>,
+        computed: bool,
+        is_static: bool,
+        body: Expression<'a>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let scope_id =
+            ctx.create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::GetAccessor);
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let stmts = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(body)));
+        Self::create_accessor_method(
+            MethodDefinitionKind::Get,
+            key,
+            computed,
+            is_static,
+            params,
+            stmts,
+            scope_id,
+            ctx,
+        )
+    }
+
+    /// `set key(v) { body; }`
+    fn create_setter(
+        key: PropertyKey<'a>,
+        computed: bool,
+        is_static: bool,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+        create_body: impl FnOnce(Expression<'a>, &mut TraverseCtx<'a>) -> Expression<'a>,
+    ) -> ClassElement<'a> {
+        let scope_id =
+            ctx.create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::SetAccessor);
+        let value =
+            ctx.generate_binding(Atom::from("v"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let items =
+            ctx.ast.vec1(ctx.ast.plain_formal_parameter(SPAN, value.create_binding_pattern(ctx)));
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            items,
+            NONE,
+        );
+        let body = create_body(value.create_read_expression(ctx), ctx);
+        let stmts = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, body));
+        Self::create_accessor_method(
+            MethodDefinitionKind::Set,
+            key,
+            computed,
+            is_static,
+            params,
+            stmts,
+            scope_id,
+            ctx,
+        )
+    }
+
+    #[expect(clippy::too_many_arguments)]
+    fn create_accessor_method(
+        kind: MethodDefinitionKind,
+        key: PropertyKey<'a>,
+        computed: bool,
+        is_static: bool,
+        params: ArenaBox<'a, FormalParameters<'a>>,
+        stmts: ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let function = ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts)),
+            scope_id,
+        );
+        ctx.ast.class_element_method_definition(
+            SPAN,
+            MethodDefinitionType::MethodDefinition,
+            ctx.ast.vec(),
+            key,
+            function,
+            kind,
+            computed,
+            is_static,
+            false,
+            false,
+            None,
+        )
+    }
+
+    /// Memoize decorators which were not moved into `applyDecs2311` call, now that they have
+    /// been transformed by other plugins.
+    ///
+    /// * Class declaration: `_dec = dec(); class C { ... }`
+    /// * Class expression: `(_dec = dec(), class { ... })`
+    fn memoize_decorators(
+        &mut self,
+        class: &mut Class<'a>,
+        data: ClassData<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let ClassData { class_decorators, element_decorators, class_alias } = data;
+
+        let mut assignments = vec![];
+        if let Some(memoized) = class_decorators {
+            let decorators = mem::replace(&mut class.decorators, ctx.ast.vec());
+            let mut value = self.create_decorators_value(decorators, memoized.have_this, ctx);
+            // `applyDecs2311` expects class decorators to be an array
+            if !matches!(value, Expression::ArrayExpression(_)) {
+                let element = ArrayExpressionElement::from(value);
+                value = ctx.ast.expression_array(SPAN, ctx.ast.vec1(element));
+            }
+            assignments.push(create_assignment(&memoized.binding, value, ctx));
+        }
+
+        if !element_decorators.is_empty() {
+            let ast = ctx.ast;
+            let mut decorators_by_holder = class
+                .body
+                .body
+                .iter_mut()
+                .filter_map(|element| {
+                    let (holder, decorators) = match element {
+                        ClassElement::MethodDefinition(method) => {
+                            (method.address(), &mut method.decorators)
+                        }
+                        ClassElement::PropertyDefinition(prop) => {
+                            (prop.address(), &mut prop.decorators)
+                        }
+                        _ => return None,
+                    };
+                    (!decorators.is_empty()).then(|| (holder, mem::replace(decorators, ast.vec())))
+                })
+                .collect::<FxHashMap<_, _>>();
+
+            let class_scope_id = class.scope_id();
+            let current_scope_id = ctx.current_scope_id();
+            for memoized in element_decorators {
+                let Some(decorators) = decorators_by_holder.remove(&memoized.holder) else {
+                    continue;
+                };
+                // Decorators move from class body to before the class
+                let mut reparenter = ScopeReparenter {
+                    class_scope_id,
+                    parent_scope_id: current_scope_id,
+                    depth: 0,
+                    ctx,
+                };
+                for decorator in &decorators {
+                    reparenter.visit_expression(&decorator.expression);
+                }
+                let value = self.create_decorators_value(decorators, memoized.have_this, ctx);
+                assignments.push(create_assignment(&memoized.binding, value, ctx));
+            }
+        }
+
+        if class.is_declaration() {
+            let address = match ctx.parent() {
+                parent @ (Ancestor::ExportDefaultDeclarationDeclaration(_)
+                | Ancestor::ExportNamedDeclarationDeclaration(_)) => parent.address(),
+                // `Class` is always stored in a `Box`, so has a stable memory location
+                _ => Address::from_ptr(class),
+            };
+            // `let _Class;`
+            let alias_declaration = class_alias.map(|class_alias| {
+                let kind = VariableDeclarationKind::Let;
+                let pattern = class_alias.create_binding_pattern(ctx);
+                let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, None, false);
+                Statement::from(ctx.ast.declaration_variable(
+                    SPAN,
+                    kind,
+                    ctx.ast.vec1(declarator),
+                    false,
+                ))
+            });
+            if alias_declaration.is_some() || !assignments.is_empty() {
+                let stmts = alias_declaration.into_iter().chain(
+                    assignments
+                        .into_iter()
+                        .map(|assignment| ctx.ast.statement_expression(SPAN, assignment)),
+                );
+                self.ctx.statement_injector.insert_many_before(&address, stmts);
+            }
+        } else if !assignments.is_empty() || class_alias.is_some() {
+            self.class_expression_wrap = Some(ClassExpressionWrap { assignments, class_alias });
+        }
+    }
+
+    /// * Without `this`: `dec` or `[dec1, dec2]`
+    /// * With `this`: `[obj, obj.dec, void 0, dec2]`
+    fn create_decorators_value(
+        &self,
+        decorators: ArenaVec<'a, Decorator<'a>>,
+        have_this: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if have_this {
+            let mut elements = ctx.ast.vec_with_capacity(decorators.len() * 2);
+            for decorator in decorators {
+                let (this, expr) = self.split_decorator_this(decorator.expression, ctx);
+                elements.push(ArrayExpressionElement::from(this));
+                elements.push(ArrayExpressionElement::from(expr));
+            }
+            ctx.ast.expression_array(SPAN, elements)
+        } else if decorators.len() == 1 {
+            decorators.into_iter().next().unwrap().expression
+        } else {
+            let elements = ctx.ast.vec_from_iter(
+                decorators
+                    .into_iter()
+                    .map(|decorator| ArrayExpressionElement::from(decorator.expression)),
+            );
+            ctx.ast.expression_array(SPAN, elements)
+        }
+    }
+
+    /// Split a decorator into the `this` it's called with and the decorator function.
+    ///
+    /// * `dec` -> `void 0, dec`
+    /// * `obj.dec` -> `obj, obj.dec`
+    /// * `foo().dec` -> `_obj = foo(), _obj.dec`
+    fn split_decorator_this(
+        &self,
+        mut expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, Expression<'a>) {
+        while let Expression::ParenthesizedExpression(paren) = expr {
+            expr = paren.unbox().expression;
+        }
+        let object = match &mut expr {
+            Expression::StaticMemberExpression(member) => &mut member.object,
+            Expression::ComputedMemberExpression(member) => &mut member.object,
+            _ => return (ctx.ast.void_0(SPAN), expr),
+        };
+        let this = match object {
+            Expression::ThisExpression(_) => ctx.ast.expression_this(SPAN),
+            Expression::Identifier(ident) => {
+                MaybeBoundIdentifier::from_identifier_reference(ident, ctx)
+                    .create_read_expression(ctx)
+            }
+            _ => {
+                let binding = self.ctx.var_declarations.create_uid_var("obj", ctx);
+                let value = mem::replace(object, binding.create_read_expression(ctx));
+                create_assignment(&binding, value, ctx)
+            }
+        };
+        (this, expr)
+    }
+
+    /// `class {}` -> `(_dec = dec(), class {}, _Class)`
+    fn wrap_class_expression(
+        expr: &mut Expression<'a>,
+        wrap: ClassExpressionWrap<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let ClassExpressionWrap { assignments, class_alias } = wrap;
+        let mut exprs = ctx.ast.vec_with_capacity(assignments.len() + 2);
+        exprs.extend(assignments);
+        exprs.push(expr.take_in(ctx.ast));
+        if let Some(class_alias) = class_alias {
+            exprs.push(class_alias.create_read_expression(ctx));
+        }
+        *expr = ctx.ast.expression_sequence(SPAN, exprs);
+    }
+}
+
+/// `object.property`
+fn create_member<'a>(
+    object: Expression<'a>,
+    property: &'static str,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let property = ctx.ast.identifier_name(SPAN, Atom::from(property));
+    Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+}
+
+/// Visitor to change references to the class inside class body to the alias of the decorated class.
+struct ClassReferenceChanger<'a, 'b, 'ctx> {
+    class_binding: &'b BoundIdentifier<'a>,
+    class_alias: &'b BoundIdentifier<'a>,
+    ctx: &'ctx mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b, 'ctx> ClassReferenceChanger<'a, 'b, 'ctx> {
+    fn new(
+        class_binding: &'b BoundIdentifier<'a>,
+        class_alias: &'b BoundIdentifier<'a>,
+        ctx: &'ctx mut TraverseCtx<'a>,
+    ) -> Self {
+        Self { class_binding, class_alias, ctx }
+    }
+}
+
+impl<'a> VisitMut<'a> for ClassReferenceChanger<'a, '_, '_> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference = self.ctx.scoping().get_reference(ident.reference_id());
+        if reference.symbol_id() != Some(self.class_binding.symbol_id) {
+            return;
+        }
+        let flags = reference.flags();
+        self.ctx.delete_reference_for_identifier(ident);
+        *ident = self.class_alias.create_spanned_reference(ident.span, flags, self.ctx);
+    }
+}
+
+/// Visitor to collect symbols of class declarations with class decorators.
+#[derive(Default)]
+struct DecoratedClassCollector {
+    symbol_ids: Vec<SymbolId>,
+}
+
+impl<'a> Visit<'a> for DecoratedClassCollector {
+    fn visit_class(&mut self, class: &Class<'a>) {
+        if class.is_declaration() && !class.declare && !class.decorators.is_empty() {
+            if let Some(id) = &class.id {
+                self.symbol_ids.push(id.symbol_id());
+            }
+        }
+        oxc_ast_visit::walk::walk_class(self, class);
+    }
+}
+
+/// Visitor to wrap `super()` calls in class constructor with initializer calls.
+///
+/// `super()` -> `_initProto(super())`
+struct SuperCallWrapper<'a, 'b, 'ctx> {
+    initializers: &'b [BoundIdentifier<'a>],
+    ctx: &'ctx mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for SuperCallWrapper<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        oxc_ast_visit::walk_mut::walk_expression(self, expr);
+        if matches!(expr, Expression::CallExpression(call) if call.callee.is_super()) {
+            let mut result = expr.take_in(self.ctx.ast);
+            for binding in self.initializers {
+                result =
+                    StandardDecorator::create_initializer_call(binding, Some(result), self.ctx);
+            }
+            *expr = result;
+        }
+    }
+
+    // `super()` can't appear in nested functions or classes, except arrow functions
+    #[inline]
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    #[inline]
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+/// Visitor to change parent scope of first-level scopes in decorators,
+/// which move from class body to before the class.
+struct ScopeReparenter<'a, 'ctx> {
+    class_scope_id: ScopeId,
+    parent_scope_id: ScopeId,
+    depth: u32,
+    ctx: &'ctx mut TraverseCtx<'a>,
+}
+
+impl<'a> Visit<'a> for ScopeReparenter<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            let scope_id = scope_id.get().unwrap();
+            if self.ctx.scoping().scope_parent_id(scope_id) == Some(self.class_scope_id) {
+                self.ctx.scoping_mut().change_scope_parent_id(scope_id, Some(self.parent_scope_id));
+            }
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
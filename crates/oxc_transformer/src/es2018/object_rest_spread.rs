@@ -18,6 +18,19 @@
 //! var y = _objectSpread({}, x, { c: 3 });
 //! ```
 //!
+//! ## Assumptions
+//!
+//! * `setSpreadProperties`: Spread properties are set with `_extends` (or `Object.assign` with `useBuiltIns`)
+//!   instead of being defined with `_objectSpread2`.
+//! * `pureGetters`: Object spread is lowered to a single call, and unused properties
+//!   of a destructuring with a rest are removed.
+//! * `objectRestNoSymbols`: Rest properties are collected with `_objectWithoutPropertiesLoose`,
+//!   which ignores symbol keys.
+//! * `ignoreFunctionLength`: Parameters which are moved into the function body are kept in the
+//!   parameter list, instead of being read from a rest parameter to preserve `length` of the function.
+//!
+//! All of them are enabled by the `loose` option.
+//!
 //! ## Implementation
 //!
 //! Implementation based on [@babel/plugin-transform-object-rest-spread](https://babeljs.io/docs/babel-plugin-transform-object-rest-spread).
@@ -31,30 +44,55 @@ use std::mem;
 
 use serde::Deserialize;
 
+use rustc_hash::FxHashSet;
+
 use oxc_allocator::{Box as ArenaBox, GetAddress, TakeIn, Vec as ArenaVec};
 use oxc_ast::{NONE, ast::*};
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_ast_visit::Visit;
 use oxc_ecmascript::{
     BoundNames, ToJsString, is_global_reference::WithoutGlobalReferenceInformation,
 };
-use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
-use oxc_span::{GetSpan, SPAN};
-use oxc_traverse::{Ancestor, MaybeBoundIdentifier, Traverse};
+use oxc_semantic::{ScopeFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::{Atom, GetSpan, SPAN};
+use oxc_syntax::{number::NumberBase, reference::ReferenceFlags};
+use oxc_traverse::{Ancestor, BoundIdentifier, MaybeBoundIdentifier, Traverse};
 
 use crate::{
     common::helper_loader::Helper,
     context::{TransformCtx, TraverseCtx},
     state::TransformState,
+    utils::ast_builder::create_member_callee,
 };
 
 #[derive(Debug, Default, Clone, Copy, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
 pub struct ObjectRestSpreadOptions {
+    /// Enables the `setSpreadProperties`, `pureGetters`, `objectRestNoSymbols` and
+    /// `ignoreFunctionLength` assumptions.
     pub loose: bool,
 
+    /// Use `Object.assign` instead of the `_extends` helper.
     pub use_built_ins: bool,
 }
 
+impl ObjectRestSpreadOptions {
+    fn set_spread_properties(self, ctx: &TransformCtx<'_>) -> bool {
+        self.loose || ctx.assumptions.set_spread_properties
+    }
+
+    fn pure_getters(self, ctx: &TransformCtx<'_>) -> bool {
+        self.loose || ctx.assumptions.pure_getters
+    }
+
+    fn object_rest_no_symbols(self, ctx: &TransformCtx<'_>) -> bool {
+        self.loose || ctx.assumptions.object_rest_no_symbols
+    }
+
+    fn ignore_function_length(self, ctx: &TransformCtx<'_>) -> bool {
+        self.loose || ctx.assumptions.ignore_function_length
+    }
+}
+
 pub struct ObjectRestSpread<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
 
@@ -65,26 +103,6 @@ pub struct ObjectRestSpread<'a, 'ctx> {
 
 impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
     pub fn new(options: ObjectRestSpreadOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
-        if options.loose {
-            ctx.error(OxcDiagnostic::error(
-                "Option `loose` is not implemented for object-rest-spread.",
-            ));
-        }
-        if options.use_built_ins {
-            ctx.error(OxcDiagnostic::error(
-                "Option `useBuiltIns` is not implemented for object-rest-spread.",
-            ));
-        }
-        if ctx.assumptions.object_rest_no_symbols {
-            ctx.error(OxcDiagnostic::error(
-                "Compiler assumption `objectRestNoSymbols` is not implemented for object-rest-spread.",
-            ));
-        }
-        if ctx.assumptions.ignore_function_length {
-            ctx.error(OxcDiagnostic::error(
-                "Compiler assumption `ignoreFunctionLength` is not implemented for object-rest-spread.",
-            ));
-        }
         Self { ctx, options, excluded_variable_declarators: vec![] }
     }
 }
@@ -125,13 +143,13 @@ impl<'a> Traverse<'a, TransformState<'a>> for ObjectRestSpread<'a, '_> {
         arrow: &mut ArrowFunctionExpression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
-        Self::transform_arrow(arrow, ctx);
+        self.transform_arrow(arrow, ctx);
     }
 
     // `function foo({...x}) {}`.
     #[inline]
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
-        Self::transform_function(func, ctx);
+        self.transform_function(func, ctx);
     }
 
     // `let { x, ..y } = foo`.
@@ -266,6 +284,7 @@ impl<'a> ObjectRestSpread<'a, '_> {
         // Insert all `rest = _extends({}, (_objectDestructuringEmpty(_foo), _foo))`
         for datum in data {
             let (lhs, rhs) = datum.get_lhs_rhs(
+                self.options,
                 &mut reference_builder,
                 &mut self.excluded_variable_declarators,
                 self.ctx,
@@ -480,7 +499,7 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
     // Transform `({ x, ..y })`.
     // `pub` for jsx spread.
     pub fn transform_object_expression(
-        _options: ObjectRestSpreadOptions,
+        options: ObjectRestSpreadOptions,
         expr: &mut Expression<'a>,
         transform_ctx: &'ctx TransformCtx<'a>,
         ctx: &mut TraverseCtx<'a>,
@@ -496,7 +515,7 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
 
         for prop in obj_expr.properties.drain(..) {
             if let ObjectPropertyKind::SpreadProperty(mut spread_prop) = prop {
-                Self::make_object_spread(&mut call_expr, &mut props, transform_ctx, ctx);
+                Self::make_object_spread(&mut call_expr, &mut props, options, transform_ctx, ctx);
                 let arg = spread_prop.argument.take_in(ctx.ast);
                 call_expr.as_mut().unwrap().arguments.push(Argument::from(arg));
             } else {
@@ -505,7 +524,7 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
         }
 
         if !props.is_empty() {
-            Self::make_object_spread(&mut call_expr, &mut props, transform_ctx, ctx);
+            Self::make_object_spread(&mut call_expr, &mut props, options, transform_ctx, ctx);
         }
 
         *expr = Expression::CallExpression(call_expr.unwrap());
@@ -514,6 +533,7 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
     fn make_object_spread(
         expr: &mut Option<ArenaBox<'a, CallExpression<'a>>>,
         props: &mut ArenaVec<'a, ObjectPropertyKind<'a>>,
+        options: ObjectRestSpreadOptions,
         transform_ctx: &'ctx TransformCtx<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
@@ -523,7 +543,17 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
             // Reserve maximize might be used space for new vec
             mem::replace(props, ctx.ast.vec_with_capacity(props.capacity() - props.len())),
         );
-        let arguments = if let Some(call_expr) = expr.take() {
+        let arguments = if let Some(mut call_expr) = expr.take() {
+            // When getters are pure, the order of evaluation does not matter,
+            // so all objects can be spread in a single call.
+            // `_objectSpread2({}, a, { b }, c)`
+            if options.pure_getters(transform_ctx) {
+                if had_props {
+                    call_expr.arguments.push(Argument::from(obj));
+                }
+                expr.replace(call_expr);
+                return;
+            }
             let arg = Expression::CallExpression(call_expr);
             let arg = Argument::from(arg);
             if had_props {
@@ -535,59 +565,223 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
         } else {
             ctx.ast.vec1(Argument::from(obj))
         };
-        let new_expr = transform_ctx.helper_call(Helper::ObjectSpread2, SPAN, arguments, ctx);
+        let new_expr = if options.set_spread_properties(transform_ctx) {
+            let callee = create_extends_callee(options, transform_ctx, ctx);
+            ctx.ast.call_expression(SPAN, callee, NONE, arguments, false)
+        } else {
+            transform_ctx.helper_call(Helper::ObjectSpread2, SPAN, arguments, ctx)
+        };
         expr.replace(ctx.ast.alloc(new_expr));
     }
 }
 
 impl<'a> ObjectRestSpread<'a, '_> {
     // Transform `function foo({...x}) {}`.
-    fn transform_function(func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+    fn transform_function(&self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
         let scope_id = func.scope_id();
         let Some(body) = func.body.as_mut() else { return };
-        for param in &mut func.params.items {
+        if !Self::has_params_with_object_rest(&func.params) {
+            return;
+        }
+        self.transform_params(&mut func.params, &mut body.statements, scope_id, ctx);
+    }
+
+    // Transform `(...x) => {}`.
+    fn transform_arrow(&self, arrow: &mut ArrowFunctionExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !Self::has_params_with_object_rest(&arrow.params) {
+            return;
+        }
+        let scope_id = arrow.scope_id();
+        // `({ ...args }) => { args }`
+        if arrow.expression {
+            arrow.expression = false;
+
+            debug_assert!(arrow.body.statements.len() == 1);
+
+            let Statement::ExpressionStatement(stmt) = arrow.body.statements.pop().unwrap() else {
+                unreachable!(
+                    "`arrow.expression` is true, which means it has only one ExpressionStatement."
+                );
+            };
+            let return_stmt = ctx.ast.statement_return(stmt.span, Some(stmt.unbox().expression));
+            arrow.body.statements.push(return_stmt);
+        }
+        self.transform_params(&mut arrow.params, &mut arrow.body.statements, scope_id, ctx);
+    }
+
+    fn has_params_with_object_rest(params: &FormalParameters<'a>) -> bool {
+        params.items.iter().any(|param| Self::has_nested_object_rest(&param.pattern))
+            || params.rest.as_ref().is_some_and(|rest| Self::has_nested_object_rest(&rest.argument))
+    }
+
+    /// Move the parameters containing an object rest into the function body.
+    ///
+    /// Parameters which are evaluated after a parameter with an object rest and reference
+    /// its bindings, have to be moved into the body as well, because the bindings are not
+    /// initialized until the body is evaluated.
+    ///
+    /// * `function foo({...a}, b) {}` -> `function foo(_ref, b) { let {...a} = _ref; }`
+    /// * `function foo({...a}, b = a) {}` -> `function foo(_ref, ..._args) { let {...a} = _ref; let b = _args[0] === void 0 ? a : _args[0]; }`
+    /// * With `ignoreFunctionLength`:
+    ///   `function foo({...a}, b = a) {}` -> `function foo(_ref, _ref2) { let {...a} = _ref; let b = _ref2 === void 0 ? a : _ref2; }`
+    fn transform_params(
+        &self,
+        params: &mut FormalParameters<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let kind = VariableDeclarationKind::Var;
+
+        // Collect the symbols which are bound by parameters containing an object rest.
+        let mut rest_symbols = FxHashSet::default();
+        for param in &params.items {
             if Self::has_nested_object_rest(&param.pattern) {
+                param.pattern.bound_names(&mut |ident| {
+                    rest_symbols.insert(ident.symbol_id());
+                });
+            }
+        }
+
+        // Find the first parameter which references a binding of a parameter containing an object rest.
+        let tail_index = params
+            .items
+            .iter()
+            .position(|param| {
+                !Self::has_nested_object_rest(&param.pattern)
+                    && references_any_symbol(&param.pattern, &rest_symbols, ctx.scoping())
+            })
+            .or_else(|| {
+                params
+                    .rest
+                    .as_ref()
+                    .filter(|rest| {
+                        references_any_symbol(&rest.argument, &rest_symbols, ctx.scoping())
+                    })
+                    .map(|_| params.items.len())
+            });
+
+        let mut statements = vec![];
+
+        let Some(tail_index) = tail_index else {
+            for param in &mut params.items {
                 Self::replace_rest_element(
-                    VariableDeclarationKind::Var,
+                    kind,
                     &mut param.pattern,
-                    &mut body.statements,
+                    &mut statements,
                     scope_id,
                     ctx,
                 );
             }
-        }
-    }
-
-    // Transform `(...x) => {}`.
-    fn transform_arrow(arrow: &mut ArrowFunctionExpression<'a>, ctx: &mut TraverseCtx<'a>) {
-        let scope_id = arrow.scope_id();
-        for param in &mut arrow.params.items {
-            if Self::has_nested_object_rest(&param.pattern) {
-                // `({ ...args }) => { args }`
-                if arrow.expression {
-                    arrow.expression = false;
+            if let Some(rest) = &mut params.rest {
+                Self::replace_rest_element(
+                    kind,
+                    &mut rest.argument,
+                    &mut statements,
+                    scope_id,
+                    ctx,
+                );
+            }
+            body.splice(0..0, statements);
+            return;
+        };
 
-                    debug_assert!(arrow.body.statements.len() == 1);
+        // Parameters after the first parameter with a default value don't count to `length`,
+        // so they can be read from a rest parameter without changing `length` of the function.
+        let split_index = if self.options.ignore_function_length(self.ctx) {
+            params.items.len()
+        } else {
+            params
+                .items
+                .iter()
+                .position(|param| param.pattern.kind.is_assignment_pattern())
+                .map_or(params.items.len(), |index| index.max(tail_index))
+        };
 
-                    let Statement::ExpressionStatement(stmt) = arrow.body.statements.pop().unwrap()
-                    else {
-                        unreachable!(
-                            "`arrow.expression` is true, which means it has only one ExpressionStatement."
-                        );
-                    };
-                    let return_stmt =
-                        ctx.ast.statement_return(stmt.span, Some(stmt.unbox().expression));
-                    arrow.body.statements.push(return_stmt);
-                }
+        for (index, param) in params.items.iter_mut().enumerate().take(split_index) {
+            if index < tail_index || Self::has_nested_object_rest(&param.pattern) {
                 Self::replace_rest_element(
-                    VariableDeclarationKind::Var,
+                    kind,
                     &mut param.pattern,
-                    &mut arrow.body.statements,
+                    &mut statements,
                     scope_id,
                     ctx,
                 );
+            } else if !param.pattern.kind.is_binding_identifier() {
+                // `function foo({...a}, { b } = a)` -> `function foo(_ref, _ref2) { let { b } = _ref2 === void 0 ? a : _ref2; }`
+                let bound_identifier =
+                    ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+                let pattern =
+                    mem::replace(&mut param.pattern, bound_identifier.create_binding_pattern(ctx));
+                statements.push(Self::create_param_declaration(
+                    pattern,
+                    |ctx| bound_identifier.create_read_expression(ctx),
+                    ctx,
+                ));
+            }
+        }
+
+        if split_index < params.items.len() {
+            // `function foo({...a}, b = a, ...c)` -> `function foo(_ref, ..._args) { ... }`
+            let bound_identifier =
+                ctx.generate_uid("args", scope_id, SymbolFlags::FunctionScopedVariable);
+            let moved_params = params.items.drain(split_index..).collect::<Vec<_>>();
+            let moved_count = moved_params.len();
+            for (index, param) in moved_params.into_iter().enumerate() {
+                statements.push(Self::create_param_declaration(
+                    param.pattern,
+                    |ctx| Self::create_args_element(&bound_identifier, index, ctx),
+                    ctx,
+                ));
+            }
+            if let Some(rest) = params.rest.take() {
+                // `let c = _args.slice(1)`
+                let callee = create_member_callee(
+                    bound_identifier.create_read_expression(ctx),
+                    "slice",
+                    ctx,
+                );
+                #[expect(clippy::cast_precision_loss)]
+                let start = ctx.ast.expression_numeric_literal(
+                    SPAN,
+                    moved_count as f64,
+                    None,
+                    NumberBase::Decimal,
+                );
+                let arguments = ctx.ast.vec1(Argument::from(start));
+                let init = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+                let decl = Self::create_let_declaration(rest.unbox().argument, init, ctx);
+                statements.push(Statement::VariableDeclaration(ctx.ast.alloc(decl)));
+            }
+            let argument = bound_identifier.create_binding_pattern(ctx);
+            params.rest = Some(ctx.ast.alloc_binding_rest_element(SPAN, argument));
+        } else if let Some(rest) = &mut params.rest {
+            if !rest.argument.kind.is_binding_identifier() {
+                // `function foo({...a}, ...[b = a])` -> `function foo(_ref, ..._ref2) { let [b = a] = _ref2; }`
+                let bound_identifier =
+                    ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+                let pattern =
+                    mem::replace(&mut rest.argument, bound_identifier.create_binding_pattern(ctx));
+                let init = bound_identifier.create_read_expression(ctx);
+                let decl = Self::create_let_declaration(pattern, init, ctx);
+                statements.push(Statement::VariableDeclaration(ctx.ast.alloc(decl)));
             }
         }
+
+        body.splice(0..0, statements);
+    }
+
+    /// `_args[index]`
+    fn create_args_element(
+        args: &BoundIdentifier<'a>,
+        index: usize,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        #[expect(clippy::cast_precision_loss)]
+        let index =
+            ctx.ast.expression_numeric_literal(SPAN, index as f64, None, NumberBase::Decimal);
+        let object = args.create_read_expression(ctx);
+        Expression::from(ctx.ast.member_expression_computed(SPAN, object, index, false))
     }
 
     // Transform `try {} catch ({...x}) {}`.
@@ -601,13 +795,15 @@ impl<'a> ObjectRestSpread<'a, '_> {
                     .symbol_flags_mut(ident.symbol_id())
                     .remove(SymbolFlags::CatchVariable);
             });
+            let mut statements = vec![];
             Self::replace_rest_element(
                 VariableDeclarationKind::Var,
                 &mut param.pattern,
-                &mut clause.body.body,
+                &mut statements,
                 scope_id,
                 ctx,
             );
+            clause.body.body.splice(0..0, statements);
         }
     }
 
@@ -626,13 +822,15 @@ impl<'a> ObjectRestSpread<'a, '_> {
                 };
                 let mut bound_names = vec![];
                 declarator.id.bound_names(&mut |ident| bound_names.push(ident.clone()));
+                let mut statements = vec![];
                 Self::replace_rest_element(
                     declarator.kind,
                     &mut declarator.id,
-                    &mut block.body,
+                    &mut statements,
                     if decl.kind.is_var() { ctx.current_hoist_scope_id() } else { scope_id },
                     ctx,
                 );
+                block.body.splice(0..0, statements);
                 // Move the bindings from the for init scope to scope of the loop body.
                 for ident in bound_names {
                     ctx.scoping_mut().set_symbol_scope_id(ident.symbol_id(), new_scope_id);
@@ -715,7 +913,7 @@ impl<'a> ObjectRestSpread<'a, '_> {
     fn replace_rest_element(
         kind: VariableDeclarationKind,
         pattern: &mut BindingPattern<'a>,
-        body: &mut ArenaVec<'a, Statement<'a>>,
+        body: &mut Vec<Statement<'a>>,
         scope_id: ScopeId,
         ctx: &mut TraverseCtx<'a>,
     ) {
@@ -752,12 +950,12 @@ impl<'a> ObjectRestSpread<'a, '_> {
     fn replace_object_pattern_and_insert_into_block_body(
         kind: VariableDeclarationKind,
         pat: &mut BindingPattern<'a>,
-        body: &mut ArenaVec<'a, Statement<'a>>,
+        body: &mut Vec<Statement<'a>>,
         scope_id: ScopeId,
         ctx: &mut TraverseCtx<'a>,
     ) {
         let decl = Self::create_temporary_reference_for_binding(kind, pat, scope_id, ctx);
-        body.insert(0, Statement::VariableDeclaration(ctx.ast.alloc(decl)));
+        body.push(Statement::VariableDeclaration(ctx.ast.alloc(decl)));
     }
 
     fn create_temporary_reference_for_binding(
@@ -773,9 +971,18 @@ impl<'a> ObjectRestSpread<'a, '_> {
             flags |= SymbolFlags::CatchVariable;
         }
         let bound_identifier = ctx.generate_uid("ref", scope_id, flags);
-        let kind = VariableDeclarationKind::Let;
         let id = mem::replace(pat, bound_identifier.create_binding_pattern(ctx));
         let init = bound_identifier.create_read_expression(ctx);
+        Self::create_let_declaration(id, init, ctx)
+    }
+
+    /// `let pat = init`, the bindings of `pat` become block scoped variables.
+    fn create_let_declaration(
+        id: BindingPattern<'a>,
+        init: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> VariableDeclaration<'a> {
+        let kind = VariableDeclarationKind::Let;
         let declarations =
             ctx.ast.vec1(ctx.ast.variable_declarator(SPAN, kind, id, Some(init), false));
         let decl = ctx.ast.variable_declaration(SPAN, kind, declarations, false);
@@ -785,6 +992,30 @@ impl<'a> ObjectRestSpread<'a, '_> {
         });
         decl
     }
+
+    /// `let pat = init`, or `let pat = init === void 0 ? default : init` for `pat = default`.
+    fn create_param_declaration(
+        pattern: BindingPattern<'a>,
+        mut create_init: impl FnMut(&mut TraverseCtx<'a>) -> Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let (id, init) = match pattern.kind {
+            BindingPatternKind::AssignmentPattern(assign_pat) => {
+                let AssignmentPattern { left, right, .. } = assign_pat.unbox();
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    create_init(ctx),
+                    BinaryOperator::StrictEquality,
+                    ctx.ast.void_0(SPAN),
+                );
+                let alternate = create_init(ctx);
+                (left, ctx.ast.expression_conditional(SPAN, test, right, alternate))
+            }
+            _ => (pattern, create_init(ctx)),
+        };
+        let decl = Self::create_let_declaration(id, init, ctx);
+        Statement::VariableDeclaration(ctx.ast.alloc(decl))
+    }
 }
 
 impl<'a> ObjectRestSpread<'a, '_> {
@@ -838,7 +1069,7 @@ impl<'a> ObjectRestSpread<'a, '_> {
         let mut new_decls = vec![];
 
         let mut reference_builder = ReferenceBuilder::new(init, symbol_flags, scope_id, false, ctx);
-        let remove_empty_object_pattern;
+        let mut remove_empty_object_pattern;
 
         // Add `_foo = foo()`
         if let Some(id) = reference_builder.binding.take() {
@@ -889,9 +1120,19 @@ impl<'a> ObjectRestSpread<'a, '_> {
                     has_no_properties: pat.properties.is_empty(),
                     all_primitives,
                 };
+                // The excluded keys are still needed, but unused bindings can be removed
+                // when reading a property has no side effects.
+                // `let { a, ...b } = c` -> `let b = _objectWithoutProperties(c, ["a"])`
+                if self.options.pure_getters(self.ctx)
+                    && !matches!(ctx.parent(), Ancestor::ExportNamedDeclarationDeclaration(_))
+                {
+                    Self::remove_unused_properties(pat, ctx);
+                    remove_empty_object_pattern = pat.properties.is_empty();
+                }
                 // Add `rest = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_foo), _foo))`.
                 // Or `rest = babelHelpers.objectWithoutProperties(_foo, ["x"])`.
                 let (lhs, rhs) = datum.get_lhs_rhs(
+                    self.options,
                     &mut reference_builder,
                     &mut self.excluded_variable_declarators,
                     self.ctx,
@@ -930,6 +1171,26 @@ impl<'a> ObjectRestSpread<'a, '_> {
         ctx.ast.vec_from_iter(new_decls)
     }
 
+    /// Remove properties which are bound to an identifier that is never referenced.
+    fn remove_unused_properties(pat: &mut ObjectPattern<'a>, ctx: &mut TraverseCtx<'a>) {
+        pat.properties.retain(|property| {
+            let BindingPatternKind::BindingIdentifier(ident) = &property.value.kind else {
+                return true;
+            };
+            let symbol_id = ident.symbol_id();
+            if !ctx.scoping().get_resolved_reference_ids(symbol_id).is_empty() {
+                return true;
+            }
+            let scope_id = ctx.scoping().symbol_scope_id(symbol_id);
+            ctx.scoping_mut().remove_binding(scope_id, &ident.name);
+            // `{ [_key]: a }`
+            if let PropertyKey::Identifier(key) = &property.key {
+                ctx.delete_reference_for_identifier(key);
+            }
+            false
+        });
+    }
+
     // Returns all temporary references
     fn recursive_walk_binding_pattern(
         &mut self,
@@ -1037,6 +1298,46 @@ impl<'a> ObjectRestSpread<'a, '_> {
     }
 }
 
+/// `_extends`, or `Object.assign` with `useBuiltIns`.
+fn create_extends_callee<'a>(
+    options: ObjectRestSpreadOptions,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    if options.use_built_ins {
+        let object =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+        create_member_callee(object, "assign", ctx)
+    } else {
+        transform_ctx.helper_load(Helper::Extends, ctx)
+    }
+}
+
+/// Whether `pattern` references any of `symbols`.
+fn references_any_symbol(
+    pattern: &BindingPattern<'_>,
+    symbols: &FxHashSet<SymbolId>,
+    scoping: &Scoping,
+) -> bool {
+    let mut finder = SymbolReferenceFinder { symbols, scoping, found: false };
+    finder.visit_binding_pattern(pattern);
+    finder.found
+}
+
+struct SymbolReferenceFinder<'s> {
+    symbols: &'s FxHashSet<SymbolId>,
+    scoping: &'s Scoping,
+    found: bool,
+}
+
+impl<'a> Visit<'a> for SymbolReferenceFinder<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if let Some(symbol_id) = self.scoping.get_reference(ident.reference_id()).symbol_id() {
+            self.found |= self.symbols.contains(&symbol_id);
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct State {
     kind: VariableDeclarationKind,
@@ -1079,6 +1380,7 @@ struct SpreadPair<'a> {
 impl<'a> SpreadPair<'a> {
     fn get_lhs_rhs(
         self,
+        options: ObjectRestSpreadOptions,
         reference_builder: &mut ReferenceBuilder<'a>,
         excluded_variable_declarators: &mut Vec<VariableDeclarator<'a>>,
         transform_ctx: &TransformCtx<'a>,
@@ -1107,7 +1409,8 @@ impl<'a> SpreadPair<'a> {
                     sequence
                 },
             )));
-            transform_ctx.helper_call_expr(Helper::Extends, SPAN, arguments, ctx)
+            let callee = create_extends_callee(options, transform_ctx, ctx);
+            ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
         } else {
             // / `let { a, b, ...c } = z` -> _objectWithoutProperties(_z, ["a", "b"]);
             // / `_objectWithoutProperties(_z, ["a", "b"])`
@@ -1148,7 +1451,12 @@ impl<'a> SpreadPair<'a> {
                 key_expression
             };
             arguments.push(Argument::from(key_expression));
-            transform_ctx.helper_call_expr(Helper::ObjectWithoutProperties, SPAN, arguments, ctx)
+            let helper = if options.object_rest_no_symbols(transform_ctx) {
+                Helper::ObjectWithoutPropertiesLoose
+            } else {
+                Helper::ObjectWithoutProperties
+            };
+            transform_ctx.helper_call_expr(helper, SPAN, arguments, ctx)
         };
         (self.lhs, rhs)
     }
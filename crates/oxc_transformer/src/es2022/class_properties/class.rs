@@ -112,6 +112,11 @@ impl<'a> ClassProperties<'a, '_> {
                     if let PropertyKey::PrivateIdentifier(ident) = &method.key {
                         if method.r#static {
                             has_static_private_method_or_static_block = true;
+                            // Loose: `Object.defineProperty(Class, _method, { value: _method2 })`
+                            // is inserted after class, same as a static property
+                            if self.private_fields_as_properties {
+                                has_static_prop = true;
+                            }
                         } else {
                             has_instance_private_method = true;
                         }
@@ -122,25 +127,35 @@ impl<'a> ClassProperties<'a, '_> {
                             MethodDefinitionKind::Set => &format!("set_{}", ident.name),
                             MethodDefinitionKind::Constructor => unreachable!(),
                         };
-                        let binding = ctx.generate_uid(
-                            name,
-                            ctx.current_block_scope_id(),
-                            SymbolFlags::Function,
-                        );
-
                         match private_props.entry(ident.name) {
                             Entry::Occupied(mut entry) => {
                                 // If there's already a binding for this private property,
                                 // it's a setter or getter, so store the binding in `binding2`.
+                                let binding = ctx.generate_uid(
+                                    name,
+                                    ctx.current_block_scope_id(),
+                                    SymbolFlags::Function,
+                                );
                                 entry.get_mut().set_binding2(binding);
                             }
                             Entry::Vacant(entry) => {
-                                entry.insert(PrivateProp::new(
+                                // Loose: Key is `_method`, and function is `_method2`.
+                                // Note: Current scope is outside class.
+                                let key_binding = self
+                                    .private_fields_as_properties
+                                    .then(|| ctx.generate_uid_in_current_hoist_scope(&ident.name));
+                                let binding = ctx.generate_uid(
+                                    name,
+                                    ctx.current_block_scope_id(),
+                                    SymbolFlags::Function,
+                                );
+                                let prop = entry.insert(PrivateProp::new(
                                     binding,
                                     method.r#static,
                                     Some(method.kind),
                                     false,
                                 ));
+                                prop.key_binding = key_binding;
                             }
                         }
                     }
@@ -207,12 +222,15 @@ impl<'a> ClassProperties<'a, '_> {
             None
         };
 
-        let class_brand_binding = has_instance_private_method.then(|| {
-            // `_Class_brand`
-            let name = class_name_binding.as_ref().map_or_else(|| "Class", |binding| &binding.name);
-            let name = &format!("_{name}_brand");
-            ctx.generate_uid_in_current_hoist_scope(name)
-        });
+        // Loose mode defines private methods as properties on the instance, so needs no brand
+        let class_brand_binding =
+            (has_instance_private_method && !self.private_fields_as_properties).then(|| {
+                // `_Class_brand`
+                let name =
+                    class_name_binding.as_ref().map_or_else(|| "Class", |binding| &binding.name);
+                let name = &format!("_{name}_brand");
+                ctx.generate_uid_in_current_hoist_scope(name)
+            });
 
         let static_private_fields_use_temp = !is_declaration;
         let class_bindings = ClassBindings::new(
@@ -251,9 +269,22 @@ impl<'a> ClassProperties<'a, '_> {
         let mut instance_inits =
             Vec::with_capacity(instance_prop_count + usize::from(has_instance_private_method));
 
-        // `_classPrivateMethodInitSpec(this, _C_brand);`
         if has_instance_private_method {
-            instance_inits.push(self.create_class_private_method_init_spec(ctx));
+            if self.private_fields_as_properties {
+                // `Object.defineProperty(this, _method, { value: _method2 });`
+                // In reverse order, same as Babel
+                let private_props = self.current_class().private_props.as_ref().unwrap();
+                for prop in private_props.values().rev() {
+                    if prop.is_method() && !prop.is_static {
+                        let this = ctx.ast.expression_this(SPAN);
+                        instance_inits
+                            .push(Self::create_private_method_init_loose(prop, this, ctx));
+                    }
+                }
+            } else {
+                // `_classPrivateMethodInitSpec(this, _C_brand);`
+                instance_inits.push(self.create_class_private_method_init_spec(ctx));
+            }
         }
 
         let mut constructor = None;
@@ -485,14 +516,18 @@ impl<'a> ClassProperties<'a, '_> {
                 let mut private_props = private_props
                     .iter()
                     .filter_map(|(&name, prop)| {
-                        // TODO: Output `var _C_brand = new WeakSet();` for private instance method
-                        if prop.is_method() || prop.is_accessor {
+                        if prop.is_accessor {
                             return None;
                         }
 
                         // `var _prop = _classPrivateFieldLooseKey("prop");`
-                        let value = Self::create_private_prop_key_loose(name, self.ctx, ctx);
-                        Some(create_variable_declaration(&prop.binding, value, ctx))
+                        let value = Self::create_private_prop_key_loose(
+                            name,
+                            self.private_fields_as_symbols,
+                            self.ctx,
+                            ctx,
+                        );
+                        Some(create_variable_declaration(prop.loose_key_binding(), value, ctx))
                     })
                     .peekable();
                 if private_props.peek().is_some() {
@@ -636,17 +671,22 @@ impl<'a> ClassProperties<'a, '_> {
             // TODO(improve-on-babel): Simplify this.
             if self.private_fields_as_properties {
                 exprs.extend(private_props.iter().filter_map(|(&name, prop)| {
-                    // TODO: Output `_C_brand = new WeakSet()` for private instance method
-                    if prop.is_method() || prop.is_accessor {
+                    if prop.is_accessor {
                         return None;
                     }
 
                     // Insert `var _prop;` declaration
-                    self.ctx.var_declarations.insert_var(&prop.binding, ctx);
+                    let key_binding = prop.loose_key_binding();
+                    self.ctx.var_declarations.insert_var(key_binding, ctx);
 
                     // `_prop = _classPrivateFieldLooseKey("prop")`
-                    let value = Self::create_private_prop_key_loose(name, self.ctx, ctx);
-                    Some(create_assignment(&prop.binding, value, ctx))
+                    let value = Self::create_private_prop_key_loose(
+                        name,
+                        self.private_fields_as_symbols,
+                        self.ctx,
+                        ctx,
+                    );
+                    Some(create_assignment(key_binding, value, ctx))
                 }));
             } else {
                 let mut weakmap_symbol_id = None;
@@ -762,6 +802,12 @@ impl<'a> ClassProperties<'a, '_> {
     /// * Extract computed key assignments and insert them before class.
     /// * Remove all properties, private methods and static blocks from class body.
     fn transform_class_elements(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Loose: `Object.defineProperty(Class, _method, { value: _method2 });` before static properties.
+        // In reverse order, same as Babel.
+        if self.private_fields_as_properties {
+            self.insert_private_static_method_inits_loose(ctx);
+        }
+
         let mut class_methods = vec![];
         class.body.body.retain_mut(|element| {
             match element {
@@ -838,17 +884,23 @@ impl<'a> ClassProperties<'a, '_> {
     }
 
     /// `_classPrivateFieldLooseKey("prop")`
+    /// (or `Symbol("prop")` if `private_fields_as_symbols`).
     fn create_private_prop_key_loose(
         name: Atom<'a>,
+        as_symbol: bool,
         transform_ctx: &TransformCtx<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
-        transform_ctx.helper_call_expr(
-            Helper::ClassPrivateFieldLooseKey,
-            SPAN,
-            ctx.ast.vec1(Argument::from(ctx.ast.expression_string_literal(SPAN, name, None))),
-            ctx,
-        )
+        let arguments =
+            ctx.ast.vec1(Argument::from(ctx.ast.expression_string_literal(SPAN, name, None)));
+        if as_symbol {
+            let symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), "Symbol");
+            let callee =
+                ctx.create_ident_expr(SPAN, Atom::from("Symbol"), symbol_id, ReferenceFlags::Read);
+            ctx.ast.expression_call_with_pure(SPAN, callee, NONE, arguments, false, true)
+        } else {
+            transform_ctx.helper_call_expr(Helper::ClassPrivateFieldLooseKey, SPAN, arguments, ctx)
+        }
     }
 
     /// Insert an expression after the class.
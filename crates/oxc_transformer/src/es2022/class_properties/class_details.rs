@@ -45,6 +45,10 @@ pub(super) struct PrivateProp<'a> {
     // For accessor methods, they have two bindings,
     // one for getter and another for setter.
     pub binding2: Option<BoundIdentifier<'a>>,
+    /// Binding for key of private method or accessor methods, when private properties are stored
+    /// as normal properties (`privateFieldsAsProperties` or `privateFieldsAsSymbols` assumption).
+    /// `binding` and `binding2` are then only the bindings of the functions.
+    pub key_binding: Option<BoundIdentifier<'a>>,
 }
 
 impl<'a> PrivateProp<'a> {
@@ -54,7 +58,7 @@ impl<'a> PrivateProp<'a> {
         method_kind: Option<MethodDefinitionKind>,
         is_accessor: bool,
     ) -> Self {
-        Self { binding, is_static, method_kind, is_accessor, binding2: None }
+        Self { binding, is_static, method_kind, is_accessor, binding2: None, key_binding: None }
     }
 
     pub fn is_method(&self) -> bool {
@@ -68,6 +72,21 @@ impl<'a> PrivateProp<'a> {
     pub fn set_binding2(&mut self, binding: BoundIdentifier<'a>) {
         self.binding2 = Some(binding);
     }
+
+    /// Get binding for the key of the property, when private properties are stored as normal properties.
+    pub fn loose_key_binding(&self) -> &BoundIdentifier<'a> {
+        self.key_binding.as_ref().unwrap_or(&self.binding)
+    }
+
+    /// Get binding for the function of a private method, getter or setter.
+    pub fn method_binding(&self, kind: MethodDefinitionKind) -> &BoundIdentifier<'a> {
+        let is_setter = kind == MethodDefinitionKind::Set;
+        if is_setter == matches!(self.method_kind, Some(MethodDefinitionKind::Set)) {
+            &self.binding
+        } else {
+            self.binding2.as_ref().unwrap()
+        }
+    }
 }
 
 /// Stack of `ClassDetails`.
@@ -145,7 +164,7 @@ impl<'a> ClassesStack<'a> {
     ) -> ResolvedPrivateProp<'a, 'b> {
         self.lookup_private_prop(ident, move |prop, class_bindings, is_declaration| {
             ResolvedPrivateProp {
-                prop_binding: &prop.binding,
+                prop_binding: prop.loose_key_binding(),
                 class_bindings,
                 is_static: prop.is_static,
                 is_method: prop.is_method(),
@@ -161,7 +180,9 @@ impl<'a> ClassesStack<'a> {
         ident: &PrivateIdentifier<'a>,
     ) -> Option<ResolvedPrivateProp<'a, 'b>> {
         self.lookup_private_prop(ident, move |prop, class_bindings, is_declaration| {
-            let prop_binding = if matches!(prop.method_kind, Some(MethodDefinitionKind::Set)) {
+            let prop_binding = if let Some(key_binding) = &prop.key_binding {
+                Some(key_binding)
+            } else if matches!(prop.method_kind, Some(MethodDefinitionKind::Set)) {
                 prop.binding2.as_ref()
             } else {
                 Some(&prop.binding)
@@ -184,8 +205,9 @@ impl<'a> ClassesStack<'a> {
         ident: &PrivateIdentifier<'a>,
     ) -> Option<ResolvedPrivateProp<'a, 'b>> {
         self.lookup_private_prop(ident, move |prop, class_bindings, is_declaration| {
-            let prop_binding = if matches!(prop.method_kind, Some(MethodDefinitionKind::Set) | None)
-            {
+            let prop_binding = if let Some(key_binding) = &prop.key_binding {
+                Some(key_binding)
+            } else if matches!(prop.method_kind, Some(MethodDefinitionKind::Set) | None) {
                 Some(&prop.binding)
             } else {
                 prop.binding2.as_ref()
@@ -208,6 +230,9 @@ impl<'a> ClassesStack<'a> {
     ) -> ResolvedGetSetPrivateProp<'a, 'b> {
         self.lookup_private_prop(ident, move |prop, class_bindings, is_declaration| {
             let (get_binding, set_binding) = match prop.method_kind {
+                _ if prop.key_binding.is_some() => {
+                    (prop.key_binding.as_ref(), prop.key_binding.as_ref())
+                }
                 Some(MethodDefinitionKind::Set) => (prop.binding2.as_ref(), Some(&prop.binding)),
                 Some(_) => (Some(&prop.binding), prop.binding2.as_ref()),
                 _ => (Some(&prop.binding), Some(&prop.binding)),
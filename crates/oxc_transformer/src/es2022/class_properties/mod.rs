@@ -95,6 +95,41 @@
 //! }
 //! ```
 //!
+//! ### Assumptions
+//!
+//! * `setPublicClassFields`: Same as `loose: true` above, for public properties only.
+//! * `privateFieldsAsProperties`: Private properties are stored as non-enumerable properties
+//!   with string keys created by `_classPrivateFieldLooseKey` helper, instead of in `WeakMap`s.
+//! * `privateFieldsAsSymbols`: As `privateFieldsAsProperties`, but keys are `Symbol`s.
+//!
+//! Private methods and accessors are stored the same way, with a descriptor of `{ value }`
+//! or `{ get, set }` defined on the instance (or on the class for static methods).
+//! Enabling both assumptions at once is an error.
+//!
+//! `loose: true` enables both `setPublicClassFields` and `privateFieldsAsProperties`.
+//!
+//! #### Example
+//!
+//! Input:
+//! ```js
+//! class C {
+//!   #foo = 123;
+//!   get() { return this.#foo; }
+//! }
+//! ```
+//!
+//! With `privateFieldsAsSymbols`:
+//!
+//! ```js
+//! var _foo = /*#__PURE__*/ Symbol("foo");
+//! class C {
+//!   constructor() {
+//!     Object.defineProperty(this, _foo, { writable: true, value: 123 });
+//!   }
+//!   get() { return babelHelpers.classPrivateFieldLooseBase(this, _foo)[_foo]; }
+//! }
+//! ```
+//!
 //! ## Implementation
 //!
 //! ### Reference implementation
@@ -202,6 +237,7 @@ use rustc_hash::{FxBuildHasher, FxHashMap};
 use serde::Deserialize;
 
 use oxc_ast::ast::*;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Atom;
 use oxc_syntax::symbol::SymbolId;
 use oxc_traverse::Traverse;
@@ -245,7 +281,11 @@ pub struct ClassProperties<'a, 'ctx> {
     /// If `true`, set properties with `=`, instead of `_defineProperty` helper (loose option).
     set_public_class_fields: bool,
     /// If `true`, store private properties as normal properties as string keys (loose option).
+    /// Also `true` when `private_fields_as_symbols` is enabled, as both store private properties
+    /// as normal properties, and only differ in the type of the key.
     private_fields_as_properties: bool,
+    /// If `true`, store private properties as normal properties with `Symbol` keys.
+    private_fields_as_symbols: bool,
     /// If `true`, transform static blocks.
     transform_static_blocks: bool,
     /// If `true`, remove class fields without initializer. Only works with `set_public_class_fields: true`.
@@ -301,10 +341,20 @@ impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
         // TODO: Raise error if these 2 options are inconsistent
         let private_fields_as_properties =
             options.loose || ctx.assumptions.private_fields_as_properties;
+        if ctx.assumptions.private_fields_as_properties && ctx.assumptions.private_fields_as_symbols
+        {
+            ctx.error(OxcDiagnostic::error(
+                "Cannot enable both the \"privateFieldsAsProperties\" and \"privateFieldsAsSymbols\" assumptions as the same time.",
+            ));
+        }
+        // `privateFieldsAsProperties` takes precedence over `privateFieldsAsSymbols` with `loose: true`
+        let private_fields_as_symbols =
+            !private_fields_as_properties && ctx.assumptions.private_fields_as_symbols;
 
         Self {
             set_public_class_fields,
-            private_fields_as_properties,
+            private_fields_as_properties: private_fields_as_properties || private_fields_as_symbols,
+            private_fields_as_symbols,
             transform_static_blocks,
             remove_class_fields_without_initializer,
             ctx,
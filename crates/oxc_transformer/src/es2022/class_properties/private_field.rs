@@ -6,7 +6,10 @@ use std::mem;
 use oxc_allocator::{Box as ArenaBox, TakeIn};
 use oxc_ast::{NONE, ast::*};
 use oxc_span::SPAN;
-use oxc_syntax::{reference::ReferenceId, symbol::SymbolId};
+use oxc_syntax::{
+    reference::{ReferenceFlags, ReferenceId},
+    symbol::SymbolId,
+};
 use oxc_traverse::{Ancestor, BoundIdentifier, ast_operations::get_var_name_from_node};
 
 use crate::{
@@ -1859,6 +1862,21 @@ impl<'a> ClassProperties<'a, '_> {
         let ResolvedPrivateProp { class_bindings, prop_binding, is_method, is_static, .. } =
             self.classes_stack.find_private_prop(&left);
 
+        if self.private_fields_as_properties {
+            // `Object.prototype.hasOwnProperty.call(_checkInRHS(object), _prop)`
+            let prop_ident = prop_binding.create_read_expression(ctx);
+            let object = self.create_check_in_rhs(right, SPAN, ctx);
+            let symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), "Object");
+            let callee =
+                ctx.create_ident_expr(SPAN, Atom::from("Object"), symbol_id, ReferenceFlags::Read);
+            let callee = create_member_callee(callee, "prototype", ctx);
+            let callee = create_member_callee(callee, "hasOwnProperty", ctx);
+            let callee = create_member_callee(callee, "call", ctx);
+            let arguments =
+                ctx.ast.vec_from_array([Argument::from(object), Argument::from(prop_ident)]);
+            return ctx.ast.expression_call(span, callee, NONE, arguments, false);
+        }
+
         if is_static {
             let class_binding = class_bindings.get_or_init_static_binding(ctx);
             let class_ident = class_binding.create_read_expression(ctx);
@@ -10,8 +10,9 @@ use oxc_span::SPAN;
 use crate::{Helper, context::TraverseCtx};
 
 use super::{
-    ClassProperties,
+    ClassProperties, PrivateProp,
     super_converter::{ClassPropertiesSuperConverter, ClassPropertiesSuperConverterMode},
+    utils::create_object_define_property,
 };
 
 impl<'a> ClassProperties<'a, '_> {
@@ -48,12 +49,8 @@ impl<'a> ClassProperties<'a, '_> {
 
         let mut function = value.take_in_box(ctx.ast);
 
-        let resolved_private_prop = if *kind == MethodDefinitionKind::Set {
-            self.classes_stack.find_writeable_private_prop(ident)
-        } else {
-            self.classes_stack.find_readable_private_prop(ident)
-        };
-        let temp_binding = resolved_private_prop.unwrap().prop_binding;
+        let private_props = self.current_class().private_props.as_ref().unwrap();
+        let temp_binding = private_props[&ident.name].method_binding(*kind);
 
         function.span = *span;
         function.id = Some(temp_binding.create_binding_identifier(ctx));
@@ -91,6 +88,66 @@ impl<'a> ClassProperties<'a, '_> {
         ]);
         self.ctx.helper_call_expr(Helper::ClassPrivateMethodInitSpec, SPAN, arguments, ctx)
     }
+
+    /// Loose:
+    /// * Method: `Object.defineProperty(object, _method, { value: _method2 })`
+    /// * Accessor: `Object.defineProperty(object, _prop, { get: _get_prop, set: _set_prop })`
+    pub(super) fn create_private_method_init_loose(
+        prop: &PrivateProp<'a>,
+        object: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let key = prop.loose_key_binding().create_read_expression(ctx);
+        if prop.is_accessor() {
+            let get = Self::create_accessor_binding_expr(prop, MethodDefinitionKind::Get, ctx);
+            let set = Self::create_accessor_binding_expr(prop, MethodDefinitionKind::Set, ctx);
+            create_object_define_property(object, key, [("get", get), ("set", set)], ctx)
+        } else {
+            let value = prop.binding.create_read_expression(ctx);
+            create_object_define_property(object, key, [("value", value)], ctx)
+        }
+    }
+
+    /// `_get_prop` / `_set_prop`, or `void 0` if accessor doesn't have that half.
+    fn create_accessor_binding_expr(
+        prop: &PrivateProp<'a>,
+        kind: MethodDefinitionKind,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let is_first = (kind == MethodDefinitionKind::Set)
+            == matches!(prop.method_kind, Some(MethodDefinitionKind::Set));
+        if is_first || prop.binding2.is_some() {
+            prop.method_binding(kind).create_read_expression(ctx)
+        } else {
+            ctx.ast.void_0(SPAN)
+        }
+    }
+
+    /// Loose: Insert `Object.defineProperty(Class, _method, { value: _method2 })` after class
+    /// for static private methods.
+    pub(super) fn insert_private_static_method_inits_loose(&mut self, ctx: &mut TraverseCtx<'a>) {
+        let class_details = self.current_class();
+        let Some(private_props) = &class_details.private_props else { return };
+        let class_binding = if class_details.is_declaration {
+            class_details.bindings.name.as_ref()
+        } else {
+            class_details.bindings.temp.as_ref()
+        };
+        let Some(class_binding) = class_binding else { return };
+
+        let exprs = private_props
+            .values()
+            .rev()
+            .filter(|prop| prop.is_method() && prop.is_static)
+            .map(|prop| {
+                let object = class_binding.create_read_expression(ctx);
+                Self::create_private_method_init_loose(prop, object, ctx)
+            })
+            .collect::<Vec<_>>();
+        for expr in exprs {
+            self.insert_expr_after_class(expr, ctx);
+        }
+    }
 }
 
 /// Visitor to transform private methods.
@@ -1,9 +1,8 @@
 //! ES2022: Class Properties
 //! Transform of class property declarations (instance or static properties).
 
-use oxc_ast::{NONE, ast::*};
+use oxc_ast::ast::*;
 use oxc_span::SPAN;
-use oxc_syntax::reference::ReferenceFlags;
 
 use crate::{
     common::helper_loader::Helper, context::TraverseCtx, utils::ast_builder::create_assignment,
@@ -11,7 +10,9 @@ use crate::{
 
 use super::{
     ClassProperties,
-    utils::{create_underscore_ident_name, create_variable_declaration},
+    utils::{
+        create_object_define_property, create_underscore_ident_name, create_variable_declaration,
+    },
 };
 
 // Instance properties
@@ -340,51 +341,14 @@ impl<'a> ClassProperties<'a, '_> {
         assignee: Expression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
-        // `Object.defineProperty`
-        let object_symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), "Object");
-        let object = ctx.create_ident_expr(
-            SPAN,
-            Atom::from("Object"),
-            object_symbol_id,
-            ReferenceFlags::Read,
-        );
-        let property = ctx.ast.identifier_name(SPAN, "defineProperty");
-        let callee =
-            Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false));
-
-        // `{writable: true, value: <value>}`
-        let prop_def = ctx.ast.expression_object(
-            SPAN,
-            ctx.ast.vec_from_array([
-                ctx.ast.object_property_kind_object_property(
-                    SPAN,
-                    PropertyKind::Init,
-                    ctx.ast.property_key_static_identifier(SPAN, Atom::from("writable")),
-                    ctx.ast.expression_boolean_literal(SPAN, true),
-                    false,
-                    false,
-                    false,
-                ),
-                ctx.ast.object_property_kind_object_property(
-                    SPAN,
-                    PropertyKind::Init,
-                    ctx.ast.property_key_static_identifier(SPAN, Atom::from("value")),
-                    value,
-                    false,
-                    false,
-                    false,
-                ),
-            ]),
-        );
-
         let private_props = self.current_class().private_props.as_ref().unwrap();
-        let prop_binding = &private_props[&ident.name].binding;
-        let arguments = ctx.ast.vec_from_array([
-            Argument::from(assignee),
-            Argument::from(prop_binding.create_read_expression(ctx)),
-            Argument::from(prop_def),
-        ]);
+        let key = private_props[&ident.name].binding.create_read_expression(ctx);
         // TODO: Should this have span of original `PropertyDefinition`?
-        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+        create_object_define_property(
+            assignee,
+            key,
+            [("writable", ctx.ast.expression_boolean_literal(SPAN, true)), ("value", value)],
+            ctx,
+        )
     }
 }
@@ -3,8 +3,9 @@
 
 use std::path::Path;
 
-use oxc_ast::ast::*;
+use oxc_ast::{NONE, ast::*};
 use oxc_span::SPAN;
+use oxc_syntax::reference::ReferenceFlags;
 use oxc_traverse::BoundIdentifier;
 
 use crate::context::TraverseCtx;
@@ -26,6 +27,45 @@ pub(super) fn create_variable_declaration<'a>(
     Statement::from(ctx.ast.declaration_variable(SPAN, kind, ctx.ast.vec1(declarator), false))
 }
 
+/// Create `Object.defineProperty(object, key, { <descriptor> })`.
+pub(super) fn create_object_define_property<'a, const N: usize>(
+    object: Expression<'a>,
+    key: Expression<'a>,
+    descriptor: [(&'static str, Expression<'a>); N],
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    // `Object.defineProperty`
+    let object_symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), "Object");
+    let object_ident =
+        ctx.create_ident_expr(SPAN, Atom::from("Object"), object_symbol_id, ReferenceFlags::Read);
+    let property = ctx.ast.identifier_name(SPAN, "defineProperty");
+    let callee =
+        Expression::from(ctx.ast.member_expression_static(SPAN, object_ident, property, false));
+
+    // `{ <descriptor> }`
+    let descriptor = ctx.ast.expression_object(
+        SPAN,
+        ctx.ast.vec_from_iter(descriptor.into_iter().map(|(name, value)| {
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                ctx.ast.property_key_static_identifier(SPAN, Atom::from(name)),
+                value,
+                false,
+                false,
+                false,
+            )
+        })),
+    );
+
+    let arguments = ctx.ast.vec_from_array([
+        Argument::from(object),
+        Argument::from(key),
+        Argument::from(descriptor),
+    ]);
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
 /// Convert an iterator of `Expression`s into an iterator of `Statement::ExpressionStatement`s.
 pub(super) fn exprs_into_stmts<'a, E>(
     exprs: E,
@@ -30,3 +30,20 @@ pub fn valueless_key(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Please provide an explicit key value. Using \"key\" as a shorthand for \"key={true}\" is not allowed.")
         .with_label(span)
 }
+
+pub fn v_model_requires_expression(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("You have to use JSX Expression inside your v-model").with_label(span)
+}
+
+pub fn v_models_requires_component(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("v-models can only use in custom components").with_label(span)
+}
+
+pub fn v_models_requires_array(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("You should pass a Two-dimensional Arrays to v-models").with_label(span)
+}
+
+pub fn invalid_v_model_value(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("v-model value must be an identifier or a member expression.")
+        .with_label(span)
+}
@@ -317,7 +317,7 @@ fn get_import_source(jsx_runtime_importer: &str, react_importer_len: u32) -> Ato
 /// Pragma used in classic mode.
 ///
 /// `Double` is first as it's most common.
-enum Pragma<'a> {
+pub(super) enum Pragma<'a> {
     /// `React.createElement`
     Double(Atom<'a>, Atom<'a>),
     /// `createElement`
@@ -334,7 +334,7 @@ impl<'a> Pragma<'a> {
     /// Parse `options.pragma` or `options.pragma_frag`.
     ///
     /// If provided option is invalid, raise an error and use default.
-    fn parse(
+    pub(super) fn parse(
         pragma: Option<&str>,
         default_property_name: &'static str,
         ast: AstBuilder<'a>,
@@ -365,7 +365,7 @@ impl<'a> Pragma<'a> {
         }
     }
 
-    fn create_expression(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    pub(super) fn create_expression(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
         let (object, parts) = match self {
             Self::Double(first, second) => {
                 let object = get_read_identifier_reference(SPAN, *first, ctx);
@@ -427,6 +427,11 @@ impl<'a, 'ctx> JsxImpl<'a, 'ctx> {
                     Pragma::parse(options.pragma_frag.as_deref(), "Fragment", ast, ctx);
                 Bindings::Classic(ClassicBindings { pragma, pragma_frag })
             }
+            // Vue JSX is transformed by `VueJsx`, so these bindings are never used.
+            JsxRuntime::Vue => Bindings::Classic(ClassicBindings {
+                pragma: Pragma::parse(None, "createElement", ast, ctx),
+                pragma_frag: Pragma::parse(None, "Fragment", ast, ctx),
+            }),
             JsxRuntime::Automatic => {
                 if options.pragma.is_some() || options.pragma_frag.is_some() {
                     ctx.error(diagnostics::pragma_and_pragma_frag_cannot_be_set());
@@ -853,7 +858,7 @@ impl<'a> JsxImpl<'a, '_> {
         }
     }
 
-    fn transform_jsx_member_expression(
+    pub(super) fn transform_jsx_member_expression(
         expr: ArenaBox<'a, JSXMemberExpression<'a>>,
         ctx: &TraverseCtx<'a>,
     ) -> Expression<'a> {
@@ -876,17 +881,7 @@ impl<'a> JsxImpl<'a, '_> {
     ) -> Expression<'a> {
         match value {
             Some(JSXAttributeValue::StringLiteral(s)) => {
-                let mut decoded = None;
-                Self::decode_entities(s.value.as_str(), &mut decoded, s.value.len(), ctx);
-                let jsx_text = if let Some(decoded) = decoded {
-                    // Text contains HTML entities which were decoded.
-                    // `decoded` contains the decoded string as an `ArenaString`. Convert it to `Atom`.
-                    Atom::from(decoded)
-                } else {
-                    // No HTML entities needed to be decoded. Use the original `Atom` without copying.
-                    s.value
-                };
-                ctx.ast.expression_string_literal(s.span, jsx_text, None)
+                Self::transform_jsx_string_literal(&s, ctx)
             }
             Some(JSXAttributeValue::Element(e)) => self.transform_jsx_element(e, ctx),
             Some(JSXAttributeValue::Fragment(e)) => {
@@ -902,6 +897,23 @@ impl<'a> JsxImpl<'a, '_> {
         }
     }
 
+    pub(super) fn transform_jsx_string_literal(
+        s: &StringLiteral<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut decoded = None;
+        Self::decode_entities(s.value.as_str(), &mut decoded, s.value.len(), ctx);
+        let jsx_text = if let Some(decoded) = decoded {
+            // Text contains HTML entities which were decoded.
+            // `decoded` contains the decoded string as an `ArenaString`. Convert it to `Atom`.
+            Atom::from(decoded)
+        } else {
+            // No HTML entities needed to be decoded. Use the original `Atom` without copying.
+            s.value
+        };
+        ctx.ast.expression_string_literal(s.span, jsx_text, None)
+    }
+
     fn transform_jsx_child_automatic(
         &mut self,
         child: JSXChild<'a>,
@@ -972,7 +984,10 @@ impl<'a> JsxImpl<'a, '_> {
         }
     }
 
-    fn transform_jsx_text(text: &JSXText<'a>, ctx: &TraverseCtx<'a>) -> Option<Expression<'a>> {
+    pub(super) fn transform_jsx_text(
+        text: &JSXText<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
         Self::fixup_whitespace_and_decode_entities(text.value, ctx)
             .map(|value| ctx.ast.expression_string_literal(text.span, value, None))
     }
@@ -1178,7 +1193,7 @@ impl<'a> JsxImpl<'a, '_> {
         false
     }
 
-    fn delete_reference_for_closing_element(
+    pub(super) fn delete_reference_for_closing_element(
         element: Option<&JSXClosingElement<'a>>,
         ctx: &mut TraverseCtx<'a>,
     ) {
@@ -15,12 +15,14 @@ mod jsx_self;
 mod jsx_source;
 mod options;
 mod refresh;
+mod vue;
 pub use comments::update_options_with_comments;
 use display_name::ReactDisplayName;
 use jsx_impl::JsxImpl;
 use jsx_self::JsxSelf;
-pub use options::{JsxOptions, JsxRuntime, ReactRefreshOptions};
+pub use options::{JsxOptions, JsxRuntime, ReactRefreshOptions, VueJsxOptions};
 use refresh::ReactRefresh;
+use vue::VueJsx;
 
 /// [Preset React](https://babel.dev/docs/babel-preset-react)
 ///
@@ -30,8 +32,12 @@ use refresh::ReactRefresh;
 /// * [plugin-transform-react-jsx-self](https://babeljs.io/docs/babel-plugin-transform-react-jsx-self)
 /// * [plugin-transform-react-jsx-source](https://babel.dev/docs/babel-plugin-transform-react-jsx-source)
 /// * [plugin-transform-react-display-name](https://babeljs.io/docs/babel-plugin-transform-react-display-name)
+///
+/// And Vue JSX, same as [@vue/babel-plugin-jsx](https://github.com/vuejs/babel-plugin-jsx),
+/// when runtime is `vue`.
 pub struct Jsx<'a, 'ctx> {
     implementation: JsxImpl<'a, 'ctx>,
+    vue: Option<VueJsx<'a, 'ctx>>,
     display_name: ReactDisplayName<'a, 'ctx>,
     refresh: ReactRefresh<'a, 'ctx>,
     enable_jsx_plugin: bool,
@@ -56,8 +62,10 @@ impl<'a, 'ctx> Jsx<'a, 'ctx> {
             jsx_plugin, display_name_plugin, jsx_self_plugin, jsx_source_plugin, ..
         } = options;
         let refresh = options.refresh.clone();
+        let vue = (jsx_plugin && options.runtime.is_vue()).then(|| VueJsx::new(&options, ast, ctx));
         Self {
             implementation: JsxImpl::new(options, object_rest_spread_options, ast, ctx),
+            vue,
             display_name: ReactDisplayName::new(ctx),
             enable_jsx_plugin: jsx_plugin,
             display_name_plugin,
@@ -120,7 +128,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for Jsx<'a, '_> {
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        if self.enable_jsx_plugin {
+        if let Some(vue) = &mut self.vue {
+            vue.exit_expression(expr, ctx);
+        } else if self.enable_jsx_plugin {
             self.implementation.exit_expression(expr, ctx);
         }
         if self.refresh_plugin {
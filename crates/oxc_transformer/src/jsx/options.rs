@@ -9,6 +9,7 @@ fn default_as_true() -> bool {
 ///
 /// Auto imports the functions that JSX transpiles to.
 /// classic does not automatic import anything.
+/// vue transforms JSX to Vue 3 `createVNode` calls.
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum JsxRuntime {
@@ -16,6 +17,8 @@ pub enum JsxRuntime {
     /// The default runtime is switched to automatic in Babel 8.
     #[default]
     Automatic,
+    /// Vue 3 JSX, same as [@vue/babel-plugin-jsx](https://github.com/vuejs/babel-plugin-jsx).
+    Vue,
 }
 
 impl JsxRuntime {
@@ -26,6 +29,10 @@ impl JsxRuntime {
     pub fn is_automatic(self) -> bool {
         self == Self::Automatic
     }
+
+    pub fn is_vue(self) -> bool {
+        self == Self::Vue
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -71,7 +78,7 @@ pub struct JsxOptions {
     //
     /// Replaces the import source when importing functions.
     ///
-    /// Defaults to `react`.
+    /// Defaults to `react`, or `vue` in Vue runtime.
     #[serde(default)]
     pub import_source: Option<String>,
 
@@ -83,6 +90,8 @@ pub struct JsxOptions {
     ///
     /// Note that the @jsx React.DOM pragma has been deprecated as of React v0.12
     ///
+    /// In Vue runtime, it replaces `createVNode`, and is not imported.
+    ///
     /// Defaults to `React.createElement`.
     #[serde(default)]
     pub pragma: Option<String>,
@@ -105,6 +114,11 @@ pub struct JsxOptions {
 
     /// Fast Refresh
     pub refresh: Option<ReactRefreshOptions>,
+
+    // Vue Runtime
+    //
+    /// Options of Vue runtime.
+    pub vue: VueJsxOptions,
 }
 
 impl Default for JsxOptions {
@@ -138,6 +152,7 @@ impl JsxOptions {
             use_built_ins: None,
             use_spread: None,
             refresh: None,
+            vue: VueJsxOptions::default(),
         }
     }
 
@@ -157,6 +172,54 @@ impl JsxOptions {
             use_built_ins: None,
             use_spread: None,
             refresh: None,
+            vue: VueJsxOptions::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct VueJsxOptions {
+    /// Transforms `on={{ click: handler }}` to `onClick: handler`,
+    /// by `@vue/babel-helper-vue-transform-on`.
+    ///
+    /// Defaults to `false`.
+    pub transform_on: bool,
+
+    /// Emits patch flags, dynamic prop names and slot flags, which let Vue skip diffing
+    /// static parts of vnodes. Enable it only when all props of components are declared.
+    ///
+    /// Defaults to `false`.
+    pub optimize: bool,
+
+    /// Merges `class`, `style` and `on*` props which are set more than once, and spread props,
+    /// with `mergeProps`.
+    ///
+    /// Defaults to `true`.
+    #[serde(default = "default_as_true")]
+    pub merge_props: bool,
+
+    /// Treats a single child of a component, which is an identifier or a call expression,
+    /// as slots if it is an object, e.g. `<Comp>{slots}</Comp>`.
+    ///
+    /// Defaults to `true`.
+    #[serde(default = "default_as_true")]
+    pub enable_object_slots: bool,
+
+    /// Tags of custom elements, which are neither resolved as components nor passed slots.
+    ///
+    /// Defaults to `[]`.
+    pub custom_elements: Vec<String>,
+}
+
+impl Default for VueJsxOptions {
+    fn default() -> Self {
+        Self {
+            transform_on: false,
+            optimize: false,
+            merge_props: default_as_true(),
+            enable_object_slots: default_as_true(),
+            custom_elements: vec![],
         }
     }
 }
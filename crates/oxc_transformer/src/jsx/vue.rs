@@ -0,0 +1,1740 @@
+//! Vue JSX
+//!
+//! This plugin transforms Vue 3 JSX to `createVNode` calls of Vue's runtime.
+//!
+//! Enabled by setting `runtime` of JSX options to `vue`.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```jsx
+//! <div class={cls} onClick={onClick} vShow={visible}>
+//!   hello {name}
+//!   <Comp vModel={value}>{() => "slot"}</Comp>
+//! </div>;
+//! ```
+//!
+//! Output:
+//! ```js
+//! import {
+//!   createTextVNode as _createTextVNode, resolveComponent as _resolveComponent,
+//!   createVNode as _createVNode, vShow as _vShow, withDirectives as _withDirectives
+//! } from "vue";
+//! _withDirectives(_createVNode("div", { "class": cls, "onClick": onClick }, [
+//!   _createTextVNode("hello "),
+//!   name,
+//!   _createVNode(_resolveComponent("Comp"), {
+//!     "modelValue": value,
+//!     "onUpdate:modelValue": ($event) => value = $event
+//!   }, { default: () => "slot" })
+//! ]), [[_vShow, visible]]);
+//! ```
+//!
+//! With `optimize` option, patch flags and dynamic prop names are passed to `createVNode`,
+//! e.g. `_createVNode("div", { "class": cls, "onClick": onClick }, [...], 10, ["onClick"])`.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@vue/babel-plugin-jsx](https://github.com/vuejs/babel-plugin-jsx).
+//!
+//! Differences from the Babel plugin:
+//! * Children of slots are not wrapped in an IIFE, when they refer to variables declared in the
+//!   same function.
+//! * `Fragment` is only imported from Vue, when there is no `Fragment` binding.
+//! * Slot flags are `DYNAMIC` when a child is an identifier declared in a function,
+//!   instead of walking scopes of all references of the identifier.
+//! * `isCustomElement` function is replaced by `customElements` list.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/vuejs/babel-plugin-jsx/tree/main/packages/babel-plugin-jsx>
+//! * Patch flags: <https://github.com/vuejs/core/blob/main/packages/shared/src/patchFlags.ts>
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{Box as ArenaBox, CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::{AstBuilder, NONE, ast::*};
+use oxc_span::{Atom, GetSpan, SPAN, Span};
+use oxc_syntax::{
+    identifier::is_identifier_name, number::NumberBase, reference::ReferenceFlags,
+    scope::ScopeFlags, symbol::SymbolFlags,
+};
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use super::{
+    diagnostics,
+    jsx_impl::{JsxImpl, Pragma},
+    options::{JsxOptions, VueJsxOptions},
+};
+
+// Patch flags
+const CLASS: u32 = 1 << 1;
+const STYLE: u32 = 1 << 2;
+const PROPS: u32 = 1 << 3;
+const FULL_PROPS: u32 = 1 << 4;
+const NEED_HYDRATION: u32 = 1 << 5;
+const NEED_PATCH: u32 = 1 << 9;
+
+// Slot flags
+const SLOT_STABLE: u32 = 1;
+const SLOT_DYNAMIC: u32 = 2;
+
+/// <https://github.com/vuejs/core/blob/main/packages/shared/src/domTagConfig.ts>
+const HTML_TAGS: &[&str] = &[
+    "html",
+    "body",
+    "base",
+    "head",
+    "link",
+    "meta",
+    "style",
+    "title",
+    "address",
+    "article",
+    "aside",
+    "footer",
+    "header",
+    "hgroup",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "nav",
+    "section",
+    "div",
+    "dd",
+    "dl",
+    "dt",
+    "figcaption",
+    "figure",
+    "picture",
+    "hr",
+    "img",
+    "li",
+    "main",
+    "ol",
+    "p",
+    "pre",
+    "ul",
+    "a",
+    "b",
+    "abbr",
+    "bdi",
+    "bdo",
+    "br",
+    "cite",
+    "code",
+    "data",
+    "dfn",
+    "em",
+    "i",
+    "kbd",
+    "mark",
+    "q",
+    "rp",
+    "rt",
+    "ruby",
+    "s",
+    "samp",
+    "small",
+    "span",
+    "strong",
+    "sub",
+    "sup",
+    "time",
+    "u",
+    "var",
+    "wbr",
+    "area",
+    "audio",
+    "map",
+    "track",
+    "video",
+    "embed",
+    "object",
+    "param",
+    "source",
+    "canvas",
+    "script",
+    "noscript",
+    "del",
+    "ins",
+    "caption",
+    "col",
+    "colgroup",
+    "table",
+    "thead",
+    "tbody",
+    "td",
+    "th",
+    "tr",
+    "button",
+    "datalist",
+    "fieldset",
+    "form",
+    "input",
+    "label",
+    "legend",
+    "meter",
+    "optgroup",
+    "option",
+    "output",
+    "progress",
+    "select",
+    "textarea",
+    "details",
+    "dialog",
+    "menu",
+    "summary",
+    "template",
+    "blockquote",
+    "iframe",
+    "tfoot",
+];
+
+/// <https://github.com/vuejs/core/blob/main/packages/shared/src/domTagConfig.ts>
+const SVG_TAGS: &[&str] = &[
+    "svg",
+    "animate",
+    "animateMotion",
+    "animateTransform",
+    "circle",
+    "clipPath",
+    "color-profile",
+    "defs",
+    "desc",
+    "discard",
+    "ellipse",
+    "feBlend",
+    "feColorMatrix",
+    "feComponentTransfer",
+    "feComposite",
+    "feConvolveMatrix",
+    "feDiffuseLighting",
+    "feDisplacementMap",
+    "feDistantLight",
+    "feDropShadow",
+    "feFlood",
+    "feFuncA",
+    "feFuncB",
+    "feFuncG",
+    "feFuncR",
+    "feGaussianBlur",
+    "feImage",
+    "feMerge",
+    "feMergeNode",
+    "feMorphology",
+    "feOffset",
+    "fePointLight",
+    "feSpecularLighting",
+    "feSpotLight",
+    "feTile",
+    "feTurbulence",
+    "filter",
+    "foreignObject",
+    "g",
+    "hatch",
+    "hatchpath",
+    "image",
+    "line",
+    "linearGradient",
+    "marker",
+    "mask",
+    "mesh",
+    "meshgradient",
+    "meshpatch",
+    "meshrow",
+    "metadata",
+    "mpath",
+    "path",
+    "pattern",
+    "polygon",
+    "polyline",
+    "radialGradient",
+    "rect",
+    "set",
+    "solidcolor",
+    "stop",
+    "switch",
+    "symbol",
+    "text",
+    "textPath",
+    "title",
+    "tspan",
+    "unknown",
+    "use",
+    "view",
+];
+
+pub struct VueJsx<'a, 'ctx> {
+    options: VueJsxOptions,
+    import_source: Atom<'a>,
+    pragma: Option<Pragma<'a>>,
+
+    ctx: &'ctx TransformCtx<'a>,
+
+    // States
+    bindings: VueBindings<'a>,
+    is_slot: Option<BoundIdentifier<'a>>,
+    transform_on: Option<BoundIdentifier<'a>>,
+}
+
+/// Bindings of Vue's runtime functions
+enum VueBindings<'a> {
+    /// `import { createVNode as _createVNode } from "vue"`
+    Module(FxHashMap<&'static str, BoundIdentifier<'a>>),
+    /// `var _vue = require("vue")`
+    Script(Option<BoundIdentifier<'a>>),
+}
+
+/// A property of props object, the second argument of `createVNode`
+enum Prop<'a> {
+    /// `"name": value`
+    Static(Atom<'a>, Expression<'a>),
+    /// `[name]: value`
+    Computed(Expression<'a>, Expression<'a>),
+    /// Properties of spread objects, e.g. `...props`
+    Other(ObjectPropertyKind<'a>),
+}
+
+#[derive(Default)]
+struct Props<'a> {
+    properties: Vec<Prop<'a>>,
+    /// Arguments of `mergeProps`
+    merge_args: Vec<Expression<'a>>,
+    /// `[directive, value, argument, modifiers]` of `withDirectives`
+    directives: Vec<Expression<'a>>,
+    dynamic_prop_names: Vec<Atom<'a>>,
+    slots: Option<Expression<'a>>,
+    // Patch flag analysis
+    has_ref: bool,
+    has_class_binding: bool,
+    has_style_binding: bool,
+    has_hydration_event_binding: bool,
+    has_dynamic_keys: bool,
+}
+
+impl<'a> Props<'a> {
+    fn add_dynamic_prop_name(&mut self, name: Atom<'a>) {
+        if !self.dynamic_prop_names.contains(&name) {
+            self.dynamic_prop_names.push(name);
+        }
+    }
+}
+
+/// `vModel={[value, "arg", ["modifier"]]}`, `v-model:arg_modifier={value}`, etc.
+struct Directive<'a> {
+    name: String,
+    values: Vec<Option<Expression<'a>>>,
+    /// `None` if the argument is not given
+    args: Vec<Option<Expression<'a>>>,
+    modifiers: Vec<Vec<Atom<'a>>>,
+    /// Whether the directive is passed to `withDirectives`,
+    /// otherwise it is transformed to props
+    should_resolve: bool,
+}
+
+/// `type` attribute of `<input>`, which decides the `v-model` directive
+#[derive(Clone, Copy)]
+enum InputType<'a> {
+    None,
+    Static(Atom<'a>),
+    Dynamic,
+}
+
+impl<'a, 'ctx> VueJsx<'a, 'ctx> {
+    pub fn new(options: &JsxOptions, ast: AstBuilder<'a>, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let import_source = match options.import_source.as_deref() {
+            Some("") => {
+                ctx.error(diagnostics::invalid_import_source());
+                Atom::from("vue")
+            }
+            Some(import_source) => ast.atom(import_source),
+            None => Atom::from("vue"),
+        };
+        let pragma = options
+            .pragma
+            .as_deref()
+            .map(|pragma| Pragma::parse(Some(pragma), "createVNode", ast, ctx));
+        let bindings = if ctx.source_type.is_script() {
+            VueBindings::Script(None)
+        } else {
+            VueBindings::Module(FxHashMap::default())
+        };
+        Self {
+            options: options.vue.clone(),
+            import_source,
+            pragma,
+            ctx,
+            bindings,
+            is_slot: None,
+            transform_on: None,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for VueJsx<'a, '_> {
+    #[inline]
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !matches!(expr, Expression::JSXElement(_) | Expression::JSXFragment(_)) {
+            return;
+        }
+        *expr = match expr.take_in(ctx.ast) {
+            Expression::JSXElement(e) => self.transform_jsx_element(e, ctx),
+            Expression::JSXFragment(e) => self.transform_jsx(e.span, None, e.unbox().children, ctx),
+            _ => unreachable!(),
+        };
+    }
+}
+
+impl<'a> VueJsx<'a, '_> {
+    fn transform_jsx_element(
+        &mut self,
+        element: ArenaBox<'a, JSXElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let JSXElement { span, opening_element, closing_element, children } = element.unbox();
+        JsxImpl::delete_reference_for_closing_element(closing_element.as_deref(), ctx);
+        self.transform_jsx(span, Some(opening_element), children, ctx)
+    }
+
+    fn transform_jsx(
+        &mut self,
+        span: Span,
+        opening_element: Option<ArenaBox<'a, JSXOpeningElement<'a>>>,
+        children: ArenaVec<'a, JSXChild<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let (tag, tag_name, is_component, attributes) = match opening_element {
+            Some(opening_element) => {
+                let JSXOpeningElement { name, attributes, .. } = opening_element.unbox();
+                let (tag, tag_name, is_component) = self.transform_tag(name, ctx);
+                (tag, tag_name, is_component, attributes)
+            }
+            None => (self.helper("Fragment", ctx), None, false, ctx.ast.vec()),
+        };
+
+        let mut slot_flag = SLOT_STABLE;
+        let mut children = children
+            .into_iter()
+            .filter_map(|child| self.transform_child(child, &mut slot_flag, ctx))
+            .collect::<Vec<_>>();
+
+        let mut props = self.build_props(attributes, tag_name, is_component, ctx);
+        let patch_flag = Self::get_patch_flag(&props);
+        let props_expr = self.create_props(&mut props, ctx);
+
+        let child = if children.len() > 1 || props.slots.is_some() {
+            if is_component {
+                if children.is_empty() {
+                    props.slots.take()
+                } else {
+                    let children = ctx.ast.vec_from_iter(children.into_iter().map(|(e, _)| e));
+                    let mut properties = ctx.ast.vec1(Self::create_default_slot(children, ctx));
+                    match props.slots.take() {
+                        Some(Expression::ObjectExpression(slots)) => {
+                            properties.extend(slots.unbox().properties);
+                        }
+                        Some(slots) => {
+                            properties
+                                .push(ctx.ast.object_property_kind_spread_property(SPAN, slots));
+                        }
+                        None => {}
+                    }
+                    Some(self.create_slots_object(properties, slot_flag, ctx))
+                }
+            } else {
+                let children = ctx.ast.vec_from_iter(children.into_iter().map(|(e, _)| e));
+                Some(ctx.ast.expression_array(SPAN, children))
+            }
+        } else if let Some((child, is_expression)) = children.pop() {
+            Some(self.transform_single_child(child, is_expression, is_component, slot_flag, ctx))
+        } else {
+            None
+        };
+
+        let callee = match &self.pragma {
+            Some(pragma) => pragma.create_expression(ctx),
+            None => self.helper("createVNode", ctx),
+        };
+        let mut arguments = ctx.ast.vec_from_array([
+            Argument::from(tag),
+            Argument::from(props_expr),
+            Argument::from(child.unwrap_or_else(|| ctx.ast.expression_null_literal(SPAN))),
+        ]);
+        if self.options.optimize {
+            if patch_flag != 0 {
+                arguments.push(Argument::from(create_number(patch_flag, ctx)));
+            }
+            if !props.dynamic_prop_names.is_empty() {
+                let names = props.dynamic_prop_names.iter().map(|name| {
+                    ArrayExpressionElement::from(
+                        ctx.ast.expression_string_literal(SPAN, *name, None),
+                    )
+                });
+                let names = ctx.ast.vec_from_iter(names);
+                arguments.push(Argument::from(ctx.ast.expression_array(SPAN, names)));
+            }
+        }
+        let vnode = ctx.ast.expression_call(span, callee, NONE, arguments, false);
+
+        if props.directives.is_empty() {
+            return vnode;
+        }
+        // `withDirectives(vnode, [[vShow, visible]])`
+        let directives =
+            ctx.ast.vec_from_iter(props.directives.into_iter().map(ArrayExpressionElement::from));
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(vnode),
+            Argument::from(ctx.ast.expression_array(SPAN, directives)),
+        ]);
+        let callee = self.helper("withDirectives", ctx);
+        ctx.ast.expression_call(span, callee, NONE, arguments, false)
+    }
+
+    /// Returns the first argument of `createVNode`, the name of native tags,
+    /// and whether children are passed as slots.
+    fn transform_tag(
+        &mut self,
+        name: JSXElementName<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, Option<Atom<'a>>, bool) {
+        match name {
+            JSXElementName::Identifier(ident) => {
+                let binding = if is_native_tag(&ident.name) {
+                    None
+                } else {
+                    ctx.scoping().find_binding(ctx.current_scope_id(), &ident.name).map(
+                        |symbol_id| {
+                            BoundIdentifier::new(ident.name, symbol_id)
+                                .create_spanned_read_expression(ident.span, ctx)
+                        },
+                    )
+                };
+                self.transform_tag_name(ident.span, ident.name, binding, ctx)
+            }
+            JSXElementName::IdentifierReference(ident) => {
+                let (span, name) = (ident.span, ident.name);
+                let is_bound =
+                    ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_some();
+                let binding = if is_bound && !is_native_tag(&name) {
+                    Some(Expression::Identifier(ident))
+                } else {
+                    ctx.delete_reference_for_identifier(&ident);
+                    None
+                };
+                self.transform_tag_name(span, name, binding, ctx)
+            }
+            JSXElementName::MemberExpression(member_expr) => {
+                let is_component = should_transform_to_slots(&member_expr.property.name);
+                (JsxImpl::transform_jsx_member_expression(member_expr, ctx), None, is_component)
+            }
+            JSXElementName::NamespacedName(namespaced) => {
+                let name = ctx.ast.atom(&namespaced.to_string());
+                (ctx.ast.expression_string_literal(namespaced.span, name, None), Some(name), false)
+            }
+            JSXElementName::ThisExpression(expr) => {
+                (ctx.ast.expression_this(expr.span), None, true)
+            }
+        }
+    }
+
+    fn transform_tag_name(
+        &mut self,
+        span: Span,
+        name: Atom<'a>,
+        binding: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, Option<Atom<'a>>, bool) {
+        if is_native_tag(&name) {
+            return (ctx.ast.expression_string_literal(span, name, None), Some(name), false);
+        }
+        let is_custom_element =
+            self.options.custom_elements.iter().any(|tag| tag.as_str() == name.as_str());
+        let is_component = !is_custom_element && should_transform_to_slots(&name);
+        let tag = if let Some(binding) = binding {
+            binding
+        } else if name == "Fragment" {
+            self.helper("Fragment", ctx)
+        } else if is_custom_element {
+            return (ctx.ast.expression_string_literal(span, name, None), Some(name), false);
+        } else {
+            // `resolveComponent("Comp")`
+            let callee = self.helper("resolveComponent", ctx);
+            let argument = ctx.ast.expression_string_literal(span, name, None);
+            let arguments = ctx.ast.vec1(Argument::from(argument));
+            ctx.ast.expression_call(span, callee, NONE, arguments, false)
+        };
+        (tag, None, is_component)
+    }
+
+    /// Returns the child and whether it is an expression written by user.
+    fn transform_child(
+        &mut self,
+        child: JSXChild<'a>,
+        slot_flag: &mut u32,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<(ArrayExpressionElement<'a>, bool)> {
+        let child = match child {
+            JSXChild::Text(text) => {
+                // `createTextVNode("text")`
+                let text = JsxImpl::transform_jsx_text(&text, ctx)?;
+                let callee = self.helper("createTextVNode", ctx);
+                let arguments = ctx.ast.vec1(Argument::from(text));
+                ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+            }
+            JSXChild::ExpressionContainer(e) => match e.unbox().expression {
+                jsx_expr @ match_expression!(JSXExpression) => {
+                    let expr = jsx_expr.into_expression();
+                    if let Expression::Identifier(ident) = &expr
+                        && is_local_identifier(ident, ctx)
+                    {
+                        *slot_flag = SLOT_DYNAMIC;
+                    }
+                    return Some((ArrayExpressionElement::from(expr), true));
+                }
+                JSXExpression::EmptyExpression(_) => return None,
+            },
+            JSXChild::Element(e) => self.transform_jsx_element(e, ctx),
+            JSXChild::Fragment(e) => self.transform_jsx(e.span, None, e.unbox().children, ctx),
+            JSXChild::Spread(e) => {
+                let JSXSpreadChild { span, expression } = e.unbox();
+                return Some((
+                    ctx.ast.array_expression_element_spread_element(span, expression),
+                    false,
+                ));
+            }
+        };
+        Some((ArrayExpressionElement::from(child), false))
+    }
+
+    /// Transform the only child of an element.
+    ///
+    /// * Component: `<Comp>{slots}</Comp>` -> `_isSlot(slots) ? slots : { default: () => [slots] }`
+    /// * Component: `<Comp>{() => child}</Comp>` -> `{ default: () => child }`
+    /// * Component: `<Comp>{{ a: () => child }}</Comp>` -> `{ a: () => child }`
+    /// * Component: `<Comp>child</Comp>` -> `{ default: () => [child] }`
+    /// * Native tag: `<div>child</div>` -> `[child]`
+    fn transform_single_child(
+        &mut self,
+        child: ArrayExpressionElement<'a>,
+        is_expression: bool,
+        is_component: bool,
+        slot_flag: u32,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let enable_object_slots = self.options.enable_object_slots;
+        match child {
+            ArrayExpressionElement::Identifier(ident) if is_component => {
+                if !enable_object_slots {
+                    let children = ctx.ast.vec1(ArrayExpressionElement::Identifier(ident));
+                    let default = Self::create_default_slot(children, ctx);
+                    return self.create_slots_object(ctx.ast.vec1(default), slot_flag, ctx);
+                }
+                let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                let create_ident = |ctx: &mut TraverseCtx<'a>| {
+                    ctx.create_ident_expr(ident.span, ident.name, symbol_id, ReferenceFlags::Read)
+                };
+                let child = create_ident(ctx);
+                let alternate = create_ident(ctx);
+                self.create_is_slot_condition(
+                    Expression::Identifier(ident),
+                    child,
+                    alternate,
+                    slot_flag,
+                    ctx,
+                )
+            }
+            ArrayExpressionElement::CallExpression(call) if is_component && is_expression => {
+                let call = Expression::CallExpression(call);
+                if !enable_object_slots {
+                    let children = ctx.ast.vec1(ArrayExpressionElement::from(call));
+                    let default = Self::create_default_slot(children, ctx);
+                    return self.create_slots_object(ctx.ast.vec1(default), slot_flag, ctx);
+                }
+                // `_isSlot(_slot = foo()) ? _slot : { default: () => [_slot] }`
+                let binding = self.ctx.var_declarations.create_uid_var("slot", ctx);
+                let test = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    binding.create_write_target(ctx),
+                    call,
+                );
+                let child = binding.create_read_expression(ctx);
+                let alternate = binding.create_read_expression(ctx);
+                self.create_is_slot_condition(test, child, alternate, slot_flag, ctx)
+            }
+            ArrayExpressionElement::FunctionExpression(_)
+            | ArrayExpressionElement::ArrowFunctionExpression(_) => {
+                let key = ctx.ast.property_key_static_identifier(SPAN, "default");
+                let value = child.into_expression();
+                let property = ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    key,
+                    value,
+                    false,
+                    false,
+                    false,
+                );
+                ctx.ast.expression_object(SPAN, ctx.ast.vec1(property))
+            }
+            ArrayExpressionElement::ObjectExpression(object) => {
+                self.create_slots_object(object.unbox().properties, slot_flag, ctx)
+            }
+            child => {
+                let children = ctx.ast.vec1(child);
+                if is_component {
+                    let default = Self::create_default_slot(children, ctx);
+                    let properties = ctx.ast.vec1(default);
+                    ctx.ast.expression_object(SPAN, properties)
+                } else {
+                    ctx.ast.expression_array(SPAN, children)
+                }
+            }
+        }
+    }
+
+    /// `_isSlot(test) ? child : { default: () => [alternate] }`
+    fn create_is_slot_condition(
+        &mut self,
+        test: Expression<'a>,
+        child: Expression<'a>,
+        alternate: Expression<'a>,
+        slot_flag: u32,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = self.get_is_slot(ctx);
+        let test =
+            ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(Argument::from(test)), false);
+        let children = ctx.ast.vec1(ArrayExpressionElement::from(alternate));
+        let default = Self::create_default_slot(children, ctx);
+        let alternate = self.create_slots_object(ctx.ast.vec1(default), slot_flag, ctx);
+        ctx.ast.expression_conditional(SPAN, test, child, alternate)
+    }
+
+    /// `default: () => [children]`
+    fn create_default_slot(
+        children: ArenaVec<'a, ArrayExpressionElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        let array = ctx.ast.expression_array(SPAN, children);
+        let scope_id =
+            ctx.insert_scope_below_expression(&array, ScopeFlags::Arrow | ScopeFlags::Function);
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, array)),
+        );
+        let arrow = ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            SPAN, true, false, NONE, params, NONE, body, scope_id, false, false,
+        );
+        let key = ctx.ast.property_key_static_identifier(SPAN, "default");
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            arrow,
+            false,
+            false,
+            false,
+        )
+    }
+
+    /// `{ ...properties, _: slot_flag }`
+    ///
+    /// Slot flag is only added with `optimize` option.
+    fn create_slots_object(
+        &self,
+        mut properties: ArenaVec<'a, ObjectPropertyKind<'a>>,
+        slot_flag: u32,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if self.options.optimize {
+            let key = ctx.ast.property_key_static_identifier(SPAN, "_");
+            properties.push(ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                create_number(slot_flag, ctx),
+                false,
+                false,
+                false,
+            ));
+        }
+        ctx.ast.expression_object(SPAN, properties)
+    }
+
+    fn build_props(
+        &mut self,
+        attributes: ArenaVec<'a, JSXAttributeItem<'a>>,
+        tag_name: Option<Atom<'a>>,
+        is_component: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Props<'a> {
+        let mut props = Props::default();
+        let mut has_ref = false;
+        let mut has_class_binding = false;
+        let mut has_style_binding = false;
+        let mut has_hydration_event_binding = false;
+
+        let input_type = get_input_type(&attributes);
+        for attribute in attributes {
+            match attribute {
+                JSXAttributeItem::Attribute(attr) => {
+                    let JSXAttribute { span, name, value } = attr.unbox();
+                    let attr_name = match &name {
+                        JSXAttributeName::Identifier(ident) => ident.name,
+                        JSXAttributeName::NamespacedName(namespaced) => {
+                            ctx.ast.atom(&namespaced.to_string())
+                        }
+                    };
+                    let is_expression_container =
+                        matches!(value, Some(JSXAttributeValue::ExpressionContainer(_)));
+                    let value = self.transform_attribute_value(value, ctx);
+
+                    if attr_name == "ref" || value.as_ref().is_some_and(|value| !is_constant(value))
+                    {
+                        if !is_component
+                            && is_on(&attr_name)
+                            && !attr_name.eq_ignore_ascii_case("onclick")
+                            && attr_name != "onUpdate:modelValue"
+                        {
+                            has_hydration_event_binding = true;
+                        }
+                        if attr_name == "ref" {
+                            has_ref = true;
+                        } else if attr_name == "class" && !is_component {
+                            has_class_binding = true;
+                        } else if attr_name == "style" && !is_component {
+                            has_style_binding = true;
+                        } else if attr_name != "key"
+                            && !is_directive(&attr_name)
+                            && attr_name != "on"
+                        {
+                            props.add_dynamic_prop_name(attr_name);
+                        }
+                    }
+
+                    // `on={{ click: handler }}` -> `mergeProps(_transformOn({ click: handler }))`
+                    if self.options.transform_on && (attr_name == "on" || attr_name == "nativeOn") {
+                        let callee = self.get_transform_on(ctx);
+                        let value =
+                            value.unwrap_or_else(|| ctx.ast.expression_boolean_literal(SPAN, true));
+                        let arguments = ctx.ast.vec1(Argument::from(value));
+                        props
+                            .merge_args
+                            .push(ctx.ast.expression_call(SPAN, callee, NONE, arguments, false));
+                        continue;
+                    }
+
+                    if is_directive(&attr_name) {
+                        self.transform_directive(
+                            &name,
+                            span,
+                            value,
+                            is_expression_container,
+                            tag_name,
+                            input_type,
+                            is_component,
+                            &mut props,
+                            ctx,
+                        );
+                    } else {
+                        let name = transform_xlink_name(attr_name, ctx);
+                        let value =
+                            value.unwrap_or_else(|| ctx.ast.expression_boolean_literal(SPAN, true));
+                        props.properties.push(Prop::Static(name, value));
+                    }
+                }
+                JSXAttributeItem::SpreadAttribute(spread) => {
+                    let JSXSpreadAttribute { span, argument } = spread.unbox();
+                    let merge_props = self.options.merge_props;
+                    if merge_props && !props.properties.is_empty() {
+                        let properties = std::mem::take(&mut props.properties);
+                        let object = self.create_props_object(properties, ctx);
+                        props.merge_args.push(object);
+                    }
+                    props.has_dynamic_keys = true;
+                    match argument {
+                        Expression::ObjectExpression(object) if !merge_props => {
+                            props
+                                .properties
+                                .extend(object.unbox().properties.into_iter().map(Prop::Other));
+                        }
+                        argument if merge_props => props.merge_args.push(argument),
+                        argument => {
+                            let property =
+                                ctx.ast.object_property_kind_spread_property(span, argument);
+                            props.properties.push(Prop::Other(property));
+                        }
+                    }
+                }
+            }
+        }
+
+        props.has_ref = has_ref;
+        props.has_class_binding = has_class_binding;
+        props.has_style_binding = has_style_binding;
+        props.has_hydration_event_binding = has_hydration_event_binding;
+        props
+    }
+
+    /// <https://github.com/vuejs/core/blob/main/packages/shared/src/patchFlags.ts>
+    fn get_patch_flag(props: &Props<'a>) -> u32 {
+        let mut patch_flag = 0;
+        if props.has_dynamic_keys {
+            patch_flag |= FULL_PROPS;
+        } else {
+            if props.has_class_binding {
+                patch_flag |= CLASS;
+            }
+            if props.has_style_binding {
+                patch_flag |= STYLE;
+            }
+            if !props.dynamic_prop_names.is_empty() {
+                patch_flag |= PROPS;
+            }
+            if props.has_hydration_event_binding {
+                patch_flag |= NEED_HYDRATION;
+            }
+        }
+        if (patch_flag == 0 || patch_flag == NEED_HYDRATION)
+            && (props.has_ref || !props.directives.is_empty())
+        {
+            patch_flag |= NEED_PATCH;
+        }
+        patch_flag
+    }
+
+    /// Create the second argument of `createVNode`.
+    ///
+    /// * `{ "a": a, "b": b }`
+    /// * `mergeProps({ "a": a }, props, { "b": b })` if there are spread props
+    /// * `null` if there are no props
+    fn create_props(&mut self, props: &mut Props<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let properties = std::mem::take(&mut props.properties);
+        let mut merge_args = std::mem::take(&mut props.merge_args);
+        if !merge_args.is_empty() {
+            if !properties.is_empty() {
+                merge_args.push(self.create_props_object(properties, ctx));
+            }
+            if merge_args.len() == 1 {
+                return merge_args.pop().unwrap();
+            }
+            let callee = self.helper("mergeProps", ctx);
+            let arguments = ctx.ast.vec_from_iter(merge_args.into_iter().map(Argument::from));
+            return ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        }
+        match properties.as_slice() {
+            [] => ctx.ast.expression_null_literal(SPAN),
+            [Prop::Other(ObjectPropertyKind::SpreadProperty(_))] => {
+                let Some(Prop::Other(ObjectPropertyKind::SpreadProperty(spread))) =
+                    properties.into_iter().next()
+                else {
+                    unreachable!()
+                };
+                spread.unbox().argument
+            }
+            _ => self.create_props_object(properties, ctx),
+        }
+    }
+
+    /// Create props object.
+    ///
+    /// With `mergeProps` option, `class`, `style` and `on*` props which are set more than once
+    /// are merged into an array, other duplicated props are removed.
+    fn create_props_object(
+        &self,
+        properties: Vec<Prop<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut deduped: Vec<Prop<'a>> = Vec::with_capacity(properties.len());
+        for prop in properties {
+            match prop {
+                Prop::Static(name, value) if self.options.merge_props => {
+                    let existing = deduped.iter_mut().find_map(|prop| match prop {
+                        Prop::Static(existing_name, existing) if *existing_name == name => {
+                            Some(existing)
+                        }
+                        _ => None,
+                    });
+                    match existing {
+                        Some(existing) => {
+                            if name == "class" || name == "style" || name.starts_with("on") {
+                                merge_as_array(existing, value, ctx);
+                            }
+                        }
+                        None => deduped.push(Prop::Static(name, value)),
+                    }
+                }
+                prop => deduped.push(prop),
+            }
+        }
+
+        let properties = ctx.ast.vec_from_iter(deduped.into_iter().map(|prop| {
+            let (key, value, computed) = match prop {
+                Prop::Static(name, value) => {
+                    let key = ctx.ast.expression_string_literal(SPAN, name, None);
+                    (PropertyKey::from(key), value, false)
+                }
+                Prop::Computed(key, value) => (PropertyKey::from(key), value, true),
+                Prop::Other(property) => return property,
+            };
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                value,
+                false,
+                false,
+                computed,
+            )
+        }));
+        ctx.ast.expression_object(SPAN, properties)
+    }
+
+    fn transform_attribute_value(
+        &mut self,
+        value: Option<JSXAttributeValue<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        match value? {
+            JSXAttributeValue::StringLiteral(s) => {
+                Some(JsxImpl::transform_jsx_string_literal(&s, ctx))
+            }
+            JSXAttributeValue::Element(e) => Some(self.transform_jsx_element(e, ctx)),
+            JSXAttributeValue::Fragment(e) => {
+                Some(self.transform_jsx(e.span, None, e.unbox().children, ctx))
+            }
+            JSXAttributeValue::ExpressionContainer(c) => match c.unbox().expression {
+                jsx_expr @ match_expression!(JSXExpression) => Some(jsx_expr.into_expression()),
+                JSXExpression::EmptyExpression(_) => None,
+            },
+        }
+    }
+
+    /// Transform `vShow`, `vModel`, `vModels`, `vSlots`, `vHtml`, `vText` and custom directives.
+    #[expect(clippy::too_many_arguments)]
+    fn transform_directive(
+        &mut self,
+        name: &JSXAttributeName<'a>,
+        span: Span,
+        value: Option<Expression<'a>>,
+        is_expression_container: bool,
+        tag_name: Option<Atom<'a>>,
+        input_type: InputType<'a>,
+        is_component: bool,
+        props: &mut Props<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(Directive { name, values, mut args, modifiers, should_resolve }) =
+            self.parse_directive(name, span, value, is_expression_container, is_component, ctx)
+        else {
+            return;
+        };
+        let mut values = values.into_iter();
+
+        match name.as_str() {
+            "slots" => {
+                props.slots = values.next().flatten();
+                return;
+            }
+            "html" | "text" => {
+                let key = Atom::from(if name == "html" { "innerHTML" } else { "textContent" });
+                let value = values
+                    .next()
+                    .flatten()
+                    .unwrap_or_else(|| ctx.ast.expression_boolean_literal(SPAN, true));
+                props.properties.push(Prop::Static(key, value));
+                props.add_dynamic_prop_name(key);
+                return;
+            }
+            _ => {}
+        }
+
+        let mut directive_value = None;
+        if name == "model" || name == "models" {
+            for (index, value) in values.enumerate() {
+                let Some(value) = value else { continue };
+                let Some(target) = clone_reference(&value, ReferenceFlags::Write, ctx)
+                    .and_then(into_assignment_target)
+                else {
+                    self.ctx.error(diagnostics::invalid_v_model_value(value.span()));
+                    continue;
+                };
+                // Argument is the prop name of components, e.g. `title` in `v-model:title`.
+                let prop_name = if should_resolve {
+                    args.get(index)
+                        .and_then(Option::as_ref)
+                        .and_then(|arg| clone_reference(arg, ReferenceFlags::Read, ctx))
+                } else {
+                    args.get_mut(index).and_then(Option::take)
+                };
+                // `modelValue` if the argument is not given
+                let is_default = matches!(prop_name, None | Some(Expression::NullLiteral(_)));
+                let (static_name, dynamic_name) = match prop_name {
+                    None | Some(Expression::NullLiteral(_)) => {
+                        (Some(Atom::from("modelValue")), None)
+                    }
+                    Some(Expression::StringLiteral(name)) => (Some(name.value), None),
+                    prop_name => (None, prop_name),
+                };
+
+                if should_resolve {
+                    if index == 0 {
+                        directive_value = Some(value);
+                    }
+                } else {
+                    // `"modelValue": value` or `[name]: value`
+                    match (static_name, &dynamic_name) {
+                        (Some(name), _) => {
+                            props.properties.push(Prop::Static(name, value));
+                            props.add_dynamic_prop_name(name);
+                        }
+                        (None, Some(dynamic_name)) => {
+                            if let Some(key) =
+                                clone_reference(dynamic_name, ReferenceFlags::Read, ctx)
+                            {
+                                props.properties.push(Prop::Computed(key, value));
+                            }
+                        }
+                        (None, None) => unreachable!(),
+                    }
+                    // `"modelModifiers": { "trim": true }` or `[name + "Modifiers"]: { "trim": true }`
+                    if let Some(modifiers) = modifiers.get(index).filter(|m| !m.is_empty()) {
+                        let value = create_modifiers_object(modifiers, ctx);
+                        match (&static_name, &dynamic_name) {
+                            (Some(name), _) => {
+                                let name = if is_default {
+                                    Atom::from("modelModifiers")
+                                } else {
+                                    ctx.ast.atom_from_strs_array([name.as_str(), "Modifiers"])
+                                };
+                                props.properties.push(Prop::Static(name, value));
+                            }
+                            (None, Some(dynamic_name)) => {
+                                if let Some(key) =
+                                    clone_reference(dynamic_name, ReferenceFlags::Read, ctx)
+                                {
+                                    let key = ctx.ast.expression_binary(
+                                        SPAN,
+                                        key,
+                                        BinaryOperator::Addition,
+                                        ctx.ast.expression_string_literal(SPAN, "Modifiers", None),
+                                    );
+                                    props.properties.push(Prop::Computed(key, value));
+                                }
+                            }
+                            (None, None) => unreachable!(),
+                        }
+                    }
+                }
+
+                // `"onUpdate:modelValue": $event => value = $event`
+                let handler = Self::create_update_handler(target, ctx);
+                match (static_name, dynamic_name) {
+                    (Some(name), _) => {
+                        let name = ctx.ast.atom_from_strs_array(["onUpdate:", name.as_str()]);
+                        props.properties.push(Prop::Static(name, handler));
+                        props.add_dynamic_prop_name(name);
+                    }
+                    (None, Some(dynamic_name)) => {
+                        let key = ctx.ast.expression_binary(
+                            SPAN,
+                            ctx.ast.expression_string_literal(SPAN, "onUpdate:", None),
+                            BinaryOperator::Addition,
+                            dynamic_name,
+                        );
+                        props.properties.push(Prop::Computed(key, handler));
+                        props.has_dynamic_keys = true;
+                    }
+                    (None, None) => unreachable!(),
+                }
+            }
+        } else {
+            directive_value = values.next().flatten();
+        }
+
+        if !should_resolve {
+            return;
+        }
+
+        // `[directive, value, argument, modifiers]`
+        let directive = self.resolve_directive(&name, tag_name, input_type, ctx);
+        let argument = args.into_iter().next().flatten();
+        let modifiers = modifiers.into_iter().next().filter(|modifiers| !modifiers.is_empty());
+        let mut elements = ctx.ast.vec1(ArrayExpressionElement::from(directive));
+        if directive_value.is_some() || argument.is_some() || modifiers.is_some() {
+            let value = directive_value.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+            elements.push(ArrayExpressionElement::from(value));
+        }
+        if argument.is_some() || modifiers.is_some() {
+            let argument = argument.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+            elements.push(ArrayExpressionElement::from(argument));
+        }
+        if let Some(modifiers) = modifiers {
+            let properties = modifiers.iter().map(|modifier| {
+                let key = if is_identifier_name(modifier) {
+                    ctx.ast.property_key_static_identifier(SPAN, *modifier)
+                } else {
+                    PropertyKey::from(ctx.ast.expression_string_literal(SPAN, *modifier, None))
+                };
+                ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    key,
+                    ctx.ast.expression_boolean_literal(SPAN, true),
+                    false,
+                    false,
+                    false,
+                )
+            });
+            let object = ctx.ast.expression_object(SPAN, ctx.ast.vec_from_iter(properties));
+            elements.push(ArrayExpressionElement::from(object));
+        }
+        props.directives.push(ctx.ast.expression_array(SPAN, elements));
+    }
+
+    /// Parse name and value of a directive.
+    ///
+    /// * `vModel_trim={value}` -> name `model`, modifiers `["trim"]`
+    /// * `v-model:title_trim={value}` -> name `model`, argument `"title"`, modifiers `["trim"]`
+    /// * `vModel={[value, "title", ["trim"]]}` -> same as above
+    /// * `vModels={[[a, "a"], [b, "b"]]}` -> values `[a, b]`, arguments `["a", "b"]`
+    fn parse_directive(
+        &self,
+        name: &JSXAttributeName<'a>,
+        span: Span,
+        value: Option<Expression<'a>>,
+        is_expression_container: bool,
+        is_component: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Directive<'a>> {
+        let (directive_name, argument, directive_modifiers) = match name {
+            JSXAttributeName::Identifier(ident) => {
+                let mut parts = ident.name.as_str().split('_');
+                let name = parts.next().unwrap_or_default();
+                (name, None, parts.map(Atom::from).collect::<Vec<_>>())
+            }
+            JSXAttributeName::NamespacedName(namespaced) => {
+                let mut parts = namespaced.name.name.as_str().split('_');
+                let argument = parts.next().unwrap_or_default();
+                let modifiers = parts.map(Atom::from).collect::<Vec<_>>();
+                (namespaced.namespace.name.as_str(), Some(Atom::from(argument)), modifiers)
+            }
+        };
+        let directive_name = directive_name.strip_prefix('v').unwrap_or(directive_name);
+        let directive_name = directive_name.strip_prefix('-').unwrap_or(directive_name);
+        let mut chars = directive_name.chars();
+        let name = chars
+            .next()
+            .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+            .unwrap_or_default();
+
+        let is_model = name == "model";
+        let is_models = name == "models";
+        if is_model && !is_expression_container {
+            self.ctx.error(diagnostics::v_model_requires_expression(span));
+            return None;
+        }
+        if is_models && !is_component {
+            self.ctx.error(diagnostics::v_models_requires_component(span));
+            return None;
+        }
+        let should_resolve =
+            !matches!(name.as_str(), "html" | "text" | "model" | "slots" | "models")
+                || (is_model && !is_component);
+
+        let mut args = vec![];
+        if let Some(argument) = argument {
+            args.push(Some(ctx.ast.expression_string_literal(SPAN, argument, None)));
+        }
+        let mut values = vec![];
+        let mut modifiers = directive_modifiers;
+        let mut modifiers_list = vec![];
+        match value {
+            Some(Expression::ArrayExpression(array)) => {
+                let array = array.unbox();
+                let arrays = if is_models {
+                    let mut arrays = vec![];
+                    for element in array.elements {
+                        let ArrayExpressionElement::ArrayExpression(array) = element else {
+                            self.ctx.error(diagnostics::v_models_requires_array(element.span()));
+                            return None;
+                        };
+                        arrays.push(array.unbox());
+                    }
+                    arrays
+                } else {
+                    vec![array]
+                };
+                for array in arrays {
+                    let array_span = array.span;
+                    let mut elements = array.elements.into_iter();
+                    let Some(first) = elements.next().and_then(into_expression) else {
+                        if is_model || is_models {
+                            self.ctx.error(diagnostics::invalid_v_model_value(array_span));
+                            return None;
+                        }
+                        continue;
+                    };
+                    match elements.next() {
+                        Some(ArrayExpressionElement::ArrayExpression(second)) => {
+                            if !should_resolve {
+                                args.push(None);
+                            }
+                            modifiers = parse_modifiers(&second);
+                        }
+                        Some(second @ match_expression!(ArrayExpressionElement)) => {
+                            args.push(Some(second.into_expression()));
+                            if let Some(ArrayExpressionElement::ArrayExpression(third)) =
+                                elements.next()
+                            {
+                                modifiers = parse_modifiers(&third);
+                            } else {
+                                modifiers = vec![];
+                            }
+                        }
+                        _ => {
+                            if !should_resolve {
+                                args.push(None);
+                            }
+                        }
+                    }
+                    modifiers_list.push(modifiers.clone());
+                    values.push(Some(first));
+                }
+            }
+            Some(value) if is_models => {
+                self.ctx.error(diagnostics::v_models_requires_array(value.span()));
+                return None;
+            }
+            value => {
+                if is_model && !should_resolve {
+                    args.push(None);
+                }
+                modifiers_list.push(modifiers);
+                values.push(value);
+            }
+        }
+
+        Some(Directive { name, values, args, modifiers: modifiers_list, should_resolve })
+    }
+
+    fn resolve_directive(
+        &mut self,
+        name: &str,
+        tag_name: Option<Atom<'a>>,
+        input_type: InputType<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match name {
+            "show" => return self.helper("vShow", ctx),
+            "model" => {
+                let directive = match tag_name.as_deref() {
+                    Some("select") => "vModelSelect",
+                    Some("textarea") => "vModelText",
+                    _ => match input_type {
+                        InputType::Static(ty) if ty == "checkbox" => "vModelCheckbox",
+                        InputType::Static(ty) if ty == "radio" => "vModelRadio",
+                        InputType::None | InputType::Static(_) => "vModelText",
+                        InputType::Dynamic => "vModelDynamic",
+                    },
+                };
+                return self.helper(directive, ctx);
+            }
+            _ => {}
+        }
+
+        // Use `vFoo` if it's in scope, otherwise `resolveDirective("foo")`
+        let mut chars = name.chars();
+        let first = chars.next().map(|c| c.to_ascii_uppercase().to_string()).unwrap_or_default();
+        let reference_name = ctx.ast.atom_from_strs_array(["v", &first, chars.as_str()]);
+        if let Some(symbol_id) = ctx.scoping().find_binding(ctx.current_scope_id(), &reference_name)
+        {
+            return BoundIdentifier::new(reference_name, symbol_id).create_read_expression(ctx);
+        }
+        let callee = self.helper("resolveDirective", ctx);
+        let argument = ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(name), None);
+        ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(Argument::from(argument)), false)
+    }
+
+    /// `$event => target = $event`
+    fn create_update_handler(
+        target: AssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id = ctx.create_child_scope_of_current(ScopeFlags::Arrow | ScopeFlags::Function);
+        let event = ctx.generate_binding(
+            Atom::from("$event"),
+            scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            ctx.ast.vec1(ctx.ast.plain_formal_parameter(SPAN, event.create_binding_pattern(ctx))),
+            NONE,
+        );
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            target,
+            event.create_read_expression(ctx),
+        );
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment)),
+        );
+        ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            SPAN, true, false, NONE, params, NONE, body, scope_id, false, false,
+        )
+    }
+
+    /// Get a runtime function of Vue, e.g. `_createVNode` or `_vue.createVNode`.
+    fn helper(&mut self, name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match &mut self.bindings {
+            VueBindings::Module(imports) => {
+                let binding = imports.entry(name).or_insert_with(|| {
+                    let binding = ctx.generate_uid_in_root_scope(name, SymbolFlags::Import);
+                    self.ctx.module_imports.add_named_import(
+                        self.import_source,
+                        Atom::from(name),
+                        binding.clone(),
+                        false,
+                    );
+                    binding
+                });
+                binding.create_read_expression(ctx)
+            }
+            VueBindings::Script(require) => {
+                let binding = require.get_or_insert_with(|| {
+                    let binding =
+                        ctx.generate_uid_in_root_scope("vue", SymbolFlags::FunctionScopedVariable);
+                    self.ctx.module_imports.add_default_import(
+                        self.import_source,
+                        binding.clone(),
+                        false,
+                    );
+                    binding
+                });
+                let object = binding.create_read_expression(ctx);
+                create_member(object, name, ctx)
+            }
+        }
+    }
+
+    /// `import _transformOn from "@vue/babel-helper-vue-transform-on"`
+    fn get_transform_on(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let binding = self.transform_on.get_or_insert_with(|| {
+            let binding = ctx.generate_uid_in_root_scope("transformOn", SymbolFlags::Import);
+            self.ctx.module_imports.add_default_import(
+                Atom::from("@vue/babel-helper-vue-transform-on"),
+                binding.clone(),
+                false,
+            );
+            binding
+        });
+        binding.create_read_expression(ctx)
+    }
+
+    /// ```js
+    /// function _isSlot(s) {
+    ///   return typeof s === "function" || Object.prototype.toString.call(s) === "[object Object]" && !_isVNode(s);
+    /// }
+    /// ```
+    fn get_is_slot(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        if let Some(binding) = &self.is_slot {
+            return binding.create_read_expression(ctx);
+        }
+
+        let binding = ctx.generate_uid_in_root_scope("isSlot", SymbolFlags::Function);
+        let scope_id = ctx.create_child_scope(ctx.scoping().root_scope_id(), ScopeFlags::Function);
+        let param =
+            ctx.generate_binding(Atom::from("s"), scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // `typeof s === "function"`
+        let type_of = ctx.ast.expression_unary(
+            SPAN,
+            UnaryOperator::Typeof,
+            param.create_read_expression(ctx),
+        );
+        let is_function = ctx.ast.expression_binary(
+            SPAN,
+            type_of,
+            BinaryOperator::StrictEquality,
+            ctx.ast.expression_string_literal(SPAN, "function", None),
+        );
+        // `Object.prototype.toString.call(s) === "[object Object]"`
+        let object =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+        let callee = create_member(
+            create_member(create_member(object, "prototype", ctx), "toString", ctx),
+            "call",
+            ctx,
+        );
+        let arguments = ctx.ast.vec1(Argument::from(param.create_read_expression(ctx)));
+        let is_object = ctx.ast.expression_binary(
+            SPAN,
+            ctx.ast.expression_call(SPAN, callee, NONE, arguments, false),
+            BinaryOperator::StrictEquality,
+            ctx.ast.expression_string_literal(SPAN, "[object Object]", None),
+        );
+        // `!_isVNode(s)`
+        let callee = self.helper("isVNode", ctx);
+        let arguments = ctx.ast.vec1(Argument::from(param.create_read_expression(ctx)));
+        let is_not_vnode = ctx.ast.expression_unary(
+            SPAN,
+            UnaryOperator::LogicalNot,
+            ctx.ast.expression_call(SPAN, callee, NONE, arguments, false),
+        );
+        let result = ctx.ast.expression_logical(
+            SPAN,
+            is_function,
+            LogicalOperator::Or,
+            ctx.ast.expression_logical(SPAN, is_object, LogicalOperator::And, is_not_vnode),
+        );
+
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec1(ctx.ast.plain_formal_parameter(SPAN, param.create_binding_pattern(ctx))),
+            NONE,
+        );
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(result))),
+        );
+        let function = ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionDeclaration,
+            SPAN,
+            Some(binding.create_binding_identifier(ctx)),
+            params,
+            body,
+            scope_id,
+        );
+        self.ctx.top_level_statements.insert_statement(Statement::FunctionDeclaration(function));
+
+        let expr = binding.create_read_expression(ctx);
+        self.is_slot = Some(binding);
+        expr
+    }
+}
+
+fn is_native_tag(name: &str) -> bool {
+    HTML_TAGS.contains(&name) || SVG_TAGS.contains(&name)
+}
+
+/// Children of `Fragment` and `KeepAlive` are not passed as slots.
+fn should_transform_to_slots(name: &str) -> bool {
+    let fragment = name.strip_prefix('_').unwrap_or(name);
+    let is_fragment = fragment
+        .strip_prefix("Fragment")
+        .is_some_and(|rest| rest.bytes().all(|b| b.is_ascii_digit()));
+    !(is_fragment || name == "KeepAlive")
+}
+
+/// Whether the identifier refers to a variable declared in a function.
+fn is_local_identifier(ident: &IdentifierReference, ctx: &TraverseCtx) -> bool {
+    ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_some_and(|symbol_id| {
+        ctx.scoping().symbol_scope_id(symbol_id) != ctx.scoping().root_scope_id()
+    })
+}
+
+/// Whether the prop value never changes, which doesn't need to be patched.
+fn is_constant(expr: &Expression) -> bool {
+    match expr {
+        Expression::Identifier(ident) => ident.name == "undefined",
+        Expression::ArrayExpression(array) => array
+            .elements
+            .iter()
+            .all(|element| element.as_expression().is_some_and(is_constant)),
+        Expression::ObjectExpression(object) => object.properties.iter().all(|property| {
+            matches!(property, ObjectPropertyKind::ObjectProperty(property) if is_constant(&property.value))
+        }),
+        Expression::TemplateLiteral(template) => template.expressions.iter().all(is_constant),
+        _ => expr.is_literal(),
+    }
+}
+
+/// `onClick`, `onUpdate:modelValue`
+fn is_on(name: &str) -> bool {
+    name.strip_prefix("on").is_some_and(|rest| !rest.starts_with(|c: char| c.is_ascii_lowercase()))
+        && name.len() > 2
+}
+
+/// `v-show`, `vShow`
+fn is_directive(name: &str) -> bool {
+    name.starts_with("v-")
+        || name
+            .strip_prefix('v')
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+/// `xlinkHref` -> `xlink:href`
+fn transform_xlink_name<'a>(name: Atom<'a>, ctx: &TraverseCtx<'a>) -> Atom<'a> {
+    let Some(rest) = name.strip_prefix("xlink") else { return name };
+    let mut chars = rest.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_uppercase() => ctx.ast.atom_from_strs_array([
+            "xlink:",
+            first.to_ascii_lowercase().encode_utf8(&mut [0; 4]),
+            chars.as_str(),
+        ]),
+        _ => name,
+    }
+}
+
+fn get_input_type<'a>(attributes: &[JSXAttributeItem<'a>]) -> InputType<'a> {
+    for attribute in attributes {
+        let JSXAttributeItem::Attribute(attribute) = attribute else { continue };
+        if !attribute.is_identifier("type") {
+            continue;
+        }
+        return match &attribute.value {
+            Some(JSXAttributeValue::StringLiteral(s)) => InputType::Static(s.value),
+            Some(JSXAttributeValue::ExpressionContainer(container)) => {
+                match &container.expression {
+                    JSXExpression::StringLiteral(s) => InputType::Static(s.value),
+                    _ => InputType::Dynamic,
+                }
+            }
+            _ => InputType::Dynamic,
+        };
+    }
+    InputType::None
+}
+
+/// `["trim", "number"]`
+fn parse_modifiers<'a>(array: &ArrayExpression<'a>) -> Vec<Atom<'a>> {
+    array
+        .elements
+        .iter()
+        .filter_map(|element| match element {
+            ArrayExpressionElement::StringLiteral(s) => Some(s.value),
+            _ => None,
+        })
+        .collect()
+}
+
+/// `{ "trim": true }`
+fn create_modifiers_object<'a>(modifiers: &[Atom<'a>], ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    let properties = modifiers.iter().map(|modifier| {
+        let key = ctx.ast.expression_string_literal(SPAN, *modifier, None);
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            PropertyKey::from(key),
+            ctx.ast.expression_boolean_literal(SPAN, true),
+            false,
+            false,
+            false,
+        )
+    });
+    ctx.ast.expression_object(SPAN, ctx.ast.vec_from_iter(properties))
+}
+
+/// Merge duplicated `class`, `style` or `on*` props into an array.
+fn merge_as_array<'a>(existing: &mut Expression<'a>, value: Expression<'a>, ctx: &TraverseCtx<'a>) {
+    if let Expression::ArrayExpression(array) = existing {
+        array.elements.push(ArrayExpressionElement::from(value));
+    } else {
+        let elements = ctx.ast.vec_from_array([
+            ArrayExpressionElement::from(existing.take_in(ctx.ast)),
+            ArrayExpressionElement::from(value),
+        ]);
+        *existing = ctx.ast.expression_array(SPAN, elements);
+    }
+}
+
+fn into_expression(element: ArrayExpressionElement) -> Option<Expression> {
+    match element {
+        element @ match_expression!(ArrayExpressionElement) => Some(element.into_expression()),
+        _ => None,
+    }
+}
+
+/// Duplicate a reference, e.g. `foo`, `foo.bar` or `foo[0]`, to be read or assigned to.
+///
+/// Returns `None` if the expression is not a reference.
+fn clone_reference<'a>(
+    expr: &Expression<'a>,
+    flags: ReferenceFlags,
+    ctx: &mut TraverseCtx<'a>,
+) -> Option<Expression<'a>> {
+    let expr = match expr {
+        Expression::Identifier(ident) => {
+            let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+            ctx.create_ident_expr(ident.span, ident.name, symbol_id, flags)
+        }
+        Expression::ThisExpression(this) => ctx.ast.expression_this(this.span),
+        Expression::StaticMemberExpression(member) => {
+            let object = clone_reference(&member.object, ReferenceFlags::Read, ctx)?;
+            Expression::from(ctx.ast.member_expression_static(
+                member.span,
+                object,
+                member.property.clone(),
+                false,
+            ))
+        }
+        Expression::ComputedMemberExpression(member) => {
+            let object = clone_reference(&member.object, ReferenceFlags::Read, ctx)?;
+            let expression = clone_reference(&member.expression, ReferenceFlags::Read, ctx)?;
+            Expression::from(ctx.ast.member_expression_computed(
+                member.span,
+                object,
+                expression,
+                false,
+            ))
+        }
+        Expression::StringLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::BooleanLiteral(_) => expr.clone_in(ctx.ast.allocator),
+        _ => return None,
+    };
+    Some(expr)
+}
+
+fn into_assignment_target(expr: Expression) -> Option<AssignmentTarget> {
+    match expr {
+        Expression::Identifier(ident) => Some(AssignmentTarget::AssignmentTargetIdentifier(ident)),
+        Expression::StaticMemberExpression(member) => {
+            Some(AssignmentTarget::StaticMemberExpression(member))
+        }
+        Expression::ComputedMemberExpression(member) => {
+            Some(AssignmentTarget::ComputedMemberExpression(member))
+        }
+        _ => None,
+    }
+}
+
+/// `object.property`
+fn create_member<'a>(
+    object: Expression<'a>,
+    property: &'static str,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let property = ctx.ast.identifier_name(SPAN, property);
+    Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+}
+
+fn create_number<'a>(value: u32, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    ctx.ast.expression_numeric_literal(SPAN, f64::from(value), None, NumberBase::Decimal)
+}
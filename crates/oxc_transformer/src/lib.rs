@@ -32,12 +32,14 @@ mod es2020;
 mod es2021;
 mod es2022;
 mod jsx;
+mod modules;
 mod proposals;
 mod regexp;
 mod typescript;
 
 mod decorator;
 mod plugins;
+mod polyfills;
 
 use common::Common;
 use context::{TransformCtx, TraverseCtx};
@@ -51,9 +53,11 @@ use es2020::ES2020;
 use es2021::ES2021;
 use es2022::ES2022;
 use jsx::Jsx;
+use modules::Modules;
+use polyfills::Polyfills;
 use proposals::ExplicitResourceManagement;
 use regexp::RegExp;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use state::TransformState;
 use typescript::TypeScript;
 
@@ -70,14 +74,22 @@ pub use crate::{
     es2020::ES2020Options,
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
-    jsx::{JsxOptions, JsxRuntime, ReactRefreshOptions},
+    jsx::{JsxOptions, JsxRuntime, ReactRefreshOptions, VueJsxOptions},
+    modules::ModulesOptions,
     options::{
         ESTarget, Engine, EngineTargets, EnvOptions, Module, TransformOptions,
-        babel::{BabelEnvOptions, BabelOptions},
+        babel::{BabelConfig, BabelConfigLoadOptions, BabelEnvOptions, BabelOptions},
     },
-    plugins::{PluginsOptions, StyledComponentsOptions},
+    plugins::{
+        EmotionAutoLabel, EmotionImportMapEntry, EmotionOptions, GraphQLTagOptions,
+        ModuleRunnerTransform, PluginsOptions, StyledComponentsOptions,
+    },
+    polyfills::{PolyfillMethod, PolyfillsOptions},
     proposals::ProposalOptions,
-    typescript::{RewriteExtensionsMode, TypeScriptOptions},
+    typescript::{
+        ConstEnumMembers, ConstEnumModule, ConstEnumValue, ExternalConstEnums,
+        RewriteExtensionsMode, RewritePathsOptions, TypeScriptOptions,
+    },
 };
 
 #[non_exhaustive]
@@ -87,6 +99,10 @@ pub struct TransformerReturn {
     /// Helpers used by this transform.
     #[deprecated = "Internal usage only"]
     pub helpers_used: FxHashMap<Helper, String>,
+    /// Imported modules, collected by [`PluginsOptions::module_runner_transform`].
+    pub deps: FxHashSet<String>,
+    /// Dynamically imported modules, collected by [`PluginsOptions::module_runner_transform`].
+    pub dynamic_deps: FxHashSet<String>,
 }
 
 pub struct Transformer<'a> {
@@ -98,6 +114,7 @@ pub struct Transformer<'a> {
     plugins: PluginsOptions,
     jsx: JsxOptions,
     env: EnvOptions,
+    modules: ModulesOptions,
     proposals: ProposalOptions,
 }
 
@@ -112,6 +129,7 @@ impl<'a> Transformer<'a> {
             plugins: options.plugins.clone(),
             jsx: options.jsx.clone(),
             env: options.env,
+            modules: options.modules.clone(),
             proposals: options.proposals,
         }
     }
@@ -136,6 +154,8 @@ impl<'a> Transformer<'a> {
             );
         }
 
+        let module_runner =
+            self.plugins.module_runner_transform.then(ModuleRunnerTransform::new_in_transformer);
         let mut transformer = TransformerImpl {
             common: Common::new(&self.env, &self.ctx),
             decorator: Decorator::new(self.decorator, &self.ctx),
@@ -163,13 +183,24 @@ impl<'a> Transformer<'a> {
             x2_es2017: ES2017::new(self.env.es2017, &self.ctx),
             x3_es2015: ES2015::new(self.env.es2015, &self.ctx),
             x4_regexp: RegExp::new(self.env.regexp, &self.ctx),
+            polyfills: Polyfills::new(self.env.polyfills, &self.ctx),
+            modules: Modules::new(self.env.module, self.modules, &self.ctx),
+            module_runner,
         };
 
         let state = TransformState::default();
         let scoping = traverse_mut(&mut transformer, allocator, program, scoping, state);
         let helpers_used = self.ctx.helper_loader.used_helpers.borrow_mut().drain().collect();
+        let (deps, dynamic_deps) =
+            transformer.module_runner.map(ModuleRunnerTransform::into_deps).unwrap_or_default();
         #[expect(deprecated)]
-        TransformerReturn { errors: self.ctx.take_errors(), scoping, helpers_used }
+        TransformerReturn {
+            errors: self.ctx.take_errors(),
+            scoping,
+            helpers_used,
+            deps,
+            dynamic_deps,
+        }
     }
 }
 
@@ -190,7 +221,10 @@ struct TransformerImpl<'a, 'ctx> {
     #[expect(unused)]
     x3_es2015: ES2015<'a, 'ctx>,
     x4_regexp: RegExp<'a, 'ctx>,
+    polyfills: Option<Polyfills<'a, 'ctx>>,
     common: Common<'a, 'ctx>,
+    modules: Option<Modules<'a, 'ctx>>,
+    module_runner: Option<ModuleRunnerTransform<'a>>,
 }
 
 impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
@@ -199,20 +233,37 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
             typescript.enter_program(program, ctx);
         }
         self.plugins.enter_program(program, ctx);
+        self.decorator.enter_program(program, ctx);
         self.x1_jsx.enter_program(program, ctx);
         if let Some(explicit_resource_management) = self.explicit_resource_management.as_mut() {
             explicit_resource_management.enter_program(program, ctx);
         }
+        if let Some(module_runner) = self.module_runner.as_mut() {
+            module_runner.enter_program(program, ctx);
+        }
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x1_jsx.exit_program(program, ctx);
+        self.plugins.exit_program(program, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.exit_program(program, ctx);
         }
         self.x2_es2022.exit_program(program, ctx);
         self.x2_es2018.exit_program(program, ctx);
+        if let Some(polyfills) = self.polyfills.as_mut() {
+            polyfills.exit_program(program, ctx);
+        }
+        if let Some(modules) = self.modules.as_ref() {
+            modules.load_helpers(program, ctx);
+        }
         self.common.exit_program(program, ctx);
+        if let Some(modules) = self.modules.as_mut() {
+            modules.exit_program(program, ctx);
+        }
+        if let Some(module_runner) = self.module_runner.as_mut() {
+            module_runner.exit_program(program, ctx);
+        }
     }
 
     // ALPHASORT
@@ -265,6 +316,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_identifier_reference(node, ctx);
+        self.decorator.enter_identifier_reference(node, ctx);
     }
 
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -334,12 +386,19 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2018.enter_expression(expr, ctx);
         self.x2_es2016.enter_expression(expr, ctx);
         self.x4_regexp.enter_expression(expr, ctx);
+        if let Some(polyfills) = self.polyfills.as_mut() {
+            polyfills.enter_expression(expr, ctx);
+        }
+        if let Some(module_runner) = self.module_runner.as_mut() {
+            module_runner.enter_expression(expr, ctx);
+        }
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.common.exit_expression(expr, ctx);
         self.x1_jsx.exit_expression(expr, ctx);
         self.x2_es2022.exit_expression(expr, ctx);
+        self.decorator.exit_expression(expr, ctx);
         self.x2_es2018.exit_expression(expr, ctx);
         self.x2_es2017.exit_expression(expr, ctx);
     }
@@ -425,6 +484,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
 
     fn enter_jsx_element_name(&mut self, node: &mut JSXElementName<'a>, ctx: &mut TraverseCtx<'a>) {
         self.common.enter_jsx_element_name(node, ctx);
+        if let Some(module_runner) = self.module_runner.as_mut() {
+            module_runner.enter_jsx_element_name(node, ctx);
+        }
     }
 
     fn enter_jsx_member_expression_object(
@@ -433,6 +495,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_jsx_member_expression_object(node, ctx);
+        if let Some(module_runner) = self.module_runner.as_mut() {
+            module_runner.enter_jsx_member_expression_object(node, ctx);
+        }
     }
 
     fn enter_jsx_fragment(&mut self, node: &mut JSXFragment<'a>, ctx: &mut TraverseCtx<'a>) {
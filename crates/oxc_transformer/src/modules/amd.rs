@@ -0,0 +1,52 @@
+//! AMD
+//!
+//! ```js
+//! import foo from "foo";
+//! export default foo;
+//! ```
+//! ->
+//! ```js
+//! define(["exports", "foo"], function(_exports, _foo) {
+//!   "use strict";
+//!   Object.defineProperty(_exports, "__esModule", { value: true });
+//!   _exports.default = void 0;
+//!   _foo = babelHelpers.interopRequireDefault(_foo);
+//!   _exports.default = _foo.default;
+//! });
+//! ```
+//!
+//! Dynamic imports are transformed into `require` calls of the AMD loader:
+//! `import("foo")` -> `new Promise((_resolve, _reject) => _require(["foo"], (_imported) => _resolve(babelHelpers.interopRequireWildcard(_imported)), _reject))`
+//!
+//! Based on Babel's [transform-modules-amd](https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-modules-amd/src/index.ts).
+
+use oxc_ast::ast::*;
+use oxc_semantic::ReferenceFlags;
+use oxc_span::SPAN;
+
+use crate::context::{TransformCtx, TraverseCtx};
+
+use super::{ModulesOptions, create_call, factory::transform_factory_body};
+
+pub(super) fn transform_program<'a>(
+    program: &mut Program<'a>,
+    options: &ModulesOptions,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let factory = transform_factory_body(program, options, transform_ctx, ctx, true);
+
+    let mut arguments = Vec::with_capacity(3);
+    if let Some(module_id) = &options.module_id {
+        arguments.push(ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(module_id), None));
+    }
+    let dependencies = ctx.ast.vec_from_iter(factory.dependency_names().map(|name| {
+        ArrayExpressionElement::from(ctx.ast.expression_string_literal(SPAN, name, None))
+    }));
+    arguments.push(ctx.ast.expression_array(SPAN, dependencies));
+    arguments.push(factory.create_function(program, ctx));
+
+    let define = ctx.create_unbound_ident_expr(SPAN, Atom::from("define"), ReferenceFlags::Read);
+    let call = create_call(define, arguments, ctx);
+    program.body.push(ctx.ast.statement_expression(SPAN, call));
+}
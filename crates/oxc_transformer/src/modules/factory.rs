@@ -0,0 +1,968 @@
+//! Factory function body of AMD and UMD modules.
+//!
+//! Imports become parameters of the factory function, and exports are assigned to the
+//! `exports` object, which is also a parameter of the factory function.
+//!
+//! ```js
+//! import foo, { bar } from "foo";
+//! export let count = 0;
+//! export function inc() { count++; }
+//! export { baz } from "baz";
+//! foo(bar);
+//! ```
+//! ->
+//! ```js
+//! // function (_exports, _foo, _baz) {
+//! Object.defineProperty(_exports, "__esModule", { value: true });
+//! _exports.count = void 0;
+//! _exports.inc = inc;
+//! _foo = babelHelpers.interopRequireDefault(_foo);
+//! Object.defineProperty(_exports, "baz", {
+//!   enumerable: true,
+//!   get: function() { return _baz.baz; }
+//! });
+//! let count = 0;
+//! _exports.count = count;
+//! function inc() { _exports.count = count = count + 1; }
+//! (0, _foo.default)(_foo.bar);
+//! // }
+//! ```
+
+use std::cell::Cell;
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+};
+
+use super::{
+    ModulesOptions, create_call, create_function, create_function_with_directives, create_getter,
+    create_member, create_member_assignment, create_module_body_scope, create_object_method,
+    create_object_property, create_var_declaration, file_stem, generate_default_binding,
+    to_identifier,
+};
+
+/// A module which the factory function depends on.
+pub(super) struct Dependency<'a> {
+    pub source: Atom<'a>,
+    /// Parameter of the factory function.
+    pub binding: BoundIdentifier<'a>,
+}
+
+/// Result of [`transform_factory_body`].
+pub(super) struct FactoryBody<'a> {
+    /// Scope of the factory function.
+    pub scope_id: ScopeId,
+    /// `_exports` parameter, if the module has any exports.
+    pub exports: Option<BoundIdentifier<'a>>,
+    /// `_require` parameter, if the module contains dynamic imports (AMD only).
+    pub require: Option<BoundIdentifier<'a>>,
+    pub dependencies: Vec<Dependency<'a>>,
+}
+
+impl<'a> FactoryBody<'a> {
+    /// Module names which the factory function depends on, in the same order as its parameters:
+    /// `"require"`, `"exports"`, then the imported modules.
+    pub fn dependency_names(&self) -> impl Iterator<Item = Atom<'a>> + '_ {
+        self.require
+            .iter()
+            .map(|_| Atom::from("require"))
+            .chain(self.exports.iter().map(|_| Atom::from("exports")))
+            .chain(self.dependencies.iter().map(|dep| dep.source))
+    }
+
+    /// `function(_exports, _foo) { "use strict"; ... }`, with the body of `program`.
+    pub fn create_function(
+        &self,
+        program: &mut Program<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let params = self
+            .require
+            .iter()
+            .chain(&self.exports)
+            .chain(self.dependencies.iter().map(|dep| &dep.binding))
+            .map(|binding| binding.create_binding_pattern(ctx))
+            .collect::<Vec<_>>();
+        let mut directives = program.directives.take_in(ctx.ast);
+        if !directives.iter().any(Directive::is_use_strict) {
+            directives.insert(0, ctx.ast.use_strict_directive());
+        }
+        let body = program.body.take_in(ctx.ast);
+        create_function_with_directives(params, directives, body, self.scope_id, ctx)
+    }
+}
+
+struct DependencyInfo<'a> {
+    source: Atom<'a>,
+    binding: Option<BoundIdentifier<'a>>,
+    import_default: bool,
+    import_namespace: bool,
+}
+
+impl DependencyInfo<'_> {
+    fn interop(&self) -> Option<Helper> {
+        if self.import_namespace {
+            Some(Helper::InteropRequireWildcard)
+        } else if self.import_default {
+            Some(Helper::InteropRequireDefault)
+        } else {
+            None
+        }
+    }
+}
+
+/// `export { imported as exported } from "source"`, or `import { imported } from "source"; export { imported as exported }`.
+/// `imported` is `None` for namespaces.
+struct ReExport<'a> {
+    dependency: usize,
+    imported: Option<Atom<'a>>,
+    exported: Option<Atom<'a>>,
+}
+
+/// Imported binding: index of the dependency, and the imported name, or `None` for namespaces.
+type ImportBinding<'a> = (usize, Option<Atom<'a>>);
+
+/// Transform `program.body` into the factory function body.
+///
+/// `dynamic_import`: transform `import()` into AMD `require([])`.
+pub(super) fn transform_factory_body<'a>(
+    program: &mut Program<'a>,
+    options: &ModulesOptions,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+    dynamic_import: bool,
+) -> FactoryBody<'a> {
+    let scope_id = create_module_body_scope(program, ctx);
+
+    let mut dependencies = Vec::<DependencyInfo<'a>>::new();
+    let mut dependency_indexes = FxHashMap::<Atom<'a>, usize>::default();
+    let mut get_dependency = |source: Atom<'a>, dependencies: &mut Vec<DependencyInfo<'a>>| {
+        *dependency_indexes.entry(source).or_insert_with(|| {
+            dependencies.push(DependencyInfo {
+                source,
+                binding: None,
+                import_default: false,
+                import_namespace: false,
+            });
+            dependencies.len() - 1
+        })
+    };
+
+    // Imports
+    let mut imports = FxHashMap::<SymbolId, ImportBinding<'a>>::default();
+    for stmt in &program.body {
+        let Statement::ImportDeclaration(decl) = stmt else { continue };
+        let index = get_dependency(decl.source.value, &mut dependencies);
+        let dependency = &mut dependencies[index];
+        if let Some(specifiers) = &decl.specifiers {
+            // Runtime helpers are used as is, e.g. `_defineProperty(...)`
+            if let [ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier)] =
+                specifiers.as_slice()
+                && dependency.binding.is_none()
+                && transform_ctx.helper_loader.is_helper_source(&decl.source.value)
+            {
+                let symbol_id = specifier.local.symbol_id();
+                *ctx.scoping_mut().symbol_flags_mut(symbol_id) =
+                    SymbolFlags::FunctionScopedVariable;
+                dependency.binding = Some(BoundIdentifier::from_binding_ident(&specifier.local));
+                continue;
+            }
+            for specifier in specifiers {
+                let imported = match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        let imported = specifier.imported.name();
+                        dependency.import_default |= imported == "default";
+                        Some(imported)
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+                        dependency.import_default = true;
+                        Some(Atom::from("default"))
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => {
+                        dependency.import_namespace = true;
+                        None
+                    }
+                };
+                let local = specifier.local();
+                imports.insert(local.symbol_id(), (index, imported));
+                ctx.scoping_mut().remove_binding(scope_id, &local.name);
+            }
+        }
+    }
+
+    // Exports
+    let mut has_exports = false;
+    let mut has_default_expression = false;
+    let mut local_exports = FxHashMap::<SymbolId, Vec<Atom<'a>>>::default();
+    let mut hoisted_exports = Vec::<(Atom<'a>, BoundIdentifier<'a>)>::new();
+    let mut initialized_exports = Vec::<Atom<'a>>::new();
+    let mut re_exports = Vec::<ReExport<'a>>::new();
+    let mut add_local_export =
+        |ident: &BindingIdentifier<'a>, exported: Atom<'a>, ctx: &TraverseCtx<'a>| {
+            let symbol_id = ident.symbol_id();
+            if ctx.scoping().symbol_flags(symbol_id).is_function() {
+                hoisted_exports.push((exported, BoundIdentifier::from_binding_ident(ident)));
+            } else {
+                initialized_exports.push(exported);
+            }
+            local_exports.entry(symbol_id).or_default().push(exported);
+        };
+    for stmt in &mut program.body {
+        match stmt {
+            Statement::ExportNamedDeclaration(decl) => {
+                has_exports = true;
+                if let Some(source) = &decl.source {
+                    let index = get_dependency(source.value, &mut dependencies);
+                    for specifier in &decl.specifiers {
+                        let imported = specifier.local.name();
+                        dependencies[index].import_default |= imported == "default";
+                        re_exports.push(ReExport {
+                            dependency: index,
+                            imported: Some(imported),
+                            exported: Some(specifier.exported.name()),
+                        });
+                    }
+                } else if let Some(declaration) = &decl.declaration {
+                    match declaration {
+                        Declaration::VariableDeclaration(var_decl) => {
+                            for declarator in &var_decl.declarations {
+                                for ident in declarator.id.get_binding_identifiers() {
+                                    add_local_export(ident, ident.name, ctx);
+                                }
+                            }
+                        }
+                        Declaration::FunctionDeclaration(func) => {
+                            let ident = func.id.as_ref().unwrap();
+                            add_local_export(ident, ident.name, ctx);
+                        }
+                        Declaration::ClassDeclaration(class) => {
+                            let ident = class.id.as_ref().unwrap();
+                            add_local_export(ident, ident.name, ctx);
+                        }
+                        _ => {}
+                    }
+                } else {
+                    for specifier in &decl.specifiers {
+                        let ModuleExportName::IdentifierReference(local) = &specifier.local else {
+                            continue;
+                        };
+                        let symbol_id =
+                            ctx.scoping().get_reference(local.reference_id()).symbol_id();
+                        // The specifier is removed along with the export declaration
+                        ctx.delete_reference_for_identifier(local);
+                        let Some(symbol_id) = symbol_id else { continue };
+                        let exported = specifier.exported.name();
+                        if let Some((dependency, imported)) = imports.get(&symbol_id) {
+                            re_exports.push(ReExport {
+                                dependency: *dependency,
+                                imported: *imported,
+                                exported: Some(exported),
+                            });
+                        } else {
+                            let name = ctx.scoping().symbol_name(symbol_id);
+                            let ident = ctx.ast.binding_identifier_with_symbol_id(
+                                SPAN,
+                                ctx.ast.atom(name),
+                                symbol_id,
+                            );
+                            add_local_export(&ident, exported, ctx);
+                        }
+                    }
+                }
+            }
+            Statement::ExportDefaultDeclaration(decl) => {
+                has_exports = true;
+                let exported = Atom::from("default");
+                match &mut decl.declaration {
+                    ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                        if func.id.is_none() {
+                            let binding =
+                                generate_default_binding(scope_id, SymbolFlags::Function, ctx);
+                            func.id = Some(binding.create_binding_identifier(ctx));
+                        }
+                        add_local_export(func.id.as_ref().unwrap(), exported, ctx);
+                    }
+                    ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                        if class.id.is_none() {
+                            let binding =
+                                generate_default_binding(scope_id, SymbolFlags::Class, ctx);
+                            class.id = Some(binding.create_binding_identifier(ctx));
+                        }
+                        add_local_export(class.id.as_ref().unwrap(), exported, ctx);
+                    }
+                    ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => {}
+                    _ => has_default_expression = true,
+                }
+            }
+            Statement::ExportAllDeclaration(decl) => {
+                has_exports = true;
+                let index = get_dependency(decl.source.value, &mut dependencies);
+                let exported = decl.exported.as_ref().map(ModuleExportName::name);
+                dependencies[index].import_namespace |= exported.is_some();
+                re_exports.push(ReExport { dependency: index, imported: None, exported });
+            }
+            _ => {}
+        }
+    }
+
+    if has_default_expression {
+        initialized_exports.push(Atom::from("default"));
+    }
+
+    let exports = has_exports
+        .then(|| ctx.generate_uid("exports", scope_id, SymbolFlags::FunctionScopedVariable));
+    for dependency in &mut dependencies {
+        if dependency.binding.is_none() {
+            let name = to_identifier(file_stem(&dependency.source));
+            dependency.binding =
+                Some(ctx.generate_uid(&name, scope_id, SymbolFlags::FunctionScopedVariable));
+        }
+    }
+    let bindings = dependencies.iter().map(|dep| dep.binding.clone().unwrap()).collect::<Vec<_>>();
+
+    // Header
+    let mut body = ctx.ast.vec();
+    if let Some(exports) = &exports {
+        // `Object.defineProperty(_exports, "__esModule", { value: true });`
+        let descriptor = ctx.ast.expression_object(
+            SPAN,
+            ctx.ast.vec1(create_object_property(
+                "value",
+                ctx.ast.expression_boolean_literal(SPAN, true),
+                ctx,
+            )),
+        );
+        let call = create_call(
+            create_object_method("defineProperty", ctx),
+            [
+                exports.create_read_expression(ctx),
+                ctx.ast.expression_string_literal(SPAN, "__esModule", None),
+                descriptor,
+            ],
+            ctx,
+        );
+        body.push(ctx.ast.statement_expression(SPAN, call));
+
+        // `_exports.a = _exports.b = void 0;`
+        let mut names = initialized_exports;
+        names.dedup();
+        if !names.is_empty() {
+            let mut value = ctx.ast.void_0(SPAN);
+            for name in names.into_iter().rev() {
+                value =
+                    create_member_assignment(exports.create_read_expression(ctx), name, value, ctx);
+            }
+            body.push(ctx.ast.statement_expression(SPAN, value));
+        }
+
+        // `_exports.f = f;`
+        for (exported, local) in hoisted_exports {
+            let value = local.create_read_expression(ctx);
+            let assignment =
+                create_member_assignment(exports.create_read_expression(ctx), exported, value, ctx);
+            body.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+    }
+
+    // `_foo = _interopRequireDefault(_foo);`
+    for (dependency, binding) in dependencies.iter().zip(&bindings) {
+        if let Some(helper) = dependency.interop() {
+            let arguments = ctx.ast.vec1(Argument::from(binding.create_read_expression(ctx)));
+            let value = transform_ctx.helper_call_expr(helper, SPAN, arguments, ctx);
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                binding.create_write_target(ctx),
+                value,
+            );
+            body.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+    }
+
+    if let Some(exports) = &exports {
+        for re_export in re_exports {
+            let binding = &bindings[re_export.dependency];
+            let stmt = match (re_export.imported, re_export.exported) {
+                (Some(imported), Some(exported)) => {
+                    let value = create_member(binding.create_read_expression(ctx), imported, ctx);
+                    create_export_getter(exports, exported, value, scope_id, ctx)
+                }
+                (None, Some(exported)) => {
+                    let value = binding.create_read_expression(ctx);
+                    let assignment = create_member_assignment(
+                        exports.create_read_expression(ctx),
+                        exported,
+                        value,
+                        ctx,
+                    );
+                    ctx.ast.statement_expression(SPAN, assignment)
+                }
+                (_, None) => create_export_star(exports, binding, scope_id, ctx),
+            };
+            body.push(stmt);
+        }
+    }
+
+    // Body
+    let mut visitor = FactoryBodyVisitor {
+        imports,
+        local_exports,
+        bindings,
+        exports: exports.clone(),
+        require: None,
+        temps: vec![],
+        scope_stack: vec![scope_id],
+        function_depth: 0,
+        allow_top_level_this: options.allow_top_level_this,
+        dynamic_import,
+        transform_ctx,
+        ctx,
+    };
+    let header_len = body.len();
+    for stmt in program.body.take_in(visitor.ctx.ast) {
+        let mut stmt = match stmt {
+            Statement::ImportDeclaration(_) | Statement::ExportAllDeclaration(_) => continue,
+            Statement::ExportNamedDeclaration(decl) => {
+                let Some(declaration) = decl.unbox().declaration else { continue };
+                Statement::from(declaration)
+            }
+            Statement::ExportDefaultDeclaration(decl) => match decl.unbox().declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                    Statement::FunctionDeclaration(func)
+                }
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                    Statement::ClassDeclaration(class)
+                }
+                ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => continue,
+                declaration => {
+                    let mut value = declaration.into_expression();
+                    visitor.visit_expression(&mut value);
+                    let exports = visitor.exports.as_ref().unwrap();
+                    let assignment = create_member_assignment(
+                        exports.create_read_expression(visitor.ctx),
+                        Atom::from("default"),
+                        value,
+                        visitor.ctx,
+                    );
+                    body.push(visitor.ctx.ast.statement_expression(SPAN, assignment));
+                    continue;
+                }
+            },
+            stmt => stmt,
+        };
+        visitor.visit_statement(&mut stmt);
+        let assignments = visitor.create_export_assignments(&stmt);
+        body.push(stmt);
+        body.extend(assignments);
+    }
+
+    if !visitor.temps.is_empty() {
+        let temps = visitor
+            .temps
+            .iter()
+            .map(|temp| (temp.create_binding_pattern(visitor.ctx), None))
+            .collect::<Vec<_>>();
+        let declaration = create_var_declaration(temps, visitor.ctx);
+        body.insert(header_len, declaration);
+    }
+
+    let require = visitor.require;
+    program.body = body;
+
+    let dependencies = dependencies
+        .into_iter()
+        .map(|dependency| Dependency {
+            source: dependency.source,
+            binding: dependency.binding.unwrap(),
+        })
+        .collect();
+    FactoryBody { scope_id, exports, require, dependencies }
+}
+
+/// `Object.defineProperty(_exports, "name", { enumerable: true, get: function() { return value; } });`
+fn create_export_getter<'a>(
+    exports: &BoundIdentifier<'a>,
+    name: Atom<'a>,
+    value: Expression<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let name = ctx.ast.expression_string_literal(SPAN, name, None);
+    let call = create_define_getter(exports, name, value, parent_scope_id, ctx);
+    ctx.ast.statement_expression(SPAN, call)
+}
+
+fn create_define_getter<'a>(
+    exports: &BoundIdentifier<'a>,
+    name: Expression<'a>,
+    value: Expression<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let getter = create_getter(value, parent_scope_id, ctx);
+    let descriptor = ctx.ast.expression_object(
+        SPAN,
+        ctx.ast.vec_from_array([
+            create_object_property(
+                "enumerable",
+                ctx.ast.expression_boolean_literal(SPAN, true),
+                ctx,
+            ),
+            create_object_property("get", getter, ctx),
+        ]),
+    );
+    create_call(
+        create_object_method("defineProperty", ctx),
+        [exports.create_read_expression(ctx), name, descriptor],
+        ctx,
+    )
+}
+
+/// ```js
+/// Object.keys(_foo).forEach(function(_key) {
+///   if (_key === "default" || _key === "__esModule" || _key in _exports) return;
+///   Object.defineProperty(_exports, _key, {
+///     enumerable: true,
+///     get: function() { return _foo[_key]; }
+///   });
+/// });
+/// ```
+fn create_export_star<'a>(
+    exports: &BoundIdentifier<'a>,
+    dependency: &BoundIdentifier<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::Function);
+    let key = ctx.generate_uid("key", scope_id, SymbolFlags::FunctionScopedVariable);
+
+    let is_key = |value: &'static str, ctx: &mut TraverseCtx<'a>| {
+        ctx.ast.expression_binary(
+            SPAN,
+            key.create_read_expression(ctx),
+            BinaryOperator::StrictEquality,
+            ctx.ast.expression_string_literal(SPAN, value, None),
+        )
+    };
+    let is_default = is_key("default", ctx);
+    let is_es_module = is_key("__esModule", ctx);
+    let is_exported = ctx.ast.expression_binary(
+        SPAN,
+        key.create_read_expression(ctx),
+        BinaryOperator::In,
+        exports.create_read_expression(ctx),
+    );
+    let test = ctx.ast.expression_logical(
+        SPAN,
+        ctx.ast.expression_logical(SPAN, is_default, LogicalOperator::Or, is_es_module),
+        LogicalOperator::Or,
+        is_exported,
+    );
+    let skip = ctx.ast.statement_if(SPAN, test, ctx.ast.statement_return(SPAN, None), None);
+
+    let value = Expression::from(ctx.ast.member_expression_computed(
+        SPAN,
+        dependency.create_read_expression(ctx),
+        key.create_read_expression(ctx),
+        false,
+    ));
+    let define =
+        create_define_getter(exports, key.create_read_expression(ctx), value, scope_id, ctx);
+    let callback = create_function(
+        [key.create_binding_pattern(ctx)],
+        ctx.ast.vec_from_array([skip, ctx.ast.statement_expression(SPAN, define)]),
+        scope_id,
+        ctx,
+    );
+
+    let keys = create_call(
+        create_object_method("keys", ctx),
+        [dependency.create_read_expression(ctx)],
+        ctx,
+    );
+    let for_each = create_member(keys, Atom::from("forEach"), ctx);
+    ctx.ast.statement_expression(SPAN, create_call(for_each, [callback], ctx))
+}
+
+struct FactoryBodyVisitor<'a, 'ctx, 'c> {
+    imports: FxHashMap<SymbolId, ImportBinding<'a>>,
+    local_exports: FxHashMap<SymbolId, Vec<Atom<'a>>>,
+    /// Bindings of dependencies
+    bindings: Vec<BoundIdentifier<'a>>,
+    exports: Option<BoundIdentifier<'a>>,
+    require: Option<BoundIdentifier<'a>>,
+    /// Temporary variables, declared at the top of the factory body.
+    temps: Vec<BoundIdentifier<'a>>,
+    scope_stack: Vec<ScopeId>,
+    /// Depth of non-arrow functions and classes, for replacing top-level `this`.
+    function_depth: usize,
+    allow_top_level_this: bool,
+    dynamic_import: bool,
+    transform_ctx: &'ctx TransformCtx<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> FactoryBodyVisitor<'a, '_, '_> {
+    fn import_binding(&self, ident: &IdentifierReference<'a>) -> Option<ImportBinding<'a>> {
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        self.imports.get(&symbol_id).copied()
+    }
+
+    fn exported_names(&self, ident: &IdentifierReference<'a>) -> Option<&Vec<Atom<'a>>> {
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        self.local_exports.get(&symbol_id)
+    }
+
+    /// `foo` -> `_foo.default`, `ns` -> `_foo`
+    fn create_import_reference(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+        (dependency, imported): ImportBinding<'a>,
+    ) -> Expression<'a> {
+        self.ctx.delete_reference_for_identifier(ident);
+        let object = self.bindings[dependency].create_spanned_read_expression(ident.span, self.ctx);
+        match imported {
+            Some(imported) => create_member(object, imported, self.ctx),
+            None => object,
+        }
+    }
+
+    /// `foo()` -> `(0, _foo.default)()`
+    fn transform_callee(&mut self, callee: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = callee
+            && let Some(binding @ (_, Some(_))) = self.import_binding(ident)
+        {
+            let member = self.create_import_reference(ident, binding);
+            let zero =
+                self.ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal);
+            *callee =
+                self.ctx.ast.expression_sequence(SPAN, self.ctx.ast.vec_from_array([zero, member]));
+        }
+    }
+
+    /// `_exports.b = _exports.a = value`
+    fn wrap_with_exports(
+        &mut self,
+        names: &[Atom<'a>],
+        mut value: Expression<'a>,
+    ) -> Expression<'a> {
+        let exports = self.exports.as_ref().unwrap();
+        for name in names.iter().rev() {
+            value = create_member_assignment(
+                exports.create_read_expression(self.ctx),
+                *name,
+                value,
+                self.ctx,
+            );
+        }
+        value
+    }
+
+    /// `a = 1` -> `_exports.a = a = 1`
+    /// `++a` -> `_exports.a = ++a`
+    /// `a++` -> `(_a = a++, _exports.a = a, _a)`
+    fn transform_exported_assignment(&mut self, expr: &mut Expression<'a>) {
+        let ident = match expr {
+            Expression::AssignmentExpression(assignment) => match &assignment.left {
+                AssignmentTarget::AssignmentTargetIdentifier(ident) => ident,
+                _ => return,
+            },
+            Expression::UpdateExpression(update) => match &update.argument {
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => ident,
+                _ => return,
+            },
+            _ => return,
+        };
+        let Some(names) = self.exported_names(ident).cloned() else { return };
+        let ident = BoundIdentifier::new(
+            ident.name,
+            self.ctx.scoping().get_reference(ident.reference_id()).symbol_id().unwrap(),
+        );
+
+        if let Expression::UpdateExpression(update) = expr
+            && !update.prefix
+        {
+            let temp = self.ctx.generate_uid(
+                &ident.name,
+                self.scope_stack[0],
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let span = update.span;
+            let update = expr.take_in(self.ctx.ast);
+            let save = self.ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                temp.create_write_target(self.ctx),
+                update,
+            );
+            let value = ident.create_read_expression(self.ctx);
+            let export = self.wrap_with_exports(&names, value);
+            let result = temp.create_read_expression(self.ctx);
+            *expr = self
+                .ctx
+                .ast
+                .expression_sequence(span, self.ctx.ast.vec_from_array([save, export, result]));
+            self.temps.push(temp);
+        } else {
+            let value = expr.take_in(self.ctx.ast);
+            *expr = self.wrap_with_exports(&names, value);
+        }
+    }
+
+    /// `import(source)` -> `new Promise((_resolve, _reject) => _require([source], (_imported) => _resolve(_interopRequireWildcard(_imported)), _reject))`
+    fn transform_dynamic_import(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ImportExpression(import) = expr.take_in(self.ctx.ast) else {
+            unreachable!()
+        };
+        let import = import.unbox();
+        let ctx = &mut *self.ctx;
+
+        let source = match import.source {
+            source @ Expression::StringLiteral(_) => source,
+            source => {
+                // `${source}`
+                let empty =
+                    TemplateElementValue { raw: Atom::from(""), cooked: Some(Atom::from("")) };
+                let quasis = ctx.ast.vec_from_array([
+                    ctx.ast.template_element(SPAN, empty.clone(), false),
+                    ctx.ast.template_element(SPAN, empty, true),
+                ]);
+                ctx.ast.expression_template_literal(SPAN, quasis, ctx.ast.vec1(source))
+            }
+        };
+
+        let module_scope_id = self.scope_stack[0];
+        let require = self.require.get_or_insert_with(|| {
+            ctx.generate_uid("require", module_scope_id, SymbolFlags::FunctionScopedVariable)
+        });
+        let require = require.create_read_expression(ctx);
+
+        let parent_scope_id = *self.scope_stack.last().unwrap();
+        let outer_scope_id =
+            ctx.create_child_scope(parent_scope_id, ScopeFlags::Function | ScopeFlags::Arrow);
+        let resolve =
+            ctx.generate_uid("resolve", outer_scope_id, SymbolFlags::FunctionScopedVariable);
+        let reject =
+            ctx.generate_uid("reject", outer_scope_id, SymbolFlags::FunctionScopedVariable);
+        let inner_scope_id =
+            ctx.create_child_scope(outer_scope_id, ScopeFlags::Function | ScopeFlags::Arrow);
+        let imported =
+            ctx.generate_uid("imported", inner_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        let arguments = ctx.ast.vec1(Argument::from(imported.create_read_expression(ctx)));
+        let interop = self.transform_ctx.helper_call_expr(
+            Helper::InteropRequireWildcard,
+            SPAN,
+            arguments,
+            ctx,
+        );
+        let resolved = create_call(resolve.create_read_expression(ctx), [interop], ctx);
+        let on_load = create_arrow_function(
+            [imported.create_binding_pattern(ctx)],
+            resolved,
+            inner_scope_id,
+            ctx,
+        );
+
+        let sources =
+            ctx.ast.expression_array(SPAN, ctx.ast.vec1(ArrayExpressionElement::from(source)));
+        let load =
+            create_call(require, [sources, on_load, reject.create_read_expression(ctx)], ctx);
+        let executor = create_arrow_function(
+            [resolve.create_binding_pattern(ctx), reject.create_binding_pattern(ctx)],
+            load,
+            outer_scope_id,
+            ctx,
+        );
+
+        let promise =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Promise"), ReferenceFlags::Read);
+        *expr = ctx.ast.expression_new(
+            import.span,
+            promise,
+            NONE,
+            ctx.ast.vec1(Argument::from(executor)),
+        );
+    }
+
+    /// `_exports.a = a;` for exported bindings declared by `stmt`.
+    fn create_export_assignments(&mut self, stmt: &Statement<'a>) -> Vec<Statement<'a>> {
+        let idents = match stmt {
+            Statement::VariableDeclaration(decl) => decl
+                .declarations
+                .iter()
+                .flat_map(|declarator| declarator.id.get_binding_identifiers())
+                .collect::<Vec<_>>(),
+            Statement::ClassDeclaration(class) => class.id.iter().collect(),
+            _ => return vec![],
+        };
+        let mut assignments = vec![];
+        for ident in idents {
+            let Some(names) = self.local_exports.get(&ident.symbol_id()) else { continue };
+            let names = names.clone();
+            let value = BoundIdentifier::from_binding_ident(ident).create_read_expression(self.ctx);
+            let assignment = self.wrap_with_exports(&names, value);
+            assignments.push(self.ctx.ast.statement_expression(SPAN, assignment));
+        }
+        assignments
+    }
+}
+
+impl<'a> VisitMut<'a> for FactoryBodyVisitor<'a, '_, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.scope_stack.push(scope_id.get().unwrap());
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    fn visit_function(&mut self, it: &mut Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        walk_mut::walk_function(self, it, flags);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class(&mut self, it: &mut Class<'a>) {
+        self.function_depth += 1;
+        walk_mut::walk_class(self, it);
+        self.function_depth -= 1;
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::Identifier(ident) => {
+                if let Some(binding) = self.import_binding(ident) {
+                    *expr = self.create_import_reference(ident, binding);
+                }
+                return;
+            }
+            Expression::ThisExpression(this)
+                if self.function_depth == 0 && !self.allow_top_level_this =>
+            {
+                *expr = self.ctx.ast.void_0(this.span);
+                return;
+            }
+            Expression::CallExpression(call) => self.transform_callee(&mut call.callee),
+            Expression::TaggedTemplateExpression(tagged) => self.transform_callee(&mut tagged.tag),
+            _ => {}
+        }
+
+        walk_mut::walk_expression(self, expr);
+
+        match expr {
+            Expression::AssignmentExpression(_) | Expression::UpdateExpression(_) => {
+                self.transform_exported_assignment(expr);
+            }
+            Expression::ImportExpression(_) if self.dynamic_import => {
+                self.transform_dynamic_import(expr);
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_object_property(&mut self, it: &mut ObjectProperty<'a>) {
+        walk_mut::walk_object_property(self, it);
+        if it.shorthand && !matches!(it.value, Expression::Identifier(_)) {
+            it.shorthand = false;
+        }
+    }
+
+    fn visit_jsx_element_name(&mut self, it: &mut JSXElementName<'a>) {
+        if let JSXElementName::IdentifierReference(ident) = it
+            && let Some(binding) = self.import_binding(ident)
+        {
+            let Some(replacement) = self.create_jsx_import_reference(ident, binding) else {
+                return;
+            };
+            *it = match replacement {
+                JSXMemberExpressionObject::IdentifierReference(ident) => {
+                    JSXElementName::IdentifierReference(ident)
+                }
+                JSXMemberExpressionObject::MemberExpression(member) => {
+                    JSXElementName::MemberExpression(member)
+                }
+                JSXMemberExpressionObject::ThisExpression(_) => unreachable!(),
+            };
+            return;
+        }
+        walk_mut::walk_jsx_element_name(self, it);
+    }
+
+    fn visit_jsx_member_expression_object(&mut self, it: &mut JSXMemberExpressionObject<'a>) {
+        if let JSXMemberExpressionObject::IdentifierReference(ident) = it
+            && let Some(binding) = self.import_binding(ident)
+            && let Some(replacement) = self.create_jsx_import_reference(ident, binding)
+        {
+            *it = replacement;
+            return;
+        }
+        walk_mut::walk_jsx_member_expression_object(self, it);
+    }
+}
+
+impl<'a> FactoryBodyVisitor<'a, '_, '_> {
+    /// `<Foo />` -> `<_foo.default />`
+    fn create_jsx_import_reference(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+        (dependency, imported): ImportBinding<'a>,
+    ) -> Option<JSXMemberExpressionObject<'a>> {
+        if imported.is_some_and(|imported| !oxc_syntax::identifier::is_identifier_name(&imported)) {
+            return None;
+        }
+        self.ctx.delete_reference_for_identifier(ident);
+        let binding = &self.bindings[dependency];
+        let object = self.ctx.ast.alloc(self.ctx.create_bound_ident_reference(
+            ident.span,
+            binding.name,
+            binding.symbol_id,
+            ReferenceFlags::Read,
+        ));
+        let object = JSXMemberExpressionObject::IdentifierReference(object);
+        Some(match imported {
+            Some(imported) => {
+                let property = self.ctx.ast.jsx_identifier(SPAN, imported);
+                JSXMemberExpressionObject::MemberExpression(
+                    self.ctx.ast.alloc_jsx_member_expression(SPAN, object, property),
+                )
+            }
+            None => object,
+        })
+    }
+}
+
+/// `(params) => value`
+fn create_arrow_function<'a>(
+    params: impl IntoIterator<Item = BindingPattern<'a>>,
+    value: Expression<'a>,
+    scope_id: ScopeId,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let params = ctx.ast.formal_parameters(
+        SPAN,
+        FormalParameterKind::ArrowFormalParameters,
+        ctx.ast.vec_from_iter(
+            params.into_iter().map(|pattern| ctx.ast.plain_formal_parameter(SPAN, pattern)),
+        ),
+        NONE,
+    );
+    let body = ctx.ast.function_body(
+        SPAN,
+        ctx.ast.vec(),
+        ctx.ast.vec1(ctx.ast.statement_expression(SPAN, value)),
+    );
+    ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+        SPAN, true, false, NONE, params, NONE, body, scope_id, false, false,
+    )
+}
@@ -0,0 +1,547 @@
+//! Module transforms: AMD, UMD and SystemJS
+//!
+//! Transforms ES modules into AMD, UMD or SystemJS modules, depending on [`Module`].
+//!
+//! * AMD: <https://babel.dev/docs/babel-plugin-transform-modules-amd>
+//! * UMD: <https://babel.dev/docs/babel-plugin-transform-modules-umd>
+//! * SystemJS: <https://babel.dev/docs/babel-plugin-transform-modules-systemjs>
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo from "foo";
+//! export const bar = foo();
+//! ```
+//!
+//! AMD output:
+//! ```js
+//! define(["exports", "foo"], function(_exports, _foo) {
+//!   "use strict";
+//!   Object.defineProperty(_exports, "__esModule", { value: true });
+//!   _exports.bar = void 0;
+//!   _foo = babelHelpers.interopRequireDefault(_foo);
+//!   const bar = (0, _foo.default)();
+//!   _exports.bar = bar;
+//! });
+//! ```
+//!
+//! SystemJS output:
+//! ```js
+//! System.register(["foo"], function(_export, _context) {
+//!   "use strict";
+//!   var foo, bar;
+//!   return {
+//!     setters: [function(_foo) {
+//!       foo = _foo.default;
+//!     }],
+//!     execute: function() {
+//!       _export("bar", bar = foo());
+//!     }
+//!   };
+//! });
+//! ```
+//!
+//! ## Implementation
+//!
+//! Runs after all other transforms, so that imports of helpers added by other transforms
+//! become dependencies of the module too.
+//!
+//! AMD and UMD modules share the same module body, which imports dependencies as parameters of
+//! the factory function and assigns exports to the `exports` object, see [`factory`].
+//! SystemJS modules are transformed by [`systemjs`].
+//!
+//! Not supported:
+//! * Assignments to exported bindings inside destructuring assignments in expression position,
+//!   and `for in` / `for of` loops. Exports are not updated for them.
+//! * Exported `var`s declared in nested blocks (AMD and UMD only).
+//! * `export *` doesn't exclude names which are exported by more than one `export *`.
+
+mod amd;
+mod factory;
+mod options;
+mod systemjs;
+mod umd;
+
+use std::{cell::Cell, path::Path};
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::identifier::is_identifier_name;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    Module,
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+pub use options::ModulesOptions;
+
+pub struct Modules<'a, 'ctx> {
+    module: Module,
+    options: ModulesOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Modules<'a, 'ctx> {
+    pub fn new(
+        module: Module,
+        options: ModulesOptions,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Option<Self> {
+        module.is_wrapped().then_some(Self { module, options, ctx })
+    }
+
+    /// Load interop helpers which will be used by the transform.
+    ///
+    /// Must be called before imports added by other transforms are inserted into the program,
+    /// so that imports of the helpers are inserted too.
+    pub fn load_helpers(&self, program: &Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !program.source_type.is_module() || self.module == Module::SystemJs {
+            return;
+        }
+
+        // Same as `DependencyInfo::interop` in `factory.rs`:
+        // namespace imports need `interopRequireWildcard`, default imports `interopRequireDefault`.
+        let mut interops = FxHashMap::<&str, (bool, bool)>::default();
+        for stmt in &program.body {
+            match stmt {
+                Statement::ImportDeclaration(decl) => {
+                    let Some(specifiers) = &decl.specifiers else { continue };
+                    if let [ImportDeclarationSpecifier::ImportDefaultSpecifier(_)] =
+                        specifiers.as_slice()
+                        && self.ctx.helper_loader.is_helper_source(&decl.source.value)
+                    {
+                        continue;
+                    }
+                    let (default, namespace) = interops.entry(&decl.source.value).or_default();
+                    for specifier in specifiers {
+                        match specifier {
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+                                *default = true;
+                            }
+                            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                                *default |= specifier.imported.name() == "default";
+                            }
+                            ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => {
+                                *namespace = true;
+                            }
+                        }
+                    }
+                }
+                Statement::ExportNamedDeclaration(decl) => {
+                    let Some(source) = &decl.source else { continue };
+                    let (default, _) = interops.entry(&source.value).or_default();
+                    *default |=
+                        decl.specifiers.iter().any(|specifier| specifier.local.name() == "default");
+                }
+                Statement::ExportAllDeclaration(decl) => {
+                    let (_, namespace) = interops.entry(&decl.source.value).or_default();
+                    *namespace |= decl.exported.is_some();
+                }
+                _ => {}
+            }
+        }
+
+        let interop_default = interops.values().any(|&(default, namespace)| default && !namespace);
+        let mut interop_wildcard = interops.values().any(|&(_, namespace)| namespace);
+        if self.module == Module::Amd && !interop_wildcard {
+            let mut finder = DynamicImportFinder { found: false };
+            finder.visit_program(program);
+            interop_wildcard = finder.found;
+        }
+
+        if interop_default {
+            self.preload_helper(Helper::InteropRequireDefault, ctx);
+        }
+        if interop_wildcard {
+            self.preload_helper(Helper::InteropRequireWildcard, ctx);
+        }
+    }
+
+    /// Load a helper without using it.
+    fn preload_helper(&self, helper: Helper, ctx: &mut TraverseCtx<'a>) {
+        match self.ctx.helper_load(helper, ctx) {
+            Expression::Identifier(ident) => ctx.delete_reference_for_identifier(&ident),
+            Expression::StaticMemberExpression(member) => {
+                if let Expression::Identifier(ident) = &member.object {
+                    ctx.delete_reference_for_identifier(ident);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Modules<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !program.source_type.is_module() {
+            return;
+        }
+        match self.module {
+            Module::Amd => amd::transform_program(program, &self.options, self.ctx, ctx),
+            Module::Umd => umd::transform_program(program, &self.options, self.ctx, ctx),
+            Module::SystemJs => systemjs::transform_program(program, &self.options, ctx),
+            _ => unreachable!(),
+        }
+    }
+}
+
+struct DynamicImportFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for DynamicImportFinder {
+    fn visit_import_expression(&mut self, _it: &ImportExpression<'a>) {
+        self.found = true;
+    }
+}
+
+/// Collects scopes which are direct children of the visited nodes' scope.
+#[derive(Default)]
+struct ChildScopeCollector {
+    scope_ids: Vec<ScopeId>,
+    depth: usize,
+}
+
+impl ChildScopeCollector {
+    fn collect(stmts: &[Statement<'_>]) -> Vec<ScopeId> {
+        let mut collector = Self::default();
+        for stmt in stmts {
+            collector.visit_statement(stmt);
+        }
+        collector.scope_ids
+    }
+}
+
+impl Visit<'_> for ChildScopeCollector {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            self.scope_ids.push(scope_id.get().unwrap());
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+/// Create a function scope for the module body, which contains all bindings of the root scope.
+///
+/// All scopes of the module body become children of the new scope.
+fn create_module_body_scope<'a>(program: &Program<'a>, ctx: &mut TraverseCtx<'a>) -> ScopeId {
+    let scope_id = ctx.insert_scope_below_statements(&program.body, ScopeFlags::Function);
+    let root_scope_id = ctx.scoping().root_scope_id();
+    let names = ctx
+        .scoping()
+        .get_bindings(root_scope_id)
+        .iter()
+        .map(|(name, symbol_id)| (ctx.ast.atom(name), *symbol_id))
+        .collect::<Vec<_>>();
+    for (name, symbol_id) in names {
+        ctx.scoping_mut().set_symbol_scope_id(symbol_id, scope_id);
+        ctx.scoping_mut().move_binding(root_scope_id, scope_id, &name);
+    }
+    scope_id
+}
+
+/// Move `stmts`' child scopes to be children of `scope_id`.
+fn reparent_scopes(stmts: &[Statement<'_>], scope_id: ScopeId, ctx: &mut TraverseCtx<'_>) {
+    for child_id in ChildScopeCollector::collect(stmts) {
+        ctx.scoping_mut().change_scope_parent_id(child_id, Some(scope_id));
+    }
+}
+
+/// Convert `name` into a valid identifier, same as Babel's `toIdentifier`.
+///
+/// `lodash-es` -> `lodashEs`, `./foo/bar.js` -> `bar`, `1abc` -> `_1abc`
+fn to_identifier(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut upper_next = false;
+    for c in name.chars() {
+        if oxc_syntax::identifier::is_identifier_part(c) {
+            if upper_next && !result.is_empty() {
+                result.extend(c.to_uppercase());
+            } else {
+                result.push(c);
+            }
+            upper_next = false;
+        } else {
+            upper_next = true;
+        }
+    }
+    if result.is_empty() || !is_identifier_name(&result) {
+        result.insert(0, '_');
+    }
+    result
+}
+
+/// File name of a path without extension, e.g. `./foo/bar.js` -> `bar`.
+fn file_stem(path: &str) -> &str {
+    let path = Path::new(path);
+    path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default()
+}
+
+/// `object.name` or `object["name"]`
+fn create_member<'a>(
+    object: Expression<'a>,
+    name: Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    Expression::from(create_member_expression(object, name, ctx))
+}
+
+/// `object.name = value`
+fn create_member_assignment<'a>(
+    object: Expression<'a>,
+    name: Atom<'a>,
+    value: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let target = AssignmentTarget::from(create_member_expression(object, name, ctx));
+    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+}
+
+fn create_member_expression<'a>(
+    object: Expression<'a>,
+    name: Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> MemberExpression<'a> {
+    if is_identifier_name(&name) {
+        ctx.ast.member_expression_static(SPAN, object, ctx.ast.identifier_name(SPAN, name), false)
+    } else {
+        let property = ctx.ast.expression_string_literal(SPAN, name, None);
+        ctx.ast.member_expression_computed(SPAN, object, property, false)
+    }
+}
+
+/// `callee(...arguments)`
+fn create_call<'a>(
+    callee: Expression<'a>,
+    arguments: impl IntoIterator<Item = Expression<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let arguments = ctx.ast.vec_from_iter(arguments.into_iter().map(Argument::from));
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
+/// `Object.<method>`
+fn create_object_method<'a>(method: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let object = ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+    create_member(object, Atom::from(method), ctx)
+}
+
+/// `key: value`
+fn create_object_property<'a>(
+    key: &'a str,
+    value: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    let key = if is_identifier_name(key) {
+        ctx.ast.property_key_static_identifier(SPAN, key)
+    } else {
+        PropertyKey::from(ctx.ast.expression_string_literal(SPAN, key, None))
+    };
+    ctx.ast.object_property_kind_object_property(
+        SPAN,
+        PropertyKind::Init,
+        key,
+        value,
+        false,
+        false,
+        false,
+    )
+}
+
+/// `function(params) { body }`
+fn create_function<'a>(
+    params: impl IntoIterator<Item = BindingPattern<'a>>,
+    body: oxc_allocator::Vec<'a, Statement<'a>>,
+    scope_id: ScopeId,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    create_function_with_directives(params, ctx.ast.vec(), body, scope_id, ctx)
+}
+
+fn create_function_with_directives<'a>(
+    params: impl IntoIterator<Item = BindingPattern<'a>>,
+    directives: oxc_allocator::Vec<'a, Directive<'a>>,
+    body: oxc_allocator::Vec<'a, Statement<'a>>,
+    scope_id: ScopeId,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let params = ctx.ast.formal_parameters(
+        SPAN,
+        FormalParameterKind::FormalParameter,
+        ctx.ast.vec_from_iter(
+            params.into_iter().map(|pattern| ctx.ast.plain_formal_parameter(SPAN, pattern)),
+        ),
+        NONE,
+    );
+    let body = ctx.ast.function_body(SPAN, directives, body);
+    Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+        FunctionType::FunctionExpression,
+        SPAN,
+        None,
+        params,
+        body,
+        scope_id,
+    ))
+}
+
+/// `function() { return value; }`
+fn create_getter<'a>(
+    value: Expression<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::Function);
+    let body = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value)));
+    create_function([], body, scope_id, ctx)
+}
+
+/// `var a, b;`
+fn create_var_declaration<'a>(
+    bindings: impl IntoIterator<Item = (BindingPattern<'a>, Option<Expression<'a>>)>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let kind = VariableDeclarationKind::Var;
+    let declarations = ctx.ast.vec_from_iter(
+        bindings
+            .into_iter()
+            .map(|(id, init)| ctx.ast.variable_declarator(SPAN, kind, id, init, false)),
+    );
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        declarations,
+        false,
+    ))
+}
+
+/// Convert a binding pattern into an assignment target, for converting declarations into
+/// assignments, e.g. `const { a, b: [c] } = obj` -> `({ a, b: [c] } = obj)`.
+///
+/// Symbols of the bindings are kept, with write references to them.
+fn binding_pattern_to_assignment_target<'a>(
+    pattern: BindingPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTarget<'a> {
+    match pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => {
+            AssignmentTarget::from(binding_identifier_to_simple_target(&ident, ctx))
+        }
+        BindingPatternKind::ObjectPattern(object) => {
+            let object = object.unbox();
+            let properties = ctx.ast.vec_from_iter(object.properties.into_iter().map(|property| {
+                let BindingProperty { span, key, value, shorthand, computed } = property;
+                match (shorthand, value.kind) {
+                    (true, BindingPatternKind::BindingIdentifier(ident)) => {
+                        let binding = binding_identifier_to_identifier_reference(&ident, ctx);
+                        ctx.ast.assignment_target_property_assignment_target_property_identifier(
+                            span, binding, None,
+                        )
+                    }
+                    (true, BindingPatternKind::AssignmentPattern(assignment)) => {
+                        let assignment = assignment.unbox();
+                        let BindingPatternKind::BindingIdentifier(ident) = assignment.left.kind
+                        else {
+                            unreachable!()
+                        };
+                        let binding = binding_identifier_to_identifier_reference(&ident, ctx);
+                        ctx.ast.assignment_target_property_assignment_target_property_identifier(
+                            span,
+                            binding,
+                            Some(assignment.right),
+                        )
+                    }
+                    (_, kind) => {
+                        let value = BindingPattern { kind, ..value };
+                        let binding =
+                            binding_pattern_to_assignment_target_maybe_default(value, ctx);
+                        ctx.ast.assignment_target_property_assignment_target_property_property(
+                            span, key, binding, computed,
+                        )
+                    }
+                }
+            }));
+            let rest = object.rest.map(|rest| {
+                let target = binding_pattern_to_assignment_target(rest.unbox().argument, ctx);
+                ctx.ast.assignment_target_rest(SPAN, target)
+            });
+            AssignmentTarget::ObjectAssignmentTarget(ctx.ast.alloc_object_assignment_target(
+                object.span,
+                properties,
+                rest,
+            ))
+        }
+        BindingPatternKind::ArrayPattern(array) => {
+            let array = array.unbox();
+            let elements = ctx.ast.vec_from_iter(array.elements.into_iter().map(|element| {
+                element
+                    .map(|element| binding_pattern_to_assignment_target_maybe_default(element, ctx))
+            }));
+            let rest = array.rest.map(|rest| {
+                let target = binding_pattern_to_assignment_target(rest.unbox().argument, ctx);
+                ctx.ast.assignment_target_rest(SPAN, target)
+            });
+            AssignmentTarget::ArrayAssignmentTarget(
+                ctx.ast.alloc_array_assignment_target(array.span, elements, rest),
+            )
+        }
+        BindingPatternKind::AssignmentPattern(_) => unreachable!(),
+    }
+}
+
+fn binding_pattern_to_assignment_target_maybe_default<'a>(
+    pattern: BindingPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTargetMaybeDefault<'a> {
+    if let BindingPatternKind::AssignmentPattern(assignment) = pattern.kind {
+        let assignment = assignment.unbox();
+        let binding = binding_pattern_to_assignment_target(assignment.left, ctx);
+        ctx.ast.assignment_target_maybe_default_assignment_target_with_default(
+            assignment.span,
+            binding,
+            assignment.right,
+        )
+    } else {
+        AssignmentTargetMaybeDefault::from(binding_pattern_to_assignment_target(pattern, ctx))
+    }
+}
+
+fn binding_identifier_to_identifier_reference<'a>(
+    ident: &BindingIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> IdentifierReference<'a> {
+    ctx.create_bound_ident_reference(
+        ident.span,
+        ident.name,
+        ident.symbol_id(),
+        ReferenceFlags::Write,
+    )
+}
+
+fn binding_identifier_to_simple_target<'a>(
+    ident: &BindingIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> SimpleAssignmentTarget<'a> {
+    let ident = binding_identifier_to_identifier_reference(ident, ctx);
+    SimpleAssignmentTarget::AssignmentTargetIdentifier(ctx.alloc(ident))
+}
+
+/// Add `id` to an anonymous function or class, e.g. `export default function() {}`.
+fn generate_default_binding<'a>(
+    scope_id: ScopeId,
+    flags: SymbolFlags,
+    ctx: &mut TraverseCtx<'a>,
+) -> BoundIdentifier<'a> {
+    ctx.generate_uid("default", scope_id, flags)
+}
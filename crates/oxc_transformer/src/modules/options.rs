@@ -0,0 +1,33 @@
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+/// Options for the AMD, UMD and SystemJS module transforms.
+///
+/// The output format itself is selected by [`crate::Module`].
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ModulesOptions {
+    /// Name of the module, passed to `define` and `System.register`.
+    ///
+    /// Same as Babel's top-level `moduleId` option.
+    pub module_id: Option<String>,
+
+    /// UMD only: name of the global variable which exports are assigned to, when the module is
+    /// loaded without a module loader, e.g. `"MyLib"` or `"MyOrg.MyLib"`.
+    ///
+    /// Defaults to `module_id`, or the file name converted to an identifier.
+    pub export_name: Option<String>,
+
+    /// UMD only: names of the global variables of imported modules, keyed by import source.
+    ///
+    /// Defaults to the file name of the import source converted to an identifier,
+    /// e.g. `global.lodashEs` for `import "lodash-es"`.
+    pub globals: FxHashMap<String, String>,
+
+    /// UMD only: look up `globals` with the full import source instead of its file name,
+    /// and don't convert the global names to identifiers.
+    pub exact_globals: bool,
+
+    /// Keep top-level `this` as is, instead of replacing it with `undefined`.
+    pub allow_top_level_this: bool,
+}
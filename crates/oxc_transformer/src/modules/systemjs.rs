@@ -0,0 +1,876 @@
+//! SystemJS
+//!
+//! ```js
+//! import foo from "foo";
+//! export { bar } from "bar";
+//! export let count = foo();
+//! export function inc() { count++; }
+//! ```
+//! ->
+//! ```js
+//! System.register(["foo", "bar"], function(_export, _context) {
+//!   "use strict";
+//!   var foo, count, _count;
+//!   function inc() { (_count = count++, _export("count", count), _count); }
+//!   _export("inc", inc);
+//!   return {
+//!     setters: [function(_foo) {
+//!       foo = _foo.default;
+//!     }, function(_bar) {
+//!       _export({ bar: _bar.bar });
+//!     }],
+//!     execute: function() {
+//!       _export("count", count = foo());
+//!     }
+//!   };
+//! });
+//! ```
+//!
+//! All module-level bindings are declared in the `System.register` callback, so that setters and
+//! hoisted functions can access them. `import.meta` and `import()` are transformed into
+//! `_context.meta` and `_context.import()`.
+//!
+//! Based on Babel's [transform-modules-systemjs](https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-modules-systemjs/src/index.ts).
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_traverse::BoundIdentifier;
+
+use crate::context::TraverseCtx;
+
+use super::{
+    ModulesOptions, binding_pattern_to_assignment_target, create_call, create_function,
+    create_function_with_directives, create_member, create_module_body_scope,
+    create_object_property, create_var_declaration, file_stem, generate_default_binding,
+    reparent_scopes, to_identifier,
+};
+
+#[derive(Default)]
+struct Dependency<'a> {
+    /// Imported bindings: local binding, and the imported name, or `None` for namespaces.
+    imports: Vec<(BoundIdentifier<'a>, Option<Atom<'a>>)>,
+    /// Re-exports: exported name, and the imported name, or `None` for namespaces.
+    re_exports: Vec<(Atom<'a>, Option<Atom<'a>>)>,
+    /// `export * from "source"`
+    export_star: bool,
+}
+
+pub(super) fn transform_program<'a>(
+    program: &mut Program<'a>,
+    options: &ModulesOptions,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let scope_id = create_module_body_scope(program, ctx);
+
+    // All module-level bindings except functions become `var`s of the `System.register` callback
+    let mut hoisted_symbols = ctx
+        .scoping()
+        .get_bindings(scope_id)
+        .values()
+        .copied()
+        .filter(|&symbol_id| !ctx.scoping().symbol_flags(symbol_id).is_function())
+        .collect::<Vec<_>>();
+    hoisted_symbols.sort_unstable();
+    for &symbol_id in &hoisted_symbols {
+        *ctx.scoping_mut().symbol_flags_mut(symbol_id) = SymbolFlags::FunctionScopedVariable;
+    }
+
+    let export = ctx.generate_uid("export", scope_id, SymbolFlags::FunctionScopedVariable);
+    let context = ctx.generate_uid("context", scope_id, SymbolFlags::FunctionScopedVariable);
+
+    // Imports and re-exports
+    let mut sources = Vec::<Atom<'a>>::new();
+    let mut dependencies = FxHashMap::<Atom<'a>, Dependency<'a>>::default();
+    let mut imports = FxHashMap::<SymbolId, (Atom<'a>, Option<Atom<'a>>)>::default();
+    for stmt in &program.body {
+        let Statement::ImportDeclaration(decl) = stmt else { continue };
+        let dependency = get_dependency(&mut dependencies, &mut sources, decl.source.value);
+        for specifier in decl.specifiers.iter().flatten() {
+            let imported = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    Some(specifier.imported.name())
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+                    Some(Atom::from("default"))
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => None,
+            };
+            let local = specifier.local();
+            dependency.imports.push((BoundIdentifier::from_binding_ident(local), imported));
+            imports.insert(local.symbol_id(), (decl.source.value, imported));
+        }
+    }
+
+    // Exports
+    let mut local_exports = FxHashMap::<SymbolId, Vec<Atom<'a>>>::default();
+    for stmt in &mut program.body {
+        match stmt {
+            Statement::ExportNamedDeclaration(decl) => {
+                if let Some(source) = &decl.source {
+                    let dependency = get_dependency(&mut dependencies, &mut sources, source.value);
+                    for specifier in &decl.specifiers {
+                        dependency
+                            .re_exports
+                            .push((specifier.exported.name(), Some(specifier.local.name())));
+                    }
+                } else if let Some(declaration) = &decl.declaration {
+                    let idents = match declaration {
+                        Declaration::VariableDeclaration(var_decl) => var_decl
+                            .declarations
+                            .iter()
+                            .flat_map(|declarator| declarator.id.get_binding_identifiers())
+                            .collect::<Vec<_>>(),
+                        Declaration::FunctionDeclaration(func) => func.id.iter().collect(),
+                        Declaration::ClassDeclaration(class) => class.id.iter().collect(),
+                        _ => vec![],
+                    };
+                    for ident in idents {
+                        local_exports.entry(ident.symbol_id()).or_default().push(ident.name);
+                    }
+                } else {
+                    for specifier in &decl.specifiers {
+                        let ModuleExportName::IdentifierReference(local) = &specifier.local else {
+                            continue;
+                        };
+                        let symbol_id =
+                            ctx.scoping().get_reference(local.reference_id()).symbol_id();
+                        // The specifier is removed along with the export declaration
+                        ctx.delete_reference_for_identifier(local);
+                        let Some(symbol_id) = symbol_id else { continue };
+                        let exported = specifier.exported.name();
+                        if let Some((source, imported)) = imports.get(&symbol_id) {
+                            get_dependency(&mut dependencies, &mut sources, *source)
+                                .re_exports
+                                .push((exported, *imported));
+                        } else {
+                            local_exports.entry(symbol_id).or_default().push(exported);
+                        }
+                    }
+                }
+            }
+            Statement::ExportDefaultDeclaration(decl) => match &mut decl.declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                    if func.id.is_none() {
+                        let binding =
+                            generate_default_binding(scope_id, SymbolFlags::Function, ctx);
+                        func.id = Some(binding.create_binding_identifier(ctx));
+                    }
+                    let symbol_id = func.id.as_ref().unwrap().symbol_id();
+                    local_exports.entry(symbol_id).or_default().push(Atom::from("default"));
+                }
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                    if let Some(id) = &class.id {
+                        local_exports
+                            .entry(id.symbol_id())
+                            .or_default()
+                            .push(Atom::from("default"));
+                    }
+                }
+                _ => {}
+            },
+            Statement::ExportAllDeclaration(decl) => {
+                let dependency = get_dependency(&mut dependencies, &mut sources, decl.source.value);
+                match &decl.exported {
+                    Some(exported) => dependency.re_exports.push((exported.name(), None)),
+                    None => dependency.export_star = true,
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let execute_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::Function);
+    let mut visitor = SystemJsVisitor {
+        local_exports,
+        export,
+        context,
+        temps: vec![],
+        declare_scope_id: scope_id,
+        this_depth: 0,
+        var_depth: 0,
+        allow_top_level_this: options.allow_top_level_this,
+        ctx,
+    };
+
+    // Split the body into hoisted functions and the `execute` function
+    let mut functions = vec![];
+    let mut execute = visitor.ctx.ast.vec();
+    for stmt in program.body.take_in(visitor.ctx.ast) {
+        let stmt = match stmt {
+            Statement::ImportDeclaration(_) | Statement::ExportAllDeclaration(_) => continue,
+            Statement::ExportNamedDeclaration(decl) => {
+                let Some(declaration) = decl.unbox().declaration else { continue };
+                Statement::from(declaration)
+            }
+            Statement::ExportDefaultDeclaration(decl) => match decl.unbox().declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                    Statement::FunctionDeclaration(func)
+                }
+                ExportDefaultDeclarationKind::ClassDeclaration(class) if class.id.is_some() => {
+                    Statement::ClassDeclaration(class)
+                }
+                ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => continue,
+                declaration => {
+                    let value = match declaration {
+                        ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                            class.r#type = ClassType::ClassExpression;
+                            Expression::ClassExpression(class)
+                        }
+                        declaration => declaration.into_expression(),
+                    };
+                    let call = visitor.create_export_call(Atom::from("default"), value);
+                    visitor.ctx.ast.statement_expression(SPAN, call)
+                }
+            },
+            stmt => stmt,
+        };
+        let mut stmt = match stmt {
+            Statement::FunctionDeclaration(_) => {
+                functions.push(stmt);
+                continue;
+            }
+            // `var a = 1` -> `a = 1`
+            Statement::VariableDeclaration(mut decl) => {
+                let Some(expr) = visitor.transform_variable_declaration(&mut decl) else {
+                    continue;
+                };
+                visitor.ctx.ast.statement_expression(decl.span, expr)
+            }
+            // `class A {}` -> `A = class {}`
+            Statement::ClassDeclaration(mut class) => {
+                let id = class.id.take().unwrap();
+                class.r#type = ClassType::ClassExpression;
+                let target =
+                    BoundIdentifier::from_binding_ident(&id).create_write_target(visitor.ctx);
+                let assignment = visitor.ctx.ast.expression_assignment(
+                    class.span,
+                    AssignmentOperator::Assign,
+                    target,
+                    Expression::ClassExpression(class),
+                );
+                visitor.ctx.ast.statement_expression(SPAN, assignment)
+            }
+            stmt => stmt,
+        };
+        visitor.visit_statement(&mut stmt);
+        execute.push(stmt);
+    }
+    for function in &mut functions {
+        visitor.visit_statement(function);
+    }
+    let is_async = TopLevelAwaitFinder::contains_await(&execute);
+    reparent_scopes(&execute, execute_scope_id, visitor.ctx);
+
+    let SystemJsVisitor { local_exports, export, context, temps, ctx, .. } = visitor;
+
+    // Body of the `System.register` callback
+    let mut body = ctx.ast.vec();
+    let vars = hoisted_symbols
+        .into_iter()
+        .map(|symbol_id| {
+            let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+            let span = ctx.scoping().symbol_span(symbol_id);
+            ctx.ast.binding_identifier_with_symbol_id(span, name, symbol_id)
+        })
+        .chain(temps.iter().map(|temp| temp.create_binding_identifier(ctx)))
+        .map(|ident| {
+            (
+                ctx.ast.binding_pattern(
+                    BindingPatternKind::BindingIdentifier(ctx.alloc(ident)),
+                    NONE,
+                    false,
+                ),
+                None,
+            )
+        })
+        .collect::<Vec<_>>();
+    if !vars.is_empty() {
+        body.push(create_var_declaration(vars, ctx));
+    }
+    let mut function_exports = vec![];
+    for function in &functions {
+        let Statement::FunctionDeclaration(func) = function else { unreachable!() };
+        let ident = func.id.as_ref().unwrap();
+        if let Some(names) = local_exports.get(&ident.symbol_id()) {
+            for name in names {
+                let value = BoundIdentifier::from_binding_ident(ident).create_read_expression(ctx);
+                function_exports.push((*name, value));
+            }
+        }
+    }
+    body.extend(functions);
+
+    // `var _exportNames = { a: true };`, for excluding names exported by this module from `export *`
+    let export_names = if dependencies.values().any(|dependency| dependency.export_star) {
+        let mut names = local_exports.values().flatten().copied().collect::<Vec<_>>();
+        names.extend(
+            dependencies
+                .values()
+                .flat_map(|dependency| &dependency.re_exports)
+                .map(|(name, _)| *name),
+        );
+        names.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+        names.dedup();
+        (!names.is_empty()).then(|| {
+            let binding =
+                ctx.generate_uid("exportNames", scope_id, SymbolFlags::FunctionScopedVariable);
+            let properties = ctx.ast.vec_from_iter(names.into_iter().map(|name| {
+                create_object_property(
+                    name.as_str(),
+                    ctx.ast.expression_boolean_literal(SPAN, true),
+                    ctx,
+                )
+            }));
+            let object = ctx.ast.expression_object(SPAN, properties);
+            body.push(create_var_declaration(
+                [(binding.create_binding_pattern(ctx), Some(object))],
+                ctx,
+            ));
+            binding
+        })
+    } else {
+        None
+    };
+    for (name, value) in function_exports {
+        let call = create_call(
+            export.create_read_expression(ctx),
+            [ctx.ast.expression_string_literal(SPAN, name, None), value],
+            ctx,
+        );
+        body.push(ctx.ast.statement_expression(SPAN, call));
+    }
+
+    // `setters: [function(_foo) { foo = _foo.default; }]`
+    let setters = ctx.ast.vec_from_iter(sources.iter().map(|source| {
+        let dependency = dependencies.remove(source).unwrap();
+        ArrayExpressionElement::from(create_setter(
+            *source,
+            dependency,
+            &export,
+            export_names.as_ref(),
+            scope_id,
+            ctx,
+        ))
+    }));
+    let setters = ctx.ast.expression_array(SPAN, setters);
+
+    // `execute: function() { ... }`
+    let mut execute = create_function(vec![], execute, execute_scope_id, ctx);
+    if let Expression::FunctionExpression(func) = &mut execute {
+        func.r#async = is_async;
+    }
+
+    let module = ctx.ast.expression_object(
+        SPAN,
+        ctx.ast.vec_from_array([
+            create_object_property("setters", setters, ctx),
+            create_object_property("execute", execute, ctx),
+        ]),
+    );
+    body.push(ctx.ast.statement_return(SPAN, Some(module)));
+
+    // `System.register(["foo"], function(_export, _context) { ... })`
+    let mut directives = program.directives.take_in(ctx.ast);
+    if !directives.iter().any(Directive::is_use_strict) {
+        directives.insert(0, ctx.ast.use_strict_directive());
+    }
+    let declare = create_function_with_directives(
+        [export.create_binding_pattern(ctx), context.create_binding_pattern(ctx)],
+        directives,
+        body,
+        scope_id,
+        ctx,
+    );
+    let mut arguments = Vec::with_capacity(3);
+    if let Some(module_id) = &options.module_id {
+        arguments.push(ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(module_id), None));
+    }
+    let sources = ctx.ast.vec_from_iter(sources.into_iter().map(|source| {
+        ArrayExpressionElement::from(ctx.ast.expression_string_literal(SPAN, source, None))
+    }));
+    arguments.push(ctx.ast.expression_array(SPAN, sources));
+    arguments.push(declare);
+
+    let system = ctx.create_unbound_ident_expr(SPAN, Atom::from("System"), ReferenceFlags::Read);
+    let register = create_member(system, Atom::from("register"), ctx);
+    let call = create_call(register, arguments, ctx);
+    program.body.push(ctx.ast.statement_expression(SPAN, call));
+}
+
+fn get_dependency<'a, 'd>(
+    dependencies: &'d mut FxHashMap<Atom<'a>, Dependency<'a>>,
+    sources: &mut Vec<Atom<'a>>,
+    source: Atom<'a>,
+) -> &'d mut Dependency<'a> {
+    dependencies.entry(source).or_insert_with(|| {
+        sources.push(source);
+        Dependency::default()
+    })
+}
+
+/// ```js
+/// function(_foo) {
+///   foo = _foo.default;
+///   var _exportObj = {};
+///   for (var _key in _foo) {
+///     if (_key !== "default" && _key !== "__esModule" && !Object.prototype.hasOwnProperty.call(_exportNames, _key)) _exportObj[_key] = _foo[_key];
+///   }
+///   _exportObj.bar = _foo.bar;
+///   _export(_exportObj);
+/// }
+/// ```
+fn create_setter<'a>(
+    source: Atom<'a>,
+    dependency: Dependency<'a>,
+    export: &BoundIdentifier<'a>,
+    export_names: Option<&BoundIdentifier<'a>>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::Function);
+    let module = ctx.generate_uid(
+        &to_identifier(file_stem(&source)),
+        scope_id,
+        SymbolFlags::FunctionScopedVariable,
+    );
+    let imported_value = |imported: Option<Atom<'a>>, ctx: &mut TraverseCtx<'a>| {
+        let module = module.create_read_expression(ctx);
+        match imported {
+            Some(imported) => create_member(module, imported, ctx),
+            None => module,
+        }
+    };
+
+    let mut body = ctx.ast.vec();
+    for (local, imported) in dependency.imports {
+        let value = imported_value(imported, ctx);
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            local.create_write_target(ctx),
+            value,
+        );
+        body.push(ctx.ast.statement_expression(SPAN, assignment));
+    }
+
+    if dependency.export_star {
+        let export_obj =
+            ctx.generate_uid("exportObj", scope_id, SymbolFlags::FunctionScopedVariable);
+        body.push(create_var_declaration(
+            [(
+                export_obj.create_binding_pattern(ctx),
+                Some(ctx.ast.expression_object(SPAN, ctx.ast.vec())),
+            )],
+            ctx,
+        ));
+
+        // `for (var _key in _foo) { if (_key !== "default" && _key !== "__esModule") _exportObj[_key] = _foo[_key]; }`
+        let for_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+        let block_scope_id = ctx.create_child_scope(for_scope_id, ScopeFlags::empty());
+        let key = ctx.generate_uid("key", scope_id, SymbolFlags::FunctionScopedVariable);
+        let is_not = |value: &'static str, ctx: &mut TraverseCtx<'a>| {
+            ctx.ast.expression_binary(
+                SPAN,
+                key.create_read_expression(ctx),
+                BinaryOperator::StrictInequality,
+                ctx.ast.expression_string_literal(SPAN, value, None),
+            )
+        };
+        let mut test = ctx.ast.expression_logical(
+            SPAN,
+            is_not("default", ctx),
+            LogicalOperator::And,
+            is_not("__esModule", ctx),
+        );
+        if let Some(export_names) = export_names {
+            // `!Object.prototype.hasOwnProperty.call(_exportNames, _key)`
+            let object =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+            let prototype = create_member(object, Atom::from("prototype"), ctx);
+            let has_own_property = create_member(prototype, Atom::from("hasOwnProperty"), ctx);
+            let call = create_member(has_own_property, Atom::from("call"), ctx);
+            let is_exported = create_call(
+                call,
+                [export_names.create_read_expression(ctx), key.create_read_expression(ctx)],
+                ctx,
+            );
+            let is_not_exported =
+                ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, is_exported);
+            test = ctx.ast.expression_logical(SPAN, test, LogicalOperator::And, is_not_exported);
+        }
+        let target = ctx.ast.member_expression_computed(
+            SPAN,
+            export_obj.create_read_expression(ctx),
+            key.create_read_expression(ctx),
+            false,
+        );
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            module.create_read_expression(ctx),
+            key.create_read_expression(ctx),
+            false,
+        ));
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(target),
+            value,
+        );
+        let copy =
+            ctx.ast.statement_if(SPAN, test, ctx.ast.statement_expression(SPAN, assignment), None);
+        let left = ForStatementLeft::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec1(ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                key.create_binding_pattern(ctx),
+                None,
+                false,
+            )),
+            false,
+        ));
+        let for_in = ctx.ast.alloc_for_in_statement_with_scope_id(
+            SPAN,
+            left,
+            module.create_read_expression(ctx),
+            ctx.ast.statement_block_with_scope_id(SPAN, ctx.ast.vec1(copy), block_scope_id),
+            for_scope_id,
+        );
+        body.push(Statement::ForInStatement(for_in));
+
+        // `_exportObj.bar = _foo.bar;`
+        for (exported, imported) in dependency.re_exports {
+            let value = imported_value(imported, ctx);
+            let assignment = super::create_member_assignment(
+                export_obj.create_read_expression(ctx),
+                exported,
+                value,
+                ctx,
+            );
+            body.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+        let call = create_call(
+            export.create_read_expression(ctx),
+            [export_obj.create_read_expression(ctx)],
+            ctx,
+        );
+        body.push(ctx.ast.statement_expression(SPAN, call));
+    } else if !dependency.re_exports.is_empty() {
+        // `_export({ bar: _foo.bar })`
+        let properties = ctx.ast.vec_from_iter(
+            dependency
+                .re_exports
+                .into_iter()
+                .map(|(exported, imported)| {
+                    let value = imported_value(imported, ctx);
+                    create_object_property(exported.as_str(), value, ctx)
+                })
+                .collect::<Vec<_>>(),
+        );
+        let object = ctx.ast.expression_object(SPAN, properties);
+        let call = create_call(export.create_read_expression(ctx), [object], ctx);
+        body.push(ctx.ast.statement_expression(SPAN, call));
+    }
+
+    create_function([module.create_binding_pattern(ctx)], body, scope_id, ctx)
+}
+
+struct SystemJsVisitor<'a, 'c> {
+    local_exports: FxHashMap<SymbolId, Vec<Atom<'a>>>,
+    /// `_export` parameter
+    export: BoundIdentifier<'a>,
+    /// `_context` parameter
+    context: BoundIdentifier<'a>,
+    /// Temporary variables, declared in the `System.register` callback.
+    temps: Vec<BoundIdentifier<'a>>,
+    declare_scope_id: ScopeId,
+    /// Depth of non-arrow functions and classes, for replacing top-level `this`.
+    this_depth: usize,
+    /// Depth of functions and static blocks, for hoisting `var` declarations.
+    var_depth: usize,
+    allow_top_level_this: bool,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> SystemJsVisitor<'a, '_> {
+    /// `_export("name", value)`
+    fn create_export_call(&mut self, name: Atom<'a>, value: Expression<'a>) -> Expression<'a> {
+        let name = self.ctx.ast.expression_string_literal(SPAN, name, None);
+        create_call(self.export.create_read_expression(self.ctx), [name, value], self.ctx)
+    }
+
+    fn wrap_with_exports(
+        &mut self,
+        names: &[Atom<'a>],
+        mut value: Expression<'a>,
+    ) -> Expression<'a> {
+        for name in names {
+            value = self.create_export_call(*name, value);
+        }
+        value
+    }
+
+    /// Convert a hoisted variable declaration into assignments.
+    ///
+    /// `var a = 1, { b } = obj` -> `a = 1, { b } = obj, _export("b", b)`
+    ///
+    /// Exported bindings assigned directly are wrapped with `_export()` when visited afterwards.
+    fn transform_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+    ) -> Option<Expression<'a>> {
+        let mut expressions = self.ctx.ast.vec();
+        for declarator in decl.declarations.take_in(self.ctx.ast) {
+            if let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind {
+                let init = match declarator.init {
+                    Some(init) => init,
+                    // `export let a;` -> `a = void 0`
+                    None if decl.kind != VariableDeclarationKind::Var
+                        && self.local_exports.contains_key(&ident.symbol_id()) =>
+                    {
+                        self.ctx.ast.void_0(SPAN)
+                    }
+                    None => continue,
+                };
+                let target =
+                    BoundIdentifier::from_binding_ident(ident).create_write_target(self.ctx);
+                expressions.push(self.ctx.ast.expression_assignment(
+                    declarator.span,
+                    AssignmentOperator::Assign,
+                    target,
+                    init,
+                ));
+                continue;
+            }
+
+            let Some(init) = declarator.init else { continue };
+            let exported = declarator
+                .id
+                .get_binding_identifiers()
+                .into_iter()
+                .filter_map(|ident| {
+                    let names = self.local_exports.get(&ident.symbol_id())?;
+                    Some((BoundIdentifier::from_binding_ident(ident), names.clone()))
+                })
+                .collect::<Vec<_>>();
+            let target = binding_pattern_to_assignment_target(declarator.id, self.ctx);
+            expressions.push(self.ctx.ast.expression_assignment(
+                declarator.span,
+                AssignmentOperator::Assign,
+                target,
+                init,
+            ));
+            for (ident, names) in exported {
+                let value = ident.create_read_expression(self.ctx);
+                let export = self.wrap_with_exports(&names, value);
+                expressions.push(export);
+            }
+        }
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(decl.span, expressions)),
+        }
+    }
+
+    /// `a = 1` -> `_export("a", a = 1)`
+    /// `++a` -> `_export("a", ++a)`
+    /// `a++` -> `(_a = a++, _export("a", a), _a)`
+    fn transform_exported_assignment(&mut self, expr: &mut Expression<'a>) {
+        let ident = match expr {
+            Expression::AssignmentExpression(assignment) => match &assignment.left {
+                AssignmentTarget::AssignmentTargetIdentifier(ident) => ident,
+                _ => return,
+            },
+            Expression::UpdateExpression(update) => match &update.argument {
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => ident,
+                _ => return,
+            },
+            _ => return,
+        };
+        let Some(symbol_id) = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()
+        else {
+            return;
+        };
+        let Some(names) = self.local_exports.get(&symbol_id).cloned() else { return };
+        let ident = BoundIdentifier::new(ident.name, symbol_id);
+
+        if let Expression::UpdateExpression(update) = expr
+            && !update.prefix
+        {
+            let temp = self.ctx.generate_uid(
+                &ident.name,
+                self.declare_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let span = update.span;
+            let update = expr.take_in(self.ctx.ast);
+            let save = self.ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                temp.create_write_target(self.ctx),
+                update,
+            );
+            let value = ident.create_read_expression(self.ctx);
+            let export = self.wrap_with_exports(&names, value);
+            let result = temp.create_read_expression(self.ctx);
+            *expr = self
+                .ctx
+                .ast
+                .expression_sequence(span, self.ctx.ast.vec_from_array([save, export, result]));
+            self.temps.push(temp);
+        } else {
+            let value = expr.take_in(self.ctx.ast);
+            *expr = self.wrap_with_exports(&names, value);
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for SystemJsVisitor<'a, '_> {
+    fn visit_function(&mut self, it: &mut Function<'a>, flags: ScopeFlags) {
+        self.this_depth += 1;
+        self.var_depth += 1;
+        walk_mut::walk_function(self, it, flags);
+        self.var_depth -= 1;
+        self.this_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &mut ArrowFunctionExpression<'a>) {
+        self.var_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, it);
+        self.var_depth -= 1;
+    }
+
+    fn visit_class(&mut self, it: &mut Class<'a>) {
+        self.this_depth += 1;
+        walk_mut::walk_class(self, it);
+        self.this_depth -= 1;
+    }
+
+    fn visit_static_block(&mut self, it: &mut StaticBlock<'a>) {
+        self.var_depth += 1;
+        walk_mut::walk_static_block(self, it);
+        self.var_depth -= 1;
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if self.var_depth == 0
+            && let Statement::VariableDeclaration(decl) = stmt
+            && decl.kind.is_var()
+        {
+            let span = decl.span;
+            *stmt = match self.transform_variable_declaration(decl) {
+                Some(expr) => self.ctx.ast.statement_expression(span, expr),
+                None => self.ctx.ast.statement_empty(span),
+            };
+        }
+        walk_mut::walk_statement(self, stmt);
+    }
+
+    fn visit_for_statement_init(&mut self, it: &mut ForStatementInit<'a>) {
+        if self.var_depth == 0
+            && let ForStatementInit::VariableDeclaration(decl) = it
+            && decl.kind.is_var()
+        {
+            let expr = self
+                .transform_variable_declaration(decl)
+                .unwrap_or_else(|| self.ctx.ast.void_0(SPAN));
+            *it = ForStatementInit::from(expr);
+        }
+        walk_mut::walk_for_statement_init(self, it);
+    }
+
+    fn visit_for_statement_left(&mut self, it: &mut ForStatementLeft<'a>) {
+        if self.var_depth == 0
+            && let ForStatementLeft::VariableDeclaration(decl) = it
+            && decl.kind.is_var()
+        {
+            let declarator = decl.declarations.pop().unwrap();
+            *it = ForStatementLeft::from(binding_pattern_to_assignment_target(
+                declarator.id,
+                self.ctx,
+            ));
+        }
+        walk_mut::walk_for_statement_left(self, it);
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(this)
+                if self.this_depth == 0 && !self.allow_top_level_this =>
+            {
+                *expr = self.ctx.ast.void_0(this.span);
+                return;
+            }
+            // `import.meta` -> `_context.meta`
+            Expression::MetaProperty(meta)
+                if meta.meta.name == "import" && meta.property.name == "meta" =>
+            {
+                let context = self.context.create_spanned_read_expression(meta.span, self.ctx);
+                *expr = create_member(context, Atom::from("meta"), self.ctx);
+                return;
+            }
+            _ => {}
+        }
+
+        walk_mut::walk_expression(self, expr);
+
+        match expr {
+            Expression::AssignmentExpression(_) | Expression::UpdateExpression(_) => {
+                self.transform_exported_assignment(expr);
+            }
+            // `import(source)` -> `_context.import(source)`
+            Expression::ImportExpression(import) => {
+                let span = import.span;
+                let context = self.context.create_read_expression(self.ctx);
+                let callee = create_member(context, Atom::from("import"), self.ctx);
+                let mut arguments =
+                    self.ctx.ast.vec1(Argument::from(import.source.take_in(self.ctx.ast)));
+                if let Some(options) = import.options.take() {
+                    arguments.push(Argument::from(options));
+                }
+                *expr = self.ctx.ast.expression_call(span, callee, NONE, arguments, false);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Finds top-level `await`, for making the `execute` function async.
+struct TopLevelAwaitFinder {
+    found: bool,
+}
+
+impl TopLevelAwaitFinder {
+    fn contains_await(stmts: &[Statement<'_>]) -> bool {
+        let mut finder = Self { found: false };
+        for stmt in stmts {
+            finder.visit_statement(stmt);
+        }
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for TopLevelAwaitFinder {
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_await_expression(&mut self, _it: &AwaitExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+        if it.r#await {
+            self.found = true;
+        } else {
+            oxc_ast_visit::walk::walk_for_of_statement(self, it);
+        }
+    }
+}
@@ -0,0 +1,304 @@
+//! UMD
+//!
+//! ```js
+//! import foo from "foo";
+//! export default foo;
+//! ```
+//! ->
+//! ```js
+//! (function(global, factory) {
+//!   if (typeof define === "function" && define.amd) {
+//!     define(["exports", "foo"], factory);
+//!   } else if (typeof exports !== "undefined") {
+//!     factory(exports, require("foo"));
+//!   } else {
+//!     var mod = { exports: {} };
+//!     factory(mod.exports, global.foo);
+//!     global.input = mod.exports;
+//!   }
+//! })(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_exports, _foo) {
+//!   "use strict";
+//!   Object.defineProperty(_exports, "__esModule", { value: true });
+//!   _exports.default = void 0;
+//!   _foo = babelHelpers.interopRequireDefault(_foo);
+//!   _exports.default = _foo.default;
+//! });
+//! ```
+//!
+//! Based on Babel's [transform-modules-umd](https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-modules-umd/src/index.ts).
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::BoundIdentifier;
+
+use crate::context::{TransformCtx, TraverseCtx};
+
+use super::{
+    ModulesOptions, create_call, create_function, create_member, create_member_assignment,
+    create_object_property, create_var_declaration, factory::transform_factory_body, file_stem,
+    to_identifier,
+};
+
+pub(super) fn transform_program<'a>(
+    program: &mut Program<'a>,
+    options: &ModulesOptions,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let factory = transform_factory_body(program, options, transform_ctx, ctx, false);
+
+    let root_scope_id = ctx.scoping().root_scope_id();
+    let wrapper_scope_id = ctx.create_child_scope(root_scope_id, ScopeFlags::Function);
+    let global = ctx.generate_binding(
+        Atom::from("global"),
+        wrapper_scope_id,
+        SymbolFlags::FunctionScopedVariable,
+    );
+    let factory_param = ctx.generate_binding(
+        Atom::from("factory"),
+        wrapper_scope_id,
+        SymbolFlags::FunctionScopedVariable,
+    );
+    let module = ctx.generate_binding(
+        Atom::from("mod"),
+        wrapper_scope_id,
+        SymbolFlags::FunctionScopedVariable,
+    );
+
+    // `define(["exports", "foo"], factory);`
+    let amd_scope_id = ctx.create_child_scope(wrapper_scope_id, ScopeFlags::empty());
+    let amd = {
+        let mut arguments = Vec::with_capacity(3);
+        if let Some(module_id) = &options.module_id {
+            arguments.push(ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(module_id), None));
+        }
+        let dependencies = ctx.ast.vec_from_iter(factory.dependency_names().map(|name| {
+            ArrayExpressionElement::from(ctx.ast.expression_string_literal(SPAN, name, None))
+        }));
+        arguments.push(ctx.ast.expression_array(SPAN, dependencies));
+        arguments.push(factory_param.create_read_expression(ctx));
+        let define =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("define"), ReferenceFlags::Read);
+        let call = create_call(define, arguments, ctx);
+        ctx.ast.statement_block_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+            amd_scope_id,
+        )
+    };
+
+    // `factory(exports, require("foo"));`
+    let commonjs_scope_id = ctx.create_child_scope(wrapper_scope_id, ScopeFlags::empty());
+    let commonjs = {
+        let mut arguments = vec![];
+        if factory.exports.is_some() {
+            arguments.push(ctx.create_unbound_ident_expr(
+                SPAN,
+                Atom::from("exports"),
+                ReferenceFlags::Read,
+            ));
+        }
+        for dependency in &factory.dependencies {
+            let require =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("require"), ReferenceFlags::Read);
+            let source = ctx.ast.expression_string_literal(SPAN, dependency.source, None);
+            arguments.push(create_call(require, [source], ctx));
+        }
+        let call = create_call(factory_param.create_read_expression(ctx), arguments, ctx);
+        ctx.ast.statement_block_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+            commonjs_scope_id,
+        )
+    };
+
+    // ```js
+    // var mod = { exports: {} };
+    // factory(mod.exports, global.foo);
+    // global.input = mod.exports;
+    // ```
+    let browser_scope_id = ctx.create_child_scope(wrapper_scope_id, ScopeFlags::empty());
+    let browser = {
+        let mut body = ctx.ast.vec();
+        let module_init = ctx.ast.expression_object(
+            SPAN,
+            ctx.ast.vec1(create_object_property(
+                "exports",
+                ctx.ast.expression_object(SPAN, ctx.ast.vec()),
+                ctx,
+            )),
+        );
+        body.push(create_var_declaration(
+            [(module.create_binding_pattern(ctx), Some(module_init))],
+            ctx,
+        ));
+
+        let mut arguments = vec![];
+        if factory.exports.is_some() {
+            arguments.push(create_module_exports(&module, ctx));
+        }
+        for dependency in &factory.dependencies {
+            let global_name = dependency_global_name(&dependency.source, options);
+            arguments.push(create_global_member(&global, &global_name, ctx));
+        }
+        let call = create_call(factory_param.create_read_expression(ctx), arguments, ctx);
+        body.push(ctx.ast.statement_expression(SPAN, call));
+
+        export_global(&mut body, &global, &module, options, transform_ctx, ctx);
+        ctx.ast.statement_block_with_scope_id(SPAN, body, browser_scope_id)
+    };
+
+    // `typeof define === "function" && define.amd`
+    let is_amd = {
+        let define =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("define"), ReferenceFlags::Read);
+        let is_function =
+            create_typeof_check(define, BinaryOperator::StrictEquality, "function", ctx);
+        let define =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("define"), ReferenceFlags::Read);
+        let amd = create_member(define, Atom::from("amd"), ctx);
+        ctx.ast.expression_logical(SPAN, is_function, LogicalOperator::And, amd)
+    };
+    // `typeof exports !== "undefined"`
+    let is_commonjs = {
+        let exports =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("exports"), ReferenceFlags::Read);
+        create_typeof_check(exports, BinaryOperator::StrictInequality, "undefined", ctx)
+    };
+    let commonjs_or_browser = ctx.ast.statement_if(SPAN, is_commonjs, commonjs, Some(browser));
+    let wrapper_body =
+        ctx.ast.vec1(ctx.ast.statement_if(SPAN, is_amd, amd, Some(commonjs_or_browser)));
+    let wrapper = create_function(
+        [global.create_binding_pattern(ctx), factory_param.create_binding_pattern(ctx)],
+        wrapper_body,
+        wrapper_scope_id,
+        ctx,
+    );
+
+    // `typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this`
+    let global_this = {
+        let is_defined = |name: &'static str, ctx: &mut TraverseCtx<'a>| {
+            let ident = ctx.create_unbound_ident_expr(SPAN, Atom::from(name), ReferenceFlags::Read);
+            create_typeof_check(ident, BinaryOperator::StrictInequality, "undefined", ctx)
+        };
+        let has_self = is_defined("self", ctx);
+        let self_ = ctx.create_unbound_ident_expr(SPAN, Atom::from("self"), ReferenceFlags::Read);
+        let self_or_this =
+            ctx.ast.expression_conditional(SPAN, has_self, self_, ctx.ast.expression_this(SPAN));
+        let has_global_this = is_defined("globalThis", ctx);
+        let global_this =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("globalThis"), ReferenceFlags::Read);
+        ctx.ast.expression_conditional(SPAN, has_global_this, global_this, self_or_this)
+    };
+
+    let factory_function = factory.create_function(program, ctx);
+    let wrapper = ctx.ast.expression_parenthesized(SPAN, wrapper);
+    let call = ctx.ast.expression_call(
+        SPAN,
+        wrapper,
+        NONE,
+        ctx.ast.vec_from_array([Argument::from(global_this), Argument::from(factory_function)]),
+        false,
+    );
+    program.body.push(ctx.ast.statement_expression(SPAN, call));
+}
+
+/// `typeof value <operator> "type"`
+fn create_typeof_check<'a>(
+    value: Expression<'a>,
+    operator: BinaryOperator,
+    r#type: &'static str,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    ctx.ast.expression_binary(
+        SPAN,
+        ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, value),
+        operator,
+        ctx.ast.expression_string_literal(SPAN, r#type, None),
+    )
+}
+
+/// `mod.exports`
+fn create_module_exports<'a>(
+    module: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    create_member(module.create_read_expression(ctx), Atom::from("exports"), ctx)
+}
+
+/// Global variable name of an imported module, e.g. `lodashEs` for `lodash-es`.
+///
+/// Dots in names from [`ModulesOptions::globals`] are namespaces when `exact_globals` is enabled,
+/// e.g. `MyOrg.MyLib` -> `global.MyOrg.MyLib`.
+fn dependency_global_name(source: &str, options: &ModulesOptions) -> Vec<String> {
+    if options.exact_globals {
+        match options.globals.get(source) {
+            Some(name) => name.split('.').map(String::from).collect(),
+            None => vec![to_identifier(source)],
+        }
+    } else {
+        let name = file_stem(source);
+        let name = options.globals.get(name).map_or(name, String::as_str);
+        vec![to_identifier(name)]
+    }
+}
+
+/// `global.a.b`
+fn create_global_member<'a>(
+    global: &BoundIdentifier<'a>,
+    names: &[String],
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    names.iter().fold(global.create_read_expression(ctx), |object, name| {
+        create_member(object, ctx.ast.atom(name), ctx)
+    })
+}
+
+/// Assign exports to the global variable of this module.
+///
+/// ```js
+/// global.MyOrg = global.MyOrg || {};
+/// global.MyOrg.MyLib = mod.exports;
+/// ```
+fn export_global<'a>(
+    body: &mut ArenaVec<'a, Statement<'a>>,
+    global: &BoundIdentifier<'a>,
+    module: &BoundIdentifier<'a>,
+    options: &ModulesOptions,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let names = if let Some(export_name) = &options.export_name {
+        export_name.split('.').map(String::from).collect::<Vec<_>>()
+    } else {
+        let name = options.module_id.as_deref().unwrap_or(&transform_ctx.filename);
+        match options.globals.get(name) {
+            Some(global_name) if options.exact_globals => {
+                global_name.split('.').map(String::from).collect()
+            }
+            _ => vec![to_identifier(name)],
+        }
+    };
+
+    // `global.MyOrg = global.MyOrg || {};`
+    for i in 1..names.len() {
+        let namespace = create_global_member(global, &names[..i], ctx);
+        let init = ctx.ast.expression_logical(
+            SPAN,
+            namespace,
+            LogicalOperator::Or,
+            ctx.ast.expression_object(SPAN, ctx.ast.vec()),
+        );
+        let target = create_global_member(global, &names[..i - 1], ctx);
+        let assignment = create_member_assignment(target, ctx.ast.atom(&names[i - 1]), init, ctx);
+        body.push(ctx.ast.statement_expression(SPAN, assignment));
+    }
+
+    let (last, namespaces) = names.split_last().unwrap();
+    let target = create_global_member(global, namespaces, ctx);
+    let value = create_module_exports(module, ctx);
+    let assignment = create_member_assignment(target, ctx.ast.atom(last), value, ctx);
+    body.push(ctx.ast.statement_expression(SPAN, assignment));
+}
@@ -0,0 +1,533 @@
+//! Load Babel config files of a project.
+//!
+//! Supports JSON config files only:
+//!
+//! * Project-wide `babel.config.json` in [`BabelConfigLoadOptions::cwd`].
+//! * File-relative `.babelrc`, `.babelrc.json` or the `babel` key of `package.json`,
+//!   found from the directory of the transformed file up to its package root.
+//!
+//! Within each file, `extends`, `env`, `overrides`, `test`, `include`, `exclude`, `ignore` and
+//! `only` are resolved the same way as Babel. Later options take precedence, and plugins or
+//! presets with the same name are merged into the position of their first occurrence.
+//!
+//! <https://babeljs.io/docs/config-files>
+
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use serde_json::{Map, Value};
+
+use crate::TransformOptions;
+
+use super::BabelOptions;
+
+const ROOT_CONFIG_FILES: &[&str] = &[
+    "babel.config.json",
+    "babel.config.js",
+    "babel.config.cjs",
+    "babel.config.mjs",
+    "babel.config.cts",
+];
+
+const RELATIVE_CONFIG_FILES: &[&str] =
+    &[".babelrc", ".babelrc.json", ".babelrc.js", ".babelrc.cjs", ".babelrc.mjs", ".babelrc.cts"];
+
+/// Options which only affect how config files are loaded, and are not passed on to plugins.
+const LOADING_OPTIONS: &[&str] = &[
+    "babelrc",
+    "babelrcRoots",
+    "configFile",
+    "env",
+    "envName",
+    "exclude",
+    "extends",
+    "filename",
+    "ignore",
+    "include",
+    "only",
+    "overrides",
+    "plugins",
+    "presets",
+    "root",
+    "rootMode",
+    "test",
+];
+
+/// Parsed content of a config file, or a block of it.
+type ConfigObject = Map<String, Value>;
+
+/// Options which are merged key by key instead of being replaced.
+const MERGED_OPTIONS: &[&str] = &["assumptions", "parserOpts", "generatorOpts"];
+
+/// Options for [`BabelConfig::load`].
+#[derive(Debug, Default, Clone)]
+pub struct BabelConfigLoadOptions {
+    /// The root directory of the project, which contains `babel.config.json`.
+    /// Relative paths are resolved from it.
+    pub cwd: PathBuf,
+
+    /// Path of the file to be transformed.
+    ///
+    /// It is used to find `.babelrc` files, and to match `test`, `include`, `exclude`,
+    /// `ignore` and `only` conditions.
+    pub filename: PathBuf,
+
+    /// Path of the project-wide config file, instead of `babel.config.json` in `cwd`.
+    pub config_file: Option<PathBuf>,
+
+    /// Name of the `env` block to apply.
+    ///
+    /// Defaults to the `BABEL_ENV` or `NODE_ENV` environment variable, or `"development"`.
+    pub env_name: Option<String>,
+}
+
+/// Transform options loaded from Babel config files.
+#[derive(Debug, Default)]
+pub struct BabelConfig {
+    pub options: TransformOptions,
+
+    /// Config files which were read, in the order they were applied.
+    pub files: Vec<PathBuf>,
+
+    /// Plugins and presets which are not supported by oxc, and were ignored.
+    pub unsupported: Vec<String>,
+
+    /// Whether the file is excluded by `ignore` or `only`.
+    /// Babel does not transform ignored files.
+    pub ignored: bool,
+}
+
+impl BabelConfig {
+    /// Load and merge the Babel config files which apply to [`BabelConfigLoadOptions::filename`].
+    ///
+    /// # Errors
+    ///
+    /// * When a config file cannot be read or parsed, or is not a JSON file.
+    /// * When the options of a supported plugin or preset are invalid.
+    pub fn load(load_options: &BabelConfigLoadOptions) -> Result<Self, Vec<String>> {
+        let (babel_options, files, ignored) =
+            BabelOptions::from_config_files(load_options).map_err(|err| vec![err])?;
+        let mut unsupported = babel_options.plugins.unsupported.clone();
+        unsupported.extend(babel_options.presets.unsupported.iter().cloned());
+        if babel_options.plugins.modules_commonjs {
+            unsupported.push("transform-modules-commonjs".to_string());
+        }
+        let options = TransformOptions::try_from(&babel_options)?;
+        Ok(Self { options, files, unsupported, ignored })
+    }
+}
+
+impl BabelOptions {
+    /// Load and merge the Babel config files which apply to [`BabelConfigLoadOptions::filename`].
+    ///
+    /// Returns the merged options, the config files which were read, and whether the file
+    /// is excluded by `ignore` or `only`.
+    ///
+    /// # Errors
+    ///
+    /// * When a config file cannot be read or parsed, or is not a JSON file.
+    pub fn from_config_files(
+        load_options: &BabelConfigLoadOptions,
+    ) -> Result<(Self, Vec<PathBuf>, bool), String> {
+        let cwd = normalize_path(&std::env::current_dir().unwrap_or_default(), &load_options.cwd);
+        let env_name = load_options.env_name.clone().unwrap_or_else(|| {
+            std::env::var("BABEL_ENV")
+                .or_else(|_| std::env::var("NODE_ENV"))
+                .unwrap_or_else(|_| "development".to_string())
+        });
+        let mut chain = ConfigChain {
+            filename: normalize_path(&cwd, &load_options.filename),
+            env_name,
+            ..ConfigChain::default()
+        };
+
+        let root_config = match &load_options.config_file {
+            Some(config_file) => Some(normalize_path(&cwd, config_file)),
+            None => find_config_file(&cwd, ROOT_CONFIG_FILES),
+        };
+        let mut babelrc = true;
+        if let Some(path) = root_config {
+            if let Some(config) = read_config_file(&path)? {
+                babelrc = config.get("babelrc").and_then(Value::as_bool).unwrap_or(true);
+                chain.apply_file(&path, config, 0)?;
+            }
+        }
+
+        if babelrc {
+            if let Some((path, config)) = find_relative_config(&chain.filename)? {
+                chain.apply_file(&path, config, 0)?;
+            }
+        }
+
+        let ConfigChain { mut options, mut plugins, presets, files, ignored, .. } = chain;
+        // `@babel/plugin-external-helpers` references helpers from the `babelHelpers` global.
+        let external_helpers = plugins.iter().position(|entry| entry.name == "external-helpers");
+        if let Some(index) = external_helpers {
+            plugins.remove(index);
+        }
+        options.insert("externalHelpers".to_string(), Value::Bool(external_helpers.is_some()));
+        options.insert("plugins".to_string(), entries_to_json(plugins));
+        options.insert("presets".to_string(), entries_to_json(presets));
+
+        let mut babel_options = serde_json::from_value::<Self>(Value::Object(options))
+            .map_err(|err| format!("Invalid Babel options: {err}"))?;
+        babel_options.cwd = Some(cwd);
+        Ok((babel_options, files, ignored))
+    }
+}
+
+#[derive(Default)]
+struct ConfigChain {
+    filename: PathBuf,
+    env_name: String,
+    options: ConfigObject,
+    plugins: Vec<PluginPresetItem>,
+    presets: Vec<PluginPresetItem>,
+    files: Vec<PathBuf>,
+    ignored: bool,
+}
+
+/// A plugin or preset with a normalized name.
+struct PluginPresetItem {
+    name: String,
+    options: Option<Value>,
+    alias: Option<String>,
+}
+
+impl ConfigChain {
+    fn apply_file(
+        &mut self,
+        path: &Path,
+        mut config: ConfigObject,
+        depth: usize,
+    ) -> Result<(), String> {
+        // Limit depth to avoid infinite loops on circular `extends`.
+        if depth > 32 {
+            return Err(format!("{}: too many nested `extends`", path.display()));
+        }
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+
+        if let Some(extends) = config.remove("extends") {
+            let Value::String(extends) = extends else {
+                return Err(format!("{}: `extends` must be a string", path.display()));
+            };
+            let extends = normalize_path(dir, Path::new(&extends));
+            if let Some(extended) = read_config_file(&extends)? {
+                self.apply_file(&extends, extended, depth + 1)?;
+            }
+        }
+
+        self.files.push(path.to_path_buf());
+        self.apply_options(&config, dir, path)
+    }
+
+    /// Apply options, their `env` block and their `overrides`.
+    fn apply_options(
+        &mut self,
+        config: &ConfigObject,
+        dir: &Path,
+        path: &Path,
+    ) -> Result<(), String> {
+        self.apply_block(config, dir, path)?;
+        if let Some(env) = self.env_block(config, path)? {
+            self.apply_block(env, dir, path)?;
+        }
+        if let Some(overrides) = config.get("overrides") {
+            let Value::Array(overrides) = overrides else {
+                return Err(format!("{}: `overrides` must be an array", path.display()));
+            };
+            for config in overrides {
+                let Value::Object(config) = config else {
+                    return Err(format!("{}: `overrides` must be objects", path.display()));
+                };
+                if !self.is_applicable(config, dir, path)? {
+                    continue;
+                }
+                self.apply_block(config, dir, path)?;
+                if let Some(env) = self.env_block(config, path)? {
+                    self.apply_block(env, dir, path)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn env_block<'c>(
+        &self,
+        config: &'c ConfigObject,
+        path: &Path,
+    ) -> Result<Option<&'c ConfigObject>, String> {
+        match config.get("env").and_then(|env| env.get(&self.env_name)) {
+            None => Ok(None),
+            Some(Value::Object(env)) => Ok(Some(env)),
+            Some(_) => {
+                Err(format!("{}: `env.{}` must be an object", path.display(), self.env_name))
+            }
+        }
+    }
+
+    /// Merge a single block of options, if its `test`, `include` and `exclude` match the file.
+    fn apply_block(
+        &mut self,
+        config: &ConfigObject,
+        dir: &Path,
+        path: &Path,
+    ) -> Result<(), String> {
+        if !self.is_applicable(config, dir, path)? {
+            return Ok(());
+        }
+        if let Some(ignore) = config.get("ignore") {
+            self.ignored |= self.matches_any(ignore, dir, path)?;
+        }
+        if let Some(only) = config.get("only") {
+            self.ignored |= !self.matches_any(only, dir, path)?;
+        }
+
+        for (key, value) in config {
+            if LOADING_OPTIONS.contains(&key.as_str()) {
+                continue;
+            }
+            if let (true, Value::Object(value), Some(Value::Object(existing))) =
+                (MERGED_OPTIONS.contains(&key.as_str()), value, self.options.get_mut(key))
+            {
+                existing.extend(value.iter().map(|(k, v)| (k.clone(), v.clone())));
+            } else {
+                self.options.insert(key.clone(), value.clone());
+            }
+        }
+
+        if let Some(plugins) = config.get("plugins") {
+            merge_entries(&mut self.plugins, plugins, "plugin", path)?;
+        }
+        if let Some(presets) = config.get("presets") {
+            merge_entries(&mut self.presets, presets, "preset", path)?;
+        }
+        Ok(())
+    }
+
+    fn is_applicable(
+        &self,
+        config: &ConfigObject,
+        dir: &Path,
+        path: &Path,
+    ) -> Result<bool, String> {
+        for key in ["test", "include"] {
+            if let Some(conditions) = config.get(key) {
+                if !self.matches_any(conditions, dir, path)? {
+                    return Ok(false);
+                }
+            }
+        }
+        if let Some(conditions) = config.get("exclude") {
+            if self.matches_any(conditions, dir, path)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Whether the file matches any of the path patterns, which are relative to `dir`.
+    fn matches_any(&self, conditions: &Value, dir: &Path, path: &Path) -> Result<bool, String> {
+        let conditions = match conditions {
+            Value::String(_) => std::slice::from_ref(conditions),
+            Value::Array(conditions) => conditions.as_slice(),
+            _ => {
+                return Err(format!(
+                    "{}: conditions must be a string or an array of strings",
+                    path.display()
+                ));
+            }
+        };
+        for condition in conditions {
+            let Value::String(pattern) = condition else {
+                return Err(format!(
+                    "{}: conditions must be a string or an array of strings",
+                    path.display()
+                ));
+            };
+            let pattern = normalize_path(dir, Path::new(pattern));
+            let pattern = pattern.iter().filter_map(|s| s.to_str()).collect::<Vec<_>>();
+            let filename = self.filename.iter().filter_map(|s| s.to_str()).collect::<Vec<_>>();
+            if matches_segments(&pattern, &filename) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// Match path segments against pattern segments, which may contain `*` and `**` wildcards.
+///
+/// Patterns match the path as a prefix, e.g. `src` matches `src/index.js`.
+fn matches_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => true,
+        Some((&"**", rest)) => (0..=path.len()).any(|i| matches_segments(rest, &path[i..])),
+        Some((segment, rest)) => path.split_first().is_some_and(|(first, path_rest)| {
+            matches_wildcard(segment, first) && matches_segments(rest, path_rest)
+        }),
+    }
+}
+
+/// Match a single path segment against a pattern, which may contain `*` wildcards.
+fn matches_wildcard(pattern: &str, segment: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == segment,
+        Some((prefix, rest)) => {
+            let Some(segment) = segment.strip_prefix(prefix) else { return false };
+            (0..=segment.len())
+                .filter(|&i| segment.is_char_boundary(i))
+                .any(|i| matches_wildcard(rest, &segment[i..]))
+        }
+    }
+}
+
+/// Merge `plugins` or `presets` of a config block into the existing ones.
+///
+/// An entry with the same name and alias replaces the options of the existing one,
+/// and options of `false` disable it.
+fn merge_entries(
+    entries: &mut Vec<PluginPresetItem>,
+    value: &Value,
+    kind: &str,
+    path: &Path,
+) -> Result<(), String> {
+    let Value::Array(items) = value else {
+        return Err(format!("{}: `{kind}s` must be an array", path.display()));
+    };
+    for item in items {
+        let invalid = || format!("{}: invalid {kind} entry `{item}`", path.display());
+        let (name, options, alias) = match item {
+            Value::String(name) => (name, None, None),
+            Value::Array(tuple) => match tuple.as_slice() {
+                [Value::String(name)] => (name, None, None),
+                [Value::String(name), options] => (name, Some(options), None),
+                [Value::String(name), options, Value::String(alias)] => {
+                    (name, Some(options), Some(alias.clone()))
+                }
+                _ => return Err(invalid()),
+            },
+            _ => return Err(invalid()),
+        };
+        let mut name = normalize_name(name, kind);
+        let mut options = options.filter(|options| !options.is_null()).cloned();
+        // `["@babel/plugin-proposal-decorators", { "version": "legacy" }]`
+        if name == "proposal-decorators"
+            && options.as_ref().is_some_and(|options| {
+                options.get("legacy").and_then(Value::as_bool) == Some(true)
+                    || options.get("version").and_then(Value::as_str) == Some("legacy")
+            })
+        {
+            name = "transform-legacy-decorator".to_string();
+            options = None;
+        }
+
+        let existing = entries.iter().position(|entry| entry.name == name && entry.alias == alias);
+        match (existing, options) {
+            (Some(index), Some(Value::Bool(false))) => {
+                entries.remove(index);
+            }
+            (None, Some(Value::Bool(false))) => {}
+            (Some(index), options) => entries[index].options = options,
+            (None, options) => entries.push(PluginPresetItem { name, options, alias }),
+        }
+    }
+    Ok(())
+}
+
+/// Normalize a plugin or preset name to the short name Babel's test options use,
+/// e.g. `@babel/plugin-transform-typescript` -> `transform-typescript`.
+fn normalize_name(name: &str, kind: &str) -> String {
+    let name = name.strip_prefix("module:").unwrap_or(name);
+    let name = name
+        .strip_prefix("@babel/")
+        .or_else(|| {
+            name.strip_prefix(if kind == "plugin" { "babel-plugin-" } else { "babel-preset-" })
+        })
+        .map_or(name, |name| {
+            name.strip_prefix(if kind == "plugin" { "plugin-" } else { "preset-" }).unwrap_or(name)
+        });
+    name.to_string()
+}
+
+fn entries_to_json(entries: Vec<PluginPresetItem>) -> Value {
+    Value::Array(
+        entries
+            .into_iter()
+            .map(|entry| match entry.options {
+                None => Value::String(entry.name),
+                Some(options) => Value::Array(vec![Value::String(entry.name), options]),
+            })
+            .collect(),
+    )
+}
+
+/// Find the first existing config file in `dir`.
+fn find_config_file(dir: &Path, names: &[&str]) -> Option<PathBuf> {
+    names.iter().map(|name| dir.join(name)).find(|path| path.is_file())
+}
+
+/// Find the nearest `.babelrc`, `.babelrc.json` or `package.json#babel`,
+/// from the directory of `filename` up to its package root.
+fn find_relative_config(filename: &Path) -> Result<Option<(PathBuf, ConfigObject)>, String> {
+    for dir in filename.ancestors().skip(1) {
+        if let Some(path) = find_config_file(dir, RELATIVE_CONFIG_FILES) {
+            return Ok(read_config_file(&path)?.map(|config| (path, config)));
+        }
+        let package_json = dir.join("package.json");
+        if package_json.is_file() {
+            return Ok(read_config_file(&package_json)?.map(|config| (package_json, config)));
+        }
+    }
+    Ok(None)
+}
+
+/// Read a JSON config file, or the `babel` key of a `package.json`.
+fn read_config_file(path: &Path) -> Result<Option<ConfigObject>, String> {
+    let file_name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+    let is_package_json = file_name == "package.json";
+    if !(is_package_json
+        || file_name == ".babelrc"
+        || path.extension().is_some_and(|extension| extension == "json"))
+    {
+        return Err(format!("{}: only JSON config files are supported", path.display()));
+    }
+    let mut text = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+    json_strip_comments::strip(&mut text)
+        .map_err(|err| format!("Failed to parse {}: {err}", path.display()))?;
+    let value = serde_json::from_str::<Value>(&text)
+        .map_err(|err| format!("Failed to parse {}: {err}", path.display()))?;
+    let value = if is_package_json {
+        match value {
+            Value::Object(mut package_json) => match package_json.remove("babel") {
+                Some(babel) => babel,
+                None => return Ok(None),
+            },
+            _ => return Ok(None),
+        }
+    } else {
+        value
+    };
+    match value {
+        Value::Object(config) => Ok(Some(config)),
+        _ => Err(format!("{}: config must be an object", path.display())),
+    }
+}
+
+/// Resolve `path` from `base`, and remove `.` and `..` segments.
+fn normalize_path(base: &Path, path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in base.join(path).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
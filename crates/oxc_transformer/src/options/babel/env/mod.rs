@@ -36,7 +36,7 @@ pub struct BabelEnvOptions {
     #[deprecated = "Not Implemented"]
     pub exclude: Option<serde_json::Value>,
 
-    #[deprecated = "Not Implemented"]
+    /// Only `"usage"` is supported, which is the same as `polyfill-corejs3` plugin with `method: "usage-global"`.
     pub use_built_ins: Option<serde_json::Value>,
 
     #[deprecated = "Not Implemented"]
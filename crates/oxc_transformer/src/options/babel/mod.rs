@@ -4,9 +4,11 @@ use serde::{Deserialize, de::DeserializeOwned};
 
 use crate::CompilerAssumptions;
 
+mod config;
 mod env;
 mod plugins;
 mod presets;
+pub use config::{BabelConfig, BabelConfigLoadOptions};
 pub use env::{BabelEnvOptions, BabelModule, BabelTargets};
 pub use plugins::BabelPlugins;
 pub use presets::BabelPresets;
@@ -32,6 +34,10 @@ pub struct BabelOptions {
     // Misc options
     pub source_type: Option<String>,
 
+    // Code Generation options
+    /// Name of the module for AMD, UMD and SystemJS modules.
+    pub module_id: Option<String>,
+
     #[serde(default)]
     pub assumptions: CompilerAssumptions,
 
@@ -1,9 +1,14 @@
 use serde::Deserialize;
 
 use crate::{
-    DecoratorOptions, TypeScriptOptions, es2015::ArrowFunctionsOptions,
-    es2018::ObjectRestSpreadOptions, es2022::ClassPropertiesOptions, jsx::JsxOptions,
-    plugins::StyledComponentsOptions,
+    DecoratorOptions, TypeScriptOptions,
+    es2015::ArrowFunctionsOptions,
+    es2018::ObjectRestSpreadOptions,
+    es2022::ClassPropertiesOptions,
+    jsx::{JsxOptions, JsxRuntime, VueJsxOptions},
+    modules::ModulesOptions,
+    plugins::{EmotionOptions, GraphQLTagOptions, StyledComponentsOptions},
+    polyfills::PolyfillMethod,
 };
 
 use super::PluginPresetEntries;
@@ -20,6 +25,12 @@ pub struct SyntaxDecoratorOptions {
     pub version: String,
 }
 
+/// Options of `babel-plugin-polyfill-corejs3`.
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+pub struct PolyfillCoreJs3Options {
+    pub method: PolyfillMethod,
+}
+
 #[derive(Debug, Default, Clone, Deserialize)]
 #[serde(try_from = "PluginPresetEntries")]
 pub struct BabelPlugins {
@@ -41,6 +52,9 @@ pub struct BabelPlugins {
     pub react_display_name: bool,
     // modules
     pub modules_commonjs: bool,
+    pub modules_amd: Option<ModulesOptions>,
+    pub modules_umd: Option<ModulesOptions>,
+    pub modules_systemjs: Option<ModulesOptions>,
     // regexp
     pub sticky_flag: bool,
     pub unicode_flag: bool,
@@ -76,6 +90,10 @@ pub struct BabelPlugins {
     pub explicit_resource_management: bool,
     // Built-in plugins
     pub styled_components: Option<StyledComponentsOptions>,
+    pub emotion: Option<EmotionOptions>,
+    pub graphql_tag: Option<GraphQLTagOptions>,
+    // Polyfills
+    pub polyfill_corejs3: Option<PolyfillCoreJs3Options>,
 }
 
 impl TryFrom<PluginPresetEntries> for BabelPlugins {
@@ -116,6 +134,17 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         })
                         .ok();
                 }
+                "vue-jsx" => {
+                    p.react_jsx = entry
+                        .value::<VueJsxOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .map(|vue| JsxOptions {
+                            runtime: JsxRuntime::Vue,
+                            vue,
+                            ..JsxOptions::default()
+                        })
+                        .ok();
+                }
                 "transform-react-jsx-development" => {
                     p.react_jsx_dev =
                         entry.value::<JsxOptions>().map_err(|err| p.errors.push(err)).ok();
@@ -124,6 +153,18 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "transform-react-jsx-self" => p.react_jsx_self = true,
                 "transform-react-jsx-source" => p.react_jsx_source = true,
                 "transform-modules-commonjs" => p.modules_commonjs = true,
+                "transform-modules-amd" => {
+                    p.modules_amd =
+                        entry.value::<ModulesOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-modules-umd" => {
+                    p.modules_umd =
+                        entry.value::<ModulesOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-modules-systemjs" => {
+                    p.modules_systemjs =
+                        entry.value::<ModulesOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
                 "transform-sticky-regex" => p.sticky_flag = true,
                 "transform-unicode-regex" => p.unicode_flag = true,
                 "transform-dotall-regex" => p.dot_all_flag = true,
@@ -170,6 +211,20 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "emotion" | "@emotion" | "@emotion/babel-plugin" => {
+                    p.emotion =
+                        entry.value::<EmotionOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "graphql-tag" => {
+                    p.graphql_tag =
+                        entry.value::<GraphQLTagOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "polyfill-corejs3" => {
+                    p.polyfill_corejs3 = entry
+                        .value::<PolyfillCoreJs3Options>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
                 s => p.unsupported.push(s.to_string()),
             }
         }
@@ -12,6 +12,7 @@ use crate::{
     es2020::ES2020Options,
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
+    polyfills::{PolyfillMethod, PolyfillsOptions},
     regexp::RegExpOptions,
 };
 
@@ -40,6 +41,9 @@ pub struct EnvOptions {
     pub es2021: ES2021Options,
 
     pub es2022: ES2022Options,
+
+    /// `core-js` polyfills for built-ins not supported by the targets.
+    pub polyfills: PolyfillsOptions,
 }
 
 impl EnvOptions {
@@ -86,6 +90,7 @@ impl EnvOptions {
                 class_static_block: true,
                 class_properties: Some(ClassPropertiesOptions::default()),
             },
+            polyfills: PolyfillsOptions::all(None),
         }
     }
 
@@ -142,7 +147,11 @@ impl EnvOptions {
 
 impl From<BabelEnvOptions> for EnvOptions {
     fn from(o: BabelEnvOptions) -> Self {
-        Self::from(o.targets)
+        let mut env = Self::from(o.targets);
+        if o.use_built_ins.as_ref().and_then(serde_json::Value::as_str) == Some("usage") {
+            env.polyfills.method = Some(PolyfillMethod::UsageGlobal);
+        }
+        env
     }
 }
 
@@ -188,6 +197,7 @@ impl From<EngineTargets> for EnvOptions {
                 class_static_block: o.has_feature(ES2022ClassStaticBlock),
                 class_properties: o.has_feature(ES2022ClassProperties).then(Default::default),
             },
+            polyfills: PolyfillsOptions::from(&o),
         }
     }
 }
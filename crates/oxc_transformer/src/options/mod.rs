@@ -14,7 +14,9 @@ use crate::{
     es2021::ES2021Options,
     es2022::ES2022Options,
     jsx::JsxOptions,
-    plugins::{PluginsOptions, StyledComponentsOptions},
+    modules::ModulesOptions,
+    plugins::{EmotionOptions, GraphQLTagOptions, PluginsOptions, StyledComponentsOptions},
+    polyfills::PolyfillsOptions,
     proposals::ProposalOptions,
     regexp::RegExpOptions,
     typescript::TypeScriptOptions,
@@ -67,6 +69,9 @@ pub struct TransformOptions {
     /// ECMAScript Env Options
     pub env: EnvOptions,
 
+    /// Options for AMD, UMD and SystemJS modules, see [`EnvOptions::module`].
+    pub modules: ModulesOptions,
+
     /// Proposals
     pub proposals: ProposalOptions,
 
@@ -86,15 +91,25 @@ impl TransformOptions {
             cwd: PathBuf::new(),
             assumptions: CompilerAssumptions::default(),
             typescript: TypeScriptOptions::default(),
-            decorator: DecoratorOptions { legacy: true, emit_decorator_metadata: true },
+            decorator: DecoratorOptions {
+                legacy: true,
+                standard: true,
+                emit_decorator_metadata: true,
+            },
             jsx: JsxOptions {
                 development: true,
                 refresh: Some(ReactRefreshOptions::default()),
                 ..JsxOptions::default()
             },
             env: EnvOptions::enable_all(/* include_unfinished_plugins */ false),
+            modules: ModulesOptions::default(),
             proposals: ProposalOptions::default(),
-            plugins: PluginsOptions { styled_components: Some(StyledComponentsOptions::default()) },
+            plugins: PluginsOptions {
+                styled_components: Some(StyledComponentsOptions::default()),
+                emotion: Some(EmotionOptions::default()),
+                graphql_tag: Some(GraphQLTagOptions::default()),
+                module_runner_transform: false,
+            },
             helper_loader: HelperLoaderOptions {
                 mode: HelperLoaderMode::Runtime,
                 ..Default::default()
@@ -162,6 +177,11 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let decorator = DecoratorOptions {
             legacy: options.plugins.legacy_decorator.is_some(),
+            standard: options
+                .plugins
+                .proposal_decorators
+                .as_ref()
+                .is_some_and(|o| o.version == "2023-11"),
             emit_decorator_metadata: options
                 .plugins
                 .legacy_decorator
@@ -250,6 +270,15 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             class_properties: options.plugins.class_properties.or(env.es2022.class_properties),
         };
 
+        let mut polyfills = env.polyfills;
+        if let Some(polyfill_corejs3) = options.plugins.polyfill_corejs3 {
+            // Without `preset-env` targets, polyfill everything, same as Babel.
+            if options.presets.env.is_none() {
+                polyfills = PolyfillsOptions::all(None);
+            }
+            polyfills.method = Some(polyfill_corejs3.method);
+        }
+
         if !errors.is_empty() {
             return Err(errors);
         }
@@ -263,10 +292,28 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             ..HelperLoaderOptions::default()
         };
 
+        let mut modules = options
+            .plugins
+            .modules_amd
+            .as_ref()
+            .or(options.plugins.modules_umd.as_ref())
+            .or(options.plugins.modules_systemjs.as_ref())
+            .cloned()
+            .unwrap_or_default();
+        if modules.module_id.is_none() {
+            modules.module_id.clone_from(&options.module_id);
+        }
+
         let mut plugins = PluginsOptions::default();
         if let Some(styled_components) = &options.plugins.styled_components {
             plugins.styled_components = Some(styled_components.clone());
         }
+        if let Some(emotion) = &options.plugins.emotion {
+            plugins.emotion = Some(emotion.clone());
+        }
+        if let Some(graphql_tag) = &options.plugins.graphql_tag {
+            plugins.graphql_tag = Some(graphql_tag.clone());
+        }
 
         Ok(Self {
             cwd: options.cwd.clone().unwrap_or_default(),
@@ -285,7 +332,9 @@ impl TryFrom<&BabelOptions> for TransformOptions {
                 es2020,
                 es2021,
                 es2022,
+                polyfills,
             },
+            modules,
             proposals: ProposalOptions {
                 explicit_resource_management: options.plugins.explicit_resource_management,
             },
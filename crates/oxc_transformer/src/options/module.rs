@@ -10,7 +10,7 @@ use crate::options::babel::BabelModule;
 /// - esbuild: <https://esbuild.github.io/api/#format>
 /// - Babel: <https://babeljs.io/docs/babel-preset-env#modules>
 /// - TypeScript: <https://www.typescriptlang.org/tsconfig/#module>
-#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
 #[serde(try_from = "BabelModule")]
 #[non_exhaustive]
 pub enum Module {
@@ -18,6 +18,12 @@ pub enum Module {
     Preserve,
     Esm,
     CommonJS,
+    /// Asynchronous Module Definition, `define([...], function (...) {})`.
+    Amd,
+    /// Universal Module Definition, which works as AMD, CommonJS or a browser global.
+    Umd,
+    /// SystemJS, `System.register([...], function (_export, _context) {})`.
+    SystemJs,
 }
 
 impl Module {
@@ -30,6 +36,11 @@ impl Module {
     pub fn is_commonjs(self) -> bool {
         matches!(self, Self::CommonJS)
     }
+
+    /// Check if the module is AMD, UMD or SystemJS.
+    pub fn is_wrapped(self) -> bool {
+        matches!(self, Self::Amd | Self::Umd | Self::SystemJs)
+    }
 }
 
 impl TryFrom<BabelModule> for Module {
@@ -38,8 +49,13 @@ impl TryFrom<BabelModule> for Module {
     fn try_from(value: BabelModule) -> Result<Self, Self::Error> {
         match value {
             BabelModule::Commonjs => Ok(Self::CommonJS),
+            BabelModule::Amd => Ok(Self::Amd),
+            BabelModule::Umd => Ok(Self::Umd),
+            BabelModule::Systemjs => Ok(Self::SystemJs),
             BabelModule::Auto | BabelModule::Boolean(false) => Ok(Self::Preserve),
-            _ => Err(Error::msg(format!("{value:?} module is not implemented."))),
+            BabelModule::Boolean(true) => {
+                Err(Error::msg(format!("{value:?} module is not implemented.")))
+            }
         }
     }
 }
@@ -50,6 +66,12 @@ impl TryFrom<&BabelPlugins> for Module {
     fn try_from(value: &BabelPlugins) -> Result<Self, Self::Error> {
         if value.modules_commonjs {
             Ok(Self::CommonJS)
+        } else if value.modules_amd.is_some() {
+            Ok(Self::Amd)
+        } else if value.modules_umd.is_some() {
+            Ok(Self::Umd)
+        } else if value.modules_systemjs.is_some() {
+            Ok(Self::SystemJs)
         } else {
             Err(Error::msg("Doesn't find any transform-modules-* plugin."))
         }
@@ -0,0 +1,1310 @@
+//! Emotion
+//!
+//! This plugin minifies styles, precompiles static styles, and adds labels and source maps
+//! to improve the debugging experience when using Emotion.
+//!
+//! > This plugin is port from the official Babel plugin for Emotion.
+//!
+//! ## Implementation Status
+//!
+//! > Note: Same as the styled-components plugin, this plugin only supports Emotion imported via
+//! import statements. `require("@emotion/react")` is not transformed.
+//!
+//! ### Options:
+//! **✅ Fully Supported:**
+//! - `sourceMap`: Adds inline source maps to styles in development
+//! - `autoLabel`: Adds labels to class names
+//! - `labelFormat`: Controls the format of labels
+//! - `cssPropOptimization`: Precompiles object and array styles of the `css` prop
+//! - `importMap`: Treats exports of other packages as Emotion exports
+//!
+//! **⚠️ Partially Supported:**
+//! - Precompiled styles are not hoisted to the top level scope
+//! - The `target` of styled components is a hash of the file path relative to `cwd`,
+//!   the package name is not included
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import { css } from '@emotion/react';
+//!
+//! const button = css`
+//!   color: hotpink;
+//! `;
+//! ```
+//!
+//! Output (with `sourceMap: false`):
+//! ```js
+//! function _EMOTION_STRINGIFIED_CSS_ERROR__() { return "You have tried to stringify ..."; }
+//! import { css } from '@emotion/react';
+//!
+//! const button = process.env.NODE_ENV === "production" ? {
+//!   name: "3sn2xs",
+//!   styles: "color:hotpink"
+//! } : {
+//!   name: "1jvmdft-button",
+//!   styles: "color:hotpink;label:button;",
+//!   toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+//! };
+//! ```
+//!
+//! ## References
+//!
+//! - Babel plugin: <https://github.com/emotion-js/emotion/tree/main/packages/babel-plugin>
+//! - Documentation: <https://emotion.sh/docs/@emotion/babel-plugin>
+
+use std::{borrow::Cow, fmt::Write, path::Path};
+
+use cow_utils::CowUtils;
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, SymbolFlags, SymbolId};
+use oxc_sourcemap::SourceMapBuilder;
+use oxc_span::SPAN;
+use oxc_syntax::number::ToJsString;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use super::styled_components::minify_template_literal;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct EmotionOptions {
+    /// Adds inline source maps to styles, so that browser devtools can show where they are declared.
+    ///
+    /// Source maps are only included when `process.env.NODE_ENV !== "production"`.
+    ///
+    /// Default: `true`
+    pub source_map: bool,
+
+    /// Controls when labels are added to styles. A label is appended to the generated class name,
+    /// e.g. `css-1f2g3h-button` instead of `css-1f2g3h`.
+    ///
+    /// Default: [`EmotionAutoLabel::DevOnly`]
+    pub auto_label: EmotionAutoLabel,
+
+    /// Format of labels.
+    ///
+    /// * `[local]`: Name of the variable the styles are assigned to.
+    /// * `[filename]`: Name of the file without extension. For `index` files, the directory name.
+    /// * `[dirname]`: Name of the directory containing the file.
+    ///
+    /// Example: With `labelFormat: "[dirname]--[filename]--[local]"`, styles assigned to `button`
+    /// in `components/Button.js` are labelled `components--Button--button`.
+    ///
+    /// Default: `"[local]"`
+    pub label_format: String,
+
+    /// Precompiles object and array styles passed to the JSX `css` prop, by wrapping them in
+    /// a call to `css` from `@emotion/react`.
+    ///
+    /// Only applies to files which import from `@emotion/react`, or set
+    /// `@jsxImportSource @emotion/react`, as the styles are only handled by `@emotion/react`'s
+    /// `jsx` function.
+    ///
+    /// Default: `true`
+    pub css_prop_optimization: bool,
+
+    /// Treats exports of other packages as Emotion exports.
+    ///
+    /// Keyed by package name, then by export name.
+    ///
+    /// ```json
+    /// {
+    ///   "my-package": {
+    ///     "myStyled": {
+    ///       "canonicalImport": ["@emotion/styled", "default"],
+    ///       "styledBaseImport": ["my-package/base", "something"]
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Default: `{}`
+    pub import_map: FxHashMap<String, FxHashMap<String, EmotionImportMapEntry>>,
+}
+
+impl Default for EmotionOptions {
+    fn default() -> Self {
+        Self {
+            source_map: true,
+            auto_label: EmotionAutoLabel::default(),
+            label_format: String::from("[local]"),
+            css_prop_optimization: true,
+            import_map: FxHashMap::default(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EmotionAutoLabel {
+    /// Only add labels when `process.env.NODE_ENV !== "production"`.
+    #[default]
+    DevOnly,
+    /// Always add labels.
+    Always,
+    /// Never add labels.
+    Never,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EmotionImportMapEntry {
+    /// `[package, export]` of the Emotion export which this export behaves like,
+    /// e.g. `["@emotion/react", "css"]`.
+    pub canonical_import: (String, String),
+
+    /// `[package, export]` of the function called instead of this export, when it behaves like
+    /// `@emotion/styled`. Defaults to this export itself.
+    #[serde(default)]
+    pub styled_base_import: Option<(String, String)>,
+}
+
+/// Message returned by `toString` of precompiled styles.
+const STRINGIFIED_CSS_ERROR: &str = "You have tried to stringify object returned from `css` function. It isn't supposed to be used directly (e.g. as value of the `className` prop), but rather handed to emotion so it can handle it (e.g. as value of `css` prop).";
+
+/// Emotion exports, which are transformed.
+#[derive(Clone, Copy)]
+enum CanonicalExport {
+    /// `css` from `@emotion/react`, which returns precompiled styles as is
+    ReactCss,
+    /// `css`, `keyframes` and `injectGlobal` from `@emotion/css`, and `keyframes` from `@emotion/react`
+    Css { pure: bool },
+    /// Default export of `@emotion/styled`
+    Styled,
+}
+
+impl CanonicalExport {
+    fn from_import(source: &str, imported: &str) -> Option<Self> {
+        match (source, imported) {
+            ("@emotion/react", "css") => Some(Self::ReactCss),
+            ("@emotion/react" | "@emotion/css", "keyframes") | ("@emotion/css", "css") => {
+                Some(Self::Css { pure: true })
+            }
+            ("@emotion/css", "injectGlobal") => Some(Self::Css { pure: false }),
+            ("@emotion/styled", "default") => Some(Self::Styled),
+            _ => None,
+        }
+    }
+}
+
+/// An imported binding which is an Emotion export.
+enum EmotionBinding<'a> {
+    /// `css`, `keyframes` or `injectGlobal`
+    Css {
+        pure: bool,
+        /// Replace the whole call with precompiled styles, instead of only its arguments
+        replace_call: bool,
+    },
+    /// `styled`, which is replaced with a call to the base `styled` function
+    Styled { source: Atom<'a>, imported: Atom<'a> },
+}
+
+pub struct Emotion<'a, 'ctx> {
+    pub options: EmotionOptions,
+    pub ctx: &'ctx TransformCtx<'a>,
+
+    // State
+    /// Imported bindings of Emotion exports
+    bindings: FxHashMap<SymbolId, EmotionBinding<'a>>,
+    /// Added imports, keyed by source and imported name
+    imports: FxHashMap<(Atom<'a>, Atom<'a>), BoundIdentifier<'a>>,
+    /// `_EMOTION_STRINGIFIED_CSS_ERROR__` function, which is added when styles are precompiled
+    stringified_css_error: Option<BoundIdentifier<'a>>,
+    /// Counter for generating unique targets of styled components
+    target_count: usize,
+    /// Hash of the current file for target generation
+    target_prefix: Option<String>,
+    /// Whether the file uses `@emotion/react`, so that styles of the `css` prop are transformed
+    transform_css_prop: bool,
+}
+
+impl<'a, 'ctx> Emotion<'a, 'ctx> {
+    pub fn new(options: EmotionOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            options,
+            ctx,
+            bindings: FxHashMap::default(),
+            imports: FxHashMap::default(),
+            stringified_css_error: None,
+            target_count: 0,
+            target_prefix: None,
+            transform_css_prop: false,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Emotion<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.collect_bindings(program, ctx);
+    }
+
+    #[inline] // Because it's a hot path, and most `Expression`s are not calls or styles of `css` prop
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::TaggedTemplateExpression(_) | Expression::CallExpression(_) => {
+                if !self.bindings.is_empty() {
+                    self.transform_emotion_expression(expr, ctx);
+                }
+            }
+            Expression::ObjectExpression(_) | Expression::ArrayExpression(_) => {
+                if self.transform_css_prop {
+                    self.transform_css_prop(expr, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Emotion<'a, '_> {
+    /// Collects import bindings of Emotion exports
+    fn collect_bindings(&mut self, program: &Program<'a>, ctx: &TraverseCtx<'a>) {
+        if self.options.css_prop_optimization {
+            self.transform_css_prop = program.comments.iter().any(|comment| {
+                comment
+                    .content_span()
+                    .source_text(program.source_text)
+                    .contains("@jsxImportSource @emotion/react")
+            });
+        }
+
+        for statement in &program.body {
+            let Statement::ImportDeclaration(import) = statement else { continue };
+            if import.source.value == "@emotion/react" {
+                self.transform_css_prop |= self.options.css_prop_optimization;
+            }
+            let Some(specifiers) = &import.specifiers else { continue };
+            if import.import_kind.is_type() {
+                continue;
+            }
+            let source = import.source.value;
+            let import_map = self.options.import_map.get(source.as_str());
+            if import_map.is_none() && !source.starts_with("@emotion/") {
+                continue;
+            }
+
+            for specifier in specifiers {
+                let (imported, local) = match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        if specifier.import_kind.is_type() {
+                            continue;
+                        }
+                        (specifier.imported.name(), &specifier.local)
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                        (Atom::from("default"), &specifier.local)
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => continue,
+                };
+
+                let mapped = import_map.and_then(|import_map| import_map.get(imported.as_str()));
+                let export = match mapped {
+                    Some(entry) => {
+                        let (source, imported) = &entry.canonical_import;
+                        CanonicalExport::from_import(source, imported)
+                    }
+                    None => CanonicalExport::from_import(&source, &imported),
+                };
+                let binding = match export {
+                    None => continue,
+                    Some(CanonicalExport::ReactCss) => {
+                        EmotionBinding::Css { pure: true, replace_call: true }
+                    }
+                    Some(CanonicalExport::Css { pure }) => {
+                        EmotionBinding::Css { pure, replace_call: false }
+                    }
+                    Some(CanonicalExport::Styled) => match mapped {
+                        Some(EmotionImportMapEntry {
+                            styled_base_import: Some((base_source, base_imported)),
+                            ..
+                        }) => EmotionBinding::Styled {
+                            source: ctx.ast.atom(base_source),
+                            imported: ctx.ast.atom(base_imported),
+                        },
+                        Some(_) => EmotionBinding::Styled { source, imported },
+                        None => EmotionBinding::Styled {
+                            source: Atom::from("@emotion/styled/base"),
+                            imported: Atom::from("default"),
+                        },
+                    },
+                };
+                self.bindings.insert(local.symbol_id(), binding);
+            }
+        }
+    }
+
+    /// Transforms calls and tagged templates of `css`, `keyframes`, `injectGlobal` and `styled`.
+    fn transform_emotion_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let callee = match expr {
+            Expression::TaggedTemplateExpression(tagged) => &tagged.tag,
+            Expression::CallExpression(call) => &call.callee,
+            _ => unreachable!(),
+        };
+
+        match callee {
+            // `css\`\`` or `css()`
+            Expression::Identifier(ident) => {
+                if let Some(&EmotionBinding::Css { pure, replace_call }) =
+                    self.get_binding(ident, ctx)
+                {
+                    self.transform_css(expr, pure, replace_call, ctx);
+                }
+            }
+            // `styled.div\`\`` or `styled.div()`
+            Expression::StaticMemberExpression(member) => {
+                if matches!(&member.object, Expression::Identifier(ident)
+                    if matches!(self.get_binding(ident, ctx), Some(EmotionBinding::Styled { .. })))
+                {
+                    self.transform_styled(expr, ctx);
+                }
+            }
+            // `styled(Component)\`\`` or `styled(Component)()`
+            Expression::CallExpression(call) => {
+                if matches!(&call.callee, Expression::Identifier(ident)
+                    if matches!(self.get_binding(ident, ctx), Some(EmotionBinding::Styled { .. })))
+                {
+                    self.transform_styled(expr, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// ```js
+    /// css`color: ${color};`
+    /// // transforms into
+    /// /*#__PURE__*/css("color:", color, ";" + (process.env.NODE_ENV === "production" ? "" : ";label:foo;"));
+    /// ```
+    fn transform_css(
+        &mut self,
+        expr: &mut Expression<'a>,
+        pure: bool,
+        replace_call: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let source_map_offset = Self::convert_to_call_expression(expr, ctx);
+        let Expression::CallExpression(call) = expr else { unreachable!() };
+
+        match self.transform_styles_arguments(&mut call.arguments, source_map_offset, true, ctx) {
+            Some(styles) if replace_call => {
+                if let Expression::Identifier(ident) = &call.callee {
+                    ctx.delete_reference_for_identifier(ident);
+                }
+                *expr = styles;
+            }
+            Some(styles) => {
+                call.arguments[0] = Argument::from(styles);
+                call.pure = pure;
+            }
+            None => call.pure = pure,
+        }
+    }
+
+    /// ```js
+    /// styled.div`color: red;`
+    /// // transforms into
+    /// /*#__PURE__*/_styled("div", { target: "e1abc0", label: "Foo" })(precompiledStyles);
+    /// ```
+    fn transform_styled(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let source_map_offset = Self::convert_to_call_expression(expr, ctx);
+        let Expression::CallExpression(call) = expr else { unreachable!() };
+
+        // `styled.div` -> `_styled("div")`, `styled(Component)` -> `_styled(Component)`
+        let (styled, mut arguments) = match call.callee.take_in(ctx.ast) {
+            Expression::StaticMemberExpression(member) => {
+                let member = member.unbox();
+                let Expression::Identifier(styled) = member.object else { unreachable!() };
+                let tag = ctx.ast.expression_string_literal(
+                    member.property.span,
+                    member.property.name,
+                    None,
+                );
+                (styled, ctx.ast.vec1(Argument::from(tag)))
+            }
+            Expression::CallExpression(styled_call) => {
+                let styled_call = styled_call.unbox();
+                let Expression::Identifier(styled) = styled_call.callee else { unreachable!() };
+                (styled, styled_call.arguments)
+            }
+            _ => unreachable!(),
+        };
+        let Some(EmotionBinding::Styled { source, imported }) = self.get_binding(&styled, ctx)
+        else {
+            unreachable!()
+        };
+        let (source, imported) = (*source, *imported);
+        ctx.delete_reference_for_identifier(&styled);
+        let base = self.get_or_add_import(source, imported, "styled", ctx);
+
+        // Add `target` and `label` to options
+        let options = if arguments.len() >= 2 { Some(arguments.remove(1)) } else { None };
+        let options = self.create_styled_options(options, ctx);
+        arguments.insert(1, options);
+        call.callee = ctx.ast.expression_call(styled.span, base, NONE, arguments, false);
+
+        if let Some(styles) =
+            self.transform_styles_arguments(&mut call.arguments, source_map_offset, false, ctx)
+        {
+            call.arguments[0] = Argument::from(styles);
+        }
+        call.pure = true;
+    }
+
+    /// ```jsx
+    /// <div css={{ color: "red" }} />
+    /// // transforms into
+    /// <div css={process.env.NODE_ENV === "production" ? { name: "...", styles: "color:red" } : ...} />
+    /// ```
+    fn transform_css_prop(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !matches!(ctx.parent(), Ancestor::JSXExpressionContainerExpression(_)) {
+            return;
+        }
+        let Ancestor::JSXAttributeValue(attribute) = ctx.ancestor(1) else { return };
+        if !matches!(attribute.name(), JSXAttributeName::Identifier(name) if name.name == "css") {
+            return;
+        }
+        let source_map_offset = attribute.span().start;
+
+        let mut arguments =
+            match expr.take_in(ctx.ast) {
+                Expression::ObjectExpression(object) => {
+                    ctx.ast.vec1(Argument::ObjectExpression(object))
+                }
+                Expression::ArrayExpression(array) => ctx.ast.vec_from_iter(
+                    array.unbox().elements.into_iter().filter_map(|element| match element {
+                        ArrayExpressionElement::SpreadElement(spread) => {
+                            Some(Argument::SpreadElement(spread))
+                        }
+                        ArrayExpressionElement::Elision(_) => None,
+                        element => Some(Argument::from(element.into_expression())),
+                    }),
+                ),
+                _ => unreachable!(),
+            };
+        if let Some(styles) =
+            self.transform_styles_arguments(&mut arguments, source_map_offset, true, ctx)
+        {
+            *expr = styles;
+        } else {
+            // Styles are dynamic, `_css(...styles)`
+            let css =
+                self.get_or_add_import(Atom::from("@emotion/react"), Atom::from("css"), "css", ctx);
+            *expr = ctx.ast.expression_call(SPAN, css, NONE, arguments, false);
+        }
+    }
+
+    /// Convert tagged template to call expression with minified styles.
+    ///
+    /// `` css`color: ${color};` `` -> `css("color:", color, ";")`
+    ///
+    /// Returns offset of the styles in source text, for source maps.
+    fn convert_to_call_expression(expr: &mut Expression<'a>, ctx: &TraverseCtx<'a>) -> u32 {
+        let Expression::TaggedTemplateExpression(tagged) = expr else {
+            let Expression::CallExpression(call) = expr else { unreachable!() };
+            return call.span.start;
+        };
+        let source_map_offset = tagged.quasi.span.start;
+
+        let TaggedTemplateExpression { span, tag, mut quasi, type_arguments } =
+            tagged.take_in(ctx.ast.allocator);
+        minify_template_literal(&mut quasi, ctx.ast);
+
+        let TemplateLiteral { quasis, expressions, .. } = quasi;
+        let mut arguments = ctx.ast.vec_with_capacity(quasis.len() + expressions.len());
+        let mut expressions = expressions.into_iter();
+        for quasi in quasis {
+            if !quasi.value.raw.is_empty() {
+                arguments.push(Argument::from(ctx.ast.expression_string_literal(
+                    quasi.span,
+                    quasi.value.raw,
+                    None,
+                )));
+            }
+            if let Some(expression) = expressions.next() {
+                arguments.push(Argument::from(expression));
+            }
+        }
+        if arguments.is_empty() {
+            arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, "", None)));
+        }
+
+        *expr = ctx.ast.expression_call(span, tag, type_arguments, arguments, false);
+        source_map_offset
+    }
+
+    /// Simplify the styles in `arguments`, and add labels and source maps.
+    ///
+    /// Returns precompiled styles if the styles are static.
+    fn transform_styles_arguments(
+        &mut self,
+        arguments: &mut ArenaVec<'a, Argument<'a>>,
+        source_map_offset: u32,
+        should_label: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let can_append_strings = !arguments.iter().any(Argument::is_spread);
+
+        for argument in arguments.iter_mut() {
+            if let Argument::ObjectExpression(object) = argument {
+                if let Some(styles) = simplify_object(object) {
+                    *argument = Argument::from(ctx.ast.expression_string_literal(
+                        object.span,
+                        ctx.ast.atom(&styles),
+                        None,
+                    ));
+                }
+            }
+        }
+        join_string_literals(arguments, ctx);
+
+        let source_map = if self.options.source_map && can_append_strings {
+            self.create_source_map(source_map_offset)
+        } else {
+            None
+        };
+        let label = if should_label && self.options.auto_label != EmotionAutoLabel::Never {
+            self.get_label(ctx)
+        } else {
+            None
+        };
+
+        if let [Argument::StringLiteral(styles)] = arguments.as_slice() {
+            let styles = styles.value.as_str();
+            let styles = styles.strip_suffix(';').unwrap_or(styles);
+            return Some(self.create_precompiled_styles(
+                styles,
+                label.as_deref(),
+                source_map.as_deref(),
+                ctx,
+            ));
+        }
+
+        if can_append_strings {
+            if let Some(label) = label {
+                let label = format!(";label:{label};");
+                match self.options.auto_label {
+                    EmotionAutoLabel::DevOnly => {
+                        let label =
+                            ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&label), None);
+                        let label = create_node_env_conditional(
+                            ctx.ast.expression_string_literal(SPAN, "", None),
+                            label,
+                            ctx,
+                        );
+                        append_to_arguments(arguments, label, ctx);
+                    }
+                    EmotionAutoLabel::Always => {
+                        append_string_to_arguments(arguments, &label, ctx);
+                    }
+                    EmotionAutoLabel::Never => unreachable!(),
+                }
+            }
+        }
+        if let Some(source_map) = source_map {
+            let source_map =
+                ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&source_map), None);
+            let source_map = create_node_env_conditional(
+                ctx.ast.expression_string_literal(SPAN, "", None),
+                source_map,
+                ctx,
+            );
+            append_to_arguments(arguments, source_map, ctx);
+        }
+        None
+    }
+
+    /// ```js
+    /// process.env.NODE_ENV === "production" ? {
+    ///   name: "<hash>",
+    ///   styles: "<styles>"
+    /// } : {
+    ///   name: "<hash>-<label>",
+    ///   styles: "<styles>;label:<label>;",
+    ///   map: "<source map>",
+    ///   toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+    /// }
+    /// ```
+    fn create_precompiled_styles(
+        &mut self,
+        styles: &str,
+        label: Option<&str>,
+        source_map: Option<&str>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let labelled_styles = match label {
+            Some(label) => Cow::Owned(format!("{styles};label:{label};")),
+            None => Cow::Borrowed(styles),
+        };
+        let prod_styles = if self.options.auto_label == EmotionAutoLabel::Always {
+            &labelled_styles
+        } else {
+            styles
+        };
+
+        let prod = {
+            let properties = ctx.ast.vec_from_array([
+                create_string_property("name", &serialized_name(prod_styles), ctx),
+                create_string_property("styles", prod_styles, ctx),
+            ]);
+            ctx.ast.expression_object(SPAN, properties)
+        };
+
+        let dev = {
+            let mut properties = ctx.ast.vec_from_array([
+                create_string_property("name", &serialized_name(&labelled_styles), ctx),
+                create_string_property("styles", &labelled_styles, ctx),
+            ]);
+            if let Some(source_map) = source_map {
+                properties.push(create_string_property("map", source_map, ctx));
+            }
+            let to_string = self.get_stringified_css_error(ctx).create_read_expression(ctx);
+            properties.push(create_property("toString", to_string, ctx));
+            ctx.ast.expression_object(SPAN, properties)
+        };
+
+        create_node_env_conditional(prod, dev, ctx)
+    }
+
+    /// Create options of a styled component, with `target` and `label` added.
+    fn create_styled_options(
+        &mut self,
+        options: Option<Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Argument<'a> {
+        let has_property = |options: &Option<Argument<'a>>, name: &str| {
+            matches!(options, Some(Argument::ObjectExpression(object))
+                if object.properties.iter().any(|property| matches!(property,
+                    ObjectPropertyKind::ObjectProperty(property)
+                        if property.key.is_specific_static_name(name))))
+        };
+
+        let target = (!has_property(&options, "target")).then(|| self.get_target_class_name());
+        let label = if self.options.auto_label == EmotionAutoLabel::Never
+            || has_property(&options, "label")
+        {
+            None
+        } else {
+            self.get_label(ctx)
+        };
+        let dev_only_label =
+            label.as_ref().filter(|_| self.options.auto_label == EmotionAutoLabel::DevOnly);
+
+        let create_properties = |with_label: bool, ctx: &mut TraverseCtx<'a>| {
+            let mut properties = ctx.ast.vec();
+            if let Some(target) = &target {
+                properties.push(create_string_property("target", target, ctx));
+            }
+            if let Some(label) = label.as_deref().filter(|_| with_label) {
+                properties.push(create_string_property("label", label, ctx));
+            }
+            properties
+        };
+
+        let generated = if dev_only_label.is_some() {
+            let prod = ctx.ast.expression_object(SPAN, create_properties(false, ctx));
+            let dev = ctx.ast.expression_object(SPAN, create_properties(true, ctx));
+            create_node_env_conditional(prod, dev, ctx)
+        } else {
+            let properties = create_properties(true, ctx);
+            match options {
+                // `{ shouldForwardProp }` -> `{ shouldForwardProp, target: "e1abc0", label: "Foo" }`
+                Some(Argument::ObjectExpression(mut object)) => {
+                    object.properties.extend(properties);
+                    return Argument::ObjectExpression(object);
+                }
+                None => return Argument::from(ctx.ast.expression_object(SPAN, properties)),
+                Some(_) => ctx.ast.expression_object(SPAN, properties),
+            }
+        };
+
+        match options {
+            Some(Argument::ObjectExpression(object)) if object.properties.is_empty() => {
+                Argument::from(generated)
+            }
+            None => Argument::from(generated),
+            // `_extends({}, generated, options)`
+            Some(options) => {
+                let arguments = ctx.ast.vec_from_array([
+                    Argument::from(ctx.ast.expression_object(SPAN, ctx.ast.vec())),
+                    Argument::from(generated),
+                    options,
+                ]);
+                self.ctx.helper_call_expr(Helper::Extends, SPAN, arguments, ctx).into()
+            }
+        }
+    }
+
+    fn get_binding(
+        &self,
+        ident: &IdentifierReference<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<&EmotionBinding<'a>> {
+        let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        self.bindings.get(&symbol_id)
+    }
+
+    /// Get binding of an import which is added by this plugin, adding the import if needed.
+    fn get_or_add_import(
+        &mut self,
+        source: Atom<'a>,
+        imported: Atom<'a>,
+        name: &str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let binding = self.imports.entry((source, imported)).or_insert_with(|| {
+            let binding = ctx.generate_uid_in_root_scope(name, SymbolFlags::Import);
+            if imported == "default" {
+                self.ctx.module_imports.add_default_import(source, binding.clone(), false);
+            } else {
+                self.ctx.module_imports.add_named_import(source, imported, binding.clone(), false);
+            }
+            binding
+        });
+        binding.create_read_expression(ctx)
+    }
+
+    /// `function _EMOTION_STRINGIFIED_CSS_ERROR__() { return "..."; }`
+    fn get_stringified_css_error(&mut self, ctx: &mut TraverseCtx<'a>) -> &BoundIdentifier<'a> {
+        self.stringified_css_error.get_or_insert_with(|| {
+            let binding = ctx.generate_uid_in_root_scope(
+                "EMOTION_STRINGIFIED_CSS_ERROR__",
+                SymbolFlags::Function,
+            );
+            let scope_id = ctx.create_child_scope(
+                ctx.scoping().root_scope_id(),
+                ScopeFlags::Function | ScopeFlags::StrictMode,
+            );
+            let params = ctx.ast.formal_parameters(
+                SPAN,
+                FormalParameterKind::FormalParameter,
+                ctx.ast.vec(),
+                NONE,
+            );
+            let message = ctx.ast.expression_string_literal(SPAN, STRINGIFIED_CSS_ERROR, None);
+            let body = ctx.ast.function_body(
+                SPAN,
+                ctx.ast.vec(),
+                ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(message))),
+            );
+            let function = ctx.ast.alloc_plain_function_with_scope_id(
+                FunctionType::FunctionDeclaration,
+                SPAN,
+                Some(binding.create_binding_identifier(ctx)),
+                params,
+                body,
+                scope_id,
+            );
+            self.ctx
+                .top_level_statements
+                .insert_statement(Statement::FunctionDeclaration(function));
+            binding
+        })
+    }
+
+    /// `e<file_hash><target_count>`
+    ///
+    /// The file hash is based on the source path relative to `cwd`, or source text if the source
+    /// path is not inside `cwd`.
+    fn get_target_class_name(&mut self) -> String {
+        let prefix = self.target_prefix.get_or_insert_with(|| {
+            // Hash of the path relative to `cwd`, so that targets are stable across machines
+            let relative_path = Some(self.ctx.cwd.as_path())
+                .filter(|cwd| !cwd.as_os_str().is_empty())
+                .and_then(|cwd| self.ctx.source_path.strip_prefix(cwd).ok());
+            let hash = match relative_path {
+                Some(path) => {
+                    let path = path.to_string_lossy();
+                    hash_string(&format!("/{}", path.cow_replace('\\', "/")))
+                }
+                None => hash_string(self.ctx.source_text),
+            };
+            format!("e{hash}")
+        });
+        let target = format!("{prefix}{}", self.target_count);
+        self.target_count += 1;
+        target
+    }
+
+    /// Get label of styles, based on `labelFormat` option.
+    fn get_label(&self, ctx: &TraverseCtx<'a>) -> Option<String> {
+        let name = get_identifier_name(ctx)?;
+        let local = sanitize_label_part(&name);
+
+        let label_format = self.options.label_format.as_str();
+        if label_format == "[local]" {
+            return Some(local.into_owned());
+        }
+
+        let source_path = self.ctx.source_path.as_path();
+        let dirname = source_path
+            .parent()
+            .and_then(Path::file_name)
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        let filename = match source_path.file_stem().and_then(|stem| stem.to_str()) {
+            Some("index") | None => dirname,
+            Some(filename) => filename,
+        };
+        let label = label_format
+            .cow_replace("[local]", &local)
+            .cow_replace("[filename]", &sanitize_label_part(filename))
+            .cow_replace("[dirname]", &sanitize_label_part(dirname))
+            .into_owned();
+        Some(label)
+    }
+
+    /// Create an inline source map, which maps the styles to `offset` in source text.
+    fn create_source_map(&self, offset: u32) -> Option<String> {
+        let file_name = self.ctx.source_path.file_name()?.to_str()?;
+        let source_text = self.ctx.source_text;
+
+        // Line and UTF-16 column of `offset`
+        let before = &source_text[..offset as usize];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        #[expect(clippy::cast_possible_truncation)]
+        let line = before.matches('\n').count() as u32;
+        #[expect(clippy::cast_possible_truncation)]
+        let column = before[line_start..].encode_utf16().count() as u32;
+
+        let mut builder = SourceMapBuilder::default();
+        builder.set_file(file_name);
+        let source_id = builder.add_source_and_content(file_name, source_text);
+        builder.add_token(0, 0, line, column, Some(source_id), None);
+        let source_map = builder.into_sourcemap();
+        Some(format!("/*# sourceMappingURL={} */", source_map.to_data_url()))
+    }
+}
+
+/// Infers name of the styles from the variable declarator, assignment expression, object property,
+/// class or function which contains them.
+fn get_identifier_name<'a>(ctx: &TraverseCtx<'a>) -> Option<Cow<'a, str>> {
+    // `{ foo: css`` }`
+    if let Ancestor::ObjectPropertyValue(property) = ctx.parent() {
+        if !*property.computed() {
+            match property.key() {
+                PropertyKey::StaticIdentifier(ident) => return Some(ident.name.as_str().into()),
+                PropertyKey::StringLiteral(lit) => return Some(lit.value.as_str().into()),
+                _ => {}
+            }
+        }
+    }
+
+    // `class Foo { bar = css`` }`
+    for ancestor in ctx.ancestors() {
+        match ancestor {
+            Ancestor::PropertyDefinitionValue(property) => {
+                if let PropertyKey::StaticIdentifier(ident) = property.key() {
+                    if !*property.computed() {
+                        return Some(ident.name.as_str().into());
+                    }
+                }
+                break;
+            }
+            Ancestor::ClassBody(class) => {
+                if let Some(id) = class.id() {
+                    return Some(id.name.as_str().into());
+                }
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    let name = get_declarator_name(ctx)?;
+    // Names starting with `_` are probably generated, so ignore them
+    if name.starts_with('_') { None } else { Some(name) }
+}
+
+fn get_declarator_name<'a>(ctx: &TraverseCtx<'a>) -> Option<Cow<'a, str>> {
+    for ancestor in ctx.ancestors() {
+        match ancestor {
+            // `const foo = css``;`
+            Ancestor::VariableDeclaratorInit(declarator) => {
+                return declarator.id().get_binding_identifier().map(|id| id.name.as_str().into());
+            }
+            // `foo = css``;` or `foo.bar = css``;`
+            Ancestor::AssignmentExpressionRight(assignment) => {
+                return match assignment.left() {
+                    AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                        Some(ident.name.as_str().into())
+                    }
+                    AssignmentTarget::StaticMemberExpression(member) => {
+                        // `a.b.c` -> `a-b-c`
+                        let mut name = member.property.name.to_string();
+                        let mut object = &member.object;
+                        loop {
+                            match object {
+                                Expression::Identifier(ident) => {
+                                    name.insert(0, '-');
+                                    name.insert_str(0, &ident.name);
+                                    break Some(name.into());
+                                }
+                                Expression::StaticMemberExpression(member) => {
+                                    name.insert(0, '-');
+                                    name.insert_str(0, &member.property.name);
+                                    object = &member.object;
+                                }
+                                _ => break None,
+                            }
+                        }
+                    }
+                    _ => None,
+                };
+            }
+            // `function Foo() { return <div css={{}} />; }`
+            Ancestor::FunctionBody(function) => match function.id() {
+                Some(id) => return Some(id.name.as_str().into()),
+                None if *function.r#type() == FunctionType::FunctionDeclaration => return None,
+                None => {}
+            },
+            // `{ foo: () => css`` }`
+            Ancestor::ObjectPropertyValue(property) => {
+                if *property.computed() {
+                    break;
+                }
+                return property.key().static_name();
+            }
+            _ => {}
+        }
+    }
+
+    // Computed property key, use the closest variable declarator
+    ctx.ancestors().find_map(|ancestor| match ancestor {
+        Ancestor::VariableDeclaratorInit(declarator) => {
+            declarator.id().get_binding_identifier().map(|id| id.name.as_str().into())
+        }
+        _ => None,
+    })
+}
+
+/// Replace characters which are invalid in class names with `-`.
+fn sanitize_label_part(label: &str) -> Cow<'_, str> {
+    const INVALID: &[char] = &[
+        '!', '"', '#', '$', '%', '&', '\'', '(', ')', '*', '+', ',', '.', '/', ':', ';', '<', '=',
+        '>', '?', '@', '[', '\\', ']', '^', '`', '{', '|', '}', '~',
+    ];
+    let label = label.trim();
+    if label.contains(INVALID) {
+        Cow::Owned(label.chars().map(|c| if INVALID.contains(&c) { '-' } else { c }).collect())
+    } else {
+        Cow::Borrowed(label)
+    }
+}
+
+/// Convert an object of static styles into a CSS string.
+///
+/// `{ fontSize: 12, ":hover": { color: "red" } }` -> `font-size:12px;:hover{color:red;}`
+///
+/// Returns `None` if the object is not static.
+fn simplify_object(object: &ObjectExpression<'_>) -> Option<String> {
+    let mut styles = String::new();
+    for property in &object.properties {
+        let ObjectPropertyKind::ObjectProperty(property) = property else { return None };
+        if property.computed {
+            return None;
+        }
+        let key = match &property.key {
+            PropertyKey::StaticIdentifier(ident) => ident.name.as_str(),
+            PropertyKey::StringLiteral(lit) => lit.value.as_str(),
+            _ => return None,
+        };
+        if key == "styles" {
+            return None;
+        }
+        match &property.value {
+            Expression::ObjectExpression(object) => {
+                let child = simplify_object(object)?;
+                write!(styles, "{key}{{{child}}}").unwrap();
+            }
+            Expression::StringLiteral(lit) => {
+                write!(styles, "{}:{};", process_style_name(key), lit.value).unwrap();
+            }
+            Expression::NumericLiteral(lit) => {
+                let value = lit.value.to_js_string();
+                let unit = if lit.value == 0.0 || is_custom_property(key) || is_unitless(key) {
+                    ""
+                } else {
+                    "px"
+                };
+                write!(styles, "{}:{value}{unit};", process_style_name(key)).unwrap();
+            }
+            _ => return None,
+        }
+    }
+    Some(styles)
+}
+
+fn is_custom_property(name: &str) -> bool {
+    name.starts_with("--")
+}
+
+/// `fontSize` -> `font-size`, `msFlex` -> `-ms-flex`
+fn process_style_name(name: &str) -> Cow<'_, str> {
+    if is_custom_property(name)
+        || !(name.starts_with("ms") || name.contains(|c: char| c.is_ascii_uppercase()))
+    {
+        return Cow::Borrowed(name);
+    }
+    let mut result = String::with_capacity(name.len() + 4);
+    if name.starts_with("ms") {
+        result.push('-');
+    }
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            result.push('-');
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    Cow::Owned(result)
+}
+
+/// Properties which don't have a unit, from `@emotion/unitless`.
+fn is_unitless(name: &str) -> bool {
+    matches!(
+        name,
+        "animationIterationCount"
+            | "aspectRatio"
+            | "borderImageOutset"
+            | "borderImageSlice"
+            | "borderImageWidth"
+            | "boxFlex"
+            | "boxFlexGroup"
+            | "boxOrdinalGroup"
+            | "columnCount"
+            | "columns"
+            | "flex"
+            | "flexGrow"
+            | "flexPositive"
+            | "flexShrink"
+            | "flexNegative"
+            | "flexOrder"
+            | "gridRow"
+            | "gridRowEnd"
+            | "gridRowSpan"
+            | "gridRowStart"
+            | "gridColumn"
+            | "gridColumnEnd"
+            | "gridColumnSpan"
+            | "gridColumnStart"
+            | "msGridRow"
+            | "msGridRowSpan"
+            | "msGridColumn"
+            | "msGridColumnSpan"
+            | "fontWeight"
+            | "lineHeight"
+            | "opacity"
+            | "order"
+            | "orphans"
+            | "scale"
+            | "tabSize"
+            | "widows"
+            | "zIndex"
+            | "zoom"
+            | "WebkitLineClamp"
+            | "fillOpacity"
+            | "floodOpacity"
+            | "stopOpacity"
+            | "strokeDasharray"
+            | "strokeDashoffset"
+            | "strokeMiterlimit"
+            | "strokeOpacity"
+            | "strokeWidth"
+    )
+}
+
+/// Join adjacent string literals, `css("a", "b", c)` -> `css("ab", c)`
+fn join_string_literals<'a>(arguments: &mut ArenaVec<'a, Argument<'a>>, ctx: &TraverseCtx<'a>) {
+    if !arguments
+        .windows(2)
+        .any(|pair| matches!(pair, [Argument::StringLiteral(_), Argument::StringLiteral(_)]))
+    {
+        return;
+    }
+
+    let mut joined = ctx.ast.vec_with_capacity(arguments.len());
+    for argument in arguments.drain(..) {
+        if let (Some(Argument::StringLiteral(last)), Argument::StringLiteral(lit)) =
+            (joined.last_mut(), &argument)
+        {
+            last.value = ctx.ast.atom_from_strs_array([&last.value, &lit.value]);
+            last.span = Span::new(last.span.start, lit.span.end);
+            last.raw = None;
+            continue;
+        }
+        joined.push(argument);
+    }
+    *arguments = joined;
+}
+
+/// Append a string to the last argument if it's a string literal, otherwise add a new argument.
+fn append_string_to_arguments<'a>(
+    arguments: &mut ArenaVec<'a, Argument<'a>>,
+    string: &str,
+    ctx: &TraverseCtx<'a>,
+) {
+    if let Some(Argument::StringLiteral(last)) = arguments.last_mut() {
+        last.value = ctx.ast.atom_from_strs_array([&last.value, string]);
+        last.raw = None;
+    } else {
+        arguments.push(Argument::from(ctx.ast.expression_string_literal(
+            SPAN,
+            ctx.ast.atom(string),
+            None,
+        )));
+    }
+}
+
+/// Concatenate an expression to the last argument if it's a string literal,
+/// otherwise add a new argument.
+fn append_to_arguments<'a>(
+    arguments: &mut ArenaVec<'a, Argument<'a>>,
+    expr: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) {
+    if let Some(last @ Argument::StringLiteral(_)) = arguments.last_mut() {
+        let left = last.to_expression_mut().take_in(ctx.ast);
+        *last =
+            Argument::from(ctx.ast.expression_binary(SPAN, left, BinaryOperator::Addition, expr));
+    } else {
+        arguments.push(Argument::from(expr));
+    }
+}
+
+/// `process.env.NODE_ENV === "production" ? prod : dev`
+fn create_node_env_conditional<'a>(
+    prod: Expression<'a>,
+    dev: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let process = ctx.create_unbound_ident_expr(SPAN, Atom::from("process"), ReferenceFlags::Read);
+    let env = ctx.ast.member_expression_static(
+        SPAN,
+        process,
+        ctx.ast.identifier_name(SPAN, "env"),
+        false,
+    );
+    let node_env = ctx.ast.member_expression_static(
+        SPAN,
+        Expression::from(env),
+        ctx.ast.identifier_name(SPAN, "NODE_ENV"),
+        false,
+    );
+    let test = ctx.ast.expression_binary(
+        SPAN,
+        Expression::from(node_env),
+        BinaryOperator::StrictEquality,
+        ctx.ast.expression_string_literal(SPAN, "production", None),
+    );
+    ctx.ast.expression_conditional(SPAN, test, prod, dev)
+}
+
+/// `key: "value"`
+fn create_string_property<'a>(
+    key: &'static str,
+    value: &str,
+    ctx: &TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    let value = ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(value), None);
+    create_property(key, value, ctx)
+}
+
+/// `key: value`
+fn create_property<'a>(
+    key: &'static str,
+    value: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    let key = ctx.ast.property_key_static_identifier(SPAN, key);
+    ctx.ast.object_property_kind_object_property(
+        SPAN,
+        PropertyKind::Init,
+        key,
+        value,
+        false,
+        false,
+        false,
+    )
+}
+
+/// Name of serialized styles, i.e. hash of the styles followed by their labels.
+///
+/// `color:red;label:foo;` -> `<hash>-foo`
+fn serialized_name(styles: &str) -> String {
+    let mut name = hash_string(styles);
+    // Same as `/label:\s*([^\s;{]+)\s*(;|$)/g`
+    let mut rest = styles;
+    while let Some(index) = rest.find("label:") {
+        rest = &rest[index + "label:".len()..];
+        let value = rest.trim_start();
+        let end =
+            value.find(|c: char| c.is_whitespace() || c == ';' || c == '{').unwrap_or(value.len());
+        if end == 0 {
+            continue;
+        }
+        let after = value[end..].trim_start();
+        if after.is_empty() || after.starts_with(';') {
+            name.push('-');
+            name.push_str(&value[..end]);
+            rest = &value[end..];
+        }
+    }
+    name
+}
+
+/// MurmurHash2 of the UTF-16 code units of `s` in base 36, same as `@emotion/hash`.
+fn hash_string(s: &str) -> String {
+    const M: u32 = 0x5bd1_e995;
+
+    let units = s.encode_utf16().map(|unit| u32::from(unit & 0xff)).collect::<Vec<_>>();
+    let mut h: u32 = 0;
+    let mut chunks = units.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = chunk[0] | (chunk[1] << 8) | (chunk[2] << 16) | (chunk[3] << 24);
+        k = k.wrapping_mul(M);
+        k ^= k >> 24;
+        h = k.wrapping_mul(M) ^ h.wrapping_mul(M);
+    }
+    let rest = chunks.remainder();
+    if rest.len() >= 3 {
+        h ^= rest[2] << 16;
+    }
+    if rest.len() >= 2 {
+        h ^= rest[1] << 8;
+    }
+    if !rest.is_empty() {
+        h ^= rest[0];
+        h = h.wrapping_mul(M);
+    }
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^= h >> 15;
+
+    // Base 36
+    let mut digits = vec![];
+    loop {
+        digits.push(b"0123456789abcdefghijklmnopqrstuvwxyz"[(h % 36) as usize]);
+        h /= 36;
+        if h == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
@@ -0,0 +1,557 @@
+//! GraphQL Tag
+//!
+//! This plugin precompiles GraphQL queries written with `graphql-tag`'s `gql` tagged templates
+//! into `DocumentNode` object literals, so that queries are not parsed at runtime.
+//!
+//! > This plugin is port from [babel-plugin-graphql-tag](https://github.com/gajus/babel-plugin-graphql-tag).
+//!
+//! ## Implementation Status
+//!
+//! > Note: Same as the styled-components plugin, this plugin only supports `gql` imported via
+//! import statements, or names listed in `gqlTagIdentifiers`.
+//!
+//! ### Options:
+//! **✅ Fully Supported:**
+//! - `importSources`: Packages which export the `gql` tag
+//! - `onlyMatchImportSuffix`: Match the end of import sources instead of the whole source
+//! - `gqlTagIdentifiers`: Names of `gql` tags which are not imported
+//! - `strip`: Strips whitespace, commas and comments from the source in `loc`
+//! - `removeLoc`: Removes `loc` of the document (not available in the Babel plugin)
+//!
+//! **⚠️ Partially Supported:**
+//! - Type system definitions (SDL) are not supported, such tagged templates are left untouched
+//! - `undefined` fields of AST nodes, e.g. `alias` of fields without alias, are omitted
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import gql from 'graphql-tag';
+//!
+//! const query = gql`
+//!   query User { user { ...UserFields } }
+//!   ${UserFields}
+//! `;
+//! ```
+//!
+//! Output:
+//! ```js
+//! function _unique(definitions) {
+//!   var names = {};
+//!   return definitions.filter(function(definition) {
+//!     if (definition.kind !== "FragmentDefinition") return true;
+//!     var name = definition.name.value;
+//!     if (names[name]) return false;
+//!     names[name] = true;
+//!     return true;
+//!   });
+//! }
+//!
+//! const query = {
+//!   kind: "Document",
+//!   definitions: _unique([{ kind: "OperationDefinition", operation: "query", ... }].concat(UserFields.definitions)),
+//!   loc: { start: 0, end: 38, source: { body: "...", name: "GraphQL request", locationOffset: { line: 1, column: 1 } } }
+//! };
+//! ```
+//!
+//! ## References
+//!
+//! - Babel plugin: <https://github.com/gajus/babel-plugin-graphql-tag>
+//! - graphql-tag: <https://github.com/apollographql/graphql-tag>
+
+mod parser;
+
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::number::NumberBase;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use parser::Node;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct GraphQLTagOptions {
+    /// Packages which export the `gql` tag, as the default export or the `gql` export.
+    ///
+    /// Default: `["graphql-tag"]`
+    pub import_sources: Vec<String>,
+
+    /// Match import sources which end with one of [`GraphQLTagOptions::import_sources`],
+    /// e.g. `@my-org/graphql-tag`, instead of the whole source.
+    ///
+    /// Default: `false`
+    pub only_match_import_suffix: bool,
+
+    /// Names of `gql` tags which are compiled without being imported, e.g. a global `gql`.
+    ///
+    /// Default: `[]`
+    pub gql_tag_identifiers: Vec<String>,
+
+    /// Strips whitespace, commas and comments from the GraphQL source kept in `loc`,
+    /// to reduce the size of the output.
+    ///
+    /// Default: `false`
+    pub strip: bool,
+
+    /// Removes `loc` of the document, which contains the GraphQL source.
+    ///
+    /// `loc` is used by Apollo Client to print queries, and by `graphql-tag` to cache fragments.
+    /// Only remove it when they are not needed.
+    ///
+    /// Default: `false`
+    pub remove_loc: bool,
+}
+
+impl Default for GraphQLTagOptions {
+    fn default() -> Self {
+        Self {
+            import_sources: vec![String::from("graphql-tag")],
+            only_match_import_suffix: false,
+            gql_tag_identifiers: vec![],
+            strip: false,
+            remove_loc: false,
+        }
+    }
+}
+
+pub struct GraphQLTag<'a, 'ctx> {
+    pub options: GraphQLTagOptions,
+    pub ctx: &'ctx TransformCtx<'a>,
+
+    // State
+    /// Imported bindings of `gql` tags
+    tags: FxHashSet<SymbolId>,
+    /// `_unique` function, which is added when fragments are interpolated
+    unique: Option<BoundIdentifier<'a>>,
+}
+
+impl<'a, 'ctx> GraphQLTag<'a, 'ctx> {
+    pub fn new(options: GraphQLTagOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx, tags: FxHashSet::default(), unique: None }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for GraphQLTag<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.collect_tags(program);
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.remove_unused_imports(program, ctx);
+    }
+
+    #[inline] // Because it's a hot path, and most `Expression`s are not tagged templates
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::TaggedTemplateExpression(tagged) = expr
+            && self.is_gql_tag(&tagged.tag, ctx)
+        {
+            self.transform_tagged_template(expr, ctx);
+        }
+    }
+}
+
+impl<'a> GraphQLTag<'a, '_> {
+    fn is_import_source(&self, source: &str) -> bool {
+        self.options.import_sources.iter().any(|import_source| {
+            if self.options.only_match_import_suffix {
+                source.ends_with(import_source.as_str())
+            } else {
+                source == import_source
+            }
+        })
+    }
+
+    /// Collects import bindings of `gql` tags
+    fn collect_tags(&mut self, program: &Program<'a>) {
+        for statement in &program.body {
+            let Statement::ImportDeclaration(import) = statement else { continue };
+            if import.import_kind.is_type() || !self.is_import_source(&import.source.value) {
+                continue;
+            }
+            let Some(specifiers) = &import.specifiers else { continue };
+            for specifier in specifiers {
+                match specifier {
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                        self.tags.insert(specifier.local.symbol_id());
+                    }
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier)
+                        if specifier.imported.name() == "gql" =>
+                    {
+                        self.tags.insert(specifier.local.symbol_id());
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn is_gql_tag(&self, tag: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Expression::Identifier(ident) = tag else { return false };
+        match ctx.scoping().get_reference(ident.reference_id()).symbol_id() {
+            Some(symbol_id) => self.tags.contains(&symbol_id),
+            None => self.options.gql_tag_identifiers.iter().any(|name| ident.name == name),
+        }
+    }
+
+    /// ```js
+    /// gql`query { a } ${Fragment}`
+    /// // transforms into
+    /// { kind: "Document", definitions: _unique([...].concat(Fragment.definitions)), loc: {...} }
+    /// ```
+    fn transform_tagged_template(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::TaggedTemplateExpression(tagged) = expr else { unreachable!() };
+        let quasi = &mut tagged.quasi;
+
+        for expression in &quasi.expressions {
+            if !matches!(
+                expression,
+                Expression::Identifier(_) | Expression::StaticMemberExpression(_)
+            ) {
+                self.ctx.error(
+                    OxcDiagnostic::error("Only identifiers or member expressions are allowed by this plugin as an interpolation in a graphql template literal.")
+                        .with_label(expression.span()),
+                );
+                return;
+            }
+        }
+
+        let source = quasi.quasis.iter().map(|quasi| quasi.value.raw.as_str()).collect::<String>();
+        let document = match self.compile(&source) {
+            Ok(document) => document,
+            // Leave type system definitions to be parsed at runtime
+            Err(None) => return,
+            Err(Some(message)) => {
+                self.ctx.error(OxcDiagnostic::error(message).with_label(quasi.span));
+                return;
+            }
+        };
+
+        let mut fragments = quasi.expressions.take_in(ctx.ast);
+        let Node::Object(fields) = document else { unreachable!() };
+        let mut properties = ctx.ast.vec_with_capacity(fields.len());
+        for (key, value) in fields {
+            let mut value = Self::create_value(value, ctx);
+            if key == "definitions" && !fragments.is_empty() {
+                let fragments = fragments.take_in(ctx.ast);
+                value = self.create_definitions_with_fragments(value, fragments, ctx);
+            }
+            properties.push(create_object_property(key, value, ctx));
+        }
+
+        if let Expression::Identifier(ident) = &tagged.tag {
+            ctx.delete_reference_for_identifier(ident);
+        }
+        *expr = ctx.ast.expression_object(tagged.span, properties);
+    }
+
+    /// Parse the GraphQL source into a document.
+    ///
+    /// Returns `Err(None)` for documents which are not supported.
+    fn compile(&self, source: &str) -> Result<Node, Option<String>> {
+        let stripped;
+        let source = if self.options.strip {
+            stripped = parser::strip_ignored_characters(source).map_err(Some)?;
+            stripped.as_str()
+        } else {
+            source
+        };
+        let mut document = match parser::parse(source) {
+            Ok(document) => document,
+            Err(err) if err.starts_with("Syntax Error") => return Err(Some(err)),
+            Err(_) => return Err(None),
+        };
+        let Node::Object(fields) = &mut document else { unreachable!() };
+
+        // If a document contains only one operation, that operation may be unnamed.
+        if let Some((_, Node::Array(definitions))) = fields.iter().find(|(key, _)| *key == "definitions")
+            && definitions.len() > 1
+            && definitions.iter().any(|definition| {
+                matches!(definition, Node::Object(fields) if !fields.iter().any(|(key, _)| *key == "name"))
+            })
+        {
+            return Err(Some("GraphQL query must have name.".to_string()));
+        }
+
+        if self.options.remove_loc {
+            fields.retain(|(key, _)| *key != "loc");
+        }
+        Ok(document)
+    }
+
+    fn create_value(node: Node, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        match node {
+            Node::Object(fields) => ctx.ast.expression_object(
+                SPAN,
+                ctx.ast.vec_from_iter(fields.into_iter().map(|(key, value)| {
+                    create_object_property(key, Self::create_value(value, ctx), ctx)
+                })),
+            ),
+            Node::Array(elements) => {
+                ctx.ast.expression_array(
+                    SPAN,
+                    ctx.ast.vec_from_iter(elements.into_iter().map(|element| {
+                        ArrayExpressionElement::from(Self::create_value(element, ctx))
+                    })),
+                )
+            }
+            Node::String(value) => {
+                ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&value), None)
+            }
+            Node::Boolean(value) => ctx.ast.expression_boolean_literal(SPAN, value),
+            #[expect(clippy::cast_precision_loss)]
+            Node::Number(value) => {
+                ctx.ast.expression_numeric_literal(SPAN, value as f64, None, NumberBase::Decimal)
+            }
+        }
+    }
+
+    /// `_unique(definitions.concat(Fragment.definitions, ...))`
+    fn create_definitions_with_fragments(
+        &mut self,
+        definitions: Expression<'a>,
+        fragments: ArenaVec<'a, Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let concat = create_member(definitions, "concat", ctx);
+        let arguments = ctx.ast.vec_from_iter(
+            fragments
+                .into_iter()
+                .map(|fragment| Argument::from(create_member(fragment, "definitions", ctx))),
+        );
+        let definitions = ctx.ast.expression_call(SPAN, concat, NONE, arguments, false);
+        let unique = self.get_unique(ctx).create_read_expression(ctx);
+        ctx.ast.expression_call(
+            SPAN,
+            unique,
+            NONE,
+            ctx.ast.vec1(Argument::from(definitions)),
+            false,
+        )
+    }
+
+    /// ```js
+    /// function _unique(definitions) {
+    ///   var names = {};
+    ///   return definitions.filter(function(definition) {
+    ///     if (definition.kind !== "FragmentDefinition") return true;
+    ///     var name = definition.name.value;
+    ///     if (names[name]) return false;
+    ///     names[name] = true;
+    ///     return true;
+    ///   });
+    /// }
+    /// ```
+    fn get_unique(&mut self, ctx: &mut TraverseCtx<'a>) -> &BoundIdentifier<'a> {
+        self.unique.get_or_insert_with(|| {
+            let binding = ctx.generate_uid_in_root_scope("unique", SymbolFlags::Function);
+            let scope_id =
+                ctx.create_child_scope(ctx.scoping().root_scope_id(), ScopeFlags::Function);
+            let definitions = ctx.generate_binding(
+                Atom::from("definitions"),
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let names = ctx.generate_binding(
+                Atom::from("names"),
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+
+            // `function(definition) { ... }`
+            let filter_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::Function);
+            let definition = ctx.generate_binding(
+                Atom::from("definition"),
+                filter_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let name = ctx.generate_binding(
+                Atom::from("name"),
+                filter_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let return_bool = |value: bool, ctx: &TraverseCtx<'a>| {
+                ctx.ast
+                    .statement_return(SPAN, Some(ctx.ast.expression_boolean_literal(SPAN, value)))
+            };
+            let names_member = |ctx: &mut TraverseCtx<'a>| {
+                let object = names.create_read_expression(ctx);
+                let property = name.create_read_expression(ctx);
+                ctx.ast.member_expression_computed(SPAN, object, property, false)
+            };
+            let is_fragment = {
+                let kind = create_member(definition.create_read_expression(ctx), "kind", ctx);
+                ctx.ast.expression_binary(
+                    SPAN,
+                    kind,
+                    BinaryOperator::StrictInequality,
+                    ctx.ast.expression_string_literal(SPAN, "FragmentDefinition", None),
+                )
+            };
+            let name_value = {
+                let object = create_member(definition.create_read_expression(ctx), "name", ctx);
+                create_member(object, "value", ctx)
+            };
+            let has_name = Expression::from(names_member(ctx));
+            let set_name = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::from(names_member(ctx)),
+                ctx.ast.expression_boolean_literal(SPAN, true),
+            );
+            let filter_body = ctx.ast.vec_from_array([
+                ctx.ast.statement_if(SPAN, is_fragment, return_bool(true, ctx), None),
+                create_var_declaration(name.create_binding_pattern(ctx), name_value, ctx),
+                ctx.ast.statement_if(SPAN, has_name, return_bool(false, ctx), None),
+                ctx.ast.statement_expression(SPAN, set_name),
+                return_bool(true, ctx),
+            ]);
+            let filter = create_function(
+                None,
+                definition.create_binding_pattern(ctx),
+                filter_body,
+                filter_scope_id,
+                ctx,
+            );
+
+            let filter_call = {
+                let callee = create_member(definitions.create_read_expression(ctx), "filter", ctx);
+                ctx.ast.expression_call(
+                    SPAN,
+                    callee,
+                    NONE,
+                    ctx.ast.vec1(Argument::from(filter)),
+                    false,
+                )
+            };
+            let body = ctx.ast.vec_from_array([
+                create_var_declaration(
+                    names.create_binding_pattern(ctx),
+                    ctx.ast.expression_object(SPAN, ctx.ast.vec()),
+                    ctx,
+                ),
+                ctx.ast.statement_return(SPAN, Some(filter_call)),
+            ]);
+            let Expression::FunctionExpression(mut function) = create_function(
+                Some(binding.create_binding_identifier(ctx)),
+                definitions.create_binding_pattern(ctx),
+                body,
+                scope_id,
+                ctx,
+            ) else {
+                unreachable!()
+            };
+            function.r#type = FunctionType::FunctionDeclaration;
+            self.ctx
+                .top_level_statements
+                .insert_statement(Statement::FunctionDeclaration(function));
+            binding
+        })
+    }
+
+    /// Removes imports of `gql` tags which are no longer referenced.
+    fn remove_unused_imports(&self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.tags.is_empty() {
+            return;
+        }
+        let root_scope_id = ctx.scoping().root_scope_id();
+        program.body.retain_mut(|statement| {
+            let Statement::ImportDeclaration(import) = statement else { return true };
+            let Some(specifiers) = &mut import.specifiers else { return true };
+            let len = specifiers.len();
+            specifiers.retain(|specifier| {
+                let symbol_id = specifier.local().symbol_id();
+                let unused = self.tags.contains(&symbol_id)
+                    && ctx.scoping().get_resolved_reference_ids(symbol_id).is_empty();
+                if unused {
+                    ctx.scoping_mut().remove_binding(root_scope_id, &specifier.local().name);
+                }
+                !unused
+            });
+            // Keep side effect imports, e.g. `import "graphql-tag"`
+            len == 0 || !specifiers.is_empty()
+        });
+    }
+}
+
+/// `object.property`
+fn create_member<'a>(
+    object: Expression<'a>,
+    property: &'static str,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    Expression::from(ctx.ast.member_expression_static(
+        SPAN,
+        object,
+        ctx.ast.identifier_name(SPAN, property),
+        false,
+    ))
+}
+
+/// `key: value`
+fn create_object_property<'a>(
+    key: &'static str,
+    value: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    ctx.ast.object_property_kind_object_property(
+        SPAN,
+        PropertyKind::Init,
+        ctx.ast.property_key_static_identifier(SPAN, key),
+        value,
+        false,
+        false,
+        false,
+    )
+}
+
+/// `var id = init;`
+fn create_var_declaration<'a>(
+    id: BindingPattern<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let kind = VariableDeclarationKind::Var;
+    let declarator = ctx.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
+
+/// `function id(param) { body }`
+fn create_function<'a>(
+    id: Option<BindingIdentifier<'a>>,
+    param: BindingPattern<'a>,
+    body: ArenaVec<'a, Statement<'a>>,
+    scope_id: ScopeId,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let params = ctx.ast.formal_parameters(
+        SPAN,
+        FormalParameterKind::FormalParameter,
+        ctx.ast.vec1(ctx.ast.plain_formal_parameter(SPAN, param)),
+        NONE,
+    );
+    let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), body);
+    Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+        FunctionType::FunctionExpression,
+        SPAN,
+        id,
+        params,
+        body,
+        scope_id,
+    ))
+}
@@ -0,0 +1,821 @@
+//! Parser of GraphQL executable documents.
+//!
+//! Produces the same AST as `parse` of [graphql-js](https://github.com/graphql/graphql-js/blob/v16.9.0/src/language/parser.ts),
+//! as plain values which can be converted to JS object literals.
+//! `undefined` fields, e.g. `alias` of fields without alias, are omitted.
+//!
+//! Type system definitions (SDL) are not supported.
+
+use cow_utils::CowUtils;
+
+/// A value of GraphQL AST.
+#[derive(Debug)]
+pub enum Node {
+    Object(Vec<(&'static str, Node)>),
+    Array(Vec<Node>),
+    String(String),
+    Boolean(bool),
+    Number(usize),
+}
+
+impl Node {
+    /// AST node with `kind` as the first field.
+    fn ast(kind: &'static str, fields: impl IntoIterator<Item = (&'static str, Node)>) -> Self {
+        let mut object = vec![("kind", Self::String(kind.to_string()))];
+        object.extend(fields);
+        Self::Object(object)
+    }
+}
+
+/// Parse a GraphQL document.
+///
+/// Same as `graphql-tag`, `loc` is only kept on the document, and `loc` of other nodes is removed.
+///
+/// # Errors
+///
+/// * Syntax errors, and type system definitions which are not supported.
+pub fn parse(body: &str) -> Result<Node, String> {
+    let tokens = lex(body)?;
+    let mut parser = Parser { body, tokens, index: 0 };
+    let definitions = parser.many(TokenKind::Sof, Parser::parse_definition, TokenKind::Eof)?;
+    // `Location` of the document spans from the start of the source to the end of the last token
+    let end = parser.tokens[parser.tokens.len() - 2].end;
+    let loc = Node::Object(vec![
+        ("start", Node::Number(0)),
+        ("end", Node::Number(utf16_len(&body[..end]))),
+        (
+            "source",
+            Node::Object(vec![
+                ("body", Node::String(body.to_string())),
+                ("name", Node::String("GraphQL request".to_string())),
+                (
+                    "locationOffset",
+                    Node::Object(vec![("line", Node::Number(1)), ("column", Node::Number(1))]),
+                ),
+            ]),
+        ),
+    ]);
+    Ok(Node::ast("Document", [("definitions", Node::Array(definitions)), ("loc", loc)]))
+}
+
+/// Remove whitespace, commas and comments, same as `stripIgnoredCharacters` of graphql-js.
+///
+/// # Errors
+///
+/// * Syntax errors of tokens.
+pub fn strip_ignored_characters(body: &str) -> Result<String, String> {
+    let mut stripped = String::with_capacity(body.len());
+    let mut was_last_non_punctuator = false;
+    for token in lex(body)? {
+        if matches!(token.kind, TokenKind::Sof | TokenKind::Eof) {
+            continue;
+        }
+        let is_non_punctuator = !token.kind.is_punctuator();
+        if was_last_non_punctuator && (is_non_punctuator || token.kind == TokenKind::Spread) {
+            stripped.push(' ');
+        }
+        if token.kind == TokenKind::BlockString {
+            stripped.push_str(&print_block_string_minimized(&token.value));
+        } else {
+            stripped.push_str(&body[token.start..token.end]);
+        }
+        was_last_non_punctuator = is_non_punctuator;
+    }
+    Ok(stripped)
+}
+
+fn utf16_len(s: &str) -> usize {
+    s.chars().map(char::len_utf16).sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Sof,
+    Eof,
+    Bang,
+    Dollar,
+    Amp,
+    ParenL,
+    ParenR,
+    Spread,
+    Colon,
+    Equals,
+    At,
+    BracketL,
+    BracketR,
+    BraceL,
+    Pipe,
+    BraceR,
+    Name,
+    Int,
+    Float,
+    String,
+    BlockString,
+}
+
+impl TokenKind {
+    fn is_punctuator(self) -> bool {
+        !matches!(
+            self,
+            Self::Sof
+                | Self::Eof
+                | Self::Name
+                | Self::Int
+                | Self::Float
+                | Self::String
+                | Self::BlockString
+        )
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::Sof => "<SOF>",
+            Self::Eof => "<EOF>",
+            Self::Bang => "\"!\"",
+            Self::Dollar => "\"$\"",
+            Self::Amp => "\"&\"",
+            Self::ParenL => "\"(\"",
+            Self::ParenR => "\")\"",
+            Self::Spread => "\"...\"",
+            Self::Colon => "\":\"",
+            Self::Equals => "\"=\"",
+            Self::At => "\"@\"",
+            Self::BracketL => "\"[\"",
+            Self::BracketR => "\"]\"",
+            Self::BraceL => "\"{\"",
+            Self::Pipe => "\"|\"",
+            Self::BraceR => "\"}\"",
+            Self::Name => "Name",
+            Self::Int => "Int",
+            Self::Float => "Float",
+            Self::String => "String",
+            Self::BlockString => "BlockString",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Token {
+    kind: TokenKind,
+    start: usize,
+    end: usize,
+    /// Value of names, numbers and strings
+    value: String,
+}
+
+fn syntax_error(message: impl std::fmt::Display) -> String {
+    format!("Syntax Error: {message}")
+}
+
+fn lex(body: &str) -> Result<Vec<Token>, String> {
+    let bytes = body.as_bytes();
+    let mut tokens = vec![Token { kind: TokenKind::Sof, start: 0, end: 0, value: String::new() }];
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let start = pos;
+        let punctuator = match bytes[pos] {
+            b' ' | b'\t' | b',' | b'\n' | b'\r' => {
+                pos += 1;
+                continue;
+            }
+            b'#' => {
+                while pos < bytes.len() && !matches!(bytes[pos], b'\n' | b'\r') {
+                    pos += 1;
+                }
+                continue;
+            }
+            b'!' => Some(TokenKind::Bang),
+            b'$' => Some(TokenKind::Dollar),
+            b'&' => Some(TokenKind::Amp),
+            b'(' => Some(TokenKind::ParenL),
+            b')' => Some(TokenKind::ParenR),
+            b':' => Some(TokenKind::Colon),
+            b'=' => Some(TokenKind::Equals),
+            b'@' => Some(TokenKind::At),
+            b'[' => Some(TokenKind::BracketL),
+            b']' => Some(TokenKind::BracketR),
+            b'{' => Some(TokenKind::BraceL),
+            b'|' => Some(TokenKind::Pipe),
+            b'}' => Some(TokenKind::BraceR),
+            _ => None,
+        };
+        if let Some(kind) = punctuator {
+            pos += 1;
+            tokens.push(Token { kind, start, end: pos, value: String::new() });
+            continue;
+        }
+
+        let token = match bytes[pos] {
+            b'.' => {
+                if !body[pos..].starts_with("...") {
+                    return Err(syntax_error("Unexpected character: \".\"."));
+                }
+                pos += 3;
+                Token { kind: TokenKind::Spread, start, end: pos, value: String::new() }
+            }
+            b'_' | b'a'..=b'z' | b'A'..=b'Z' => {
+                while pos < bytes.len()
+                    && (bytes[pos] == b'_' || bytes[pos].is_ascii_alphanumeric())
+                {
+                    pos += 1;
+                }
+                Token {
+                    kind: TokenKind::Name,
+                    start,
+                    end: pos,
+                    value: body[start..pos].to_string(),
+                }
+            }
+            b'-' | b'0'..=b'9' => {
+                let (kind, end) = lex_number(bytes, pos)?;
+                pos = end;
+                Token { kind, start, end, value: body[start..end].to_string() }
+            }
+            b'"' if body[pos..].starts_with("\"\"\"") => {
+                let (value, end) = lex_block_string(body, pos)?;
+                pos = end;
+                Token { kind: TokenKind::BlockString, start, end, value }
+            }
+            b'"' => {
+                let (value, end) = lex_string(body, pos)?;
+                pos = end;
+                Token { kind: TokenKind::String, start, end, value }
+            }
+            _ => {
+                let c = body[pos..].chars().next().unwrap();
+                // Byte order mark
+                if c == '\u{feff}' {
+                    pos += c.len_utf8();
+                    continue;
+                }
+                return Err(syntax_error(format!("Unexpected character: {c:?}.")));
+            }
+        };
+        tokens.push(token);
+    }
+    tokens.push(Token {
+        kind: TokenKind::Eof,
+        start: body.len(),
+        end: body.len(),
+        value: String::new(),
+    });
+    Ok(tokens)
+}
+
+fn lex_number(bytes: &[u8], start: usize) -> Result<(TokenKind, usize), String> {
+    let mut pos = start;
+    let mut kind = TokenKind::Int;
+    let digits = |pos: &mut usize| -> Result<(), String> {
+        if !bytes.get(*pos).is_some_and(u8::is_ascii_digit) {
+            return Err(syntax_error("Invalid number, expected digit."));
+        }
+        while bytes.get(*pos).is_some_and(u8::is_ascii_digit) {
+            *pos += 1;
+        }
+        Ok(())
+    };
+    if bytes[pos] == b'-' {
+        pos += 1;
+    }
+    if bytes.get(pos) == Some(&b'0') {
+        pos += 1;
+        if bytes.get(pos).is_some_and(u8::is_ascii_digit) {
+            return Err(syntax_error("Invalid number, unexpected digit after 0."));
+        }
+    } else {
+        digits(&mut pos)?;
+    }
+    if bytes.get(pos) == Some(&b'.') {
+        kind = TokenKind::Float;
+        pos += 1;
+        digits(&mut pos)?;
+    }
+    if matches!(bytes.get(pos), Some(b'e' | b'E')) {
+        kind = TokenKind::Float;
+        pos += 1;
+        if matches!(bytes.get(pos), Some(b'+' | b'-')) {
+            pos += 1;
+        }
+        digits(&mut pos)?;
+    }
+    if bytes.get(pos).is_some_and(|&b| b == b'.' || b == b'_' || b.is_ascii_alphabetic()) {
+        return Err(syntax_error("Invalid number, expected digit."));
+    }
+    Ok((kind, pos))
+}
+
+fn lex_string(body: &str, start: usize) -> Result<(String, usize), String> {
+    let mut value = String::new();
+    let mut chars = body[start + 1..].char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((value, start + 1 + i + 1)),
+            '\n' | '\r' => break,
+            '\\' => {
+                let escaped = match chars.next().map(|(_, c)| c) {
+                    Some('"') => '"',
+                    Some('\\') => '\\',
+                    Some('/') => '/',
+                    Some('b') => '\u{8}',
+                    Some('f') => '\u{c}',
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('u') => {
+                        let (c, len) = lex_unicode_escape(chars.as_str())
+                            .ok_or_else(|| syntax_error("Invalid Unicode escape sequence."))?;
+                        for _ in 0..len {
+                            chars.next();
+                        }
+                        c
+                    }
+                    _ => return Err(syntax_error("Invalid character escape sequence.")),
+                };
+                value.push(escaped);
+            }
+            c => value.push(c),
+        }
+    }
+    Err(syntax_error("Unterminated string."))
+}
+
+/// Unicode escape sequence after `\u`, e.g. `00e9`, `{1F600}` or a surrogate pair `D83D\uDE00`.
+///
+/// Returns the character and the length of the escape sequence.
+fn lex_unicode_escape(rest: &str) -> Option<(char, usize)> {
+    if let Some(braced) = rest.strip_prefix('{') {
+        let end = braced.find('}')?;
+        let c = u32::from_str_radix(&braced[..end], 16).ok().and_then(char::from_u32)?;
+        return Some((c, end + 2));
+    }
+    let code = u32::from_str_radix(rest.get(..4)?, 16).ok()?;
+    if (0xD800..0xDC00).contains(&code) {
+        let low = rest.get(4..10)?.strip_prefix("\\u")?;
+        let low = u32::from_str_radix(low, 16).ok().filter(|low| (0xDC00..0xE000).contains(low))?;
+        let c = char::from_u32(0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00))?;
+        return Some((c, 10));
+    }
+    Some((char::from_u32(code)?, 4))
+}
+
+fn lex_block_string(body: &str, start: usize) -> Result<(String, usize), String> {
+    let content_start = start + 3;
+    let mut raw = String::new();
+    let mut pos = content_start;
+    while pos < body.len() {
+        let rest = &body[pos..];
+        if rest.starts_with("\"\"\"") {
+            return Ok((dedent_block_string(&raw), pos + 3));
+        }
+        if rest.starts_with("\\\"\"\"") {
+            raw.push_str("\"\"\"");
+            pos += 4;
+            continue;
+        }
+        let c = rest.chars().next().unwrap();
+        raw.push(c);
+        pos += c.len_utf8();
+    }
+    Err(syntax_error("Unterminated string."))
+}
+
+/// `dedentBlockStringLines` of graphql-js
+fn dedent_block_string(raw: &str) -> String {
+    let normalized = raw.cow_replace("\r\n", "\n");
+    let normalized = normalized.cow_replace('\r', "\n");
+    let lines = normalized.split('\n').collect::<Vec<_>>();
+    let mut common_indent = usize::MAX;
+    let mut first_non_empty_line = None;
+    let mut last_non_empty_line = None;
+    for (i, line) in lines.iter().enumerate() {
+        let indent = line.bytes().take_while(|b| matches!(b, b' ' | b'\t')).count();
+        if indent == line.len() {
+            continue;
+        }
+        first_non_empty_line.get_or_insert(i);
+        last_non_empty_line = Some(i);
+        if i != 0 && indent < common_indent {
+            common_indent = indent;
+        }
+    }
+    let Some(first) = first_non_empty_line else { return String::new() };
+    let last = last_non_empty_line.unwrap_or(first);
+    lines[first..=last]
+        .iter()
+        .enumerate()
+        .map(
+            |(i, line)| {
+                if i + first == 0 { *line } else { line.get(common_indent..).unwrap_or("") }
+            },
+        )
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `printBlockString` of graphql-js with `minimize: true`
+fn print_block_string_minimized(value: &str) -> String {
+    let escaped = value.cow_replace("\"\"\"", "\\\"\"\"");
+    let mut lines = escaped.split('\n');
+    lines.next();
+    let rest = lines.collect::<Vec<_>>();
+    let force_leading_new_line = !rest.is_empty()
+        && rest.iter().all(|line| line.is_empty() || line.starts_with([' ', '\t']));
+    let has_trailing_triple_quotes = escaped.ends_with("\\\"\"\"");
+    let force_trailing_new_line =
+        (value.ends_with('"') && !has_trailing_triple_quotes) || value.ends_with('\\');
+    let mut result = String::from("\"\"\"");
+    if force_leading_new_line {
+        result.push('\n');
+    }
+    result.push_str(&escaped);
+    if force_trailing_new_line {
+        result.push('\n');
+    }
+    result.push_str("\"\"\"");
+    result
+}
+
+struct Parser<'s> {
+    body: &'s str,
+    tokens: Vec<Token>,
+    index: usize,
+}
+
+type ParseResult<T> = Result<T, String>;
+
+impl Parser<'_> {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.index]
+    }
+
+    fn peek_kind(&self, kind: TokenKind) -> bool {
+        self.peek().kind == kind
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        self.peek_kind(TokenKind::Name) && self.peek().value == keyword
+    }
+
+    fn advance(&mut self) -> &Token {
+        let token = &self.tokens[self.index];
+        if token.kind != TokenKind::Eof {
+            self.index += 1;
+        }
+        token
+    }
+
+    fn unexpected(&self) -> String {
+        let token = self.peek();
+        let description = match token.kind {
+            TokenKind::Name | TokenKind::Int | TokenKind::Float => {
+                format!("{} \"{}\"", token.kind.description(), token.value)
+            }
+            TokenKind::String | TokenKind::BlockString => {
+                format!("{} {:?}", token.kind.description(), &self.body[token.start..token.end])
+            }
+            kind => kind.description().to_string(),
+        };
+        syntax_error(format!("Unexpected {description}."))
+    }
+
+    fn expect(&mut self, kind: TokenKind) -> ParseResult<&Token> {
+        if self.peek_kind(kind) {
+            return Ok(self.advance());
+        }
+        Err(syntax_error(format!("Expected {}, found {}.", kind.description(), self.found())))
+    }
+
+    /// Description of the next token in error messages.
+    fn found(&self) -> String {
+        let token = self.peek();
+        match token.kind {
+            TokenKind::Name => format!("Name \"{}\"", token.value),
+            kind => kind.description().to_string(),
+        }
+    }
+
+    fn expect_optional(&mut self, kind: TokenKind) -> bool {
+        let matched = self.peek_kind(kind);
+        if matched {
+            self.advance();
+        }
+        matched
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> ParseResult<()> {
+        if self.peek_keyword(keyword) {
+            self.advance();
+            Ok(())
+        } else {
+            Err(syntax_error(format!("Expected \"{keyword}\", found {}.", self.found())))
+        }
+    }
+
+    /// One or more nodes between `open` and `close`.
+    fn many(
+        &mut self,
+        open: TokenKind,
+        parse: fn(&mut Self) -> ParseResult<Node>,
+        close: TokenKind,
+    ) -> ParseResult<Vec<Node>> {
+        self.expect(open)?;
+        let mut nodes = vec![];
+        loop {
+            nodes.push(parse(self)?);
+            if self.expect_optional(close) {
+                return Ok(nodes);
+            }
+        }
+    }
+
+    /// Zero or more nodes between `open` and `close`.
+    fn any(
+        &mut self,
+        open: TokenKind,
+        parse: fn(&mut Self) -> ParseResult<Node>,
+        close: TokenKind,
+    ) -> ParseResult<Vec<Node>> {
+        self.expect(open)?;
+        let mut nodes = vec![];
+        while !self.expect_optional(close) {
+            nodes.push(parse(self)?);
+        }
+        Ok(nodes)
+    }
+
+    /// One or more nodes between `open` and `close`, or none if the next token is not `open`.
+    fn optional_many(
+        &mut self,
+        open: TokenKind,
+        parse: fn(&mut Self) -> ParseResult<Node>,
+        close: TokenKind,
+    ) -> ParseResult<Vec<Node>> {
+        if self.peek_kind(open) { self.many(open, parse, close) } else { Ok(vec![]) }
+    }
+
+    fn parse_definition(&mut self) -> ParseResult<Node> {
+        if self.peek_kind(TokenKind::BraceL) {
+            return Ok(Node::ast(
+                "OperationDefinition",
+                [
+                    ("operation", Node::String("query".to_string())),
+                    ("variableDefinitions", Node::Array(vec![])),
+                    ("directives", Node::Array(vec![])),
+                    ("selectionSet", self.parse_selection_set()?),
+                ],
+            ));
+        }
+        let token = self.peek();
+        match (token.kind, token.value.as_str()) {
+            (TokenKind::Name, "query" | "mutation" | "subscription") => {
+                self.parse_operation_definition()
+            }
+            (TokenKind::Name, "fragment") => self.parse_fragment_definition(),
+            (
+                TokenKind::Name,
+                "schema" | "scalar" | "type" | "interface" | "union" | "enum" | "input"
+                | "directive" | "extend",
+            )
+            | (TokenKind::String | TokenKind::BlockString, _) => {
+                Err("Type system definitions are not supported.".to_string())
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn parse_operation_definition(&mut self) -> ParseResult<Node> {
+        let operation = self.advance().value.clone();
+        let mut fields = vec![("operation", Node::String(operation))];
+        if self.peek_kind(TokenKind::Name) {
+            fields.push(("name", self.parse_name()?));
+        }
+        fields.push((
+            "variableDefinitions",
+            Node::Array(self.optional_many(
+                TokenKind::ParenL,
+                Self::parse_variable_definition,
+                TokenKind::ParenR,
+            )?),
+        ));
+        fields.push(("directives", self.parse_directives(false)?));
+        fields.push(("selectionSet", self.parse_selection_set()?));
+        Ok(Node::ast("OperationDefinition", fields))
+    }
+
+    fn parse_variable_definition(&mut self) -> ParseResult<Node> {
+        let mut fields = vec![("variable", self.parse_variable()?)];
+        self.expect(TokenKind::Colon)?;
+        fields.push(("type", self.parse_type_reference()?));
+        if self.expect_optional(TokenKind::Equals) {
+            fields.push(("defaultValue", self.parse_value_literal(true)?));
+        }
+        fields.push(("directives", self.parse_directives(true)?));
+        Ok(Node::ast("VariableDefinition", fields))
+    }
+
+    fn parse_variable(&mut self) -> ParseResult<Node> {
+        self.expect(TokenKind::Dollar)?;
+        Ok(Node::ast("Variable", [("name", self.parse_name()?)]))
+    }
+
+    fn parse_selection_set(&mut self) -> ParseResult<Node> {
+        let selections = self.many(TokenKind::BraceL, Self::parse_selection, TokenKind::BraceR)?;
+        Ok(Node::ast("SelectionSet", [("selections", Node::Array(selections))]))
+    }
+
+    fn parse_selection(&mut self) -> ParseResult<Node> {
+        if self.peek_kind(TokenKind::Spread) { self.parse_fragment() } else { self.parse_field() }
+    }
+
+    fn parse_field(&mut self) -> ParseResult<Node> {
+        let name_or_alias = self.parse_name()?;
+        let mut fields = vec![];
+        if self.expect_optional(TokenKind::Colon) {
+            fields.push(("alias", name_or_alias));
+            fields.push(("name", self.parse_name()?));
+        } else {
+            fields.push(("name", name_or_alias));
+        }
+        fields.push(("arguments", self.parse_arguments(false)?));
+        fields.push(("directives", self.parse_directives(false)?));
+        if self.peek_kind(TokenKind::BraceL) {
+            fields.push(("selectionSet", self.parse_selection_set()?));
+        }
+        Ok(Node::ast("Field", fields))
+    }
+
+    fn parse_arguments(&mut self, is_const: bool) -> ParseResult<Node> {
+        let parse = if is_const { Self::parse_const_argument } else { Self::parse_argument };
+        Ok(Node::Array(self.optional_many(TokenKind::ParenL, parse, TokenKind::ParenR)?))
+    }
+
+    fn parse_argument(&mut self) -> ParseResult<Node> {
+        self.parse_argument_impl(false)
+    }
+
+    fn parse_const_argument(&mut self) -> ParseResult<Node> {
+        self.parse_argument_impl(true)
+    }
+
+    fn parse_argument_impl(&mut self, is_const: bool) -> ParseResult<Node> {
+        let name = self.parse_name()?;
+        self.expect(TokenKind::Colon)?;
+        let value = self.parse_value_literal(is_const)?;
+        Ok(Node::ast("Argument", [("name", name), ("value", value)]))
+    }
+
+    fn parse_fragment(&mut self) -> ParseResult<Node> {
+        self.expect(TokenKind::Spread)?;
+        let has_type_condition = self.peek_keyword("on");
+        if has_type_condition {
+            self.advance();
+        } else if self.peek_kind(TokenKind::Name) {
+            let name = self.parse_fragment_name()?;
+            let directives = self.parse_directives(false)?;
+            return Ok(Node::ast("FragmentSpread", [("name", name), ("directives", directives)]));
+        }
+        let mut fields = vec![];
+        if has_type_condition {
+            fields.push(("typeCondition", self.parse_named_type()?));
+        }
+        fields.push(("directives", self.parse_directives(false)?));
+        fields.push(("selectionSet", self.parse_selection_set()?));
+        Ok(Node::ast("InlineFragment", fields))
+    }
+
+    fn parse_fragment_definition(&mut self) -> ParseResult<Node> {
+        self.expect_keyword("fragment")?;
+        let name = self.parse_fragment_name()?;
+        self.expect_keyword("on")?;
+        let type_condition = self.parse_named_type()?;
+        let directives = self.parse_directives(false)?;
+        let selection_set = self.parse_selection_set()?;
+        Ok(Node::ast(
+            "FragmentDefinition",
+            [
+                ("name", name),
+                ("typeCondition", type_condition),
+                ("directives", directives),
+                ("selectionSet", selection_set),
+            ],
+        ))
+    }
+
+    fn parse_fragment_name(&mut self) -> ParseResult<Node> {
+        if self.peek_keyword("on") {
+            return Err(self.unexpected());
+        }
+        self.parse_name()
+    }
+
+    fn parse_value_literal(&mut self, is_const: bool) -> ParseResult<Node> {
+        let token = self.peek();
+        let node = match token.kind {
+            TokenKind::BracketL => {
+                let parse = if is_const { Self::parse_const_value } else { Self::parse_value };
+                let values = self.any(TokenKind::BracketL, parse, TokenKind::BracketR)?;
+                return Ok(Node::ast("ListValue", [("values", Node::Array(values))]));
+            }
+            TokenKind::BraceL => {
+                let parse = if is_const {
+                    Self::parse_const_object_field
+                } else {
+                    Self::parse_object_field
+                };
+                let fields = self.any(TokenKind::BraceL, parse, TokenKind::BraceR)?;
+                return Ok(Node::ast("ObjectValue", [("fields", Node::Array(fields))]));
+            }
+            TokenKind::Int => Node::ast("IntValue", [("value", Node::String(token.value.clone()))]),
+            TokenKind::Float => {
+                Node::ast("FloatValue", [("value", Node::String(token.value.clone()))])
+            }
+            TokenKind::String | TokenKind::BlockString => Node::ast(
+                "StringValue",
+                [
+                    ("value", Node::String(token.value.clone())),
+                    ("block", Node::Boolean(token.kind == TokenKind::BlockString)),
+                ],
+            ),
+            TokenKind::Name => match token.value.as_str() {
+                "true" | "false" => {
+                    Node::ast("BooleanValue", [("value", Node::Boolean(token.value == "true"))])
+                }
+                "null" => Node::ast("NullValue", []),
+                value => Node::ast("EnumValue", [("value", Node::String(value.to_string()))]),
+            },
+            TokenKind::Dollar => {
+                if is_const {
+                    let name =
+                        self.tokens.get(self.index + 1).filter(|t| t.kind == TokenKind::Name);
+                    return Err(match name {
+                        Some(name) => syntax_error(format!(
+                            "Unexpected variable \"${}\" in constant value.",
+                            name.value
+                        )),
+                        None => self.unexpected(),
+                    });
+                }
+                return self.parse_variable();
+            }
+            _ => return Err(self.unexpected()),
+        };
+        self.advance();
+        Ok(node)
+    }
+
+    fn parse_value(&mut self) -> ParseResult<Node> {
+        self.parse_value_literal(false)
+    }
+
+    fn parse_const_value(&mut self) -> ParseResult<Node> {
+        self.parse_value_literal(true)
+    }
+
+    fn parse_object_field(&mut self) -> ParseResult<Node> {
+        self.parse_object_field_impl(false)
+    }
+
+    fn parse_const_object_field(&mut self) -> ParseResult<Node> {
+        self.parse_object_field_impl(true)
+    }
+
+    fn parse_object_field_impl(&mut self, is_const: bool) -> ParseResult<Node> {
+        let name = self.parse_name()?;
+        self.expect(TokenKind::Colon)?;
+        let value = self.parse_value_literal(is_const)?;
+        Ok(Node::ast("ObjectField", [("name", name), ("value", value)]))
+    }
+
+    fn parse_directives(&mut self, is_const: bool) -> ParseResult<Node> {
+        let mut directives = vec![];
+        while self.expect_optional(TokenKind::At) {
+            let name = self.parse_name()?;
+            let arguments = self.parse_arguments(is_const)?;
+            directives.push(Node::ast("Directive", [("name", name), ("arguments", arguments)]));
+        }
+        Ok(Node::Array(directives))
+    }
+
+    fn parse_type_reference(&mut self) -> ParseResult<Node> {
+        let r#type = if self.expect_optional(TokenKind::BracketL) {
+            let inner = self.parse_type_reference()?;
+            self.expect(TokenKind::BracketR)?;
+            Node::ast("ListType", [("type", inner)])
+        } else {
+            self.parse_named_type()?
+        };
+        if self.expect_optional(TokenKind::Bang) {
+            return Ok(Node::ast("NonNullType", [("type", r#type)]));
+        }
+        Ok(r#type)
+    }
+
+    fn parse_named_type(&mut self) -> ParseResult<Node> {
+        Ok(Node::ast("NamedType", [("name", self.parse_name()?)]))
+    }
+
+    fn parse_name(&mut self) -> ParseResult<Node> {
+        let value = self.expect(TokenKind::Name)?.value.clone();
+        Ok(Node::ast("Name", [("value", Node::String(value))]))
+    }
+}
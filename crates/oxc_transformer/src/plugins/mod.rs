@@ -1,6 +1,12 @@
+mod emotion;
+mod graphql_tag;
+mod module_runner_transform;
 mod options;
 mod styled_components;
 
+pub use emotion::{EmotionAutoLabel, EmotionImportMapEntry, EmotionOptions};
+pub use graphql_tag::GraphQLTagOptions;
+pub use module_runner_transform::ModuleRunnerTransform;
 pub use options::PluginsOptions;
 use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
@@ -8,12 +14,14 @@ pub use styled_components::StyledComponentsOptions;
 
 use crate::{
     context::{TransformCtx, TraverseCtx},
-    plugins::styled_components::StyledComponents,
+    plugins::{emotion::Emotion, graphql_tag::GraphQLTag, styled_components::StyledComponents},
     state::TransformState,
 };
 
 pub struct Plugins<'a, 'ctx> {
     styled_components: Option<StyledComponents<'a, 'ctx>>,
+    emotion: Option<Emotion<'a, 'ctx>>,
+    graphql_tag: Option<GraphQLTag<'a, 'ctx>>,
 }
 
 impl<'a, 'ctx> Plugins<'a, 'ctx> {
@@ -22,6 +30,8 @@ impl<'a, 'ctx> Plugins<'a, 'ctx> {
             styled_components: options
                 .styled_components
                 .map(|options| StyledComponents::new(options, ctx)),
+            emotion: options.emotion.map(|options| Emotion::new(options, ctx)),
+            graphql_tag: options.graphql_tag.map(|options| GraphQLTag::new(options, ctx)),
         }
     }
 }
@@ -31,6 +41,18 @@ impl<'a> Traverse<'a, TransformState<'a>> for Plugins<'a, '_> {
         if let Some(styled_components) = &mut self.styled_components {
             styled_components.enter_program(node, ctx);
         }
+        if let Some(emotion) = &mut self.emotion {
+            emotion.enter_program(node, ctx);
+        }
+        if let Some(graphql_tag) = &mut self.graphql_tag {
+            graphql_tag.enter_program(node, ctx);
+        }
+    }
+
+    fn exit_program(&mut self, node: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(graphql_tag) = &mut self.graphql_tag {
+            graphql_tag.exit_program(node, ctx);
+        }
     }
 
     fn enter_variable_declarator(
@@ -51,6 +73,12 @@ impl<'a> Traverse<'a, TransformState<'a>> for Plugins<'a, '_> {
         if let Some(styled_components) = &mut self.styled_components {
             styled_components.enter_expression(node, ctx);
         }
+        if let Some(emotion) = &mut self.emotion {
+            emotion.enter_expression(node, ctx);
+        }
+        if let Some(graphql_tag) = &mut self.graphql_tag {
+            graphql_tag.enter_expression(node, ctx);
+        }
     }
 
     fn enter_call_expression(&mut self, node: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -1,6 +1,11 @@
-use super::StyledComponentsOptions;
+use super::{EmotionOptions, GraphQLTagOptions, StyledComponentsOptions};
 
 #[derive(Default, Debug, Clone)]
 pub struct PluginsOptions {
     pub styled_components: Option<StyledComponentsOptions>,
+    pub emotion: Option<EmotionOptions>,
+    pub graphql_tag: Option<GraphQLTagOptions>,
+    /// Transform imports and exports for Vite's module runner.
+    /// See [`ModuleRunnerTransform`](crate::ModuleRunnerTransform).
+    pub module_runner_transform: bool,
 }
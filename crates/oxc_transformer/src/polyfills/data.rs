@@ -0,0 +1,496 @@
+//! `core-js` modules and the built-ins which require them.
+//!
+//! Support data is a subset of [core-js-compat](https://github.com/zloirock/core-js/blob/v3.38.1/packages/core-js-compat/src/data.mjs),
+//! built-in definitions are based on [babel-plugin-polyfill-corejs3](https://github.com/babel/babel-polyfills/blob/polyfill-corejs3%400.10.6/packages/babel-plugin-polyfill-corejs3/src/built-in-definitions.ts).
+//!
+//! Only versions of the main engines are recorded. Versions of other engines are derived from them:
+//! Edge, Opera and Android WebView from Chrome, iOS from Safari, and Samsung Internet and Electron from
+//! the Chromium version they are based on. Engines with no data are assumed not to support any of the modules.
+
+#![allow(clippy::enum_glob_use)]
+
+use browserslist::Version;
+
+use crate::options::Engine;
+
+/// First version of each engine which supports a `core-js` module.
+pub struct CoreJsModule {
+    pub name: &'static str,
+    /// ECMAScript version which added the feature. `None` for web platform features.
+    es: Option<u32>,
+    chrome: Version,
+    firefox: Version,
+    safari: Version,
+    node: Version,
+    deno: Version,
+}
+
+impl CoreJsModule {
+    /// Returns `true` if `engine` at `version` needs this module to be polyfilled.
+    pub fn is_required(&self, engine: Engine, version: &Version) -> bool {
+        let supported = match engine {
+            Engine::Es => return self.es.is_none_or(|es| version.0 < es),
+            Engine::Chrome | Engine::Android => self.chrome,
+            // Chromium-based Edge
+            Engine::Edge => self.chrome.max(Version(79, 0, 0)),
+            Engine::Opera | Engine::OperaMobile => {
+                Version(self.chrome.0.saturating_sub(14).max(15), 0, 0)
+            }
+            Engine::Samsung => chrome_to_samsung(self.chrome),
+            Engine::Electron => chrome_to_electron(self.chrome),
+            Engine::Firefox => self.firefox,
+            Engine::Safari | Engine::Ios => self.safari,
+            Engine::Node => self.node,
+            Engine::Deno => self.deno,
+            Engine::Ie | Engine::Rhino | Engine::Hermes => return true,
+        };
+        *version < supported
+    }
+}
+
+/// Samsung Internet version based on a Chromium version.
+fn chrome_to_samsung(chrome: Version) -> Version {
+    const VERSIONS: &[(u32, Version)] = &[
+        (44, Version(4, 0, 0)),
+        (51, Version(5, 0, 0)),
+        (56, Version(6, 2, 0)),
+        (59, Version(7, 2, 0)),
+        (63, Version(8, 2, 0)),
+        (67, Version(9, 2, 0)),
+        (71, Version(10, 1, 0)),
+        (75, Version(11, 1, 0)),
+        (79, Version(12, 0, 0)),
+        (83, Version(13, 0, 0)),
+        (87, Version(14, 0, 0)),
+        (90, Version(15, 0, 0)),
+        (92, Version(16, 0, 0)),
+        (96, Version(17, 0, 0)),
+        (99, Version(18, 0, 0)),
+        (102, Version(19, 0, 0)),
+        (108, Version(20, 0, 0)),
+        (110, Version(21, 0, 0)),
+        (111, Version(22, 0, 0)),
+        (115, Version(23, 0, 0)),
+        (117, Version(24, 0, 0)),
+        (121, Version(25, 0, 0)),
+        (125, Version(26, 0, 0)),
+        (130, Version(27, 0, 0)),
+    ];
+    first_version_based_on(VERSIONS, chrome)
+}
+
+/// Electron version based on a Chromium version.
+fn chrome_to_electron(chrome: Version) -> Version {
+    const VERSIONS: &[(u32, Version)] = &[
+        (49, Version(0, 37, 0)),
+        (51, Version(1, 2, 0)),
+        (56, Version(1, 6, 0)),
+        (61, Version(2, 0, 0)),
+        (66, Version(3, 0, 0)),
+        (69, Version(4, 0, 0)),
+        (73, Version(5, 0, 0)),
+        (76, Version(6, 0, 0)),
+        (78, Version(7, 0, 0)),
+        (80, Version(8, 0, 0)),
+        (83, Version(9, 0, 0)),
+        (85, Version(10, 0, 0)),
+        (87, Version(11, 0, 0)),
+        (89, Version(12, 0, 0)),
+        (91, Version(13, 0, 0)),
+        (93, Version(14, 0, 0)),
+        (94, Version(15, 0, 0)),
+        (96, Version(16, 0, 0)),
+        (98, Version(17, 0, 0)),
+        (100, Version(18, 0, 0)),
+        (102, Version(19, 0, 0)),
+        (104, Version(20, 0, 0)),
+        (106, Version(21, 0, 0)),
+        (108, Version(22, 0, 0)),
+        (110, Version(23, 0, 0)),
+        (112, Version(24, 0, 0)),
+        (114, Version(25, 0, 0)),
+        (116, Version(26, 0, 0)),
+        (118, Version(27, 0, 0)),
+        (120, Version(28, 0, 0)),
+        (122, Version(29, 0, 0)),
+        (124, Version(30, 0, 0)),
+        (126, Version(31, 0, 0)),
+        (128, Version(32, 0, 0)),
+        (130, Version(33, 0, 0)),
+    ];
+    first_version_based_on(VERSIONS, chrome)
+}
+
+/// Find the first version in `versions` based on a Chromium version `>= chrome`.
+/// If there is none, no released version supports it yet.
+fn first_version_based_on(versions: &[(u32, Version)], chrome: Version) -> Version {
+    versions
+        .iter()
+        .find(|(chromium, _)| *chromium >= chrome.0)
+        .map_or(Version(u32::MAX, 0, 0), |(_, version)| *version)
+}
+
+const fn v(major: u32, minor: u32) -> Version {
+    Version(major, minor, 0)
+}
+
+macro_rules! modules {
+    ($(
+        $id:ident = $name:literal {
+            es: $es:expr, chrome: $chrome:expr, firefox: $firefox:expr,
+            safari: $safari:expr, node: $node:expr, deno: $deno:expr $(,)?
+        }
+    ),* $(,)?) => {
+        /// Index of a module in [`MODULES`].
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u8)]
+        pub enum ModuleId { $($id,)* }
+
+        pub const MODULES: &[CoreJsModule] = &[$(
+            CoreJsModule {
+                name: $name,
+                es: $es,
+                chrome: $chrome,
+                firefox: $firefox,
+                safari: $safari,
+                node: $node,
+                deno: $deno,
+            },
+        )*];
+    };
+}
+
+modules! {
+    Symbol = "es.symbol" {
+        es: Some(2015), chrome: v(49, 0), firefox: v(51, 0), safari: v(10, 0), node: v(6, 0), deno: v(1, 0),
+    },
+    SymbolDescription = "es.symbol.description" {
+        es: Some(2019), chrome: v(70, 0), firefox: v(63, 0), safari: v(12, 1), node: v(11, 0), deno: v(1, 0),
+    },
+    SymbolAsyncIterator = "es.symbol.async-iterator" {
+        es: Some(2018), chrome: v(63, 0), firefox: v(55, 0), safari: v(12, 0), node: v(10, 0), deno: v(1, 0),
+    },
+    Promise = "es.promise" {
+        es: Some(2015), chrome: v(67, 0), firefox: v(69, 0), safari: v(11, 0), node: v(10, 4), deno: v(1, 0),
+    },
+    PromiseFinally = "es.promise.finally" {
+        es: Some(2018), chrome: v(67, 0), firefox: v(69, 0), safari: v(13, 1), node: v(10, 4), deno: v(1, 0),
+    },
+    PromiseAllSettled = "es.promise.all-settled" {
+        es: Some(2020), chrome: v(76, 0), firefox: v(71, 0), safari: v(13, 0), node: v(12, 9), deno: v(1, 0),
+    },
+    PromiseAny = "es.promise.any" {
+        es: Some(2021), chrome: v(85, 0), firefox: v(79, 0), safari: v(14, 0), node: v(15, 0), deno: v(1, 2),
+    },
+    PromiseWithResolvers = "es.promise.with-resolvers" {
+        es: Some(2024), chrome: v(119, 0), firefox: v(121, 0), safari: v(17, 4), node: v(22, 0), deno: v(1, 38),
+    },
+    PromiseTry = "es.promise.try" {
+        es: Some(2025), chrome: v(128, 0), firefox: v(134, 0), safari: v(18, 2), node: v(23, 0), deno: v(1, 46),
+    },
+    Map = "es.map" {
+        es: Some(2015), chrome: v(51, 0), firefox: v(53, 0), safari: v(10, 0), node: v(6, 5), deno: v(1, 0),
+    },
+    MapGroupBy = "es.map.group-by" {
+        es: Some(2024), chrome: v(117, 0), firefox: v(119, 0), safari: v(17, 4), node: v(21, 0), deno: v(1, 37),
+    },
+    Set = "es.set" {
+        es: Some(2015), chrome: v(51, 0), firefox: v(53, 0), safari: v(10, 0), node: v(6, 5), deno: v(1, 0),
+    },
+    SetDifference = "es.set.difference.v2" {
+        es: Some(2025), chrome: v(122, 0), firefox: v(127, 0), safari: v(17, 0), node: v(22, 0), deno: v(1, 42),
+    },
+    SetIntersection = "es.set.intersection.v2" {
+        es: Some(2025), chrome: v(122, 0), firefox: v(127, 0), safari: v(17, 0), node: v(22, 0), deno: v(1, 42),
+    },
+    SetIsDisjointFrom = "es.set.is-disjoint-from.v2" {
+        es: Some(2025), chrome: v(122, 0), firefox: v(127, 0), safari: v(17, 0), node: v(22, 0), deno: v(1, 42),
+    },
+    SetIsSubsetOf = "es.set.is-subset-of.v2" {
+        es: Some(2025), chrome: v(122, 0), firefox: v(127, 0), safari: v(17, 0), node: v(22, 0), deno: v(1, 42),
+    },
+    SetIsSupersetOf = "es.set.is-superset-of.v2" {
+        es: Some(2025), chrome: v(122, 0), firefox: v(127, 0), safari: v(17, 0), node: v(22, 0), deno: v(1, 42),
+    },
+    SetSymmetricDifference = "es.set.symmetric-difference.v2" {
+        es: Some(2025), chrome: v(122, 0), firefox: v(127, 0), safari: v(17, 0), node: v(22, 0), deno: v(1, 42),
+    },
+    SetUnion = "es.set.union.v2" {
+        es: Some(2025), chrome: v(122, 0), firefox: v(127, 0), safari: v(17, 0), node: v(22, 0), deno: v(1, 42),
+    },
+    WeakMap = "es.weak-map" {
+        es: Some(2015), chrome: v(51, 0), firefox: v(53, 0), safari: v(10, 0), node: v(6, 5), deno: v(1, 0),
+    },
+    WeakSet = "es.weak-set" {
+        es: Some(2015), chrome: v(51, 0), firefox: v(53, 0), safari: v(10, 0), node: v(6, 5), deno: v(1, 0),
+    },
+    GlobalThis = "es.global-this" {
+        es: Some(2020), chrome: v(71, 0), firefox: v(65, 0), safari: v(12, 1), node: v(12, 0), deno: v(1, 0),
+    },
+    AggregateError = "es.aggregate-error" {
+        es: Some(2021), chrome: v(85, 0), firefox: v(79, 0), safari: v(14, 0), node: v(15, 0), deno: v(1, 2),
+    },
+    ObjectAssign = "es.object.assign" {
+        es: Some(2015), chrome: v(49, 0), firefox: v(36, 0), safari: v(9, 0), node: v(4, 0), deno: v(1, 0),
+    },
+    ObjectEntries = "es.object.entries" {
+        es: Some(2017), chrome: v(54, 0), firefox: v(47, 0), safari: v(10, 1), node: v(7, 0), deno: v(1, 0),
+    },
+    ObjectFromEntries = "es.object.from-entries" {
+        es: Some(2019), chrome: v(73, 0), firefox: v(63, 0), safari: v(12, 1), node: v(12, 0), deno: v(1, 0),
+    },
+    ObjectGetOwnPropertyDescriptors = "es.object.get-own-property-descriptors" {
+        es: Some(2017), chrome: v(54, 0), firefox: v(50, 0), safari: v(10, 1), node: v(7, 0), deno: v(1, 0),
+    },
+    ObjectGroupBy = "es.object.group-by" {
+        es: Some(2024), chrome: v(117, 0), firefox: v(119, 0), safari: v(17, 4), node: v(21, 0), deno: v(1, 37),
+    },
+    ObjectHasOwn = "es.object.has-own" {
+        es: Some(2022), chrome: v(93, 0), firefox: v(92, 0), safari: v(15, 4), node: v(16, 9), deno: v(1, 13),
+    },
+    ObjectValues = "es.object.values" {
+        es: Some(2017), chrome: v(54, 0), firefox: v(47, 0), safari: v(10, 1), node: v(7, 0), deno: v(1, 0),
+    },
+    ArrayAt = "es.array.at" {
+        es: Some(2022), chrome: v(92, 0), firefox: v(90, 0), safari: v(15, 4), node: v(16, 6), deno: v(1, 12),
+    },
+    ArrayFill = "es.array.fill" {
+        es: Some(2015), chrome: v(45, 0), firefox: v(31, 0), safari: v(7, 1), node: v(4, 0), deno: v(1, 0),
+    },
+    ArrayFind = "es.array.find" {
+        es: Some(2015), chrome: v(45, 0), firefox: v(25, 0), safari: v(7, 1), node: v(4, 0), deno: v(1, 0),
+    },
+    ArrayFindIndex = "es.array.find-index" {
+        es: Some(2015), chrome: v(45, 0), firefox: v(25, 0), safari: v(7, 1), node: v(4, 0), deno: v(1, 0),
+    },
+    ArrayFindLast = "es.array.find-last" {
+        es: Some(2023), chrome: v(97, 0), firefox: v(104, 0), safari: v(15, 4), node: v(18, 0), deno: v(1, 16),
+    },
+    ArrayFindLastIndex = "es.array.find-last-index" {
+        es: Some(2023), chrome: v(97, 0), firefox: v(104, 0), safari: v(15, 4), node: v(18, 0), deno: v(1, 16),
+    },
+    ArrayFlat = "es.array.flat" {
+        es: Some(2019), chrome: v(69, 0), firefox: v(62, 0), safari: v(12, 0), node: v(11, 0), deno: v(1, 0),
+    },
+    ArrayFlatMap = "es.array.flat-map" {
+        es: Some(2019), chrome: v(69, 0), firefox: v(62, 0), safari: v(12, 0), node: v(11, 0), deno: v(1, 0),
+    },
+    ArrayFrom = "es.array.from" {
+        es: Some(2015), chrome: v(51, 0), firefox: v(53, 0), safari: v(9, 0), node: v(6, 5), deno: v(1, 0),
+    },
+    ArrayIncludes = "es.array.includes" {
+        es: Some(2016), chrome: v(53, 0), firefox: v(102, 0), safari: v(10, 0), node: v(7, 0), deno: v(1, 0),
+    },
+    ArrayOf = "es.array.of" {
+        es: Some(2015), chrome: v(45, 0), firefox: v(25, 0), safari: v(9, 0), node: v(4, 0), deno: v(1, 0),
+    },
+    ArrayToReversed = "es.array.to-reversed" {
+        es: Some(2023), chrome: v(110, 0), firefox: v(115, 0), safari: v(16, 0), node: v(20, 0), deno: v(1, 28),
+    },
+    ArrayToSorted = "es.array.to-sorted" {
+        es: Some(2023), chrome: v(110, 0), firefox: v(115, 0), safari: v(16, 0), node: v(20, 0), deno: v(1, 28),
+    },
+    ArrayToSpliced = "es.array.to-spliced" {
+        es: Some(2023), chrome: v(110, 0), firefox: v(115, 0), safari: v(16, 0), node: v(20, 0), deno: v(1, 28),
+    },
+    ArrayWith = "es.array.with" {
+        es: Some(2023), chrome: v(110, 0), firefox: v(115, 0), safari: v(16, 0), node: v(20, 0), deno: v(1, 28),
+    },
+    StringAt = "es.string.at-alternative" {
+        es: Some(2022), chrome: v(92, 0), firefox: v(90, 0), safari: v(15, 4), node: v(16, 6), deno: v(1, 12),
+    },
+    StringEndsWith = "es.string.ends-with" {
+        es: Some(2015), chrome: v(51, 0), firefox: v(40, 0), safari: v(10, 0), node: v(6, 5), deno: v(1, 0),
+    },
+    StringIncludes = "es.string.includes" {
+        es: Some(2015), chrome: v(51, 0), firefox: v(40, 0), safari: v(10, 0), node: v(6, 5), deno: v(1, 0),
+    },
+    StringIsWellFormed = "es.string.is-well-formed" {
+        es: Some(2024), chrome: v(111, 0), firefox: v(119, 0), safari: v(16, 4), node: v(20, 0), deno: v(1, 32),
+    },
+    StringMatchAll = "es.string.match-all" {
+        es: Some(2020), chrome: v(80, 0), firefox: v(73, 0), safari: v(13, 1), node: v(14, 0), deno: v(1, 0),
+    },
+    StringPadEnd = "es.string.pad-end" {
+        es: Some(2017), chrome: v(57, 0), firefox: v(48, 0), safari: v(11, 0), node: v(8, 0), deno: v(1, 0),
+    },
+    StringPadStart = "es.string.pad-start" {
+        es: Some(2017), chrome: v(57, 0), firefox: v(48, 0), safari: v(11, 0), node: v(8, 0), deno: v(1, 0),
+    },
+    StringRepeat = "es.string.repeat" {
+        es: Some(2015), chrome: v(41, 0), firefox: v(24, 0), safari: v(9, 0), node: v(4, 0), deno: v(1, 0),
+    },
+    StringReplaceAll = "es.string.replace-all" {
+        es: Some(2021), chrome: v(85, 0), firefox: v(77, 0), safari: v(13, 1), node: v(15, 0), deno: v(1, 2),
+    },
+    StringStartsWith = "es.string.starts-with" {
+        es: Some(2015), chrome: v(51, 0), firefox: v(40, 0), safari: v(10, 0), node: v(6, 5), deno: v(1, 0),
+    },
+    StringToWellFormed = "es.string.to-well-formed" {
+        es: Some(2024), chrome: v(111, 0), firefox: v(119, 0), safari: v(16, 4), node: v(20, 0), deno: v(1, 32),
+    },
+    StringTrimEnd = "es.string.trim-end" {
+        es: Some(2019), chrome: v(66, 0), firefox: v(61, 0), safari: v(12, 0), node: v(10, 0), deno: v(1, 0),
+    },
+    StringTrimStart = "es.string.trim-start" {
+        es: Some(2019), chrome: v(66, 0), firefox: v(61, 0), safari: v(12, 0), node: v(10, 0), deno: v(1, 0),
+    },
+    TypedArrayAt = "es.typed-array.at" {
+        es: Some(2022), chrome: v(92, 0), firefox: v(90, 0), safari: v(15, 4), node: v(16, 6), deno: v(1, 12),
+    },
+    TypedArrayFindLast = "es.typed-array.find-last" {
+        es: Some(2023), chrome: v(97, 0), firefox: v(104, 0), safari: v(15, 4), node: v(18, 0), deno: v(1, 16),
+    },
+    TypedArrayFindLastIndex = "es.typed-array.find-last-index" {
+        es: Some(2023), chrome: v(97, 0), firefox: v(104, 0), safari: v(15, 4), node: v(18, 0), deno: v(1, 16),
+    },
+    TypedArrayToReversed = "es.typed-array.to-reversed" {
+        es: Some(2023), chrome: v(110, 0), firefox: v(115, 0), safari: v(16, 0), node: v(20, 0), deno: v(1, 28),
+    },
+    TypedArrayToSorted = "es.typed-array.to-sorted" {
+        es: Some(2023), chrome: v(110, 0), firefox: v(115, 0), safari: v(16, 0), node: v(20, 0), deno: v(1, 28),
+    },
+    TypedArrayWith = "es.typed-array.with" {
+        es: Some(2023), chrome: v(110, 0), firefox: v(115, 0), safari: v(16, 0), node: v(20, 0), deno: v(1, 28),
+    },
+    NumberIsFinite = "es.number.is-finite" {
+        es: Some(2015), chrome: v(19, 0), firefox: v(16, 0), safari: v(9, 0), node: v(0, 10), deno: v(1, 0),
+    },
+    NumberIsInteger = "es.number.is-integer" {
+        es: Some(2015), chrome: v(34, 0), firefox: v(16, 0), safari: v(9, 0), node: v(0, 12), deno: v(1, 0),
+    },
+    NumberIsNaN = "es.number.is-nan" {
+        es: Some(2015), chrome: v(19, 0), firefox: v(15, 0), safari: v(9, 0), node: v(0, 10), deno: v(1, 0),
+    },
+    NumberIsSafeInteger = "es.number.is-safe-integer" {
+        es: Some(2015), chrome: v(34, 0), firefox: v(32, 0), safari: v(9, 0), node: v(0, 12), deno: v(1, 0),
+    },
+    MathSign = "es.math.sign" {
+        es: Some(2015), chrome: v(38, 0), firefox: v(25, 0), safari: v(9, 0), node: v(0, 12), deno: v(1, 0),
+    },
+    MathTrunc = "es.math.trunc" {
+        es: Some(2015), chrome: v(38, 0), firefox: v(25, 0), safari: v(8, 0), node: v(0, 12), deno: v(1, 0),
+    },
+    StructuredClone = "web.structured-clone" {
+        es: None, chrome: v(98, 0), firefox: v(94, 0), safari: v(15, 4), node: v(17, 0), deno: v(1, 14),
+    },
+    QueueMicrotask = "web.queue-microtask" {
+        es: None, chrome: v(71, 0), firefox: v(69, 0), safari: v(12, 1), node: v(12, 0), deno: v(1, 0),
+    },
+}
+
+/// A built-in which can be polyfilled.
+pub struct BuiltIn {
+    /// Modules required by the built-in.
+    pub modules: &'static [ModuleId],
+    /// Path of the pure version in `core-js-pure/stable/`, without extension.
+    /// `None` if it can't be polyfilled without polluting the global scope.
+    pub pure: Option<&'static str>,
+}
+
+const fn built_in(modules: &'static [ModuleId], pure: &'static str) -> BuiltIn {
+    BuiltIn { modules, pure: Some(pure) }
+}
+
+const fn global_only(modules: &'static [ModuleId]) -> BuiltIn {
+    BuiltIn { modules, pure: None }
+}
+
+/// Global built-ins, e.g. `Promise`.
+pub fn global(name: &str) -> Option<BuiltIn> {
+    use ModuleId::*;
+    let built_in = match name {
+        "AggregateError" => built_in(&[AggregateError], "aggregate-error"),
+        "globalThis" => built_in(&[GlobalThis], "global-this"),
+        "Map" => built_in(&[Map], "map/index"),
+        "Promise" => built_in(&[Promise], "promise/index"),
+        "queueMicrotask" => built_in(&[QueueMicrotask], "queue-microtask"),
+        "Set" => built_in(&[Set], "set/index"),
+        "structuredClone" => built_in(&[StructuredClone], "structured-clone"),
+        "Symbol" => built_in(&[Symbol], "symbol/index"),
+        "WeakMap" => built_in(&[WeakMap], "weak-map/index"),
+        "WeakSet" => built_in(&[WeakSet], "weak-set/index"),
+        _ => return None,
+    };
+    Some(built_in)
+}
+
+/// Static properties of global built-ins, e.g. `Object.hasOwn`.
+pub fn static_property(object: &str, property: &str) -> Option<BuiltIn> {
+    use ModuleId::*;
+    let built_in = match (object, property) {
+        ("Array", "from") => built_in(&[ArrayFrom], "array/from"),
+        ("Array", "of") => built_in(&[ArrayOf], "array/of"),
+        ("Map", "groupBy") => built_in(&[Map, MapGroupBy], "map/group-by"),
+        ("Math", "sign") => built_in(&[MathSign], "math/sign"),
+        ("Math", "trunc") => built_in(&[MathTrunc], "math/trunc"),
+        ("Number", "isFinite") => built_in(&[NumberIsFinite], "number/is-finite"),
+        ("Number", "isInteger") => built_in(&[NumberIsInteger], "number/is-integer"),
+        ("Number", "isNaN") => built_in(&[NumberIsNaN], "number/is-nan"),
+        ("Number", "isSafeInteger") => built_in(&[NumberIsSafeInteger], "number/is-safe-integer"),
+        ("Object", "assign") => built_in(&[ObjectAssign], "object/assign"),
+        ("Object", "entries") => built_in(&[ObjectEntries], "object/entries"),
+        ("Object", "fromEntries") => built_in(&[ObjectFromEntries], "object/from-entries"),
+        ("Object", "getOwnPropertyDescriptors") => {
+            built_in(&[ObjectGetOwnPropertyDescriptors], "object/get-own-property-descriptors")
+        }
+        ("Object", "groupBy") => built_in(&[ObjectGroupBy], "object/group-by"),
+        ("Object", "hasOwn") => built_in(&[ObjectHasOwn], "object/has-own"),
+        ("Object", "values") => built_in(&[ObjectValues], "object/values"),
+        ("Promise", "allSettled") => built_in(&[Promise, PromiseAllSettled], "promise/all-settled"),
+        ("Promise", "any") => built_in(&[Promise, AggregateError, PromiseAny], "promise/any"),
+        ("Promise", "try") => built_in(&[Promise, PromiseTry], "promise/try"),
+        ("Promise", "withResolvers") => {
+            built_in(&[Promise, PromiseWithResolvers], "promise/with-resolvers")
+        }
+        ("Symbol", "asyncIterator") => built_in(&[SymbolAsyncIterator], "symbol/async-iterator"),
+        _ => return None,
+    };
+    Some(built_in)
+}
+
+/// Instance properties, e.g. `array.at`.
+///
+/// The type of the object is not known, so all modules which define a property of this name are required.
+pub fn instance_property(property: &str) -> Option<BuiltIn> {
+    use ModuleId::*;
+    let built_in = match property {
+        "at" => built_in(&[ArrayAt, StringAt, TypedArrayAt], "instance/at"),
+        "description" => global_only(&[Symbol, SymbolDescription]),
+        "difference" => global_only(&[Set, SetDifference]),
+        "endsWith" => built_in(&[StringEndsWith], "instance/ends-with"),
+        "fill" => built_in(&[ArrayFill], "instance/fill"),
+        "finally" => global_only(&[Promise, PromiseFinally]),
+        "find" => built_in(&[ArrayFind], "instance/find"),
+        "findIndex" => built_in(&[ArrayFindIndex], "instance/find-index"),
+        "findLast" => built_in(&[ArrayFindLast, TypedArrayFindLast], "instance/find-last"),
+        "findLastIndex" => {
+            built_in(&[ArrayFindLastIndex, TypedArrayFindLastIndex], "instance/find-last-index")
+        }
+        "flat" => built_in(&[ArrayFlat], "instance/flat"),
+        "flatMap" => built_in(&[ArrayFlatMap], "instance/flat-map"),
+        "includes" => built_in(&[ArrayIncludes, StringIncludes], "instance/includes"),
+        "intersection" => global_only(&[Set, SetIntersection]),
+        "isDisjointFrom" => global_only(&[Set, SetIsDisjointFrom]),
+        "isSubsetOf" => global_only(&[Set, SetIsSubsetOf]),
+        "isSupersetOf" => global_only(&[Set, SetIsSupersetOf]),
+        "isWellFormed" => built_in(&[StringIsWellFormed], "instance/is-well-formed"),
+        "matchAll" => built_in(&[StringMatchAll], "instance/match-all"),
+        "padEnd" => built_in(&[StringPadEnd], "instance/pad-end"),
+        "padStart" => built_in(&[StringPadStart], "instance/pad-start"),
+        "repeat" => built_in(&[StringRepeat], "instance/repeat"),
+        "replaceAll" => built_in(&[StringReplaceAll], "instance/replace-all"),
+        "startsWith" => built_in(&[StringStartsWith], "instance/starts-with"),
+        "symmetricDifference" => global_only(&[Set, SetSymmetricDifference]),
+        "toReversed" => built_in(&[ArrayToReversed, TypedArrayToReversed], "instance/to-reversed"),
+        "toSorted" => built_in(&[ArrayToSorted, TypedArrayToSorted], "instance/to-sorted"),
+        "toSpliced" => built_in(&[ArrayToSpliced], "instance/to-spliced"),
+        "toWellFormed" => built_in(&[StringToWellFormed], "instance/to-well-formed"),
+        "trimEnd" => built_in(&[StringTrimEnd], "instance/trim-end"),
+        "trimStart" => built_in(&[StringTrimStart], "instance/trim-start"),
+        "union" => global_only(&[Set, SetUnion]),
+        "with" => built_in(&[ArrayWith, TypedArrayWith], "instance/with"),
+        _ => return None,
+    };
+    Some(built_in)
+}
@@ -0,0 +1,342 @@
+//! Usage-based `core-js` polyfills
+//!
+//! Injects `core-js` polyfills for built-ins which are used in the file, and which are not supported
+//! by the targets.
+//!
+//! This plugin mirrors [babel-plugin-polyfill-corejs3](https://github.com/babel/babel-polyfills/tree/main/packages/babel-plugin-polyfill-corejs3)
+//! with `method: "usage-global"` or `method: "usage-pure"`.
+//!
+//! Built-ins are detected by name, using semantic information to ignore references which are
+//! shadowed by a local binding:
+//!
+//! * Globals: `Promise`, `structuredClone`.
+//! * Static properties of globals: `Object.hasOwn`, `Array.from`.
+//! * Instance properties: `array.at(-1)`, `string.replaceAll("a", "b")`.
+//!   The type of the object can't be known, so all modules for the property name are injected.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! Object.hasOwn(obj, "x");
+//! arr.at(-1);
+//! foo().at(0);
+//! ```
+//!
+//! Output with `method: "usage-global"`:
+//! ```js
+//! import "core-js/modules/es.object.has-own.js";
+//! import "core-js/modules/es.array.at.js";
+//! import "core-js/modules/es.string.at-alternative.js";
+//! import "core-js/modules/es.typed-array.at.js";
+//! Object.hasOwn(obj, "x");
+//! arr.at(-1);
+//! foo().at(0);
+//! ```
+//!
+//! Output with `method: "usage-pure"`:
+//! ```js
+//! import _Object$hasOwn from "core-js-pure/stable/object/has-own.js";
+//! import _atInstanceProperty from "core-js-pure/stable/instance/at.js";
+//! var _context;
+//! _Object$hasOwn(obj, "x");
+//! _atInstanceProperty(arr).call(arr, -1);
+//! _atInstanceProperty(_context = foo()).call(_context, 0);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [babel-plugin-polyfill-corejs3](https://github.com/babel/babel-polyfills/blob/polyfill-corejs3%400.10.6/packages/babel-plugin-polyfill-corejs3/src/index.ts).
+//!
+//! Only a subset of `core-js` modules is supported, see [`data`].
+//! Built-ins used implicitly (e.g. iterators used by `for of` or spread) are not polyfilled.
+
+mod data;
+mod options;
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{IsGlobalReference, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::{Ancestor, BoundIdentifier, MaybeBoundIdentifier, Traverse};
+
+pub use options::{PolyfillMethod, PolyfillsOptions};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use data::BuiltIn;
+use options::CoreJsModules;
+
+pub struct Polyfills<'a, 'ctx> {
+    method: PolyfillMethod,
+    /// Modules not supported by the targets.
+    required: CoreJsModules,
+    /// Modules used in the file. Only used in `usage-global` mode.
+    used: CoreJsModules,
+    /// Bindings of `core-js-pure` imports, keyed by path. Only used in `usage-pure` mode.
+    pure_imports: FxHashMap<&'static str, BoundIdentifier<'a>>,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Polyfills<'a, 'ctx> {
+    pub fn new(options: PolyfillsOptions, ctx: &'ctx TransformCtx<'a>) -> Option<Self> {
+        let method = options.method?;
+        Some(Self {
+            method,
+            required: options.required,
+            used: CoreJsModules::default(),
+            pure_imports: FxHashMap::default(),
+            ctx,
+        })
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Polyfills<'a, '_> {
+    fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Polyfills must run before any other code, so add them in front of other imports.
+        // Iterate in reverse, so they end up in `MODULES` order.
+        for name in self.used.names().rev() {
+            let source = ctx.ast.atom(&format!("core-js/modules/{name}.js"));
+            self.ctx.module_imports.add_side_effect_import(source, true);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match self.method {
+            PolyfillMethod::UsageGlobal => self.detect_usage(expr, ctx),
+            PolyfillMethod::UsagePure => self.replace_usage(expr, ctx),
+        }
+    }
+}
+
+// `usage-global`
+impl<'a> Polyfills<'a, '_> {
+    /// Record modules required by built-ins used in `expr`.
+    fn detect_usage(&mut self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) {
+        let built_in = match expr {
+            Expression::Identifier(ident) => Self::global_built_in(ident, ctx),
+            Expression::StaticMemberExpression(member) => Self::member_built_in(member, ctx),
+            Expression::ChainExpression(chain) => match &chain.expression {
+                ChainElement::StaticMemberExpression(member) => Self::member_built_in(member, ctx),
+                ChainElement::CallExpression(call) => match &call.callee {
+                    Expression::StaticMemberExpression(member) => {
+                        Self::member_built_in(member, ctx)
+                    }
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(built_in) = built_in {
+            for &id in built_in.modules {
+                if self.required.contains(id) {
+                    self.used.insert(id);
+                }
+            }
+        }
+    }
+
+    /// `Promise`
+    fn global_built_in(ident: &IdentifierReference<'a>, ctx: &TraverseCtx<'a>) -> Option<BuiltIn> {
+        data::global(&ident.name).filter(|_| ident.is_global_reference(ctx.scoping()))
+    }
+
+    /// `Object.hasOwn` or `x.at`
+    fn member_built_in(
+        member: &StaticMemberExpression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<BuiltIn> {
+        let property = member.property.name.as_str();
+        match &member.object {
+            Expression::Identifier(object) if object.is_global_reference(ctx.scoping()) => {
+                if let Some(built_in) = data::static_property(&object.name, property) {
+                    return Some(built_in);
+                }
+            }
+            Expression::Super(_) => return None,
+            _ => {}
+        }
+        data::instance_property(property)
+    }
+}
+
+// `usage-pure`
+impl<'a> Polyfills<'a, '_> {
+    /// Replace built-ins used in `expr` with imports from `core-js-pure`.
+    fn replace_usage(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let replacement = match expr {
+            Expression::Identifier(ident) => self.replace_global(ident, ctx),
+            Expression::StaticMemberExpression(member) => self.replace_member(member, ctx),
+            Expression::CallExpression(call) => self.replace_instance_call(call, ctx),
+            _ => None,
+        };
+        if let Some(replacement) = replacement {
+            *expr = replacement;
+        }
+    }
+
+    /// `Promise` -> `_Promise`
+    fn replace_global(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let built_in = Self::global_built_in(ident, ctx)?;
+        let binding = self.pure_import(&built_in, &ident.name, ctx)?;
+        ctx.delete_reference_for_identifier(ident);
+        Some(binding.create_read_expression(ctx))
+    }
+
+    /// * `Object.hasOwn` -> `_Object$hasOwn`
+    /// * `x.at` -> `_atInstanceProperty(x)`
+    fn replace_member(
+        &mut self,
+        member: &mut StaticMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let property = member.property.name;
+        match &member.object {
+            Expression::Identifier(object) if object.is_global_reference(ctx.scoping()) => {
+                if let Some(built_in) = data::static_property(&object.name, &property) {
+                    let name = format!("{}${property}", object.name);
+                    let binding = self.pure_import(&built_in, &name, ctx)?;
+                    ctx.delete_reference_for_identifier(object);
+                    return Some(binding.create_read_expression(ctx));
+                }
+            }
+            Expression::Super(_) => return None,
+            _ => {}
+        }
+
+        // `delete x.at` must not be replaced
+        if let Ancestor::UnaryExpressionArgument(unary) = ctx.parent() {
+            if unary.operator().is_delete() {
+                return None;
+            }
+        }
+
+        let callee = self.instance_property_import(&property, ctx)?;
+        let object = member.object.take_in(ctx.ast);
+        Some(ctx.ast.expression_call(
+            SPAN,
+            callee,
+            NONE,
+            ctx.ast.vec1(Argument::from(object)),
+            false,
+        ))
+    }
+
+    /// * `x.at(0)` -> `_atInstanceProperty(x).call(x, 0)`
+    /// * `foo().at(0)` -> `_atInstanceProperty(_context = foo()).call(_context, 0)`
+    fn replace_instance_call(
+        &mut self,
+        call: &mut CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let Expression::StaticMemberExpression(member) = &mut call.callee else { return None };
+        if matches!(member.object, Expression::Super(_))
+            || (member.object.is_identifier_reference()
+                && Self::member_is_static_built_in(member, ctx))
+        {
+            return None;
+        }
+        let callee = self.instance_property_import(&member.property.name, ctx)?;
+
+        let object = member.object.take_in(ctx.ast);
+        let (object, this) = match object {
+            Expression::Identifier(ident) => {
+                let binding = MaybeBoundIdentifier::from_identifier_reference(&ident, ctx);
+                let this = binding.create_read_expression(ctx);
+                (Expression::Identifier(ident), this)
+            }
+            Expression::ThisExpression(this) => {
+                (Expression::ThisExpression(this), ctx.ast.expression_this(SPAN))
+            }
+            object => {
+                let binding = self.ctx.var_declarations.create_uid_var("context", ctx);
+                let object = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    binding.create_write_target(ctx),
+                    object,
+                );
+                (object, binding.create_read_expression(ctx))
+            }
+        };
+
+        // `_atInstanceProperty(x).call`
+        let callee = ctx.ast.member_expression_static(
+            SPAN,
+            ctx.ast.expression_call(
+                SPAN,
+                callee,
+                NONE,
+                ctx.ast.vec1(Argument::from(object)),
+                false,
+            ),
+            ctx.ast.identifier_name(SPAN, "call"),
+            false,
+        );
+        let mut arguments = call.arguments.take_in(ctx.ast);
+        arguments.insert(0, Argument::from(this));
+        Some(ctx.ast.expression_call(SPAN, Expression::from(callee), NONE, arguments, false))
+    }
+
+    /// Returns `true` if `member` is a static property of a global built-in, e.g. `Object.hasOwn`.
+    fn member_is_static_built_in(
+        member: &StaticMemberExpression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        let Expression::Identifier(object) = &member.object else { return false };
+        object.is_global_reference(ctx.scoping())
+            && data::static_property(&object.name, &member.property.name).is_some()
+    }
+
+    /// Get a reference to `core-js-pure` import for instance property `property`,
+    /// e.g. `_atInstanceProperty`.
+    fn instance_property_import(
+        &mut self,
+        property: &str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let built_in = data::instance_property(property)?;
+        let name = format!("{property}InstanceProperty");
+        let binding = self.pure_import(&built_in, &name, ctx)?;
+        Some(binding.create_read_expression(ctx))
+    }
+
+    /// Get binding for default import of `built_in` from `core-js-pure`.
+    ///
+    /// Returns `None` if `built_in` is supported by the targets, or has no pure version.
+    fn pure_import(
+        &mut self,
+        built_in: &BuiltIn,
+        name: &str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<BoundIdentifier<'a>> {
+        let path = built_in.pure?;
+        if !built_in.modules.iter().any(|&id| self.required.contains(id)) {
+            return None;
+        }
+        if let Some(binding) = self.pure_imports.get(path) {
+            return Some(binding.clone());
+        }
+
+        let flag = if self.ctx.source_type.is_script() {
+            SymbolFlags::FunctionScopedVariable
+        } else {
+            SymbolFlags::Import
+        };
+        let binding = ctx.generate_uid_in_root_scope(name, flag);
+        let source = ctx.ast.atom(&format!("core-js-pure/stable/{path}.js"));
+        self.ctx.module_imports.add_default_import(source, binding.clone(), false);
+        self.pure_imports.insert(path, binding.clone());
+        Some(binding)
+    }
+}
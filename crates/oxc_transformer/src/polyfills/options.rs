@@ -0,0 +1,80 @@
+use serde::Deserialize;
+
+use crate::options::EngineTargets;
+
+use super::data::{MODULES, ModuleId};
+
+/// How `core-js` polyfills are injected.
+///
+/// Mirrors the `method` option of [babel-plugin-polyfill-corejs3](https://github.com/babel/babel-polyfills/tree/main/packages/babel-plugin-polyfill-corejs3).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum PolyfillMethod {
+    /// Import `core-js/modules/*` for each built-in used in the file, polluting the global scope.
+    ///
+    /// `import "core-js/modules/es.promise.js";`
+    #[default]
+    #[serde(rename = "usage-global")]
+    UsageGlobal,
+    /// Replace each built-in used in the file with an import from `core-js-pure`.
+    ///
+    /// `import _Promise from "core-js-pure/stable/promise/index.js";`
+    #[serde(rename = "usage-pure")]
+    UsagePure,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PolyfillsOptions {
+    /// Polyfills are only injected when this is set.
+    pub method: Option<PolyfillMethod>,
+
+    /// `core-js` modules required by the targets.
+    pub(crate) required: CoreJsModules,
+}
+
+impl PolyfillsOptions {
+    /// Polyfill all built-ins regardless of targets.
+    pub fn all(method: Option<PolyfillMethod>) -> Self {
+        Self { method, required: CoreJsModules::all() }
+    }
+}
+
+impl From<&EngineTargets> for PolyfillsOptions {
+    fn from(targets: &EngineTargets) -> Self {
+        let mut required = CoreJsModules::default();
+        for (index, module) in MODULES.iter().enumerate() {
+            if targets.iter().any(|(engine, version)| module.is_required(*engine, version)) {
+                required.0 |= 1 << index;
+            }
+        }
+        Self { method: None, required }
+    }
+}
+
+const _: () = assert!(MODULES.len() <= u128::BITS as usize);
+
+/// Set of `core-js` modules, indexed by [`ModuleId`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CoreJsModules(u128);
+
+impl CoreJsModules {
+    fn all() -> Self {
+        Self(u128::MAX >> (u128::BITS as usize - MODULES.len()))
+    }
+
+    pub fn contains(self, id: ModuleId) -> bool {
+        self.0 & (1 << id as u8) != 0
+    }
+
+    pub fn insert(&mut self, id: ModuleId) {
+        self.0 |= 1 << id as u8;
+    }
+
+    /// Iterate over module names, in [`MODULES`] order.
+    pub fn names(self) -> impl DoubleEndedIterator<Item = &'static str> {
+        MODULES
+            .iter()
+            .enumerate()
+            .filter(move |(index, _)| self.0 & (1 << index) != 0)
+            .map(|(_, module)| module.name)
+    }
+}
@@ -1,10 +1,19 @@
 //! RegExp Transformer
 //!
 //! This module supports various RegExp plugins to handle unsupported RegExp literal features.
-//! When an unsupported feature is detected, these plugins convert the RegExp literal into
-//! a `new RegExp()` constructor call to avoid syntax errors.
 //!
-//! Note: You will need to include a polyfill for the `RegExp` constructor in your code to have the correct runtime behavior.
+//! Where possible, the pattern is rewritten into an equivalent one which doesn't use the unsupported
+//! feature (see [`rewrite`] for details):
+//!
+//! * `s` flag, `u` flag, `v` flag and `\p{...}` property escapes are rewritten like `regexpu-core` does.
+//! * Named capture groups are stripped, and the RegExp is wrapped in the `wrapRegExp` helper,
+//!   which restores `groups` on match results.
+//!
+//! Other features can't be rewritten (sticky flag, match indices flag, lookbehind assertions,
+//! and properties of strings like `\p{RGI_Emoji}`). RegExp literals which contain them
+//! are converted into a `new RegExp()` constructor call to avoid syntax errors.
+//! You will need to include a polyfill for the `RegExp` constructor in your code to have the correct
+//! runtime behavior in that case.
 //!
 //! ### ES2015
 //!
@@ -40,76 +49,90 @@
 //! - @babel/plugin-transform-unicode-sets-regex: <https://babeljs.io/docs/en/babel-plugin-proposal-unicode-sets-regex>
 //! - TC39 Proposal: <https://github.com/tc39/proposal-regexp-set-notation>
 //!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! /a.b/s;
+//! /(?<year>\d{4})/;
+//! /[\p{ASCII}--\p{L}]/v;
+//! ```
+//!
+//! Output (targeting Chrome 40):
+//! ```js
+//! /a[\0-\uFFFF]b/;
+//! _wrapRegExp(/(\d{4})/, { year: 1 });
+//! /[\0-@\[-`\{-\x7F]/;
+//! ```
+//!
 //! TODO(improve-on-babel): We could convert to plain `RegExp(...)` instead of `new RegExp(...)`.
 //! TODO(improve-on-babel): When flags is empty, we could output `RegExp("(?<=x)")` instead of `RegExp("(?<=x)", "")`.
 //! (actually these would be improvements on ESBuild, not Babel)
 
 use oxc_ast::{NONE, ast::*};
 use oxc_diagnostics::Result;
-use oxc_regular_expression::ast::{
-    CharacterClass, CharacterClassContents, LookAroundAssertionKind, Pattern, Term,
+use oxc_regular_expression::{
+    ast::{
+        CapturingGroup, LookAroundAssertion, LookAroundAssertionKind, Pattern,
+        UnicodePropertyEscape,
+    },
+    visit::{Visit, walk},
 };
 use oxc_semantic::ReferenceFlags;
 use oxc_span::{Atom, SPAN};
 use oxc_traverse::Traverse;
 
 use crate::{
+    common::helper_loader::Helper,
     context::{TransformCtx, TraverseCtx},
     state::TransformState,
 };
 
 mod options;
+mod rewrite;
+mod unicode;
 
 pub use options::RegExpOptions;
+use rewrite::{RewrittenPattern, rewrite_pattern};
 
 pub struct RegExp<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
+    options: RegExpOptions,
+    /// Flags which can't be rewritten, so force `new RegExp(...)`
     unsupported_flags: RegExpFlags,
+    /// Flags which can be removed by rewriting the pattern
+    rewritable_flags: RegExpFlags,
     some_unsupported_patterns: bool,
-    look_behind_assertions: bool,
-    named_capture_groups: bool,
-    unicode_property_escapes: bool,
 }
 
 impl<'a, 'ctx> RegExp<'a, 'ctx> {
     pub fn new(options: RegExpOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
         // Get unsupported flags
         let mut unsupported_flags = RegExpFlags::empty();
-        if options.dot_all_flag {
-            unsupported_flags |= RegExpFlags::S;
-        }
         if options.sticky_flag {
             unsupported_flags |= RegExpFlags::Y;
         }
-        if options.unicode_flag {
-            unsupported_flags |= RegExpFlags::U;
-        }
         if options.match_indices {
             unsupported_flags |= RegExpFlags::D;
         }
+
+        let mut rewritable_flags = RegExpFlags::empty();
+        if options.dot_all_flag {
+            rewritable_flags |= RegExpFlags::S;
+        }
+        if options.unicode_flag {
+            rewritable_flags |= RegExpFlags::U | RegExpFlags::V;
+        }
         if options.set_notation {
-            unsupported_flags |= RegExpFlags::V;
+            rewritable_flags |= RegExpFlags::V;
         }
 
         // Get if some unsupported patterns
-        let RegExpOptions {
-            look_behind_assertions,
-            named_capture_groups,
-            unicode_property_escapes,
-            ..
-        } = options;
-
-        let some_unsupported_patterns =
-            look_behind_assertions || named_capture_groups || unicode_property_escapes;
-
-        Self {
-            ctx,
-            unsupported_flags,
-            some_unsupported_patterns,
-            look_behind_assertions,
-            named_capture_groups,
-            unicode_property_escapes,
-        }
+        let some_unsupported_patterns = options.look_behind_assertions
+            || options.named_capture_groups
+            || options.unicode_property_escapes;
+
+        Self { ctx, options, unsupported_flags, rewritable_flags, some_unsupported_patterns }
     }
 }
 
@@ -124,7 +147,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for RegExp<'a, '_> {
 }
 
 impl<'a> RegExp<'a, '_> {
-    /// If `RegExpLiteral` contains unsupported syntax or flags, transform to `new RegExp(...)`.
+    /// If `RegExpLiteral` contains unsupported syntax or flags, rewrite the pattern,
+    /// or transform to `new RegExp(...)` if it can't be rewritten.
     fn transform_regexp(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         let Expression::RegExpLiteral(regexp) = expr else {
             unreachable!();
@@ -135,7 +159,8 @@ impl<'a> RegExp<'a, '_> {
         let flags = regexp.regex.flags;
         let has_unsupported_flags = flags.intersects(self.unsupported_flags);
         if !has_unsupported_flags {
-            if !self.some_unsupported_patterns {
+            let has_rewritable_flags = flags.intersects(self.rewritable_flags);
+            if !has_rewritable_flags && !self.some_unsupported_patterns {
                 // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
                 // so there's nothing to do
                 return;
@@ -150,8 +175,8 @@ impl<'a> RegExp<'a, '_> {
                 let literal_span = regexp.span;
                 let pattern_span_start = literal_span.start + 1; // +1 to skip the opening `/`
                 let flags_span_start = pattern_span_start + pattern_len + 1; // +1 to skip the closing `/`
-                let flags_text =
-                    Span::new(flags_span_start, literal_span.end).source_text(self.ctx.source_text);
+                // Not taken from source text, as literals created by rewriting have no source
+                let flags_text = ctx.ast.str(flags.to_inline_string().as_str());
                 // Try to parse pattern
                 match try_parse_pattern(
                     pattern_text.as_str(),
@@ -171,8 +196,20 @@ impl<'a> RegExp<'a, '_> {
                 }
             };
 
-            if !self.has_unsupported_regular_expression_pattern(pattern) {
-                return;
+            let mut features = PatternFeatures::default();
+            features.visit_pattern(pattern);
+            if !(self.options.look_behind_assertions && features.look_behind_assertions) {
+                let needs_rewrite = has_rewritable_flags
+                    || (self.options.named_capture_groups && features.named_capture_groups)
+                    || (self.options.unicode_property_escapes && features.unicode_property_escapes);
+                if !needs_rewrite {
+                    return;
+                }
+                // Fall back to `new RegExp(...)` if the pattern can't be rewritten
+                if let Ok(rewritten) = rewrite_pattern(pattern, flags, self.options) {
+                    *expr = self.create_rewritten_regexp(regexp.span, rewritten, ctx);
+                    return;
+                }
             }
         }
 
@@ -193,48 +230,93 @@ impl<'a> RegExp<'a, '_> {
         *expr = ctx.ast.expression_new(regexp.span, callee, NONE, arguments);
     }
 
-    /// Check if the regular expression contains any unsupported syntax.
-    ///
-    /// Based on parsed regular expression pattern.
-    fn has_unsupported_regular_expression_pattern(&self, pattern: &Pattern<'a>) -> bool {
-        pattern.body.body.iter().any(|alternative| {
-            alternative.body.iter().any(|term| self.term_contains_unsupported(term))
-        })
+    /// `/(?<a>x)(?<b>y)/` -> `_wrapRegExp(/(x)(y)/, { a: 1, b: 2 })`
+    fn create_rewritten_regexp(
+        &self,
+        span: Span,
+        rewritten: RewrittenPattern<'_>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let RewrittenPattern { pattern, flags, groups } = rewritten;
+        let regexp = ctx.ast.expression_reg_exp_literal(
+            span,
+            oxc_ast::ast::RegExp {
+                pattern: RegExpPattern { text: ctx.ast.atom(&pattern), pattern: None },
+                flags,
+            },
+            None,
+        );
+        if groups.is_empty() {
+            return regexp;
+        }
+
+        let properties = ctx.ast.vec_from_iter(groups.into_iter().map(|(name, indices)| {
+            let key = PropertyKey::StaticIdentifier(
+                ctx.ast.alloc_identifier_name(SPAN, ctx.ast.atom(name)),
+            );
+            let mut indices = indices.into_iter().map(|index| {
+                ctx.ast.expression_numeric_literal(
+                    SPAN,
+                    f64::from(index),
+                    None,
+                    NumberBase::Decimal,
+                )
+            });
+            let value = if indices.len() == 1 {
+                indices.next().unwrap()
+            } else {
+                ctx.ast.expression_array(
+                    SPAN,
+                    ctx.ast.vec_from_iter(indices.map(ArrayExpressionElement::from)),
+                )
+            };
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                value,
+                false,
+                false,
+                false,
+            )
+        }));
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(regexp),
+            Argument::from(ctx.ast.expression_object(SPAN, properties)),
+        ]);
+        self.ctx.helper_call_expr(Helper::WrapRegExp, span, arguments, ctx)
     }
+}
 
-    fn term_contains_unsupported(&self, mut term: &Term) -> bool {
-        // Loop because `Term::Quantifier` contains a nested `Term`
-        loop {
-            match term {
-                Term::CapturingGroup(_) => return self.named_capture_groups,
-                Term::UnicodePropertyEscape(_) => return self.unicode_property_escapes,
-                Term::CharacterClass(character_class) => {
-                    return self.unicode_property_escapes
-                        && character_class_has_unicode_property_escape(character_class);
-                }
-                Term::LookAroundAssertion(assertion) => {
-                    return self.look_behind_assertions
-                        && matches!(
-                            assertion.kind,
-                            LookAroundAssertionKind::Lookbehind
-                                | LookAroundAssertionKind::NegativeLookbehind
-                        );
-                }
-                Term::Quantifier(quantifier) => term = &quantifier.body,
-                _ => return false,
-            }
+/// Features of a pattern which targets may not support.
+#[derive(Default)]
+struct PatternFeatures {
+    look_behind_assertions: bool,
+    named_capture_groups: bool,
+    unicode_property_escapes: bool,
+}
+
+impl<'a> Visit<'a> for PatternFeatures {
+    fn visit_lookaround_assertion(&mut self, it: &LookAroundAssertion<'a>) {
+        if matches!(
+            it.kind,
+            LookAroundAssertionKind::Lookbehind | LookAroundAssertionKind::NegativeLookbehind
+        ) {
+            self.look_behind_assertions = true;
         }
+        walk::walk_lookaround_assertion(self, it);
     }
-}
 
-fn character_class_has_unicode_property_escape(character_class: &CharacterClass) -> bool {
-    character_class.body.iter().any(|element| match element {
-        CharacterClassContents::UnicodePropertyEscape(_) => true,
-        CharacterClassContents::NestedCharacterClass(character_class) => {
-            character_class_has_unicode_property_escape(character_class)
+    fn visit_capturing_group(&mut self, it: &CapturingGroup<'a>) {
+        if it.name.is_some() {
+            self.named_capture_groups = true;
         }
-        _ => false,
-    })
+        walk::walk_capturing_group(self, it);
+    }
+
+    fn visit_unicode_property_escape(&mut self, _it: &UnicodePropertyEscape<'a>) {
+        self.unicode_property_escapes = true;
+    }
 }
 
 fn try_parse_pattern<'a>(
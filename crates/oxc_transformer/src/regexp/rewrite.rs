@@ -0,0 +1,647 @@
+//! Rewrite RegExp patterns into equivalent patterns which older engines support.
+//!
+//! Based on [regexpu-core](https://github.com/mathiasbynens/regexpu-core), which Babel's RegExp plugins use.
+//!
+//! * `\p{...}` property escapes are expanded into explicit character classes.
+//! * `v` flag set operations (`[A--B]`, `[A&&B]`, nested classes and `\q{...}`) are evaluated
+//!   into plain character classes (and alternations for strings).
+//! * Removing the `u` flag rewrites astral code points and ranges into surrogate pair alternations.
+//! * Removing the `s` flag rewrites `.` into a class which matches every code point.
+//! * Named groups are stripped, and named backreferences replaced with indexed ones.
+//!   The caller restores `groups` with the `wrapRegExp` helper.
+
+use std::fmt::Write;
+
+use oxc_ast::ast::RegExpFlags;
+use oxc_regular_expression::ast::{
+    Alternative, CharacterClass, CharacterClassContents, CharacterClassContentsKind,
+    CharacterClassEscape, CharacterClassEscapeKind, Disjunction, IgnoreGroup, LookAroundAssertion,
+    LookAroundAssertionKind, Modifier, Pattern, Quantifier, Term, UnicodePropertyEscape,
+};
+use oxc_span::Atom;
+
+use super::{
+    options::RegExpOptions,
+    unicode::{CodePointSet, LEAD_SURROGATES, MAX_CODE_POINT, TRAIL_SURROGATES, to_surrogate_pair},
+};
+
+/// Inclusive range of code points or code units.
+type CodePointRange = (u32, u32);
+
+/// The pattern uses syntax or Unicode data which cannot be rewritten.
+pub struct Unsupported;
+
+pub struct RewrittenPattern<'p> {
+    pub pattern: String,
+    pub flags: RegExpFlags,
+    /// Names of stripped named groups, and the indices of groups with each name.
+    pub groups: Vec<(&'p str, Vec<u32>)>,
+}
+
+pub fn rewrite_pattern<'p>(
+    pattern: &'p Pattern<'_>,
+    flags: RegExpFlags,
+    options: RegExpOptions,
+) -> Result<RewrittenPattern<'p>, Unsupported> {
+    let mut rewriter = PatternRewriter::new(flags, options);
+    if rewriter.strip_names {
+        let mut index = 0;
+        collect_groups(&pattern.body, &mut index, &mut rewriter.groups);
+        if rewriter.groups.is_empty() {
+            rewriter.strip_names = false;
+        }
+    }
+    rewriter.write_disjunction(&pattern.body)?;
+
+    let mut flags = flags;
+    if rewriter.remove_dot_all {
+        flags.remove(RegExpFlags::S);
+    }
+    if rewriter.remove_unicode {
+        flags.remove(RegExpFlags::U | RegExpFlags::V);
+    } else if rewriter.lower_sets {
+        flags.remove(RegExpFlags::V);
+        flags.insert(RegExpFlags::U);
+    }
+
+    let groups = if rewriter.strip_names { rewriter.groups } else { vec![] };
+    Ok(RewrittenPattern { pattern: rewriter.out, flags, groups })
+}
+
+/// Collect named groups in the order of their opening parentheses, which determines their indices.
+fn collect_groups<'p>(
+    disjunction: &'p Disjunction<'_>,
+    index: &mut u32,
+    groups: &mut Vec<(&'p str, Vec<u32>)>,
+) {
+    for term in disjunction.body.iter().flat_map(|alternative| &alternative.body) {
+        let mut term = term;
+        while let Term::Quantifier(quantifier) = term {
+            term = &quantifier.body;
+        }
+        match term {
+            Term::CapturingGroup(group) => {
+                *index += 1;
+                if let Some(name) = &group.name {
+                    match groups.iter_mut().find(|(group_name, _)| *group_name == name.as_str()) {
+                        Some((_, indices)) => indices.push(*index),
+                        None => groups.push((name.as_str(), vec![*index])),
+                    }
+                }
+                collect_groups(&group.body, index, groups);
+            }
+            Term::IgnoreGroup(group) => collect_groups(&group.body, index, groups),
+            Term::LookAroundAssertion(assertion) => collect_groups(&assertion.body, index, groups),
+            _ => {}
+        }
+    }
+}
+
+struct PatternRewriter<'p> {
+    /// The pattern has the `u` or `v` flag.
+    unicode_mode: bool,
+    ignore_case: bool,
+    dot_all: bool,
+
+    /// Remove the `u` or `v` flag, rewriting the pattern to work on UTF-16 code units.
+    remove_unicode: bool,
+    /// Replace the `v` flag with `u`, evaluating set operations.
+    lower_sets: bool,
+    remove_dot_all: bool,
+    expand_properties: bool,
+    strip_names: bool,
+
+    groups: Vec<(&'p str, Vec<u32>)>,
+    out: String,
+}
+
+impl PatternRewriter<'_> {
+    fn new(flags: RegExpFlags, options: RegExpOptions) -> Self {
+        let unicode_sets_mode = flags.contains(RegExpFlags::V);
+        let unicode_mode = unicode_sets_mode || flags.contains(RegExpFlags::U);
+        // Keep the `v` flag as is when targets support it, even if they don't support some other features
+        let keep_sets = unicode_sets_mode && !options.set_notation;
+        let lower_sets = unicode_sets_mode && options.set_notation;
+        let remove_unicode = unicode_mode && !keep_sets && options.unicode_flag;
+        Self {
+            unicode_mode,
+            ignore_case: flags.contains(RegExpFlags::I),
+            dot_all: flags.contains(RegExpFlags::S),
+            remove_unicode,
+            lower_sets,
+            remove_dot_all: flags.contains(RegExpFlags::S) && options.dot_all_flag,
+            expand_properties: unicode_mode
+                && !keep_sets
+                && (options.unicode_property_escapes || remove_unicode),
+            strip_names: options.named_capture_groups,
+            groups: vec![],
+            out: String::new(),
+        }
+    }
+
+    /// Case folding has to be done here, rather than by the engine, once the `u` flag is removed.
+    fn fold_case(&self) -> bool {
+        self.ignore_case && self.remove_unicode
+    }
+
+    fn write_disjunction(&mut self, disjunction: &Disjunction<'_>) -> Result<(), Unsupported> {
+        for (i, alternative) in disjunction.body.iter().enumerate() {
+            if i > 0 {
+                self.out.push('|');
+            }
+            self.write_alternative(alternative)?;
+        }
+        Ok(())
+    }
+
+    fn write_alternative(&mut self, alternative: &Alternative<'_>) -> Result<(), Unsupported> {
+        let mut terms = alternative.body.iter().peekable();
+        while let Some(term) = terms.next() {
+            let start = self.out.len();
+            self.write_term(term)?;
+            // `\k<a>1` -> `(?:\1)1`, not `\11`
+            if matches!(term, Term::NamedReference(_))
+                && self.strip_names
+                && matches!(terms.peek(), Some(Term::Character(c)) if char::from_u32(c.value).is_some_and(|c| c.is_ascii_digit()))
+            {
+                self.out.insert_str(start, "(?:");
+                self.out.push(')');
+            }
+        }
+        Ok(())
+    }
+
+    /// Write a term, and return whether the output is a single atom which can be quantified as is.
+    fn write_term(&mut self, term: &Term<'_>) -> Result<bool, Unsupported> {
+        match term {
+            Term::BoundaryAssertion(assertion) => {
+                write!(self.out, "{assertion}").unwrap();
+            }
+            Term::LookAroundAssertion(assertion) => self.write_look_around(assertion)?,
+            Term::Quantifier(quantifier) => self.write_quantifier(quantifier)?,
+            Term::Character(character) => {
+                let cp = character.value;
+                let is_surrogate = (LEAD_SURROGATES.0..=TRAIL_SURROGATES.1).contains(&cp);
+                if !self.remove_unicode {
+                    write!(self.out, "{character}").unwrap();
+                } else if self.fold_case() {
+                    let set = CodePointSet::single(cp).case_fold();
+                    // Without the `u` flag, the engine only matches characters with the same uppercase
+                    let canonical = canonicalize(cp);
+                    let engine_matches_all = set.ranges().iter().all(|&(start, end)| {
+                        (start..=end).all(|member| canonicalize(member) == canonical)
+                    });
+                    if engine_matches_all && cp <= 0xFFFF && !is_surrogate {
+                        write!(self.out, "{character}").unwrap();
+                    } else {
+                        return Ok(self.write_set(&set, &[], false));
+                    }
+                } else if cp > 0xFFFF {
+                    let (lead, trail) = to_surrogate_pair(cp);
+                    write!(self.out, "\\u{lead:04X}\\u{trail:04X}").unwrap();
+                    return Ok(false);
+                } else if is_surrogate {
+                    return Ok(self.write_set(&CodePointSet::single(cp), &[], false));
+                } else {
+                    write!(self.out, "{character}").unwrap();
+                }
+            }
+            Term::Dot(_) => {
+                if self.remove_unicode {
+                    return Ok(self.write_set(&CodePointSet::dot(self.dot_all), &[], false));
+                } else if self.remove_dot_all {
+                    self.out.push_str(if self.unicode_mode {
+                        "[\\0-\\u{10FFFF}]"
+                    } else {
+                        "[\\0-\\uFFFF]"
+                    });
+                } else {
+                    self.out.push('.');
+                }
+            }
+            Term::CharacterClassEscape(escape) => return Ok(self.write_class_escape(escape)),
+            Term::UnicodePropertyEscape(escape) => {
+                if self.expand_properties {
+                    let set = property(escape)?;
+                    return Ok(self.write_class_set(set, &[], escape.negative));
+                }
+                write!(self.out, "{escape}").unwrap();
+            }
+            Term::CharacterClass(class) => {
+                if self.remove_unicode
+                    || self.lower_sets
+                    || (self.expand_properties && has_property_escape(class))
+                {
+                    let (set, strings) = self.class_contents(class)?;
+                    return Ok(self.write_class_set(set, &strings, class.negative));
+                }
+                write!(self.out, "{class}").unwrap();
+            }
+            Term::CapturingGroup(group) => {
+                self.out.push('(');
+                if let Some(name) = &group.name {
+                    if !self.strip_names {
+                        write!(self.out, "?<{name}>").unwrap();
+                    }
+                }
+                self.write_disjunction(&group.body)?;
+                self.out.push(')');
+            }
+            Term::IgnoreGroup(group) => self.write_ignore_group(group)?,
+            Term::IndexedReference(reference) => write!(self.out, "{reference}").unwrap(),
+            Term::NamedReference(reference) => {
+                let indices = self
+                    .groups
+                    .iter()
+                    .find(|(name, _)| *name == reference.name.as_str())
+                    .map(|(_, indices)| indices.as_slice());
+                match indices {
+                    Some(indices) if self.strip_names => {
+                        // Only one of duplicate named groups can participate in a match,
+                        // and references to groups which did not participate match the empty string
+                        let is_atom = indices.len() == 1;
+                        if !is_atom {
+                            self.out.push_str("(?:");
+                        }
+                        for index in indices {
+                            write!(self.out, "\\{index}").unwrap();
+                        }
+                        if !is_atom {
+                            self.out.push(')');
+                        }
+                    }
+                    _ => write!(self.out, "{reference}").unwrap(),
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn write_look_around(
+        &mut self,
+        assertion: &LookAroundAssertion<'_>,
+    ) -> Result<(), Unsupported> {
+        self.out.push_str(match assertion.kind {
+            LookAroundAssertionKind::Lookahead => "(?=",
+            LookAroundAssertionKind::NegativeLookahead => "(?!",
+            LookAroundAssertionKind::Lookbehind => "(?<=",
+            LookAroundAssertionKind::NegativeLookbehind => "(?<!",
+        });
+        self.write_disjunction(&assertion.body)?;
+        self.out.push(')');
+        Ok(())
+    }
+
+    fn write_quantifier(&mut self, quantifier: &Quantifier<'_>) -> Result<(), Unsupported> {
+        let start = self.out.len();
+        if !self.write_term(&quantifier.body)? {
+            self.out.insert_str(start, "(?:");
+            self.out.push(')');
+        }
+        match (quantifier.min, quantifier.max) {
+            (0, None) => self.out.push('*'),
+            (1, None) => self.out.push('+'),
+            (0, Some(1)) => self.out.push('?'),
+            (min, Some(max)) if min == max => write!(self.out, "{{{min}}}").unwrap(),
+            (min, Some(max)) => write!(self.out, "{{{min},{max}}}").unwrap(),
+            (min, None) => write!(self.out, "{{{min},}}").unwrap(),
+        }
+        if !quantifier.greedy {
+            self.out.push('?');
+        }
+        Ok(())
+    }
+
+    fn write_ignore_group(&mut self, group: &IgnoreGroup<'_>) -> Result<(), Unsupported> {
+        fn write_modifiers(out: &mut String, modifiers: Modifier) {
+            for (modifier, c) in [(Modifier::I, 'i'), (Modifier::M, 'm'), (Modifier::S, 's')] {
+                if modifiers.contains(modifier) {
+                    out.push(c);
+                }
+            }
+        }
+
+        self.out.push_str("(?");
+        if let Some(modifiers) = &group.modifiers {
+            write_modifiers(&mut self.out, modifiers.enabling);
+            if !modifiers.disabling.is_empty() {
+                self.out.push('-');
+                write_modifiers(&mut self.out, modifiers.disabling);
+            }
+        }
+        self.out.push(':');
+        self.write_disjunction(&group.body)?;
+        self.out.push(')');
+        Ok(())
+    }
+
+    fn write_class_escape(&mut self, escape: &CharacterClassEscape) -> bool {
+        let (positive, negative) = match escape.kind {
+            CharacterClassEscapeKind::D => (CharacterClassEscapeKind::D, false),
+            CharacterClassEscapeKind::NegativeD => (CharacterClassEscapeKind::D, true),
+            CharacterClassEscapeKind::S => (CharacterClassEscapeKind::S, false),
+            CharacterClassEscapeKind::NegativeS => (CharacterClassEscapeKind::S, true),
+            CharacterClassEscapeKind::W => (CharacterClassEscapeKind::W, false),
+            CharacterClassEscapeKind::NegativeW => (CharacterClassEscapeKind::W, true),
+        };
+        // Negated escapes match astral code points, and `\w` with `iu` flags matches U+017F and U+212A
+        if self.remove_unicode
+            && (negative || (self.ignore_case && positive == CharacterClassEscapeKind::W))
+        {
+            return self.write_class_set(CodePointSet::class_escape(positive), &[], negative);
+        }
+        write!(self.out, "{escape}").unwrap();
+        true
+    }
+
+    /// Evaluate the contents of a class, ignoring its negation.
+    /// Returns the code points, and strings whose length is not 1.
+    fn class_contents(
+        &self,
+        class: &CharacterClass<'_>,
+    ) -> Result<(CodePointSet, Vec<Vec<u32>>), Unsupported> {
+        let mut operands = class.body.iter().map(|contents| self.class_operand(contents));
+        let Some(first) = operands.next() else {
+            return Ok((CodePointSet::default(), vec![]));
+        };
+        let (mut set, mut strings) = first?;
+        for operand in operands {
+            let (other_set, other_strings) = operand?;
+            match class.kind {
+                CharacterClassContentsKind::Union => {
+                    set = set.union(&other_set);
+                    strings.extend(other_strings);
+                }
+                CharacterClassContentsKind::Intersection => {
+                    set = set.intersection(&other_set);
+                    strings.retain(|string| other_strings.contains(string));
+                }
+                CharacterClassContentsKind::Subtraction => {
+                    set = set.difference(&other_set);
+                    strings.retain(|string| !other_strings.contains(string));
+                }
+            }
+        }
+        strings.sort_unstable();
+        strings.dedup();
+        Ok((set, strings))
+    }
+
+    fn class_operand(
+        &self,
+        contents: &CharacterClassContents<'_>,
+    ) -> Result<(CodePointSet, Vec<Vec<u32>>), Unsupported> {
+        let set = match contents {
+            CharacterClassContents::CharacterClassRange(range) => {
+                CodePointSet::from_ranges([(range.min.value, range.max.value)])
+            }
+            CharacterClassContents::CharacterClassEscape(escape) => {
+                CodePointSet::class_escape(escape.kind)
+            }
+            CharacterClassContents::UnicodePropertyEscape(escape) => {
+                let set = property(escape)?;
+                if escape.negative { self.negate(&set) } else { set }
+            }
+            CharacterClassContents::Character(character) => CodePointSet::single(character.value),
+            CharacterClassContents::NestedCharacterClass(class) => {
+                let (set, strings) = self.class_contents(class)?;
+                if !class.negative {
+                    return Ok((set, strings));
+                }
+                self.negate(&set)
+            }
+            CharacterClassContents::ClassStringDisjunction(disjunction) => {
+                let mut set = CodePointSet::default();
+                let mut strings = vec![];
+                for string in &disjunction.body {
+                    if let [character] = string.body.as_slice() {
+                        set = set.union(&CodePointSet::single(character.value));
+                    } else {
+                        strings.push(string.body.iter().map(|character| character.value).collect());
+                    }
+                }
+                return Ok((set, strings));
+            }
+        };
+        Ok((set, vec![]))
+    }
+
+    /// Complement of `set`, as matched by `[^...]` with the same flags.
+    fn negate(&self, set: &CodePointSet) -> CodePointSet {
+        if self.ignore_case { set.case_fold().complement() } else { set.complement() }
+    }
+
+    /// Write a class with the given contents, and return whether the output is a single atom.
+    fn write_class_set(&mut self, set: CodePointSet, strings: &[Vec<u32>], negative: bool) -> bool {
+        if !self.remove_unicode {
+            // The engine handles negation and case folding
+            return self.write_set(&set, strings, negative);
+        }
+        let set = if self.fold_case() { set.case_fold() } else { set };
+        let set = if negative { set.complement() } else { set };
+        self.write_set(&set, strings, false)
+    }
+
+    /// Write `[set]`, or an alternation if there are strings or the output is not in unicode mode.
+    fn write_set(&mut self, set: &CodePointSet, strings: &[Vec<u32>], negative: bool) -> bool {
+        // Alternatives, and whether each is a single atom
+        let mut alternatives = vec![];
+
+        // Longest strings first, so they take precedence over their prefixes
+        let mut strings = strings.iter().collect::<Vec<_>>();
+        strings.sort_by_key(|string| std::cmp::Reverse(string.len()));
+        let has_empty_string = strings.last().is_some_and(|string| string.is_empty());
+        for string in strings.iter().filter(|string| !string.is_empty()) {
+            let mut text = String::new();
+            for &cp in *string {
+                if self.remove_unicode && cp > 0xFFFF {
+                    let (lead, trail) = to_surrogate_pair(cp);
+                    write!(text, "\\u{lead:04X}\\u{trail:04X}").unwrap();
+                } else {
+                    write_code_point(&mut text, cp, false);
+                }
+            }
+            alternatives.push((text, false));
+        }
+
+        if self.remove_unicode {
+            alternatives.extend(surrogate_alternatives(set));
+        } else if !set.is_empty() || alternatives.is_empty() {
+            let mut text = String::from(if negative { "[^" } else { "[" });
+            write_class_ranges(&mut text, set.ranges());
+            text.push(']');
+            alternatives.push((text, true));
+        }
+        if has_empty_string {
+            alternatives.push((String::new(), false));
+        }
+
+        match alternatives.as_slice() {
+            [] => self.out.push_str("[]"),
+            [(alternative, is_atom)] => {
+                self.out.push_str(alternative);
+                return *is_atom;
+            }
+            alternatives => {
+                self.out.push_str("(?:");
+                for (i, (alternative, _)) in alternatives.iter().enumerate() {
+                    if i > 0 {
+                        self.out.push('|');
+                    }
+                    self.out.push_str(alternative);
+                }
+                self.out.push(')');
+            }
+        }
+        true
+    }
+}
+
+/// `Canonicalize` of the spec, for the `i` flag without the `u` flag.
+fn canonicalize(cp: u32) -> u32 {
+    let Some(c) = char::from_u32(cp) else { return cp };
+    let mut upper = c.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) if c.is_ascii() || !upper.is_ascii() => upper as u32,
+        _ => cp,
+    }
+}
+
+fn has_property_escape(class: &CharacterClass<'_>) -> bool {
+    class.body.iter().any(|contents| match contents {
+        CharacterClassContents::UnicodePropertyEscape(_) => true,
+        CharacterClassContents::NestedCharacterClass(class) => has_property_escape(class),
+        _ => false,
+    })
+}
+
+fn property(escape: &UnicodePropertyEscape<'_>) -> Result<CodePointSet, Unsupported> {
+    // Properties of strings, e.g. `\p{RGI_Emoji}`
+    if escape.strings {
+        return Err(Unsupported);
+    }
+    CodePointSet::property(escape.name.as_str(), escape.value.as_ref().map(Atom::as_str))
+        .ok_or(Unsupported)
+}
+
+/// Split `set` into alternatives which match the same code points in a pattern without the `u` flag.
+///
+/// ```text
+/// [BMP]|lead[trail]|[lone lead](?![trail])|(?:[^lead]|^)[lone trail]
+/// ```
+fn surrogate_alternatives(set: &CodePointSet) -> Vec<(String, bool)> {
+    let bmp = set.intersection(&CodePointSet::from_ranges([(0, 0xD7FF), (0xE000, 0xFFFF)]));
+    let leads = set.intersection(&CodePointSet::from_ranges([LEAD_SURROGATES]));
+    let trails = set.intersection(&CodePointSet::from_ranges([TRAIL_SURROGATES]));
+    let astral = set.intersection(&CodePointSet::from_ranges([(0x10000, MAX_CODE_POINT)]));
+
+    let mut alternatives = vec![];
+    if !bmp.is_empty() {
+        alternatives.push((code_unit_class(&bmp), true));
+    }
+
+    // Trail surrogate ranges of each lead surrogate
+    let mut trails_by_lead: Vec<(u32, Vec<(u32, u32)>)> = vec![];
+    let mut add_pair = |lead: u32, trails: (u32, u32)| match trails_by_lead.last_mut() {
+        Some((last_lead, last_trails)) if *last_lead == lead => last_trails.push(trails),
+        _ => trails_by_lead.push((lead, vec![trails])),
+    };
+    for &(start, end) in astral.ranges() {
+        let (start_lead, start_trail) = to_surrogate_pair(start);
+        let (end_lead, end_trail) = to_surrogate_pair(end);
+        if start_lead == end_lead {
+            add_pair(start_lead, (start_trail, end_trail));
+            continue;
+        }
+        add_pair(start_lead, (start_trail, TRAIL_SURROGATES.1));
+        for lead in start_lead + 1..end_lead {
+            add_pair(lead, TRAIL_SURROGATES);
+        }
+        add_pair(end_lead, (TRAIL_SURROGATES.0, end_trail));
+    }
+    // Merge adjacent lead surrogates which have the same trail surrogates,
+    // e.g. `\uD800[\uDC00-\uDFFF]|\uD801[\uDC00-\uDFFF]` -> `[\uD800\uD801][\uDC00-\uDFFF]`
+    let mut pairs: Vec<(CodePointRange, Vec<CodePointRange>)> = vec![];
+    for (lead, trails) in trails_by_lead {
+        match pairs.last_mut() {
+            Some(((_, last_lead), last_trails))
+                if *last_lead + 1 == lead && *last_trails == trails =>
+            {
+                *last_lead = lead;
+            }
+            _ => pairs.push(((lead, lead), trails)),
+        }
+    }
+    for (leads, trails) in pairs {
+        let leads = code_unit_class(&CodePointSet::from_ranges([leads]));
+        let trails = code_unit_class(&CodePointSet::from_ranges(trails));
+        alternatives.push((format!("{leads}{trails}"), false));
+    }
+
+    if !leads.is_empty() {
+        let leads = code_unit_class(&leads);
+        alternatives.push((format!("{leads}(?![\\uDC00-\\uDFFF])"), false));
+    }
+    if !trails.is_empty() {
+        let trails = code_unit_class(&trails);
+        alternatives.push((format!("(?:[^\\uD800-\\uDBFF]|^){trails}"), false));
+    }
+    alternatives
+}
+
+/// A single escaped code unit, or a class of code units.
+fn code_unit_class(set: &CodePointSet) -> String {
+    let mut text = String::new();
+    if let [(start, end)] = set.ranges() {
+        if start == end && (LEAD_SURROGATES.0..=TRAIL_SURROGATES.1).contains(start) {
+            write!(text, "\\u{start:04X}").unwrap();
+            return text;
+        }
+    }
+    text.push('[');
+    write_class_ranges(&mut text, set.ranges());
+    text.push(']');
+    text
+}
+
+fn write_class_ranges(out: &mut String, ranges: &[(u32, u32)]) {
+    for &(start, end) in ranges {
+        if start == end {
+            write_code_point(out, start, true);
+        } else {
+            // `\0` can't be followed by a digit, but is fine before `-`
+            if start == 0 {
+                out.push_str("\\0");
+            } else {
+                write_code_point(out, start, true);
+            }
+            if end > start + 1 {
+                out.push('-');
+            }
+            write_code_point(out, end, true);
+        }
+    }
+}
+
+fn write_code_point(out: &mut String, cp: u32, in_class: bool) {
+    match cp {
+        0x09 => out.push_str("\\t"),
+        0x0A => out.push_str("\\n"),
+        0x0C => out.push_str("\\f"),
+        0x0D => out.push_str("\\r"),
+        0x2D if in_class => out.push_str("\\-"),
+        _ => match char::from_u32(cp) {
+            Some(
+                c @ ('^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}'
+                | '|' | '/'),
+            ) => {
+                out.push('\\');
+                out.push(c);
+            }
+            Some(c @ ' '..='~') => out.push(c),
+            _ if cp <= 0xFF => write!(out, "\\x{cp:02X}").unwrap(),
+            _ if cp <= 0xFFFF => write!(out, "\\u{cp:04X}").unwrap(),
+            _ => write!(out, "\\u{{{cp:X}}}").unwrap(),
+        },
+    }
+}
@@ -0,0 +1,194 @@
+//! Sets of code points, and the Unicode data needed to build them.
+//!
+//! Property data and case folding come from `regex-syntax`'s Unicode tables.
+//! Those tables operate on `char`s, so surrogates (U+D800 to U+DFFF) are handled separately here.
+
+use regex_syntax::hir::{Class, ClassUnicode, ClassUnicodeRange, HirKind};
+
+use oxc_regular_expression::ast::CharacterClassEscapeKind;
+
+pub const MAX_CODE_POINT: u32 = 0x10_FFFF;
+pub const LEAD_SURROGATES: (u32, u32) = (0xD800, 0xDBFF);
+pub const TRAIL_SURROGATES: (u32, u32) = (0xDC00, 0xDFFF);
+const SURROGATES: (u32, u32) = (0xD800, 0xDFFF);
+
+/// `\d`
+const DIGIT: &[(u32, u32)] = &[(0x30, 0x39)];
+/// `\s`: WhiteSpace and LineTerminator
+const SPACE: &[(u32, u32)] = &[
+    (0x09, 0x0D),
+    (0x20, 0x20),
+    (0xA0, 0xA0),
+    (0x1680, 0x1680),
+    (0x2000, 0x200A),
+    (0x2028, 0x2029),
+    (0x202F, 0x202F),
+    (0x205F, 0x205F),
+    (0x3000, 0x3000),
+    (0xFEFF, 0xFEFF),
+];
+/// `\w`
+const WORD: &[(u32, u32)] = &[(0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A)];
+/// Code points which `.` does not match without the `s` flag.
+const LINE_TERMINATORS: &[(u32, u32)] = &[(0x0A, 0x0A), (0x0D, 0x0D), (0x2028, 0x2029)];
+
+/// A set of code points, stored as sorted, non-overlapping and non-adjacent inclusive ranges.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CodePointSet {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CodePointSet {
+    pub fn from_ranges(ranges: impl IntoIterator<Item = (u32, u32)>) -> Self {
+        let mut ranges = ranges.into_iter().collect::<Vec<_>>();
+        ranges.sort_unstable();
+        let mut normalized: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match normalized.last_mut() {
+                Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+                _ => normalized.push((start, end)),
+            }
+        }
+        Self { ranges: normalized }
+    }
+
+    pub fn single(cp: u32) -> Self {
+        Self { ranges: vec![(cp, cp)] }
+    }
+
+    pub fn all() -> Self {
+        Self { ranges: vec![(0, MAX_CODE_POINT)] }
+    }
+
+    /// Code points matched by `.`.
+    pub fn dot(dot_all: bool) -> Self {
+        let line_terminators = Self::from_ranges(LINE_TERMINATORS.iter().copied());
+        if dot_all { Self::all() } else { line_terminators.complement() }
+    }
+
+    /// Code points matched by `\d`, `\s`, `\w` and their negations.
+    pub fn class_escape(kind: CharacterClassEscapeKind) -> Self {
+        let (ranges, negative) = match kind {
+            CharacterClassEscapeKind::D => (DIGIT, false),
+            CharacterClassEscapeKind::NegativeD => (DIGIT, true),
+            CharacterClassEscapeKind::S => (SPACE, false),
+            CharacterClassEscapeKind::NegativeS => (SPACE, true),
+            CharacterClassEscapeKind::W => (WORD, false),
+            CharacterClassEscapeKind::NegativeW => (WORD, true),
+        };
+        let set = Self::from_ranges(ranges.iter().copied());
+        if negative { set.complement() } else { set }
+    }
+
+    /// Code points matched by a `\p{...}` escape with a property which is not a property of strings.
+    ///
+    /// Returns `None` for properties which are not in the Unicode tables.
+    pub fn property(name: &str, value: Option<&str>) -> Option<Self> {
+        // `\p{Script=Greek}` or `\p{Alphabetic}`
+        let (name, value) = match value {
+            Some(value) => (Some(name), value),
+            None => (None, name),
+        };
+        let is_general_category = matches!(name, None | Some("General_Category" | "gc"));
+
+        // Surrogates are not in the tables, which are keyed by `char`
+        if is_general_category && matches!(value, "Cs" | "Surrogate") {
+            return Some(Self::from_ranges([SURROGATES]));
+        }
+        let includes_surrogates = (is_general_category && matches!(value, "C" | "Other"))
+            || (name.is_none() && matches!(value, "Any" | "Assigned"));
+
+        let text = match name {
+            Some(name) => format!(r"\p{{{name}={value}}}"),
+            None => format!(r"\p{{{value}}}"),
+        };
+        let hir = regex_syntax::Parser::new().parse(&text).ok()?;
+        let HirKind::Class(Class::Unicode(class)) = hir.kind() else { return None };
+        let mut set = Self::from_class(class);
+        if includes_surrogates {
+            set = set.union(&Self::from_ranges([SURROGATES]));
+        }
+        Some(set)
+    }
+
+    pub fn ranges(&self) -> &[(u32, u32)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(self.ranges.iter().chain(&other.ranges).copied())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a_start, a_end) = self.ranges[i];
+            let (b_start, b_end) = other.ranges[j];
+            let start = a_start.max(b_start);
+            let end = a_end.min(b_end);
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+
+    pub fn complement(&self) -> Self {
+        let mut ranges = vec![];
+        let mut next = 0;
+        for &(start, end) in &self.ranges {
+            if start > next {
+                ranges.push((next, start - 1));
+            }
+            next = end + 1;
+        }
+        if next <= MAX_CODE_POINT {
+            ranges.push((next, MAX_CODE_POINT));
+        }
+        Self { ranges }
+    }
+
+    /// Add all code points which are equivalent under simple case folding,
+    /// i.e. the set matched with the `i` and `u` flags.
+    pub fn case_fold(&self) -> Self {
+        let mut class = ClassUnicode::new(self.ranges.iter().filter_map(|&(start, end)| {
+            // Surrogates have no case mapping
+            let (start, end) = match (start, end) {
+                (start, end) if end < SURROGATES.0 || start > SURROGATES.1 => (start, end),
+                (start, _) if start >= SURROGATES.0 && end <= SURROGATES.1 => return None,
+                (start, end) => (start, end),
+            };
+            let start = char::from_u32(start).unwrap_or('\u{E000}');
+            let end = char::from_u32(end).unwrap_or('\u{D7FF}');
+            Some(ClassUnicodeRange::new(start, end))
+        }));
+        class.case_fold_simple();
+        self.union(&Self::from_class(&class))
+    }
+
+    fn from_class(class: &ClassUnicode) -> Self {
+        Self::from_ranges(
+            class.ranges().iter().map(|range| (range.start() as u32, range.end() as u32)),
+        )
+    }
+}
+
+/// Split an astral code point into a surrogate pair.
+pub fn to_surrogate_pair(cp: u32) -> (u32, u32) {
+    let cp = cp - 0x10000;
+    (0xD800 + (cp >> 10), 0xDC00 + (cp & 0x3FF))
+}
@@ -6,7 +6,7 @@ use oxc_ast::{NONE, ast::*};
 use oxc_ast_visit::{VisitMut, walk_mut};
 use oxc_data_structures::stack::NonEmptyStack;
 use oxc_ecmascript::ToInt32;
-use oxc_semantic::{ScopeFlags, ScopeId};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolId};
 use oxc_span::{Atom, SPAN, Span};
 use oxc_syntax::{
     number::{NumberBase, ToJsString},
@@ -18,20 +18,66 @@ use oxc_traverse::{BoundIdentifier, Traverse};
 
 use crate::{context::TraverseCtx, state::TransformState};
 
+use super::options::{ConstEnumModule, ConstEnumValue, ExternalConstEnums, TypeScriptOptions};
+
 /// enum member values (or None if it can't be evaluated at build time) keyed by names
 type PrevMembers<'a> = FxHashMap<Atom<'a>, Option<ConstantValue>>;
 
+#[derive(Default)]
 pub struct TypeScriptEnum<'a> {
     enums: FxHashMap<Atom<'a>, PrevMembers<'a>>,
+    external_const_enums: ExternalConstEnums,
+    /// Imported bindings which refer to external const enums.
+    /// Values are the import specifier, and the imported name (`None` for namespace imports).
+    imported_const_enums: FxHashMap<SymbolId, (Atom<'a>, Option<Atom<'a>>)>,
 }
 
 impl TypeScriptEnum<'_> {
-    pub fn new() -> Self {
-        Self { enums: FxHashMap::default() }
+    pub fn new(options: &TypeScriptOptions) -> Self {
+        Self { external_const_enums: options.external_const_enums.clone(), ..Self::default() }
     }
 }
 
 impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptEnum<'a> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.external_const_enums.is_empty() {
+            return;
+        }
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            if decl.import_kind.is_type() {
+                continue;
+            }
+            let Some(module) = self.external_const_enums.get(&decl.source.value) else { continue };
+            for specifier in decl.specifiers.iter().flatten() {
+                match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        let imported = specifier.imported.name();
+                        if !specifier.import_kind.is_type()
+                            && module.contains_key(imported.as_str())
+                        {
+                            self.imported_const_enums.insert(
+                                specifier.local.symbol_id(),
+                                (decl.source.value, Some(imported)),
+                            );
+                        }
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                        self.imported_const_enums
+                            .insert(specifier.local.symbol_id(), (decl.source.value, None));
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {}
+                }
+            }
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.imported_const_enums.is_empty() {
+            self.inline_external_const_enum_member(expr, ctx);
+        }
+    }
+
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         let new_stmt = match stmt {
             Statement::TSEnumDeclaration(ts_enum_decl) => {
@@ -321,6 +367,66 @@ impl<'a> TypeScriptEnum<'a> {
         statements
     }
 
+    /// Replace a member of an imported const enum with its value.
+    ///
+    /// ```ts
+    /// import { Direction } from "./direction";
+    /// import * as enums from "./direction";
+    /// Direction.Up;
+    /// enums.Direction.Down;
+    /// ```
+    /// ```js
+    /// 0;
+    /// 1;
+    /// ```
+    fn inline_external_const_enum_member(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(member_expr) = expr.as_member_expression() else { return };
+        let Some(member_name) = member_expr.static_property_name() else { return };
+
+        // `Direction.Up` or `enums.Direction.Up`
+        let (ident, enum_name) = match member_expr.object() {
+            Expression::Identifier(ident) => (ident, None),
+            object => {
+                let Some(object) = object.as_member_expression() else { return };
+                let Expression::Identifier(ident) = object.object() else { return };
+                let Some(enum_name) = object.static_property_name() else { return };
+                (ident, Some(enum_name))
+            }
+        };
+
+        let reference = ctx.scoping().get_reference(ident.reference_id());
+        let Some(symbol_id) = reference.symbol_id() else { return };
+        let Some((specifier, imported)) = self.imported_const_enums.get(&symbol_id) else {
+            return;
+        };
+        let enum_name = match (imported, enum_name) {
+            (Some(imported), None) => imported.as_str(),
+            (None, Some(enum_name)) => enum_name,
+            _ => return,
+        };
+        let Some(value) = self
+            .external_const_enums
+            .get(specifier)
+            .and_then(|module| module.get(enum_name))
+            .and_then(|members| members.get(member_name))
+        else {
+            return;
+        };
+
+        // The import is removed by `TypeScriptAnnotations` if no references remain
+        ctx.delete_reference_for_identifier(ident);
+        *expr = match value {
+            ConstEnumValue::Number(value) => Self::get_initializer_expr(*value, ctx),
+            ConstEnumValue::String(value) => {
+                ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(value), None)
+            }
+        };
+    }
+
     fn get_number_literal_expression(value: f64, ctx: &TraverseCtx<'a>) -> Expression<'a> {
         ctx.ast.expression_numeric_literal(SPAN, value, None, NumberBase::Decimal)
     }
@@ -356,7 +462,85 @@ enum ConstantValue {
     String(String),
 }
 
+impl From<ConstantValue> for ConstEnumValue {
+    fn from(value: ConstantValue) -> Self {
+        match value {
+            ConstantValue::Number(value) => Self::Number(value),
+            ConstantValue::String(value) => Self::String(value),
+        }
+    }
+}
+
+/// Collect the const enums exported from `program`.
+///
+/// See [`ExternalConstEnums::collect`].
+pub fn collect_const_enums(program: &Program<'_>) -> ConstEnumModule {
+    let mut collector = TypeScriptEnum::default();
+    let mut const_enums = vec![];
+    // Exported name and local name
+    let mut exports = vec![];
+
+    for stmt in &program.body {
+        match stmt {
+            Statement::TSEnumDeclaration(decl) => {
+                collector.collect_enum_members(decl);
+                if decl.r#const {
+                    const_enums.push(decl.id.name);
+                }
+            }
+            Statement::ExportNamedDeclaration(decl) => {
+                if let Some(Declaration::TSEnumDeclaration(decl)) = &decl.declaration {
+                    collector.collect_enum_members(decl);
+                    if decl.r#const {
+                        const_enums.push(decl.id.name);
+                        exports.push((decl.id.name, decl.id.name));
+                    }
+                } else if decl.source.is_none() {
+                    // `export { Direction }`
+                    exports.extend(
+                        decl.specifiers
+                            .iter()
+                            .map(|specifier| (specifier.exported.name(), specifier.local.name())),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    exports
+        .into_iter()
+        .filter(|(_, local)| const_enums.contains(local))
+        .filter_map(|(exported, local)| {
+            let members = collector.enums.get(&local)?;
+            let members = members
+                .iter()
+                .filter_map(|(name, value)| Some((name.to_string(), value.clone()?.into())))
+                .collect();
+            Some((exported.to_string(), members))
+        })
+        .collect()
+}
+
 impl<'a> TypeScriptEnum<'a> {
+    /// Evaluate the members of `decl` without transforming it, and record them in `self.enums`.
+    fn collect_enum_members(&mut self, decl: &TSEnumDeclaration<'a>) {
+        let mut members = self.enums.get(&decl.id.name).cloned().unwrap_or_default();
+        let mut prev_constant_value = Some(ConstantValue::Number(-1.0));
+        for member in &decl.body.members {
+            let constant_value = if let Some(initializer) = &member.initializer {
+                self.computed_constant_value(initializer, &members)
+            } else if let Some(ConstantValue::Number(value)) = prev_constant_value {
+                Some(ConstantValue::Number(value + 1.0))
+            } else {
+                None
+            };
+            prev_constant_value.clone_from(&constant_value);
+            members.insert(member.id.static_name(), constant_value);
+        }
+        self.enums.insert(decl.id.name, members);
+    }
+
     /// Evaluate the expression to a constant value.
     /// Refer to [babel](https://github.com/babel/babel/blob/610897a9a96c5e344e77ca9665df7613d2f88358/packages/babel-plugin-transform-typescript/src/enum.ts#L241C1-L394C2)
     fn computed_constant_value(
@@ -15,13 +15,18 @@ mod module;
 mod namespace;
 mod options;
 mod rewrite_extensions;
+mod rewrite_paths;
 
 use annotations::TypeScriptAnnotations;
 use r#enum::TypeScriptEnum;
 use module::TypeScriptModule;
 use namespace::TypeScriptNamespace;
-pub use options::{RewriteExtensionsMode, TypeScriptOptions};
+pub use options::{
+    ConstEnumMembers, ConstEnumModule, ConstEnumValue, ExternalConstEnums, RewriteExtensionsMode,
+    RewritePathsOptions, TypeScriptOptions,
+};
 use rewrite_extensions::TypeScriptRewriteExtensions;
+use rewrite_paths::TypeScriptRewritePaths;
 
 /// [Preset TypeScript](https://babeljs.io/docs/babel-preset-typescript)
 ///
@@ -52,6 +57,7 @@ pub struct TypeScript<'a, 'ctx> {
     namespace: TypeScriptNamespace<'a, 'ctx>,
     module: TypeScriptModule<'a, 'ctx>,
     rewrite_extensions: Option<TypeScriptRewriteExtensions>,
+    rewrite_paths: Option<TypeScriptRewritePaths>,
     // Options
     remove_class_fields_without_initializer: bool,
 }
@@ -61,10 +67,11 @@ impl<'a, 'ctx> TypeScript<'a, 'ctx> {
         Self {
             ctx,
             annotations: TypeScriptAnnotations::new(options, ctx),
-            r#enum: TypeScriptEnum::new(),
+            r#enum: TypeScriptEnum::new(options),
             namespace: TypeScriptNamespace::new(options, ctx),
             module: TypeScriptModule::new(options.only_remove_type_imports, ctx),
             rewrite_extensions: TypeScriptRewriteExtensions::new(options),
+            rewrite_paths: TypeScriptRewritePaths::new(options, ctx),
             remove_class_fields_without_initializer: !options.allow_declare_fields
                 || options.remove_class_fields_without_initializer,
         }
@@ -80,6 +87,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
             program.body.clear();
         } else {
             program.source_type = program.source_type.with_javascript(true);
+            self.r#enum.enter_program(program, ctx);
             self.namespace.enter_program(program, ctx);
         }
     }
@@ -112,6 +120,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
 
     fn enter_call_expression(&mut self, expr: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.enter_call_expression(expr, ctx);
+        if let Some(rewrite_paths) = &mut self.rewrite_paths {
+            rewrite_paths.enter_call_expression(expr, ctx);
+        }
     }
 
     fn enter_chain_element(&mut self, element: &mut ChainElement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -137,6 +148,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.enter_expression(expr, ctx);
+        self.r#enum.enter_expression(expr, ctx);
+        if let Some(rewrite_paths) = &mut self.rewrite_paths {
+            rewrite_paths.enter_expression(expr, ctx);
+        }
     }
 
     fn enter_simple_assignment_target(
@@ -286,6 +301,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
         node: &mut ImportDeclaration<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        if let Some(rewrite_paths) = &mut self.rewrite_paths {
+            rewrite_paths.enter_import_declaration(node, ctx);
+        }
         if let Some(rewrite_extensions) = &mut self.rewrite_extensions {
             rewrite_extensions.enter_import_declaration(node, ctx);
         }
@@ -296,6 +314,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
         node: &mut ExportAllDeclaration<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        if let Some(rewrite_paths) = &mut self.rewrite_paths {
+            rewrite_paths.enter_export_all_declaration(node, ctx);
+        }
         if let Some(rewrite_extensions) = &mut self.rewrite_extensions {
             rewrite_extensions.enter_export_all_declaration(node, ctx);
         }
@@ -306,6 +327,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
         node: &mut ExportNamedDeclaration<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        if let Some(rewrite_paths) = &mut self.rewrite_paths {
+            rewrite_paths.enter_export_named_declaration(node, ctx);
+        }
         if let Some(rewrite_extensions) = &mut self.rewrite_extensions {
             rewrite_extensions.enter_export_named_declaration(node, ctx);
         }
@@ -1,5 +1,13 @@
-use std::{borrow::Cow, fmt};
+use std::{
+    borrow::Cow,
+    fmt, fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
+use indexmap::IndexMap;
+use oxc_ast::ast::Program;
+use rustc_hash::FxHashMap;
 use serde::{
     Deserialize, Deserializer,
     de::{self, Visitor},
@@ -87,6 +95,17 @@ pub struct TypeScriptOptions {
     /// Unused.
     pub optimize_const_enums: bool,
 
+    /// Members of `const enum`s declared in other modules.
+    ///
+    /// When a file imports a const enum which is found in this table,
+    /// member accesses (`Direction.Up`) are replaced with their values,
+    /// and the import is removed if it is no longer referenced.
+    ///
+    /// Use [`ExternalConstEnums::collect`] to build the table from the modules which declare them.
+    ///
+    /// Defaults to empty (do nothing).
+    pub external_const_enums: ExternalConstEnums,
+
     // Preset options
     /// Modifies extensions in import and export declarations.
     ///
@@ -96,6 +115,16 @@ pub struct TypeScriptOptions {
     /// When set to `true`, same as [`RewriteExtensionsMode::Rewrite`]. Defaults to `false` (do nothing).
     #[serde(deserialize_with = "deserialize_rewrite_import_extensions")]
     pub rewrite_import_extensions: Option<RewriteExtensionsMode>,
+
+    /// Rewrites import specifiers which match path aliases of tsconfig's
+    /// [`compilerOptions.paths`](https://www.typescriptlang.org/tsconfig#paths) or
+    /// [`compilerOptions.baseUrl`](https://www.typescriptlang.org/tsconfig#baseUrl)
+    /// to paths relative to the current file.
+    ///
+    /// Applies to `import` / `export` declarations, dynamic `import()` and `require()` calls.
+    ///
+    /// Defaults to `None` (do nothing).
+    pub rewrite_paths: Option<RewritePathsOptions>,
 }
 
 impl Default for TypeScriptOptions {
@@ -108,7 +137,9 @@ impl Default for TypeScriptOptions {
             allow_declare_fields: default_as_true(),
             remove_class_fields_without_initializer: false,
             optimize_const_enums: false,
+            external_const_enums: ExternalConstEnums::default(),
             rewrite_import_extensions: None,
+            rewrite_paths: None,
         }
     }
 }
@@ -166,3 +197,147 @@ where
 
     deserializer.deserialize_any(RewriteExtensionsModeVisitor)
 }
+
+/// Value of a `const enum` member.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum ConstEnumValue {
+    Number(f64),
+    String(String),
+}
+
+/// Members of a `const enum`, keyed by member name.
+pub type ConstEnumMembers = FxHashMap<String, ConstEnumValue>;
+
+/// Const enums exported from a module, keyed by exported name.
+pub type ConstEnumModule = FxHashMap<String, ConstEnumMembers>;
+
+/// Const enums declared in other modules, keyed by the import specifier used to import them.
+///
+/// ```json
+/// { "./direction": { "Direction": { "Up": 0, "Down": 1 } } }
+/// ```
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(from = "FxHashMap<String, ConstEnumModule>")]
+pub struct ExternalConstEnums(Arc<FxHashMap<String, ConstEnumModule>>);
+
+impl From<FxHashMap<String, ConstEnumModule>> for ExternalConstEnums {
+    fn from(modules: FxHashMap<String, ConstEnumModule>) -> Self {
+        Self(Arc::new(modules))
+    }
+}
+
+impl ExternalConstEnums {
+    /// Add const enums exported from the module imported via `specifier`.
+    pub fn insert(&mut self, specifier: impl Into<String>, module: ConstEnumModule) {
+        Arc::make_mut(&mut self.0).insert(specifier.into(), module);
+    }
+
+    /// Get the const enums exported from the module imported via `specifier`.
+    pub fn get(&self, specifier: &str) -> Option<&ConstEnumModule> {
+        self.0.get(specifier)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Collect the const enums exported from `program`.
+    ///
+    /// `program` must be the untransformed AST of a TypeScript module.
+    /// Members whose values cannot be evaluated at build time are omitted.
+    pub fn collect(program: &Program<'_>) -> ConstEnumModule {
+        super::r#enum::collect_const_enums(program)
+    }
+}
+
+/// Path aliases of tsconfig's `compilerOptions`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct RewritePathsOptions {
+    /// Directory which relative `base_url` and `paths` are resolved from.
+    /// This is usually the directory containing tsconfig.
+    ///
+    /// If it is relative, it is resolved from [`crate::TransformOptions::cwd`].
+    pub base_path: PathBuf,
+
+    /// Base directory to resolve non-relative module names from.
+    ///
+    /// When set, `paths` are resolved relative to it, otherwise relative to `base_path`.
+    pub base_url: Option<PathBuf>,
+
+    /// Map of module name patterns to a list of locations, e.g. `{ "@/*": ["./src/*"] }`.
+    ///
+    /// Patterns may contain a single `*` wildcard. When a pattern has multiple locations, the first
+    /// one which exists on disk is used, falling back to the first location.
+    pub paths: IndexMap<String, Vec<String>>,
+}
+
+impl RewritePathsOptions {
+    /// Read `compilerOptions.baseUrl` and `compilerOptions.paths` from a tsconfig file.
+    ///
+    /// Relative `extends` are followed, and options in the extending file take precedence.
+    ///
+    /// # Errors
+    ///
+    /// * When the file, or a file it extends, cannot be read or parsed.
+    pub fn from_tsconfig(path: &Path) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut has_base_url = false;
+        let mut has_paths = false;
+        let mut path = path.to_path_buf();
+        // Limit depth to avoid infinite loops on circular `extends`.
+        for _ in 0..32 {
+            let mut text = fs::read_to_string(&path)
+                .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+            json_strip_comments::strip(&mut text)
+                .map_err(|err| format!("Failed to parse {}: {err}", path.display()))?;
+            let tsconfig: TsConfig = serde_json::from_str(&text)
+                .map_err(|err| format!("Failed to parse {}: {err}", path.display()))?;
+            let dir = path.parent().unwrap_or_else(|| Path::new("")).to_path_buf();
+
+            let compiler_options = tsconfig.compiler_options;
+            if !has_base_url {
+                if let Some(base_url) = compiler_options.base_url {
+                    has_base_url = true;
+                    options.base_url = Some(dir.join(base_url));
+                }
+            }
+            if !has_paths {
+                if let Some(paths) = compiler_options.paths {
+                    has_paths = true;
+                    // `paths` are resolved relative to the tsconfig which declares them.
+                    options.base_path.clone_from(&dir);
+                    options.paths = paths;
+                }
+            }
+
+            match tsconfig.extends {
+                Some(extends) if extends.starts_with('.') => {
+                    let mut extends = dir.join(extends);
+                    if extends.extension().is_none() {
+                        extends.set_extension("json");
+                    }
+                    path = extends;
+                }
+                _ => break,
+            }
+        }
+        Ok(options)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TsConfig {
+    extends: Option<String>,
+    #[serde(default)]
+    compiler_options: TsConfigCompilerOptions,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TsConfigCompilerOptions {
+    base_url: Option<String>,
+    paths: Option<IndexMap<String, Vec<String>>>,
+}
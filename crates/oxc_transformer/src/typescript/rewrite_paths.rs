@@ -0,0 +1,266 @@
+//! Rewrite path aliases
+//!
+//! This plugin rewrites import specifiers which match tsconfig's `compilerOptions.paths` or
+//! `compilerOptions.baseUrl` to paths relative to the current file, so the output can run
+//! without a resolver which understands tsconfig (e.g. plain Node.js).
+//!
+//! ## Example
+//!
+//! tsconfig.json:
+//! ```json
+//! { "compilerOptions": { "paths": { "@/*": ["./src/*"] } } }
+//! ```
+//!
+//! Input (`src/pages/index.ts`):
+//! ```ts
+//! import { foo } from "@/utils/foo";
+//! const bar = await import("@/utils/bar");
+//! ```
+//!
+//! Output:
+//! ```js
+//! import { foo } from "../utils/foo";
+//! const bar = await import("../utils/bar");
+//! ```
+//!
+//! ## Implementation
+//!
+//! Matching follows TypeScript's module resolution:
+//!
+//! * A pattern without `*` only matches the exact specifier, and takes precedence over patterns with `*`.
+//! * Among patterns with `*`, the one with the longest prefix before `*` wins.
+//! * Each location is tried in order and the first one which exists on disk is used.
+//!   If none exist, the first location is used.
+//! * When `baseUrl` is set, non-relative specifiers which don't match any pattern are resolved from
+//!   `baseUrl`, and rewritten only if they exist on disk.
+//!
+//! Based on [typescript-transform-paths](https://github.com/LeDDGroup/typescript-transform-paths)
+//! and TypeScript's [`tryLoadModuleUsingPathsIfEligible`](https://github.com/microsoft/TypeScript/blob/v5.8.3/src/compiler/moduleNameResolver.ts#L1558-L1583).
+
+use std::path::{Component, Path, PathBuf};
+
+use cow_utils::CowUtils;
+
+use oxc_ast::ast::{
+    Argument, CallExpression, ExportAllDeclaration, ExportNamedDeclaration, Expression,
+    ImportDeclaration, StringLiteral,
+};
+use oxc_semantic::IsGlobalReference;
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use super::options::{RewritePathsOptions, TypeScriptOptions};
+
+/// Extensions and index files tried when checking if a location exists.
+const RESOLVE_SUFFIXES: &[&str] = &[
+    "",
+    ".ts",
+    ".tsx",
+    ".d.ts",
+    ".mts",
+    ".cts",
+    ".js",
+    ".jsx",
+    ".mjs",
+    ".cjs",
+    ".json",
+    "/index.ts",
+    "/index.tsx",
+    "/index.d.ts",
+    "/index.js",
+    "/index.jsx",
+];
+
+pub struct TypeScriptRewritePaths {
+    /// Directory which `paths` locations are resolved from.
+    paths_base: PathBuf,
+    base_url: Option<PathBuf>,
+    /// Exact patterns, e.g. `"jquery"`.
+    exact: Vec<(String, Vec<String>)>,
+    /// Patterns with a wildcard, split into prefix and suffix, e.g. `"@/*"` -> `("@/", "")`.
+    wildcard: Vec<(String, String, Vec<String>)>,
+    /// Directory of the current file.
+    source_dir: PathBuf,
+}
+
+impl TypeScriptRewritePaths {
+    pub fn new(options: &TypeScriptOptions, ctx: &TransformCtx) -> Option<Self> {
+        let RewritePathsOptions { base_path, base_url, paths } = options.rewrite_paths.as_ref()?;
+
+        let base_path = normalize(&ctx.cwd.join(base_path));
+        let base_url = base_url.as_ref().map(|base_url| normalize(&base_path.join(base_url)));
+        let paths_base = base_url.clone().unwrap_or_else(|| base_path.clone());
+
+        let mut exact = vec![];
+        let mut wildcard = vec![];
+        for (pattern, locations) in paths {
+            if let Some((prefix, suffix)) = pattern.split_once('*') {
+                wildcard.push((prefix.to_string(), suffix.to_string(), locations.clone()));
+            } else {
+                exact.push((pattern.clone(), locations.clone()));
+            }
+        }
+
+        let source_path = ctx.cwd.join(&ctx.source_path);
+        let source_dir = normalize(source_path.parent().unwrap_or_else(|| Path::new("")));
+
+        Some(Self { paths_base, base_url, exact, wildcard, source_dir })
+    }
+
+    fn rewrite_paths<'a>(&self, source: &mut StringLiteral<'a>, ctx: &TraverseCtx<'a>) {
+        let Some(resolved) = self.resolve(source.value.as_str()) else { return };
+        let relative = relative_specifier(&self.source_dir, &resolved);
+        source.value = ctx.ast.atom(&relative);
+        source.raw = None;
+    }
+
+    /// Resolve `specifier` to an absolute path, or `None` if it isn't an alias.
+    fn resolve(&self, specifier: &str) -> Option<PathBuf> {
+        // Relative and absolute specifiers are never aliases
+        if specifier.starts_with('.') || Path::new(specifier).is_absolute() {
+            return None;
+        }
+
+        if let Some(locations) = self.match_pattern(specifier) {
+            let candidates = locations
+                .iter()
+                .map(|(location, star)| {
+                    self.paths_base.join(location.cow_replacen('*', star, 1).as_ref())
+                })
+                .collect::<Vec<_>>();
+            let resolved = candidates
+                .iter()
+                .find(|candidate| exists(candidate))
+                .or_else(|| candidates.first())?;
+            return Some(normalize(resolved));
+        }
+
+        let resolved = self.base_url.as_ref()?.join(specifier);
+        exists(&resolved).then(|| normalize(&resolved))
+    }
+
+    /// Find the pattern which matches `specifier`,
+    /// and return its locations with the text matched by `*`.
+    fn match_pattern<'s>(&'s self, specifier: &'s str) -> Option<Vec<(&'s str, &'s str)>> {
+        if let Some((_, locations)) = self.exact.iter().find(|(pattern, _)| pattern == specifier) {
+            return Some(locations.iter().map(|location| (location.as_str(), "")).collect());
+        }
+
+        let mut best: Option<(&str, &Vec<String>)> = None;
+        let mut best_prefix_len = 0;
+        for (prefix, suffix, locations) in &self.wildcard {
+            if specifier.len() < prefix.len() + suffix.len()
+                || !specifier.starts_with(prefix.as_str())
+                || !specifier.ends_with(suffix.as_str())
+            {
+                continue;
+            }
+            if best.is_none() || prefix.len() > best_prefix_len {
+                let star = &specifier[prefix.len()..specifier.len() - suffix.len()];
+                best = Some((star, locations));
+                best_prefix_len = prefix.len();
+            }
+        }
+        let (star, locations) = best?;
+        Some(locations.iter().map(|location| (location.as_str(), star)).collect())
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptRewritePaths {
+    fn enter_import_declaration(
+        &mut self,
+        node: &mut ImportDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if node.import_kind.is_type() {
+            return;
+        }
+        self.rewrite_paths(&mut node.source, ctx);
+    }
+
+    fn enter_export_named_declaration(
+        &mut self,
+        node: &mut ExportNamedDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if node.export_kind.is_type() {
+            return;
+        }
+        if let Some(source) = node.source.as_mut() {
+            self.rewrite_paths(source, ctx);
+        }
+    }
+
+    fn enter_export_all_declaration(
+        &mut self,
+        node: &mut ExportAllDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if node.export_kind.is_type() {
+            return;
+        }
+        self.rewrite_paths(&mut node.source, ctx);
+    }
+
+    /// `import("@/foo")`
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::ImportExpression(import) = expr {
+            if let Expression::StringLiteral(source) = &mut import.source {
+                self.rewrite_paths(source, ctx);
+            }
+        }
+    }
+
+    /// `require("@/foo")`
+    fn enter_call_expression(&mut self, call: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::Identifier(callee) = &call.callee else { return };
+        if callee.name != "require" || !callee.is_global_reference(ctx.scoping()) {
+            return;
+        }
+        if let [Argument::StringLiteral(source)] = call.arguments.as_mut_slice() {
+            self.rewrite_paths(source, ctx);
+        }
+    }
+}
+
+fn exists(path: &Path) -> bool {
+    let path = path.to_string_lossy();
+    RESOLVE_SUFFIXES.iter().any(|suffix| Path::new(&format!("{path}{suffix}")).exists())
+}
+
+/// Lexically remove `.` and `..` components.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Get specifier for `to` relative to directory `from`, e.g. `./foo` or `../foo`.
+fn relative_specifier(from: &Path, to: &Path) -> String {
+    let from = from.components().collect::<Vec<_>>();
+    let to = to.components().collect::<Vec<_>>();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let mut parts = vec![];
+    parts.extend((common..from.len()).map(|_| "..".to_string()));
+    parts.extend(
+        to[common..].iter().map(|component| component.as_os_str().to_string_lossy().into_owned()),
+    );
+
+    let specifier = parts.join("/");
+    if specifier.starts_with("..") { specifier } else { format!("./{specifier}") }
+}
@@ -0,0 +1,105 @@
+use std::{fs, path::Path};
+
+use oxc_transformer::{BabelConfig, BabelConfigLoadOptions};
+
+use crate::test;
+
+fn load(cwd: &Path, filename: &str, env_name: &str) -> BabelConfig {
+    BabelConfig::load(&BabelConfigLoadOptions {
+        cwd: cwd.to_path_buf(),
+        filename: filename.into(),
+        env_name: Some(env_name.to_string()),
+        ..BabelConfigLoadOptions::default()
+    })
+    .unwrap()
+}
+
+#[test]
+fn config_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::create_dir_all(root.join("packages/app")).unwrap();
+    fs::write(root.join("package.json"), r#"{ "name": "root" }"#).unwrap();
+    fs::write(
+        root.join("babel.config.json"),
+        r#"{
+            // Comments are allowed
+            "plugins": ["babel-plugin-macros", "@babel/plugin-transform-modules-commonjs"],
+            "env": {
+                "test": { "plugins": [["@babel/plugin-transform-arrow-functions"]] }
+            },
+            "overrides": [{
+                "test": "./packages/legacy/**/*.js",
+                "plugins": [["@babel/plugin-proposal-decorators", { "version": "legacy" }]]
+            }],
+            "assumptions": { "noDocumentAll": true }
+        }"#,
+    )
+    .unwrap();
+    fs::write(
+        root.join("shared.json"),
+        r#"{
+            "plugins": ["@babel/plugin-transform-exponentiation-operator"],
+            "assumptions": { "pureGetters": true }
+        }"#,
+    )
+    .unwrap();
+    fs::write(
+        root.join("packages/app/package.json"),
+        r#"{
+            "name": "app",
+            "babel": {
+                "extends": "../../shared.json",
+                "ignore": ["./src/generated"],
+                "plugins": [["babel-plugin-macros", false]]
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let config = load(root, "packages/app/src/index.js", "test");
+    assert_eq!(
+        config.files,
+        vec![
+            root.join("babel.config.json"),
+            root.join("shared.json"),
+            root.join("packages/app/package.json")
+        ]
+    );
+    assert_eq!(config.unsupported, vec!["transform-modules-commonjs".to_string()]);
+    assert!(!config.ignored);
+    assert!(config.options.env.es2015.arrow_function.is_some());
+    assert!(config.options.env.es2016.exponentiation_operator);
+    assert!(!config.options.decorator.legacy);
+    assert!(config.options.assumptions.no_document_all);
+    assert!(config.options.assumptions.pure_getters);
+    assert_eq!(test("a ** b", &config.options).unwrap(), "Math.pow(a, b);\n");
+
+    let config = load(root, "packages/legacy/src/index.js", "production");
+    assert_eq!(config.files, vec![root.join("babel.config.json")]);
+    assert_eq!(
+        config.unsupported,
+        vec!["macros".to_string(), "transform-modules-commonjs".to_string()]
+    );
+    assert!(config.options.env.es2015.arrow_function.is_none());
+    assert!(config.options.decorator.legacy);
+
+    let config = load(root, "packages/app/src/generated/schema.js", "test");
+    assert!(config.ignored);
+}
+
+#[test]
+fn unsupported_config_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::write(root.join("babel.config.js"), "module.exports = {};").unwrap();
+    let options = BabelConfigLoadOptions {
+        cwd: root.to_path_buf(),
+        filename: "index.js".into(),
+        ..BabelConfigLoadOptions::default()
+    };
+    assert!(BabelConfig::load(&options).is_err());
+
+    let options = BabelConfigLoadOptions { config_file: Some("missing.json".into()), ..options };
+    assert!(BabelConfig::load(&options).is_err());
+}
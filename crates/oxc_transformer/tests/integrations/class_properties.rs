@@ -0,0 +1,23 @@
+use oxc_transformer::{ClassPropertiesOptions, CompilerAssumptions, TransformOptions};
+
+use crate::test;
+
+#[test]
+fn private_fields_as_properties_and_symbols() {
+    let mut options = TransformOptions {
+        assumptions: CompilerAssumptions {
+            private_fields_as_properties: true,
+            private_fields_as_symbols: true,
+            ..CompilerAssumptions::default()
+        },
+        ..TransformOptions::default()
+    };
+    options.env.es2022.class_properties = Some(ClassPropertiesOptions::default());
+
+    let errors = test("class Foo { #foo = 1; }", &options).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].to_string(),
+        "Cannot enable both the \"privateFieldsAsProperties\" and \"privateFieldsAsSymbols\" assumptions as the same time."
+    );
+}
@@ -0,0 +1,136 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{
+    ConstEnumValue, ExternalConstEnums, TransformOptions, Transformer, TypeScriptOptions,
+};
+
+use crate::codegen;
+
+fn collect(source_text: &str) -> oxc_transformer::ConstEnumModule {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+    assert!(ret.errors.is_empty());
+    ExternalConstEnums::collect(&ret.program)
+}
+
+fn transform(source_text: &str, external_const_enums: ExternalConstEnums) -> String {
+    let options = TransformOptions {
+        typescript: TypeScriptOptions { external_const_enums, ..TypeScriptOptions::default() },
+        ..TransformOptions::default()
+    };
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, Path::new("test.ts"), &options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+#[test]
+fn collect_const_enums() {
+    let module = collect(
+        "
+        const enum Local { A = 1 }
+        export const enum Direction { Up, Down, Left = 10, Right }
+        export const enum Flags { None = 0, A = 1 << 0, B = 1 << 1, AB = A | B, C = Local.A * 4 }
+        export const enum Str { Hello = 'hello', World = `${Hello} world`, Dynamic = foo() }
+        export enum NotConst { A }
+        const enum Renamed { X = -1 }
+        export { Renamed as Alias, Local };
+        ",
+    );
+
+    let mut names = module.keys().map(String::as_str).collect::<Vec<_>>();
+    names.sort_unstable();
+    assert_eq!(names, ["Alias", "Direction", "Flags", "Local", "Str"]);
+
+    let number = |n: f64| Some(ConstEnumValue::Number(n));
+    assert_eq!(module["Direction"].get("Up").cloned(), number(0.0));
+    assert_eq!(module["Direction"].get("Right").cloned(), number(11.0));
+    assert_eq!(module["Flags"].get("AB").cloned(), number(3.0));
+    assert_eq!(module["Flags"].get("C").cloned(), number(4.0));
+    assert_eq!(module["Alias"].get("X").cloned(), number(-1.0));
+    assert_eq!(module["Str"].get("Hello").cloned(), Some(ConstEnumValue::String("hello".into())));
+    // Not evaluable at build time
+    assert_eq!(module["Str"].get("Dynamic"), None);
+}
+
+#[test]
+fn inline_imported_const_enums() {
+    let mut external_const_enums = ExternalConstEnums::default();
+    external_const_enums.insert(
+        "./enums",
+        collect(
+            "
+            export const enum Direction { Up, Down, Infinite = 1 / 0 }
+            export const enum Greeting { Hello = 'hello' }
+            ",
+        ),
+    );
+
+    let cases = [
+        (
+            "
+            import { Direction, Greeting as G } from './enums';
+            console.log(Direction.Up, Direction['Down'], G.Hello, Direction.Infinite);
+            ",
+            "console.log(0, 1, 'hello', Infinity); export {};",
+        ),
+        (
+            "
+            import * as enums from './enums';
+            console.log(enums.Direction.Down);
+            ",
+            "console.log(1); export {};",
+        ),
+        // The import is kept when it is still referenced
+        (
+            "
+            import { Direction } from './enums';
+            console.log(Direction.Up, Object.keys(Direction));
+            ",
+            "
+            import { Direction } from './enums';
+            console.log(0, Object.keys(Direction));
+            ",
+        ),
+        // Unknown members and modules are left untouched
+        (
+            "
+            import { Direction } from './enums';
+            import { Other } from './other';
+            console.log(Direction.Unknown, Other.A);
+            ",
+            "
+            import { Direction } from './enums';
+            import { Other } from './other';
+            console.log(Direction.Unknown, Other.A);
+            ",
+        ),
+        // Shadowed by a local binding
+        (
+            "
+            import { Direction } from './enums';
+            function f(Direction) { return Direction.Up; }
+            ",
+            "
+            function f(Direction) { return Direction.Up; }
+            export {};
+            ",
+        ),
+    ];
+    for (source, expected) in cases {
+        let output = transform(source, external_const_enums.clone());
+        assert_eq!(output, codegen(expected, SourceType::mjs()), "{source}");
+    }
+}
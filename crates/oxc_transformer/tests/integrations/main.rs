@@ -1,4 +1,10 @@
+mod babel_config;
+mod class_properties;
+mod const_enums;
 mod es_target;
+mod polyfills;
+mod regexp;
+mod rewrite_paths;
 mod targets;
 
 use std::path::Path;
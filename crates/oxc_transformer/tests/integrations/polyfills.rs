@@ -0,0 +1,90 @@
+use oxc_span::SourceType;
+use oxc_transformer::{EnvOptions, PolyfillMethod, TransformOptions};
+
+use crate::{codegen, test};
+
+fn options(query: &str, method: PolyfillMethod) -> TransformOptions {
+    let mut env = EnvOptions::from_browserslist_query(query).unwrap();
+    env.polyfills.method = Some(method);
+    TransformOptions { env, ..TransformOptions::default() }
+}
+
+#[test]
+fn usage_global() {
+    let options = options("chrome 80", PolyfillMethod::UsageGlobal);
+    let cases = [
+        (
+            "Object.hasOwn(a, 'b'); Object.keys(a);",
+            "import 'core-js/modules/es.object.has-own.js'; Object.hasOwn(a, 'b'); Object.keys(a);",
+        ),
+        (
+            "x.at(-1); structuredClone(y); Promise.resolve();",
+            "import 'core-js/modules/es.array.at.js';
+            import 'core-js/modules/es.string.at-alternative.js';
+            import 'core-js/modules/es.typed-array.at.js';
+            import 'core-js/modules/web.structured-clone.js';
+            x.at(-1); structuredClone(y); Promise.resolve();",
+        ),
+        (
+            "x?.replaceAll('a', 'b')",
+            "import 'core-js/modules/es.string.replace-all.js';
+            var _x;
+            (_x = x) === null || _x === void 0 ? void 0 : _x.replaceAll('a', 'b');",
+        ),
+        // Supported by targets
+        ("Object.entries(a); x.flat();", "Object.entries(a); x.flat();"),
+        // Shadowed by a local binding
+        ("let structuredClone; structuredClone(y);", "let structuredClone; structuredClone(y);"),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(Ok(codegen(expected, SourceType::mjs())), test(source, &options), "{source}");
+    }
+}
+
+#[test]
+fn usage_pure() {
+    let options = options("chrome 80", PolyfillMethod::UsagePure);
+    let cases = [
+        (
+            "Object.hasOwn(a, 'b'); Object.hasOwn(c, 'd');",
+            "import _Object$hasOwn from 'core-js-pure/stable/object/has-own.js';
+            _Object$hasOwn(a, 'b'); _Object$hasOwn(c, 'd');",
+        ),
+        (
+            "x.at(-1); foo().at(0); this.at(1); x.at;",
+            "import _atInstanceProperty from 'core-js-pure/stable/instance/at.js';
+            var _context;
+            _atInstanceProperty(x).call(x, -1);
+            _atInstanceProperty(_context = foo()).call(_context, 0);
+            _atInstanceProperty(this).call(this, 1);
+            _atInstanceProperty(x);",
+        ),
+        (
+            "new AggregateError([]); globalThis;",
+            "import _AggregateError from 'core-js-pure/stable/aggregate-error.js';
+            new _AggregateError([]); globalThis;",
+        ),
+        // No pure version
+        ("s.union(t); delete x.at;", "s.union(t); delete x.at;"),
+        // Supported by targets
+        ("Promise.resolve(); x.flat();", "Promise.resolve(); x.flat();"),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(Ok(codegen(expected, SourceType::mjs())), test(source, &options), "{source}");
+    }
+}
+
+#[test]
+fn targets() {
+    let source = "Promise.withResolvers();";
+    let expected =
+        "import 'core-js/modules/es.promise.with-resolvers.js'; Promise.withResolvers();";
+    for query in ["node 20", "safari 17", "firefox 120", "samsung 24"] {
+        let options = options(query, PolyfillMethod::UsageGlobal);
+        assert_eq!(Ok(codegen(expected, SourceType::mjs())), test(source, &options), "{query}");
+    }
+    for query in ["node 22", "chrome 120", "edge 120", "firefox 121"] {
+        let options = options(query, PolyfillMethod::UsageGlobal);
+        assert_eq!(Ok(codegen(source, SourceType::mjs())), test(source, &options), "{query}");
+    }
+}
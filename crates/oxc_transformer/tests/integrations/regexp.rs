@@ -0,0 +1,63 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{codegen, test};
+
+#[test]
+fn rewrite_patterns() {
+    let options = TransformOptions::from_target("es5").unwrap();
+    let cases = [
+        // `s` flag
+        (r"/a.b/s", r"/a[\0-\uFFFF]b/"),
+        // Property escapes
+        (r"/\p{ASCII_Hex_Digit}/u", r"/[0-9A-Fa-f]/"),
+        (
+            r"/[^\p{ASCII}]/u",
+            r"/(?:[\x80-\uD7FF\uE000-\uFFFF]|[\uD800-\uDBFF][\uDC00-\uDFFF]|[\uD800-\uDBFF](?![\uDC00-\uDFFF])|(?:[^\uD800-\uDBFF]|^)[\uDC00-\uDFFF])/",
+        ),
+        // Astral code points and ranges with `u` flag
+        (r"/[💩-💫]/u", r"/\uD83D[\uDCA9-\uDCAB]/"),
+        (r"/💩+/u", r"/(?:\uD83D\uDCA9)+/"),
+        (r"/\u{1F600}/u", r"/\uD83D\uDE00/"),
+        (r"/[a-z]/iu", r"/[A-Za-z\u017F\u212A]/i"),
+        // Set notation
+        (r"/[\p{ASCII}--\p{L}]/v", r"/[\0-@\[-`\{-\x7F]/"),
+        (r"/[\w&&\d]/v", r"/[0-9]/"),
+        (r"/[\q{abc|d}a]/v", r"/(?:abc|[ad])/"),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(test(source, &options), Ok(codegen(expected, SourceType::mjs())), "{source}");
+    }
+}
+
+#[test]
+fn named_capture_groups() {
+    let options = TransformOptions::from_target("es5").unwrap();
+    let cases = [
+        (
+            r"/(?<year>\d{4})-(?<month>\d{2})/",
+            r"_wrapRegExp(/(\d{4})-(\d{2})/, { year: 1, month: 2 })",
+        ),
+        (r"/(?<a>x)|(?<a>y)/", r"_wrapRegExp(/(x)|(y)/, { a: [1, 2] })"),
+        (r"/(?<a>x)\k<a>/", r"_wrapRegExp(/(x)\1/, { a: 1 })"),
+    ];
+    for (source, expected) in cases {
+        let expected = format!(
+            "import _wrapRegExp from '@oxc-project/runtime/helpers/wrapRegExp';\n{expected}"
+        );
+        assert_eq!(test(source, &options), Ok(codegen(&expected, SourceType::mjs())), "{source}");
+    }
+}
+
+#[test]
+fn fallback_to_constructor() {
+    let options = TransformOptions::from_target("es5").unwrap();
+    let cases = [
+        (r"/a/y", r"new RegExp('a', 'y')"),
+        (r"/(?<=a)b/u", r"new RegExp('(?<=a)b', 'u')"),
+        (r"/\p{RGI_Emoji}/v", r"new RegExp('\\p{RGI_Emoji}', 'v')"),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(test(source, &options), Ok(codegen(expected, SourceType::mjs())), "{source}");
+    }
+}
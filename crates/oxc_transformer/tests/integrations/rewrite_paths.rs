@@ -0,0 +1,178 @@
+use std::{fs, path::Path};
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{RewritePathsOptions, TransformOptions, Transformer, TypeScriptOptions};
+
+use crate::codegen;
+
+fn transform(
+    source_path: &str,
+    source_text: &str,
+    options: RewritePathsOptions,
+    cwd: &Path,
+) -> String {
+    let options = TransformOptions {
+        cwd: cwd.to_path_buf(),
+        typescript: TypeScriptOptions {
+            rewrite_paths: Some(options),
+            ..TypeScriptOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+    let source_path = Path::new(source_path);
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path(source_path).unwrap();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, source_path, &options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+fn paths(paths: &[(&str, &[&str])]) -> RewritePathsOptions {
+    RewritePathsOptions {
+        paths: paths
+            .iter()
+            .map(|(pattern, locations)| {
+                ((*pattern).to_string(), locations.iter().map(ToString::to_string).collect())
+            })
+            .collect(),
+        ..RewritePathsOptions::default()
+    }
+}
+
+#[test]
+fn specifiers() {
+    let options = paths(&[("@/*", &["./src/*"]), ("~lib", &["./lib/index.ts"])]);
+    let source = "
+        import { a } from '@/utils/a';
+        import type { T } from '@/types';
+        export { b } from '@/b';
+        export * from '~lib';
+        import('@/c');
+        require('@/d');
+        import x from './x';
+        import y from 'y';
+        import z from '~lib/z';
+        console.log(a, x, y, z);
+    ";
+    let expected = "
+        import { a } from '../utils/a';
+        export { b } from '../b';
+        export * from '../../lib/index.ts';
+        import('../c');
+        require('../d');
+        import x from './x';
+        import y from 'y';
+        import z from '~lib/z';
+        console.log(a, x, y, z);
+    ";
+    let output = transform("src/pages/index.ts", source, options, Path::new("/project"));
+    assert_eq!(output, codegen(expected, SourceType::mjs()));
+}
+
+#[test]
+fn local_require() {
+    let options = paths(&[("@/*", &["./src/*"])]);
+    let source = "function f(require) { require('@/a'); }";
+    let output = transform("src/index.ts", source, options, Path::new("/project"));
+    assert_eq!(output, codegen(source, SourceType::mjs()));
+}
+
+#[test]
+fn longest_prefix() {
+    let options = paths(&[
+        ("*", &["./vendor/*"]),
+        ("@/*", &["./src/*"]),
+        ("@/components/*", &["./src/ui/*"]),
+        ("@/components/button", &["./src/button.ts"]),
+    ]);
+    let source = "
+        import a from '@/components/input';
+        import b from '@/components/button';
+        import c from '@/utils';
+        import d from 'lodash';
+        console.log(a, b, c, d);
+    ";
+    let expected = "
+        import a from './ui/input';
+        import b from './button.ts';
+        import c from './utils';
+        import d from '../vendor/lodash';
+        console.log(a, b, c, d);
+    ";
+    let output = transform("src/index.ts", source, options, Path::new("/project"));
+    assert_eq!(output, codegen(expected, SourceType::mjs()));
+}
+
+#[test]
+fn fallbacks_and_base_url() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::create_dir_all(root.join("src/generated")).unwrap();
+    fs::create_dir_all(root.join("src/shared/utils")).unwrap();
+    fs::write(root.join("src/generated/schema.ts"), "").unwrap();
+    fs::write(root.join("src/shared/utils/index.ts"), "").unwrap();
+
+    let options = RewritePathsOptions {
+        base_url: Some("./src".into()),
+        ..paths(&[("#/*", &["./custom/*", "./generated/*"])])
+    };
+    let source = "
+        import a from '#/schema';
+        import b from '#/missing';
+        import c from 'shared/utils';
+        import d from 'react';
+        console.log(a, b, c, d);
+    ";
+    let expected = "
+        import a from '../generated/schema';
+        import b from '../custom/missing';
+        import c from '../shared/utils';
+        import d from 'react';
+        console.log(a, b, c, d);
+    ";
+    let output = transform("src/pages/index.ts", source, options, root);
+    assert_eq!(output, codegen(expected, SourceType::mjs()));
+}
+
+#[test]
+fn from_tsconfig() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    fs::create_dir_all(root.join("configs")).unwrap();
+    fs::write(
+        root.join("configs/base.json"),
+        r#"{
+            // Comments are allowed
+            "compilerOptions": { "baseUrl": "..", "paths": { "@/*": ["./src/*"] } }
+        }"#,
+    )
+    .unwrap();
+    fs::write(
+        root.join("tsconfig.json"),
+        r#"{ "extends": "./configs/base.json", "compilerOptions": { "strict": true } }"#,
+    )
+    .unwrap();
+
+    let options = RewritePathsOptions::from_tsconfig(&root.join("tsconfig.json")).unwrap();
+    assert_eq!(options.base_path, root.join("configs"));
+    assert_eq!(options.base_url, Some(root.join("configs/..")));
+    assert_eq!(options.paths["@/*"], vec!["./src/*".to_string()]);
+
+    let source = "import a from '@/a'; console.log(a);";
+    let expected = "import a from './a'; console.log(a);";
+    let output = transform("src/index.ts", source, options, root);
+    assert_eq!(output, codegen(expected, SourceType::mjs()));
+
+    assert!(RewritePathsOptions::from_tsconfig(&root.join("missing.json")).is_err());
+}
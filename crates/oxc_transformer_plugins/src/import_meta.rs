@@ -0,0 +1,580 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Write},
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use cow_utils::CowUtils;
+
+use oxc_allocator::{Allocator, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeFlags, ScopeId, Scoping, SymbolFlags};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_syntax::identifier::is_identifier_name;
+use oxc_traverse::{BoundIdentifier, Traverse, traverse_mut};
+
+use crate::{ReplaceGlobalDefines, ReplaceGlobalDefinesConfig, TraverseCtx};
+
+/// Lists all files under a directory, recursively.
+///
+/// Receives an absolute, normalized directory path, and returns absolute paths of files.
+/// A directory which does not exist should return an empty list.
+pub type ImportMetaReadDir = dyn Fn(&Path) -> Vec<PathBuf> + Send + Sync;
+
+/// Configuration for [ImportMetaTransform].
+///
+/// The data is stored in an `Arc` so this can be shared across threads.
+#[derive(Clone)]
+pub struct ImportMetaTransformConfig {
+    /// Project root, which patterns starting with `/` are resolved against.
+    root: PathBuf,
+    read_dir: Arc<ImportMetaReadDir>,
+    /// `import.meta.env` defines.
+    env: Option<ReplaceGlobalDefinesConfig>,
+}
+
+impl fmt::Debug for ImportMetaTransformConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImportMetaTransformConfig")
+            .field("root", &self.root)
+            .field("env", &self.env)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ImportMetaTransformConfig {
+    pub fn new<F>(root: impl Into<PathBuf>, read_dir: F) -> Self
+    where
+        F: Fn(&Path) -> Vec<PathBuf> + Send + Sync + 'static,
+    {
+        Self { root: root.into(), read_dir: Arc::new(read_dir), env: None }
+    }
+
+    /// Replace `import.meta.env.KEY` with the value of `KEY`, and `import.meta.env` with an object
+    /// of all values. Values are JavaScript expressions, e.g. `"production"` or `true`.
+    ///
+    /// # Errors
+    ///
+    /// * key is not an identifier
+    /// * value has a syntax error
+    pub fn with_env<S: AsRef<str>>(mut self, env: &[(S, S)]) -> Result<Self, Vec<OxcDiagnostic>> {
+        let mut object = String::from("{");
+        let mut defines = Vec::with_capacity(env.len() + 1);
+        for (index, (key, value)) in env.iter().enumerate() {
+            let (key, value) = (key.as_ref(), value.as_ref());
+            if !is_identifier_name(key) {
+                return Err(vec![OxcDiagnostic::error(format!(
+                    "The env key `{key}` is not an identifier."
+                ))]);
+            }
+            if index > 0 {
+                object.push_str(", ");
+            }
+            let _ = write!(object, "{key}: ({value})");
+            defines.push((format!("import.meta.env.{key}"), value.to_string()));
+        }
+        object.push('}');
+        defines.push(("import.meta.env".to_string(), object));
+        self.env = Some(ReplaceGlobalDefinesConfig::new(&defines)?);
+        Ok(self)
+    }
+}
+
+#[must_use]
+pub struct ImportMetaTransformReturn {
+    pub scoping: Scoping,
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+/// Expands Vite style `import.meta.glob` calls and `import.meta.env`.
+///
+/// ```js
+/// const modules = import.meta.glob('./dir/*.js');
+/// // becomes
+/// const modules = {
+///   './dir/a.js': () => import('./dir/a.js'),
+///   './dir/b.js': () => import('./dir/b.js'),
+/// };
+///
+/// const modules = import.meta.glob('./dir/*.js', { eager: true, import: 'setup' });
+/// // becomes
+/// import { setup as _glob } from './dir/a.js';
+/// import { setup as _glob2 } from './dir/b.js';
+/// const modules = { './dir/a.js': _glob, './dir/b.js': _glob2 };
+/// ```
+///
+/// Supported options are `eager`, `import`, `query` and `exhaustive`.
+/// Files in `node_modules` and hidden files are skipped unless `exhaustive` is `true`.
+///
+/// References:
+///
+/// * <https://vite.dev/guide/features.html#glob-import>
+/// * <https://vite.dev/guide/env-and-mode.html#env-variables>
+pub struct ImportMetaTransform<'a> {
+    allocator: &'a Allocator,
+    config: ImportMetaTransformConfig,
+    /// Path of the file being transformed.
+    source_path: PathBuf,
+
+    // states
+    /// Imports of eager globs, which are inserted at the top of the program.
+    imports: Vec<Statement<'a>>,
+    errors: Vec<OxcDiagnostic>,
+}
+
+impl<'a> Traverse<'a, ()> for ImportMetaTransform<'a> {
+    fn exit_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        program.body.splice(0..0, self.imports.drain(..));
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::CallExpression(call) = expr else { return };
+        if !is_import_meta_glob(&call.callee) {
+            return;
+        }
+        if let Some(new_expr) = self.transform_glob(call, ctx) {
+            *expr = new_expr;
+        }
+    }
+}
+
+/// Options of `import.meta.glob`.
+#[derive(Default)]
+struct GlobOptions<'a> {
+    eager: bool,
+    import: Option<Atom<'a>>,
+    /// Query string, including the leading `?`.
+    query: Option<String>,
+    exhaustive: bool,
+}
+
+impl<'a> ImportMetaTransform<'a> {
+    pub fn new(
+        allocator: &'a Allocator,
+        source_path: &Path,
+        config: ImportMetaTransformConfig,
+    ) -> Self {
+        Self {
+            allocator,
+            config,
+            source_path: normalize_path(source_path),
+            imports: vec![],
+            errors: vec![],
+        }
+    }
+
+    pub fn build(
+        &mut self,
+        scoping: Scoping,
+        program: &mut Program<'a>,
+    ) -> ImportMetaTransformReturn {
+        let mut scoping = traverse_mut(self, self.allocator, program, scoping, ());
+        if let Some(env) = &self.config.env {
+            let ret =
+                ReplaceGlobalDefines::new(self.allocator, env.clone()).build(scoping, program);
+            scoping = ret.scoping;
+        }
+        ImportMetaTransformReturn { scoping, errors: std::mem::take(&mut self.errors) }
+    }
+
+    fn transform_glob(
+        &mut self,
+        call: &CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let Some(first) = call.arguments.first().and_then(Argument::as_expression) else {
+            self.errors.push(
+                OxcDiagnostic::error("`import.meta.glob` requires patterns.").with_label(call.span),
+            );
+            return None;
+        };
+        let patterns = self.parse_patterns(first)?;
+        let options = match call.arguments.get(1) {
+            Some(Argument::ObjectExpression(object)) => self.parse_options(object)?,
+            Some(argument) => {
+                self.errors.push(
+                    OxcDiagnostic::error("`import.meta.glob` options must be an object literal.")
+                        .with_label(argument.span()),
+                );
+                return None;
+            }
+            None => GlobOptions::default(),
+        };
+
+        let files = self.resolve_files(&patterns, options.exhaustive);
+        let mut properties = ctx.ast.vec_with_capacity(files.len());
+        for (key, specifier) in files {
+            let value = if options.eager {
+                let binding = ctx.generate_uid_in_root_scope("glob", SymbolFlags::Import);
+                self.add_eager_import(&binding, &specifier, options.import, ctx);
+                binding.create_read_expression(ctx)
+            } else {
+                let specifier = match &options.query {
+                    Some(query) => format!("{specifier}{query}"),
+                    None => specifier,
+                };
+                Self::create_lazy_import(&specifier, options.import, ctx)
+            };
+            let key = ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&key), None);
+            properties.push(ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                PropertyKey::from(key),
+                value,
+                false,
+                false,
+                false,
+            ));
+        }
+
+        Some(ctx.ast.expression_object(call.span, properties))
+    }
+
+    /// Patterns are a string literal, or an array of string literals.
+    fn parse_patterns(&mut self, expr: &Expression<'a>) -> Option<Vec<Atom<'a>>> {
+        let patterns = match expr {
+            Expression::ArrayExpression(array) => {
+                let mut patterns = Vec::with_capacity(array.elements.len());
+                for element in &array.elements {
+                    let pattern = element.as_expression().and_then(static_string);
+                    let Some(pattern) = pattern else {
+                        self.errors.push(invalid_patterns(element.span()));
+                        return None;
+                    };
+                    patterns.push(pattern);
+                }
+                patterns
+            }
+            expr => {
+                let Some(pattern) = static_string(expr) else {
+                    self.errors.push(invalid_patterns(expr.span()));
+                    return None;
+                };
+                vec![pattern]
+            }
+        };
+
+        for pattern in &patterns {
+            let path = pattern.strip_prefix('!').unwrap_or(pattern);
+            if !(path.starts_with("./") || path.starts_with("../") || path.starts_with('/')) {
+                self.errors.push(
+                    OxcDiagnostic::error(format!(
+                        "Invalid glob: \"{pattern}\" (must start with '/', './' or '../')"
+                    ))
+                    .with_label(expr.span()),
+                );
+                return None;
+            }
+        }
+        if patterns.iter().all(|pattern| pattern.starts_with('!')) {
+            self.errors.push(
+                OxcDiagnostic::error("`import.meta.glob` requires at least one positive pattern.")
+                    .with_label(expr.span()),
+            );
+            return None;
+        }
+        Some(patterns)
+    }
+
+    fn parse_options(&mut self, object: &ObjectExpression<'a>) -> Option<GlobOptions<'a>> {
+        let mut options = GlobOptions::default();
+        for property in &object.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else {
+                self.errors.push(invalid_option(property.span()));
+                return None;
+            };
+            let Some(name) = property.key.static_name() else {
+                self.errors.push(invalid_option(property.key.span()));
+                return None;
+            };
+            let value = &property.value;
+            match (name.as_ref(), value) {
+                ("eager", Expression::BooleanLiteral(lit)) => options.eager = lit.value,
+                ("exhaustive", Expression::BooleanLiteral(lit)) => options.exhaustive = lit.value,
+                ("import", value) if static_string(value).is_some() => {
+                    options.import = static_string(value);
+                }
+                ("query", Expression::ObjectExpression(query)) => {
+                    options.query = Some(self.parse_query_object(query)?);
+                }
+                ("query", value) if static_string(value).is_some() => {
+                    let query = static_string(value).unwrap();
+                    options.query = Some(if query.starts_with('?') {
+                        query.to_string()
+                    } else {
+                        format!("?{query}")
+                    });
+                }
+                _ => {
+                    self.errors.push(invalid_option(property.span));
+                    return None;
+                }
+            }
+        }
+        Some(options)
+    }
+
+    /// `{ foo: 'bar', raw: true }` -> `?foo=bar&raw=true`
+    fn parse_query_object(&mut self, object: &ObjectExpression<'a>) -> Option<String> {
+        let mut query = String::new();
+        for property in &object.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else {
+                self.errors.push(invalid_option(property.span()));
+                return None;
+            };
+            let key = property.key.static_name();
+            let value = match &property.value {
+                Expression::BooleanLiteral(lit) => Some(lit.value.to_string()),
+                Expression::NumericLiteral(lit) => Some(lit.value.to_string()),
+                value => static_string(value).map(|value| value.to_string()),
+            };
+            let (Some(key), Some(value)) = (key, value) else {
+                self.errors.push(invalid_option(property.span));
+                return None;
+            };
+            query.push(if query.is_empty() { '?' } else { '&' });
+            query.push_str(&key);
+            query.push('=');
+            query.push_str(&value);
+        }
+        Some(query)
+    }
+
+    /// Returns a map of keys, which are paths in the same form as the patterns,
+    /// to import specifiers, which are relative to the importer.
+    fn resolve_files(&self, patterns: &[Atom<'a>], exhaustive: bool) -> BTreeMap<String, String> {
+        let importer_dir = self.source_path.parent().unwrap_or_else(|| Path::new(""));
+        let negative_patterns =
+            patterns.iter().filter_map(|pattern| pattern.strip_prefix('!')).collect::<Vec<_>>();
+
+        let mut files = BTreeMap::new();
+        for pattern in patterns.iter().filter(|pattern| !pattern.starts_with('!')) {
+            let is_root = pattern.starts_with('/');
+            let base = base_dir(pattern);
+            let base = if is_root {
+                self.config.root.join(base.trim_start_matches('/'))
+            } else {
+                importer_dir.join(base)
+            };
+
+            for file in (self.config.read_dir)(&normalize_path(&base)) {
+                let file = normalize_path(&file);
+                if file == self.source_path {
+                    continue;
+                }
+                let specifier = relative_path(importer_dir, &file);
+                let key = if is_root {
+                    match file.strip_prefix(&self.config.root) {
+                        Ok(path) => format!("/{}", to_slash(path)),
+                        Err(_) => continue,
+                    }
+                } else {
+                    specifier.clone()
+                };
+                if !exhaustive && is_ignored(&key) {
+                    continue;
+                }
+                if fast_glob::glob_match(pattern.as_str(), &key)
+                    && !negative_patterns.iter().any(|pattern| fast_glob::glob_match(pattern, &key))
+                {
+                    files.insert(key, specifier);
+                }
+            }
+        }
+        files
+    }
+
+    /// * `import * as _glob from './a.js'`
+    /// * `import _glob from './a.js'` with `import: 'default'`
+    /// * `import { setup as _glob } from './a.js'` with `import: 'setup'`
+    fn add_eager_import(
+        &mut self,
+        binding: &BoundIdentifier<'a>,
+        specifier: &str,
+        import: Option<Atom<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let local = binding.create_binding_identifier(ctx);
+        let import_specifier = match import {
+            None => ctx.ast.import_declaration_specifier_import_namespace_specifier(SPAN, local),
+            Some(import) if import == "*" => {
+                ctx.ast.import_declaration_specifier_import_namespace_specifier(SPAN, local)
+            }
+            Some(import) if import == "default" => {
+                ctx.ast.import_declaration_specifier_import_default_specifier(SPAN, local)
+            }
+            Some(import) => {
+                let imported = if is_identifier_name(&import) {
+                    ctx.ast.module_export_name_identifier_name(SPAN, import)
+                } else {
+                    ctx.ast.module_export_name_string_literal(SPAN, import, None)
+                };
+                ctx.ast.import_declaration_specifier_import_specifier(
+                    SPAN,
+                    imported,
+                    local,
+                    ImportOrExportKind::Value,
+                )
+            }
+        };
+        let source = ctx.ast.string_literal(SPAN, ctx.ast.atom(specifier), None);
+        let import_decl = ctx.ast.module_declaration_import_declaration(
+            SPAN,
+            Some(ctx.ast.vec1(import_specifier)),
+            source,
+            None,
+            NONE,
+            ImportOrExportKind::Value,
+        );
+        self.imports.push(Statement::from(import_decl));
+    }
+
+    /// * `() => import('./a.js')`
+    /// * `() => import('./a.js').then((m) => m.setup)` with `import: 'setup'`
+    fn create_lazy_import(
+        specifier: &str,
+        import: Option<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id = ctx.create_child_scope_of_current(ScopeFlags::Arrow | ScopeFlags::Function);
+        let source = ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(specifier), None);
+        let mut body = ctx.ast.expression_import(SPAN, source, None, None);
+
+        if let Some(import) = import.filter(|import| import != "*") {
+            let then_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::Arrow);
+            let module = ctx.generate_binding(
+                Atom::from("m"),
+                then_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let object = module.create_read_expression(ctx);
+            let member = if is_identifier_name(&import) {
+                let property = ctx.ast.identifier_name(SPAN, import);
+                Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+            } else {
+                let property = ctx.ast.expression_string_literal(SPAN, import, None);
+                Expression::from(ctx.ast.member_expression_computed(SPAN, object, property, false))
+            };
+            let param = ctx.ast.plain_formal_parameter(SPAN, module.create_binding_pattern(ctx));
+            let then = create_arrow(ctx.ast.vec1(param), member, then_scope_id, ctx);
+            let callee = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                body,
+                ctx.ast.identifier_name(SPAN, "then"),
+                false,
+            ));
+            body = ctx.ast.expression_call(
+                SPAN,
+                callee,
+                NONE,
+                ctx.ast.vec1(Argument::from(then)),
+                false,
+            );
+        }
+
+        create_arrow(ctx.ast.vec(), body, scope_id, ctx)
+    }
+}
+
+/// `(params) => body`
+fn create_arrow<'a>(
+    items: ArenaVec<'a, FormalParameter<'a>>,
+    body: Expression<'a>,
+    scope_id: ScopeId,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let params =
+        ctx.ast.formal_parameters(SPAN, FormalParameterKind::ArrowFormalParameters, items, NONE);
+    let body = ctx.ast.function_body(
+        SPAN,
+        ctx.ast.vec(),
+        ctx.ast.vec1(ctx.ast.statement_expression(SPAN, body)),
+    );
+    ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+        SPAN, true, false, NONE, params, NONE, body, scope_id, false, false,
+    )
+}
+
+/// `import.meta.glob`
+fn is_import_meta_glob(callee: &Expression<'_>) -> bool {
+    let Expression::StaticMemberExpression(member) = callee else { return false };
+    member.property.name == "glob"
+        && matches!(&member.object, Expression::MetaProperty(meta)
+            if meta.meta.name == "import" && meta.property.name == "meta")
+}
+
+/// String literal, or template literal without expressions.
+fn static_string<'a>(expr: &Expression<'a>) -> Option<Atom<'a>> {
+    match expr {
+        Expression::StringLiteral(lit) => Some(lit.value),
+        Expression::TemplateLiteral(lit) => lit.single_quasi(),
+        _ => None,
+    }
+}
+
+/// Longest leading directory of a pattern which contains no glob syntax.
+///
+/// `./dir/**/*.js` -> `./dir`
+fn base_dir(pattern: &str) -> &str {
+    let end = pattern.find(['*', '?', '[', '{']).unwrap_or(pattern.len());
+    match pattern[..end].rfind('/') {
+        Some(0) => "/",
+        Some(index) => &pattern[..index],
+        None => ".",
+    }
+}
+
+/// Files in `node_modules` and hidden files are not included by default.
+fn is_ignored(key: &str) -> bool {
+    key.split('/').any(|segment| {
+        segment == "node_modules" || (segment.starts_with('.') && segment != "." && segment != "..")
+    })
+}
+
+/// Resolve `.` and `..` lexically.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Relative path from a directory to a file, starting with `./` or `../`.
+fn relative_path(from_dir: &Path, to: &Path) -> String {
+    let from = from_dir.components().collect::<Vec<_>>();
+    let to = to.components().collect::<Vec<_>>();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let mut path =
+        if common == from.len() { String::from("./") } else { "../".repeat(from.len() - common) };
+    let rest = to[common..].iter().collect::<PathBuf>();
+    path.push_str(&to_slash(&rest));
+    path
+}
+
+fn to_slash(path: &Path) -> String {
+    let path = path.to_string_lossy();
+    path.cow_replace('\\', "/").into_owned()
+}
+
+fn invalid_patterns(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("`import.meta.glob` patterns must be string literals.").with_label(span)
+}
+
+fn invalid_option(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(
+        "`import.meta.glob` options must be static: `eager`, `import`, `query` or `exhaustive`.",
+    )
+    .with_label(span)
+}
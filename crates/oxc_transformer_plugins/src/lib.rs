@@ -1,9 +1,10 @@
+mod import_meta;
 mod inject_global_variables;
-mod module_runner_transform;
 mod replace_global_defines;
 
+pub use import_meta::*;
 pub use inject_global_variables::*;
-pub use module_runner_transform::*;
+pub use oxc_transformer::ModuleRunnerTransform;
 pub use replace_global_defines::*;
 
 type TraverseCtx<'a> = oxc_traverse::TraverseCtx<'a, ()>;
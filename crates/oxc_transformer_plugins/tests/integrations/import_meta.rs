@@ -0,0 +1,177 @@
+use std::path::{Path, PathBuf};
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer_plugins::{ImportMetaTransform, ImportMetaTransformConfig};
+
+use crate::codegen;
+
+const FILES: &[&str] = &[
+    "/project/src/main.js",
+    "/project/src/dir/a.js",
+    "/project/src/dir/b.js",
+    "/project/src/dir/c.ts",
+    "/project/src/dir/nested/d.js",
+    "/project/src/dir/.hidden.js",
+    "/project/src/dir/node_modules/e.js",
+    "/project/lib/f.js",
+];
+
+fn config() -> ImportMetaTransformConfig {
+    ImportMetaTransformConfig::new("/project", |dir: &Path| {
+        FILES.iter().map(PathBuf::from).filter(|file| file.starts_with(dir)).collect()
+    })
+}
+
+fn transform(source_text: &str, config: ImportMetaTransformConfig) -> Result<String, String> {
+    let source_type = SourceType::mjs();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = ImportMetaTransform::new(&allocator, Path::new("/project/src/main.js"), config)
+        .build(scoping, &mut program);
+    if let Some(error) = ret.errors.first() {
+        return Err(error.to_string());
+    }
+    Ok(Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code)
+}
+
+#[track_caller]
+fn test(source_text: &str, expected: &str) {
+    test_with_config(source_text, expected, config());
+}
+
+#[track_caller]
+fn test_with_config(source_text: &str, expected: &str, config: ImportMetaTransformConfig) {
+    let result = transform(source_text, config).unwrap();
+    let expected = codegen(expected, SourceType::mjs());
+    assert_eq!(result, expected, "for source {source_text}");
+}
+
+#[track_caller]
+fn test_error(source_text: &str, message: &str) {
+    let error = transform(source_text, config()).unwrap_err();
+    assert!(error.contains(message), "expected `{message}`, got `{error}`");
+}
+
+#[test]
+fn lazy() {
+    test(
+        "const modules = import.meta.glob('./dir/*.js')",
+        "const modules = {
+            './dir/a.js': () => import('./dir/a.js'),
+            './dir/b.js': () => import('./dir/b.js'),
+        }",
+    );
+    test(
+        "const modules = import.meta.glob('./dir/**/*.js')",
+        "const modules = {
+            './dir/a.js': () => import('./dir/a.js'),
+            './dir/b.js': () => import('./dir/b.js'),
+            './dir/nested/d.js': () => import('./dir/nested/d.js'),
+        }",
+    );
+    test(
+        "const modules = import.meta.glob(`./dir/*.ts`)",
+        "const modules = { './dir/c.ts': () => import('./dir/c.ts') }",
+    );
+}
+
+#[test]
+fn eager() {
+    test(
+        "const modules = import.meta.glob('./dir/*.js', { eager: true })",
+        "import * as _glob from './dir/a.js';
+        import * as _glob2 from './dir/b.js';
+        const modules = { './dir/a.js': _glob, './dir/b.js': _glob2 }",
+    );
+    test(
+        "const a = import.meta.glob('./dir/a.js', { eager: true, import: 'default' });
+        const b = import.meta.glob('./dir/b.js', { eager: true, import: 'setup' });",
+        "import _glob from './dir/a.js';
+        import { setup as _glob2 } from './dir/b.js';
+        const a = { './dir/a.js': _glob };
+        const b = { './dir/b.js': _glob2 };",
+    );
+}
+
+#[test]
+fn import_and_query() {
+    test(
+        "const modules = import.meta.glob('./dir/a.js', { import: 'setup' })",
+        "const modules = { './dir/a.js': () => import('./dir/a.js').then((m) => m.setup) }",
+    );
+    test(
+        "const modules = import.meta.glob('./dir/a.js', { query: '?raw', import: 'default' })",
+        "const modules = { './dir/a.js': () => import('./dir/a.js?raw').then((m) => m.default) }",
+    );
+    test(
+        "const modules = import.meta.glob('./dir/a.js', { query: { foo: 'bar', n: 1 } })",
+        "const modules = { './dir/a.js': () => import('./dir/a.js?foo=bar&n=1') }",
+    );
+}
+
+#[test]
+fn multiple_patterns() {
+    test(
+        "const modules = import.meta.glob(['./dir/*.js', '!./dir/b.js', '../lib/*.js'])",
+        "const modules = {
+            '../lib/f.js': () => import('../lib/f.js'),
+            './dir/a.js': () => import('./dir/a.js'),
+        }",
+    );
+}
+
+#[test]
+fn root_patterns() {
+    test(
+        "const modules = import.meta.glob('/lib/*.js')",
+        "const modules = { '/lib/f.js': () => import('../lib/f.js') }",
+    );
+}
+
+#[test]
+fn excludes() {
+    // The importer itself
+    test("const modules = import.meta.glob('./*.js')", "const modules = {}");
+    // Hidden files and `node_modules`
+    test(
+        "const modules = import.meta.glob('./dir/**/*.js', { exhaustive: true })",
+        "const modules = {
+            './dir/.hidden.js': () => import('./dir/.hidden.js'),
+            './dir/a.js': () => import('./dir/a.js'),
+            './dir/b.js': () => import('./dir/b.js'),
+            './dir/nested/d.js': () => import('./dir/nested/d.js'),
+            './dir/node_modules/e.js': () => import('./dir/node_modules/e.js'),
+        }",
+    );
+}
+
+#[test]
+fn errors() {
+    test_error("import.meta.glob(pattern)", "patterns must be string literals");
+    test_error("import.meta.glob('dir/*.js')", "Invalid glob");
+    test_error("import.meta.glob(['!./dir/a.js'])", "at least one positive pattern");
+    test_error("import.meta.glob('./dir/*.js', options)", "options must be an object literal");
+    test_error("import.meta.glob('./dir/*.js', { eager })", "options must be static");
+    test_error("import.meta.glob('./dir/*.js', { as: 'raw' })", "options must be static");
+}
+
+#[test]
+fn env() {
+    let env = config().with_env(&[("MODE", "'production'"), ("DEV", "false")]).unwrap();
+    test_with_config(
+        "console.log(import.meta.env.MODE, import.meta.env.DEV, import.meta.env)",
+        "console.log('production', false, { MODE: 'production', DEV: false })",
+        env,
+    );
+    assert!(config().with_env(&[("NOT-IDENT", "1")]).is_err());
+}
@@ -1,4 +1,6 @@
+mod import_meta;
 mod inject_global_variables;
+mod module_runner_transform;
 mod replace_global_defines;
 
 use oxc_allocator::Allocator;
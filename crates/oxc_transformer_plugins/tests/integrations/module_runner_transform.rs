@@ -0,0 +1,1677 @@
+use std::path::Path;
+
+use rustc_hash::FxHashSet;
+use similar::TextDiff;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions, CommentOptions};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_tasks_common::print_diff_in_terminal;
+use oxc_transformer::{JsxRuntime, TransformOptions, Transformer};
+use oxc_transformer_plugins::ModuleRunnerTransform;
+
+struct TransformReturn {
+    code: String,
+    deps: FxHashSet<String>,
+    dynamic_deps: FxHashSet<String>,
+}
+
+fn transform(source_text: &str, is_jsx: bool) -> Result<TransformReturn, Vec<OxcDiagnostic>> {
+    let source_type = SourceType::default().with_jsx(is_jsx);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let mut scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    if is_jsx {
+        let mut options = TransformOptions::default();
+        options.jsx.runtime = JsxRuntime::Classic;
+        let ret = Transformer::new(&allocator, Path::new(""), &options)
+            .build_with_scoping(scoping, &mut program);
+        scoping = ret.scoping;
+    }
+    let (deps, dynamic_deps) =
+        ModuleRunnerTransform::new().transform(&allocator, &mut program, scoping);
+
+    if !ret.errors.is_empty() {
+        return Err(ret.errors);
+    }
+    let code = Codegen::new()
+        .with_options(CodegenOptions {
+            comments: CommentOptions::disabled(),
+            single_quote: true,
+            ..CodegenOptions::default()
+        })
+        .build(&program)
+        .code;
+
+    Ok(TransformReturn { code, deps, dynamic_deps })
+}
+
+fn format_expected_code(source_text: &str) -> String {
+    let source_type = SourceType::default();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+
+    Codegen::new()
+        .with_options(CodegenOptions {
+            comments: CommentOptions::disabled(),
+            single_quote: true,
+            ..CodegenOptions::default()
+        })
+        .build(&ret.program)
+        .code
+}
+
+#[track_caller]
+fn test_same(source_text: &str, expected: &str) {
+    let expected = format_expected_code(expected);
+    let result = transform(source_text, false).unwrap().code;
+    if result != expected {
+        let diff = TextDiff::from_lines(&expected, &result);
+        print_diff_in_terminal(&diff);
+        panic!("Expected code does not match the result");
+    }
+}
+
+#[track_caller]
+fn test_same_jsx(source_text: &str, expected: &str) {
+    let expected = format_expected_code(expected);
+    let result = transform(source_text, true).unwrap().code;
+    if result != expected {
+        let diff = TextDiff::from_lines(&expected, &result);
+        print_diff_in_terminal(&diff);
+        panic!("Expected code does not match the result");
+    }
+}
+
+#[track_caller]
+fn test_same_and_deps(source_text: &str, expected: &str, deps: &[&str], dynamic_deps: &[&str]) {
+    let expected = format_expected_code(expected);
+    let TransformReturn { code, deps: result_deps, dynamic_deps: result_dynamic_deps } =
+        transform(source_text, false).unwrap();
+    if code != expected {
+        let diff = TextDiff::from_lines(&expected, &code);
+        print_diff_in_terminal(&diff);
+        panic!("Expected code does not match the result");
+    }
+    for dep in deps {
+        assert!(result_deps.contains(*dep));
+    }
+    for dep in dynamic_deps {
+        assert!(result_dynamic_deps.contains(*dep));
+    }
+}
+
+#[test]
+fn default_import() {
+    test_same(
+        "import foo from 'vue';console.log(foo.bar)",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['default'] });
+console.log(__vite_ssr_import_0__.default.bar);",
+    );
+}
+
+#[test]
+fn named_import() {
+    test_same(
+        "import { ref } from 'vue';function foo() { return ref(0) }",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['ref'] });
+function foo() {
+  return (0, __vite_ssr_import_0__.ref)(0);
+}",
+    );
+}
+
+#[test]
+fn named_import_arbitrary_module_namespace() {
+    test_same(
+        "import { \"some thing\" as ref } from 'vue';function foo() { return ref(0) }",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['some thing'] });
+function foo() {
+  return (0, __vite_ssr_import_0__['some thing'])(0);
+}",
+    );
+}
+
+#[test]
+fn namespace_import() {
+    test_same(
+        "import * as vue from 'vue';function foo() { return vue.ref(0) }",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue');
+function foo() {
+  return __vite_ssr_import_0__.ref(0);
+}",
+    );
+}
+
+#[test]
+fn export_function_declaration() {
+    test_same(
+        "export function foo() {}",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'foo', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return foo;
+  }
+});
+function foo() {}
+",
+    );
+}
+
+#[test]
+fn export_class_declaration() {
+    test_same(
+        "export class foo {}",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'foo', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return foo;
+  }
+});
+class foo {}
+",
+    );
+}
+
+#[test]
+fn export_var_declaration() {
+    test_same(
+        "export const a = 1, b = 2",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'a', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return a;
+  }
+});
+Object.defineProperty(__vite_ssr_exports__, 'b', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return b;
+  }
+});
+const a = 1, b = 2;
+",
+    );
+}
+
+#[test]
+fn export_named() {
+    test_same(
+        "const a = 1, b = 2; export { a, b as c }",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'a', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return a;
+  }
+});
+Object.defineProperty(__vite_ssr_exports__, 'c', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return b;
+  }
+});
+const a = 1, b = 2;
+",
+    );
+}
+
+#[test]
+fn export_named_from() {
+    test_same(
+        "export { ref, computed as c } from 'vue'",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'ref', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return __vite_ssr_import_0__.ref;
+  }
+});
+Object.defineProperty(__vite_ssr_exports__, 'c', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return __vite_ssr_import_0__.computed;
+  }
+});
+const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['ref', 'computed'] });
+",
+    );
+}
+
+#[test]
+fn export_named_imported_binding() {
+    test_same(
+        "
+        import { createApp } from 'vue';
+        export { createApp }
+        ",
+        "
+        Object.defineProperty(__vite_ssr_exports__, 'createApp', {
+          enumerable: true,
+          configurable: true,
+          get() {
+            return __vite_ssr_import_0__.createApp;
+          }
+        });
+        const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['createApp'] });
+        ",
+    );
+}
+
+#[test]
+fn export_star_from() {
+    test_same(
+        "export * from 'vue'\nexport * from 'react'",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue');
+__vite_ssr_exportAll__(__vite_ssr_import_0__);
+const __vite_ssr_import_1__ = await __vite_ssr_import__('react');
+__vite_ssr_exportAll__(__vite_ssr_import_1__);",
+    );
+}
+
+#[test]
+fn export_star_as_from() {
+    test_same(
+        "export * as foo from 'vue'",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'foo', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return __vite_ssr_import_0__;
+  }
+});
+const __vite_ssr_import_0__ = await __vite_ssr_import__('vue');
+",
+    );
+}
+
+#[test]
+fn re_export_by_imported_name() {
+    test_same(
+        "import * as foo from 'foo'; export * as foo from 'foo'",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'foo', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return __vite_ssr_import_1__;
+  }
+});
+const __vite_ssr_import_0__ = await __vite_ssr_import__('foo');
+const __vite_ssr_import_1__ = await __vite_ssr_import__('foo');
+",
+    );
+
+    test_same(
+        "import { foo } from 'foo'; export { foo } from 'foo'",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'foo', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return __vite_ssr_import_1__.foo;
+  }
+});
+const __vite_ssr_import_0__ = await __vite_ssr_import__('foo', { importedNames: ['foo'] });
+const __vite_ssr_import_1__ = await __vite_ssr_import__('foo', { importedNames: ['foo'] });
+",
+    );
+}
+
+#[test]
+fn export_arbitrary_namespace() {
+    test_same(
+        "export * as \"arbitrary string\" from 'vue'",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'arbitrary string', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return __vite_ssr_import_0__;
+  }
+});
+const __vite_ssr_import_0__ = await __vite_ssr_import__('vue');
+",
+    );
+
+    test_same(
+        "const something = \"Something\"; export { something as \"arbitrary string\" }",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'arbitrary string', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return something;
+  }
+});
+const something = 'Something';
+",
+    );
+
+    test_same(
+        "export { \"arbitrary string2\" as \"arbitrary string\" } from 'vue'",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'arbitrary string', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return __vite_ssr_import_0__['arbitrary string2'];
+  }
+});
+const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['arbitrary string2'] });
+",
+    );
+}
+
+#[test]
+fn export_default() {
+    test_same(
+        "export default {}",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'default', {
+   enumerable: true,
+   configurable: true,
+   get() {
+           return __vite_ssr_export_default__;
+   }
+});
+const __vite_ssr_export_default__ = {};
+",
+    );
+}
+
+#[test]
+fn export_then_import_minified() {
+    test_same(
+        "export * from 'vue';import {createApp} from 'vue'",
+        "
+const __vite_ssr_import_0__ = await __vite_ssr_import__('vue');
+__vite_ssr_exportAll__(__vite_ssr_import_0__);
+const __vite_ssr_import_1__ = await __vite_ssr_import__('vue', { importedNames: ['createApp'] });
+",
+    );
+}
+
+#[test]
+fn hoist_import_to_top() {
+    test_same(
+        "path.resolve('server.js');import path from 'node:path';",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('node:path', { importedNames: ['default'] });
+__vite_ssr_import_0__.default.resolve('server.js');",
+    );
+}
+
+#[test]
+fn import_meta() {
+    test_same("console.log(import.meta.url)", "console.log(__vite_ssr_import_meta__.url);");
+}
+
+#[test]
+fn dynamic_import() {
+    test_same_and_deps(
+        "export const i = () => import('./foo')",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'i', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return i;
+  }
+});
+const i = () => __vite_ssr_dynamic_import__('./foo');
+",
+        &[],
+        &["./foo"],
+    );
+}
+
+#[test]
+fn do_not_rewrite_method_definition() {
+    test_same_and_deps(
+        "import { fn } from 'vue';class A { fn() { fn() } }",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['fn'] });
+        class A {
+          fn() {
+            (0, __vite_ssr_import_0__.fn)();
+          }
+        }",
+        &["vue"],
+        &[],
+    );
+}
+
+#[test]
+fn do_not_rewrite_when_variable_in_scope() {
+    test_same_and_deps(
+        "import { fn } from 'vue';function A(){ const fn = () => {}; return { fn }; }",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['fn'] });
+        function A() {
+          const fn = () => {};
+          return { fn };
+        }",
+        &["vue"],
+        &[],
+    );
+}
+
+// <https://github.com/vitejs/vite/issues/5472>
+#[test]
+fn do_not_rewrite_destructuring_object() {
+    test_same_and_deps(
+        "import { fn } from 'vue';function A(){ let {fn, test} = {fn: 'foo', test: 'bar'}; return { fn }; }",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['fn'] });
+        function A() {
+          let { fn, test } = {
+            fn: 'foo',
+            test: 'bar'
+          };
+          return { fn };
+        }",
+        &["vue"],
+        &[],
+    );
+}
+
+// <https://github.com/vitejs/vite/issues/5472>
+#[test]
+fn do_not_rewrite_destructuring_array() {
+    test_same_and_deps(
+        "import { fn } from 'vue';function A(){ let [fn, test] = ['foo', 'bar']; return { fn }; }",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['fn'] });
+        function A() {
+          let [fn, test] = ['foo', 'bar'];
+          return { fn };
+        }",
+        &["vue"],
+        &[],
+    );
+}
+
+// <https://github.com/vitejs/vite/issues/5727>
+#[test]
+fn rewrite_vars_in_string_interpolation_in_function_args() {
+    test_same_and_deps(
+        "import { fn } from 'vue';function A({foo = `test${fn}`} = {}){ return {}; }",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['fn'] });
+        function A({ foo = `test${__vite_ssr_import_0__.fn}` } = {}) {
+          return {};
+        }",
+        &["vue"],
+        &[],
+    );
+}
+
+// <https://github.com/vitejs/vite/issues/6520>
+#[test]
+fn rewrite_vars_in_default_value_of_destructure_params() {
+    test_same_and_deps(
+        "import { fn } from 'vue';function A({foo = fn}){ return {}; }",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['fn'] });
+        function A({ foo = __vite_ssr_import_0__.fn }) {
+          return {};
+        }",
+        &["vue"],
+        &[],
+    );
+}
+
+#[test]
+fn do_not_rewrite_when_function_declaration_is_in_scope() {
+    test_same_and_deps(
+        "import { fn } from 'vue';function A(){ function fn() {}; return { fn }; }",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['fn'] });
+      function A() {
+        function fn() {};
+        return { fn };
+      }",
+        &["vue"],
+        &[],
+    );
+}
+
+// <https://github.com/vitejs/vite/issues/16552>
+#[test]
+fn do_not_rewrite_when_function_expression_in_scope() {
+    test_same(
+        "import {fn} from './vue';var a = function() { return function fn() { console.log(fn) } }",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('./vue', { importedNames: ['fn'] });
+        var a = function() {
+          return function fn() {
+        console.log(fn);
+          };
+        };",
+    );
+}
+
+// <https://github.com/vitejs/vite/issues/16452>
+#[test]
+fn do_not_rewrite_when_function_expression_in_global_scope() {
+    test_same(
+        "import {fn} from './vue';foo(function fn(a = fn) { console.log(fn) })",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('./vue', { importedNames: ['fn'] });
+        foo(function fn(a = fn) {
+          console.log(fn);
+        });",
+    );
+}
+
+#[test]
+fn do_not_rewrite_when_class_declaration_is_in_scope() {
+    test_same_and_deps(
+        "import { cls } from 'vue';function A(){ class cls {} return { cls }; }",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['cls'] });
+        function A() {
+          class cls {}
+          return { cls };
+        }",
+        &["vue"],
+        &[]
+    );
+}
+
+#[test]
+fn do_not_rewrite_when_class_expression_in_scope() {
+    test_same(
+        "import { cls } from './vue';var a = function() { return class cls { constructor() { console.log(cls) } } }",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('./vue', { importedNames: ['cls'] });
+        var a = function() {
+          return class cls {
+        constructor() {
+          console.log(cls);
+        }
+          };
+        };",
+    );
+}
+
+#[test]
+fn do_not_rewrite_when_class_expression_in_global_scope() {
+    test_same(
+        "import { cls } from './vue';foo(class cls { constructor() { console.log(cls) } })",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('./vue', { importedNames: ['cls'] });
+        foo(class cls {
+          constructor() {
+        console.log(cls);
+          }
+        });",
+    );
+}
+
+#[test]
+fn do_not_rewrite_catch_clause() {
+    test_same_and_deps(
+        "import {error} from './dependency';try {} catch(error) {}",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('./dependency', { importedNames: ['error'] });
+try {} catch (error) {}",
+        &["./dependency"],
+        &[]
+    );
+}
+
+#[test]
+fn should_declare_imported_super_class() {
+    test_same(
+        "import { Foo } from './dependency'; class A extends Foo {}",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('./dependency', { importedNames: ['Foo'] });
+class A extends __vite_ssr_import_0__.Foo {}",
+    );
+}
+
+#[test]
+fn should_declare_exported_super_class() {
+    test_same(
+        "
+        import { Foo } from './dependency';
+        export default class A extends Foo {}
+        export class B extends Foo {}
+        ",
+        "
+        Object.defineProperty(__vite_ssr_exports__, 'default', {
+          enumerable: true,
+          configurable: true,
+          get() {
+            return A;
+          }
+        });
+        Object.defineProperty(__vite_ssr_exports__, 'B', {
+          enumerable: true,
+          configurable: true,
+          get() {
+            return B;
+          }
+        });
+        const __vite_ssr_import_0__ = await __vite_ssr_import__('./dependency', { importedNames: ['Foo'] });
+        class A extends __vite_ssr_import_0__.Foo {}
+        class B extends __vite_ssr_import_0__.Foo {}
+        ",
+    );
+}
+
+/// <https://github.com/vitejs/vite/issues/4049>
+#[test]
+fn should_handle_default_export_variants() {
+    // default anonymous functions
+    test_same(
+        "export default function() {}",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'default', {
+   enumerable: true,
+   configurable: true,
+   get() {
+           return __vite_ssr_export_default__;
+   }
+});
+const __vite_ssr_export_default__ = function() {};
+",
+    );
+
+    // default anonymous class
+    test_same(
+        "export default class {}",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'default', {
+   enumerable: true,
+   configurable: true,
+   get() {
+           return __vite_ssr_export_default__;
+   }
+});
+const __vite_ssr_export_default__ = class {};
+",
+    );
+
+    // default named functions
+    test_same(
+        "
+export default function foo() {}
+foo.prototype = Object.prototype;
+",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'default', {
+enumerable: true,
+configurable: true,
+get() {
+return foo;
+}
+});
+function foo() {}
+foo.prototype = Object.prototype;
+",
+    );
+
+    // default named classes
+    test_same(
+        "export default class A {}\nexport class B extends A {}",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'default', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return A;
+  }
+});
+Object.defineProperty(__vite_ssr_exports__, 'B', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return B;
+  }
+});
+class A {}
+class B extends A {}
+",
+    );
+}
+
+#[test]
+fn overwrite_bindings() {
+    test_same(
+        "import { inject } from 'vue';
+        const a = { inject }
+        const b = { test: inject }
+        function c() { const { test: inject } = { test: true }; console.log(inject) }
+        const d = inject
+        function f() { console.log(inject) }
+        function e() { const { inject } = { inject: true } }
+        function g() { const f = () => { const inject = true }; console.log(inject) }",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['inject'] });
+const a = { inject: __vite_ssr_import_0__.inject };
+const b = { test: __vite_ssr_import_0__.inject };
+function c() {
+  const { test: inject } = { test: true };
+  console.log(inject);
+}
+const d = __vite_ssr_import_0__.inject;
+function f() {
+  console.log(__vite_ssr_import_0__.inject);
+}
+function e() {
+  const { inject } = { inject: true };
+}
+function g() {
+  const f = () => {
+const inject = true;
+  };
+  console.log(__vite_ssr_import_0__.inject);
+}",
+    );
+}
+
+#[test]
+fn empty_array_pattern() {
+    test_same("const [, LHS, RHS] = inMatch;", "const [, LHS, RHS] = inMatch;");
+}
+
+#[test]
+fn function_argument_destructure() {
+    test_same(
+        "
+import { foo, bar } from 'foo'
+const a = ({ _ = foo() }) => {}
+function b({ _ = bar() }) {}
+function c({ _ = bar() + foo() }) {}",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('foo', { importedNames: ['foo', 'bar'] });
+const a = ({ _ = (0, __vite_ssr_import_0__.foo)() }) => {};
+function b({ _ = (0, __vite_ssr_import_0__.bar)() }) {}
+function c({ _ = (0, __vite_ssr_import_0__.bar)() + (0, __vite_ssr_import_0__.foo)() }) {}",
+    );
+}
+
+#[test]
+fn object_destructure_alias() {
+    test_same(
+        "
+import { n } from 'foo'
+const a = () => {
+    const { type: n = 'bar' } = {}
+    console.log(n)
+}",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('foo', { importedNames: ['n'] });
+const a = () => {
+  const { type: n = 'bar' } = {};
+  console.log(n);
+};",
+    );
+
+    // https://github.com/vitejs/vite/issues/9585
+    test_same(
+        "
+import { n, m } from 'foo'
+const foo = {}
+{
+  const { [n]: m } = foo
+}",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('foo', { importedNames: ['n', 'm'] });
+const foo = {};
+{
+  const { [__vite_ssr_import_0__.n]: m } = foo;
+}",
+    );
+}
+
+#[test]
+fn nested_object_destructure_alias() {
+    test_same(
+        "import { remove, add, get, set, rest, objRest } from 'vue'
+
+function a() {
+    const {
+        o: { remove },
+        a: { b: { c: [ add ] }},
+        d: [{ get }, set, ...rest],
+        ...objRest
+    } = foo
+
+    remove()
+    add()
+    get()
+    set()
+    rest()
+    objRest()
+}
+
+remove()
+add()
+get()
+set()
+rest()
+objRest()",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['remove', 'add', 'get', 'set', 'rest', 'objRest'] });
+
+function a() {
+  const {
+o: { remove },
+a: { b: { c: [ add ] }},
+d: [{ get }, set, ...rest],
+...objRest
+  } = foo;
+
+  remove();
+  add();
+  get();
+  set();
+  rest();
+  objRest();
+}
+
+(0, __vite_ssr_import_0__.remove)();
+(0, __vite_ssr_import_0__.add)();
+(0, __vite_ssr_import_0__.get)();
+(0, __vite_ssr_import_0__.set)();
+(0, __vite_ssr_import_0__.rest)();
+(0, __vite_ssr_import_0__.objRest)();
+",
+    );
+}
+
+#[test]
+fn object_props_and_methods() {
+    test_same(
+        "import foo from 'foo'
+
+const bar = 'bar'
+
+const obj = {
+    foo() {},
+    [foo]() {},
+    [bar]() {},
+    foo: () => {},
+    [foo]: () => {},
+    [bar]: () => {},
+    bar(foo) {}
+}",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('foo', { importedNames: ['default'] });
+
+const bar = 'bar';
+
+const obj = {
+  foo() {},
+  [__vite_ssr_import_0__.default]() {},
+  [bar]() {},
+  foo: () => {},
+  [__vite_ssr_import_0__.default]: () => {},
+  [bar]: () => {},
+  bar(foo) {}
+};",
+    );
+}
+
+#[test]
+fn class_props() {
+    test_same(
+        "import { remove, add } from 'vue'
+        class A {
+            remove = 1
+            add = null
+        }",
+        "
+        const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['remove', 'add'] });
+        class A {
+          remove = 1;
+          add = null;
+        }
+        ",
+    );
+}
+
+#[test]
+fn class_methods() {
+    test_same(
+        "import foo from 'foo'
+
+const bar = 'bar'
+
+class A {
+    foo() {}
+    [foo]() {}
+    [bar]() {}
+    #foo() {}
+    bar(foo) {}
+}",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('foo', { importedNames: ['default'] });
+
+const bar = 'bar';
+
+class A {
+  foo() {}
+  [__vite_ssr_import_0__.default]() {}
+  [bar]() {}
+  #foo() {}
+  bar(foo) {}
+}",
+    );
+}
+
+#[test]
+fn declare_scope() {
+    test_same(
+        "import { aaa, bbb, ccc, ddd } from 'vue'
+
+function foobar() {
+    ddd()
+
+    const aaa = () => {
+        bbb(ccc)
+        ddd()
+    }
+    const bbb = () => {
+        console.log('hi')
+    }
+    const ccc = 1
+    function ddd() {}
+
+    aaa()
+    bbb()
+    ccc()
+}
+
+aaa()
+bbb()",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('vue', { importedNames: ['aaa', 'bbb', 'ccc', 'ddd'] });
+
+function foobar() {
+  ddd();
+
+  const aaa = () => {
+bbb(ccc);
+ddd();
+  };
+  const bbb = () => {
+console.log('hi');
+  };
+  const ccc = 1;
+  function ddd() {}
+
+  aaa();
+  bbb();
+  ccc();
+}
+
+(0, __vite_ssr_import_0__.aaa)();
+(0, __vite_ssr_import_0__.bbb)();
+",
+    );
+}
+
+#[test]
+fn jsx() {
+    test_same_jsx(
+        "import React from 'react'
+import { Foo, Slot } from 'foo'
+
+function Bar({ Slot = <Foo /> }) {
+  return (
+    <>
+      <Slot />
+    </>
+  )
+}",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('react', { importedNames: ['default'] });
+const __vite_ssr_import_1__ = await __vite_ssr_import__('foo', { importedNames: ['Foo', 'Slot'] });
+function Bar({ Slot = /* @__PURE__ */ __vite_ssr_import_0__.default.createElement(__vite_ssr_import_1__.Foo, null) }) {
+  return /* @__PURE__ */ __vite_ssr_import_0__.default.createElement(__vite_ssr_import_0__.default.Fragment, null, /* @__PURE__ */ __vite_ssr_import_0__.default.createElement(Slot, null));
+}",
+    );
+}
+
+#[test]
+fn continuous_exports() {
+    test_same(
+        "export function fn1() {
+}export function fn2() {
+}",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'fn1', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return fn1;
+  }
+});
+Object.defineProperty(__vite_ssr_exports__, 'fn2', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return fn2;
+  }
+});
+function fn1() {}
+function fn2() {}
+",
+    );
+}
+
+// https://github.com/vitest-dev/vitest/issues/1141
+#[test]
+fn export_default_expression() {
+    // esbuild transform result of following TS code
+    // export default <MyFn> function getRandom() {
+    //   return Math.random()
+    // }
+    test_same(
+        "
+export default (function getRandom() {
+  return Math.random();
+});
+",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'default', {
+   enumerable: true,
+   configurable: true,
+   get() {
+           return __vite_ssr_export_default__;
+   }
+});
+const __vite_ssr_export_default__ = function getRandom() {
+  return Math.random();
+};
+",
+    );
+
+    test_same(
+        "export default (class A {});",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'default', {
+   enumerable: true,
+   configurable: true,
+   get() {
+           return __vite_ssr_export_default__;
+   }
+});
+const __vite_ssr_export_default__ = class A {};",
+    );
+}
+
+// https://github.com/vitejs/vite/issues/8002
+#[test]
+fn with_hashbang() {
+    test_same(
+        "#!/usr/bin/env node
+console.log(\"it can parse the hashbang\")",
+        "#!/usr/bin/env node
+console.log(\"it can parse the hashbang\");",
+    );
+}
+
+#[test]
+fn import_hoisted_after_hashbang() {
+    test_same(
+        "#!/usr/bin/env node
+console.log(foo);
+import foo from 'foo'",
+        "#!/usr/bin/env node
+        const __vite_ssr_import_0__ = await __vite_ssr_import__('foo', { importedNames: ['default'] });
+        console.log(__vite_ssr_import_0__.default);
+        ",
+    );
+}
+
+#[test]
+fn identity_function_helper_injected_after_hashbang() {
+    test_same(
+        "#!/usr/bin/env node
+        import { foo } from 'foo';
+        foo();",
+        "#!/usr/bin/env node
+        const __vite_ssr_import_0__ = await __vite_ssr_import__('foo', { importedNames: ['foo'] });
+        (0, __vite_ssr_import_0__.foo)();
+        ",
+    );
+}
+
+// https://github.com/vitejs/vite/issues/10289
+#[test]
+fn track_scope_by_class_function_condition_blocks() {
+    test_same(
+        "import { foo, bar } from 'foobar'
+if (false) {
+  const foo = 'foo'
+  console.log(foo)
+} else if (false) {
+  const [bar] = ['bar']
+  console.log(bar)
+} else {
+  console.log(foo)
+  console.log(bar)
+}
+export class Test {
+  constructor() {
+    if (false) {
+      const foo = 'foo'
+      console.log(foo)
+    } else if (false) {
+      const [bar] = ['bar']
+      console.log(bar)
+    } else {
+      console.log(foo)
+      console.log(bar)
+    }
+  }
+}",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'Test', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return Test;
+  }
+});
+const __vite_ssr_import_0__ = await __vite_ssr_import__('foobar', { importedNames: ['foo', 'bar'] });
+if (false) {
+  const foo = 'foo';
+  console.log(foo);
+} else if (false) {
+  const [bar] = ['bar'];
+  console.log(bar);
+} else {
+  console.log(__vite_ssr_import_0__.foo);
+  console.log(__vite_ssr_import_0__.bar);
+}
+class Test {
+  constructor() {
+if (false) {
+  const foo = 'foo';
+  console.log(foo);
+} else if (false) {
+  const [bar] = ['bar'];
+  console.log(bar);
+} else {
+  console.log(__vite_ssr_import_0__.foo);
+  console.log(__vite_ssr_import_0__.bar);
+}
+  }
+}
+",
+    );
+}
+
+// https://github.com/vitejs/vite/issues/10386
+#[test]
+fn track_var_scope_by_function() {
+    test_same(
+        "import { foo, bar } from 'foobar'
+function test() {
+  if (true) {
+    var foo = () => { var why = 'would' }, bar = 'someone'
+  }
+  return [foo, bar]
+}",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('foobar', { importedNames: ['foo', 'bar'] });
+function test() {
+  if (true) {
+var foo = () => { var why = 'would'; }, bar = 'someone';
+  }
+  return [foo, bar];
+}",
+    );
+}
+
+// https://github.com/vitejs/vite/issues/11806
+#[test]
+fn track_scope_by_blocks() {
+    test_same(
+        "import { foo, bar, baz } from 'foobar'
+function test() {
+  [foo];
+  {
+    let foo = 10;
+    let bar = 10;
+  }
+  try {} catch (baz){ baz }
+  return bar;
+}",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('foobar', { importedNames: ['foo', 'bar', 'baz'] });
+function test() {
+  [__vite_ssr_import_0__.foo];
+  {
+let foo = 10;
+let bar = 10;
+  }
+  try {} catch (baz) { baz; }
+  return __vite_ssr_import_0__.bar;
+}",
+    );
+}
+
+#[test]
+fn track_scope_in_for_loops() {
+    test_same(
+        "import { test } from './test.js'
+
+for (const test of tests) {
+  console.log(test)
+}
+
+for (let test = 0; test < 10; test++) {
+  console.log(test)
+}
+
+for (const test in tests) {
+  console.log(test)
+}",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('./test.js', { importedNames: ['test'] });
+for (const test of tests) {
+  console.log(test);
+}
+for (let test = 0; test < 10; test++) {
+  console.log(test);
+}
+for (const test in tests) {
+  console.log(test);
+}",
+    );
+}
+
+#[test]
+fn avoid_binding_class_expression() {
+    test_same(
+        "import Foo, { Bar } from './foo';
+
+console.log(Foo, Bar);
+const obj = {
+  foo: class Foo {},
+  bar: class Bar {}
+}
+const Baz = class extends Foo {}",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('./foo', { importedNames: ['default', 'Bar'] });
+console.log(__vite_ssr_import_0__.default, __vite_ssr_import_0__.Bar);
+const obj = {
+  foo: class Foo {},
+  bar: class Bar {}
+};
+const Baz = class extends __vite_ssr_import_0__.default {};",
+    );
+}
+
+#[test]
+fn import_assertion_attribute() {
+    test_same(
+        "import * as foo from './foo.json' with { type: 'json' };
+import('./bar.json', { with: { type: 'json' } });",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('./foo.json');
+__vite_ssr_dynamic_import__('./bar.json', { with: { type: 'json' } });",
+    );
+}
+
+#[test]
+fn import_and_export_ordering() {
+    // Given all imported modules logs `mod ${mod}` on execution,
+    // and `foo` is `bar`, the logging order should be:
+    // "mod a", "mod foo", "mod b", "bar1", "bar2"
+    test_same(
+        "
+console.log(foo + 1)
+export * from './a'
+import { foo } from './foo'
+export * from './b'
+console.log(foo + 2)",
+        "
+const __vite_ssr_import_0__ = await __vite_ssr_import__('./a');
+__vite_ssr_exportAll__(__vite_ssr_import_0__);
+const __vite_ssr_import_1__ = await __vite_ssr_import__('./foo', { importedNames: ['foo']});
+const __vite_ssr_import_2__ = await __vite_ssr_import__('./b');
+__vite_ssr_exportAll__(__vite_ssr_import_2__);
+console.log(__vite_ssr_import_1__.foo + 1);
+console.log(__vite_ssr_import_1__.foo + 2);",
+    );
+}
+
+#[test]
+fn identity_function_is_declared_before_used() {
+    test_same(
+        "
+import { foo } from './foo'
+export default foo()
+export * as bar from './bar'
+console.log(bar)
+",
+        "
+Object.defineProperty(__vite_ssr_exports__, 'default', {
+   enumerable: true,
+   configurable: true,
+   get() {
+           return __vite_ssr_export_default__;
+   }
+});
+Object.defineProperty(__vite_ssr_exports__, 'bar', {
+  enumerable: true,
+  configurable: true,
+  get() {
+return __vite_ssr_import_1__;
+  }
+});
+const __vite_ssr_import_0__ = await __vite_ssr_import__('./foo', { importedNames: ['foo'] });
+const __vite_ssr_import_1__ = await __vite_ssr_import__('./bar');
+const __vite_ssr_export_default__ = (0, __vite_ssr_import_0__.foo)();
+console.log(bar);
+",
+    );
+}
+
+#[test]
+fn inject_semicolon_call_wrapper() {
+    let code = "import { f } from './f'
+
+let x = 0;
+
+x
+f()
+
+if (1)
+  x
+f()
+
+if (1)
+  x
+else
+  x
+f()
+
+
+let y = x
+f()
+
+x /*;;*/ /*;;*/
+f()
+
+function z() {
+  x
+  f()
+
+  if (1) {
+    x
+    f()
+  }
+}
+
+let a = {}
+f()
+
+let b = () => {}
+f()
+
+function c() {
+}
+f()
+
+class D {
+}
+f()
+
+{
+  x
+}
+f()
+
+switch (1) {
+  case 1:
+    x
+    f()
+    break
+}
+
+if(0){}f()
+
+if(0){}else{}f()
+
+switch(1){}f()
+
+{}f(1)";
+
+    let expected = "
+
+const __vite_ssr_import_0__ = await __vite_ssr_import__('./f', { importedNames: ['f'] });
+
+let x = 0;
+
+x;
+(0,__vite_ssr_import_0__.f)();
+
+if (1)
+  x;
+(0,__vite_ssr_import_0__.f)();
+
+if (1)
+  x
+else
+  x;
+(0,__vite_ssr_import_0__.f)();
+
+
+let y = x;
+(0,__vite_ssr_import_0__.f)();
+
+x; /*;;*/ /*;;*/
+(0,__vite_ssr_import_0__.f)();
+
+function z() {
+  x;
+  (0,__vite_ssr_import_0__.f)();
+
+  if (1) {
+    x;
+    (0,__vite_ssr_import_0__.f)()
+  }
+}
+
+let a = {};
+(0,__vite_ssr_import_0__.f)();
+
+let b = () => {};
+(0,__vite_ssr_import_0__.f)();
+
+function c() {
+}
+(0,__vite_ssr_import_0__.f)();
+
+class D {
+}
+(0,__vite_ssr_import_0__.f)();
+
+{
+  x
+}
+(0,__vite_ssr_import_0__.f)();
+
+switch (1) {
+  case 1:
+    x;
+    (0,__vite_ssr_import_0__.f)();
+    break
+}
+
+if(0){}(0,__vite_ssr_import_0__.f)();
+
+if(0){}else{}(0,__vite_ssr_import_0__.f)();
+
+switch(1){}(0,__vite_ssr_import_0__.f)();
+
+{}(0,__vite_ssr_import_0__.f)(1)
+";
+    test_same(code, expected);
+}
+
+#[test]
+fn does_not_break_minified_code() {
+    // Based on https://unpkg.com/@headlessui/vue@1.7.23/dist/components/transitions/transition.js
+    test_same(
+        "import O from 'a';
+const c = () => {
+  if(true){return}O(1,{})
+}",
+        "const __vite_ssr_import_0__ = await __vite_ssr_import__('a', { importedNames: ['default'] });
+const c = () => {
+  if (true) {
+return;
+  }
+  (0, __vite_ssr_import_0__.default)(1, {});
+};",
+    );
+}
+
+#[test]
+fn deps() {
+    let code = r#"
+    import a from "a";
+    export { b } from "b";
+    export * from "c";
+    export * as d from "d";
+    import("e")
+    export * as A from "a";
+    "#;
+    let expected = "
+    Object.defineProperty(__vite_ssr_exports__, 'b', {
+      enumerable: true,
+      configurable: true,
+      get() {
+              return __vite_ssr_import_1__.b;
+      }
+    });
+    Object.defineProperty(__vite_ssr_exports__, 'd', {
+      enumerable: true,
+      configurable: true,
+      get() {
+              return __vite_ssr_import_3__;
+      }
+    });
+    Object.defineProperty(__vite_ssr_exports__, 'A', {
+           enumerable: true,
+           configurable: true,
+           get() {
+                   return __vite_ssr_import_4__;
+           }
+    });
+    const __vite_ssr_import_0__ = await __vite_ssr_import__('a', { importedNames: ['default'] });
+    const __vite_ssr_import_1__ = await __vite_ssr_import__('b', { importedNames: ['b'] });
+    const __vite_ssr_import_2__ = await __vite_ssr_import__('c');
+    __vite_ssr_exportAll__(__vite_ssr_import_2__);
+    const __vite_ssr_import_3__ = await __vite_ssr_import__('d');
+    const __vite_ssr_import_4__ = await __vite_ssr_import__('a');
+    __vite_ssr_dynamic_import__('e');
+    ";
+    test_same_and_deps(code, expected, &["a", "b", "c", "d"], &["e"]);
+}
+
+fn transform_in_transformer(
+    source_text: &str,
+    source_type: SourceType,
+    mut options: TransformOptions,
+) -> TransformReturn {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    options.plugins.module_runner_transform = true;
+    let ret = Transformer::new(&allocator, Path::new("test.js"), &options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+    let code = Codegen::new()
+        .with_options(CodegenOptions {
+            comments: CommentOptions::disabled(),
+            single_quote: true,
+            ..CodegenOptions::default()
+        })
+        .build(&program)
+        .code;
+    TransformReturn { code, deps: ret.deps, dynamic_deps: ret.dynamic_deps }
+}
+
+fn string_set(items: &[&str]) -> FxHashSet<String> {
+    items.iter().map(ToString::to_string).collect()
+}
+
+#[track_caller]
+fn test_in_transformer(
+    source_text: &str,
+    expected: &str,
+    source_type: SourceType,
+    options: TransformOptions,
+) -> TransformReturn {
+    let expected = format_expected_code(expected);
+    let ret = transform_in_transformer(source_text, source_type, options);
+    if ret.code != expected {
+        let diff = TextDiff::from_lines(&expected, &ret.code);
+        print_diff_in_terminal(&diff);
+        panic!("Expected code does not match the result");
+    }
+    ret
+}
+
+#[test]
+fn in_transformer() {
+    let code = "
+    import { foo } from 'a';
+    import * as b from 'b';
+    export { bar } from 'c';
+    foo(b.baz, import.meta.url);
+    export const x = () => import('d');
+    ";
+    let expected = "
+    Object.defineProperty(__vite_ssr_exports__, 'bar', { enumerable: true, configurable: true, get(){ return __vite_ssr_import_2__.bar }});
+    Object.defineProperty(__vite_ssr_exports__, 'x', { enumerable: true, configurable: true, get(){ return x }});
+    const __vite_ssr_import_0__ = await __vite_ssr_import__('a', {importedNames:['foo']});
+    const __vite_ssr_import_1__ = await __vite_ssr_import__('b');
+    const __vite_ssr_import_2__ = await __vite_ssr_import__('c', {importedNames:['bar']});
+    (0, __vite_ssr_import_0__.foo)(__vite_ssr_import_1__.baz, __vite_ssr_import_meta__.url);
+    const x = () => __vite_ssr_dynamic_import__('d');
+    ";
+    let ret = test_in_transformer(code, expected, SourceType::mjs(), TransformOptions::default());
+    assert_eq!(ret.deps, string_set(&["a", "b", "c"]));
+    assert_eq!(ret.dynamic_deps, string_set(&["d"]));
+}
+
+#[test]
+fn in_transformer_typescript() {
+    let code = "
+    import { type A, b } from 'a';
+    import type { T } from 't';
+    import { unused } from 'unused';
+    export type { A };
+    export const c: T = b;
+    ";
+    let expected = "
+    Object.defineProperty(__vite_ssr_exports__, 'c', { enumerable: true, configurable: true, get(){ return c }});
+    const __vite_ssr_import_0__ = await __vite_ssr_import__('a', {importedNames:['b']});
+    const c = __vite_ssr_import_0__.b;
+    ";
+    let ret = test_in_transformer(code, expected, SourceType::ts(), TransformOptions::default());
+    assert_eq!(ret.deps, string_set(&["a"]));
+}
+
+#[test]
+fn in_transformer_jsx() {
+    let code = "
+    import { Foo } from 'foo';
+    import * as ns from 'ns';
+    export const el = <Foo><ns.Bar /></Foo>;
+    ";
+    let expected = "
+    Object.defineProperty(__vite_ssr_exports__, 'el', { enumerable: true, configurable: true, get(){ return el }});
+    const __vite_ssr_import_0__ = await __vite_ssr_import__('foo', {importedNames:['Foo']});
+    const __vite_ssr_import_1__ = await __vite_ssr_import__('ns');
+    const __vite_ssr_import_2__ = await __vite_ssr_import__('react/jsx-runtime', {importedNames:['jsx']});
+    const el = (0, __vite_ssr_import_2__.jsx)(__vite_ssr_import_0__.Foo, { children: (0, __vite_ssr_import_2__.jsx)(__vite_ssr_import_1__.Bar, {}) });
+    ";
+    test_in_transformer(
+        code,
+        expected,
+        SourceType::jsx().with_module(true),
+        TransformOptions::default(),
+    );
+}
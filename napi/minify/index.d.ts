@@ -35,14 +35,106 @@ export interface CompressOptions {
    * @default true
    */
   dropDebugger?: boolean
+  /**
+   * Remove labeled statements with these labels, e.g. `DEV: { ... }`.
+   *
+   * @default []
+   */
+  dropLabels?: Array<string>
+  /**
+   * Drop calls to these functions when their return value is unused.
+   *
+   * Dotted names such as `console.log` are supported.
+   * Arguments with side effects are kept.
+   *
+   * @default []
+   */
+  pureFuncs?: Array<string>
   /**
    * Drop unreferenced functions and variables.
    *
    * Simple direct variable assignments do not count as references unless set to "keep_assign".
+   * Also drops assignments which are always overwritten before being read.
+   *
+   * @default false
    */
   unused?: true | false | 'keep_assign'
+  /**
+   * Inline single-use function declarations into their call site.
+   *
+   * * `0`: disabled
+   * * `1`: functions without parameters
+   * * `2`: functions whose parameters are used at most once
+   * * `3`: functions whose parameters are used multiple times, if the arguments are primitive literals
+   *
+   * Has no effect unless `unused` is enabled.
+   *
+   * @default 3
+   */
+  inline?: 0 | 1 | 2 | 3
+  /**
+   * Substitute variables which are never reassigned.
+   *
+   * Has no effect unless `unused` is enabled.
+   *
+   * @default true
+   */
+  reduceVars?: boolean
   /** Keep function / class names. */
   keepNames?: CompressOptionsKeepNames
+  /**
+   * Maximum number of times the compress optimizations are run.
+   *
+   * `0` runs them until no more changes are made.
+   *
+   * @default 0
+   */
+  passes?: number
+  /**
+   * Assume that reading a property has no side effects.
+   *
+   * @default false
+   */
+  pureGetters?: boolean
+  /**
+   * Reassociate numeric constants, e.g. `x * 2 * 3` -> `x * 6`.
+   *
+   * @default false
+   */
+  unsafeMath?: boolean
+  /**
+   * Convert function expressions which do not use `this` to arrow functions.
+   *
+   * @default false
+   */
+  unsafeArrows?: boolean
+  /**
+   * Convert function expressions in object literals to methods.
+   *
+   * @default false
+   */
+  unsafeMethods?: boolean
+  /**
+   * Keep unused function parameters.
+   *
+   * @default true
+   */
+  keepFargs?: boolean
+  /**
+   * Hoist properties of local object literals into variables.
+   *
+   * @default true
+   */
+  hoistProps?: boolean
+  /**
+   * Compress a scope-hoisted bundle.
+   *
+   * Unused top level declarations of scripts are removed,
+   * and namespace objects defined by `__export` or `Object.defineProperty` are inlined.
+   *
+   * @default false
+   */
+  bundle?: boolean
 }
 
 export interface CompressOptionsKeepNames {
@@ -79,6 +171,8 @@ export interface MangleOptions {
   keepNames?: boolean | MangleOptionsKeepNames
   /** Debug mangled names. */
   debug?: boolean
+  /** Mangle property names. */
+  props?: MangleOptionsProps
 }
 
 export interface MangleOptionsKeepNames {
@@ -99,17 +193,75 @@ export interface MangleOptionsKeepNames {
 /** Minify synchronously. */
 export declare function minify(filename: string, sourceText: string, options?: MinifyOptions | undefined | null): MinifyResult
 
+export interface MangleOptionsProps {
+  /**
+   * Only mangle property names matching this regular expression, e.g. `"^_"`.
+   *
+   * Public property names are not mangled if not set.
+   */
+  regex?: string
+  /** Never mangle property names matching this regular expression. */
+  reservedRegex?: string
+  /** Never mangle these property names. */
+  reserved?: Array<string>
+  /**
+   * Only mangle unquoted property names.
+   *
+   * @default false
+   */
+  keepQuoted?: boolean
+  /**
+   * Mangle names of private class members.
+   *
+   * @default false
+   */
+  private?: boolean
+}
+
 export interface MinifyOptions {
   compress?: boolean | CompressOptions
   mangle?: boolean | MangleOptions
   codegen?: boolean | CodegenOptions
   sourcemap?: boolean
+  /**
+   * Source map of the input source text, e.g. produced by a previous compilation step.
+   *
+   * When {@link MinifyOptions#sourcemap sourcemap} is `true`,
+   * the generated source map is composed with it, so it maps back to the original sources.
+   */
+  inputSourceMap?: SourceMap
+  /**
+   * Mangled names of a previous build, in the JSON format of terser's `nameCache`.
+   *
+   * Pass {@link MinifyResult#nameCache} of the previous build to mangle names consistently.
+   *
+   * Top level names are only reused when {@link MangleOptions#toplevel toplevel} is enabled.
+   */
+  nameCache?: string
+  /**
+   * Return the symbols renamed by the mangler in {@link MinifyResult#renameMap}.
+   *
+   * @default false
+   */
+  renameMap?: boolean
 }
 
 export interface MinifyResult {
   code: string
   map?: SourceMap
   errors: Array<OxcError>
+  /**
+   * Mangled names of this build, including the ones from {@link MinifyOptions#nameCache},
+   * in the JSON format of terser's `nameCache`.
+   */
+  nameCache?: string
+  /**
+   * Symbols renamed by the mangler, as JSON of the form
+   * `{ "symbols": [{ "original", "renamed", "start", "end", "scope": { "name", "start", "end" } }] }`.
+   *
+   * Only returned when {@link MinifyOptions#renameMap} is `true`.
+   */
+  renameMap?: string
 }
 export interface Comment {
   type: 'Line' | 'Block'
@@ -17,8 +17,8 @@ use napi_derive::napi;
 use oxc_allocator::Allocator;
 use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_minifier::Minifier;
-use oxc_napi::OxcError;
+use oxc_minifier::{Minifier, NameCache};
+use oxc_napi::{OxcError, get_input_source_map};
 use oxc_parser::Parser;
 use oxc_sourcemap::napi::SourceMap;
 use oxc_span::SourceType;
@@ -31,6 +31,16 @@ pub struct MinifyResult {
     pub code: String,
     pub map: Option<SourceMap>,
     pub errors: Vec<OxcError>,
+
+    /// Mangled names of this build, including the ones from {@link MinifyOptions#nameCache},
+    /// in the JSON format of terser's `nameCache`.
+    pub name_cache: Option<String>,
+
+    /// Symbols renamed by the mangler, as JSON of the form
+    /// `{ "symbols": [{ "original", "renamed", "start", "end", "scope": { "name", "start", "end" } }] }`.
+    ///
+    /// Only returned when {@link MinifyOptions#renameMap} is `true`.
+    pub rename_map: Option<String>,
 }
 
 /// Minify synchronously.
@@ -40,7 +50,7 @@ pub fn minify(
     source_text: String,
     options: Option<MinifyOptions>,
 ) -> MinifyResult {
-    let options = options.unwrap_or_default();
+    let mut options = options.unwrap_or_default();
 
     let minifier_options = match oxc_minifier::MinifierOptions::try_from(&options) {
         Ok(options) => options,
@@ -56,6 +66,36 @@ pub fn minify(
         }
     };
 
+    let input_source_map = match options.input_source_map.take().map(get_input_source_map) {
+        Some(Ok(map)) => Some(map),
+        Some(Err(error)) => {
+            return MinifyResult {
+                errors: OxcError::from_diagnostics(
+                    &filename,
+                    &source_text,
+                    vec![OxcDiagnostic::error(error)],
+                ),
+                ..MinifyResult::default()
+            };
+        }
+        None => None,
+    };
+
+    let name_cache = match options.name_cache.as_deref().map(NameCache::from_json) {
+        Some(Ok(name_cache)) => name_cache,
+        Some(Err(error)) => {
+            return MinifyResult {
+                errors: OxcError::from_diagnostics(
+                    &filename,
+                    &source_text,
+                    vec![OxcDiagnostic::error(format!("Invalid name cache: {error}"))],
+                ),
+                ..MinifyResult::default()
+            };
+        }
+        None => NameCache::default(),
+    };
+
     let allocator = Allocator::default();
 
     let source_type = SourceType::from_path(&filename).unwrap_or_default();
@@ -63,7 +103,10 @@ pub fn minify(
     let parser_ret = Parser::new(&allocator, &source_text, source_type).parse();
     let mut program = parser_ret.program;
 
-    let scoping = Minifier::new(minifier_options).build(&allocator, &mut program).scoping;
+    let minifier_ret = Minifier::new(minifier_options)
+        .with_name_cache(name_cache)
+        .with_rename_map(options.rename_map == Some(true))
+        .build(&allocator, &mut program);
 
     let mut codegen_options = match &options.codegen {
         // Need to remove all comments.
@@ -74,13 +117,19 @@ pub fn minify(
 
     if options.sourcemap == Some(true) {
         codegen_options.source_map_path = Some(PathBuf::from(&filename));
+        codegen_options.input_source_map = input_source_map;
     }
 
-    let ret = Codegen::new().with_options(codegen_options).with_scoping(scoping).build(&program);
+    let ret = Codegen::new()
+        .with_options(codegen_options)
+        .with_scoping(minifier_ret.scoping)
+        .build(&program);
 
     MinifyResult {
         code: ret.code,
         map: ret.map.map(oxc_sourcemap::napi::SourceMap::from),
         errors: OxcError::from_diagnostics(&filename, &source_text, parser_ret.errors),
+        name_cache: minifier_ret.name_cache.map(|name_cache| name_cache.to_json()),
+        rename_map: minifier_ret.rename_map.map(|rename_map| rename_map.to_json()),
     }
 }
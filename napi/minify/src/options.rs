@@ -1,9 +1,11 @@
 use std::str::FromStr;
 
+use lazy_regex::Regex;
 use napi::Either;
 use napi_derive::napi;
 
 use oxc_minifier::TreeShakeOptions;
+use oxc_sourcemap::napi::SourceMap;
 use oxc_syntax::es_target::ESTarget;
 
 #[napi(object)]
@@ -33,14 +35,95 @@ pub struct CompressOptions {
     /// @default true
     pub drop_debugger: Option<bool>,
 
+    /// Remove labeled statements with these labels, e.g. `DEV: { ... }`.
+    ///
+    /// @default []
+    pub drop_labels: Option<Vec<String>>,
+
+    /// Drop calls to these functions when their return value is unused.
+    ///
+    /// Dotted names such as `console.log` are supported.
+    /// Arguments with side effects are kept.
+    ///
+    /// @default []
+    pub pure_funcs: Option<Vec<String>>,
+
     /// Drop unreferenced functions and variables.
     ///
     /// Simple direct variable assignments do not count as references unless set to "keep_assign".
+    /// Also drops assignments which are always overwritten before being read.
+    ///
+    /// @default false
     #[napi(ts_type = "true | false | 'keep_assign'")]
-    pub unused: Option<String>,
+    pub unused: Option<Either<bool, String>>,
+
+    /// Inline single-use function declarations into their call site.
+    ///
+    /// * `0`: disabled
+    /// * `1`: functions without parameters
+    /// * `2`: functions whose parameters are used at most once
+    /// * `3`: functions whose parameters are used multiple times, if the arguments are primitive literals
+    ///
+    /// Has no effect unless `unused` is enabled.
+    ///
+    /// @default 3
+    #[napi(ts_type = "0 | 1 | 2 | 3")]
+    pub inline: Option<u32>,
+
+    /// Substitute variables which are never reassigned.
+    ///
+    /// Has no effect unless `unused` is enabled.
+    ///
+    /// @default true
+    pub reduce_vars: Option<bool>,
 
     /// Keep function / class names.
     pub keep_names: Option<CompressOptionsKeepNames>,
+
+    /// Maximum number of times the compress optimizations are run.
+    ///
+    /// `0` runs them until no more changes are made.
+    ///
+    /// @default 0
+    pub passes: Option<u32>,
+
+    /// Assume that reading a property has no side effects.
+    ///
+    /// @default false
+    pub pure_getters: Option<bool>,
+
+    /// Reassociate numeric constants, e.g. `x * 2 * 3` -> `x * 6`.
+    ///
+    /// @default false
+    pub unsafe_math: Option<bool>,
+
+    /// Convert function expressions which do not use `this` to arrow functions.
+    ///
+    /// @default false
+    pub unsafe_arrows: Option<bool>,
+
+    /// Convert function expressions in object literals to methods.
+    ///
+    /// @default false
+    pub unsafe_methods: Option<bool>,
+
+    /// Keep unused function parameters.
+    ///
+    /// @default true
+    pub keep_fargs: Option<bool>,
+
+    /// Hoist properties of local object literals into variables.
+    ///
+    /// @default true
+    pub hoist_props: Option<bool>,
+
+    /// Compress a scope-hoisted bundle.
+    ///
+    /// Unused top level declarations of scripts are removed,
+    /// and namespace objects defined by `__export` or `Object.defineProperty` are inlined.
+    ///
+    /// @default false
+    pub bundle: Option<bool>,
 }
 
 impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
@@ -56,11 +139,38 @@ impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
                 .unwrap_or(default.target),
             drop_console: o.drop_console.unwrap_or(default.drop_console),
             drop_debugger: o.drop_debugger.unwrap_or(default.drop_debugger),
+            drop_labels: o.drop_labels.clone().unwrap_or(default.drop_labels),
+            pure_funcs: o.pure_funcs.clone().unwrap_or(default.pure_funcs),
             // TODO
             join_vars: true,
             sequences: true,
-            // TODO
-            unused: oxc_minifier::CompressOptionsUnused::Keep,
+            unused: match &o.unused {
+                Some(Either::A(true)) => oxc_minifier::CompressOptionsUnused::Remove,
+                Some(Either::A(false)) | None => oxc_minifier::CompressOptionsUnused::Keep,
+                Some(Either::B(s)) if s == "keep_assign" => {
+                    oxc_minifier::CompressOptionsUnused::KeepAssign
+                }
+                Some(Either::B(s)) => return Err(format!("Invalid unused option: `{s}`")),
+            },
+            inline: match o.inline {
+                Some(inline @ 0..=3) => u8::try_from(inline).unwrap(),
+                Some(_) => return Err("inline must be 0, 1, 2 or 3".to_string()),
+                None => default.inline,
+            },
+            reduce_vars: o.reduce_vars.unwrap_or(default.reduce_vars),
+            passes: o
+                .passes
+                .map(u8::try_from)
+                .transpose()
+                .map_err(|_| "passes must be less than 256".to_string())?
+                .unwrap_or(default.passes),
+            pure_getters: o.pure_getters.unwrap_or(default.pure_getters),
+            unsafe_math: o.unsafe_math.unwrap_or(default.unsafe_math),
+            unsafe_arrows: o.unsafe_arrows.unwrap_or(default.unsafe_arrows),
+            unsafe_methods: o.unsafe_methods.unwrap_or(default.unsafe_methods),
+            keep_fargs: o.keep_fargs.unwrap_or(default.keep_fargs),
+            hoist_props: o.hoist_props.unwrap_or(default.hoist_props),
+            bundle: o.bundle.unwrap_or(default.bundle),
             keep_names: o.keep_names.as_ref().map(Into::into).unwrap_or_default(),
             treeshake: TreeShakeOptions::default(),
         })
@@ -105,12 +215,17 @@ pub struct MangleOptions {
 
     /// Debug mangled names.
     pub debug: Option<bool>,
+
+    /// Mangle property names.
+    pub props: Option<MangleOptionsProps>,
 }
 
-impl From<&MangleOptions> for oxc_minifier::MangleOptions {
-    fn from(o: &MangleOptions) -> Self {
+impl TryFrom<&MangleOptions> for oxc_minifier::MangleOptions {
+    type Error = String;
+
+    fn try_from(o: &MangleOptions) -> Result<Self, Self::Error> {
         let default = oxc_minifier::MangleOptions::default();
-        Self {
+        Ok(Self {
             top_level: o.toplevel.unwrap_or(default.top_level),
             keep_names: match &o.keep_names {
                 Some(Either::A(false)) => oxc_minifier::MangleOptionsKeepNames::all_false(),
@@ -119,7 +234,50 @@ impl From<&MangleOptions> for oxc_minifier::MangleOptions {
                 None => default.keep_names,
             },
             debug: o.debug.unwrap_or(default.debug),
-        }
+            props: o.props.as_ref().map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct MangleOptionsProps {
+    /// Only mangle property names matching this regular expression, e.g. `"^_"`.
+    ///
+    /// Public property names are not mangled if not set.
+    pub regex: Option<String>,
+
+    /// Never mangle property names matching this regular expression.
+    pub reserved_regex: Option<String>,
+
+    /// Never mangle these property names.
+    pub reserved: Option<Vec<String>>,
+
+    /// Only mangle unquoted property names.
+    ///
+    /// @default false
+    pub keep_quoted: Option<bool>,
+
+    /// Mangle names of private class members.
+    ///
+    /// @default false
+    pub private: Option<bool>,
+}
+
+impl TryFrom<&MangleOptionsProps> for oxc_minifier::MangleOptionsProps {
+    type Error = String;
+
+    fn try_from(o: &MangleOptionsProps) -> Result<Self, Self::Error> {
+        let regex = |pattern: &Option<String>| {
+            pattern.as_deref().map(Regex::new).transpose().map_err(|err| err.to_string())
+        };
+        Ok(Self {
+            regex: regex(&o.regex)?,
+            reserved_regex: regex(&o.reserved_regex)?,
+            reserved: o.reserved.clone().unwrap_or_default(),
+            keep_quoted: o.keep_quoted.unwrap_or_default(),
+            private: o.private.unwrap_or_default(),
+        })
     }
 }
 
@@ -177,6 +335,24 @@ pub struct MinifyOptions {
     pub codegen: Option<Either<bool, CodegenOptions>>,
 
     pub sourcemap: Option<bool>,
+
+    /// Source map of the input source text, e.g. produced by a previous compilation step.
+    ///
+    /// When {@link MinifyOptions#sourcemap sourcemap} is `true`,
+    /// the generated source map is composed with it, so it maps back to the original sources.
+    pub input_source_map: Option<SourceMap>,
+
+    /// Mangled names of a previous build, in the JSON format of terser's `nameCache`.
+    ///
+    /// Pass {@link MinifyResult#nameCache} of the previous build to mangle names consistently.
+    ///
+    /// Top level names are only reused when {@link MangleOptions#toplevel toplevel} is enabled.
+    pub name_cache: Option<String>,
+
+    /// Return the symbols renamed by the mangler in {@link MinifyResult#renameMap}.
+    ///
+    /// @default false
+    pub rename_map: Option<bool>,
 }
 
 impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
@@ -191,7 +367,7 @@ impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
         let mangle = match &o.mangle {
             Some(Either::A(false)) => None,
             None | Some(Either::A(true)) => Some(oxc_minifier::MangleOptions::default()),
-            Some(Either::B(o)) => Some(oxc_minifier::MangleOptions::from(o)),
+            Some(Either::B(o)) => Some(oxc_minifier::MangleOptions::try_from(o)?),
         };
         Ok(oxc_minifier::MinifierOptions { compress, mangle })
     }
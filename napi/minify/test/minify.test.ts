@@ -40,6 +40,18 @@ describe('simple', () => {
     expect(ret.code).toBe('try{foo}catch{}');
   });
 
+  it('inlines functions when unused is enabled', () => {
+    const code = 'function f() { return 1 } console.log(f())';
+    const ret = minify('test.js', code, { compress: { unused: true } });
+    expect(ret.code).toBe('console.log(1);');
+  });
+
+  it('keeps functions when inline is disabled', () => {
+    const code = 'function f() { return 1 } console.log(f())';
+    const ret = minify('test.js', code, { compress: { unused: true, inline: 0 } });
+    expect(ret.code).toContain('function f()');
+  });
+
   it('returns parser error', () => {
     const code = 'const';
     const ret = minify('test.js', code);
@@ -52,6 +52,16 @@ export interface CompilerAssumptions {
   ignoreFunctionLength?: boolean
   noDocumentAll?: boolean
   objectRestNoSymbols?: boolean
+  /**
+   * Store private class fields as non-enumerable properties with string keys,
+   * instead of in `WeakMap`s.
+   */
+  privateFieldsAsProperties?: boolean
+  /**
+   * Store private class fields as non-enumerable properties with `Symbol` keys,
+   * instead of in `WeakMap`s.
+   */
+  privateFieldsAsSymbols?: boolean
   pureGetters?: boolean
   /**
    * When using public class fields, assume that they don't shadow any getter in the current class,
@@ -95,6 +105,11 @@ export interface CompilerAssumptions {
    * to `true`.
    */
   setPublicClassFields?: boolean
+  /**
+   * When using object spread, assume that spread properties don't trigger getters on the target
+   * object and thus it's safe to assign them rather than defining them using `Object.defineProperty`.
+   */
+  setSpreadProperties?: boolean
 }
 
 export interface DecoratorOptions {
@@ -108,6 +123,16 @@ export interface DecoratorOptions {
    * @default false
    */
   legacy?: boolean
+  /**
+   * Enables the transform of decorators following the TC39 2023-11 decorators proposal,
+   * including lowering of class auto-accessors.
+   *
+   * Has no effect if `legacy` is true.
+   *
+   * @see https://github.com/tc39/proposal-decorators
+   * @default false
+   */
+  standard?: boolean
   /**
    * Enables emitting decorator metadata.
    *
@@ -120,11 +145,96 @@ export interface DecoratorOptions {
   emitDecoratorMetadata?: boolean
 }
 
+export interface EmotionImportMapEntry {
+  /** `[package, export]` of the Emotion export which this export behaves like. */
+  canonicalImport: [string, string]
+  /**
+   * `[package, export]` of the function called instead of this export,
+   * when it behaves like `@emotion/styled`.
+   */
+  styledBaseImport?: [string, string]
+}
+
+/**
+ * Configure how Emotion styles are transformed.
+ *
+ * @see {@link https://emotion.sh/docs/@emotion/babel-plugin}
+ */
+export interface EmotionOptions {
+  /**
+   * Adds inline source maps to styles in development.
+   *
+   * @default true
+   */
+  sourceMap?: boolean
+  /**
+   * Controls when labels are added to class names.
+   *
+   * @default 'dev-only'
+   */
+  autoLabel?: 'dev-only' | 'always' | 'never'
+  /**
+   * Format of labels. Supports `[local]`, `[filename]` and `[dirname]` placeholders.
+   *
+   * @default '[local]'
+   */
+  labelFormat?: string
+  /**
+   * Precompiles object and array styles of the JSX `css` prop.
+   *
+   * @default true
+   */
+  cssPropOptimization?: boolean
+  /**
+   * Treats exports of other packages as Emotion exports, keyed by package name,
+   * then by export name.
+   */
+  importMap?: Record<string, Record<string, EmotionImportMapEntry>>
+}
+
 export interface Es2015Options {
   /** Transform arrow functions into function expressions. */
   arrowFunction?: ArrowFunctionsOptions
 }
 
+/**
+ * Configure how `gql` tagged templates of `graphql-tag` are precompiled.
+ *
+ * @see {@link https://github.com/gajus/babel-plugin-graphql-tag}
+ */
+export interface GraphQLTagOptions {
+  /**
+   * Packages which export the `gql` tag, as the default export or the `gql` export.
+   *
+   * @default ['graphql-tag']
+   */
+  importSources?: Array<string>
+  /**
+   * Match import sources which end with one of `importSources`, instead of the whole source.
+   *
+   * @default false
+   */
+  onlyMatchImportSuffix?: boolean
+  /**
+   * Names of `gql` tags which are compiled without being imported.
+   *
+   * @default []
+   */
+  gqlTagIdentifiers?: Array<string>
+  /**
+   * Strips whitespace, commas and comments from the GraphQL source kept in `loc`.
+   *
+   * @default false
+   */
+  strip?: boolean
+  /**
+   * Removes `loc` of the document, which contains the GraphQL source.
+   *
+   * @default false
+   */
+  removeLoc?: boolean
+}
+
 export declare const enum HelperMode {
   /**
    * Runtime mode (default): Helper functions are imported from a runtime package.
@@ -186,10 +296,11 @@ export interface JsxOptions {
    *
    * - 'automatic' - auto-import the correct JSX factories
    * - 'classic' - no auto-import
+   * - 'vue' - transform to Vue 3 `createVNode` calls
    *
    * @default 'automatic'
    */
-  runtime?: 'classic' | 'automatic'
+  runtime?: 'classic' | 'automatic' | 'vue'
   /**
    * Emit development-specific information, such as `__source` and `__self`.
    *
@@ -268,6 +379,14 @@ export interface JsxOptions {
    * @default false
    */
   refresh?: boolean | ReactRefreshOptions
+  /**
+   * Options of Vue JSX.
+   *
+   * Only used for `vue` {@link runtime}.
+   *
+   * @see {@link https://github.com/vuejs/babel-plugin-jsx#options}
+   */
+  vue?: VueJsxOptions
 }
 
 /**
@@ -325,6 +444,17 @@ export interface ModuleRunnerTransformResult {
 
 export interface PluginsOptions {
   styledComponents?: StyledComponentsOptions
+  emotion?: EmotionOptions
+  graphqlTag?: GraphQLTagOptions
+  /**
+   * Transform imports and exports for Vite's module runner, like {@link moduleRunnerTransform}.
+   *
+   * Imported modules are returned in {@link TransformResult#deps} and
+   * {@link TransformResult#dynamicDeps}.
+   *
+   * @default false
+   */
+  moduleRunnerTransform?: boolean
 }
 
 export interface ReactRefreshOptions {
@@ -348,6 +478,27 @@ export interface ReactRefreshOptions {
  *
  * @see {@link https://styled-components.com/docs/tooling#babel-plugin}
  */
+export interface RewritePathsOptions {
+  /**
+   * Directory which relative `baseUrl` and `paths` are resolved from.
+   *
+   * @default cwd
+   */
+  basePath?: string
+  /**
+   * Base directory to resolve non-relative module names from.
+   *
+   * @see https://www.typescriptlang.org/tsconfig/#baseUrl
+   */
+  baseUrl?: string
+  /**
+   * Map of module name patterns to a list of locations.
+   *
+   * @see https://www.typescriptlang.org/tsconfig/#paths
+   */
+  paths?: Record<string, Array<string>>
+}
+
 export interface StyledComponentsOptions {
   /**
    * Enhances the attached CSS class name on each component with richer output to help
@@ -461,6 +612,14 @@ export interface TransformOptions {
    * @see {@link SourceMap}
    */
   sourcemap?: boolean
+  /**
+   * Source map of the input source text, e.g. produced by a previous compilation step
+   * such as a Vue or Svelte compiler.
+   *
+   * When {@link TransformOptions#sourcemap sourcemap} is `true`, the generated source map
+   * is composed with it, so it maps back to the original sources.
+   */
+  inputSourceMap?: SourceMap
   /** Set assumptions in order to produce smaller output. */
   assumptions?: CompilerAssumptions
   /** Configure how TypeScript is transformed. */
@@ -484,6 +643,17 @@ export interface TransformOptions {
   target?: string | Array<string>
   /** Behaviour for runtime helpers. */
   helpers?: Helpers
+  /**
+   * Inject `core-js` polyfills for built-ins used in the code which are not supported by `target`.
+   *
+   * * `usage-global`: `import "core-js/modules/es.promise.js"`
+   * * `usage-pure`: `import _Promise from "core-js-pure/stable/promise/index.js"`
+   *
+   * @default undefined (No polyfills)
+   *
+   * @see [babel-plugin-polyfill-corejs3](https://github.com/babel/babel-polyfills/tree/main/packages/babel-plugin-polyfill-corejs3)
+   */
+  polyfills?: 'usage-global' | 'usage-pure'
   /** Define Plugin */
   define?: Record<string, string>
   /** Inject Plugin */
@@ -492,6 +662,20 @@ export interface TransformOptions {
   decorator?: DecoratorOptions
   /** Third-party plugins to use. */
   plugins?: PluginsOptions
+  /**
+   * Load plugins and presets from the project's Babel config files.
+   *
+   * * `true`: use `babel.config.json` in {@link TransformOptions#cwd cwd}, and the nearest
+   *   `.babelrc`, `.babelrc.json` or `package.json#babel` of the transformed file.
+   * * `string`: path of the project-wide config file, instead of `babel.config.json`.
+   *
+   * Only JSON config files are supported. `env` blocks are selected by `BABEL_ENV` or `NODE_ENV`.
+   * When set, `assumptions`, `typescript`, `jsx`, `target`, `helpers`, `polyfills`, `decorator`
+   * and `plugins` options are ignored. Unsupported plugins and presets are reported as warnings.
+   *
+   * @default false
+   */
+  babelConfig?: boolean | string
 }
 
 export interface TransformResult {
@@ -536,6 +720,10 @@ export interface TransformResult {
    * ```
    */
   helpersUsed: Record<string, string>
+  /** Import sources collected by {@link PluginsOptions#moduleRunnerTransform}. */
+  deps: Array<string>
+  /** Dynamic import sources collected by {@link PluginsOptions#moduleRunnerTransform}. */
+  dynamicDeps: Array<string>
   /**
    * Parse and transformation errors.
    *
@@ -616,4 +804,47 @@ export interface TypeScriptOptions {
    * @default false
    */
   rewriteImportExtensions?: 'rewrite' | 'remove' | boolean
+  /**
+   * Rewrite import specifiers which match tsconfig `paths` or `baseUrl` to relative paths.
+   *
+   * - When set to a string, `baseUrl` and `paths` are read from the tsconfig file at that path.
+   * - When set to an object, `baseUrl` and `paths` are taken from it directly.
+   * - When omitted, no changes will be made to the specifiers.
+   *
+   * @default undefined
+   */
+  rewritePaths?: string | RewritePathsOptions
+}
+
+export interface VueJsxOptions {
+  /**
+   * Transform `on={{ click: handler }}` to `onClick: handler`.
+   *
+   * @default false
+   */
+  transformOn?: boolean
+  /**
+   * Emit patch flags and dynamic prop names for Vue's optimized diffing.
+   *
+   * @default false
+   */
+  optimize?: boolean
+  /**
+   * Merge `class`, `style`, `on*` and spread props with `mergeProps`.
+   *
+   * @default true
+   */
+  mergeProps?: boolean
+  /**
+   * Treat a single identifier or call expression child of a component as slots.
+   *
+   * @default true
+   */
+  enableObjectSlots?: boolean
+  /**
+   * Tags of custom elements, which are not resolved as components.
+   *
+   * @default []
+   */
+  customElements?: Array<string>
 }
@@ -19,15 +19,15 @@ use oxc::{
     semantic::{SemanticBuilder, SemanticBuilderReturn},
     span::SourceType,
     transformer::{
-        EnvOptions, HelperLoaderMode, HelperLoaderOptions, JsxRuntime, ProposalOptions,
-        RewriteExtensionsMode,
+        EnvOptions, HelperLoaderMode, HelperLoaderOptions, JsxRuntime, PolyfillMethod,
+        ProposalOptions, RewriteExtensionsMode,
     },
     transformer_plugins::{
         InjectGlobalVariablesConfig, InjectImport, ModuleRunnerTransform,
         ReplaceGlobalDefinesConfig,
     },
 };
-use oxc_napi::{OxcError, get_source_type};
+use oxc_napi::{OxcError, get_input_source_map, get_source_type};
 use oxc_sourcemap::napi::SourceMap;
 
 use crate::IsolatedDeclarationsOptions;
@@ -72,6 +72,12 @@ pub struct TransformResult {
     #[napi(ts_type = "Record<string, string>")]
     pub helpers_used: FxHashMap<String, String>,
 
+    /// Import sources collected by {@link PluginsOptions#moduleRunnerTransform}.
+    pub deps: Vec<String>,
+
+    /// Dynamic import sources collected by {@link PluginsOptions#moduleRunnerTransform}.
+    pub dynamic_deps: Vec<String>,
+
     /// Parse and transformation errors.
     ///
     /// Oxc's parser recovers from common syntax errors, meaning that
@@ -107,6 +113,13 @@ pub struct TransformOptions {
     /// @see {@link SourceMap}
     pub sourcemap: Option<bool>,
 
+    /// Source map of the input source text, e.g. produced by a previous compilation step
+    /// such as a Vue or Svelte compiler.
+    ///
+    /// When {@link TransformOptions#sourcemap sourcemap} is `true`, the generated source map
+    /// is composed with it, so it maps back to the original sources.
+    pub input_source_map: Option<SourceMap>,
+
     /// Set assumptions in order to produce smaller output.
     pub assumptions: Option<CompilerAssumptions>,
 
@@ -134,6 +147,17 @@ pub struct TransformOptions {
     /// Behaviour for runtime helpers.
     pub helpers: Option<Helpers>,
 
+    /// Inject `core-js` polyfills for built-ins used in the code which are not supported by `target`.
+    ///
+    /// * `usage-global`: `import "core-js/modules/es.promise.js"`
+    /// * `usage-pure`: `import _Promise from "core-js-pure/stable/promise/index.js"`
+    ///
+    /// @default undefined (No polyfills)
+    ///
+    /// @see [babel-plugin-polyfill-corejs3](https://github.com/babel/babel-polyfills/tree/main/packages/babel-plugin-polyfill-corejs3)
+    #[napi(ts_type = "'usage-global' | 'usage-pure'")]
+    pub polyfills: Option<String>,
+
     /// Define Plugin
     #[napi(ts_type = "Record<string, string>")]
     pub define: Option<FxHashMap<String, String>>,
@@ -147,24 +171,58 @@ pub struct TransformOptions {
 
     /// Third-party plugins to use.
     pub plugins: Option<PluginsOptions>,
+
+    /// Load plugins and presets from the project's Babel config files.
+    ///
+    /// * `true`: use `babel.config.json` in {@link TransformOptions#cwd cwd}, and the nearest
+    ///   `.babelrc`, `.babelrc.json` or `package.json#babel` of the transformed file.
+    /// * `string`: path of the project-wide config file, instead of `babel.config.json`.
+    ///
+    /// Only JSON config files are supported. `env` blocks are selected by `BABEL_ENV` or `NODE_ENV`.
+    /// When set, `assumptions`, `typescript`, `jsx`, `target`, `helpers`, `polyfills`, `decorator`
+    /// and `plugins` options are ignored. Unsupported plugins and presets are reported as warnings.
+    ///
+    /// @default false
+    #[napi(ts_type = "boolean | string")]
+    pub babel_config: Option<Either<bool, String>>,
 }
 
 impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
     type Error = String;
 
-    fn try_from(options: TransformOptions) -> Result<Self, Self::Error> {
-        let env = match options.target {
+    fn try_from(mut options: TransformOptions) -> Result<Self, Self::Error> {
+        let rewrite_paths = options
+            .typescript
+            .as_mut()
+            .and_then(|typescript| typescript.rewrite_paths.take())
+            .map(|rewrite_paths| match rewrite_paths {
+                Either::A(tsconfig) => {
+                    oxc::transformer::RewritePathsOptions::from_tsconfig(Path::new(&tsconfig))
+                }
+                Either::B(options) => Ok(oxc::transformer::RewritePathsOptions::from(options)),
+            })
+            .transpose()?;
+        let mut env = match options.target {
             Some(Either::A(s)) => EnvOptions::from_target(&s)?,
             Some(Either::B(list)) => EnvOptions::from_target_list(&list)?,
             _ => EnvOptions::default(),
         };
+        env.polyfills.method = match options.polyfills.as_deref() {
+            Some("usage-global") => Some(PolyfillMethod::UsageGlobal),
+            Some("usage-pure") => Some(PolyfillMethod::UsagePure),
+            Some(s) => return Err(format!("Invalid polyfills option: `{s}`.")),
+            None => None,
+        };
         Ok(Self {
             cwd: options.cwd.map(PathBuf::from).unwrap_or_default(),
             assumptions: options.assumptions.map(Into::into).unwrap_or_default(),
-            typescript: options
-                .typescript
-                .map(oxc::transformer::TypeScriptOptions::from)
-                .unwrap_or_default(),
+            typescript: oxc::transformer::TypeScriptOptions {
+                rewrite_paths,
+                ..options
+                    .typescript
+                    .map(oxc::transformer::TypeScriptOptions::from)
+                    .unwrap_or_default()
+            },
             decorator: options
                 .decorator
                 .map(oxc::transformer::DecoratorOptions::from)
@@ -181,13 +239,15 @@ impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
                 None => oxc::transformer::JsxOptions::enable(),
             },
             env,
+            modules: oxc::transformer::ModulesOptions::default(),
             proposals: ProposalOptions::default(),
             helper_loader: options
                 .helpers
                 .map_or_else(HelperLoaderOptions::default, HelperLoaderOptions::from),
             plugins: options
                 .plugins
-                .map(oxc::transformer::PluginsOptions::from)
+                .map(oxc::transformer::PluginsOptions::try_from)
+                .transpose()?
                 .unwrap_or_default(),
         })
     }
@@ -199,6 +259,12 @@ pub struct CompilerAssumptions {
     pub ignore_function_length: Option<bool>,
     pub no_document_all: Option<bool>,
     pub object_rest_no_symbols: Option<bool>,
+    /// Store private class fields as non-enumerable properties with string keys,
+    /// instead of in `WeakMap`s.
+    pub private_fields_as_properties: Option<bool>,
+    /// Store private class fields as non-enumerable properties with `Symbol` keys,
+    /// instead of in `WeakMap`s.
+    pub private_fields_as_symbols: Option<bool>,
     pub pure_getters: Option<bool>,
     /// When using public class fields, assume that they don't shadow any getter in the current class,
     /// in its subclasses or in its superclass. Thus, it's safe to assign them rather than using
@@ -240,6 +306,9 @@ pub struct CompilerAssumptions {
     /// set both `set_public_class_fields` and [`crate::TypeScriptOptions::remove_class_fields_without_initializer`]
     /// to `true`.
     pub set_public_class_fields: Option<bool>,
+    /// When using object spread, assume that spread properties don't trigger getters on the target
+    /// object and thus it's safe to assign them rather than defining them using `Object.defineProperty`.
+    pub set_spread_properties: Option<bool>,
 }
 
 impl From<CompilerAssumptions> for oxc::transformer::CompilerAssumptions {
@@ -253,10 +322,17 @@ impl From<CompilerAssumptions> for oxc::transformer::CompilerAssumptions {
             object_rest_no_symbols: value
                 .object_rest_no_symbols
                 .unwrap_or(ops.object_rest_no_symbols),
+            private_fields_as_properties: value
+                .private_fields_as_properties
+                .unwrap_or(ops.private_fields_as_properties),
+            private_fields_as_symbols: value
+                .private_fields_as_symbols
+                .unwrap_or(ops.private_fields_as_symbols),
             pure_getters: value.pure_getters.unwrap_or(ops.pure_getters),
             set_public_class_fields: value
                 .set_public_class_fields
                 .unwrap_or(ops.set_public_class_fields),
+            set_spread_properties: value.set_spread_properties.unwrap_or(ops.set_spread_properties),
             ..ops
         }
     }
@@ -327,6 +403,42 @@ pub struct TypeScriptOptions {
     /// @default false
     #[napi(ts_type = "'rewrite' | 'remove' | boolean")]
     pub rewrite_import_extensions: Option<Either<bool, String>>,
+    /// Rewrite import specifiers which match tsconfig `paths` or `baseUrl` to relative paths.
+    ///
+    /// - When set to a string, `baseUrl` and `paths` are read from the tsconfig file at that path.
+    /// - When set to an object, `baseUrl` and `paths` are taken from it directly.
+    /// - When omitted, no changes will be made to the specifiers.
+    ///
+    /// @default undefined
+    #[napi(ts_type = "string | RewritePathsOptions")]
+    pub rewrite_paths: Option<Either<String, RewritePathsOptions>>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct RewritePathsOptions {
+    /// Directory which relative `baseUrl` and `paths` are resolved from.
+    ///
+    /// @default cwd
+    pub base_path: Option<String>,
+    /// Base directory to resolve non-relative module names from.
+    ///
+    /// @see https://www.typescriptlang.org/tsconfig/#baseUrl
+    pub base_url: Option<String>,
+    /// Map of module name patterns to a list of locations.
+    ///
+    /// @see https://www.typescriptlang.org/tsconfig/#paths
+    pub paths: Option<FxHashMap<String, Vec<String>>>,
+}
+
+impl From<RewritePathsOptions> for oxc::transformer::RewritePathsOptions {
+    fn from(options: RewritePathsOptions) -> Self {
+        oxc::transformer::RewritePathsOptions {
+            base_path: options.base_path.map(PathBuf::from).unwrap_or_default(),
+            base_url: options.base_url.map(PathBuf::from),
+            paths: options.paths.map(|paths| paths.into_iter().collect()).unwrap_or_default(),
+        }
+    }
 }
 
 impl From<TypeScriptOptions> for oxc::transformer::TypeScriptOptions {
@@ -341,6 +453,7 @@ impl From<TypeScriptOptions> for oxc::transformer::TypeScriptOptions {
             allow_namespaces: options.allow_namespaces.unwrap_or(ops.allow_namespaces),
             allow_declare_fields: options.allow_declare_fields.unwrap_or(ops.allow_declare_fields),
             optimize_const_enums: false,
+            external_const_enums: ops.external_const_enums,
             remove_class_fields_without_initializer: options
                 .remove_class_fields_without_initializer
                 .unwrap_or(ops.remove_class_fields_without_initializer),
@@ -360,6 +473,7 @@ impl From<TypeScriptOptions> for oxc::transformer::TypeScriptOptions {
                     },
                 }
             }),
+            rewrite_paths: None,
         }
     }
 }
@@ -376,6 +490,15 @@ pub struct DecoratorOptions {
     /// @default false
     pub legacy: Option<bool>,
 
+    /// Enables the transform of decorators following the TC39 2023-11 decorators proposal,
+    /// including lowering of class auto-accessors.
+    ///
+    /// Has no effect if `legacy` is true.
+    ///
+    /// @see https://github.com/tc39/proposal-decorators
+    /// @default false
+    pub standard: Option<bool>,
+
     /// Enables emitting decorator metadata.
     ///
     /// This option the same as [emitDecoratorMetadata](https://www.typescriptlang.org/tsconfig/#emitDecoratorMetadata)
@@ -390,6 +513,7 @@ impl From<DecoratorOptions> for oxc::transformer::DecoratorOptions {
     fn from(options: DecoratorOptions) -> Self {
         oxc::transformer::DecoratorOptions {
             legacy: options.legacy.unwrap_or_default(),
+            standard: options.standard.unwrap_or_default(),
             emit_decorator_metadata: options.emit_decorator_metadata.unwrap_or_default(),
         }
     }
@@ -463,19 +587,176 @@ pub struct StyledComponentsOptions {
     pub top_level_import_paths: Option<Vec<String>>,
 }
 
+/// Configure how Emotion styles are transformed.
+///
+/// @see {@link https://emotion.sh/docs/@emotion/babel-plugin}
+#[napi(object)]
+#[derive(Default)]
+pub struct EmotionOptions {
+    /// Adds inline source maps to styles in development.
+    ///
+    /// @default true
+    pub source_map: Option<bool>,
+
+    /// Controls when labels are added to class names.
+    ///
+    /// @default 'dev-only'
+    #[napi(ts_type = "'dev-only' | 'always' | 'never'")]
+    pub auto_label: Option<String>,
+
+    /// Format of labels. Supports `[local]`, `[filename]` and `[dirname]` placeholders.
+    ///
+    /// @default '[local]'
+    pub label_format: Option<String>,
+
+    /// Precompiles object and array styles of the JSX `css` prop.
+    ///
+    /// @default true
+    pub css_prop_optimization: Option<bool>,
+
+    /// Treats exports of other packages as Emotion exports, keyed by package name,
+    /// then by export name.
+    #[napi(ts_type = "Record<string, Record<string, EmotionImportMapEntry>>")]
+    pub import_map: Option<FxHashMap<String, FxHashMap<String, EmotionImportMapEntry>>>,
+}
+
+#[napi(object)]
+pub struct EmotionImportMapEntry {
+    /// `[package, export]` of the Emotion export which this export behaves like.
+    #[napi(ts_type = "[string, string]")]
+    pub canonical_import: Vec<String>,
+
+    /// `[package, export]` of the function called instead of this export,
+    /// when it behaves like `@emotion/styled`.
+    #[napi(ts_type = "[string, string]")]
+    pub styled_base_import: Option<Vec<String>>,
+}
+
+/// Configure how `gql` tagged templates of `graphql-tag` are precompiled.
+///
+/// @see {@link https://github.com/gajus/babel-plugin-graphql-tag}
+#[napi(object)]
+#[derive(Default)]
+pub struct GraphQLTagOptions {
+    /// Packages which export the `gql` tag, as the default export or the `gql` export.
+    ///
+    /// @default ['graphql-tag']
+    pub import_sources: Option<Vec<String>>,
+
+    /// Match import sources which end with one of `importSources`, instead of the whole source.
+    ///
+    /// @default false
+    pub only_match_import_suffix: Option<bool>,
+
+    /// Names of `gql` tags which are compiled without being imported.
+    ///
+    /// @default []
+    pub gql_tag_identifiers: Option<Vec<String>>,
+
+    /// Strips whitespace, commas and comments from the GraphQL source kept in `loc`.
+    ///
+    /// @default false
+    pub strip: Option<bool>,
+
+    /// Removes `loc` of the document, which contains the GraphQL source.
+    ///
+    /// @default false
+    pub remove_loc: Option<bool>,
+}
+
 #[napi(object)]
 #[derive(Default)]
 pub struct PluginsOptions {
     pub styled_components: Option<StyledComponentsOptions>,
+    pub emotion: Option<EmotionOptions>,
+    pub graphql_tag: Option<GraphQLTagOptions>,
+    /// Transform imports and exports for Vite's module runner, like {@link moduleRunnerTransform}.
+    ///
+    /// Imported modules are returned in {@link TransformResult#deps} and
+    /// {@link TransformResult#dynamicDeps}.
+    ///
+    /// @default false
+    pub module_runner_transform: Option<bool>,
 }
 
-impl From<PluginsOptions> for oxc::transformer::PluginsOptions {
-    fn from(options: PluginsOptions) -> Self {
-        oxc::transformer::PluginsOptions {
+impl TryFrom<PluginsOptions> for oxc::transformer::PluginsOptions {
+    type Error = String;
+
+    fn try_from(options: PluginsOptions) -> Result<Self, Self::Error> {
+        Ok(oxc::transformer::PluginsOptions {
             styled_components: options
                 .styled_components
                 .map(oxc::transformer::StyledComponentsOptions::from),
+            emotion: options.emotion.map(oxc::transformer::EmotionOptions::try_from).transpose()?,
+            graphql_tag: options.graphql_tag.map(oxc::transformer::GraphQLTagOptions::from),
+            module_runner_transform: options.module_runner_transform.unwrap_or(false),
+        })
+    }
+}
+
+impl From<GraphQLTagOptions> for oxc::transformer::GraphQLTagOptions {
+    fn from(options: GraphQLTagOptions) -> Self {
+        let ops = oxc::transformer::GraphQLTagOptions::default();
+        oxc::transformer::GraphQLTagOptions {
+            import_sources: options.import_sources.unwrap_or(ops.import_sources),
+            only_match_import_suffix: options
+                .only_match_import_suffix
+                .unwrap_or(ops.only_match_import_suffix),
+            gql_tag_identifiers: options.gql_tag_identifiers.unwrap_or(ops.gql_tag_identifiers),
+            strip: options.strip.unwrap_or(ops.strip),
+            remove_loc: options.remove_loc.unwrap_or(ops.remove_loc),
+        }
+    }
+}
+
+impl TryFrom<EmotionOptions> for oxc::transformer::EmotionOptions {
+    type Error = String;
+
+    fn try_from(options: EmotionOptions) -> Result<Self, Self::Error> {
+        fn import_pair(import: Vec<String>) -> Result<(String, String), String> {
+            <[String; 2]>::try_from(import)
+                .map(Into::into)
+                .map_err(|import| format!("Invalid emotion import map entry: `{import:?}`."))
         }
+
+        let ops = oxc::transformer::EmotionOptions::default();
+        let auto_label = match options.auto_label.as_deref() {
+            Some("dev-only") => oxc::transformer::EmotionAutoLabel::DevOnly,
+            Some("always") => oxc::transformer::EmotionAutoLabel::Always,
+            Some("never") => oxc::transformer::EmotionAutoLabel::Never,
+            Some(s) => return Err(format!("Invalid emotion autoLabel option: `{s}`.")),
+            None => ops.auto_label,
+        };
+        let import_map = options
+            .import_map
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(source, exports)| {
+                let exports = exports
+                    .into_iter()
+                    .map(|(name, entry)| {
+                        let entry = oxc::transformer::EmotionImportMapEntry {
+                            canonical_import: import_pair(entry.canonical_import)?,
+                            styled_base_import: entry
+                                .styled_base_import
+                                .map(import_pair)
+                                .transpose()?,
+                        };
+                        Ok((name, entry))
+                    })
+                    .collect::<Result<_, String>>()?;
+                Ok((source, exports))
+            })
+            .collect::<Result<_, String>>()?;
+        Ok(oxc::transformer::EmotionOptions {
+            source_map: options.source_map.unwrap_or(ops.source_map),
+            auto_label,
+            label_format: options.label_format.unwrap_or(ops.label_format),
+            css_prop_optimization: options
+                .css_prop_optimization
+                .unwrap_or(ops.css_prop_optimization),
+            import_map,
+        })
     }
 }
 
@@ -512,9 +793,10 @@ pub struct JsxOptions {
     ///
     /// - 'automatic' - auto-import the correct JSX factories
     /// - 'classic' - no auto-import
+    /// - 'vue' - transform to Vue 3 `createVNode` calls
     ///
     /// @default 'automatic'
-    #[napi(ts_type = "'classic' | 'automatic'")]
+    #[napi(ts_type = "'classic' | 'automatic' | 'vue'")]
     pub runtime: Option<String>,
 
     /// Emit development-specific information, such as `__source` and `__self`.
@@ -585,6 +867,13 @@ pub struct JsxOptions {
     ///
     /// @default false
     pub refresh: Option<Either<bool, ReactRefreshOptions>>,
+
+    /// Options of Vue JSX.
+    ///
+    /// Only used for `vue` {@link runtime}.
+    ///
+    /// @see {@link https://github.com/vuejs/babel-plugin-jsx#options}
+    pub vue: Option<VueJsxOptions>,
 }
 
 impl From<JsxOptions> for oxc::transformer::JsxOptions {
@@ -593,6 +882,7 @@ impl From<JsxOptions> for oxc::transformer::JsxOptions {
         oxc::transformer::JsxOptions {
             runtime: match options.runtime.as_deref() {
                 Some("classic") => JsxRuntime::Classic,
+                Some("vue") => JsxRuntime::Vue,
                 /* "automatic" */ _ => JsxRuntime::Automatic,
             },
             development: options.development.unwrap_or(ops.development),
@@ -607,6 +897,7 @@ impl From<JsxOptions> for oxc::transformer::JsxOptions {
                 Either::A(b) => b.then(oxc::transformer::ReactRefreshOptions::default),
                 Either::B(options) => Some(oxc::transformer::ReactRefreshOptions::from(options)),
             }),
+            vue: options.vue.map(oxc::transformer::VueJsxOptions::from).unwrap_or_default(),
             ..Default::default()
         }
     }
@@ -638,6 +929,47 @@ impl From<ReactRefreshOptions> for oxc::transformer::ReactRefreshOptions {
     }
 }
 
+#[napi(object)]
+pub struct VueJsxOptions {
+    /// Transform `on={{ click: handler }}` to `onClick: handler`.
+    ///
+    /// @default false
+    pub transform_on: Option<bool>,
+
+    /// Emit patch flags and dynamic prop names for Vue's optimized diffing.
+    ///
+    /// @default false
+    pub optimize: Option<bool>,
+
+    /// Merge `class`, `style`, `on*` and spread props with `mergeProps`.
+    ///
+    /// @default true
+    pub merge_props: Option<bool>,
+
+    /// Treat a single identifier or call expression child of a component as slots.
+    ///
+    /// @default true
+    pub enable_object_slots: Option<bool>,
+
+    /// Tags of custom elements, which are not resolved as components.
+    ///
+    /// @default []
+    pub custom_elements: Option<Vec<String>>,
+}
+
+impl From<VueJsxOptions> for oxc::transformer::VueJsxOptions {
+    fn from(options: VueJsxOptions) -> Self {
+        let ops = oxc::transformer::VueJsxOptions::default();
+        oxc::transformer::VueJsxOptions {
+            transform_on: options.transform_on.unwrap_or(ops.transform_on),
+            optimize: options.optimize.unwrap_or(ops.optimize),
+            merge_props: options.merge_props.unwrap_or(ops.merge_props),
+            enable_object_slots: options.enable_object_slots.unwrap_or(ops.enable_object_slots),
+            custom_elements: options.custom_elements.unwrap_or(ops.custom_elements),
+        }
+    }
+}
+
 #[napi(object)]
 pub struct ArrowFunctionsOptions {
     /// This option enables the following:
@@ -720,6 +1052,7 @@ struct Compiler {
     isolated_declaration_options: Option<oxc::isolated_declarations::IsolatedDeclarationsOptions>,
 
     sourcemap: bool,
+    input_source_map: Option<oxc_sourcemap::SourceMap>,
 
     printed: String,
     printed_sourcemap: Option<SourceMap>,
@@ -731,11 +1064,13 @@ struct Compiler {
     inject: Option<InjectGlobalVariablesConfig>,
 
     helpers_used: FxHashMap<String, String>,
+    deps: Vec<String>,
+    dynamic_deps: Vec<String>,
     errors: Vec<OxcDiagnostic>,
 }
 
 impl Compiler {
-    fn new(options: Option<TransformOptions>) -> Result<Self, Vec<OxcDiagnostic>> {
+    fn new(filename: &Path, options: Option<TransformOptions>) -> Result<Self, Vec<OxcDiagnostic>> {
         let mut options = options;
 
         let isolated_declaration_options = options
@@ -746,6 +1081,13 @@ impl Compiler {
 
         let sourcemap = options.as_ref().and_then(|o| o.sourcemap).unwrap_or_default();
 
+        let input_source_map = options
+            .as_mut()
+            .and_then(|options| options.input_source_map.take())
+            .map(get_input_source_map)
+            .transpose()
+            .map_err(|err| vec![OxcDiagnostic::error(err)])?;
+
         let define = options
             .as_mut()
             .and_then(|options| options.define.take())
@@ -781,16 +1123,28 @@ impl Compiler {
             .transpose()?
             .map(InjectGlobalVariablesConfig::new);
 
-        let transform_options = match options {
-            Some(options) => oxc::transformer::TransformOptions::try_from(options)
-                .map_err(|err| vec![OxcDiagnostic::error(err)])?,
-            None => oxc::transformer::TransformOptions::default(),
+        let mut errors = vec![];
+        let babel_config = options.as_mut().and_then(|options| match options.babel_config.take() {
+            Some(Either::A(true)) => Some(None),
+            Some(Either::B(config_file)) => Some(Some(PathBuf::from(config_file))),
+            Some(Either::A(false)) | None => None,
+        });
+        let transform_options = if let Some(config_file) = babel_config {
+            let cwd = options.as_ref().and_then(|options| options.cwd.as_deref()).unwrap_or(".");
+            load_babel_config(Path::new(cwd), filename, config_file, &mut errors)?
+        } else {
+            match options {
+                Some(options) => oxc::transformer::TransformOptions::try_from(options)
+                    .map_err(|err| vec![OxcDiagnostic::error(err)])?,
+                None => oxc::transformer::TransformOptions::default(),
+            }
         };
 
         Ok(Self {
             transform_options,
             isolated_declaration_options,
             sourcemap,
+            input_source_map,
             printed: String::default(),
             printed_sourcemap: None,
             declaration: None,
@@ -798,11 +1152,39 @@ impl Compiler {
             define,
             inject,
             helpers_used: FxHashMap::default(),
-            errors: vec![],
+            deps: vec![],
+            dynamic_deps: vec![],
+            errors,
         })
     }
 }
 
+/// Load transform options from Babel config files.
+///
+/// Unsupported plugins and presets are pushed to `warnings`.
+/// Files which are ignored by the config are transformed with the default options.
+fn load_babel_config(
+    cwd: &Path,
+    filename: &Path,
+    config_file: Option<PathBuf>,
+    warnings: &mut Vec<OxcDiagnostic>,
+) -> Result<oxc::transformer::TransformOptions, Vec<OxcDiagnostic>> {
+    let config = oxc::transformer::BabelConfig::load(&oxc::transformer::BabelConfigLoadOptions {
+        cwd: cwd.to_path_buf(),
+        filename: filename.to_path_buf(),
+        config_file,
+        env_name: None,
+    })
+    .map_err(|errors| errors.into_iter().map(OxcDiagnostic::error).collect::<Vec<_>>())?;
+    warnings.extend(config.unsupported.iter().map(|name| {
+        OxcDiagnostic::warn(format!("Babel plugin or preset `{name}` is not supported."))
+    }));
+    if config.ignored {
+        return Ok(oxc::transformer::TransformOptions::default());
+    }
+    Ok(config.options)
+}
+
 impl CompilerInterface for Compiler {
     fn handle_errors(&mut self, errors: Vec<OxcDiagnostic>) {
         self.errors.extend(errors);
@@ -812,6 +1194,13 @@ impl CompilerInterface for Compiler {
         self.sourcemap
     }
 
+    fn codegen_options(&self) -> Option<CodegenOptions> {
+        Some(CodegenOptions {
+            input_source_map: self.input_source_map.clone(),
+            ..CodegenOptions::default()
+        })
+    }
+
     fn transform_options(&self) -> Option<&oxc::transformer::TransformOptions> {
         Some(&self.transform_options)
     }
@@ -851,6 +1240,8 @@ impl CompilerInterface for Compiler {
             .drain()
             .map(|(helper, source)| (helper.name().to_string(), source))
             .collect();
+        self.deps = transformer_return.deps.drain().collect();
+        self.dynamic_deps = transformer_return.dynamic_deps.drain().collect();
         ControlFlow::Continue(())
     }
 }
@@ -880,7 +1271,7 @@ pub fn transform(
         options.as_ref().and_then(|options| options.source_type.as_deref()),
     );
 
-    let mut compiler = match Compiler::new(options) {
+    let mut compiler = match Compiler::new(source_path, options) {
         Ok(compiler) => compiler,
         Err(errors) => {
             return TransformResult {
@@ -898,6 +1289,8 @@ pub fn transform(
         declaration: compiler.declaration,
         declaration_map: compiler.declaration_map,
         helpers_used: compiler.helpers_used,
+        deps: compiler.deps,
+        dynamic_deps: compiler.dynamic_deps,
         errors: OxcError::from_diagnostics(&filename, &source_text, compiler.errors),
     }
 }
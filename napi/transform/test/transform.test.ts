@@ -1,3 +1,6 @@
+import fs from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
 import { Worker } from 'node:worker_threads';
 import { describe, expect, it, test } from 'vitest';
 
@@ -45,6 +48,17 @@ describe('simple', () => {
       version: 3,
     });
   });
+
+  it('uses the `inputSourceMap` option', () => {
+    const first = transform('test.ts', code, { sourcemap: true });
+    const ret = transform('test.js', first.code, {
+      sourcemap: true,
+      inputSourceMap: first.map,
+    });
+    expect(ret.code).toEqual('export class A {}\n');
+    expect(ret.map?.sources).toStrictEqual(['test.ts']);
+    expect(ret.map?.sourcesContent).toStrictEqual(['export class A<T> {}']);
+  });
 });
 
 describe('transform', () => {
@@ -416,6 +430,31 @@ describe('typescript', () => {
         "
       `);
     });
+
+    test('rewritePaths', () => {
+      const code = `
+        import { a } from '@/utils/a';
+        export * from '~lib';
+        const b = import('@/b');
+      `;
+      const ret = transform('src/pages/index.ts', code, {
+        cwd: '/project',
+        typescript: {
+          rewritePaths: {
+            paths: {
+              '@/*': ['./src/*'],
+              '~lib': ['./lib/index.ts'],
+            },
+          },
+        },
+      });
+      expect(ret.code).toMatchInlineSnapshot(`
+        "import { a } from "../utils/a";
+        export * from "../../lib/index.ts";
+        const b = import("../b");
+        "
+      `);
+    });
   });
 });
 
@@ -445,3 +484,20 @@ describe('styled-components', () => {
 		`);
   });
 });
+
+describe('babelConfig', () => {
+  test('loads babel.config.json', () => {
+    const cwd = fs.mkdtempSync(path.join(os.tmpdir(), 'oxc-babel-config-'));
+    fs.writeFileSync(
+      path.join(cwd, 'babel.config.json'),
+      JSON.stringify({
+        plugins: ['@babel/plugin-transform-exponentiation-operator', 'babel-plugin-macros'],
+      }),
+    );
+    const ret = transform('test.js', 'a ** b', { cwd, babelConfig: true });
+    expect(ret.code).toEqual('Math.pow(a, b);\n');
+    expect(ret.errors.map((error) => [error.severity, error.message])).toStrictEqual([
+      ['Warning', 'Babel plugin or preset `macros` is not supported.'],
+    ]);
+  });
+});
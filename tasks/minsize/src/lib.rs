@@ -14,8 +14,12 @@ use pico_args::Arguments;
 use rustc_hash::FxHashMap;
 
 use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
 use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_minifier::{CompressOptions, MangleOptions, Minifier, MinifierOptions};
+use oxc_minifier::{
+    CompressOptions, Compressor, CompressorPass, CompressorStats, MangleOptions, Minifier,
+    MinifierOptions, PeepholeOptimization,
+};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
@@ -31,6 +35,8 @@ fn test() {
 #[derive(Debug, Clone, Copy)]
 struct Options {
     compress_only: bool,
+    /// Print the changes made by each compressor pass instead of the sizes.
+    stats: bool,
 }
 
 /// # Panics
@@ -38,12 +44,20 @@ struct Options {
 pub fn run() -> Result<(), io::Error> {
     let mut args = Arguments::from_env();
 
-    let options = Options { compress_only: args.contains("--compress-only") };
+    let options = Options {
+        compress_only: args.contains("--compress-only"),
+        stats: args.contains("--stats"),
+    };
 
     let marker = args.free_from_str().unwrap_or_else(|_| "default".to_string());
 
     let files = TestFiles::minifier();
 
+    if options.stats {
+        print_stats(&files);
+        return Ok(());
+    }
+
     let path = project_root().join("tasks/minsize/minsize.snap");
 
     // Data copied from https://github.com/privatenumber/minification-benchmarks
@@ -152,16 +166,25 @@ fn minify_twice(file: &TestFile, options: Options) -> String {
     code2
 }
 
-fn minify(source_text: &str, source_type: SourceType, options: Options) -> String {
-    let allocator = Allocator::default();
-    let ret = Parser::new(&allocator, source_text, source_type).parse();
+fn parse_and_replace_defines<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+) -> Program<'a> {
+    let ret = Parser::new(allocator, source_text, source_type).parse();
     let mut program = ret.program;
     let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
     let _ = ReplaceGlobalDefines::new(
-        &allocator,
+        allocator,
         ReplaceGlobalDefinesConfig::new(&[("process.env.NODE_ENV", "'development'")]).unwrap(),
     )
     .build(scoping, &mut program);
+    program
+}
+
+fn minify(source_text: &str, source_type: SourceType, options: Options) -> String {
+    let allocator = Allocator::default();
+    let mut program = parse_and_replace_defines(&allocator, source_text, source_type);
     let ret = Minifier::new(MinifierOptions {
         mangle: (!options.compress_only).then(MangleOptions::default),
         compress: Some(CompressOptions::default()),
@@ -174,6 +197,92 @@ fn minify(source_text: &str, source_type: SourceType, options: Options) -> Strin
         .code
 }
 
+fn print_stats(files: &TestFiles) {
+    let mut out = String::new();
+    let mut total: Vec<(CompressorPass, i64, i64)> = vec![];
+    let mut total_optimizations: Vec<(PeepholeOptimization, i64, i64)> = vec![];
+    for file in files.files() {
+        let source_type = SourceType::from_path(&file.file_name).unwrap();
+        let allocator = Allocator::default();
+        let mut program = parse_and_replace_defines(&allocator, &file.source_text, source_type);
+        let stats =
+            Compressor::new(&allocator).build_with_stats(&mut program, CompressOptions::default());
+        writeln!(
+            out,
+            "{}: {} nodes, {} -> {} nodes, {}",
+            file.file_name,
+            stats.original_nodes,
+            format_size(stats.original_bytes, DECIMAL),
+            stats.nodes(),
+            format_size(stats.bytes(), DECIMAL),
+        )
+        .unwrap();
+        write_pass_stats(&mut out, &stats);
+        for pass in &stats.passes {
+            match total.iter_mut().find(|(p, _, _)| *p == pass.pass) {
+                Some((_, nodes, bytes)) => {
+                    *nodes += pass.nodes_removed;
+                    *bytes += pass.bytes_saved;
+                }
+                None => total.push((pass.pass, pass.nodes_removed, pass.bytes_saved)),
+            }
+        }
+        for optimization in &stats.optimizations {
+            match total_optimizations.iter_mut().find(|(o, _, _)| *o == optimization.optimization) {
+                Some((_, nodes, bytes)) => {
+                    *nodes += optimization.nodes_removed;
+                    *bytes += optimization.bytes_saved;
+                }
+                None => total_optimizations.push((
+                    optimization.optimization,
+                    optimization.nodes_removed,
+                    optimization.bytes_saved,
+                )),
+            }
+        }
+        out.push('\n');
+    }
+    writeln!(out, "Total:").unwrap();
+    for (pass, nodes, bytes) in total {
+        writeln!(out, "  {:24} | {nodes:>13} | {bytes:>11}", pass.to_string()).unwrap();
+    }
+    writeln!(out, "Total peephole optimizations:").unwrap();
+    for (optimization, nodes, bytes) in total_optimizations {
+        writeln!(out, "  {:38} | {nodes:>13} | {bytes:>11}", optimization.to_string()).unwrap();
+    }
+    println!("{out}");
+}
+
+fn write_pass_stats(out: &mut String, stats: &CompressorStats) {
+    writeln!(out, "  {:24} | {:>13} | {:>11}", "Pass", "Nodes removed", "Bytes saved").unwrap();
+    for pass in &stats.passes {
+        writeln!(
+            out,
+            "  {:24} | {:>13} | {:>11}",
+            pass.pass.to_string(),
+            pass.nodes_removed,
+            pass.bytes_saved
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "  {:38} | {:>13} | {:>11}",
+        "Peephole optimization", "Nodes removed", "Bytes saved"
+    )
+    .unwrap();
+    for optimization in &stats.optimizations {
+        writeln!(
+            out,
+            "  {:38} | {:>13} | {:>11}",
+            optimization.optimization.to_string(),
+            optimization.nodes_removed,
+            optimization.bytes_saved
+        )
+        .unwrap();
+    }
+}
+
 fn gzip_size(s: &str) -> usize {
     let mut e = GzEncoder::new(Vec::new(), Compression::best());
     e.write_all(s.as_bytes()).unwrap();
@@ -61,12 +61,20 @@ pub const PLUGINS: &[&str] = &[
     "regexp",
     // Legacy decorators, tests almost ported from TypeScript
     "legacy-decorators",
+    // Standard decorators (2023-11)
+    "standard-decorators",
+    // AMD, UMD and SystemJS modules
+    "modules-amd",
+    "modules-umd",
+    "modules-systemjs",
     // Built-in third-party plugins
     "plugin-styled-components",
+    "plugin-emotion",
+    "plugin-graphql-tag",
+    "plugin-vue-jsx",
 ];
 
 pub const PLUGINS_NOT_SUPPORTED_YET: &[&str] = &[
-    "proposal-decorators",
     "transform-classes",
     "transform-destructuring",
     "transform-modules-commonjs",
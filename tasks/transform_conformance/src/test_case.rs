@@ -179,6 +179,11 @@ impl TestCase {
             return true;
         }
 
+        // Only 2023-11 version of standard decorators is supported
+        if options.plugins.proposal_decorators.as_ref().is_some_and(|o| o.version != "2023-11") {
+            return true;
+        }
+
         // Skip some Babel tests.
         if let Ok(path) = self.path.strip_prefix(packages_root()) {
             // babel skip test cases that in a directory starting with a dot
@@ -470,7 +475,7 @@ test("exec", () => {{
 
 fn get_babel_error(error: &str) -> String {
     match error {
-        "transform-react-jsx: unknown variant `invalidOption`, expected `classic` or `automatic`" => "Runtime must be either \"classic\" or \"automatic\".",
+        "transform-react-jsx: unknown variant `invalidOption`, expected one of `classic`, `automatic`, `vue`" => "Runtime must be either \"classic\" or \"automatic\".",
         "Duplicate __self prop found." => "Duplicate __self prop found. You are most likely using the deprecated transform-react-jsx-self Babel plugin. Both __source and __self are automatically set when using the automatic runtime. Please remove transform-react-jsx-source and transform-react-jsx-self from your Babel config.",
         "Duplicate __source prop found." => "Duplicate __source prop found. You are most likely using the deprecated transform-react-jsx-source Babel plugin. Both __source and __self are automatically set when using the automatic runtime. Please remove transform-react-jsx-source and transform-react-jsx-self from your Babel config.",
         "Expected `>` but found `/`" => "Unexpected token, expected \",\"",
@@ -0,0 +1,9 @@
+class Foo {
+  bar = "bar";
+  #baz = 1;
+  #qux;
+
+  method(other) {
+    return [this.#baz, this.#qux, other.#baz, #baz in other];
+  }
+}
@@ -0,0 +1,23 @@
+var _baz = /* @__PURE__ */ babelHelpers.classPrivateFieldLooseKey("baz");
+var _qux = /* @__PURE__ */ babelHelpers.classPrivateFieldLooseKey("qux");
+class Foo {
+  constructor() {
+    babelHelpers.defineProperty(this, "bar", "bar");
+    Object.defineProperty(this, _baz, {
+      writable: true,
+      value: 1
+    });
+    Object.defineProperty(this, _qux, {
+      writable: true,
+      value: void 0
+    });
+  }
+  method(other) {
+    return [
+      babelHelpers.classPrivateFieldLooseBase(this, _baz)[_baz],
+      babelHelpers.classPrivateFieldLooseBase(this, _qux)[_qux],
+      babelHelpers.classPrivateFieldLooseBase(other, _baz)[_baz],
+      Object.prototype.hasOwnProperty.call(babelHelpers.checkInRHS(other), _baz)
+    ];
+  }
+}
@@ -0,0 +1,26 @@
+var _Class, _qux, _quux;
+var _baz = /* @__PURE__ */ Symbol("baz");
+class Foo {
+  static method() {
+    return babelHelpers.classPrivateFieldLooseBase(Foo, _baz)[_baz];
+  }
+}
+babelHelpers.defineProperty(Foo, "bar", "bar");
+Object.defineProperty(Foo, _baz, {
+  writable: true,
+  value: 1
+});
+const Bar = (_qux = /* @__PURE__ */ Symbol("qux"), _quux = /* @__PURE__ */ Symbol("quux"), _Class = class {
+  constructor() {
+    Object.defineProperty(this, _quux, {
+      writable: true,
+      value: 3
+    });
+  }
+  static get() {
+    return [babelHelpers.classPrivateFieldLooseBase(Bar, _qux)[_qux], babelHelpers.classPrivateFieldLooseBase(new Bar(), _quux)[_quux]];
+  }
+}, Object.defineProperty(_Class, _qux, {
+  writable: true,
+  value: 2
+}), _Class);
@@ -0,0 +1,15 @@
+var _count = /* @__PURE__ */ Symbol("count");
+class Foo {
+  constructor() {
+    Object.defineProperty(this, _count, {
+      writable: true,
+      value: 0
+    });
+  }
+  increment(other) {
+    babelHelpers.classPrivateFieldLooseBase(this, _count)[_count]++;
+    babelHelpers.classPrivateFieldLooseBase(other, _count)[_count] += 1;
+    [babelHelpers.classPrivateFieldLooseBase(this, _count)[_count]] = [babelHelpers.classPrivateFieldLooseBase(other, _count)[_count]];
+    return babelHelpers.classPrivateFieldLooseBase(this, _count)[_count]?.toString();
+  }
+}
@@ -0,0 +1,17 @@
+var _baz = /* @__PURE__ */ new WeakMap();
+var _qux = /* @__PURE__ */ new WeakMap();
+class Foo {
+  constructor() {
+    this.bar = "bar";
+    babelHelpers.classPrivateFieldInitSpec(this, _baz, 1);
+    babelHelpers.classPrivateFieldInitSpec(this, _qux, void 0);
+  }
+  method(other) {
+    return [
+      babelHelpers.classPrivateFieldGet2(_baz, this),
+      babelHelpers.classPrivateFieldGet2(_qux, this),
+      babelHelpers.classPrivateFieldGet2(_baz, other),
+      _baz.has(babelHelpers.checkInRHS(other))
+    ];
+  }
+}
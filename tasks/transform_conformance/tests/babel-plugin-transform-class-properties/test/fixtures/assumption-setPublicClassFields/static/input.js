@@ -0,0 +1,16 @@
+class Foo {
+  static bar = "bar";
+  static #baz = 1;
+
+  static method() {
+    return Foo.#baz;
+  }
+}
+
+const Bar = class {
+  static #qux = 2;
+  #quux = 3;
+  static get() {
+    return [Bar.#qux, new Bar().#quux];
+  }
+};
@@ -0,0 +1,16 @@
+var _Class, _qux, _quux;
+class Foo {
+  static method() {
+    return _baz._;
+  }
+}
+Foo.bar = "bar";
+var _baz = { _: 1 };
+const Bar = (_quux = /* @__PURE__ */ new WeakMap(), _Class = class {
+  constructor() {
+    babelHelpers.classPrivateFieldInitSpec(this, _quux, 3);
+  }
+  static get() {
+    return [babelHelpers.assertClassBrand(_Class, Bar, _qux)._, babelHelpers.classPrivateFieldGet2(_quux, new Bar())];
+  }
+}, _qux = { _: 2 }, _Class);
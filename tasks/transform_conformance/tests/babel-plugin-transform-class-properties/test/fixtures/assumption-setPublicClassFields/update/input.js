@@ -0,0 +1,10 @@
+class Foo {
+  #count = 0;
+
+  increment(other) {
+    this.#count++;
+    other.#count += 1;
+    [this.#count] = [other.#count];
+    return this.#count?.toString();
+  }
+}
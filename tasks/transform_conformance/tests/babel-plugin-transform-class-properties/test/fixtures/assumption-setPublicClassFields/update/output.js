@@ -0,0 +1,13 @@
+var _count = /* @__PURE__ */ new WeakMap();
+class Foo {
+  constructor() {
+    babelHelpers.classPrivateFieldInitSpec(this, _count, 0);
+  }
+  increment(other) {
+    var _this$count, _this$count2;
+    babelHelpers.classPrivateFieldSet2(_count, this, (_this$count = babelHelpers.classPrivateFieldGet2(_count, this), _this$count2 = _this$count++, _this$count)), _this$count2;
+    babelHelpers.classPrivateFieldSet2(_count, other, babelHelpers.classPrivateFieldGet2(_count, other) + 1);
+    [babelHelpers.toSetter(babelHelpers.classPrivateFieldSet2, [_count, this])._] = [babelHelpers.classPrivateFieldGet2(_count, other)];
+    return babelHelpers.classPrivateFieldGet2(_count, this)?.toString();
+  }
+}
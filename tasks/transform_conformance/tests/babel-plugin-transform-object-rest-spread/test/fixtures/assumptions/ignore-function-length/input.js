@@ -0,0 +1,7 @@
+function a({ ...x }, y) {}
+function b({ ...x }, { ...y }) {}
+function c({ ...x }, y = x) {}
+function d(u, { ...x } = {}, y = x, z, ...rest) {}
+function e({ ...x }, { y } = x, z) {}
+function f({ ...x }, ...[y = x]) {}
+const g = ({ ...x }, y = x) => [x, y];
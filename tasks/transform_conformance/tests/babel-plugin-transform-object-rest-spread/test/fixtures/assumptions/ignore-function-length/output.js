@@ -0,0 +1,28 @@
+function a(_ref, y) {
+	let x = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref), _ref));
+}
+function b(_ref2, _ref3) {
+	let x = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref2), _ref2));
+	let y = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref3), _ref3));
+}
+function c(_ref4, _ref5) {
+	let x = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref4), _ref4));
+	let y = _ref5 === void 0 ? x : _ref5;
+}
+function d(u, _ref6 = {}, _ref7, z, ...rest) {
+	let x = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref6), _ref6));
+	let y = _ref7 === void 0 ? x : _ref7;
+}
+function e(_ref8, _ref9, z) {
+	let x = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref8), _ref8));
+	let { y } = _ref9 === void 0 ? x : _ref9;
+}
+function f(_ref10, ..._ref11) {
+	let x = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref10), _ref10));
+	let [y = x] = _ref11;
+}
+const g = (_ref12, _ref13) => {
+	let x = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref12), _ref12));
+	let y = _ref13 === void 0 ? x : _ref13;
+	return [x, y];
+};
@@ -0,0 +1,3 @@
+let { a, ...b } = c;
+({ d, ...e } = f);
+function foo({ g, ...h }) {}
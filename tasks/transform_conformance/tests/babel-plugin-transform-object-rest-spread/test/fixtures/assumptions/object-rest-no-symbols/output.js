@@ -0,0 +1,7 @@
+const _excluded = ["g"];
+let { a } = c, b = babelHelpers.objectWithoutPropertiesLoose(c, ["a"]);
+var _f;
+_f = f, {d} = _f, e = babelHelpers.objectWithoutPropertiesLoose(_f, ["d"]), _f;
+function foo(_ref) {
+	let { g } = _ref, h = babelHelpers.objectWithoutPropertiesLoose(_ref, _excluded);
+}
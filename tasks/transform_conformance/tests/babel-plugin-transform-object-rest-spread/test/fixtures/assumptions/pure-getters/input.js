@@ -0,0 +1,14 @@
+const a = { x, ...b, y, ...c, z };
+
+let { d, e, ...f } = g;
+console.log(e, f);
+
+let { h, ...i } = j;
+console.log(i);
+
+export let { k, ...l } = m;
+
+function foo() {
+  const { n, [o]: p, ...q } = r;
+  return q;
+}
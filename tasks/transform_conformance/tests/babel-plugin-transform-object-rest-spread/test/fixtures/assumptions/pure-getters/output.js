@@ -0,0 +1,10 @@
+const a = babelHelpers.objectSpread2({ x }, b, { y }, c, { z });
+let { e } = g, f = babelHelpers.objectWithoutProperties(g, ["d", "e"]);
+console.log(e, f);
+let i = babelHelpers.objectWithoutProperties(j, ["h"]);
+console.log(i);
+export let { k } = m, l = babelHelpers.objectWithoutProperties(m, ["k"]);
+function foo() {
+	const _o = o, q = babelHelpers.objectWithoutProperties(r, ["n", _o].map(babelHelpers.toPropertyKey));
+	return q;
+}
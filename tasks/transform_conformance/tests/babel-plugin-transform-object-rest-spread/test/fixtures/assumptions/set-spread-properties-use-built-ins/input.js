@@ -0,0 +1,3 @@
+const a = { ...b };
+const c = { x, ...d, y, ...e, z };
+let { ...f } = g;
@@ -0,0 +1,3 @@
+const a = Object.assign({}, b);
+const c = Object.assign(Object.assign(Object.assign({ x }, d), {}, { y }, e), {}, { z });
+let f = Object.assign({}, (babelHelpers.objectDestructuringEmpty(g), g));
@@ -0,0 +1,3 @@
+const a = babelHelpers.extends({}, b);
+const c = babelHelpers.extends(babelHelpers.extends(babelHelpers.extends({ x }, d), {}, { y }, e), {}, { z });
+let f = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(g), g));
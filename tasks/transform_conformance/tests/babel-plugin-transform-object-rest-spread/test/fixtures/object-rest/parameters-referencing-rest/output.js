@@ -0,0 +1,31 @@
+function a(_ref, y) {
+	let x = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref), _ref));
+}
+function b(_ref2, _ref3) {
+	let x = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref2), _ref2));
+	let y = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref3), _ref3));
+}
+function c(_ref4, ..._args) {
+	let x = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref4), _ref4));
+	let y = _args[0] === void 0 ? x : _args[0];
+}
+function d(u, _ref5 = {}, ..._args2) {
+	let x = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref5), _ref5));
+	let y = _args2[0] === void 0 ? x : _args2[0];
+	let z = _args2[1];
+	let rest = _args2.slice(2);
+}
+function e(_ref6, ..._args3) {
+	let x = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref6), _ref6));
+	let { y } = _args3[0] === void 0 ? x : _args3[0];
+	let z = _args3[1];
+}
+function f(_ref7, ..._ref8) {
+	let x = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref7), _ref7));
+	let [y = x] = _ref8;
+}
+const g = (_ref9, ..._args4) => {
+	let x = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_ref9), _ref9));
+	let y = _args4[0] === void 0 ? x : _args4[0];
+	return [x, y];
+};
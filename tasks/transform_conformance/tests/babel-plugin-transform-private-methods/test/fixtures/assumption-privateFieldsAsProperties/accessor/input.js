@@ -0,0 +1,16 @@
+class Foo {
+  get #getter() {
+    return 1;
+  }
+
+  get #value() {
+    return this.#getter;
+  }
+
+  set #value(v) {}
+
+  update() {
+    this.#value = this.#getter;
+    this.#value++;
+  }
+}
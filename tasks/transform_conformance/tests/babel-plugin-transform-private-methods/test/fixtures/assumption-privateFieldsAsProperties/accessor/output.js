@@ -0,0 +1,25 @@
+var _getter = /* @__PURE__ */ babelHelpers.classPrivateFieldLooseKey("getter");
+var _value = /* @__PURE__ */ babelHelpers.classPrivateFieldLooseKey("value");
+class Foo {
+  constructor() {
+    Object.defineProperty(this, _value, {
+      get: _get_value,
+      set: _set_value
+    });
+    Object.defineProperty(this, _getter, {
+      get: _get_getter,
+      set: void 0
+    });
+  }
+  update() {
+    babelHelpers.classPrivateFieldLooseBase(this, _value)[_value] = babelHelpers.classPrivateFieldLooseBase(this, _getter)[_getter];
+    babelHelpers.classPrivateFieldLooseBase(this, _value)[_value]++;
+  }
+}
+function _get_getter() {
+  return 1;
+}
+function _get_value() {
+  return babelHelpers.classPrivateFieldLooseBase(this, _getter)[_getter];
+}
+function _set_value(v) {}
@@ -0,0 +1,17 @@
+var _bar = /* @__PURE__ */ babelHelpers.classPrivateFieldLooseKey("bar");
+var _privateMethod = /* @__PURE__ */ babelHelpers.classPrivateFieldLooseKey("privateMethod");
+class Foo {
+  constructor() {
+    Object.defineProperty(this, _privateMethod, { value: _privateMethod2 });
+    Object.defineProperty(this, _bar, {
+      writable: true,
+      value: 1
+    });
+  }
+  publicMethod() {
+    return babelHelpers.classPrivateFieldLooseBase(this, _privateMethod)[_privateMethod]();
+  }
+}
+function _privateMethod2() {
+  return babelHelpers.classPrivateFieldLooseBase(this, _bar)[_bar];
+}
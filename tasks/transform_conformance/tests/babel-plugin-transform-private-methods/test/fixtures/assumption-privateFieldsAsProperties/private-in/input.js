@@ -0,0 +1,9 @@
+class Foo {
+  #method() {}
+  get #getter() {}
+  static #staticMethod() {}
+
+  test(other) {
+    return [#method in other, #getter in other, #staticMethod in other];
+  }
+}
@@ -0,0 +1,23 @@
+var _method = /* @__PURE__ */ babelHelpers.classPrivateFieldLooseKey("method");
+var _getter = /* @__PURE__ */ babelHelpers.classPrivateFieldLooseKey("getter");
+var _staticMethod = /* @__PURE__ */ babelHelpers.classPrivateFieldLooseKey("staticMethod");
+class Foo {
+  constructor() {
+    Object.defineProperty(this, _getter, {
+      get: _get_getter,
+      set: void 0
+    });
+    Object.defineProperty(this, _method, { value: _method2 });
+  }
+  test(other) {
+    return [
+      Object.prototype.hasOwnProperty.call(babelHelpers.checkInRHS(other), _method),
+      Object.prototype.hasOwnProperty.call(babelHelpers.checkInRHS(other), _getter),
+      Object.prototype.hasOwnProperty.call(babelHelpers.checkInRHS(other), _staticMethod)
+    ];
+  }
+}
+function _method2() {}
+function _get_getter() {}
+function _staticMethod2() {}
+Object.defineProperty(Foo, _staticMethod, { value: _staticMethod2 });
@@ -0,0 +1,17 @@
+class Foo {
+  static #privateStaticMethod() {
+    return 1;
+  }
+
+  static publicStaticMethod() {
+    return Foo.#privateStaticMethod();
+  }
+}
+
+const Bar = class {
+  static #privateStaticMethod() {}
+
+  static publicStaticMethod() {
+    return Bar.#privateStaticMethod();
+  }
+};
@@ -0,0 +1,17 @@
+var _Class, _privateStaticMethod3;
+var _privateStaticMethod = /* @__PURE__ */ babelHelpers.classPrivateFieldLooseKey("privateStaticMethod");
+class Foo {
+  static publicStaticMethod() {
+    return babelHelpers.classPrivateFieldLooseBase(Foo, _privateStaticMethod)[_privateStaticMethod]();
+  }
+}
+function _privateStaticMethod2() {
+  return 1;
+}
+Object.defineProperty(Foo, _privateStaticMethod, { value: _privateStaticMethod2 });
+const Bar = (_privateStaticMethod3 = /* @__PURE__ */ babelHelpers.classPrivateFieldLooseKey("privateStaticMethod"), _Class = class {
+  static publicStaticMethod() {
+    return babelHelpers.classPrivateFieldLooseBase(Bar, _privateStaticMethod3)[_privateStaticMethod3]();
+  }
+}, Object.defineProperty(_Class, _privateStaticMethod3, { value: _privateStaticMethod4 }), _Class);
+function _privateStaticMethod4() {}
@@ -0,0 +1,11 @@
+class Foo {
+  #bar = 1;
+
+  #privateMethod() {
+    return this.#bar;
+  }
+
+  publicMethod() {
+    return this.#privateMethod();
+  }
+}
@@ -0,0 +1,17 @@
+var _bar = /* @__PURE__ */ Symbol("bar");
+var _privateMethod = /* @__PURE__ */ Symbol("privateMethod");
+class Foo {
+  constructor() {
+    Object.defineProperty(this, _privateMethod, { value: _privateMethod2 });
+    Object.defineProperty(this, _bar, {
+      writable: true,
+      value: 1
+    });
+  }
+  publicMethod() {
+    return babelHelpers.classPrivateFieldLooseBase(this, _privateMethod)[_privateMethod]();
+  }
+}
+function _privateMethod2() {
+  return babelHelpers.classPrivateFieldLooseBase(this, _bar)[_bar];
+}
@@ -0,0 +1,12 @@
+import foo from "foo";
+this.foo = foo;
+const arrow = () => this;
+function fn() {
+  return this;
+}
+class Foo {
+  bar = this;
+  method() {
+    return this;
+  }
+}
@@ -0,0 +1,16 @@
+define(["foo"], function(_foo) {
+	"use strict";
+	_foo = babelHelpers.interopRequireDefault(_foo);
+	this.foo = _foo.default;
+	const arrow = () => this;
+	function fn() {
+		return this;
+	}
+	class Foo {
+		bar = this;
+		method() {
+			return this;
+		}
+	}
+});
+
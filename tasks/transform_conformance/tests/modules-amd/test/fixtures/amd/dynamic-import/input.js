@@ -0,0 +1,5 @@
+export async function load(name) {
+  const a = await import("./a.js");
+  const b = await import(`./locale/${name}.js`);
+  return [a, b];
+}
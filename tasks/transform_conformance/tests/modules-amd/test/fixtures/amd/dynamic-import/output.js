@@ -0,0 +1,11 @@
+define(["require", "exports"], function(_require, _exports) {
+	"use strict";
+	Object.defineProperty(_exports, "__esModule", { value: true });
+	_exports.load = load;
+	async function load(name) {
+		const a = await new Promise((_resolve, _reject) => _require(["./a.js"], (_imported) => _resolve(babelHelpers.interopRequireWildcard(_imported)), _reject));
+		const b = await new Promise((_resolve2, _reject2) => _require([`${`./locale/${name}.js`}`], (_imported2) => _resolve2(babelHelpers.interopRequireWildcard(_imported2)), _reject2));
+		return [a, b];
+	}
+});
+
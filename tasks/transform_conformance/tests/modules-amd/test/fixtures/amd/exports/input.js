@@ -0,0 +1,13 @@
+export let count = 0;
+export var a = 1, b;
+export const { c, d: [e] } = obj;
+export function increment() {
+  count++;
+  return ++count;
+}
+export class Foo {}
+let local = 1;
+export { local, local as renamed };
+local = 2;
+count += 1;
+export default count * 2;
@@ -0,0 +1,27 @@
+define(["exports"], function(_exports) {
+	"use strict";
+	Object.defineProperty(_exports, "__esModule", { value: true });
+	_exports.count = _exports.a = _exports.b = _exports.c = _exports.e = _exports.Foo = _exports.local = _exports.renamed = _exports.default = void 0;
+	_exports.increment = increment;
+	var _count;
+	let count = 0;
+	_exports.count = count;
+	var a = 1, b;
+	_exports.a = a;
+	_exports.b = b;
+	const { c, d: [e] } = obj;
+	_exports.c = c;
+	_exports.e = e;
+	function increment() {
+		_count = count++, _exports.count = count, _count;
+		return _exports.count = ++count;
+	}
+	class Foo {}
+	_exports.Foo = Foo;
+	let local = 1;
+	_exports.local = _exports.renamed = local;
+	_exports.local = _exports.renamed = local = 2;
+	_exports.count = count += 1;
+	_exports.default = count * 2;
+});
+
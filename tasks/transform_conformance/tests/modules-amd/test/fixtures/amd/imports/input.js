@@ -0,0 +1,8 @@
+import "side-effect";
+import foo, { bar, baz as qux } from "foo";
+import * as ns from "./lib/name-space.js";
+
+foo(bar, qux);
+qux`template`;
+new ns.Foo({ bar, qux });
+console.log(typeof foo, ns.default);
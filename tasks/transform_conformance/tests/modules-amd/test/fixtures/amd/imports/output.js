@@ -0,0 +1,17 @@
+define([
+	"side-effect",
+	"foo",
+	"./lib/name-space.js"
+], function(_sideEffect, _foo, _nameSpace) {
+	"use strict";
+	_foo = babelHelpers.interopRequireDefault(_foo);
+	_nameSpace = babelHelpers.interopRequireWildcard(_nameSpace);
+	(0, _foo.default)(_foo.bar, _foo.baz);
+	(0, _foo.baz)`template`;
+	new _nameSpace.Foo({
+		bar: _foo.bar,
+		qux: _foo.baz
+	});
+	console.log(typeof _foo.default, _nameSpace.default);
+});
+
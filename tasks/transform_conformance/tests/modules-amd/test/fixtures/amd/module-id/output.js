@@ -0,0 +1,7 @@
+define("my-module", ["exports"], function(_exports) {
+	"use strict";
+	Object.defineProperty(_exports, "__esModule", { value: true });
+	_exports.default = _default;
+	function _default() {}
+});
+
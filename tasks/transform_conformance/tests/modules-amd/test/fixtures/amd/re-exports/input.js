@@ -0,0 +1,7 @@
+import { x } from "x";
+import * as all from "all";
+export { x, all };
+export { default, y as z } from "y";
+export * from "star";
+export * as ns from "ns";
+export const own = 1;
@@ -0,0 +1,47 @@
+define([
+	"exports",
+	"x",
+	"all",
+	"y",
+	"star",
+	"ns"
+], function(_exports, _x, _all, _y, _star, _ns) {
+	"use strict";
+	Object.defineProperty(_exports, "__esModule", { value: true });
+	_exports.own = void 0;
+	_all = babelHelpers.interopRequireWildcard(_all);
+	_y = babelHelpers.interopRequireDefault(_y);
+	_ns = babelHelpers.interopRequireWildcard(_ns);
+	Object.defineProperty(_exports, "x", {
+		enumerable: true,
+		get: function() {
+			return _x.x;
+		}
+	});
+	_exports.all = _all;
+	Object.defineProperty(_exports, "default", {
+		enumerable: true,
+		get: function() {
+			return _y.default;
+		}
+	});
+	Object.defineProperty(_exports, "z", {
+		enumerable: true,
+		get: function() {
+			return _y.y;
+		}
+	});
+	Object.keys(_star).forEach(function(_key) {
+		if (_key === "default" || _key === "__esModule" || _key in _exports) return;
+		Object.defineProperty(_exports, _key, {
+			enumerable: true,
+			get: function() {
+				return _star[_key];
+			}
+		});
+	});
+	_exports.ns = _ns;
+	const own = 1;
+	_exports.own = own;
+});
+
@@ -0,0 +1,6 @@
+export const self = this;
+this.foo = 1;
+const arrow = () => this;
+function fn() {
+  return this;
+}
@@ -0,0 +1,13 @@
+define(["exports"], function(_exports) {
+	"use strict";
+	Object.defineProperty(_exports, "__esModule", { value: true });
+	_exports.self = void 0;
+	const self = void 0;
+	_exports.self = self;
+	(void 0).foo = 1;
+	const arrow = () => void 0;
+	function fn() {
+		return this;
+	}
+});
+
@@ -0,0 +1,4 @@
+export * from "star";
+export * as ns from "ns";
+export { default as foo, bar } from "foo";
+export const own = 1;
@@ -0,0 +1,38 @@
+System.register([
+	"star",
+	"ns",
+	"foo"
+], function(_export, _context) {
+	"use strict";
+	var own;
+	var _exportNames = {
+		bar: true,
+		foo: true,
+		ns: true,
+		own: true
+	};
+	return {
+		setters: [
+			function(_star) {
+				var _exportObj = {};
+				for (var _key in _star) {
+					if (_key !== "default" && _key !== "__esModule" && !Object.prototype.hasOwnProperty.call(_exportNames, _key)) _exportObj[_key] = _star[_key];
+				}
+				_export(_exportObj);
+			},
+			function(_ns) {
+				_export({ ns: _ns });
+			},
+			function(_foo) {
+				_export({
+					foo: _foo.default,
+					bar: _foo.bar
+				});
+			}
+		],
+		execute: function() {
+			_export("own", own = 1);
+		}
+	};
+});
+
@@ -0,0 +1,11 @@
+export let count = 0;
+export const a = 1, { b, c: [d] } = obj;
+export function increment() {
+  count++;
+  return ++count;
+}
+export class Foo {}
+let local = 1;
+export { local as renamed };
+local = 2;
+export default count;
@@ -0,0 +1,21 @@
+System.register([], function(_export, _context) {
+	"use strict";
+	var count, a, b, d, Foo, local, _count;
+	function increment() {
+		_count = count++, _export("count", count), _count;
+		return _export("count", ++count);
+	}
+	_export("increment", increment);
+	return {
+		setters: [],
+		execute: function() {
+			_export("count", count = 0);
+			_export("a", a = 1), {b, c: [d]} = obj, _export("b", b), _export("d", d);
+			_export("Foo", Foo = class {});
+			_export("renamed", local = 1);
+			_export("renamed", local = 2);
+			_export("default", count);
+		}
+	};
+});
+
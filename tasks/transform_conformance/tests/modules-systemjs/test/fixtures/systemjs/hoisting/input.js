@@ -0,0 +1,12 @@
+import foo from "foo";
+export { fn };
+if (foo) {
+  var x = 1;
+}
+for (var i = 0; i < 10; i++) {}
+for (var key in foo) {}
+function fn() {
+  var local = this;
+  return [x, i, key, local];
+}
+this.foo = foo;
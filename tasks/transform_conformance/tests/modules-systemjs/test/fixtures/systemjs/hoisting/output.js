@@ -0,0 +1,28 @@
+System.register(["foo"], function(_export, _context) {
+	"use strict";
+	var foo, x, i, key;
+	function fn() {
+		var local = this;
+		return [
+			x,
+			i,
+			key,
+			local
+		];
+	}
+	_export("fn", fn);
+	return {
+		setters: [function(_foo) {
+			foo = _foo.default;
+		}],
+		execute: function() {
+			if (foo) {
+				x = 1;
+			}
+			for (i = 0; i < 10; i++) {}
+			for (key in foo) {}
+			(void 0).foo = foo;
+		}
+	};
+});
+
@@ -0,0 +1,4 @@
+export const url = import.meta.url;
+export function load() {
+  return import("./lazy.js");
+}
@@ -0,0 +1,15 @@
+System.register([], function(_export, _context) {
+	"use strict";
+	var url;
+	function load() {
+		return _context.import("./lazy.js");
+	}
+	_export("load", load);
+	return {
+		setters: [],
+		execute: function() {
+			_export("url", url = _context.meta.url);
+		}
+	};
+});
+
@@ -0,0 +1,4 @@
+import "side-effect";
+import foo, { bar as baz } from "foo";
+import * as ns from "ns";
+foo(baz, ns.qux);
@@ -0,0 +1,24 @@
+System.register([
+	"side-effect",
+	"foo",
+	"ns"
+], function(_export, _context) {
+	"use strict";
+	var foo, baz, ns;
+	return {
+		setters: [
+			function(_sideEffect) {},
+			function(_foo) {
+				foo = _foo.default;
+				baz = _foo.bar;
+			},
+			function(_ns) {
+				ns = _ns;
+			}
+		],
+		execute: function() {
+			foo(baz, ns.qux);
+		}
+	};
+});
+
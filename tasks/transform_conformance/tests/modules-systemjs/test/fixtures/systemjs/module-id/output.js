@@ -0,0 +1,10 @@
+System.register("my-module", [], function(_export, _context) {
+	"use strict";
+	function _default() {}
+	_export("default", _default);
+	return {
+		setters: [],
+		execute: function() {}
+	};
+});
+
@@ -0,0 +1,2 @@
+import { fetchConfig } from "./config.js";
+export const config = await fetchConfig();
@@ -0,0 +1,13 @@
+System.register(["./config.js"], function(_export, _context) {
+	"use strict";
+	var fetchConfig, config;
+	return {
+		setters: [function(_config) {
+			fetchConfig = _config.fetchConfig;
+		}],
+		execute: async function() {
+			_export("config", config = await fetchConfig());
+		}
+	};
+});
+
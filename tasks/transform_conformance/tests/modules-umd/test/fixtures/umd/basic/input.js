@@ -0,0 +1,4 @@
+import foo, { bar } from "foo";
+import * as utils from "./utils/index.js";
+export const value = foo(bar);
+export default utils;
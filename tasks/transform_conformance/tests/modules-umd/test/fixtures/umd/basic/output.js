@@ -0,0 +1,25 @@
+(function(global, factory) {
+	if (typeof define === "function" && define.amd) {
+		define([
+			"exports",
+			"foo",
+			"./utils/index.js"
+		], factory);
+	} else if (typeof exports !== "undefined") {
+		factory(exports, require("foo"), require("./utils/index.js"));
+	} else {
+		var mod = { exports: {} };
+		factory(mod.exports, global.foo, global.index);
+		global.input = mod.exports;
+	}
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_exports, _foo, _index) {
+	"use strict";
+	Object.defineProperty(_exports, "__esModule", { value: true });
+	_exports.value = _exports.default = void 0;
+	_foo = babelHelpers.interopRequireDefault(_foo);
+	_index = babelHelpers.interopRequireWildcard(_index);
+	const value = (0, _foo.default)(_foo.bar);
+	_exports.value = value;
+	_exports.default = _index;
+});
+
@@ -0,0 +1,4 @@
+import $ from "jquery";
+import { Component } from "@my-org/ui";
+import helper from "./helper.js";
+export default [$, Component, helper];
@@ -0,0 +1,29 @@
+(function(global, factory) {
+	if (typeof define === "function" && define.amd) {
+		define("my-lib", [
+			"exports",
+			"jquery",
+			"@my-org/ui",
+			"./helper.js"
+		], factory);
+	} else if (typeof exports !== "undefined") {
+		factory(exports, require("jquery"), require("@my-org/ui"), require("./helper.js"));
+	} else {
+		var mod = { exports: {} };
+		factory(mod.exports, global.jquery, global.MyOrg.UI, global.helperJs);
+		global.MyOrg = global.MyOrg || {};
+		global.MyOrg.MyLib = mod.exports;
+	}
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_exports, _jquery, _ui, _helper) {
+	"use strict";
+	Object.defineProperty(_exports, "__esModule", { value: true });
+	_exports.default = void 0;
+	_jquery = babelHelpers.interopRequireDefault(_jquery);
+	_helper = babelHelpers.interopRequireDefault(_helper);
+	_exports.default = [
+		_jquery.default,
+		_ui.Component,
+		_helper.default
+	];
+});
+
@@ -0,0 +1,19 @@
+(function(global, factory) {
+	if (typeof define === "function" && define.amd) {
+		define(["exports"], factory);
+	} else if (typeof exports !== "undefined") {
+		factory(exports);
+	} else {
+		var mod = { exports: {} };
+		factory(mod.exports);
+		global.MyOrg = global.MyOrg || {};
+		global.MyOrg.Utils = global.MyOrg.Utils || {};
+		global.MyOrg.Utils.hello = mod.exports;
+	}
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_exports) {
+	"use strict";
+	Object.defineProperty(_exports, "__esModule", { value: true });
+	_exports.hello = hello;
+	function hello() {}
+});
+
@@ -0,0 +1,4 @@
+import $ from "jquery";
+import _ from "lodash-es";
+import React from "./vendor/react.js";
+export default [$, _, React];
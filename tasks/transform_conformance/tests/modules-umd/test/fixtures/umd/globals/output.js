@@ -0,0 +1,29 @@
+(function(global, factory) {
+	if (typeof define === "function" && define.amd) {
+		define([
+			"exports",
+			"jquery",
+			"lodash-es",
+			"./vendor/react.js"
+		], factory);
+	} else if (typeof exports !== "undefined") {
+		factory(exports, require("jquery"), require("lodash-es"), require("./vendor/react.js"));
+	} else {
+		var mod = { exports: {} };
+		factory(mod.exports, global.$, global._, global.React);
+		global.input = mod.exports;
+	}
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_exports, _jquery, _lodashEs, _react) {
+	"use strict";
+	Object.defineProperty(_exports, "__esModule", { value: true });
+	_exports.default = void 0;
+	_jquery = babelHelpers.interopRequireDefault(_jquery);
+	_lodashEs = babelHelpers.interopRequireDefault(_lodashEs);
+	_react = babelHelpers.interopRequireDefault(_react);
+	_exports.default = [
+		_jquery.default,
+		_lodashEs.default,
+		_react.default
+	];
+});
+
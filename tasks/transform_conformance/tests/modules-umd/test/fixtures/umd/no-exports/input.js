@@ -0,0 +1,3 @@
+import "polyfill";
+import $ from "jquery";
+$(".app").show();
@@ -0,0 +1,16 @@
+(function(global, factory) {
+	if (typeof define === "function" && define.amd) {
+		define(["polyfill", "jquery"], factory);
+	} else if (typeof exports !== "undefined") {
+		factory(require("polyfill"), require("jquery"));
+	} else {
+		var mod = { exports: {} };
+		factory(global.polyfill, global.jquery);
+		global.input = mod.exports;
+	}
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_polyfill, _jquery) {
+	"use strict";
+	_jquery = babelHelpers.interopRequireDefault(_jquery);
+	(0, _jquery.default)(".app").show();
+});
+
@@ -0,0 +1,14 @@
+import { css } from "@emotion/react";
+import styled from "@emotion/styled";
+
+const button = css`
+  color: hotpink;
+`;
+
+const dynamic = css`
+  color: ${color};
+`;
+
+const Button = styled.button`
+  color: ${color};
+`;
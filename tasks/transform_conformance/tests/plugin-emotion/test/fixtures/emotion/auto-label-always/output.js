@@ -0,0 +1,20 @@
+import { css } from "@emotion/react";
+import styled from "@emotion/styled";
+function _EMOTION_STRINGIFIED_CSS_ERROR__() {
+	return "You have tried to stringify object returned from `css` function. It isn't supposed to be used directly (e.g. as value of the `className` prop), but rather handed to emotion so it can handle it (e.g. as value of `css` prop).";
+}
+import _styled from "@emotion/styled/base";
+const button = process.env.NODE_ENV === "production" ? {
+	name: "1jvmdft-button",
+	styles: "color:hotpink;label:button;"
+} : {
+	name: "1jvmdft-button",
+	styles: "color:hotpink;label:button;",
+	toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+};
+const dynamic = /* @__PURE__ */ css("color:", color, ";;label:dynamic;");
+const Button = /* @__PURE__ */ _styled("button", {
+	target: "e14qrnod0",
+	label: "Button"
+})("color:", color, ";");
+
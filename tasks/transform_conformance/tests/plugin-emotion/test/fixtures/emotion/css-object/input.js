@@ -0,0 +1,12 @@
+import { css } from "@emotion/react";
+
+const box = css({ fontSize: 12, lineHeight: 1.5, msTransition: "none", ":hover": { color: "red" } });
+
+const mixed = css({ color: "red" }, props.styles);
+
+const spread = css(...mixins);
+
+function Component() {
+  const inner = css({ margin: 0 });
+  return inner;
+}
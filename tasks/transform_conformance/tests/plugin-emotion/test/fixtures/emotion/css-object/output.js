@@ -0,0 +1,26 @@
+import { css } from "@emotion/react";
+function _EMOTION_STRINGIFIED_CSS_ERROR__() {
+	return "You have tried to stringify object returned from `css` function. It isn't supposed to be used directly (e.g. as value of the `className` prop), but rather handed to emotion so it can handle it (e.g. as value of `css` prop).";
+}
+const box = process.env.NODE_ENV === "production" ? {
+	name: "lvufax",
+	styles: "font-size:12px;line-height:1.5;-ms-transition:none;:hover{color:red;}"
+} : {
+	name: "1n23dnv-box",
+	styles: "font-size:12px;line-height:1.5;-ms-transition:none;:hover{color:red;};label:box;",
+	toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+};
+const mixed = /* @__PURE__ */ css("color:red;", props.styles, process.env.NODE_ENV === "production" ? "" : ";label:mixed;");
+const spread = /* @__PURE__ */ css(...mixins);
+function Component() {
+	const inner = process.env.NODE_ENV === "production" ? {
+		name: "ti75j2",
+		styles: "margin:0"
+	} : {
+		name: "1tklc1f-inner",
+		styles: "margin:0;label:inner;",
+		toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+	};
+	return inner;
+}
+
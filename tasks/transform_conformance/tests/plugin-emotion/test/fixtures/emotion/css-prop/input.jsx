@@ -0,0 +1,9 @@
+import { jsx } from "@emotion/react";
+
+function Button() {
+  return <button css={{ color: "hotpink", padding: 4 }} />;
+}
+
+const Link = () => <a css={[{ color: "blue" }, props.style]} />;
+
+const Plain = <div css={styles} className="foo" />;
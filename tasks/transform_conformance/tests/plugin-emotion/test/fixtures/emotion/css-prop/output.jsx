@@ -0,0 +1,18 @@
+import { jsx } from "@emotion/react";
+function _EMOTION_STRINGIFIED_CSS_ERROR__() {
+	return "You have tried to stringify object returned from `css` function. It isn't supposed to be used directly (e.g. as value of the `className` prop), but rather handed to emotion so it can handle it (e.g. as value of `css` prop).";
+}
+import { css as _css } from "@emotion/react";
+function Button() {
+	return <button css={process.env.NODE_ENV === "production" ? {
+		name: "gkqj29",
+		styles: "color:hotpink;padding:4px"
+	} : {
+		name: "1gg3rf0-Button",
+		styles: "color:hotpink;padding:4px;label:Button;",
+		toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+	}} />;
+}
+const Link = () => <a css={_css("color:blue;", props.style, process.env.NODE_ENV === "production" ? "" : ";label:Link;")} />;
+const Plain = <div css={styles} className="foo" />;
+
@@ -0,0 +1,19 @@
+import { css } from "@emotion/react";
+
+const button = css`
+  color: hotpink;
+  font-size: 12px;
+`;
+
+const dynamic = css`
+  color: ${color};
+  &:hover {
+    color: blue;
+  }
+`;
+
+const styles = {
+  primary: css`
+    background: red;
+  `,
+};
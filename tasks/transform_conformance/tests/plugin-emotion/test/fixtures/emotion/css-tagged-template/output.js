@@ -0,0 +1,22 @@
+import { css } from "@emotion/react";
+function _EMOTION_STRINGIFIED_CSS_ERROR__() {
+	return "You have tried to stringify object returned from `css` function. It isn't supposed to be used directly (e.g. as value of the `className` prop), but rather handed to emotion so it can handle it (e.g. as value of `css` prop).";
+}
+const button = process.env.NODE_ENV === "production" ? {
+	name: "1jo6qrz",
+	styles: "color:hotpink;font-size:12px"
+} : {
+	name: "vhksqf-button",
+	styles: "color:hotpink;font-size:12px;label:button;",
+	toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+};
+const dynamic = /* @__PURE__ */ css("color:", color, ";&:hover{color:blue;}" + (process.env.NODE_ENV === "production" ? "" : ";label:dynamic;"));
+const styles = { primary: process.env.NODE_ENV === "production" ? {
+	name: "7ass1o",
+	styles: "background:red"
+} : {
+	name: "1w6gqih-primary",
+	styles: "background:red;label:primary;",
+	toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+} };
+
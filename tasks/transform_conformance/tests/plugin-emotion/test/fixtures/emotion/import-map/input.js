@@ -0,0 +1,9 @@
+import { myCss, myStyled } from "my-package";
+
+const button = myCss`
+  color: hotpink;
+`;
+
+const Link = myStyled.a`
+  color: blue;
+`;
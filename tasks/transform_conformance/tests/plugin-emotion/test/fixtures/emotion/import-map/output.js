@@ -0,0 +1,25 @@
+import { myCss, myStyled } from "my-package";
+function _EMOTION_STRINGIFIED_CSS_ERROR__() {
+	return "You have tried to stringify object returned from `css` function. It isn't supposed to be used directly (e.g. as value of the `className` prop), but rather handed to emotion so it can handle it (e.g. as value of `css` prop).";
+}
+import { something as _styled } from "my-package/base";
+const button = process.env.NODE_ENV === "production" ? {
+	name: "3sn2xs",
+	styles: "color:hotpink"
+} : {
+	name: "1jvmdft-button",
+	styles: "color:hotpink;label:button;",
+	toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+};
+const Link = /* @__PURE__ */ _styled("a", process.env.NODE_ENV === "production" ? { target: "e1v478sf0" } : {
+	target: "e1v478sf0",
+	label: "Link"
+})(process.env.NODE_ENV === "production" ? {
+	name: "117wnve",
+	styles: "color:blue"
+} : {
+	name: "117wnve",
+	styles: "color:blue",
+	toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+});
+
@@ -0,0 +1,14 @@
+import { css, keyframes, injectGlobal } from "@emotion/css";
+
+const fade = keyframes`
+  from { opacity: 0; }
+  to { opacity: 1; }
+`;
+
+const className = css`
+  animation: ${fade} 1s;
+`;
+
+injectGlobal`
+  body { margin: 0; }
+`;
@@ -0,0 +1,22 @@
+import { css, keyframes, injectGlobal } from "@emotion/css";
+function _EMOTION_STRINGIFIED_CSS_ERROR__() {
+	return "You have tried to stringify object returned from `css` function. It isn't supposed to be used directly (e.g. as value of the `className` prop), but rather handed to emotion so it can handle it (e.g. as value of `css` prop).";
+}
+const fade = /* @__PURE__ */ keyframes(process.env.NODE_ENV === "production" ? {
+	name: "5j8bii",
+	styles: "from{opacity:0;}to{opacity:1;}"
+} : {
+	name: "i91cvj-fade",
+	styles: "from{opacity:0;}to{opacity:1;};label:fade;",
+	toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+});
+const className = /* @__PURE__ */ css("animation:", fade, "1s;" + (process.env.NODE_ENV === "production" ? "" : ";label:className;"));
+injectGlobal(process.env.NODE_ENV === "production" ? {
+	name: "1o1js1e",
+	styles: "body{margin:0;}"
+} : {
+	name: "1o1js1e",
+	styles: "body{margin:0;}",
+	toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+});
+
@@ -0,0 +1,15 @@
+import { css } from "@emotion/react";
+
+const button = css`
+  color: hotpink;
+`;
+
+class Card {
+  static styles = css`
+    padding: ${padding};
+  `;
+}
+
+theme.colors.primary = css`
+  color: red;
+`;
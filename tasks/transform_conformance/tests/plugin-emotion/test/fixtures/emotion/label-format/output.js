@@ -0,0 +1,23 @@
+import { css } from "@emotion/react";
+function _EMOTION_STRINGIFIED_CSS_ERROR__() {
+	return "You have tried to stringify object returned from `css` function. It isn't supposed to be used directly (e.g. as value of the `className` prop), but rather handed to emotion so it can handle it (e.g. as value of `css` prop).";
+}
+const button = process.env.NODE_ENV === "production" ? {
+	name: "3sn2xs",
+	styles: "color:hotpink"
+} : {
+	name: "1hyrtqy-label-format--input--button",
+	styles: "color:hotpink;label:label-format--input--button;",
+	toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+};
+class Card {}
+babelHelpers.defineProperty(Card, "styles", /* @__PURE__ */ css("padding:", padding, ";" + (process.env.NODE_ENV === "production" ? "" : ";label:label-format--input--styles;")));
+theme.colors.primary = process.env.NODE_ENV === "production" ? {
+	name: "hwfcu5",
+	styles: "color:red"
+} : {
+	name: "1nhj640-label-format--input--theme-colors-primary",
+	styles: "color:red;label:label-format--input--theme-colors-primary;",
+	toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+};
+
@@ -0,0 +1,9 @@
+import { css } from "@emotion/react";
+
+const button = css`
+  color: hotpink;
+`;
+
+const dynamic = css`
+  color: ${color};
+`;
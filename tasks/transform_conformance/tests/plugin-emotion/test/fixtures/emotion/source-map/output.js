@@ -0,0 +1,15 @@
+import { css } from "@emotion/react";
+function _EMOTION_STRINGIFIED_CSS_ERROR__() {
+	return "You have tried to stringify object returned from `css` function. It isn't supposed to be used directly (e.g. as value of the `className` prop), but rather handed to emotion so it can handle it (e.g. as value of `css` prop).";
+}
+const button = process.env.NODE_ENV === "production" ? {
+	name: "3sn2xs",
+	styles: "color:hotpink"
+} : {
+	name: "1jvmdft-button",
+	styles: "color:hotpink;label:button;",
+	map: "/*# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiaW5wdXQuanMiLCJuYW1lcyI6W10sInNvdXJjZXMiOlsiaW5wdXQuanMiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgY3NzIH0gZnJvbSBcIkBlbW90aW9uL3JlYWN0XCI7XG5cbmNvbnN0IGJ1dHRvbiA9IGNzc2BcbiAgY29sb3I6IGhvdHBpbms7XG5gO1xuXG5jb25zdCBkeW5hbWljID0gY3NzYFxuICBjb2xvcjogJHtjb2xvcn07XG5gO1xuIl0sIm1hcHBpbmdzIjoiQUFFa0IifQ== */",
+	toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+};
+const dynamic = /* @__PURE__ */ css("color:", color, ";" + (process.env.NODE_ENV === "production" ? "" : ";label:dynamic;"), process.env.NODE_ENV === "production" ? "" : "/*# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoiaW5wdXQuanMiLCJuYW1lcyI6W10sInNvdXJjZXMiOlsiaW5wdXQuanMiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgY3NzIH0gZnJvbSBcIkBlbW90aW9uL3JlYWN0XCI7XG5cbmNvbnN0IGJ1dHRvbiA9IGNzc2BcbiAgY29sb3I6IGhvdHBpbms7XG5gO1xuXG5jb25zdCBkeW5hbWljID0gY3NzYFxuICBjb2xvcjogJHtjb2xvcn07XG5gO1xuIl0sIm1hcHBpbmdzIjoiQUFNbUIifQ== */");
+
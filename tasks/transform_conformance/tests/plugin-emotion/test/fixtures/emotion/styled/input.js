@@ -0,0 +1,15 @@
+import styled from "@emotion/styled";
+
+const Button = styled.button`
+  color: hotpink;
+`;
+
+const Link = styled("a")({ color: "blue" });
+
+const Input = styled("input", { shouldForwardProp: (prop) => prop !== "color" })`
+  color: ${(props) => props.color};
+`;
+
+const Custom = styled(Component, options)`
+  display: block;
+`;
@@ -0,0 +1,43 @@
+import styled from "@emotion/styled";
+function _EMOTION_STRINGIFIED_CSS_ERROR__() {
+	return "You have tried to stringify object returned from `css` function. It isn't supposed to be used directly (e.g. as value of the `className` prop), but rather handed to emotion so it can handle it (e.g. as value of `css` prop).";
+}
+import _styled from "@emotion/styled/base";
+const Button = /* @__PURE__ */ _styled("button", process.env.NODE_ENV === "production" ? { target: "ezmpkss0" } : {
+	target: "ezmpkss0",
+	label: "Button"
+})(process.env.NODE_ENV === "production" ? {
+	name: "3sn2xs",
+	styles: "color:hotpink"
+} : {
+	name: "3sn2xs",
+	styles: "color:hotpink",
+	toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+});
+const Link = /* @__PURE__ */ _styled("a", process.env.NODE_ENV === "production" ? { target: "ezmpkss1" } : {
+	target: "ezmpkss1",
+	label: "Link"
+})(process.env.NODE_ENV === "production" ? {
+	name: "117wnve",
+	styles: "color:blue"
+} : {
+	name: "117wnve",
+	styles: "color:blue",
+	toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+});
+const Input = /* @__PURE__ */ _styled("input", babelHelpers.extends({}, process.env.NODE_ENV === "production" ? { target: "ezmpkss2" } : {
+	target: "ezmpkss2",
+	label: "Input"
+}, { shouldForwardProp: (prop) => prop !== "color" }))("color:", (props) => props.color, ";");
+const Custom = /* @__PURE__ */ _styled(Component, babelHelpers.extends({}, process.env.NODE_ENV === "production" ? { target: "ezmpkss3" } : {
+	target: "ezmpkss3",
+	label: "Custom"
+}, options))(process.env.NODE_ENV === "production" ? {
+	name: "4zleql",
+	styles: "display:block"
+} : {
+	name: "4zleql",
+	styles: "display:block",
+	toString: _EMOTION_STRINGIFIED_CSS_ERROR__
+});
+
@@ -0,0 +1,11 @@
+import gql from "graphql-tag";
+
+const query = gql`
+  query User($id: ID!) {
+    user(id: $id) {
+      id
+      name @include(if: true)
+      friends(first: 10) { edges { node { id } } }
+    }
+  }
+`;
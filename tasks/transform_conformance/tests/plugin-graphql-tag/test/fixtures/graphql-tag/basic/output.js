@@ -0,0 +1,166 @@
+const query = {
+	kind: "Document",
+	definitions: [{
+		kind: "OperationDefinition",
+		operation: "query",
+		name: {
+			kind: "Name",
+			value: "User"
+		},
+		variableDefinitions: [{
+			kind: "VariableDefinition",
+			variable: {
+				kind: "Variable",
+				name: {
+					kind: "Name",
+					value: "id"
+				}
+			},
+			type: {
+				kind: "NonNullType",
+				type: {
+					kind: "NamedType",
+					name: {
+						kind: "Name",
+						value: "ID"
+					}
+				}
+			},
+			directives: []
+		}],
+		directives: [],
+		selectionSet: {
+			kind: "SelectionSet",
+			selections: [{
+				kind: "Field",
+				name: {
+					kind: "Name",
+					value: "user"
+				},
+				arguments: [{
+					kind: "Argument",
+					name: {
+						kind: "Name",
+						value: "id"
+					},
+					value: {
+						kind: "Variable",
+						name: {
+							kind: "Name",
+							value: "id"
+						}
+					}
+				}],
+				directives: [],
+				selectionSet: {
+					kind: "SelectionSet",
+					selections: [
+						{
+							kind: "Field",
+							name: {
+								kind: "Name",
+								value: "id"
+							},
+							arguments: [],
+							directives: []
+						},
+						{
+							kind: "Field",
+							name: {
+								kind: "Name",
+								value: "name"
+							},
+							arguments: [],
+							directives: [{
+								kind: "Directive",
+								name: {
+									kind: "Name",
+									value: "include"
+								},
+								arguments: [{
+									kind: "Argument",
+									name: {
+										kind: "Name",
+										value: "if"
+									},
+									value: {
+										kind: "BooleanValue",
+										value: true
+									}
+								}]
+							}]
+						},
+						{
+							kind: "Field",
+							name: {
+								kind: "Name",
+								value: "friends"
+							},
+							arguments: [{
+								kind: "Argument",
+								name: {
+									kind: "Name",
+									value: "first"
+								},
+								value: {
+									kind: "IntValue",
+									value: "10"
+								}
+							}],
+							directives: [],
+							selectionSet: {
+								kind: "SelectionSet",
+								selections: [{
+									kind: "Field",
+									name: {
+										kind: "Name",
+										value: "edges"
+									},
+									arguments: [],
+									directives: [],
+									selectionSet: {
+										kind: "SelectionSet",
+										selections: [{
+											kind: "Field",
+											name: {
+												kind: "Name",
+												value: "node"
+											},
+											arguments: [],
+											directives: [],
+											selectionSet: {
+												kind: "SelectionSet",
+												selections: [{
+													kind: "Field",
+													name: {
+														kind: "Name",
+														value: "id"
+													},
+													arguments: [],
+													directives: []
+												}]
+											}
+										}]
+									}
+								}]
+							}
+						}
+					]
+				}
+			}]
+		}
+	}],
+	loc: {
+		start: 0,
+		end: 145,
+		source: {
+			body: "\n  query User($id: ID!) {\n    user(id: $id) {\n      id\n      name @include(if: true)\n      friends(first: 10) { edges { node { id } } }\n    }\n  }\n",
+			name: "GraphQL request",
+			locationOffset: {
+				line: 1,
+				column: 1
+			}
+		}
+	}
+};
+
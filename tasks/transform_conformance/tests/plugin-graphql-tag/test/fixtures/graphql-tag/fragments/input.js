@@ -0,0 +1,12 @@
+import gql from "graphql-tag";
+import { UserFields } from "./fragments";
+
+const fragments = { post: gql`fragment PostFields on Post { title }` };
+
+const query = gql`
+  query User {
+    user { ...UserFields posts { ...PostFields } }
+  }
+  ${UserFields}
+  ${fragments.post}
+`;
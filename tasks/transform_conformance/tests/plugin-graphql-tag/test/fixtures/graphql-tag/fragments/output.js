@@ -0,0 +1,97 @@
+import { UserFields } from "./fragments";
+function _unique(definitions) {
+	var names = {};
+	return definitions.filter(function(definition) {
+		if (definition.kind !== "FragmentDefinition") return true;
+		var name = definition.name.value;
+		if (names[name]) return false;
+		names[name] = true;
+		return true;
+	});
+}
+const fragments = { post: {
+	kind: "Document",
+	definitions: [{
+		kind: "FragmentDefinition",
+		name: {
+			kind: "Name",
+			value: "PostFields"
+		},
+		typeCondition: {
+			kind: "NamedType",
+			name: {
+				kind: "Name",
+				value: "Post"
+			}
+		},
+		directives: [],
+		selectionSet: {
+			kind: "SelectionSet",
+			selections: [{
+				kind: "Field",
+				name: {
+					kind: "Name",
+					value: "title"
+				},
+				arguments: [],
+				directives: []
+			}]
+		}
+	}]
+} };
+const query = {
+	kind: "Document",
+	definitions: _unique([{
+		kind: "OperationDefinition",
+		operation: "query",
+		name: {
+			kind: "Name",
+			value: "User"
+		},
+		variableDefinitions: [],
+		directives: [],
+		selectionSet: {
+			kind: "SelectionSet",
+			selections: [{
+				kind: "Field",
+				name: {
+					kind: "Name",
+					value: "user"
+				},
+				arguments: [],
+				directives: [],
+				selectionSet: {
+					kind: "SelectionSet",
+					selections: [{
+						kind: "FragmentSpread",
+						name: {
+							kind: "Name",
+							value: "UserFields"
+						},
+						directives: []
+					}, {
+						kind: "Field",
+						name: {
+							kind: "Name",
+							value: "posts"
+						},
+						arguments: [],
+						directives: [],
+						selectionSet: {
+							kind: "SelectionSet",
+							selections: [{
+								kind: "FragmentSpread",
+								name: {
+									kind: "Name",
+									value: "PostFields"
+								},
+								directives: []
+							}]
+						}
+					}]
+				}
+			}]
+		}
+	}].concat(UserFields.definitions, fragments.post.definitions))
+};
+
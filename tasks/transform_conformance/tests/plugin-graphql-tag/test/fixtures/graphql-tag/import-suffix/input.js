@@ -0,0 +1,5 @@
+import gql from "@my-org/graphql-tag";
+import graphql from "other";
+
+const query = gql`{ viewer { id } }`;
+const other = graphql`{ viewer { id } }`;
@@ -0,0 +1,36 @@
+import graphql from "other";
+const query = {
+	kind: "Document",
+	definitions: [{
+		kind: "OperationDefinition",
+		operation: "query",
+		variableDefinitions: [],
+		directives: [],
+		selectionSet: {
+			kind: "SelectionSet",
+			selections: [{
+				kind: "Field",
+				name: {
+					kind: "Name",
+					value: "viewer"
+				},
+				arguments: [],
+				directives: [],
+				selectionSet: {
+					kind: "SelectionSet",
+					selections: [{
+						kind: "Field",
+						name: {
+							kind: "Name",
+							value: "id"
+						},
+						arguments: [],
+						directives: []
+					}]
+				}
+			}]
+		}
+	}]
+};
+const other = graphql`{ viewer { id } }`;
+
@@ -0,0 +1,4 @@
+import { gql, other } from "graphql-tag";
+
+const query = gql`{ a: field(arg: [1, 2.5, "s", ENUM, null, { key: $var }]) }`;
+other();
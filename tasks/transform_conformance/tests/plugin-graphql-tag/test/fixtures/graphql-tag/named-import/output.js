@@ -0,0 +1,74 @@
+import { other } from "graphql-tag";
+const query = {
+	kind: "Document",
+	definitions: [{
+		kind: "OperationDefinition",
+		operation: "query",
+		variableDefinitions: [],
+		directives: [],
+		selectionSet: {
+			kind: "SelectionSet",
+			selections: [{
+				kind: "Field",
+				alias: {
+					kind: "Name",
+					value: "a"
+				},
+				name: {
+					kind: "Name",
+					value: "field"
+				},
+				arguments: [{
+					kind: "Argument",
+					name: {
+						kind: "Name",
+						value: "arg"
+					},
+					value: {
+						kind: "ListValue",
+						values: [
+							{
+								kind: "IntValue",
+								value: "1"
+							},
+							{
+								kind: "FloatValue",
+								value: "2.5"
+							},
+							{
+								kind: "StringValue",
+								value: "s",
+								block: false
+							},
+							{
+								kind: "EnumValue",
+								value: "ENUM"
+							},
+							{ kind: "NullValue" },
+							{
+								kind: "ObjectValue",
+								fields: [{
+									kind: "ObjectField",
+									name: {
+										kind: "Name",
+										value: "key"
+									},
+									value: {
+										kind: "Variable",
+										name: {
+											kind: "Name",
+											value: "var"
+										}
+									}
+								}]
+							}
+						]
+					}
+				}],
+				directives: []
+			}]
+		}
+	}]
+};
+other();
+
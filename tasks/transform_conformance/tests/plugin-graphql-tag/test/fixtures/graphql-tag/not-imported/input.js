@@ -0,0 +1,2 @@
+const query = gql`{ viewer { id } }`;
+const other = graphql`{ viewer { id } }`;
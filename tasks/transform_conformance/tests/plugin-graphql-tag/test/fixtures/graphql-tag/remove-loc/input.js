@@ -0,0 +1,3 @@
+import gql from "graphql-tag";
+
+export const mutation = gql`mutation Like($id: ID!, $value: Boolean = false) { like(id: $id, value: $value) { count } }`;
@@ -0,0 +1,105 @@
+export const mutation = {
+	kind: "Document",
+	definitions: [{
+		kind: "OperationDefinition",
+		operation: "mutation",
+		name: {
+			kind: "Name",
+			value: "Like"
+		},
+		variableDefinitions: [{
+			kind: "VariableDefinition",
+			variable: {
+				kind: "Variable",
+				name: {
+					kind: "Name",
+					value: "id"
+				}
+			},
+			type: {
+				kind: "NonNullType",
+				type: {
+					kind: "NamedType",
+					name: {
+						kind: "Name",
+						value: "ID"
+					}
+				}
+			},
+			directives: []
+		}, {
+			kind: "VariableDefinition",
+			variable: {
+				kind: "Variable",
+				name: {
+					kind: "Name",
+					value: "value"
+				}
+			},
+			type: {
+				kind: "NamedType",
+				name: {
+					kind: "Name",
+					value: "Boolean"
+				}
+			},
+			defaultValue: {
+				kind: "BooleanValue",
+				value: false
+			},
+			directives: []
+		}],
+		directives: [],
+		selectionSet: {
+			kind: "SelectionSet",
+			selections: [{
+				kind: "Field",
+				name: {
+					kind: "Name",
+					value: "like"
+				},
+				arguments: [{
+					kind: "Argument",
+					name: {
+						kind: "Name",
+						value: "id"
+					},
+					value: {
+						kind: "Variable",
+						name: {
+							kind: "Name",
+							value: "id"
+						}
+					}
+				}, {
+					kind: "Argument",
+					name: {
+						kind: "Name",
+						value: "value"
+					},
+					value: {
+						kind: "Variable",
+						name: {
+							kind: "Name",
+							value: "value"
+						}
+					}
+				}],
+				directives: [],
+				selectionSet: {
+					kind: "SelectionSet",
+					selections: [{
+						kind: "Field",
+						name: {
+							kind: "Name",
+							value: "count"
+						},
+						arguments: [],
+						directives: []
+					}]
+				}
+			}]
+		}
+	}]
+};
+
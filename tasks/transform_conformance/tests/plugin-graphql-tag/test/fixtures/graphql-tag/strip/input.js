@@ -0,0 +1,11 @@
+import gql from "graphql-tag";
+
+const query = gql`
+  # Fetch the viewer
+  query Viewer {
+    viewer {
+      id,
+      login
+    }
+  }
+`;
@@ -0,0 +1,58 @@
+const query = {
+	kind: "Document",
+	definitions: [{
+		kind: "OperationDefinition",
+		operation: "query",
+		name: {
+			kind: "Name",
+			value: "Viewer"
+		},
+		variableDefinitions: [],
+		directives: [],
+		selectionSet: {
+			kind: "SelectionSet",
+			selections: [{
+				kind: "Field",
+				name: {
+					kind: "Name",
+					value: "viewer"
+				},
+				arguments: [],
+				directives: [],
+				selectionSet: {
+					kind: "SelectionSet",
+					selections: [{
+						kind: "Field",
+						name: {
+							kind: "Name",
+							value: "id"
+						},
+						arguments: [],
+						directives: []
+					}, {
+						kind: "Field",
+						name: {
+							kind: "Name",
+							value: "login"
+						},
+						arguments: [],
+						directives: []
+					}]
+				}
+			}]
+		}
+	}],
+	loc: {
+		start: 0,
+		end: 30,
+		source: {
+			body: "query Viewer{viewer{id login}}",
+			name: "GraphQL request",
+			locationOffset: {
+				line: 1,
+				column: 1
+			}
+		}
+	}
+};
+
@@ -0,0 +1,3 @@
+import gql from "graphql-tag";
+
+const query = gql`query { a } query { b }`;
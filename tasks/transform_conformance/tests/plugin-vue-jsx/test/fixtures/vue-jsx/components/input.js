@@ -0,0 +1,6 @@
+import { defineComponent } from "vue";
+const Local = defineComponent({});
+const a = <Comp foo="bar" />;
+const b = <Local />;
+const c = <a.b.Comp />;
+const d = <Comp>text</Comp>;
@@ -0,0 +1,8 @@
+import { defineComponent } from "vue";
+import { resolveComponent as _resolveComponent, createVNode as _createVNode, createTextVNode as _createTextVNode } from "vue";
+const Local = defineComponent({});
+const a = _createVNode(_resolveComponent("Comp"), { "foo": "bar" }, null);
+const b = _createVNode(Local, null, null);
+const c = _createVNode(a.b.Comp, null, null);
+const d = _createVNode(_resolveComponent("Comp"), null, { default: () => [_createTextVNode("text")] });
+
@@ -0,0 +1 @@
+const a = <my-element foo="bar">text</my-element>;
@@ -0,0 +1,3 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
+const a = _createVNode("my-element", { "foo": "bar" }, [_createTextVNode("text")]);
+
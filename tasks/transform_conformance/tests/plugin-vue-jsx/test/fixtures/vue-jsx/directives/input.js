@@ -0,0 +1,8 @@
+const a = <input v-model={val} v-show={visible} />;
+const b = <select v-model={sel}><option>1</option></select>;
+const c = <input type="checkbox" v-model={checked} />;
+const d = <input type={type} v-model={value} />;
+const e = <div v-html={html} />;
+const f = <div v-text={text} />;
+const g = <Comp v-custom:arg_mod={x} />;
+const h = <input v-model={[val, ["trim", "lazy"]]} />;
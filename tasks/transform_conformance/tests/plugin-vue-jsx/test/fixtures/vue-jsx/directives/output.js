@@ -0,0 +1,29 @@
+import { vModelText as _vModelText, vShow as _vShow, createVNode as _createVNode, withDirectives as _withDirectives, createTextVNode as _createTextVNode, vModelSelect as _vModelSelect, vModelCheckbox as _vModelCheckbox, vModelDynamic as _vModelDynamic, resolveComponent as _resolveComponent, resolveDirective as _resolveDirective } from "vue";
+const a = _withDirectives(_createVNode("input", { "onUpdate:modelValue": ($event) => val = $event }, null), [[_vModelText, val], [_vShow, visible]]);
+const b = _withDirectives(_createVNode("select", { "onUpdate:modelValue": ($event) => sel = $event }, [_createVNode("option", null, [_createTextVNode("1")])]), [[_vModelSelect, sel]]);
+const c = _withDirectives(_createVNode("input", {
+	"type": "checkbox",
+	"onUpdate:modelValue": ($event) => checked = $event
+}, null), [[_vModelCheckbox, checked]]);
+const d = _withDirectives(_createVNode("input", {
+	"type": type,
+	"onUpdate:modelValue": ($event) => value = $event
+}, null), [[_vModelDynamic, value]]);
+const e = _createVNode("div", { "innerHTML": html }, null);
+const f = _createVNode("div", { "textContent": text }, null);
+const g = _withDirectives(_createVNode(_resolveComponent("Comp"), null, null), [[
+	_resolveDirective("custom"),
+	x,
+	"arg",
+	{ mod: true }
+]]);
+const h = _withDirectives(_createVNode("input", { "onUpdate:modelValue": ($event) => val = $event }, null), [[
+	_vModelText,
+	val,
+	void 0,
+	{
+		trim: true,
+		lazy: true
+	}
+]]);
+
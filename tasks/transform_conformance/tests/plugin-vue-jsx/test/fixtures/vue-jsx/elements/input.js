@@ -0,0 +1,7 @@
+const a = <div class="a" id={id} onClick={handler}>hello {name}</div>;
+const b = <svg:circle xlink:href="#a" />;
+const c = <><div /><p>text</p></>;
+const d = <div>
+  <span>  multi
+    line  </span>
+</div>;
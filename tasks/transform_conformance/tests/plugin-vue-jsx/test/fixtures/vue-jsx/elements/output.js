@@ -0,0 +1,10 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode, Fragment as _Fragment } from "vue";
+const a = _createVNode("div", {
+	"class": "a",
+	"id": id,
+	"onClick": handler
+}, [_createTextVNode("hello "), name]);
+const b = _createVNode("svg:circle", { "xlink:href": "#a" }, null);
+const c = _createVNode(_Fragment, null, [_createVNode("div", null, null), _createVNode("p", null, [_createTextVNode("text")])]);
+const d = _createVNode("div", null, [_createVNode("span", null, [_createTextVNode("  multi line  ")])]);
+
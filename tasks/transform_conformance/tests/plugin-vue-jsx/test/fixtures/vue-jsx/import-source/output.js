@@ -0,0 +1,3 @@
+import { resolveComponent as _resolveComponent, createVNode as _createVNode } from "@vue/runtime-dom";
+const a = _createVNode(_resolveComponent("Comp"), null, null);
+
@@ -0,0 +1 @@
+const a = <div {...props} class="x" />;
@@ -0,0 +1,6 @@
+import { createVNode as _createVNode } from "vue";
+const a = _createVNode("div", {
+	...props,
+	"class": "x"
+}, null);
+
@@ -0,0 +1,3 @@
+const a = <div {...props} class="x" class={y} style={s} />;
+const b = <div onClick={a} onClick={b} />;
+const c = <div {...a} {...b} />;
@@ -0,0 +1,8 @@
+import { mergeProps as _mergeProps, createVNode as _createVNode } from "vue";
+const a = _createVNode("div", _mergeProps(props, {
+	"class": ["x", y],
+	"style": s
+}), null);
+const b = _createVNode("div", { "onClick": [a, b] }, null);
+const c = _createVNode("div", _mergeProps(a, b), null);
+
@@ -0,0 +1 @@
+const a = <Comp>{slots}</Comp>;
@@ -0,0 +1,3 @@
+import { resolveComponent as _resolveComponent, createVNode as _createVNode } from "vue";
+const a = _createVNode(_resolveComponent("Comp"), null, { default: () => [slots] });
+
@@ -0,0 +1,5 @@
+const a = <div class="a" id={id} onClick={handler}>hello</div>;
+const b = <Comp foo={bar}>{{ default: () => "a" }}</Comp>;
+const c = <div {...p} />;
+const d = <div ref="r" />;
+const e = <div class={cls} style={style} />;
@@ -0,0 +1,17 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
+const a = _createVNode("div", {
+	"class": "a",
+	"id": id,
+	"onClick": handler
+}, [_createTextVNode("hello")], 8, ["id", "onClick"]);
+const b = _createVNode(_resolveComponent("Comp"), { "foo": bar }, {
+	default: () => "a",
+	_: 1
+}, 8, ["foo"]);
+const c = _createVNode("div", p, null, 16);
+const d = _createVNode("div", { "ref": "r" }, null, 512);
+const e = _createVNode("div", {
+	"class": cls,
+	"style": style
+}, null, 6);
+
@@ -0,0 +1 @@
+const a = <Comp a="1">text</Comp>;
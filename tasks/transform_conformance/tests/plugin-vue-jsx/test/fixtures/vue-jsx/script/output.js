@@ -0,0 +1,2 @@
+var _vue = require("vue");
+const a = _vue.createVNode(_vue.resolveComponent("Comp"), { "a": "1" }, { default: () => [_vue.createTextVNode("text")] });
@@ -0,0 +1,6 @@
+const a = <Comp>{() => <span />}</Comp>;
+const b = <Comp>{{ default: () => "a", foo: () => "b" }}</Comp>;
+const c = <Comp>{slots}</Comp>;
+const d = <Comp>{getSlots()}</Comp>;
+const e = <Comp v-slots={{ a: () => <i /> }} />;
+const f = <Comp><div /><span /></Comp>;
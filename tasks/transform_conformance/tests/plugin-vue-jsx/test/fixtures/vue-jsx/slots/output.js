@@ -0,0 +1,15 @@
+function _isSlot(s) {
+	return typeof s === "function" || Object.prototype.toString.call(s) === "[object Object]" && !_isVNode(s);
+}
+import { createVNode as _createVNode, resolveComponent as _resolveComponent, isVNode as _isVNode } from "vue";
+var _slot;
+const a = _createVNode(_resolveComponent("Comp"), null, { default: () => _createVNode("span", null, null) });
+const b = _createVNode(_resolveComponent("Comp"), null, {
+	default: () => "a",
+	foo: () => "b"
+});
+const c = _createVNode(_resolveComponent("Comp"), null, _isSlot(slots) ? slots : { default: () => [slots] });
+const d = _createVNode(_resolveComponent("Comp"), null, _isSlot(_slot = getSlots()) ? _slot : { default: () => [_slot] });
+const e = _createVNode(_resolveComponent("Comp"), null, { a: () => _createVNode("i", null, null) });
+const f = _createVNode(_resolveComponent("Comp"), null, { default: () => [_createVNode("div", null, null), _createVNode("span", null, null)] });
+
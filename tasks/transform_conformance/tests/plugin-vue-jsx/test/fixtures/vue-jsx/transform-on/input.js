@@ -0,0 +1 @@
+const a = <div on={{ click: handler }} />;
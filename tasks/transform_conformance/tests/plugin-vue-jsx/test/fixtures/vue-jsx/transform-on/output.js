@@ -0,0 +1,4 @@
+import _transformOn from "@vue/babel-helper-vue-transform-on";
+import { createVNode as _createVNode } from "vue";
+const a = _createVNode("div", _transformOn({ click: handler }), null);
+
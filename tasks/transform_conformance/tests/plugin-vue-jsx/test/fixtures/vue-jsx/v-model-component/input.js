@@ -0,0 +1,5 @@
+const a = <Comp v-model={val} />;
+const b = <Comp v-model={[val, "foo", ["trim"]]} />;
+const c = <Comp v-model:bar={obj.bar} />;
+const d = <Comp v-model={[val, name]} />;
+const e = <Comp v-models={[[a, "a"], [b, "b", ["number"]]]} />;
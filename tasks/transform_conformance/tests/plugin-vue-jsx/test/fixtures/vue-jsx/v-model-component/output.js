@@ -0,0 +1,26 @@
+import { resolveComponent as _resolveComponent, createVNode as _createVNode } from "vue";
+const a = _createVNode(_resolveComponent("Comp"), {
+	"modelValue": val,
+	"onUpdate:modelValue": ($event) => val = $event
+}, null);
+const b = _createVNode(_resolveComponent("Comp"), {
+	"foo": val,
+	"fooModifiers": { "trim": true },
+	"onUpdate:foo": ($event) => val = $event
+}, null);
+const c = _createVNode(_resolveComponent("Comp"), {
+	"bar": obj.bar,
+	"onUpdate:bar": ($event) => obj.bar = $event
+}, null);
+const d = _createVNode(_resolveComponent("Comp"), {
+	[name]: val,
+	["onUpdate:" + name]: ($event) => val = $event
+}, null);
+const e = _createVNode(_resolveComponent("Comp"), {
+	"a": a,
+	"onUpdate:a": ($event) => a = $event,
+	"b": b,
+	"bModifiers": { "number": true },
+	"onUpdate:b": ($event) => b = $event
+}, null);
+
@@ -0,0 +1 @@
+const a = <input v-model={[]} />;
@@ -0,0 +1 @@
+const a = <div v-models={[[x]]} />;
@@ -0,0 +1,30 @@
+class Foo {
+  #A = 1;
+  get a() {
+    return this.#A;
+  }
+  set a(v) {
+    this.#A = v;
+  }
+  static #B;
+  static get b() {
+    return this.#B;
+  }
+  static set b(v) {
+    this.#B = v;
+  }
+  #C = 3;
+  get #c() {
+    return this.#C;
+  }
+  set #c(v) {
+    this.#C = v;
+  }
+  #D = 4;
+  get ["d"]() {
+    return this.#D;
+  }
+  set ["d"](v) {
+    this.#D = v;
+  }
+}
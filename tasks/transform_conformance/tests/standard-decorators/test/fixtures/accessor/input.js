@@ -0,0 +1,6 @@
+class Foo {
+  @dec accessor a = 1;
+  @dec static accessor b = 2;
+  @dec accessor #c = 3;
+  @dec static accessor #d = 4;
+}
@@ -0,0 +1,65 @@
+var _init_a, _init_extra_a, _init_b, _init_extra_b, _init_c, _init_extra_c, _get_c, _set_c, _init_d, _init_extra_d, _get_d, _set_d;
+class Foo {
+  static {
+    [_init_b, _init_extra_b, _init_d, _get_d, _set_d, _init_extra_d, _init_a, _init_extra_a, _init_c, _get_c, _set_c, _init_extra_c] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        9,
+        "b"
+      ],
+      [
+        dec,
+        9,
+        "d",
+        (o) => o.#D,
+        (o, v) => o.#D = v
+      ],
+      [
+        dec,
+        1,
+        "a"
+      ],
+      [
+        dec,
+        1,
+        "c",
+        (o) => o.#C,
+        (o, v) => o.#C = v
+      ]
+    ], 0, (_) => #c in _).e;
+  }
+  #A = _init_a(this, 1);
+  get a() {
+    return this.#A;
+  }
+  set a(v) {
+    this.#A = v;
+  }
+  static #B = _init_b(2);
+  static get b() {
+    return this.#B;
+  }
+  static set b(v) {
+    this.#B = v;
+  }
+  #C = (_init_extra_a(this), _init_c(this, 3));
+  get #c() {
+    return _get_c(this);
+  }
+  set #c(v) {
+    _set_c(this, v);
+  }
+  static #D = (_init_extra_b(), _init_d(4));
+  static get #d() {
+    return _get_d();
+  }
+  static set #d(v) {
+    _set_d(v);
+  }
+  static {
+    _init_extra_d();
+  }
+  constructor() {
+    _init_extra_c(this);
+  }
+}
@@ -0,0 +1,11 @@
+var _initClass;
+let _default2;
+class _default {
+  static {
+    [_default2, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  static {
+    _initClass();
+  }
+}
+export { _default2 as default };
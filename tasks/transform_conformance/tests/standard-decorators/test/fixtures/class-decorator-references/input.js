@@ -0,0 +1,16 @@
+export function create() {
+  return new Foo();
+}
+
+@dec
+export class Foo {
+  static self() {
+    return Foo;
+  }
+}
+
+@dec()
+class Bar {}
+
+export { Bar };
+console.log(Foo, Bar);
@@ -0,0 +1,29 @@
+var _initClass, _initClass2, _dec;
+export function create() {
+  return new _Foo();
+}
+let _Foo;
+class Foo {
+  static {
+    [_Foo, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  static self() {
+    return _Foo;
+  }
+  static {
+    _initClass();
+  }
+}
+export { _Foo as Foo };
+let _Bar;
+_dec = [dec()];
+class Bar {
+  static {
+    [_Bar, _initClass2] = babelHelpers.applyDecs2311(this, _dec, []).c;
+  }
+  static {
+    _initClass2();
+  }
+}
+export { _Bar as Bar };
+console.log(_Foo, _Bar);
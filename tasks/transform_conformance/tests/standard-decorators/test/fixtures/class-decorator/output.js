@@ -0,0 +1,13 @@
+var _initClass;
+let _Foo;
+class Foo {
+  static {
+    [_Foo, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  static create() {
+    return new _Foo();
+  }
+  static {
+    _initClass();
+  }
+}
@@ -0,0 +1,17 @@
+var _Class, _initClass, _dec, _initProto;
+const Foo = (_dec = dec(), class {
+  static {
+    ({e: [_initProto], c: [_Class, _initClass]} = babelHelpers.applyDecs2311(this, [dec], [[
+      _dec,
+      2,
+      "method"
+    ]]));
+  }
+  method() {}
+  constructor() {
+    _initProto(this);
+  }
+  static {
+    _initClass();
+  }
+}, _Class);
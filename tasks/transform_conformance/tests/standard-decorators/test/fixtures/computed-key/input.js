@@ -0,0 +1,5 @@
+class Foo {
+  @dec [key()] = 1;
+  @dec accessor [key2()] = 2;
+  @dec ["literal"]() {}
+}
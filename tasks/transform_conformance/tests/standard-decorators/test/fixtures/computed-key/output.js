@@ -0,0 +1,34 @@
+var _computedKey, _init_computedKey, _init_extra_computedKey, _computedKey2, _init_computedKey2, _init_extra_computedKey2, _initProto;
+class Foo {
+  static {
+    [_init_computedKey2, _init_extra_computedKey2, _init_computedKey, _init_extra_computedKey, _initProto] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        1,
+        _computedKey2
+      ],
+      [
+        dec,
+        2,
+        "literal"
+      ],
+      [
+        dec,
+        0,
+        _computedKey
+      ]
+    ]).e;
+  }
+  [_computedKey = babelHelpers.toPropertyKey(key())] = (_initProto(this), _init_computedKey(this, 1));
+  #A = (_init_extra_computedKey(this), _init_computedKey2(this, 2));
+  get [_computedKey2 = babelHelpers.toPropertyKey(key2())]() {
+    return this.#A;
+  }
+  set [_computedKey2](v) {
+    this.#A = v;
+  }
+  ["literal"]() {}
+  constructor() {
+    _init_extra_computedKey2(this);
+  }
+}
@@ -0,0 +1,10 @@
+class Foo extends Bar {
+  @dec method() {}
+  constructor() {
+    if (x) super(1);
+    else super(2);
+  }
+}
+class Baz extends getBase() {
+  @dec accessor a;
+}
@@ -0,0 +1,35 @@
+var _initProto, _init_a, _init_extra_a, _superClass;
+class Foo extends Bar {
+  static {
+    [_initProto] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      2,
+      "method"
+    ]], 0, void 0, Bar).e;
+  }
+  method() {}
+  constructor() {
+    if (x) _initProto(super(1));
+    else _initProto(super(2));
+  }
+}
+class Baz extends (_superClass = getBase()) {
+  static {
+    [_init_a, _init_extra_a] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      1,
+      "a"
+    ]], 0, void 0, _superClass).e;
+  }
+  #A = _init_a(this);
+  get a() {
+    return this.#A;
+  }
+  set a(v) {
+    this.#A = v;
+  }
+  constructor(..._args) {
+    super(..._args);
+    _init_extra_a(this);
+  }
+}
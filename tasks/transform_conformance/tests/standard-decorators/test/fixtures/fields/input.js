@@ -0,0 +1,7 @@
+class Foo {
+  @dec a = 1;
+  @dec b;
+  c = 3;
+  @dec static d = 4;
+  @dec #e = 5;
+}
@@ -0,0 +1,40 @@
+var _init_a, _init_extra_a, _init_b, _init_extra_b, _init_d, _init_extra_d, _init_e, _init_extra_e;
+class Foo {
+  static {
+    [_init_d, _init_extra_d, _init_a, _init_extra_a, _init_b, _init_extra_b, _init_e, _init_extra_e] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        8,
+        "d"
+      ],
+      [
+        dec,
+        0,
+        "a"
+      ],
+      [
+        dec,
+        0,
+        "b"
+      ],
+      [
+        dec,
+        0,
+        "e",
+        (o) => o.#e,
+        (o, v) => o.#e = v
+      ]
+    ], 0, (_) => #e in _).e;
+  }
+  a = _init_a(this, 1);
+  b = (_init_extra_a(this), _init_b(this));
+  c = (_init_extra_b(this), 3);
+  static d = _init_d(4);
+  #e = _init_e(this, 5);
+  static {
+    _init_extra_d();
+  }
+  constructor() {
+    _init_extra_e(this);
+  }
+}
@@ -0,0 +1,4 @@
+class Foo {
+  @obj.dec a = 1;
+  @(foo().dec) @dec2 method() {}
+}
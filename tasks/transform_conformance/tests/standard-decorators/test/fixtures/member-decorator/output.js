@@ -0,0 +1,26 @@
+var _dec, _init_a, _init_extra_a, _dec2, _initProto, _obj;
+_dec = [obj, obj.dec];
+_dec2 = [
+  _obj = foo(),
+  _obj.dec,
+  void 0,
+  dec2
+];
+class Foo {
+  static {
+    [_init_a, _init_extra_a, _initProto] = babelHelpers.applyDecs2311(this, [], [[
+      _dec2,
+      18,
+      "method"
+    ], [
+      _dec,
+      16,
+      "a"
+    ]]).e;
+  }
+  a = (_initProto(this), _init_a(this, 1));
+  method() {}
+  constructor() {
+    _init_extra_a(this);
+  }
+}
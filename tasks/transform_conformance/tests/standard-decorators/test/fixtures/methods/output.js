@@ -0,0 +1,35 @@
+var _initProto, _initStatic;
+class Foo {
+  static {
+    [_initProto, _initStatic] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        10,
+        "staticMethod"
+      ],
+      [
+        dec,
+        2,
+        "method"
+      ],
+      [
+        dec,
+        3,
+        "getter"
+      ],
+      [
+        dec,
+        4,
+        "setter"
+      ]
+    ]).e;
+    _initStatic(this);
+  }
+  method() {}
+  get getter() {}
+  set setter(v) {}
+  static staticMethod() {}
+  constructor() {
+    _initProto(this);
+  }
+}
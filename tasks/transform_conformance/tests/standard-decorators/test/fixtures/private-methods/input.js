@@ -0,0 +1,6 @@
+class Foo {
+  @dec #method() {}
+  @dec get #getter() { return 1; }
+  @dec set #setter(v) {}
+  @dec static #staticMethod() {}
+}
@@ -0,0 +1,49 @@
+var _call_method, _call_getter, _call_setter, _call_staticMethod, _initProto, _initStatic;
+class Foo {
+  static {
+    [_call_staticMethod, _call_method, _call_getter, _call_setter, _initProto, _initStatic] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        10,
+        "staticMethod",
+        function() {}
+      ],
+      [
+        dec,
+        2,
+        "method",
+        function() {}
+      ],
+      [
+        dec,
+        3,
+        "getter",
+        function() {
+          return 1;
+        }
+      ],
+      [
+        dec,
+        4,
+        "setter",
+        function(v) {}
+      ]
+    ], 0, (_) => #method in _).e;
+    _initStatic(this);
+  }
+  get #method() {
+    return _call_method;
+  }
+  get #getter() {
+    return _call_getter(this);
+  }
+  set #setter(v) {
+    _call_setter(this, v);
+  }
+  static get #staticMethod() {
+    return _call_staticMethod;
+  }
+  constructor() {
+    _initProto(this);
+  }
+}
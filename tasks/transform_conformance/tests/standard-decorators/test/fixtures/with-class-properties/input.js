@@ -0,0 +1,6 @@
+@dec
+class Foo {
+  @dec accessor #a = 1;
+  @dec #method() {}
+  static #b = 2;
+}
@@ -0,0 +1,38 @@
+var _initClass, _init_a, _init_extra_a, _get_a, _set_a, _call_method, _initProto;
+var _A = /* @__PURE__ */ new WeakMap();
+var _Foo_brand = /* @__PURE__ */ new WeakSet();
+let _Foo;
+class Foo {
+  static {
+    ({e: [_init_a, _get_a, _set_a, _init_extra_a, _call_method, _initProto], c: [_Foo, _initClass]} = babelHelpers.applyDecs2311(this, [dec], [[
+      dec,
+      1,
+      "a",
+      (o) => babelHelpers.classPrivateFieldGet2(_A, o),
+      (o, v) => babelHelpers.classPrivateFieldSet2(_A, o, v)
+    ], [
+      dec,
+      2,
+      "method",
+      function() {}
+    ]], 0, (_) => _Foo_brand.has(babelHelpers.checkInRHS(_))));
+  }
+  constructor() {
+    babelHelpers.classPrivateMethodInitSpec(this, _Foo_brand);
+    babelHelpers.classPrivateFieldInitSpec(this, _A, (_initProto(this), _init_a(this, 1)));
+    _init_extra_a(this);
+  }
+  static {
+    _initClass();
+  }
+}
+function _get_a2() {
+  return _get_a(this);
+}
+function _set_a2(v) {
+  _set_a(this, v);
+}
+function _get_method() {
+  return _call_method;
+}
+var _b = { _: 2 };